@@ -9,6 +9,9 @@
 //! - [`handle_atm_pending_count`] — count unread messages without marking them read
 //! - [`handle_agent_sessions`] — list all sessions with their status (FR-10.1)
 //! - [`handle_agent_status`] — summarise proxy status (FR-10.2)
+//! - [`handle_agent_metrics`] — report internal proxy counters
+//! - [`resolve_interrupt_target`] / [`make_interrupt_result`] — `agent_interrupt`
+//!   target lookup and result shape (cancellation itself lives in the proxy)
 //!
 //! The ATM communication handlers operate synchronously using `std::fs`.
 //! The session management handlers are `async` because they must acquire the
@@ -24,19 +27,20 @@
 //! returns an error with code [`ERR_IDENTITY_REQUIRED`] (re-exported via
 //! `proxy.rs`).
 
-use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use agent_team_mail_core::InboxMessage;
+use agent_team_mail_core::config::{ConfigOverrides, HostnameRegistry, resolve_config};
 use agent_team_mail_core::home::{get_home_dir, teams_root_dir_for};
-use agent_team_mail_core::io::{inbox_append, inbox_update};
+use agent_team_mail_core::io::inbox::inbox_read_merged;
+use agent_team_mail_core::io::{configured_id_scheme, inbox_append, inbox_update, new_message_id};
 use agent_team_mail_core::text::{truncate_chars, truncate_chars_slice};
 use serde_json::{Value, json};
 use tokio::sync::Mutex;
 
 use crate::lock::release_lock;
-use crate::session::{SessionRegistry, SessionStatus, ThreadState};
+use crate::session::{SessionEntry, SessionRegistry, SessionStatus, ThreadState};
 
 /// Maximum allowed message length in characters (FR-8.4).
 const MAX_MESSAGE_LEN: usize = 4096;
@@ -119,6 +123,25 @@ fn parse_to(to: &str, default_team: &str) -> Result<(String, String), String> {
     }
 }
 
+/// Return the team an ATM tool call would write to, if it differs from the
+/// proxy's `team`.
+///
+/// Covers `atm_send` and an explicit `atm_reply` (`to = "agent@team"`) and
+/// `atm_broadcast` (`team`). Other tools always operate on the proxy team and
+/// yield `None`. Malformed `to` values yield `None` so the handler can report
+/// the parse error itself.
+pub fn cross_team_target(tool_name: &str, args: &Value, team: &str) -> Option<String> {
+    let target = match tool_name {
+        "atm_send" | "atm_reply" => {
+            let to = args.get("to").and_then(|v| v.as_str())?;
+            parse_to(to, team).ok()?.1
+        }
+        "atm_broadcast" => args.get("team").and_then(|v| v.as_str())?.to_string(),
+        _ => return None,
+    };
+    (target != team).then_some(target)
+}
+
 /// Build the path to an agent's inbox file.
 ///
 /// `<teams_root>/<team>/inboxes/<agent>.json`
@@ -166,29 +189,44 @@ fn days_to_ymd(days: u64) -> (u64, u64, u64) {
 }
 
 /// Build a new [`InboxMessage`] from parts.
-fn build_message(from: &str, text: String, summary: Option<String>) -> InboxMessage {
-    let message_id = Some(uuid::Uuid::new_v4().to_string());
-    let auto_sum = auto_summary(&text);
-    InboxMessage {
-        from: from.to_string(),
-        // local system message — source_team intentionally None
-        source_team: None,
-        text,
-        timestamp: now_iso8601(),
-        read: false,
-        summary: Some(summary.unwrap_or(auto_sum)),
-        message_id,
-        unknown_fields: HashMap::new(),
+fn build_message(
+    from: &str,
+    text: String,
+    summary: Option<String>,
+    message_id: String,
+    priority: Option<u8>,
+) -> InboxMessage {
+    let summary = summary.unwrap_or_else(|| auto_summary(&text));
+    // local system message — source_team intentionally None
+    let message = InboxMessage::new(from, text)
+        .timestamp(now_iso8601())
+        .summary(summary)
+        .message_id(message_id);
+    match priority {
+        Some(priority) => message.priority(priority),
+        None => message,
     }
 }
 
-/// Construct a successful MCP result response.
-fn make_mcp_success(id: &Value, text: String) -> Value {
+/// Construct a successful MCP result whose text is `body` pretty-printed and
+/// whose `structuredContent` is `body` itself (must be a JSON object).
+fn make_mcp_json_success(id: &Value, body: Value) -> Value {
+    let text = serde_json::to_string_pretty(&body).unwrap_or_default();
+    make_mcp_structured_success(id, text, body)
+}
+
+/// Construct a successful MCP result carrying both display text and a
+/// machine-parseable `structuredContent` object.
+///
+/// MCP requires `structuredContent` to be a JSON object, so list results are
+/// wrapped (e.g. `{"messages": [...]}`) while the text keeps the bare list.
+fn make_mcp_structured_success(id: &Value, text: String, structured: Value) -> Value {
     json!({
         "jsonrpc": "2.0",
         "id": id,
         "result": {
-            "content": [{"type": "text", "text": text}]
+            "content": [{"type": "text", "text": text}],
+            "structuredContent": structured
         }
     })
 }
@@ -229,8 +267,21 @@ pub fn make_mcp_error_result(id: &Value, message: &str) -> Value {
 ///
 /// # Returns
 ///
-/// MCP result with `"Message sent to <agent>@<team>"` on success.
+/// MCP result with `"Message sent to <agent>@<team>"` on success;
+/// `structuredContent` is `{agent, team, message_id}`.
 pub fn handle_atm_send(id: &Value, args: &Value, identity: &str, team: &str) -> Value {
+    send_message(id, args, identity, team, None)
+}
+
+/// Shared body of `atm_send` and `atm_reply`; a `reply_to` message ID is
+/// stored on the outgoing message as `replyTo`.
+fn send_message(
+    id: &Value,
+    args: &Value,
+    identity: &str,
+    team: &str,
+    reply_to: Option<&str>,
+) -> Value {
     let to = match args.get("to").and_then(|v| v.as_str()) {
         Some(s) if !s.is_empty() => s,
         _ => return make_mcp_error_result(id, "atm_send: 'to' parameter is required"),
@@ -250,8 +301,15 @@ pub fn handle_atm_send(id: &Value, args: &Value, identity: &str, team: &str) ->
         .get("summary")
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
-
-    let msg = build_message(identity, message_text, summary);
+    let priority = match args.get("priority") {
+        None | Some(Value::Null) => None,
+        Some(v) => match v.as_u64().and_then(|p| u8::try_from(p).ok()) {
+            Some(p) => Some(p),
+            None => {
+                return make_mcp_error_result(id, "atm_send: 'priority' must be an integer 0-255");
+            }
+        },
+    };
 
     let home = match get_home_dir() {
         Ok(h) => h,
@@ -272,12 +330,158 @@ pub fn handle_atm_send(id: &Value, args: &Value, identity: &str, team: &str) ->
         }
     }
 
+    let message_id = match new_message_id(configured_id_scheme(), &path) {
+        Ok(message_id) => message_id,
+        Err(e) => {
+            return make_mcp_error_result(
+                id,
+                &format!("atm_send: cannot allocate message id: {e}"),
+            );
+        }
+    };
+    let mut msg = build_message(identity, message_text, summary, message_id, priority);
+    if let Some(reply_to) = reply_to {
+        msg = msg.reply_to(reply_to);
+    }
+
     match inbox_append(&path, &msg, &effective_team, &agent) {
-        Ok(_) => make_mcp_success(id, format!("Message sent to {agent}@{effective_team}")),
+        Ok(_) => make_mcp_structured_success(
+            id,
+            format!("Message sent to {agent}@{effective_team}"),
+            json!({
+                "agent": agent,
+                "team": effective_team,
+                "message_id": msg.message_id,
+            }),
+        ),
         Err(e) => make_mcp_error_result(id, &format!("atm_send: failed to write inbox: {e}")),
     }
 }
 
+/// Handle an `atm_reply` tool call.
+///
+/// Sends `message` to `to` when given; otherwise to the sender of the most
+/// recent unread message in the caller's inbox, or of the most recent message
+/// overall when none is unread. Idle notifications are never replied to. A
+/// message carrying a `source_team` other than `team` is answered as
+/// `sender@source_team`, which requires `allow_cross_team`.
+///
+/// # Parameters (from `args`)
+///
+/// | Field     | Required | Description                                    |
+/// |-----------|----------|------------------------------------------------|
+/// | `message` | yes      | Reply body                                     |
+/// | `to`      | no       | Override recipient, optionally `agent@team`    |
+/// | `summary` | no       | Short summary (auto-generated if absent)       |
+///
+/// # Returns
+///
+/// The [`handle_atm_send`] result, with `in_reply_to` (the replied-to message
+/// ID, `null` for an explicit `to`) added to `structuredContent`. The reply
+/// carries the same ID as `replyTo`. An empty
+/// inbox without `to` is an error result; nothing is sent.
+pub fn handle_atm_reply(
+    id: &Value,
+    args: &Value,
+    identity: &str,
+    team: &str,
+    allow_cross_team: bool,
+) -> Value {
+    if args.get("message").and_then(|v| v.as_str()).is_none() {
+        return make_mcp_error_result(id, "atm_reply: 'message' parameter is required");
+    }
+
+    let explicit_to = args
+        .get("to")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty());
+    let (to, in_reply_to) = match explicit_to {
+        Some(to) => (to.to_string(), None),
+        None => {
+            let home = match get_home_dir() {
+                Ok(h) => h,
+                Err(e) => {
+                    return make_mcp_error_result(
+                        id,
+                        &format!("atm_reply: cannot resolve home dir: {e}"),
+                    );
+                }
+            };
+            let team_dir = teams_root_dir_for(&home).join(team);
+            let registry = origin_hostname_registry(&home);
+            let original = match latest_reply_target(&team_dir, identity, registry.as_ref()) {
+                Ok(Some(msg)) => msg,
+                Ok(None) => {
+                    return make_mcp_error_result(
+                        id,
+                        "atm_reply: inbox is empty, nobody to reply to (pass 'to' explicitly)",
+                    );
+                }
+                Err(e) => return make_mcp_error_result(id, &format!("atm_reply: {e}")),
+            };
+            let to = match original.source_team.as_deref() {
+                Some(source_team) if source_team != team => {
+                    if !allow_cross_team {
+                        return make_mcp_error_result(
+                            id,
+                            &format!(
+                                "atm_reply: sender {}@{source_team} is outside this proxy's \
+                                 team '{team}' (set allow_cross_team to permit)",
+                                original.from
+                            ),
+                        );
+                    }
+                    format!("{}@{source_team}", original.from)
+                }
+                _ => original.from,
+            };
+            (to, original.message_id)
+        }
+    };
+
+    let mut send_args = args.clone();
+    send_args["to"] = json!(to);
+    let mut result = send_message(id, &send_args, identity, team, in_reply_to.as_deref());
+    if let Some(structured) = result
+        .pointer_mut("/result/structuredContent")
+        .and_then(Value::as_object_mut)
+    {
+        structured.insert("in_reply_to".to_string(), json!(in_reply_to));
+    }
+    result
+}
+
+/// The message an implicit `atm_reply` answers: the last unread message in
+/// `agent`'s merged inbox (local and bridged origin files), else the last
+/// message, skipping idle notifications.
+fn latest_reply_target(
+    team_dir: &std::path::Path,
+    agent: &str,
+    hostname_registry: Option<&HostnameRegistry>,
+) -> Result<Option<InboxMessage>, String> {
+    let messages = inbox_read_merged(team_dir, agent, hostname_registry)
+        .map_err(|e| format!("cannot read inbox: {e}"))?;
+    let mut latest = None;
+    let mut latest_unread = None;
+    for msg in messages.into_iter().filter(|m| !m.is_idle_notification()) {
+        if !msg.read {
+            latest_unread = Some(msg.clone());
+        }
+        latest = Some(msg);
+    }
+    Ok(latest_unread.or(latest))
+}
+
+/// Hostname registry from the bridge plugin config, so reads include
+/// per-origin inbox files. `None` when the bridge is off or config fails to
+/// resolve.
+fn origin_hostname_registry(home: &std::path::Path) -> Option<HostnameRegistry> {
+    let current_dir = std::env::current_dir().ok()?;
+    resolve_config(&ConfigOverrides::default(), &current_dir, home)
+        .ok()?
+        .hostname_registry()
+}
+
 /// Handle an `atm_read` tool call.
 ///
 /// Reads messages from the caller's own inbox, with optional filtering.
@@ -294,7 +498,8 @@ pub fn handle_atm_send(id: &Value, args: &Value, identity: &str, team: &str) ->
 ///
 /// # Returns
 ///
-/// MCP result whose text is a JSON array of `{from, text, timestamp, message_id}` objects.
+/// MCP result whose text is a JSON array of `{from, text, timestamp, message_id}` objects;
+/// `structuredContent` is `{"messages": [...]}` with the same objects.
 pub fn handle_atm_read(id: &Value, args: &Value, identity: &str, team: &str) -> Value {
     let home = match get_home_dir() {
         Ok(h) => h,
@@ -307,7 +512,7 @@ pub fn handle_atm_read(id: &Value, args: &Value, identity: &str, team: &str) ->
 
     // If inbox doesn't exist, return empty array (not an error).
     if !path.exists() {
-        return make_mcp_success(id, "[]".to_string());
+        return make_read_result(id, Vec::new());
     }
 
     // Read current messages
@@ -419,8 +624,13 @@ pub fn handle_atm_read(id: &Value, args: &Value, identity: &str, team: &str) ->
         }
     }
 
-    let text = serde_json::to_string_pretty(&output).unwrap_or_else(|_| "[]".to_string());
-    make_mcp_success(id, text)
+    make_read_result(id, output)
+}
+
+/// Build the `atm_read` result from the returned message objects.
+fn make_read_result(id: &Value, messages: Vec<Value>) -> Value {
+    let text = serde_json::to_string_pretty(&messages).unwrap_or_else(|_| "[]".to_string());
+    make_mcp_structured_success(id, text, json!({ "messages": messages }))
 }
 
 /// Handle an `atm_broadcast` tool call.
@@ -437,7 +647,8 @@ pub fn handle_atm_read(id: &Value, args: &Value, identity: &str, team: &str) ->
 ///
 /// # Returns
 ///
-/// MCP result with `"Broadcast sent to N members of <team>"` on success.
+/// MCP result with `"Broadcast sent to N members of <team>"` on success;
+/// `structuredContent` is `{team, sent, recipients, failed}`.
 pub fn handle_atm_broadcast(id: &Value, args: &Value, identity: &str, team: &str) -> Value {
     let raw_message = match args.get("message").and_then(|v| v.as_str()) {
         Some(s) => s,
@@ -506,26 +717,44 @@ pub fn handle_atm_broadcast(id: &Value, args: &Value, identity: &str, team: &str
         .filter(|name| name != identity)
         .collect();
 
+    let id_scheme = configured_id_scheme();
     let mut sent_count = 0usize;
+    let mut failed: Vec<String> = Vec::new();
     for recipient in &recipients {
-        let msg = build_message(identity, message_text.clone(), summary.clone());
         let path = inbox_path(&home, &effective_team, recipient);
 
         if let Some(parent) = path.parent() {
             let _ = std::fs::create_dir_all(parent);
         }
 
-        match inbox_append(&path, &msg, &effective_team, recipient) {
+        let delivered = new_message_id(id_scheme, &path).and_then(|message_id| {
+            let msg = build_message(
+                identity,
+                message_text.clone(),
+                summary.clone(),
+                message_id,
+                None,
+            );
+            inbox_append(&path, &msg, &effective_team, recipient)
+        });
+        match delivered {
             Ok(_) => sent_count += 1,
             Err(e) => {
                 tracing::warn!("atm_broadcast: failed to deliver to '{recipient}': {e}");
+                failed.push(recipient.clone());
             }
         }
     }
 
-    make_mcp_success(
+    make_mcp_structured_success(
         id,
         format!("Broadcast sent to {sent_count} members of {effective_team}"),
+        json!({
+            "team": effective_team,
+            "sent": sent_count,
+            "recipients": recipients,
+            "failed": failed,
+        }),
     )
 }
 
@@ -536,7 +765,8 @@ pub fn handle_atm_broadcast(id: &Value, args: &Value, identity: &str, team: &str
 ///
 /// # Returns
 ///
-/// MCP result whose text is `{"unread": N}`.
+/// MCP result whose text is `{"unread": N}`; `structuredContent` carries the
+/// same object.
 pub fn handle_atm_pending_count(id: &Value, _args: &Value, identity: &str, team: &str) -> Value {
     let home = match get_home_dir() {
         Ok(h) => h,
@@ -551,7 +781,7 @@ pub fn handle_atm_pending_count(id: &Value, _args: &Value, identity: &str, team:
     let path = inbox_path(&home, team, identity);
 
     if !path.exists() {
-        return make_mcp_success(id, r#"{"unread":0}"#.to_string());
+        return make_pending_count_result(id, 0);
     }
 
     let content = match std::fs::read(&path) {
@@ -575,7 +805,13 @@ pub fn handle_atm_pending_count(id: &Value, _args: &Value, identity: &str, team:
     };
 
     let unread = messages.iter().filter(|m| !m.read).count();
-    make_mcp_success(id, format!(r#"{{"unread":{unread}}}"#))
+    make_pending_count_result(id, unread)
+}
+
+/// Build the `atm_pending_count` result; text and structured content match.
+fn make_pending_count_result(id: &Value, unread: usize) -> Value {
+    let structured = json!({ "unread": unread });
+    make_mcp_structured_success(id, structured.to_string(), structured)
 }
 
 // ---------------------------------------------------------------------------
@@ -584,8 +820,9 @@ pub fn handle_atm_pending_count(id: &Value, _args: &Value, identity: &str, team:
 
 /// Handle an `agent_sessions` tool call (FR-10.1).
 ///
-/// Returns a JSON array of all sessions currently tracked by the registry,
-/// regardless of status. Each element includes `agent_id`, `backend`,
+/// Returns a JSON array of the sessions tracked by the registry. Closed
+/// sessions (including those expired past `stale_session_ttl_secs`) are only
+/// listed when `include_closed` is `true`. Each element includes `agent_id`, `backend`,
 /// `backend_id` (Codex threadId), `team`, `identity`, `agent_name`,
 /// `agent_source`, `tag`, `status`, `last_active`, and `resumable`.
 ///
@@ -597,12 +834,22 @@ pub fn handle_atm_pending_count(id: &Value, _args: &Value, identity: &str, team:
 ///
 /// # Returns
 ///
-/// MCP result whose text is a pretty-printed JSON array of session objects.
-pub async fn handle_agent_sessions(id: &Value, registry: Arc<Mutex<SessionRegistry>>) -> Value {
+/// MCP result whose text is a pretty-printed JSON array of session objects;
+/// `structuredContent` is `{"sessions": [...]}` with the same objects.
+pub async fn handle_agent_sessions(
+    id: &Value,
+    args: &Value,
+    registry: Arc<Mutex<SessionRegistry>>,
+) -> Value {
+    let include_closed = args
+        .get("include_closed")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
     let guard = registry.lock().await;
     let sessions: Vec<Value> = guard
         .list_all()
         .iter()
+        .filter(|e| include_closed || e.status != SessionStatus::Closed)
         .map(|e| {
             let status_str = match e.status {
                 SessionStatus::Active => "active",
@@ -640,7 +887,7 @@ pub async fn handle_agent_sessions(id: &Value, registry: Arc<Mutex<SessionRegist
         .collect();
 
     let text = serde_json::to_string_pretty(&sessions).unwrap_or_else(|_| "[]".to_string());
-    make_mcp_success(id, text)
+    make_mcp_structured_success(id, text, json!({ "sessions": sessions }))
 }
 
 /// Count the number of unread messages in an agent's inbox.
@@ -664,12 +911,24 @@ pub fn count_unread_for_identity(identity: &str, team: &str, home: &std::path::P
     messages.iter().filter(|m| !m.read).count() as u64
 }
 
+/// Codex child liveness and restart counters reported by `agent_status`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChildStatus {
+    /// Whether a Codex child process is currently attached.
+    pub alive: bool,
+    /// Automatic respawns that produced a running child.
+    pub restarts_succeeded: u64,
+    /// Automatic respawn attempts that failed to spawn a child.
+    pub restarts_failed: u64,
+}
+
 /// Handle an `agent_status` tool call (FR-10.2).
 ///
 /// Returns a JSON object summarising the proxy's runtime status: whether a
-/// Codex child process is alive, the ATM team name, startup timestamp, uptime
-/// in seconds, active thread count, aggregate unread mail count across all
-/// active sessions, and the current identity→threadId map for active sessions.
+/// Codex child process is alive and how often it was automatically restarted,
+/// the ATM team name, startup timestamp, uptime in seconds, active thread
+/// count, aggregate unread mail count across all active sessions, and the
+/// current identity→threadId map for active sessions.
 ///
 /// # Parameters
 ///
@@ -679,11 +938,12 @@ pub fn count_unread_for_identity(identity: &str, team: &str, home: &std::path::P
 ///
 /// # Returns
 ///
-/// MCP result whose text is a pretty-printed JSON status object.
+/// MCP result whose text is a pretty-printed JSON status object;
+/// `structuredContent` carries the same object.
 pub async fn handle_agent_status(
     id: &Value,
     registry: Arc<Mutex<SessionRegistry>>,
-    child_alive: bool,
+    child: ChildStatus,
     team: &str,
     started_at: &str,
     uptime_secs: u64,
@@ -714,7 +974,9 @@ pub async fn handle_agent_status(
         .collect();
 
     let status = json!({
-        "child_alive": child_alive,
+        "child_alive": child.alive,
+        "child_restarts_succeeded": child.restarts_succeeded,
+        "child_restarts_failed": child.restarts_failed,
         "team": team,
         "started_at": started_at,
         "uptime_secs": uptime_secs,
@@ -725,8 +987,161 @@ pub async fn handle_agent_status(
         "identity_map": identity_map,
     });
 
-    let text = serde_json::to_string_pretty(&status).unwrap_or_default();
-    make_mcp_success(id, text)
+    make_mcp_json_success(id, status)
+}
+
+/// Occupancy snapshot of one bounded proxy channel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChannelSnapshot {
+    /// Configured capacity.
+    pub capacity: usize,
+    /// Deepest queue observed.
+    pub high_water: usize,
+    /// Times the channel filled to capacity.
+    pub saturations: u64,
+}
+
+impl ChannelSnapshot {
+    /// Capture the current values of `metrics`.
+    pub fn of(metrics: &crate::channel_metrics::ChannelMetrics) -> Self {
+        Self {
+            capacity: metrics.capacity(),
+            high_water: metrics.high_water(),
+            saturations: metrics.saturation_count(),
+        }
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "capacity": self.capacity,
+            "high_water": self.high_water,
+            "saturations": self.saturations,
+        })
+    }
+}
+
+/// Internal proxy counters reported by `agent_metrics`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProxyMetrics {
+    /// Events dropped because a downstream channel was full or closed.
+    pub dropped_events: u64,
+    /// Upstream requests still awaiting a child response.
+    pub pending_requests: u64,
+    /// Auto-mail turns dispatched to the child.
+    pub auto_mail_turns: u64,
+    /// Elicitations that expired without an upstream answer.
+    pub elicitation_timeouts: u64,
+    /// Elicitations still awaiting an upstream answer.
+    pub pending_elicitations: u64,
+    /// Upstream (proxy → client) write channel.
+    pub upstream_channel: ChannelSnapshot,
+    /// Child output channel.
+    pub child_channel: ChannelSnapshot,
+}
+
+/// Handle an `agent_metrics` tool call.
+///
+/// Read-only snapshot of the proxy's internal counters plus active and stale
+/// session counts from the registry, for polling proxy health without
+/// scraping logs. Requires no identity.
+pub async fn handle_agent_metrics(
+    id: &Value,
+    registry: Arc<Mutex<SessionRegistry>>,
+    metrics: ProxyMetrics,
+) -> Value {
+    let (active_sessions, stale_sessions) = {
+        let guard = registry.lock().await;
+        let entries = guard.list_all();
+        let count = |status: SessionStatus| entries.iter().filter(|e| e.status == status).count();
+        (count(SessionStatus::Active), count(SessionStatus::Stale))
+    };
+
+    let body = json!({
+        "dropped_events": metrics.dropped_events,
+        "pending_requests": metrics.pending_requests,
+        "active_sessions": active_sessions,
+        "stale_sessions": stale_sessions,
+        "auto_mail_turns": metrics.auto_mail_turns,
+        "elicitation_timeouts": metrics.elicitation_timeouts,
+        "pending_elicitations": metrics.pending_elicitations,
+        "upstream_channel": metrics.upstream_channel.to_json(),
+        "child_channel": metrics.child_channel.to_json(),
+    });
+
+    make_mcp_json_success(id, body)
+}
+
+/// Outcome of an `agent_interrupt` tool call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptStatus {
+    /// The in-flight turn was cancelled and the thread reset to `Idle`.
+    Cancelled,
+    /// The session exists but has no turn in flight.
+    NotBusy,
+    /// No session matches the requested `agent_id` / `identity`.
+    NotFound,
+}
+
+impl InterruptStatus {
+    /// Wire representation used in tool results and the audit log.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cancelled => "cancelled",
+            Self::NotBusy => "not_busy",
+            Self::NotFound => "not_found",
+        }
+    }
+}
+
+/// Resolve the session targeted by an `agent_interrupt` call.
+///
+/// Looks up `agent_id` directly, or `identity` via the active identity map
+/// with a fallback scan of all sessions.  Returns `Ok(None)` when no session
+/// matches, and `Err(response)` when neither argument was supplied.
+pub async fn resolve_interrupt_target(
+    id: &Value,
+    args: &Value,
+    registry: &Arc<Mutex<SessionRegistry>>,
+) -> Result<Option<SessionEntry>, Value> {
+    let agent_id = args.get("agent_id").and_then(|v| v.as_str());
+    let identity = args.get("identity").and_then(|v| v.as_str());
+    if agent_id.is_none() && identity.is_none() {
+        return Err(make_mcp_error_result(
+            id,
+            "agent_interrupt: one of 'agent_id' or 'identity' is required",
+        ));
+    }
+
+    let guard = registry.lock().await;
+    let entry = if let Some(aid) = agent_id {
+        guard.get(aid)
+    } else {
+        let ident = identity.unwrap_or_default();
+        match guard.find_by_identity(ident) {
+            Some(aid) => guard.get(aid),
+            None => guard.list_all().into_iter().find(|e| e.identity == ident),
+        }
+    };
+    Ok(entry.cloned())
+}
+
+/// Build the MCP result for an `agent_interrupt` call.
+///
+/// ```json
+/// {"agent_id": "...", "status": "cancelled"|"not_busy"|"not_found", "request_id": ...}
+/// ```
+pub fn make_interrupt_result(
+    id: &Value,
+    agent_id: Option<&str>,
+    status: InterruptStatus,
+    request_id: Option<&Value>,
+) -> Value {
+    let result = json!({
+        "agent_id": agent_id,
+        "status": status.as_str(),
+        "request_id": request_id,
+    });
+    make_mcp_json_success(id, result)
 }
 
 /// Handle an `agent_close` tool call (FR-17).
@@ -842,10 +1257,7 @@ pub async fn handle_agent_close(
             "agent_id": resolved_agent_id,
             "status": "already_closed"
         });
-        return make_mcp_success(
-            id,
-            serde_json::to_string_pretty(&result).unwrap_or_default(),
-        );
+        return make_mcp_json_success(id, result);
     }
 
     // Determine close status based on current thread state
@@ -895,10 +1307,7 @@ pub async fn handle_agent_close(
         "agent_id": resolved_agent_id,
         "status": close_status
     });
-    make_mcp_success(
-        id,
-        serde_json::to_string_pretty(&result).unwrap_or_default(),
-    )
+    make_mcp_json_success(id, result)
 }
 
 // ---------------------------------------------------------------------------
@@ -979,14 +1388,10 @@ mod tests {
     /// Build a minimal test InboxMessage.
     fn make_msg(from: &str, text: &str, read: bool, msg_id: Option<&str>) -> InboxMessage {
         InboxMessage {
-            from: from.to_string(),
-            source_team: None,
-            text: text.to_string(),
-            timestamp: "2026-02-18T10:00:00Z".to_string(),
-            read,
-            summary: None,
             message_id: msg_id.map(|s| s.to_string()),
-            unknown_fields: HashMap::new(),
+            ..InboxMessage::new(from, text)
+                .timestamp("2026-02-18T10:00:00Z")
+                .read(read)
         }
     }
 
@@ -1058,6 +1463,30 @@ mod tests {
         assert!(err.contains("empty agent name"));
     }
 
+    #[test]
+    fn test_cross_team_target_detects_foreign_team() {
+        let send = json!({"to": "arch-ctm@other", "message": "hi"});
+        assert_eq!(
+            cross_team_target("atm_send", &send, "atm-dev"),
+            Some("other".to_string())
+        );
+        let same = json!({"to": "arch-ctm@atm-dev", "message": "hi"});
+        assert_eq!(cross_team_target("atm_send", &same, "atm-dev"), None);
+        let bare = json!({"to": "arch-ctm", "message": "hi"});
+        assert_eq!(cross_team_target("atm_send", &bare, "atm-dev"), None);
+
+        let broadcast = json!({"message": "hi", "team": "other"});
+        assert_eq!(
+            cross_team_target("atm_broadcast", &broadcast, "atm-dev"),
+            Some("other".to_string())
+        );
+        assert_eq!(
+            cross_team_target("atm_read", &broadcast, "atm-dev"),
+            None,
+            "atm_read ignores a team argument"
+        );
+    }
+
     // -----------------------------------------------------------------------
     // Truncation tests
     // -----------------------------------------------------------------------
@@ -1155,6 +1584,11 @@ mod tests {
         assert_eq!(msgs[0].text, "Hello from test");
         assert!(!msgs[0].read);
         assert!(msgs[0].message_id.is_some());
+
+        let structured = &resp["result"]["structuredContent"];
+        assert_eq!(structured["agent"], "arch-ctm");
+        assert_eq!(structured["team"], "atm-dev");
+        assert_eq!(structured["message_id"], json!(msgs[0].message_id));
     }
 
     #[test]
@@ -1193,6 +1627,34 @@ mod tests {
         assert!(msgs[0].text.ends_with(TRUNCATION_SUFFIX));
     }
 
+    #[test]
+    #[serial]
+    fn test_atm_send_records_priority() {
+        let dir = TempDir::new().unwrap();
+        set_atm_home(&dir);
+
+        let id = json!(3);
+        let args = json!({"to": "agent-a", "message": "urgent", "priority": 9});
+        let resp = handle_atm_send(&id, &args, "sender", "team");
+
+        unset_atm_home();
+
+        assert_eq!(resp["result"]["isError"], Value::Null);
+        let msgs = read_inbox(dir.path(), "team", "agent-a");
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(msgs[0].priority, Some(9));
+    }
+
+    #[test]
+    fn test_atm_send_rejects_out_of_range_priority() {
+        let id = json!(3);
+        let args = json!({"to": "agent-a", "message": "hi", "priority": 256});
+        let resp = handle_atm_send(&id, &args, "sender", "team");
+        assert_eq!(resp["result"]["isError"], json!(true));
+        let text = resp["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("'priority'"));
+    }
+
     #[test]
     fn test_atm_send_missing_to_returns_error() {
         let id = json!(4);
@@ -1219,6 +1681,186 @@ mod tests {
         assert!(text.contains("empty agent name"));
     }
 
+    // -----------------------------------------------------------------------
+    // atm_reply tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    #[serial]
+    fn test_atm_reply_routes_to_latest_unread_sender() {
+        let dir = TempDir::new().unwrap();
+        set_atm_home(&dir);
+        let mut idle = make_msg("worker", "idle", false, Some("id-idle"));
+        idle.mark_idle_notification("worker");
+        seed_inbox(
+            dir.path(),
+            "atm-dev",
+            "arch-ctm",
+            &[
+                make_msg("alice", "first", false, Some("id-1")),
+                make_msg("bob", "second", false, Some("id-2")),
+                make_msg("carol", "already read", true, Some("id-3")),
+                idle,
+            ],
+        );
+
+        let args = json!({"message": "on it"});
+        let resp = handle_atm_reply(&json!(7), &args, "arch-ctm", "atm-dev", false);
+
+        unset_atm_home();
+
+        assert_eq!(resp["result"]["isError"], Value::Null, "{resp}");
+        assert_eq!(resp["result"]["structuredContent"]["agent"], "bob");
+        assert_eq!(resp["result"]["structuredContent"]["in_reply_to"], "id-2");
+        let msgs = read_inbox(dir.path(), "atm-dev", "bob");
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(msgs[0].from, "arch-ctm");
+        assert_eq!(msgs[0].text, "on it");
+        assert_eq!(msgs[0].unknown_fields.get("replyTo"), Some(&json!("id-2")));
+    }
+
+    #[test]
+    #[serial]
+    fn test_atm_reply_falls_back_to_latest_message_and_honours_to() {
+        let dir = TempDir::new().unwrap();
+        set_atm_home(&dir);
+        seed_inbox(
+            dir.path(),
+            "atm-dev",
+            "arch-ctm",
+            &[
+                make_msg("alice", "first", true, Some("id-1")),
+                make_msg("bob", "second", true, Some("id-2")),
+            ],
+        );
+
+        let implicit = handle_atm_reply(
+            &json!(8),
+            &json!({"message": "a"}),
+            "arch-ctm",
+            "atm-dev",
+            false,
+        );
+        let explicit = handle_atm_reply(
+            &json!(9),
+            &json!({"message": "b", "to": "alice"}),
+            "arch-ctm",
+            "atm-dev",
+            false,
+        );
+
+        unset_atm_home();
+
+        assert_eq!(implicit["result"]["structuredContent"]["agent"], "bob");
+        assert_eq!(explicit["result"]["structuredContent"]["agent"], "alice");
+        assert_eq!(
+            explicit["result"]["structuredContent"]["in_reply_to"],
+            Value::Null
+        );
+        assert!(
+            !read_inbox(dir.path(), "atm-dev", "alice")[0]
+                .unknown_fields
+                .contains_key("replyTo")
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_atm_reply_reads_bridged_origin_inbox() {
+        let dir = TempDir::new().unwrap();
+        set_atm_home(&dir);
+        seed_inbox(
+            dir.path(),
+            "atm-dev",
+            "arch-ctm",
+            &[make_msg("bob", "local", true, Some("id-1"))],
+        );
+        // A message bridged in from another host lives in its origin file.
+        seed_inbox(
+            dir.path(),
+            "atm-dev",
+            "arch-ctm.laptop",
+            &[make_msg("carol", "bridged", false, Some("id-2"))],
+        );
+        let config_dir = dir.path().join(".config/atm");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[plugins.bridge]\nenabled = true\n\n[[plugins.bridge.remotes]]\n\
+             hostname = \"laptop\"\naddress = \"user@laptop\"\n",
+        )
+        .unwrap();
+
+        let resp = handle_atm_reply(
+            &json!(11),
+            &json!({"message": "got it"}),
+            "arch-ctm",
+            "atm-dev",
+            false,
+        );
+
+        unset_atm_home();
+
+        assert_eq!(
+            resp["result"]["structuredContent"]["agent"], "carol",
+            "{resp}"
+        );
+        assert_eq!(resp["result"]["structuredContent"]["in_reply_to"], "id-2");
+    }
+
+    #[test]
+    #[serial]
+    fn test_atm_reply_empty_inbox_is_error() {
+        let dir = TempDir::new().unwrap();
+        set_atm_home(&dir);
+
+        let resp = handle_atm_reply(
+            &json!(10),
+            &json!({"message": "hi"}),
+            "arch-ctm",
+            "atm-dev",
+            false,
+        );
+
+        unset_atm_home();
+
+        assert_eq!(resp["result"]["isError"], json!(true));
+        let text = resp["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("nobody to reply to"), "{text}");
+        assert!(!dir.path().join(".claude/teams/atm-dev/inboxes").exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_atm_reply_cross_team_sender_requires_allow_cross_team() {
+        let dir = TempDir::new().unwrap();
+        set_atm_home(&dir);
+        let mut msg = make_msg("lead", "from afar", false, Some("id-x"));
+        msg.source_team = Some("other-team".to_string());
+        seed_inbox(dir.path(), "atm-dev", "arch-ctm", &[msg]);
+
+        let denied = handle_atm_reply(
+            &json!(11),
+            &json!({"message": "no"}),
+            "arch-ctm",
+            "atm-dev",
+            false,
+        );
+        let allowed = handle_atm_reply(
+            &json!(12),
+            &json!({"message": "yes"}),
+            "arch-ctm",
+            "atm-dev",
+            true,
+        );
+
+        unset_atm_home();
+
+        assert_eq!(denied["result"]["isError"], json!(true));
+        assert_eq!(allowed["result"]["structuredContent"]["team"], "other-team");
+        assert_eq!(read_inbox(dir.path(), "other-team", "lead").len(), 1);
+    }
+
     // -----------------------------------------------------------------------
     // atm_read tests
     // -----------------------------------------------------------------------
@@ -1241,6 +1883,7 @@ mod tests {
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         let msgs: Vec<Value> = serde_json::from_str(text).unwrap();
         assert!(msgs.is_empty());
+        assert_eq!(resp["result"]["structuredContent"], json!({"messages": []}));
     }
 
     #[test]
@@ -1299,6 +1942,11 @@ mod tests {
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         let msgs: Vec<Value> = serde_json::from_str(text).unwrap();
         assert_eq!(msgs.len(), 2);
+        assert_eq!(
+            resp["result"]["structuredContent"]["messages"],
+            json!(msgs),
+            "structuredContent must match the text representation"
+        );
     }
 
     #[test]
@@ -1424,36 +2072,15 @@ mod tests {
         set_atm_home(&dir);
 
         // Seed 3 messages with distinct timestamps
-        let old_msg = InboxMessage {
-            from: "sender".to_string(),
-            source_team: None,
-            text: "old message".to_string(),
-            timestamp: "2026-01-01T00:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("id-old".to_string()),
-            unknown_fields: HashMap::new(),
-        };
-        let middle_msg = InboxMessage {
-            from: "sender".to_string(),
-            source_team: None,
-            text: "middle message".to_string(),
-            timestamp: "2026-02-01T00:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("id-middle".to_string()),
-            unknown_fields: HashMap::new(),
-        };
-        let future_msg = InboxMessage {
-            from: "sender".to_string(),
-            source_team: None,
-            text: "future message".to_string(),
-            timestamp: "2026-03-01T00:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("id-future".to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let old_msg = InboxMessage::new("sender", "old message")
+            .timestamp("2026-01-01T00:00:00Z")
+            .message_id("id-old");
+        let middle_msg = InboxMessage::new("sender", "middle message")
+            .timestamp("2026-02-01T00:00:00Z")
+            .message_id("id-middle");
+        let future_msg = InboxMessage::new("sender", "future message")
+            .timestamp("2026-03-01T00:00:00Z")
+            .message_id("id-future");
         seed_inbox(
             dir.path(),
             "team",
@@ -1546,6 +2173,7 @@ mod tests {
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         let v: Value = serde_json::from_str(text).unwrap();
         assert_eq!(v["unread"], json!(2));
+        assert_eq!(resp["result"]["structuredContent"], v);
     }
 
     #[test]
@@ -1607,6 +2235,12 @@ mod tests {
             text.contains("2 members"),
             "should send to 2 members (excluding self)"
         );
+
+        let structured = &resp["result"]["structuredContent"];
+        assert_eq!(structured["team"], "atm-dev");
+        assert_eq!(structured["sent"], json!(2));
+        assert_eq!(structured["recipients"], json!(["arch-ctm", "dev-agent"]));
+        assert_eq!(structured["failed"], json!([]));
     }
 
     #[test]
@@ -1670,7 +2304,7 @@ mod tests {
     async fn test_agent_sessions_empty_registry() {
         let reg = make_test_registry(10);
         let id = json!(100);
-        let resp = handle_agent_sessions(&id, reg).await;
+        let resp = handle_agent_sessions(&id, &json!({}), reg).await;
         assert!(resp.get("error").is_none());
         assert_ne!(resp["result"]["isError"], json!(true));
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
@@ -1695,13 +2329,17 @@ mod tests {
                 .unwrap();
         }
         let id = json!(101);
-        let resp = handle_agent_sessions(&id, reg).await;
+        let resp = handle_agent_sessions(&id, &json!({}), reg).await;
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         let sessions: Vec<Value> = serde_json::from_str(text).unwrap();
         assert_eq!(sessions.len(), 1);
         assert_eq!(sessions[0]["identity"], "arch-ctm");
         assert_eq!(sessions[0]["status"], "active");
         assert_eq!(sessions[0]["resumable"], json!(false));
+        assert_eq!(
+            resp["result"]["structuredContent"],
+            json!({ "sessions": sessions })
+        );
     }
 
     #[tokio::test]
@@ -1724,7 +2362,7 @@ mod tests {
             e.agent_id.clone()
         };
         let id = json!(102);
-        let resp = handle_agent_sessions(&id, Arc::clone(&reg)).await;
+        let resp = handle_agent_sessions(&id, &json!({}), Arc::clone(&reg)).await;
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         let sessions: Vec<Value> = serde_json::from_str(text).unwrap();
         let session = sessions.iter().find(|s| s["agent_id"] == agent_id).unwrap();
@@ -1750,7 +2388,7 @@ mod tests {
             guard.mark_all_stale();
         }
         let id = json!(103);
-        let resp = handle_agent_sessions(&id, reg).await;
+        let resp = handle_agent_sessions(&id, &json!({}), reg).await;
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         let sessions: Vec<Value> = serde_json::from_str(text).unwrap();
         assert_eq!(sessions.len(), 1);
@@ -1823,7 +2461,13 @@ mod tests {
             guard.close(&closed.agent_id);
         }
         let id = json!(104);
-        let resp = handle_agent_sessions(&id, reg2).await;
+        let resp = handle_agent_sessions(&id, &json!({}), Arc::clone(&reg2)).await;
+        let text = resp["result"]["content"][0]["text"].as_str().unwrap();
+        let sessions: Vec<Value> = serde_json::from_str(text).unwrap();
+        assert_eq!(sessions.len(), 1, "closed sessions are hidden by default");
+        assert_eq!(sessions[0]["status"], json!("active"));
+
+        let resp = handle_agent_sessions(&id, &json!({"include_closed": true}), reg2).await;
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         let sessions: Vec<Value> = serde_json::from_str(text).unwrap();
         assert_eq!(sessions.len(), 2);
@@ -1843,12 +2487,22 @@ mod tests {
     async fn test_agent_status_no_sessions() {
         let reg = make_test_registry(10);
         let id = json!(200);
-        let resp =
-            handle_agent_status(&id, reg, false, "atm-dev", "2026-02-18T00:00:00Z", 42, 0).await;
+        let resp = handle_agent_status(
+            &id,
+            reg,
+            ChildStatus::default(),
+            "atm-dev",
+            "2026-02-18T00:00:00Z",
+            42,
+            0,
+        )
+        .await;
         assert!(resp.get("error").is_none());
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         let status: Value = serde_json::from_str(text).unwrap();
         assert_eq!(status["child_alive"], json!(false));
+        assert_eq!(status["child_restarts_succeeded"], json!(0));
+        assert_eq!(status["child_restarts_failed"], json!(0));
         assert_eq!(status["team"], "atm-dev");
         assert_eq!(status["started_at"], "2026-02-18T00:00:00Z");
         assert_eq!(status["uptime_secs"], json!(42));
@@ -1879,7 +2533,11 @@ mod tests {
         let resp = handle_agent_status(
             &id,
             Arc::clone(&reg),
-            true,
+            ChildStatus {
+                alive: true,
+                restarts_succeeded: 2,
+                restarts_failed: 1,
+            },
             "atm-dev",
             "2026-02-18T12:00:00Z",
             3600,
@@ -1889,12 +2547,15 @@ mod tests {
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         let status: Value = serde_json::from_str(text).unwrap();
         assert_eq!(status["child_alive"], json!(true));
+        assert_eq!(status["child_restarts_succeeded"], json!(2));
+        assert_eq!(status["child_restarts_failed"], json!(1));
         assert_eq!(status["active_thread_count"], json!(1));
         let map = status["identity_map"].as_object().unwrap();
         assert_eq!(
             map.get("arch-ctm").and_then(|v| v.as_str()),
             Some("thread-abc")
         );
+        assert_eq!(resp["result"]["structuredContent"], status);
         let _ = agent_id;
     }
 
@@ -1916,13 +2577,129 @@ mod tests {
             guard.mark_all_stale();
         }
         let id = json!(202);
-        let resp = handle_agent_status(&id, reg, false, "team", "2026-02-18T00:00:00Z", 0, 0).await;
+        let resp = handle_agent_status(
+            &id,
+            reg,
+            ChildStatus::default(),
+            "team",
+            "2026-02-18T00:00:00Z",
+            0,
+            0,
+        )
+        .await;
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         let status: Value = serde_json::from_str(text).unwrap();
         assert_eq!(status["active_thread_count"], json!(0));
         assert!(status["identity_map"].as_object().unwrap().is_empty());
     }
 
+    // -----------------------------------------------------------------------
+    // handle_agent_metrics tests
+    // -----------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_agent_metrics_reports_counters_and_session_counts() {
+        let reg = make_test_registry(10);
+        {
+            let mut guard = reg.lock().await;
+            guard
+                .register(
+                    "stale-agent".to_string(),
+                    "team".to_string(),
+                    ".".to_string(),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+            guard.mark_all_stale();
+            guard
+                .register(
+                    "live-agent".to_string(),
+                    "team".to_string(),
+                    ".".to_string(),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+        }
+        let metrics = ProxyMetrics {
+            dropped_events: 7,
+            pending_requests: 2,
+            auto_mail_turns: 3,
+            elicitation_timeouts: 1,
+            pending_elicitations: 4,
+            upstream_channel: ChannelSnapshot {
+                capacity: 256,
+                high_water: 12,
+                saturations: 0,
+            },
+            child_channel: ChannelSnapshot {
+                capacity: 4,
+                high_water: 4,
+                saturations: 2,
+            },
+        };
+        let resp = handle_agent_metrics(&json!(210), reg, metrics).await;
+        let text = resp["result"]["content"][0]["text"].as_str().unwrap();
+        let body: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(body["dropped_events"], json!(7));
+        assert_eq!(body["pending_requests"], json!(2));
+        assert_eq!(body["active_sessions"], json!(1));
+        assert_eq!(body["stale_sessions"], json!(1));
+        assert_eq!(body["auto_mail_turns"], json!(3));
+        assert_eq!(body["elicitation_timeouts"], json!(1));
+        assert_eq!(body["pending_elicitations"], json!(4));
+        assert_eq!(body["upstream_channel"]["high_water"], json!(12));
+        assert_eq!(body["child_channel"]["saturations"], json!(2));
+    }
+
+    // -----------------------------------------------------------------------
+    // resolve_interrupt_target tests
+    // -----------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_resolve_interrupt_target_by_identity_and_agent_id() {
+        let reg = make_test_registry(10);
+        let agent_id = {
+            let mut guard = reg.lock().await;
+            guard
+                .register(
+                    "interrupt-me".to_string(),
+                    "team".to_string(),
+                    ".".to_string(),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap()
+                .agent_id
+        };
+        let id = json!(1);
+
+        let by_identity = resolve_interrupt_target(&id, &json!({"identity": "interrupt-me"}), &reg)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(by_identity.agent_id, agent_id);
+
+        let by_id = resolve_interrupt_target(&id, &json!({"agent_id": agent_id}), &reg)
+            .await
+            .unwrap();
+        assert!(by_id.is_some());
+
+        let missing = resolve_interrupt_target(&id, &json!({"identity": "ghost"}), &reg)
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+
+        let err = resolve_interrupt_target(&id, &json!({}), &reg)
+            .await
+            .unwrap_err();
+        assert_eq!(err["result"]["isError"], json!(true));
+    }
+
     // -----------------------------------------------------------------------
     // handle_agent_close tests (FR-17, FR-18.5)
     // -----------------------------------------------------------------------
@@ -12,10 +12,14 @@
 //! - **Append-only**: The file is opened in append mode for every write to
 //!   tolerate concurrent proxy instances (though rare in practice).
 //! - **Structured**: Each line is valid JSON matching [`AuditEntry`].
+//! - **Bounded**: With [`AuditLog::with_rotation`], a file that would grow past
+//!   the size limit is renamed to `audit.<timestamp>.jsonl` and a fresh file is
+//!   started; only the newest rotated files are kept (FR-9.4).
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use serde::Serialize;
+use tokio::sync::Mutex;
 
 /// Maximum number of characters kept from a prompt for audit logging (FR-9.2).
 const PROMPT_SUMMARY_MAX: usize = 200;
@@ -55,6 +59,13 @@ pub struct AuditEntry {
 #[derive(Debug)]
 pub struct AuditLog {
     path: PathBuf,
+    /// Rotate once the file would grow past this many bytes (`0` = never).
+    max_bytes: u64,
+    /// Rotated files kept; older ones are deleted.
+    max_files: usize,
+    /// Cached size of the current file, loaded on first append. The lock also
+    /// serializes appends so rotation never races a write.
+    size: Mutex<Option<u64>>,
 }
 
 impl AuditLog {
@@ -63,12 +74,28 @@ impl AuditLog {
     /// The log file path is resolved via [`crate::lock::sessions_dir()`].
     pub fn new(_team: &str) -> Self {
         let path = crate::lock::sessions_dir().join(_team).join("audit.jsonl");
-        Self { path }
+        Self::new_with_path(path)
     }
 
     /// Create an audit log with an explicit path (for testing).
     pub fn new_with_path(path: PathBuf) -> Self {
-        Self { path }
+        Self {
+            path,
+            max_bytes: 0,
+            max_files: 0,
+            size: Mutex::new(None),
+        }
+    }
+
+    /// Enable size-based rotation (FR-9.4).
+    ///
+    /// Before an append would take the file past `max_bytes`, it is renamed to
+    /// `audit.<timestamp>.jsonl` and a fresh file is started. At most
+    /// `max_files` rotated files are kept. `max_bytes == 0` disables rotation.
+    pub fn with_rotation(mut self, max_bytes: u64, max_files: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self.max_files = max_files;
+        self
     }
 
     /// Log an ATM tool call (FR-9.1).
@@ -117,6 +144,27 @@ impl AuditLog {
         self.append(&entry).await;
     }
 
+    /// Log an `agent_interrupt` request and its outcome.
+    ///
+    /// `status` is one of `"cancelled"`, `"not_busy"`, `"not_found"`.
+    pub async fn log_agent_interrupt(
+        &self,
+        agent_id: Option<&str>,
+        identity: Option<&str>,
+        status: &str,
+    ) {
+        let entry = AuditEntry {
+            timestamp: now_iso8601(),
+            event_type: "agent_interrupt".to_string(),
+            agent_id: agent_id.map(String::from),
+            identity: identity.map(String::from),
+            recipient: None,
+            message_summary: Some(status.to_string()),
+            prompt_summary: None,
+        };
+        self.append(&entry).await;
+    }
+
     /// Append a serialized entry to the audit file.
     ///
     /// Creates parent directories if needed. Swallows all errors.
@@ -136,6 +184,23 @@ impl AuditLog {
         let mut line = serde_json::to_string(entry).map_err(std::io::Error::other)?;
         line.push('\n');
 
+        let mut size = self.size.lock().await;
+        let current = match *size {
+            Some(current) => current,
+            None => match tokio::fs::metadata(&self.path).await {
+                Ok(meta) => meta.len(),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+                Err(e) => return Err(e),
+            },
+        };
+        let current =
+            if self.max_bytes > 0 && current > 0 && current + line.len() as u64 > self.max_bytes {
+                self.rotate().await?;
+                0
+            } else {
+                current
+            };
+
         let mut file = tokio::fs::OpenOptions::new()
             .create(true)
             .append(true)
@@ -143,10 +208,72 @@ impl AuditLog {
             .await?;
         file.write_all(line.as_bytes()).await?;
         file.flush().await?;
+        *size = Some(current + line.len() as u64);
+        Ok(())
+    }
+
+    /// Rotated files next to the current log, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the log directory cannot be read.
+    pub async fn rotated_paths(&self) -> std::io::Result<Vec<PathBuf>> {
+        let Some(dir) = self.path.parent() else {
+            return Ok(Vec::new());
+        };
+        let (stem, ext) = file_stem_and_ext(&self.path);
+        let prefix = format!("{stem}.");
+        let suffix = format!(".{ext}");
+        let current_name = format!("{stem}.{ext}");
+        let mut rotated = Vec::new();
+        let mut entries = match tokio::fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name != current_name && name.starts_with(&prefix) && name.ends_with(&suffix) {
+                rotated.push(entry.path());
+            }
+        }
+        // Timestamps sort lexically, so the oldest files come first.
+        rotated.sort();
+        Ok(rotated)
+    }
+
+    /// Rename the current file to `audit.<timestamp>.jsonl` and delete the
+    /// oldest rotated files beyond `max_files`.
+    async fn rotate(&self) -> std::io::Result<()> {
+        let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%S%.6fZ");
+        let (stem, ext) = file_stem_and_ext(&self.path);
+        let rotated = self.path.with_file_name(format!("{stem}.{stamp}.{ext}"));
+        tokio::fs::rename(&self.path, &rotated).await?;
+
+        let rotated_files = self.rotated_paths().await?;
+        let excess = rotated_files.len().saturating_sub(self.max_files);
+        for old in &rotated_files[..excess] {
+            if let Err(e) = tokio::fs::remove_file(old).await {
+                tracing::warn!(path = %old.display(), error = %e, "failed to delete rotated audit log");
+            }
+        }
         Ok(())
     }
 }
 
+/// Split an audit path into file stem and extension (`audit`, `jsonl`).
+fn file_stem_and_ext(path: &Path) -> (String, String) {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "audit".to_string());
+    let ext = path
+        .extension()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "jsonl".to_string());
+    (stem, ext)
+}
+
 /// Truncate a string to `max_chars` characters (Unicode-safe).
 fn truncate(s: &str, max_chars: usize) -> String {
     s.chars().take(max_chars).collect()
@@ -367,6 +494,37 @@ mod tests {
         log.log_atm_call("atm_send", None, None, None, None).await;
     }
 
+    #[tokio::test]
+    async fn test_audit_log_rotates_past_max_bytes_and_prunes_old_files() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let log = AuditLog::new_with_path(path.clone()).with_rotation(400, 2);
+
+        for i in 0..20 {
+            log.log_atm_call("atm_send", None, None, None, Some(&format!("message {i}")))
+                .await;
+        }
+
+        let rotated = log.rotated_paths().await.unwrap();
+        assert_eq!(rotated.len(), 2, "only max_audit_files kept: {rotated:?}");
+        for rotated_path in &rotated {
+            let name = rotated_path.file_name().unwrap().to_string_lossy();
+            assert!(name.starts_with("audit.") && name.ends_with(".jsonl"));
+            let size = std::fs::metadata(rotated_path).unwrap().len();
+            assert!(size <= 400, "{name} is {size} bytes");
+        }
+        assert!(std::fs::metadata(&path).unwrap().len() <= 400);
+
+        // Nothing is lost between the newest rotated file and the live file.
+        let newest = read_audit_lines(&rotated[1]);
+        let live = read_audit_lines(&path);
+        assert_eq!(live.last().unwrap()["message_summary"], "message 19");
+        let first_live = live[0]["message_summary"].as_str().unwrap();
+        let last_rotated = newest.last().unwrap()["message_summary"].as_str().unwrap();
+        let index = |s: &str| s.trim_start_matches("message ").parse::<u32>().unwrap();
+        assert_eq!(index(first_live), index(last_rotated) + 1);
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_audit_log_atm_read_entry() {
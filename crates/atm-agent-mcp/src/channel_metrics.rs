@@ -0,0 +1,104 @@
+//! Occupancy metrics for the proxy's bounded message channels.
+//!
+//! The proxy routes upstream writes and child output through bounded
+//! `tokio::sync::mpsc` channels. [`ChannelMetrics`] records the deepest queue
+//! seen (high-water mark) and how often a channel filled up so operators can
+//! tune `upstream_channel_capacity` / `child_channel_capacity`.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use tokio::sync::mpsc;
+
+/// High-water mark and saturation count for one bounded channel.
+#[derive(Debug)]
+pub struct ChannelMetrics {
+    name: &'static str,
+    capacity: usize,
+    high_water: AtomicUsize,
+    saturations: AtomicU64,
+    saturated: AtomicBool,
+}
+
+impl ChannelMetrics {
+    /// Create metrics for a channel named `name` with the given capacity.
+    pub fn new(name: &'static str, capacity: usize) -> Self {
+        Self {
+            name,
+            capacity,
+            high_water: AtomicUsize::new(0),
+            saturations: AtomicU64::new(0),
+            saturated: AtomicBool::new(false),
+        }
+    }
+
+    /// Configured channel capacity.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Deepest queue observed so far.
+    pub fn high_water(&self) -> usize {
+        self.high_water.load(Ordering::Relaxed)
+    }
+
+    /// Number of times the channel went from below capacity to full.
+    pub fn saturation_count(&self) -> u64 {
+        self.saturations.load(Ordering::Relaxed)
+    }
+
+    /// Record that `queued` messages are in the channel.
+    ///
+    /// A full channel counts as one saturation until the queue drains below
+    /// capacity again, so a sustained backlog logs a single warning. Returns
+    /// `true` when this observation starts a new saturation.
+    pub fn observe(&self, queued: usize) -> bool {
+        self.high_water.fetch_max(queued, Ordering::Relaxed);
+        if queued < self.capacity {
+            self.saturated.store(false, Ordering::Relaxed);
+            return false;
+        }
+        if self.saturated.swap(true, Ordering::Relaxed) {
+            return false;
+        }
+        let count = self.saturations.fetch_add(1, Ordering::Relaxed) + 1;
+        tracing::warn!(
+            channel = self.name,
+            capacity = self.capacity,
+            saturations = count,
+            "proxy channel saturated; senders are blocked until it drains"
+        );
+        true
+    }
+
+    /// Record the current depth of `tx` just before a send.
+    pub fn observe_sender<T>(&self, tx: &mpsc::Sender<T>) -> bool {
+        self.observe(tx.max_capacity() - tx.capacity())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn small_capacity_under_load_counts_saturation() {
+        let metrics = ChannelMetrics::new("child", 2);
+        let (tx, mut rx) = mpsc::channel::<u32>(metrics.capacity());
+
+        for i in 0..2 {
+            metrics.observe_sender(&tx);
+            tx.send(i).await.unwrap();
+        }
+        // Channel is now full: the next sender would block.
+        assert!(metrics.observe_sender(&tx));
+        // A sustained backlog is one saturation, not one per observation.
+        assert!(!metrics.observe_sender(&tx));
+        assert_eq!(metrics.saturation_count(), 1);
+        assert_eq!(metrics.high_water(), 2);
+
+        rx.recv().await.unwrap();
+        metrics.observe_sender(&tx);
+        tx.send(2).await.unwrap();
+        assert!(metrics.observe_sender(&tx));
+        assert_eq!(metrics.saturation_count(), 2);
+    }
+}
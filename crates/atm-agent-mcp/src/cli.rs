@@ -79,6 +79,24 @@ pub struct ServeArgs {
     /// Request timeout in seconds
     #[arg(long)]
     pub timeout: Option<u64>,
+
+    /// Automatically respawn a crashed Codex child up to N times
+    #[arg(long, value_name = "N")]
+    pub max_child_restarts: Option<u32>,
+
+    /// Replay recorded upstream frames from a file instead of reading stdin,
+    /// then exit (newline-delimited or Content-Length framed)
+    #[arg(long, value_name = "FILE")]
+    pub replay: Option<PathBuf>,
+
+    /// Write replay responses to this file instead of stdout
+    #[arg(long, value_name = "FILE", requires = "replay")]
+    pub replay_out: Option<PathBuf>,
+
+    /// Seconds to keep replay input open after the last frame so in-flight
+    /// child responses are captured
+    #[arg(long, value_name = "SECS", default_value_t = 2)]
+    pub replay_wait: u64,
 }
 
 /// Arguments for the `config` subcommand
@@ -87,6 +105,10 @@ pub struct ConfigArgs {
     /// Output as JSON
     #[arg(long)]
     pub json: bool,
+
+    /// Also list each config file consulted and whether it existed
+    #[arg(long)]
+    pub show_sources: bool,
 }
 
 /// Arguments for the `sessions` subcommand
@@ -1,7 +1,8 @@
 //! `config` subcommand — show resolved configuration.
 //!
 //! Loads the full resolved configuration and prints it either as JSON
-//! (`--json`) or as a human-readable key=value table.
+//! (`--json`) or as a human-readable key=value table. `--show-sources` adds
+//! the config files consulted during resolution.
 
 use crate::cli::ConfigArgs;
 use crate::config::{AgentMcpConfig, ResolvedConfig, RolePreset, resolve_config};
@@ -18,9 +19,24 @@ pub async fn run(config_path: &Option<PathBuf>, args: ConfigArgs) -> anyhow::Res
     let cfg: &AgentMcpConfig = &resolved.agent_mcp;
 
     if args.json {
-        let json = serde_json::to_string_pretty(cfg)?;
+        let json = if args.show_sources {
+            serde_json::to_string_pretty(&serde_json::json!({
+                "sources": resolved.sources,
+                "config": cfg,
+            }))?
+        } else {
+            serde_json::to_string_pretty(cfg)?
+        };
         println!("{json}");
     } else {
+        if args.show_sources {
+            println!("config sources (lowest priority first):");
+            for source in &resolved.sources {
+                let status = if source.exists { "found" } else { "not found" };
+                println!("  {:<8} {} ({status})", source.layer, source.path.display());
+            }
+            println!();
+        }
         println!("atm-agent-mcp configuration:");
         println!("  codex_bin              = {}", cfg.codex_bin);
         println!(
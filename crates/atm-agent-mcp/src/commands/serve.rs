@@ -3,11 +3,19 @@
 //! Reads MCP JSON-RPC messages from stdin, proxies to a lazily spawned `codex
 //! mcp-server` child process, and writes responses to stdout. See [`crate::proxy`]
 //! for the core proxy logic and [`crate::framing`] for framing details.
+//!
+//! With `--replay <file>`, recorded upstream frames are fed from the file
+//! through an in-memory pipe instead of stdin, responses go to stdout (or
+//! `--replay-out`), and the proxy exits once the replay input closes. This
+//! reproduces client-triggered bugs without the real client.
 
 use crate::cli::ServeArgs;
 use crate::config::resolve_config;
 use crate::proxy::{ProxyServer, ResumeContext};
-use std::path::PathBuf;
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::io::{AsyncWriteExt, DuplexStream};
 
 /// Run the `serve` subcommand.
 ///
@@ -21,6 +29,8 @@ use std::path::PathBuf;
 pub async fn run(config_path: &Option<PathBuf>, args: ServeArgs) -> anyhow::Result<()> {
     // Resolve configuration from file/env/defaults
     let resolved = resolve_config(config_path.as_deref())?;
+    agent_team_mail_core::config::install_inbox_file_mode(&resolved.core);
+    agent_team_mail_core::config::install_inbox_provenance(&resolved.core);
     let mut config = resolved.agent_mcp;
 
     // Apply CLI argument overrides
@@ -44,10 +54,9 @@ pub async fn run(config_path: &Option<PathBuf>, args: ServeArgs) -> anyhow::Resu
     if let Some(timeout_secs) = args.timeout {
         config.request_timeout_secs = timeout_secs;
     }
-
-    // Set up upstream I/O (stdin for reading, stdout for writing)
-    let upstream_in = tokio::io::stdin();
-    let upstream_out = tokio::io::stdout();
+    if let Some(max_restarts) = args.max_child_restarts {
+        config.max_child_restarts = max_restarts;
+    }
 
     // Use the ATM core team name for session registration and lock files.
     let team = resolved.core.default_team.clone();
@@ -63,7 +72,48 @@ pub async fn run(config_path: &Option<PathBuf>, args: ServeArgs) -> anyhow::Resu
     };
 
     let mut proxy = ProxyServer::new_with_resume(config, team, resume_context);
-    proxy.run(upstream_in, upstream_out).await
+
+    if let Some(ref replay_path) = args.replay {
+        let upstream_in = replay_reader(replay_path, Duration::from_secs(args.replay_wait)).await?;
+        return match args.replay_out {
+            Some(ref out_path) => {
+                let upstream_out = tokio::fs::File::create(out_path)
+                    .await
+                    .with_context(|| format!("failed to create {}", out_path.display()))?;
+                proxy.run(upstream_in, upstream_out).await
+            }
+            None => proxy.run(upstream_in, tokio::io::stdout()).await,
+        };
+    }
+
+    // Set up upstream I/O (stdin for reading, stdout for writing)
+    proxy.run(tokio::io::stdin(), tokio::io::stdout()).await
+}
+
+/// Buffer size of the in-memory pipe feeding replayed frames to the proxy.
+const REPLAY_PIPE_CAPACITY: usize = 64 * 1024;
+
+/// Open an in-memory upstream reader that yields the recorded frames in
+/// `path`, stays open for `linger` so in-flight child responses can still be
+/// routed, then reports EOF.
+///
+/// # Errors
+///
+/// Returns an error if the replay file cannot be read.
+async fn replay_reader(path: &Path, linger: Duration) -> anyhow::Result<DuplexStream> {
+    let frames = tokio::fs::read(path)
+        .await
+        .with_context(|| format!("failed to read replay file {}", path.display()))?;
+    let (mut feed, upstream_in) = tokio::io::duplex(REPLAY_PIPE_CAPACITY);
+    tokio::spawn(async move {
+        if let Err(e) = feed.write_all(&frames).await {
+            tracing::warn!("replay feed stopped early: {e}");
+            return;
+        }
+        tokio::time::sleep(linger).await;
+        // Dropping `feed` closes the pipe; the proxy sees EOF and shuts down.
+    });
+    Ok(upstream_in)
 }
 
 /// Load resume context from the persisted registry (FR-6.1, FR-6.2).
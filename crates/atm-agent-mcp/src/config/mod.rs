@@ -10,4 +10,6 @@ mod types;
 
 pub use resolve::{ResolvedConfig, resolve_config};
 // Re-exported for use by command modules and future library consumers.
-pub use types::{AgentMcpConfig, RolePreset};
+pub use types::{
+    AgentMcpConfig, DownstreamFraming, MAX_REGISTRY_LOCK_RETRIES, MailInjectionRole, RolePreset,
+};
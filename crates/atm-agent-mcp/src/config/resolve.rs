@@ -9,8 +9,10 @@
 //! 4. Global `~/.config/atm/config.toml` `[plugins.atm-agent-mcp]` section
 //! 5. Compiled-in defaults (via [`AgentMcpConfig::default`])
 
-use super::types::AgentMcpConfig;
-use agent_team_mail_core::config::{ConfigOverrides, CoreConfig, resolve_config as core_resolve};
+use super::types::{AgentMcpConfig, MAX_REGISTRY_LOCK_RETRIES};
+use agent_team_mail_core::config::{
+    ConfigOverrides, ConfigSource, CoreConfig, config_sources, resolve_config as core_resolve,
+};
 use agent_team_mail_core::home::get_home_dir;
 use std::path::Path;
 
@@ -21,6 +23,8 @@ pub struct ResolvedConfig {
     pub agent_mcp: AgentMcpConfig,
     /// ATM core configuration (identity, team, etc.)
     pub core: CoreConfig,
+    /// Config files consulted during resolution, lowest priority first
+    pub sources: Vec<ConfigSource>,
 }
 
 /// Resolve the complete configuration for atm-agent-mcp.
@@ -59,6 +63,7 @@ pub fn resolve_config(config_path: Option<&Path>) -> anyhow::Result<ResolvedConf
     };
 
     let core_config = core_resolve(&overrides, &search_dir, &home_dir)?;
+    let sources = config_sources(&overrides, &search_dir, &home_dir);
 
     // Extract plugin config, falling back to defaults if the section is absent.
     let mut agent_mcp = if let Some(table) = core_config.plugin_config("atm-agent-mcp") {
@@ -70,10 +75,12 @@ pub fn resolve_config(config_path: Option<&Path>) -> anyhow::Result<ResolvedConf
     };
 
     apply_env_overrides(&mut agent_mcp);
+    clamp_limits(&mut agent_mcp);
 
     Ok(ResolvedConfig {
         agent_mcp,
         core: core_config.core,
+        sources,
     })
 }
 
@@ -124,6 +131,17 @@ fn apply_env_overrides(cfg: &mut AgentMcpConfig) {
     }
 }
 
+/// Clamp settings whose out-of-range values would misbehave at runtime.
+fn clamp_limits(cfg: &mut AgentMcpConfig) {
+    if cfg.registry_lock_retries > MAX_REGISTRY_LOCK_RETRIES {
+        tracing::warn!(
+            "registry_lock_retries = {} exceeds the maximum; using {MAX_REGISTRY_LOCK_RETRIES}",
+            cfg.registry_lock_retries
+        );
+        cfg.registry_lock_retries = MAX_REGISTRY_LOCK_RETRIES;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +159,17 @@ mod tests {
         assert!(!cfg.approval_policy.is_empty());
     }
 
+    #[test]
+    fn test_clamp_limits_caps_registry_lock_retries() {
+        let mut cfg: AgentMcpConfig = toml::from_str("registry_lock_retries = 64").unwrap();
+        clamp_limits(&mut cfg);
+        assert_eq!(cfg.registry_lock_retries, MAX_REGISTRY_LOCK_RETRIES);
+
+        let mut cfg = AgentMcpConfig::default();
+        clamp_limits(&mut cfg);
+        assert_eq!(cfg.registry_lock_retries, 5);
+    }
+
     #[test]
     fn test_default_codex_bin() {
         let cfg = AgentMcpConfig::default();
@@ -300,6 +329,17 @@ sandbox = "network-disabled"
         assert!(qa.reasoning_effort.is_none());
     }
 
+    #[test]
+    fn test_toml_per_identity_auto_mail() {
+        let toml_str = r#"
+[per_identity_auto_mail]
+"arch-ctm" = false
+"#;
+        let cfg: AgentMcpConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(cfg.per_identity_auto_mail.get("arch-ctm"), Some(&false));
+        assert!(cfg.per_thread_auto_mail.is_empty());
+    }
+
     #[test]
     fn test_role_preset_all_none_fields() {
         let toml_str = "[roles.empty]\n";
@@ -316,23 +356,52 @@ sandbox = "network-disabled"
         let original = AgentMcpConfig {
             codex_bin: "my-codex".to_string(),
             identity: Some("test-id".to_string()),
+            identity_pool: vec!["persona-a".to_string(), "persona-b".to_string()],
             model: Some("gpt-4o".to_string()),
             fast_model: None,
+            allowed_models: vec!["gpt-5-codex".to_string()],
+            cwd_base: Some("/work".to_string()),
+            restrict_cwd_to: vec!["/work/repos".to_string()],
             reasoning_effort: None,
             sandbox: "workspace-write".to_string(),
             approval_policy: "on-failure".to_string(),
+            downstream_framing: crate::config::DownstreamFraming::ContentLength,
+            inject_context_on_reply: false,
             mail_poll_interval_ms: 2000,
+            mail_poll_backoff_after: 5,
+            mail_poll_max_interval_ms: 30_000,
+            coalesce_window_ms: 250,
             request_timeout_secs: 120,
             max_concurrent_threads: 4,
             persist_threads: false,
-            auto_mail: true,
+            auto_mail: false,
             max_mail_messages: 10,
             max_mail_message_length: 4096,
             per_thread_auto_mail: std::collections::HashMap::new(),
+            per_identity_auto_mail: std::collections::HashMap::new(),
+            per_thread_timeout_secs: [("codex:build".to_string(), 1800)].into_iter().collect(),
             base_prompt_file: None,
             extra_instructions_file: None,
             roles: std::collections::HashMap::new(),
             transport: None,
+            transport_recording: Some("session.jsonl".to_string()),
+            thread_map_sweep_interval_secs: 30,
+            stale_session_ttl_secs: 86_400,
+            allow_cross_team: true,
+            max_consecutive_parse_errors: 5,
+            synthetic_tool_timeout_secs: 10,
+            max_child_line_bytes: 4096,
+            max_upstream_frame_bytes: 8192,
+            upstream_channel_capacity: 64,
+            child_channel_capacity: 32,
+            registry_lock_retries: 2,
+            max_audit_bytes: 65_536,
+            max_audit_files: 2,
+            max_child_restarts: 3,
+            mail_injection_role: crate::config::MailInjectionRole::System,
+            shutdown_summary_prompt: Some("Hand off in our format".to_string()),
+            shutdown_summary_prompt_file: Some("handoff.md".to_string()),
+            min_session_duration_for_summary_secs: 45,
         };
 
         let json = serde_json::to_string_pretty(&original).unwrap();
@@ -340,13 +409,26 @@ sandbox = "network-disabled"
 
         assert_eq!(original.codex_bin, restored.codex_bin);
         assert_eq!(original.identity, restored.identity);
+        assert_eq!(original.identity_pool, restored.identity_pool);
         assert_eq!(original.model, restored.model);
+        assert_eq!(original.allowed_models, restored.allowed_models);
+        assert_eq!(original.transport_recording, restored.transport_recording);
+        assert_eq!(original.cwd_base, restored.cwd_base);
+        assert_eq!(original.restrict_cwd_to, restored.restrict_cwd_to);
         assert_eq!(original.sandbox, restored.sandbox);
         assert_eq!(original.approval_policy, restored.approval_policy);
         assert_eq!(
             original.mail_poll_interval_ms,
             restored.mail_poll_interval_ms
         );
+        assert_eq!(
+            original.mail_poll_backoff_after,
+            restored.mail_poll_backoff_after
+        );
+        assert_eq!(
+            original.mail_poll_max_interval_ms,
+            restored.mail_poll_max_interval_ms
+        );
         assert_eq!(original.request_timeout_secs, restored.request_timeout_secs);
         assert_eq!(
             original.max_concurrent_threads,
@@ -354,6 +436,60 @@ sandbox = "network-disabled"
         );
         assert_eq!(original.persist_threads, restored.persist_threads);
         assert_eq!(original.auto_mail, restored.auto_mail);
+        assert_eq!(original.allow_cross_team, restored.allow_cross_team);
+        assert_eq!(
+            original.max_consecutive_parse_errors,
+            restored.max_consecutive_parse_errors
+        );
+        assert_eq!(
+            original.synthetic_tool_timeout_secs,
+            restored.synthetic_tool_timeout_secs
+        );
+        assert_eq!(
+            original.stale_session_ttl_secs,
+            restored.stale_session_ttl_secs
+        );
+        assert_eq!(
+            original.per_thread_timeout_secs,
+            restored.per_thread_timeout_secs
+        );
+        assert_eq!(
+            original.min_session_duration_for_summary_secs,
+            restored.min_session_duration_for_summary_secs
+        );
+        assert_eq!(original.max_child_line_bytes, restored.max_child_line_bytes);
+        assert_eq!(
+            original.max_upstream_frame_bytes,
+            restored.max_upstream_frame_bytes
+        );
+        assert_eq!(
+            original.upstream_channel_capacity,
+            restored.upstream_channel_capacity
+        );
+        assert_eq!(
+            original.child_channel_capacity,
+            restored.child_channel_capacity
+        );
+        assert_eq!(
+            original.registry_lock_retries,
+            restored.registry_lock_retries
+        );
+        assert_eq!(original.max_audit_bytes, restored.max_audit_bytes);
+        assert_eq!(original.max_audit_files, restored.max_audit_files);
+        assert_eq!(original.max_child_restarts, restored.max_child_restarts);
+        assert_eq!(original.mail_injection_role, restored.mail_injection_role);
+        assert_eq!(
+            original.shutdown_summary_prompt,
+            restored.shutdown_summary_prompt
+        );
+        assert_eq!(
+            original.shutdown_summary_prompt_file,
+            restored.shutdown_summary_prompt_file
+        );
+        assert_eq!(
+            original.thread_map_sweep_interval_secs,
+            restored.thread_map_sweep_interval_secs
+        );
     }
 
     // ─── Environment variable override tests ────────────────────────────────
@@ -34,6 +34,28 @@ pub struct RolePreset {
     pub reasoning_effort: Option<String>,
 }
 
+/// How auto-injected mail is framed in the thread (FR-8.14).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MailInjectionRole {
+    /// Plain prompt text, indistinguishable from a user turn (default).
+    #[default]
+    User,
+    /// Delimited system/context block marked as not coming from the user.
+    System,
+}
+
+/// Wire framing for messages the proxy writes to the child's stdin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DownstreamFraming {
+    /// One JSON object per `\n`-terminated line, as Codex expects (default).
+    #[default]
+    Newline,
+    /// `Content-Length: N\r\n\r\n<body>` frames, per the MCP stdio transport.
+    ContentLength,
+}
+
 /// Resolved atm-agent-mcp plugin configuration.
 ///
 /// Deserialized from `[plugins.atm-agent-mcp]` section of `.atm.toml`.
@@ -63,6 +85,15 @@ pub struct AgentMcpConfig {
     #[serde(default)]
     pub identity: Option<String>,
 
+    /// Identities reserved for sessions that register without an explicit
+    /// `identity` (default: empty).
+    ///
+    /// When non-empty, a new session takes the first pool identity that is
+    /// not bound to an active session or locked by another proxy; `identity`
+    /// is then ignored as a default.
+    #[serde(default)]
+    pub identity_pool: Vec<String>,
+
     /// Model override (None = let Codex use its default)
     #[serde(default)]
     pub model: Option<String>,
@@ -71,6 +102,28 @@ pub struct AgentMcpConfig {
     #[serde(default)]
     pub fast_model: Option<String>,
 
+    /// Models a caller may select per turn via the `model` argument of the
+    /// `codex`/`codex-reply` tools (default: empty = any model).
+    ///
+    /// When non-empty, a turn requesting a model outside this list is rejected
+    /// before it reaches the Codex child.
+    #[serde(default)]
+    pub allowed_models: Vec<String>,
+
+    /// Root that relative `cwd` arguments of `codex`/`codex-reply` resolve
+    /// against (default: the proxy's own working directory).
+    ///
+    /// When set, a new `codex` session without a `cwd` starts here.
+    #[serde(default)]
+    pub cwd_base: Option<String>,
+
+    /// Directories a session's `cwd` must fall under (default: empty = any).
+    ///
+    /// A resolved `cwd` outside every listed root is rejected before the turn
+    /// reaches the Codex child.
+    #[serde(default)]
+    pub restrict_cwd_to: Vec<String>,
+
     /// Reasoning effort level
     #[serde(default)]
     pub reasoning_effort: Option<String>,
@@ -83,10 +136,39 @@ pub struct AgentMcpConfig {
     #[serde(default = "default_approval_policy")]
     pub approval_policy: String,
 
+    /// Framing used when writing to the child's stdin: `"newline"` (default)
+    /// or `"content-length"`.
+    #[serde(default)]
+    pub downstream_framing: DownstreamFraming,
+
+    /// Inject the session-context block into every `codex-reply` turn
+    /// (FR-2.9, default: `true`).
+    ///
+    /// When `false`, only `codex` turns carry the block; continuation turns
+    /// rely on the context already in the thread.
+    #[serde(default = "default_inject_context_on_reply")]
+    pub inject_context_on_reply: bool,
+
     /// Mail poll interval in milliseconds (default: `5000`)
     #[serde(default = "default_mail_poll_interval_ms")]
     pub mail_poll_interval_ms: u64,
 
+    /// Consecutive empty idle polls before an agent's poll interval doubles
+    /// (FR-8.15, default: `3`; `0` disables backoff).
+    #[serde(default = "default_mail_poll_backoff_after")]
+    pub mail_poll_backoff_after: u32,
+
+    /// Cap on an agent's backed-off idle poll interval in milliseconds
+    /// (FR-8.15, default: `60000`).
+    #[serde(default = "default_mail_poll_max_interval_ms")]
+    pub mail_poll_max_interval_ms: u64,
+
+    /// Window in milliseconds the idle poller waits, after finding unread
+    /// mail, for more messages to arrive before injecting one batched turn
+    /// (FR-8.16, default: `0` = inject immediately).
+    #[serde(default)]
+    pub coalesce_window_ms: u64,
+
     /// Request timeout in seconds (default: `300`)
     #[serde(default = "default_request_timeout_secs")]
     pub request_timeout_secs: u64,
@@ -99,8 +181,13 @@ pub struct AgentMcpConfig {
     #[serde(default = "default_persist_threads")]
     pub persist_threads: bool,
 
-    /// Enable automatic mail injection into Codex context (default: `true`)
-    #[serde(default = "default_auto_mail")]
+    /// Enable automatic mail injection into Codex context (default: `true`).
+    ///
+    /// Also accepted as `auto_mail_enabled`. When `false`, the idle mail
+    /// poller is never started and no post-turn mail dispatch occurs,
+    /// regardless of any [`Self::per_thread_auto_mail`] or
+    /// [`Self::per_identity_auto_mail`] entry.
+    #[serde(default = "default_auto_mail", alias = "auto_mail_enabled")]
     pub auto_mail: bool,
 
     /// Maximum number of messages to inject per auto-mail turn (FR-8.5, default: `10`).
@@ -111,6 +198,13 @@ pub struct AgentMcpConfig {
     #[serde(default = "default_max_mail_message_length")]
     pub max_mail_message_length: usize,
 
+    /// Framing of injected mail: `"user"` (default) or `"system"` (FR-8.14).
+    ///
+    /// `"system"` wraps the mail in `<atm-mail role="system">` delimiters with
+    /// a note that it is incoming team mail, not an instruction from the user.
+    #[serde(default)]
+    pub mail_injection_role: MailInjectionRole,
+
     /// Per-thread auto-mail overrides.
     ///
     /// Map of `agent_id` → `bool` enabling or disabling auto-mail injection for
@@ -119,6 +213,23 @@ pub struct AgentMcpConfig {
     #[serde(default)]
     pub per_thread_auto_mail: HashMap<String, bool>,
 
+    /// Per-identity auto-mail overrides.
+    ///
+    /// Map of ATM identity → `bool` enabling or disabling auto-mail injection
+    /// for every thread bound to that identity, whatever `agent_id` it was
+    /// assigned (FR-8.8a).  A [`Self::per_thread_auto_mail`] entry for the
+    /// thread's `agent_id` takes precedence.
+    #[serde(default)]
+    pub per_identity_auto_mail: HashMap<String, bool>,
+
+    /// Per-thread request timeout overrides.
+    ///
+    /// Map of `agent_id` → timeout in seconds for `codex`/`codex-reply`
+    /// turns on that thread, including queued replies.  When absent, the
+    /// global [`Self::request_timeout_secs`] applies.
+    #[serde(default)]
+    pub per_thread_timeout_secs: HashMap<String, u64>,
+
     /// Optional base prompt file path
     #[serde(default)]
     pub base_prompt_file: Option<String>,
@@ -141,6 +252,123 @@ pub struct AgentMcpConfig {
     /// An absent or unrecognised value falls back to `"mcp"`.
     #[serde(default)]
     pub transport: Option<String>,
+
+    /// Path of a JSONL file that records every byte exchanged with the Codex
+    /// child (default: unset = no recording).
+    ///
+    /// Recordings can be replayed in tests with `ReplayTransport`.
+    #[serde(default)]
+    pub transport_recording: Option<String>,
+
+    /// Interval in seconds between sweeps that drop `threadId` → `agent_id`
+    /// mappings for sessions that are no longer active (default: `60`).
+    ///
+    /// `0` disables the periodic sweep; mappings are still pruned when a
+    /// session is closed.
+    #[serde(default = "default_thread_map_sweep_interval_secs")]
+    pub thread_map_sweep_interval_secs: u64,
+
+    /// Close `stale` sessions (left over from a previous proxy run) once
+    /// their `last_active` is this many seconds old, releasing leftover lock
+    /// files (FR-3.5, default: `0` — stale sessions are kept).
+    #[serde(default)]
+    pub stale_session_ttl_secs: u64,
+
+    /// Allow ATM tools to target teams other than the proxy's team
+    /// (default: `false`).
+    ///
+    /// When `false`, `atm_send` to `agent@other-team` and `atm_broadcast`
+    /// with a different `team` are rejected.
+    #[serde(default)]
+    pub allow_cross_team: bool,
+
+    /// Consecutive unparseable upstream messages after which the proxy logs a
+    /// fatal error and shuts down (default: `0`, never shut down).
+    ///
+    /// Each bad message still gets a `-32700` response; any successfully
+    /// parsed message resets the count.
+    #[serde(default)]
+    pub max_consecutive_parse_errors: u32,
+
+    /// Timeout in seconds for a synthetic tool call such as `atm_send`
+    /// (default: `30`).
+    ///
+    /// A call that exceeds it returns a `-32006` timeout error. `0` disables
+    /// the timeout. Tools that write inboxes (`atm_send`, `atm_reply`,
+    /// `atm_broadcast`, `atm_read`) are exempt; their lock waits are bounded
+    /// inside the write so a timed-out write is never retried into a duplicate.
+    #[serde(default = "default_synthetic_tool_timeout_secs")]
+    pub synthetic_tool_timeout_secs: u64,
+
+    /// Maximum length in bytes of a single line of Codex child output
+    /// (default: `16777216`, 16 MiB).
+    ///
+    /// Longer lines are logged and dropped without being buffered in full.
+    /// `0` disables the limit.
+    #[serde(default = "default_max_child_line_bytes")]
+    pub max_child_line_bytes: usize,
+
+    /// Maximum body size in bytes of a Content-Length framed upstream message
+    /// (default: `16777216`, 16 MiB).
+    ///
+    /// A larger declared length is rejected with a `-32600` error before the
+    /// body is allocated, and the body is skipped. `0` disables the limit.
+    #[serde(default = "default_max_upstream_frame_bytes")]
+    pub max_upstream_frame_bytes: usize,
+
+    /// Capacity of the channel carrying messages to the upstream client
+    /// (default: `256`, minimum `1`).
+    #[serde(default = "default_channel_capacity")]
+    pub upstream_channel_capacity: usize,
+
+    /// Capacity of the channel carrying Codex child output to the proxy
+    /// (default: `256`, minimum `1`).
+    #[serde(default = "default_channel_capacity")]
+    pub child_channel_capacity: usize,
+
+    /// Retry attempts when acquiring the `registry.json` lock shared with
+    /// other proxies for the same team (default: `5`, exponential backoff
+    /// from 50 ms). Values above [`MAX_REGISTRY_LOCK_RETRIES`] are clamped.
+    ///
+    /// When the lock stays contended the snapshot write is skipped with a
+    /// warning; the next persist retries. `0` tries once without waiting.
+    #[serde(default = "default_registry_lock_retries")]
+    pub registry_lock_retries: u32,
+
+    /// Size in bytes at which `audit.jsonl` is rotated to
+    /// `audit.<timestamp>.jsonl` (FR-9.4, default: `10485760`, 10 MiB).
+    /// `0` disables rotation.
+    #[serde(default = "default_max_audit_bytes")]
+    pub max_audit_bytes: u64,
+
+    /// Rotated audit files kept per team; the oldest beyond this are
+    /// deleted (FR-9.4, default: `5`). `0` keeps none.
+    #[serde(default = "default_max_audit_files")]
+    pub max_audit_files: usize,
+
+    /// Automatic respawns allowed when the Codex child exits unexpectedly
+    /// (default: `0`, never respawn).
+    ///
+    /// The budget covers the proxy's lifetime; each attempt waits an
+    /// exponential backoff first. Overridden by `serve --max-child-restarts`.
+    #[serde(default)]
+    pub max_child_restarts: u32,
+
+    /// Prompt sent to each active thread when requesting a shutdown summary
+    /// (FR-7.1). When unset, the built-in handoff prompt is used.
+    #[serde(default)]
+    pub shutdown_summary_prompt: Option<String>,
+
+    /// File whose contents replace the shutdown summary prompt. Takes
+    /// precedence over [`Self::shutdown_summary_prompt`]; an unreadable file
+    /// falls back to it (or the built-in prompt) with a warning.
+    #[serde(default)]
+    pub shutdown_summary_prompt_file: Option<String>,
+
+    /// Sessions younger than this many seconds skip the shutdown summary
+    /// request (FR-7.1, default: `0` — every session is summarized).
+    #[serde(default)]
+    pub min_session_duration_for_summary_secs: u64,
 }
 
 fn default_codex_bin() -> String {
@@ -155,10 +383,22 @@ fn default_approval_policy() -> String {
     "on-failure".to_string()
 }
 
+fn default_inject_context_on_reply() -> bool {
+    true
+}
+
 fn default_mail_poll_interval_ms() -> u64 {
     5000
 }
 
+fn default_mail_poll_backoff_after() -> u32 {
+    3
+}
+
+fn default_mail_poll_max_interval_ms() -> u64 {
+    60_000
+}
+
 fn default_request_timeout_secs() -> u64 {
     300
 }
@@ -175,28 +415,92 @@ fn default_auto_mail() -> bool {
     true
 }
 
+fn default_thread_map_sweep_interval_secs() -> u64 {
+    60
+}
+
+fn default_synthetic_tool_timeout_secs() -> u64 {
+    30
+}
+
+fn default_max_child_line_bytes() -> usize {
+    16 * 1024 * 1024
+}
+
+fn default_max_upstream_frame_bytes() -> usize {
+    crate::framing::DEFAULT_MAX_FRAME_BYTES
+}
+
+fn default_channel_capacity() -> usize {
+    256
+}
+
+/// Upper bound for `registry_lock_retries`.
+pub const MAX_REGISTRY_LOCK_RETRIES: u32 = 10;
+
+fn default_registry_lock_retries() -> u32 {
+    5
+}
+
+fn default_max_audit_bytes() -> u64 {
+    10 * 1024 * 1024
+}
+
+fn default_max_audit_files() -> usize {
+    5
+}
+
 impl Default for AgentMcpConfig {
     fn default() -> Self {
         Self {
             codex_bin: default_codex_bin(),
             identity: None,
+            identity_pool: Vec::new(),
             model: None,
             fast_model: None,
+            allowed_models: Vec::new(),
+            cwd_base: None,
+            restrict_cwd_to: Vec::new(),
             reasoning_effort: None,
             sandbox: default_sandbox(),
             approval_policy: default_approval_policy(),
+            downstream_framing: DownstreamFraming::Newline,
+            inject_context_on_reply: default_inject_context_on_reply(),
             mail_poll_interval_ms: default_mail_poll_interval_ms(),
+            mail_poll_backoff_after: default_mail_poll_backoff_after(),
+            mail_poll_max_interval_ms: default_mail_poll_max_interval_ms(),
+            coalesce_window_ms: 0,
             request_timeout_secs: default_request_timeout_secs(),
             max_concurrent_threads: default_max_concurrent_threads(),
             persist_threads: default_persist_threads(),
             auto_mail: default_auto_mail(),
             max_mail_messages: default_max_mail_messages(),
             max_mail_message_length: default_max_mail_message_length(),
+            mail_injection_role: MailInjectionRole::User,
             per_thread_auto_mail: HashMap::new(),
+            per_identity_auto_mail: HashMap::new(),
+            per_thread_timeout_secs: HashMap::new(),
             base_prompt_file: None,
             extra_instructions_file: None,
             roles: HashMap::new(),
             transport: None,
+            transport_recording: None,
+            thread_map_sweep_interval_secs: default_thread_map_sweep_interval_secs(),
+            stale_session_ttl_secs: 0,
+            allow_cross_team: false,
+            max_consecutive_parse_errors: 0,
+            synthetic_tool_timeout_secs: default_synthetic_tool_timeout_secs(),
+            max_child_line_bytes: default_max_child_line_bytes(),
+            max_upstream_frame_bytes: default_max_upstream_frame_bytes(),
+            upstream_channel_capacity: default_channel_capacity(),
+            child_channel_capacity: default_channel_capacity(),
+            registry_lock_retries: default_registry_lock_retries(),
+            max_audit_bytes: default_max_audit_bytes(),
+            max_audit_files: default_max_audit_files(),
+            max_child_restarts: 0,
+            shutdown_summary_prompt: None,
+            shutdown_summary_prompt_file: None,
+            min_session_duration_for_summary_secs: 0,
         }
     }
 }
@@ -6,12 +6,38 @@
 //! [`ElicitationRegistry`] maps upstream request IDs to pending [`PendingElicitation`]
 //! entries so that responses received from upstream can be correlated back to the
 //! correct child request and forwarded downstream.
+//!
+//! Each elicitation times out after the registry default unless the child's
+//! `params.timeoutMs` overrides it (see [`timeout_from_params`]). Expired
+//! elicitations are answered downstream with a JSON-RPC error, and their
+//! upstream IDs are remembered so a late upstream answer can be dropped
+//! instead of being forwarded to the child as an unknown response.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::time::{Duration, Instant};
 
 use tokio::sync::oneshot;
 
+/// JSON-RPC error code sent to the child when an elicitation times out
+/// (`REQUEST_TIMEOUT` per NFR-6).
+pub const ELICITATION_TIMEOUT_CODE: i64 = -32006;
+
+/// Number of expired upstream request IDs remembered for late-response
+/// detection.
+const EXPIRED_HISTORY: usize = 256;
+
+/// Read a per-elicitation timeout override from `elicitation/create` params.
+///
+/// Returns `None` when `params.timeoutMs` is absent, not a positive integer,
+/// or `params` itself is missing, so the registry default applies.
+pub fn timeout_from_params(params: Option<&serde_json::Value>) -> Option<Duration> {
+    params?
+        .get("timeoutMs")?
+        .as_u64()
+        .filter(|ms| *ms > 0)
+        .map(Duration::from_millis)
+}
+
 /// A single pending elicitation waiting for an upstream (Claude) response.
 pub struct PendingElicitation {
     /// The agent_id whose session triggered this elicitation.
@@ -38,6 +64,14 @@ impl std::fmt::Debug for PendingElicitation {
     }
 }
 
+/// What remains of a pending elicitation after it timed out.
+struct ExpiredElicitation {
+    key: String,
+    agent_id: String,
+    downstream_request_id: serde_json::Value,
+    timeout: Duration,
+}
+
 /// Registry of pending elicitations keyed by `upstream_request_id.to_string()`.
 ///
 /// Wrap in `Arc<tokio::sync::Mutex<ElicitationRegistry>>` when sharing across
@@ -65,6 +99,10 @@ pub struct ElicitationRegistry {
     pending: HashMap<String, PendingElicitation>,
     /// Default timeout applied to new registrations.
     default_timeout: Duration,
+    /// Elicitations expired by [`Self::expire_timeouts`] so far.
+    timed_out: u64,
+    /// Upstream keys of recently expired elicitations, oldest first.
+    recently_expired: VecDeque<String>,
 }
 
 impl ElicitationRegistry {
@@ -73,6 +111,8 @@ impl ElicitationRegistry {
         Self {
             pending: HashMap::new(),
             default_timeout: Duration::from_secs(default_timeout_secs),
+            timed_out: 0,
+            recently_expired: VecDeque::new(),
         }
     }
 
@@ -86,6 +126,26 @@ impl ElicitationRegistry {
         downstream_request_id: serde_json::Value,
         upstream_request_id: serde_json::Value,
         response_tx: oneshot::Sender<serde_json::Value>,
+    ) {
+        self.register_with_timeout(
+            agent_id,
+            downstream_request_id,
+            upstream_request_id,
+            None,
+            response_tx,
+        );
+    }
+
+    /// Register a new pending elicitation with an optional timeout override.
+    ///
+    /// `None` applies the registry default timeout.
+    pub fn register_with_timeout(
+        &mut self,
+        agent_id: String,
+        downstream_request_id: serde_json::Value,
+        upstream_request_id: serde_json::Value,
+        timeout: Option<Duration>,
+        response_tx: oneshot::Sender<serde_json::Value>,
     ) {
         let key = upstream_request_id.to_string();
         self.pending.insert(
@@ -95,7 +155,7 @@ impl ElicitationRegistry {
                 downstream_request_id,
                 upstream_request_id,
                 created_at: Instant::now(),
-                timeout: self.default_timeout,
+                timeout: timeout.unwrap_or(self.default_timeout),
                 response_tx,
             },
         );
@@ -207,11 +267,64 @@ impl ElicitationRegistry {
     ///
     /// Error code `-32006` maps to `REQUEST_TIMEOUT` per NFR-6.
     pub fn expire_timeouts(&mut self) -> Vec<String> {
+        self.take_expired().into_iter().map(|e| e.key).collect()
+    }
+
+    /// Expire timed-out entries like [`Self::expire_timeouts`] and build the
+    /// JSON-RPC error response owed to the child for each one.
+    ///
+    /// Returns `(upstream_key, downstream_error)` pairs; the error is
+    /// addressed to the child's original request ID:
+    ///
+    /// ```json
+    /// {"jsonrpc": "2.0", "id": <downstream id>, "error": {"code": -32006,
+    ///  "message": "elicitation timed out", "data": {"agent_id": "...", "timeout_ms": 30000}}}
+    /// ```
+    pub fn expire_timeouts_for_downstream(&mut self) -> Vec<(String, serde_json::Value)> {
+        self.take_expired()
+            .into_iter()
+            .map(|entry| {
+                let error = serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": entry.downstream_request_id,
+                    "error": {
+                        "code": ELICITATION_TIMEOUT_CODE,
+                        "message": "elicitation timed out",
+                        "data": {
+                            "agent_id": entry.agent_id,
+                            "timeout_ms": entry.timeout.as_millis() as u64,
+                        }
+                    }
+                });
+                (entry.key, error)
+            })
+            .collect()
+    }
+
+    /// Returns `true` (once) when `upstream_request_id` belongs to an
+    /// elicitation that already timed out.
+    ///
+    /// Used to drop an upstream answer that arrives after the child was told
+    /// the elicitation timed out.
+    pub fn take_late_response(&mut self, upstream_request_id: &serde_json::Value) -> bool {
+        let key = upstream_request_id.to_string();
+        match self.recently_expired.iter().position(|k| *k == key) {
+            Some(index) => {
+                self.recently_expired.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove expired entries, send each waiting channel the timeout
+    /// rejection, and remember their keys for [`Self::take_late_response`].
+    fn take_expired(&mut self) -> Vec<ExpiredElicitation> {
         let now = Instant::now();
         let timeout_rejection = serde_json::json!({
             "result": null,
             "error": {
-                "code": -32006,
+                "code": ELICITATION_TIMEOUT_CODE,
                 "message": "elicitation timeout"
             }
         });
@@ -223,13 +336,30 @@ impl ElicitationRegistry {
             .map(|(k, _)| k.clone())
             .collect();
 
-        for key in &expired_keys {
-            if let Some(entry) = self.pending.remove(key) {
+        let mut expired = Vec::with_capacity(expired_keys.len());
+        for key in expired_keys {
+            if let Some(entry) = self.pending.remove(&key) {
                 let _ = entry.response_tx.send(timeout_rejection.clone());
+                if self.recently_expired.len() == EXPIRED_HISTORY {
+                    self.recently_expired.pop_front();
+                }
+                self.recently_expired.push_back(key.clone());
+                expired.push(ExpiredElicitation {
+                    key,
+                    agent_id: entry.agent_id,
+                    downstream_request_id: entry.downstream_request_id,
+                    timeout: entry.timeout,
+                });
             }
         }
+        self.timed_out += expired.len() as u64;
 
-        expired_keys
+        expired
+    }
+
+    /// Total number of elicitations expired by [`Self::expire_timeouts`].
+    pub fn timed_out_count(&self) -> u64 {
+        self.timed_out
     }
 
     /// Number of pending elicitations currently tracked.
@@ -237,6 +367,11 @@ impl ElicitationRegistry {
         self.pending.len()
     }
 
+    /// Number of elicitations still awaiting an upstream response, for metrics.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
     /// Returns `true` when no elicitations are pending.
     pub fn is_empty(&self) -> bool {
         self.pending.is_empty()
@@ -364,6 +499,7 @@ mod tests {
         let expired = reg.expire_timeouts();
         assert_eq!(expired.len(), 2, "both entries must expire");
         assert!(reg.is_empty(), "registry must be empty after expiry");
+        assert_eq!(reg.timed_out_count(), 2);
 
         // Both channels must have received the timeout rejection
         let r1 = rx1
@@ -394,6 +530,87 @@ mod tests {
         assert_eq!(reg.len(), 1);
     }
 
+    // ─── per-request timeout + downstream timeout errors ─────────────────────
+
+    #[test]
+    fn timeout_from_params_reads_positive_timeout_ms() {
+        let params = serde_json::json!({"timeoutMs": 1500});
+        assert_eq!(
+            timeout_from_params(Some(&params)),
+            Some(std::time::Duration::from_millis(1500))
+        );
+        assert_eq!(
+            timeout_from_params(Some(&serde_json::json!({"timeoutMs": 0}))),
+            None
+        );
+        assert_eq!(
+            timeout_from_params(Some(&serde_json::json!({"timeoutMs": "5"}))),
+            None
+        );
+        assert_eq!(timeout_from_params(None), None);
+    }
+
+    #[tokio::test]
+    async fn register_with_timeout_overrides_registry_default() {
+        let mut reg = make_reg(30);
+        let (tx_short, _rx_short) = oneshot::channel::<serde_json::Value>();
+        let (tx_default, _rx_default) = oneshot::channel::<serde_json::Value>();
+        reg.register_with_timeout(
+            "agent-1".to_string(),
+            serde_json::json!(1),
+            serde_json::json!(100),
+            Some(std::time::Duration::from_millis(1)),
+            tx_short,
+        );
+        reg.register(
+            "agent-1".to_string(),
+            serde_json::json!(2),
+            serde_json::json!(101),
+            tx_default,
+        );
+        assert_eq!(reg.pending_count(), 2);
+
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+
+        assert_eq!(reg.expire_timeouts(), vec!["100".to_string()]);
+        assert_eq!(reg.pending_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn timeout_then_late_response_is_dropped() {
+        let mut reg = make_reg(0);
+        let (tx, _rx) = oneshot::channel::<serde_json::Value>();
+        reg.register(
+            "agent-late".to_string(),
+            serde_json::json!("child-7"),
+            serde_json::json!(700),
+            tx,
+        );
+
+        tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+
+        let expired = reg.expire_timeouts_for_downstream();
+        assert_eq!(expired.len(), 1);
+        let (key, error) = &expired[0];
+        assert_eq!(key, "700");
+        assert_eq!(error["id"], "child-7");
+        assert_eq!(error["error"]["code"], ELICITATION_TIMEOUT_CODE);
+        assert_eq!(error["error"]["message"], "elicitation timed out");
+        assert_eq!(error["error"]["data"]["agent_id"], "agent-late");
+        assert_eq!(reg.timed_out_count(), 1);
+
+        // The upstream answer arrives after the child was told about the timeout.
+        let late = serde_json::json!({"id": 700, "result": {"decision": "approve"}});
+        assert!(
+            reg.resolve_for_downstream(&serde_json::json!(700), late)
+                .is_none()
+        );
+        assert!(reg.take_late_response(&serde_json::json!(700)));
+        // Only flagged once; an unrelated id is never treated as late.
+        assert!(!reg.take_late_response(&serde_json::json!(700)));
+        assert!(!reg.take_late_response(&serde_json::json!(701)));
+    }
+
     // ── Security invariant: no silent approval on timeout (G.5) ─────────────
 
     /// SECURITY INVARIANT (FR-18 / G.5): an elicitation that times out MUST
@@ -6,12 +6,204 @@
 //! - **Newline-delimited**: one JSON object per `\n`-terminated line (Codex child uses this)
 //!
 //! The proxy reads from upstream (Claude) using [`UpstreamReader`] which auto-detects
-//! framing. Messages are always written to the Codex child in newline-delimited format.
+//! framing. Messages are written to the child with [`write_child_message`], which uses
+//! newline-delimited framing by default or Content-Length when configured.
+//! Child output is read with [`ChildLineReader`], which caps the length of a single
+//! message so one oversized response cannot grow the read buffer without bound, and
+//! also parses Content-Length frames when the child is configured to use them.
+//! Likewise, [`UpstreamReader`] rejects a Content-Length frame whose declared size
+//! exceeds its cap before allocating the body.
 
 use std::io;
 
 use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
 
+use crate::config::DownstreamFraming;
+
+/// A message read by [`ChildLineReader::next_line`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChildLine {
+    /// A complete line (without its trailing `\n` / `\r\n`) or frame body.
+    Line(String),
+    /// A line or frame body longer than the configured cap; its bytes were
+    /// discarded.
+    Oversized {
+        /// Total length of the discarded line or body in bytes.
+        len: usize,
+        /// Up to [`OVERSIZED_HEAD_BYTES`] leading bytes of a discarded line,
+        /// enough to recover its JSON-RPC `id`. Empty for Content-Length
+        /// frames, whose body is skipped unread.
+        head: String,
+    },
+}
+
+/// Leading bytes of an oversized line kept in [`ChildLine::Oversized`].
+pub const OVERSIZED_HEAD_BYTES: usize = 256;
+
+/// Reads messages from a child process with a per-message size cap.
+///
+/// Unlike [`AsyncBufReadExt::lines`], a line longer than `max_line_bytes` is not
+/// buffered in full: bytes past the cap are consumed and dropped, and the line is
+/// reported as [`ChildLine::Oversized`] so the caller can log and skip it.
+///
+/// With [`DownstreamFraming::ContentLength`] (see [`Self::with_framing`]), a
+/// `Content-Length:` header starts a frame whose body is returned as one
+/// message, under the same cap; other lines are still read as
+/// newline-delimited messages.
+pub struct ChildLineReader<R> {
+    reader: BufReader<R>,
+    buf: Vec<u8>,
+    max_line_bytes: usize,
+    framing: DownstreamFraming,
+}
+
+impl<R: AsyncRead + Unpin> ChildLineReader<R> {
+    /// Create a reader that rejects lines longer than `max_line_bytes`.
+    ///
+    /// A cap of `0` disables the limit.
+    pub fn new(reader: R, max_line_bytes: usize) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            buf: Vec::new(),
+            max_line_bytes,
+            framing: DownstreamFraming::Newline,
+        }
+    }
+
+    /// Parse child output with `framing`, matching how messages are written
+    /// to the child.
+    pub fn with_framing(mut self, framing: DownstreamFraming) -> Self {
+        self.framing = framing;
+        self
+    }
+
+    /// Read the next line or frame body, returning `None` on EOF.
+    ///
+    /// A final line without a trailing newline is still returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if reading fails, a message within the cap is not
+    /// valid UTF-8, or a Content-Length header is malformed. A frame cut short
+    /// by EOF yields [`FramingError::TruncatedFrame`].
+    pub async fn next_line(&mut self) -> io::Result<Option<ChildLine>> {
+        let line = match self.read_capped_line().await? {
+            Some(ChildLine::Line(line)) => line,
+            other => return Ok(other),
+        };
+        if self.framing == DownstreamFraming::ContentLength
+            && let Some(len) = parse_content_length(&line)?
+        {
+            return match read_frame(&mut self.reader, len, self.max_line_bytes).await {
+                Ok(body) => Ok(Some(ChildLine::Line(body))),
+                Err(e) => match FramingError::from_io(&e) {
+                    Some(FramingError::FrameTooLarge { declared, .. }) => {
+                        Ok(Some(ChildLine::Oversized {
+                            len: *declared,
+                            head: String::new(),
+                        }))
+                    }
+                    _ => Err(e),
+                },
+            };
+        }
+        Ok(Some(ChildLine::Line(line)))
+    }
+
+    async fn read_capped_line(&mut self) -> io::Result<Option<ChildLine>> {
+        self.buf.clear();
+        let mut len = 0usize;
+        let mut saw_any = false;
+
+        loop {
+            let available = self.reader.fill_buf().await?;
+            if available.is_empty() {
+                if !saw_any {
+                    return Ok(None); // EOF
+                }
+                break;
+            }
+            saw_any = true;
+
+            let (chunk_len, consumed, done) = match available.iter().position(|&b| b == b'\n') {
+                Some(i) => (i, i + 1, true),
+                None => (available.len(), available.len(), false),
+            };
+            len += chunk_len;
+            if self.max_line_bytes == 0 || len <= self.max_line_bytes {
+                self.buf.extend_from_slice(&available[..chunk_len]);
+            } else {
+                // Keep only a short head; the rest of the line is skipped.
+                let room = OVERSIZED_HEAD_BYTES.saturating_sub(self.buf.len());
+                self.buf
+                    .extend_from_slice(&available[..chunk_len.min(room)]);
+                if self.buf.len() > OVERSIZED_HEAD_BYTES {
+                    self.buf.truncate(OVERSIZED_HEAD_BYTES);
+                    self.buf.shrink_to_fit();
+                }
+            }
+            self.reader.consume(consumed);
+            if done {
+                break;
+            }
+        }
+
+        if self.max_line_bytes != 0 && len > self.max_line_bytes {
+            let head = String::from_utf8_lossy(&self.buf).into_owned();
+            return Ok(Some(ChildLine::Oversized { len, head }));
+        }
+        if self.buf.last() == Some(&b'\r') {
+            self.buf.pop();
+        }
+        let line = String::from_utf8(std::mem::take(&mut self.buf))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Some(ChildLine::Line(line)))
+    }
+}
+
+/// Default cap on a Content-Length frame body read by [`UpstreamReader`] (16 MiB).
+pub const DEFAULT_MAX_FRAME_BYTES: usize = 16 * 1024 * 1024;
+
+/// A Content-Length frame that [`UpstreamReader`] refused or could not complete.
+///
+/// Returned as the inner error of an [`io::Error`]; recover it with
+/// [`FramingError::from_io`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FramingError {
+    /// The declared body length exceeds the reader's cap. The body was
+    /// skipped without being buffered, so the next frame can still be read.
+    #[error("Content-Length {declared} exceeds max frame size {max}")]
+    FrameTooLarge {
+        /// Length declared by the `Content-Length` header.
+        declared: usize,
+        /// Configured cap.
+        max: usize,
+    },
+    /// EOF arrived before the declared body was complete.
+    #[error("truncated frame: Content-Length {declared} but only {received} bytes before EOF")]
+    TruncatedFrame {
+        /// Length declared by the `Content-Length` header.
+        declared: usize,
+        /// Body bytes received before EOF.
+        received: usize,
+    },
+}
+
+impl FramingError {
+    /// The framing error carried by `err`, if any.
+    pub fn from_io(err: &io::Error) -> Option<&Self> {
+        err.get_ref()?.downcast_ref()
+    }
+
+    fn into_io(self) -> io::Error {
+        let kind = match self {
+            Self::FrameTooLarge { .. } => io::ErrorKind::InvalidData,
+            Self::TruncatedFrame { .. } => io::ErrorKind::UnexpectedEof,
+        };
+        io::Error::new(kind, self)
+    }
+}
+
 /// Reads MCP messages from an async reader, auto-detecting Content-Length vs newline framing.
 ///
 /// On each call to [`UpstreamReader::next_message`], the reader peeks at incoming bytes:
@@ -20,23 +212,38 @@ use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWrite
 pub struct UpstreamReader<R> {
     reader: BufReader<R>,
     buf: String,
+    max_frame_bytes: usize,
 }
 
 impl<R: AsyncRead + Unpin> UpstreamReader<R> {
     /// Create a new upstream reader wrapping the given async reader.
+    ///
+    /// Content-Length frames are capped at [`DEFAULT_MAX_FRAME_BYTES`]; see
+    /// [`Self::with_max_frame_bytes`].
     pub fn new(reader: R) -> Self {
         Self {
             reader: BufReader::new(reader),
             buf: String::new(),
+            max_frame_bytes: DEFAULT_MAX_FRAME_BYTES,
         }
     }
 
+    /// Reject Content-Length frames declaring more than `max_frame_bytes`.
+    ///
+    /// A cap of `0` disables the limit.
+    pub fn with_max_frame_bytes(mut self, max_frame_bytes: usize) -> Self {
+        self.max_frame_bytes = max_frame_bytes;
+        self
+    }
+
     /// Read the next JSON-RPC message, returning `None` on EOF.
     ///
     /// # Errors
     ///
     /// Returns an I/O error if reading fails or Content-Length parsing encounters
-    /// malformed headers.
+    /// malformed headers. A frame over the cap or cut short by EOF yields an
+    /// error carrying a [`FramingError`]; after [`FramingError::FrameTooLarge`]
+    /// the reader is positioned at the next frame.
     pub async fn next_message(&mut self) -> io::Result<Option<String>> {
         loop {
             self.buf.clear();
@@ -51,33 +258,8 @@ impl<R: AsyncRead + Unpin> UpstreamReader<R> {
             }
 
             // Check if this is a Content-Length header
-            if let Some(rest) = trimmed.strip_prefix("Content-Length:") {
-                let len: usize = rest
-                    .trim()
-                    .parse()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-
-                // Read until blank line (consume \r\n\r\n separator)
-                loop {
-                    self.buf.clear();
-                    let header_n = self.reader.read_line(&mut self.buf).await?;
-                    if header_n == 0 {
-                        return Err(io::Error::new(
-                            io::ErrorKind::UnexpectedEof,
-                            "EOF in Content-Length headers",
-                        ));
-                    }
-                    if self.buf.trim().is_empty() {
-                        break;
-                    }
-                    // Skip other headers (e.g. Content-Type)
-                }
-
-                // Read exactly `len` bytes of body
-                let mut body = vec![0u8; len];
-                self.reader.read_exact(&mut body).await?;
-                let msg = String::from_utf8(body)
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            if let Some(len) = parse_content_length(trimmed)? {
+                let msg = read_frame(&mut self.reader, len, self.max_frame_bytes).await?;
                 return Ok(Some(msg));
             }
 
@@ -87,6 +269,70 @@ impl<R: AsyncRead + Unpin> UpstreamReader<R> {
     }
 }
 
+/// The length declared by a `Content-Length:` header line, or `None` if
+/// `line` is not one.
+fn parse_content_length(line: &str) -> io::Result<Option<usize>> {
+    let Some(rest) = line.trim().strip_prefix("Content-Length:") else {
+        return Ok(None);
+    };
+    rest.trim()
+        .parse()
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Read the rest of a Content-Length frame after its `Content-Length` line:
+/// any further headers, the blank separator line, and a `len`-byte body.
+///
+/// A body over `max_frame_bytes` (`0` = unbounded) is skipped without being
+/// buffered, leaving the reader at the next frame.
+async fn read_frame<R: AsyncRead + Unpin>(
+    reader: &mut BufReader<R>,
+    len: usize,
+    max_frame_bytes: usize,
+) -> io::Result<String> {
+    // Read until blank line (consume \r\n\r\n separator)
+    let mut header = String::new();
+    loop {
+        header.clear();
+        let header_n = reader.read_line(&mut header).await?;
+        if header_n == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "EOF in Content-Length headers",
+            ));
+        }
+        if header.trim().is_empty() {
+            break;
+        }
+        // Skip other headers (e.g. Content-Type)
+    }
+
+    // Check the declared size before allocating; skip the body so the
+    // stream stays in sync for the next frame.
+    if max_frame_bytes != 0 && len > max_frame_bytes {
+        let mut body = reader.take(len as u64);
+        tokio::io::copy(&mut body, &mut tokio::io::sink()).await?;
+        return Err(FramingError::FrameTooLarge {
+            declared: len,
+            max: max_frame_bytes,
+        }
+        .into_io());
+    }
+
+    // Read `len` bytes of body as they arrive
+    let mut body = Vec::with_capacity(len);
+    reader.take(len as u64).read_to_end(&mut body).await?;
+    if body.len() < len {
+        return Err(FramingError::TruncatedFrame {
+            declared: len,
+            received: body.len(),
+        }
+        .into_io());
+    }
+    String::from_utf8(body).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
 /// Write a JSON message in newline-delimited format to the given writer.
 ///
 /// Appends `\n` and flushes. The `json` string must not contain embedded newlines.
@@ -115,6 +361,29 @@ pub fn encode_content_length(json: &str) -> Vec<u8> {
     buf
 }
 
+/// Write a JSON message to the child's stdin using the configured framing.
+///
+/// [`DownstreamFraming::Newline`] delegates to [`write_newline_delimited`];
+/// [`DownstreamFraming::ContentLength`] writes [`encode_content_length`] output.
+/// Either way the writer is flushed.
+///
+/// # Errors
+///
+/// Returns an I/O error if writing or flushing fails.
+pub async fn write_child_message<W: AsyncWrite + Unpin + ?Sized>(
+    writer: &mut W,
+    json: &str,
+    framing: DownstreamFraming,
+) -> io::Result<()> {
+    match framing {
+        DownstreamFraming::Newline => write_newline_delimited(writer, json).await,
+        DownstreamFraming::ContentLength => {
+            writer.write_all(&encode_content_length(json)).await?;
+            writer.flush().await
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +447,21 @@ mod tests {
         assert_eq!(buf, b"{\"id\":1}\n");
     }
 
+    #[tokio::test]
+    async fn test_write_child_message_uses_configured_framing() {
+        let mut buf = Vec::new();
+        write_child_message(&mut buf, r#"{"id":1}"#, DownstreamFraming::Newline)
+            .await
+            .unwrap();
+        assert_eq!(buf, b"{\"id\":1}\n");
+
+        let mut buf = Vec::new();
+        write_child_message(&mut buf, r#"{"id":1}"#, DownstreamFraming::ContentLength)
+            .await
+            .unwrap();
+        assert_eq!(buf, b"Content-Length: 8\r\n\r\n{\"id\":1}");
+    }
+
     #[tokio::test]
     async fn test_content_length_frame_roundtrip() {
         let original = r#"{"jsonrpc":"2.0","id":99,"method":"ping"}"#;
@@ -194,6 +478,132 @@ mod tests {
         assert!(reader.next_message().await.unwrap().is_none());
     }
 
+    #[tokio::test]
+    async fn test_child_reader_skips_oversized_line() {
+        let huge = format!("{{\"id\":1,\"result\":\"{}\"}}", "x".repeat(64 * 1024));
+        let input = format!("{{\"id\":0}}\r\n{huge}\n{{\"id\":2}}");
+        let mut reader = ChildLineReader::new(input.as_bytes(), 1024);
+        assert_eq!(
+            reader.next_line().await.unwrap(),
+            Some(ChildLine::Line("{\"id\":0}".to_string()))
+        );
+        assert_eq!(
+            reader.next_line().await.unwrap(),
+            Some(ChildLine::Oversized {
+                len: huge.len(),
+                head: huge[..OVERSIZED_HEAD_BYTES].to_string(),
+            })
+        );
+        assert!(
+            reader.buf.capacity() <= 1024 * 2,
+            "oversized line was buffered"
+        );
+        assert_eq!(
+            reader.next_line().await.unwrap(),
+            Some(ChildLine::Line("{\"id\":2}".to_string()))
+        );
+        assert!(reader.next_line().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_child_reader_parses_content_length_frames() {
+        let first = r#"{"jsonrpc":"2.0","id":1,"result":{}}"#;
+        let huge = format!("{{\"id\":2,\"result\":\"{}\"}}", "x".repeat(4096));
+        let mut input = encode_content_length(first);
+        input.extend(encode_content_length(&huge));
+        input.extend(b"{\"id\":3}\n");
+        let mut reader =
+            ChildLineReader::new(&input[..], 1024).with_framing(DownstreamFraming::ContentLength);
+        assert_eq!(
+            reader.next_line().await.unwrap(),
+            Some(ChildLine::Line(first.to_string()))
+        );
+        assert_eq!(
+            reader.next_line().await.unwrap(),
+            Some(ChildLine::Oversized {
+                len: huge.len(),
+                head: String::new(),
+            })
+        );
+        assert_eq!(
+            reader.next_line().await.unwrap(),
+            Some(ChildLine::Line("{\"id\":3}".to_string()))
+        );
+        assert!(reader.next_line().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_child_reader_zero_cap_is_unbounded() {
+        let huge = "y".repeat(64 * 1024);
+        let input = format!("{huge}\n");
+        let mut reader = ChildLineReader::new(input.as_bytes(), 0);
+        assert_eq!(
+            reader.next_line().await.unwrap(),
+            Some(ChildLine::Line(huge))
+        );
+        assert!(reader.next_line().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_oversized_content_length_rejected_before_allocating() {
+        let declared = 20 * 1024 * 1024;
+        let next = r#"{"jsonrpc":"2.0","id":5}"#;
+        // Far fewer body bytes than declared: reading the body first would
+        // surface as a truncated frame instead.
+        let framed = format!(
+            "Content-Length: {declared}\r\n\r\n{{\"id\":4}}\n{}",
+            String::from_utf8(encode_content_length(next)).unwrap()
+        );
+        let mut reader = UpstreamReader::new(framed.as_bytes());
+        let err = reader.next_message().await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            FramingError::from_io(&err),
+            Some(&FramingError::FrameTooLarge {
+                declared,
+                max: DEFAULT_MAX_FRAME_BYTES,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oversized_frame_is_skipped_and_reader_resyncs() {
+        let big = r#"{"jsonrpc":"2.0","id":1,"params":"xxxxxxxxxxxxxxxx"}"#;
+        let small = r#"{"jsonrpc":"2.0","id":2}"#;
+        let mut input = encode_content_length(big);
+        input.extend(encode_content_length(small));
+        let mut reader = UpstreamReader::new(&input[..]).with_max_frame_bytes(32);
+        let err = reader.next_message().await.unwrap_err();
+        assert!(matches!(
+            FramingError::from_io(&err),
+            Some(FramingError::FrameTooLarge { max: 32, .. })
+        ));
+        assert_eq!(reader.next_message().await.unwrap().unwrap(), small);
+    }
+
+    #[tokio::test]
+    async fn test_truncated_content_length_frame() {
+        let framed = b"Content-Length: 100\r\n\r\n{\"id\":1}";
+        let mut reader = UpstreamReader::new(&framed[..]);
+        let err = reader.next_message().await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            FramingError::from_io(&err),
+            Some(&FramingError::TruncatedFrame {
+                declared: 100,
+                received: 8,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_zero_max_frame_bytes_is_unbounded() {
+        let body = format!("{{\"id\":1,\"p\":\"{}\"}}", "z".repeat(4096));
+        let encoded = encode_content_length(&body);
+        let mut reader = UpstreamReader::new(&encoded[..]).with_max_frame_bytes(0);
+        assert_eq!(reader.next_message().await.unwrap().unwrap(), body);
+    }
+
     #[tokio::test]
     async fn test_blank_lines_skipped() {
         let input = b"\n\n{\"id\":1}\n\n";
@@ -12,6 +12,8 @@
 //! - If `developer-instructions` is absent, it is **set** to the context
 //!   block.
 //! - `base-instructions` is **never touched** (FR-2.3).
+//! - A `codex-reply` may opt out with `omit_context: true` (FR-2.9); the
+//!   argument is proxy-only and stripped before forwarding.
 
 use serde_json::Value;
 
@@ -106,6 +108,33 @@ pub fn inject_developer_instructions(params: &mut Value, context: &str) {
     }
 }
 
+/// Proxy-only `codex-reply` argument that skips context injection for that
+/// turn (FR-2.9).
+pub const OMIT_CONTEXT_ARG: &str = "omit_context";
+
+/// Remove [`OMIT_CONTEXT_ARG`] from `params`, returning `true` when it was
+/// set to boolean `true`.
+///
+/// The argument is always removed so it never reaches the Codex child.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use atm_agent_mcp::inject::take_omit_context;
+///
+/// let mut params = json!({"prompt": "continue", "omit_context": true});
+/// assert!(take_omit_context(&mut params));
+/// assert!(params.get("omit_context").is_none());
+/// ```
+pub fn take_omit_context(params: &mut Value) -> bool {
+    params
+        .as_object_mut()
+        .and_then(|obj| obj.remove(OMIT_CONTEXT_ARG))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,6 +230,19 @@ mod tests {
         assert_eq!(params["developer-instructions"], "ctx");
     }
 
+    // ─── take_omit_context ───────────────────────────────────────────────────
+
+    #[test]
+    fn take_omit_context_strips_argument() {
+        let mut params = json!({"prompt": "hi", "omit_context": false});
+        assert!(!take_omit_context(&mut params));
+        assert!(params.get("omit_context").is_none());
+
+        let mut params = json!({"prompt": "hi"});
+        assert!(!take_omit_context(&mut params));
+        assert_eq!(params, json!({"prompt": "hi"}));
+    }
+
     #[test]
     fn inject_multiple_times_appends_each_time() {
         let mut params = json!({});
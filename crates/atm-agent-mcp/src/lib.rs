@@ -6,6 +6,7 @@
 
 pub mod atm_tools;
 pub mod audit;
+pub mod channel_metrics;
 pub mod cli;
 pub mod commands;
 pub mod config;
@@ -29,7 +30,10 @@ pub mod turn_control;
 pub mod watch_stream;
 
 #[doc(inline)]
-pub use transport::{MockTransport, MockTransportHandle, RawChildIo};
+pub use transport::{
+    MockTransport, MockTransportHandle, RawChildIo, RecordDirection, RecordedChunk,
+    RecordingTransport, ReplayTransport,
+};
 
 /// Test-only helpers for exercising transport factory logic from integration tests.
 ///
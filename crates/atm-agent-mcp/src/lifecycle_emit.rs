@@ -134,7 +134,11 @@ async fn emit_lifecycle_event_unix(
     let socket_path = agent_team_mail_core::daemon_client::daemon_socket_path()?;
 
     // Attempt connection — return early (without error) if daemon not running.
-    let stream = match UnixStream::connect(&socket_path).await {
+    let stream = match agent_team_mail_core::daemon_client::connect_daemon_socket(&socket_path)
+        .and_then(|s| {
+            s.set_nonblocking(true)?;
+            UnixStream::from_std(s)
+        }) {
         Ok(s) => s,
         Err(_) => {
             // Daemon not running — this is the expected steady state in CI
@@ -20,10 +20,12 @@
 //! idle mail polling, delivery ack boundary, single-flight enforcement.
 
 use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
 use std::process::ExitStatus;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
+use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
 use serde_json::{Value, json};
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::process::Child;
@@ -32,15 +34,20 @@ use tokio::time::{Duration, timeout};
 use tracing::Instrument;
 
 use crate::audit::AuditLog;
-use crate::config::AgentMcpConfig;
+use crate::channel_metrics::ChannelMetrics;
+use crate::config::{AgentMcpConfig, DownstreamFraming, MailInjectionRole};
 use crate::context::detect_context;
 use crate::elicitation::ElicitationRegistry;
-use crate::framing::{UpstreamReader, write_newline_delimited};
-use crate::inject::{build_session_context, inject_developer_instructions};
+use crate::framing::{
+    ChildLine, ChildLineReader, FramingError, UpstreamReader, write_child_message,
+    write_newline_delimited,
+};
+use crate::inject::{build_session_context, inject_developer_instructions, take_omit_context};
 use crate::lifecycle::{ThreadCommand, ThreadCommandQueue};
 use crate::lock::{acquire_lock, check_lock, release_lock};
 use crate::mail_inject::{
-    InflightMailSet, MailPoller, fetch_unread_mail, format_mail_turn_content, mark_messages_read,
+    InflightMailSet, MailBackoff, MailPoller, fetch_unread_mail_coalesced,
+    format_mail_turn_content, mark_messages_read,
 };
 use crate::session::{RegistryError, SessionRegistry, SessionStatus, ThreadState};
 use crate::tools::synthetic_tools;
@@ -55,13 +62,19 @@ use crate::watch_stream::{SourceEnvelope, WatchStreamHub, WatchSubscription, bui
 /// allows the value to be populated lazily when the child is first spawned.
 type SharedChildStdin = Arc<Mutex<Option<Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>>>>>;
 
-/// Channel buffer capacity for upstream message delivery.
-///
-/// Sized to handle burst of MCP responses without backpressure.
-const UPSTREAM_CHANNEL_CAPACITY: usize = 256;
-
 /// Grace period in ms after dropping child stdin before force-kill, giving child time to flush output.
 const CHILD_DRAIN_GRACE_MS: u64 = 100;
+/// Backoff before the first automatic child respawn; doubles per attempt.
+const CHILD_RESTART_BACKOFF_BASE_MS: u64 = 250;
+/// Upper bound on the backoff between automatic child respawns.
+const CHILD_RESTART_BACKOFF_MAX_MS: u64 = 8_000;
+/// Built-in prompt used to request a shutdown summary (FR-7.1).
+const DEFAULT_SHUTDOWN_SUMMARY_PROMPT: &str = "\
+Session ending. Write a concise summary of:\n\
+- What you were working on\n\
+- Current state \u{2014} what is done, what is not\n\
+- Any open questions or blockers\n\
+- Next steps if resumed";
 /// Maximum rendered watch line length retained in TUI feed records.
 const WATCH_RENDER_MAX_CHARS: usize = 200;
 /// Truncated prefix length before appending ellipsis (`...`).
@@ -74,6 +87,8 @@ const WATCH_FEED_MAX_BYTES: u64 = 10 * 1024 * 1024;
 static WATCH_UNKNOWN_EVENT_COUNT: AtomicU64 = AtomicU64::new(0);
 /// Periodic flush interval for dropped/unknown stream counters.
 const WATCH_COUNTER_REPORT_INTERVAL_SECS: u64 = 60;
+/// Upper bound on the interval between stale-session TTL sweeps.
+const STALE_SESSION_SWEEP_MAX_SECS: u64 = 60;
 #[cfg(test)]
 static STREAM_ERROR_EMIT_ATTEMPTS: AtomicU64 = AtomicU64::new(0);
 
@@ -120,12 +135,31 @@ pub const ERR_AGENT_FILE_NOT_FOUND: i64 = -32008;
 /// was not provided via the `identity` argument or proxy config (FR-8.x).
 pub const ERR_IDENTITY_REQUIRED: i64 = -32009;
 
+/// JSON-RPC error code: the in-flight turn was cancelled by `agent_interrupt`.
+pub const ERR_TURN_INTERRUPTED: i64 = -32010;
+
+/// JSON-RPC error code: the per-turn `model` argument is not in the
+/// configured `allowed_models` list.
+pub const ERR_MODEL_NOT_ALLOWED: i64 = -32011;
+
+/// JSON-RPC error code: an ATM tool targeted a team other than the proxy's
+/// team while `allow_cross_team` is disabled.
+pub const ERR_CROSS_TEAM_DENIED: i64 = -32012;
+
+/// JSON-RPC error code: the resolved `cwd` argument falls outside every
+/// directory in the configured `restrict_cwd_to` list.
+pub const ERR_CWD_NOT_ALLOWED: i64 = -32013;
+
 /// Manages the MCP proxy lifecycle: upstream I/O, child process, and message routing.
 pub struct ProxyServer {
     config: AgentMcpConfig,
     child: Option<ChildHandle>,
     /// Counter of event notifications dropped due to backpressure.
     pub dropped_events: Arc<AtomicU64>,
+    /// Occupancy of the channel carrying messages to the upstream client.
+    pub upstream_channel_metrics: Arc<ChannelMetrics>,
+    /// Occupancy of the channel carrying Codex child output to the proxy.
+    pub child_channel_metrics: Arc<ChannelMetrics>,
     /// In-memory session registry shared with per-request tasks.
     registry: Arc<Mutex<SessionRegistry>>,
     /// Registry of pending elicitation/create requests bridged upstream (FR-18).
@@ -151,6 +185,8 @@ pub struct ProxyServer {
     queues: Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<ThreadCommandQueue>>>>>,
     /// Mail polling configuration derived from [`AgentMcpConfig`] (FR-8.2).
     mail_poller: MailPoller,
+    /// Per-agent idle poll backoff (FR-8.15), reset by turn completions.
+    mail_backoff: Arc<Mutex<MailBackoff>>,
     /// Monotonically increasing counter for auto-generated request IDs.
     request_counter: Arc<AtomicU64>,
     /// Shared reference to the child stdin writer.
@@ -170,6 +206,35 @@ pub struct ProxyServer {
     /// Stored as a trait object so Sprint C.2b can inject `MockTransport`
     /// without modifying `ProxyServer`.
     transport: Box<dyn CodexTransport>,
+    /// Signalled by the child wait task when the child exits unexpectedly.
+    ///
+    /// Replaced with a live channel at the start of [`Self::run`].
+    child_crash_tx: mpsc::UnboundedSender<()>,
+    /// Set before the proxy closes the child itself so the wait task does
+    /// not treat the exit as a crash.
+    child_shutting_down: Arc<AtomicBool>,
+    /// Automatic respawn bookkeeping (`max_child_restarts`).
+    child_restarts: ChildRestarts,
+    /// When the next scheduled respawn attempt is due; awaited as a branch
+    /// of the [`Self::run`] loop so the backoff never blocks it.
+    child_restart_at: Option<tokio::time::Instant>,
+    /// Whether upstream has sent `notifications/initialized`; replayed to a
+    /// respawned child.
+    upstream_initialized: bool,
+    /// Whether the current child has already received
+    /// `notifications/initialized`; repeats from upstream are suppressed.
+    child_initialized: bool,
+}
+
+/// Automatic child respawn counters for the proxy's lifetime.
+#[derive(Debug, Default, Clone, Copy)]
+struct ChildRestarts {
+    /// Respawn attempts made, counted against `max_child_restarts`.
+    attempts: u32,
+    /// Attempts that produced a running child.
+    succeeded: u64,
+    /// Attempts whose spawn failed.
+    failed: u64,
 }
 
 impl std::fmt::Debug for ProxyServer {
@@ -246,6 +311,14 @@ pub(crate) struct PendingRequests {
     /// Last-known source envelope for each agent_id, used as fallback when
     /// child events do not carry a request-id correlation token.
     last_agent_source: HashMap<String, SourceEnvelope>,
+    /// Request IDs for in-flight `codex`/`codex-reply` turns mapped to the
+    /// `agent_id` whose thread is running them (used by `agent_interrupt`).
+    turn_agents: HashMap<Value, String>,
+    /// In-flight `codex-reply` requests mapped to the message forwarded to the
+    /// child, so they can be re-sent to a respawned child.
+    retryable: HashMap<Value, Value>,
+    /// Auto-mail turns dispatched to the child over the proxy's lifetime.
+    auto_mail_turns: u64,
 }
 
 impl PendingRequests {
@@ -257,6 +330,9 @@ impl PendingRequests {
             auto_mail_pending: HashMap::new(),
             request_sources: HashMap::new(),
             last_agent_source: HashMap::new(),
+            turn_agents: HashMap::new(),
+            retryable: HashMap::new(),
+            auto_mail_turns: 0,
         }
     }
 
@@ -275,9 +351,76 @@ impl PendingRequests {
     fn complete(&mut self, id: &Value) -> Option<oneshot::Sender<Value>> {
         self.tools_list_ids.remove(id);
         self.request_sources.remove(id);
+        self.turn_agents.remove(id);
+        self.retryable.remove(id);
         self.map.remove(id)
     }
 
+    /// Remember the forwarded message for a request that may be replayed
+    /// against a respawned child.
+    fn mark_retryable(&mut self, id: Value, forwarded: Value) {
+        self.retryable.insert(id, forwarded);
+    }
+
+    /// Forwarded messages of replayable requests that are still pending.
+    fn retryable_messages(&self) -> Vec<Value> {
+        self.retryable
+            .iter()
+            .filter(|(id, _)| self.map.contains_key(*id))
+            .map(|(_, msg)| msg.clone())
+            .collect()
+    }
+
+    /// Remove replayable requests, returning their IDs and response senders.
+    fn take_retryable(&mut self) -> Vec<(Value, oneshot::Sender<Value>)> {
+        let ids: Vec<Value> = self.retryable.drain().map(|(id, _)| id).collect();
+        ids.into_iter()
+            .filter_map(|id| {
+                self.request_sources.remove(&id);
+                self.turn_agents.remove(&id);
+                self.map.remove(&id).map(|tx| (id, tx))
+            })
+            .collect()
+    }
+
+    /// Cancel pending requests after the child exits.
+    ///
+    /// With `keep_retryable`, replayable requests stay pending so they can be
+    /// re-sent once the child is respawned.
+    fn clear_on_child_exit(&mut self, keep_retryable: bool) {
+        if keep_retryable {
+            let retryable = &self.retryable;
+            self.map.retain(|id, _| retryable.contains_key(id));
+            self.request_sources
+                .retain(|id, _| retryable.contains_key(id));
+        } else {
+            self.map.clear();
+            self.retryable.clear();
+            self.request_sources.clear();
+        }
+        self.codex_create_ids.clear();
+        self.auto_mail_pending.clear();
+        self.last_agent_source.clear();
+    }
+
+    fn mark_turn(&mut self, id: Value, agent_id: String) {
+        self.turn_agents.insert(id, agent_id);
+    }
+
+    fn clear_turn(&mut self, id: &Value) {
+        self.turn_agents.remove(id);
+    }
+
+    /// Request ID of the turn currently in flight for `agent_id`, including
+    /// proxy-initiated auto-mail turns.
+    fn in_flight_turn_for_agent(&self, agent_id: &str) -> Option<Value> {
+        self.turn_agents
+            .iter()
+            .chain(self.auto_mail_pending.iter())
+            .find(|(_, aid)| aid.as_str() == agent_id)
+            .map(|(id, _)| id.clone())
+    }
+
     fn mark_codex_create(&mut self, id: Value, agent_id: String) {
         self.codex_create_ids.insert(id, agent_id);
     }
@@ -292,9 +435,21 @@ impl PendingRequests {
 
     /// Register an auto-mail turn's request ID with its owning agent_id.
     fn mark_auto_mail(&mut self, id: Value, agent_id: String) {
+        self.auto_mail_turns += 1;
         self.auto_mail_pending.insert(id, agent_id);
     }
 
+    /// Count an auto-mail turn that is not tracked by request ID
+    /// (app-server `turn/start` / `turn/steer`).
+    fn count_auto_mail_turn(&mut self) {
+        self.auto_mail_turns += 1;
+    }
+
+    /// Upstream requests still awaiting a child response.
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
     /// Take the agent_id for a completed auto-mail turn, removing it from the map.
     fn take_auto_mail(&mut self, id: &Value) -> Option<String> {
         self.auto_mail_pending.remove(id)
@@ -344,12 +499,23 @@ impl ProxyServer {
         // Elicitation default timeout: 30 seconds (FR-18).
         const ELICITATION_TIMEOUT_SECS: u64 = 30;
         let mail_poller = MailPoller::new(&config);
-        let audit_log = AuditLog::new(&team_str);
+        let audit_log =
+            AuditLog::new(&team_str).with_rotation(config.max_audit_bytes, config.max_audit_files);
         let transport = make_transport(&config, &team_str);
+        let upstream_channel_metrics = Arc::new(ChannelMetrics::new(
+            "upstream",
+            config.upstream_channel_capacity.max(1),
+        ));
+        let child_channel_metrics = Arc::new(ChannelMetrics::new(
+            "child",
+            config.child_channel_capacity.max(1),
+        ));
         Self {
             config,
             child: None,
             dropped_events: Arc::new(AtomicU64::new(0)),
+            upstream_channel_metrics,
+            child_channel_metrics,
             registry: Arc::new(Mutex::new(registry)),
             elicitation_registry: Arc::new(Mutex::new(ElicitationRegistry::new(
                 ELICITATION_TIMEOUT_SECS,
@@ -362,12 +528,19 @@ impl ProxyServer {
             started_at,
             started_epoch_secs,
             queues: Arc::new(Mutex::new(HashMap::new())),
+            mail_backoff: Arc::new(Mutex::new(mail_poller.backoff())),
             mail_poller,
             request_counter: Arc::new(AtomicU64::new(1)),
             shared_child_stdin: Arc::new(Mutex::new(None)),
             audit_log,
             resume_context: None,
             transport,
+            child_crash_tx: mpsc::unbounded_channel().0,
+            child_shutting_down: Arc::new(AtomicBool::new(false)),
+            child_restarts: ChildRestarts::default(),
+            child_restart_at: None,
+            upstream_initialized: false,
+            child_initialized: false,
         }
     }
 
@@ -397,56 +570,118 @@ impl ProxyServer {
         proxy
     }
 
+    /// Resolve the identity for a new session (FR-20.6): explicit →
+    /// next free `identity_pool` entry → `config.identity` → `"codex"`.
+    ///
+    /// A pool identity is free when no active session in this proxy is bound
+    /// to it and no other live process holds its lock. When the whole pool is
+    /// taken the first entry is returned so the caller reports the usual
+    /// identity conflict.
+    async fn resolve_session_identity(&self, explicit: Option<String>) -> String {
+        if let Some(identity) = explicit {
+            return identity;
+        }
+        if let Some(first) = self.config.identity_pool.first() {
+            for candidate in &self.config.identity_pool {
+                let bound = self
+                    .registry
+                    .lock()
+                    .await
+                    .find_by_identity(candidate)
+                    .is_some();
+                if !bound && check_lock(&self.team, candidate).await.is_none() {
+                    return candidate.clone();
+                }
+            }
+            return first.clone();
+        }
+        self.config
+            .identity
+            .clone()
+            .unwrap_or_else(|| "codex".to_string())
+    }
+
     /// Persist the current registry snapshot to disk atomically (FR-5.5).
     ///
-    /// Writes a temporary file alongside the target path, then renames it to
-    /// the target, ensuring readers always see a complete file.  Parent
-    /// directories are created on demand.
+    /// Holds an exclusive lock on `registry.json.lock` (shared with other
+    /// proxies for the same team) while it merges in any sessions they have
+    /// persisted (see [`RegistrySnapshot::merge_concurrent`]), writes a
+    /// temporary file alongside the target path, and renames it over the
+    /// target, so concurrent proxies never clobber each other's records and
+    /// readers always see a complete file. Parent directories are created on
+    /// demand.
+    ///
+    /// [`RegistrySnapshot::merge_concurrent`]: crate::session::RegistrySnapshot::merge_concurrent
     ///
     /// # Errors
     ///
-    /// Returns an error when I/O fails (permissions, disk full, etc.).
+    /// Returns an error when the lock stays contended after `lock_retries`
+    /// attempts or when I/O fails (permissions, disk full, etc.).
     async fn persist_registry(
         registry: &Arc<Mutex<SessionRegistry>>,
         sessions_path: &std::path::Path,
+        lock_retries: u32,
     ) -> anyhow::Result<()> {
-        use crate::session::RegistrySnapshot;
-        use tokio::fs;
-        use tokio::io::AsyncWriteExt;
-
-        let snapshot: RegistrySnapshot = {
+        let snapshot = {
             let guard = registry.lock().await;
             guard.to_snapshot()
         };
-        let json = serde_json::to_vec_pretty(&snapshot)?;
+        let sessions_path = sessions_path.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            Self::write_registry_locked(snapshot, &sessions_path, lock_retries)
+        })
+        .await?
+    }
+
+    /// Blocking half of [`Self::persist_registry`]: lock, merge, write, rename.
+    fn write_registry_locked(
+        mut snapshot: crate::session::RegistrySnapshot,
+        sessions_path: &std::path::Path,
+        lock_retries: u32,
+    ) -> anyhow::Result<()> {
+        use crate::session::RegistrySnapshot;
+        use std::io::Write;
 
-        // Ensure parent directory exists.
         if let Some(parent) = sessions_path.parent() {
-            fs::create_dir_all(parent).await?;
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let lock_path = sessions_path.with_extension("json.lock");
+        let _lock = agent_team_mail_core::io::lock::acquire_lock(&lock_path, lock_retries)?;
+
+        if let Ok(contents) = std::fs::read_to_string(sessions_path)
+            && let Ok(on_disk) = RegistrySnapshot::from_json(&contents)
+        {
+            let merged = snapshot.merge_concurrent(on_disk);
+            if merged > 0 {
+                tracing::debug!(
+                    path = %sessions_path.display(),
+                    merged,
+                    "merged sessions persisted by a concurrent proxy"
+                );
+            }
         }
+        let json = serde_json::to_vec_pretty(&snapshot)?;
 
         // Write to a temp file alongside the target, then rename for atomicity.
         let tmp_path = sessions_path.with_extension("json.tmp");
         {
-            let mut file = fs::OpenOptions::new()
-                .create(true)
-                .write(true)
-                .truncate(true)
-                .open(&tmp_path)
-                .await?;
-            file.write_all(&json).await?;
-            file.flush().await?;
+            let mut file = std::fs::File::create(&tmp_path)?;
+            file.write_all(&json)?;
+            file.flush()?;
         }
-        fs::rename(&tmp_path, sessions_path).await?;
+        std::fs::rename(&tmp_path, sessions_path)?;
         Ok(())
     }
 
     /// Load a persisted registry file and mark any `Active` sessions as
     /// [`crate::session::SessionStatus::Stale`].
     ///
-    /// If the file does not exist or cannot be parsed, returns the registry
-    /// unchanged (fresh start). This satisfies FR-3.2's requirement to mark
-    /// prior active sessions as stale on proxy startup.
+    /// Older schema versions are migrated on load (see
+    /// [`RegistrySnapshot::from_json`]). If the file does not exist or is not
+    /// valid JSON, returns the registry unchanged (fresh start). This satisfies
+    /// FR-3.2's requirement to mark prior active sessions as stale on proxy
+    /// startup.
     fn load_stale_from_disk(registry: SessionRegistry, team: &str) -> SessionRegistry {
         use crate::lock::sessions_dir;
         use crate::session::RegistrySnapshot;
@@ -456,7 +691,7 @@ impl ProxyServer {
             Ok(c) => c,
             Err(_) => return registry, // file absent — fresh start
         };
-        let snapshot = match serde_json::from_str::<RegistrySnapshot>(&contents) {
+        let snapshot = match RegistrySnapshot::from_json(&contents) {
             Ok(s) => s,
             Err(e) => {
                 tracing::warn!(
@@ -476,20 +711,143 @@ impl ProxyServer {
         loaded
     }
 
+    /// Spawn the idle mail poller (FR-8.2).
+    ///
+    /// Checks all idle sessions for unread mail at the configured interval and
+    /// injects auto-mail turns via the shared child stdin reference. Agents
+    /// that keep coming up empty are polled less often (FR-8.15). Returns
+    /// `None` without spawning anything when auto-mail is disabled.
+    fn spawn_idle_mail_poller(
+        &self,
+        pending: &Arc<Mutex<PendingRequests>>,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        if !self.mail_poller.is_enabled() {
+            return None;
+        }
+        let poll_interval = self.mail_poller.poll_interval;
+        let max_messages = self.mail_poller.max_messages;
+        let max_message_length = self.mail_poller.max_message_length;
+        let injection_role = self.mail_poller.injection_role;
+        let coalesce_window = self.mail_poller.coalesce_window;
+        let downstream_framing = self.config.downstream_framing;
+        let backoff_bg = Arc::clone(&self.mail_backoff);
+        let registry_bg = Arc::clone(&self.registry);
+        let queues_bg = Arc::clone(&self.queues);
+        let team_bg = self.team.clone();
+        let request_counter_bg = Arc::clone(&self.request_counter);
+        let auto_mail_overrides = self.mail_poller.clone();
+        let shared_stdin_bg = Arc::clone(&self.shared_child_stdin);
+        let pending_bg = Arc::clone(pending);
+
+        Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(poll_interval);
+            loop {
+                let tick_at = interval.tick().await;
+                // Coalescing never holds a batch past the next tick (FR-8.16).
+                let next_tick = tick_at + poll_interval;
+
+                // Collect idle active sessions
+                let idle_sessions: Vec<(String, String, Option<String>)> = {
+                    let reg = registry_bg.lock().await;
+                    reg.list_all()
+                        .iter()
+                        .filter(|e| {
+                            e.status == SessionStatus::Active && e.thread_state == ThreadState::Idle
+                        })
+                        .map(|e| (e.agent_id.clone(), e.identity.clone(), e.thread_id.clone()))
+                        .collect()
+                };
+
+                // Sessions that are busy or gone start over at the base interval.
+                backoff_bg
+                    .lock()
+                    .await
+                    .retain(|id| idle_sessions.iter().any(|(agent_id, _, _)| agent_id == id));
+
+                for (agent_id, identity, thread_id_opt) in idle_sessions {
+                    // Per-thread and per-identity overrides (FR-8.8, FR-8.8a)
+                    if !auto_mail_overrides.is_enabled_for(&agent_id, &identity) {
+                        continue;
+                    }
+
+                    let Some(ref thread_id) = thread_id_opt else {
+                        continue;
+                    };
+
+                    if !backoff_bg.lock().await.should_poll(&agent_id) {
+                        continue;
+                    }
+
+                    // Fix 5: Delegate directly to dispatch_auto_mail_if_available
+                    // which handles priority checking (ClaudeReply > AutoMailInject),
+                    // single-flight guard, write, pending registration, and mark-read.
+                    // This avoids the previous push_auto_mail + inline dispatch
+                    // inconsistency where a queue entry was never popped.
+                    let coalesce_deadline = (!coalesce_window.is_zero())
+                        .then(|| (tokio::time::Instant::now() + coalesce_window).min(next_tick));
+                    dispatch_auto_mail_if_available(
+                        &agent_id,
+                        &identity,
+                        thread_id,
+                        &team_bg,
+                        max_messages,
+                        max_message_length,
+                        injection_role,
+                        downstream_framing,
+                        &registry_bg,
+                        &queues_bg,
+                        &shared_stdin_bg,
+                        &pending_bg,
+                        &request_counter_bg,
+                        None,
+                        None,
+                        coalesce_deadline,
+                    )
+                    .await;
+
+                    // A dispatched turn leaves the thread Busy; still Idle means
+                    // there was no mail to inject.
+                    let still_idle = registry_bg
+                        .lock()
+                        .await
+                        .get(&agent_id)
+                        .is_some_and(|e| e.thread_state == ThreadState::Idle);
+                    let mut backoff = backoff_bg.lock().await;
+                    if still_idle {
+                        backoff.record_empty(&agent_id);
+                    } else {
+                        backoff.reset(&agent_id);
+                    }
+                }
+            }
+        }))
+    }
+
     /// Run the proxy loop, reading from `upstream_in` and writing to `upstream_out`.
     ///
     /// This is the main entry point. It blocks until upstream EOF or a fatal error.
     ///
     /// # Errors
     ///
-    /// Returns an error on unrecoverable I/O failures. Transient errors (child crash,
+    /// Returns an error on unrecoverable I/O failures, or after
+    /// `max_consecutive_parse_errors` unparseable upstream messages in a row (the
+    /// proxy still shuts down gracefully first). Transient errors (child crash,
     /// timeout) are reported as JSON-RPC error responses to the upstream client.
     pub async fn run<R, W>(&mut self, upstream_in: R, mut upstream_out: W) -> anyhow::Result<()>
     where
         R: AsyncRead + Unpin + Send + 'static,
         W: AsyncWrite + Unpin + Send + 'static,
     {
-        let mut reader = UpstreamReader::new(upstream_in);
+        let banner = proxy_start_event(&self.config, &self.team);
+        tracing::debug!(
+            team = %self.team,
+            config = %banner.extra_fields["config"],
+            "proxy starting"
+        );
+        emit_event_best_effort(banner);
+
+        let mut reader = UpstreamReader::new(upstream_in)
+            .with_max_frame_bytes(self.config.max_upstream_frame_bytes);
         let pending = Arc::new(Mutex::new(PendingRequests::new()));
         let dropped = Arc::clone(&self.dropped_events);
         let thread_to_agent = Arc::clone(&self.thread_to_agent);
@@ -497,19 +855,43 @@ impl ProxyServer {
 
         // Channel for upstream writes (events + responses routed through the channel).
         // Bounded to prevent unbounded memory growth under backpressure.
-        let (upstream_tx, mut upstream_rx) = mpsc::channel::<Value>(UPSTREAM_CHANNEL_CAPACITY);
+        let upstream_metrics = Arc::clone(&self.upstream_channel_metrics);
+        let (upstream_tx, mut upstream_rx) = mpsc::channel::<Value>(upstream_metrics.capacity());
+
+        // Crash notifications from the child wait task (max_child_restarts).
+        let (child_crash_tx, mut child_crash_rx) = mpsc::unbounded_channel::<()>();
+        self.child_crash_tx = child_crash_tx;
 
         // Spawn a background task that periodically expires timed-out elicitations
-        // (FR-18, every 5 seconds).
+        // and tells the child why (FR-18.4, every 5 seconds).
         {
             let elicitation_registry_bg = Arc::clone(&self.elicitation_registry);
+            let shared_stdin_bg = Arc::clone(&self.shared_child_stdin);
+            let downstream_framing = self.config.downstream_framing;
             tokio::spawn(async move {
                 let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(5));
                 loop {
                     interval.tick().await;
-                    let expired = elicitation_registry_bg.lock().await.expire_timeouts();
-                    for key in &expired {
+                    let expired = elicitation_registry_bg
+                        .lock()
+                        .await
+                        .expire_timeouts_for_downstream();
+                    if expired.is_empty() {
+                        continue;
+                    }
+                    let child_stdin = shared_stdin_bg.lock().await.clone();
+                    for (key, error) in &expired {
                         tracing::warn!("elicitation timed out: upstream_request_id={key}");
+                        let Some(ref stdin) = child_stdin else {
+                            continue;
+                        };
+                        let serialized = serde_json::to_string(error).unwrap_or_default();
+                        let mut stdin = stdin.lock().await;
+                        if let Err(e) =
+                            write_child_message(&mut *stdin, &serialized, downstream_framing).await
+                        {
+                            tracing::warn!("failed to write elicitation timeout to child: {e}");
+                        }
                     }
                 }
             });
@@ -527,77 +909,49 @@ impl ProxyServer {
             }
         });
 
-        // Spawn the idle mail poller (FR-8.2): checks all idle sessions for unread
-        // mail at the configured interval and injects auto-mail turns via the
-        // shared child stdin reference.  The JoinHandle is stored so we can
-        // abort it cleanly on shutdown.
-        let mut mail_poller_handle: Option<tokio::task::JoinHandle<()>> = None;
-        if self.mail_poller.is_enabled() {
-            let poll_interval = self.mail_poller.poll_interval;
-            let max_messages = self.mail_poller.max_messages;
-            let max_message_length = self.mail_poller.max_message_length;
+        // Periodically drop thread mappings whose session is gone or no longer
+        // active so `thread_to_agent` does not grow for the proxy's lifetime.
+        let thread_map_sweep_handle = (self.config.thread_map_sweep_interval_secs > 0).then(|| {
+            let sweep_secs = self.config.thread_map_sweep_interval_secs;
+            let thread_to_agent_bg = Arc::clone(&self.thread_to_agent);
             let registry_bg = Arc::clone(&self.registry);
-            let queues_bg = Arc::clone(&self.queues);
-            let team_bg = self.team.clone();
-            let request_counter_bg = Arc::clone(&self.request_counter);
-            let per_thread_overrides = self.config.per_thread_auto_mail.clone();
-            let shared_stdin_bg = Arc::clone(&self.shared_child_stdin);
-            let pending_bg = Arc::clone(&pending);
-
-            mail_poller_handle = Some(tokio::spawn(async move {
-                let mut interval = tokio::time::interval(poll_interval);
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(sweep_secs));
                 loop {
                     interval.tick().await;
+                    let removed = prune_thread_mappings(&thread_to_agent_bg, &registry_bg).await;
+                    if removed > 0 {
+                        tracing::debug!("pruned {removed} stale thread_to_agent mapping(s)");
+                    }
+                }
+            })
+        });
 
-                    // Collect idle active sessions
-                    let idle_sessions: Vec<(String, String, Option<String>)> = {
-                        let reg = registry_bg.lock().await;
-                        reg.list_all()
-                            .iter()
-                            .filter(|e| {
-                                e.status == SessionStatus::Active
-                                    && e.thread_state == ThreadState::Idle
-                            })
-                            .map(|e| (e.agent_id.clone(), e.identity.clone(), e.thread_id.clone()))
-                            .collect()
-                    };
-
-                    for (agent_id, identity, thread_id_opt) in idle_sessions {
-                        // Per-thread override takes precedence over global setting (FR-8.8)
-                        let enabled = per_thread_overrides.get(&agent_id).copied().unwrap_or(true);
-                        if !enabled {
-                            continue;
-                        }
-
-                        let Some(ref thread_id) = thread_id_opt else {
-                            continue;
-                        };
-
-                        // Fix 5: Delegate directly to dispatch_auto_mail_if_available
-                        // which handles priority checking (ClaudeReply > AutoMailInject),
-                        // single-flight guard, write, pending registration, and mark-read.
-                        // This avoids the previous push_auto_mail + inline dispatch
-                        // inconsistency where a queue entry was never popped.
-                        dispatch_auto_mail_if_available(
-                            &agent_id,
-                            &identity,
-                            thread_id,
-                            &team_bg,
-                            max_messages,
-                            max_message_length,
-                            &registry_bg,
-                            &queues_bg,
-                            &shared_stdin_bg,
-                            &pending_bg,
-                            &request_counter_bg,
-                            None,
-                            None,
-                        )
-                        .await;
+        // Periodically close stale sessions idle past the TTL so restarted
+        // proxies do not accumulate dead `agent_sessions` entries (FR-3.5).
+        let stale_sweep_handle = (self.config.stale_session_ttl_secs > 0).then(|| {
+            let ttl_secs = self.config.stale_session_ttl_secs;
+            let registry_bg = Arc::clone(&self.registry);
+            let team_bg = self.team.clone();
+            let lock_retries = self.config.registry_lock_retries;
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(
+                    ttl_secs.min(STALE_SESSION_SWEEP_MAX_SECS),
+                ));
+                loop {
+                    interval.tick().await;
+                    let closed =
+                        expire_stale_sessions(&registry_bg, &team_bg, ttl_secs, lock_retries).await;
+                    if closed > 0 {
+                        tracing::info!("closed {closed} stale session(s) past the {ttl_secs}s TTL");
                     }
                 }
-            }));
-        }
+            })
+        });
+
+        // Spawn the idle mail poller (FR-8.2). The JoinHandle is stored so we
+        // can abort it cleanly on shutdown.
+        let mut mail_poller_handle = self.spawn_idle_mail_poller(&pending);
 
         // Cross-platform shutdown signal handler (FR-7.1, FR-7.4).
         #[cfg(unix)]
@@ -620,7 +974,13 @@ impl ProxyServer {
         };
         tokio::pin!(shutdown_signal);
 
+        // Consecutive unparseable upstream messages; reset on any good parse.
+        let mut consecutive_parse_errors: u32 = 0;
+        // Set when the loop exits because of a fatal upstream condition.
+        let mut fatal: Option<anyhow::Error> = None;
+
         loop {
+            let child_restart_at = self.child_restart_at;
             tokio::select! {
                 // Shutdown signal received (FR-7.1)
                 _ = &mut shutdown_signal => {
@@ -630,16 +990,43 @@ impl ProxyServer {
 
                 // Read from upstream stdin
                 result = reader.next_message() => {
-                    let raw = match result? {
-                        Some(r) => r,
-                        None => {
+                    let raw = match result {
+                        Ok(Some(r)) => r,
+                        Ok(None) => {
                             tracing::info!("upstream EOF, shutting down proxy");
                             break;
                         }
+                        Err(e) => match FramingError::from_io(&e) {
+                            Some(FramingError::FrameTooLarge { declared, max }) => {
+                                tracing::warn!("rejected upstream frame: {e}");
+                                let _ = upstream_tx
+                                    .send(make_error_response(
+                                        Value::Null,
+                                        -32600,
+                                        "Invalid Request",
+                                        json!({
+                                            "error_source": "proxy",
+                                            "reason": "frame too large",
+                                            "declared_bytes": declared,
+                                            "max_frame_bytes": max,
+                                        }),
+                                    ))
+                                    .await;
+                                continue;
+                            }
+                            Some(FramingError::TruncatedFrame { .. }) => {
+                                tracing::warn!("upstream closed mid-frame ({e}), shutting down proxy");
+                                break;
+                            }
+                            None => return Err(e.into()),
+                        },
                     };
 
                     let msg: Value = match serde_json::from_str(&raw) {
-                        Ok(v) => v,
+                        Ok(v) => {
+                            consecutive_parse_errors = 0;
+                            v
+                        }
                         Err(e) => {
                             tracing::warn!("failed to parse upstream JSON: {e}");
                             let _ = upstream_tx
@@ -650,6 +1037,18 @@ impl ProxyServer {
                                     json!({"error_source": "proxy"}),
                                 ))
                                 .await;
+                            consecutive_parse_errors += 1;
+                            let limit = self.config.max_consecutive_parse_errors;
+                            if limit > 0 && consecutive_parse_errors >= limit {
+                                tracing::error!(
+                                    "{consecutive_parse_errors} consecutive upstream parse errors \
+                                     (max_consecutive_parse_errors = {limit}), shutting down proxy"
+                                );
+                                fatal = Some(anyhow::anyhow!(
+                                    "upstream sent {consecutive_parse_errors} consecutive unparseable messages"
+                                ));
+                                break;
+                            }
                             continue;
                         }
                     };
@@ -673,8 +1072,14 @@ impl ProxyServer {
                                 self.handle_initialize(id, &upstream_tx).await;
                             }
                             Some("notifications/initialized") => {
-                                // No-op when child not yet spawned; forward if child is running.
-                                if self.child.is_some() {
+                                self.upstream_initialized = true;
+                                // No-op when child not yet spawned; forward once if child is running.
+                                if self.child_initialized {
+                                    tracing::info!(
+                                        "suppressing duplicate notifications/initialized from upstream"
+                                    );
+                                } else if self.child.is_some() {
+                                    self.child_initialized = true;
                                     self.forward_to_child(msg, id, false, &pending, &upstream_tx)
                                         .await;
                                 }
@@ -695,14 +1100,26 @@ impl ProxyServer {
                                         .lock()
                                         .await
                                         .resolve_for_downstream(resp_id, msg.clone());
-                                    if let Some(downstream_resp) = maybe_downstream_resp {
+                                    let late = maybe_downstream_resp.is_none()
+                                        && self
+                                            .elicitation_registry
+                                            .lock()
+                                            .await
+                                            .take_late_response(resp_id);
+                                    if late {
+                                        // The child was already told this elicitation
+                                        // timed out; its request id is no longer pending.
+                                        tracing::warn!(
+                                            "dropping late elicitation response for id={resp_id}"
+                                        );
+                                    } else if let Some(downstream_resp) = maybe_downstream_resp {
                                         tracing::debug!("elicitation response resolved for id={resp_id}");
                                         if let Some(ref handle) = self.child {
                                             let mut stdin = handle.stdin.lock().await;
                                             let serialized = serde_json::to_string(&downstream_resp)
                                                 .unwrap_or_default();
                                             if let Err(e) =
-                                                write_newline_delimited(&mut *stdin, &serialized).await
+                                                write_child_message(&mut *stdin, &serialized, self.config.downstream_framing).await
                                             {
                                                 tracing::warn!(
                                                     "failed to write elicitation response to child: {e}"
@@ -715,7 +1132,7 @@ impl ProxyServer {
                                         let serialized =
                                             serde_json::to_string(&msg).unwrap_or_default();
                                         if let Err(e) =
-                                            write_newline_delimited(&mut *stdin, &serialized).await
+                                            write_child_message(&mut *stdin, &serialized, self.config.downstream_framing).await
                                         {
                                             tracing::warn!("failed to write response to child: {e}");
                                         }
@@ -725,7 +1142,7 @@ impl ProxyServer {
                                     let mut stdin = handle.stdin.lock().await;
                                     let serialized = serde_json::to_string(&msg).unwrap_or_default();
                                     if let Err(e) =
-                                        write_newline_delimited(&mut *stdin, &serialized).await
+                                        write_child_message(&mut *stdin, &serialized, self.config.downstream_framing).await
                                     {
                                         tracing::warn!("failed to write response to child: {e}");
                                     }
@@ -760,8 +1177,24 @@ impl ProxyServer {
                     }
                 }
 
+                // Child exited unexpectedly: schedule a respawn if the budget allows.
+                Some(()) = child_crash_rx.recv() => {
+                    self.schedule_child_restart(&pending).await;
+                }
+
+                // Scheduled respawn backoff elapsed.
+                _ = async {
+                    match child_restart_at {
+                        Some(at) => tokio::time::sleep_until(at).await,
+                        None => std::future::pending::<()>().await,
+                    }
+                } => {
+                    self.respawn_child(&pending, &upstream_tx, &dropped).await;
+                }
+
                 // Drain upstream write channel
                 Some(msg) = upstream_rx.recv() => {
+                    upstream_metrics.observe(upstream_rx.len() + 1);
                     let serialized = serde_json::to_string(&msg).unwrap_or_default();
                     if write_newline_delimited(&mut upstream_out, &serialized)
                         .await
@@ -773,10 +1206,31 @@ impl ProxyServer {
             }
         }
 
+        // Flush responses still queued at exit: on a fatal exit (e.g. the final
+        // -32700) so the client sees why the proxy stopped, and on EOF so
+        // replayed input does not lose replies that raced the end of input.
+        while let Ok(msg) = upstream_rx.try_recv() {
+            let serialized = serde_json::to_string(&msg).unwrap_or_default();
+            if write_newline_delimited(&mut upstream_out, &serialized)
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+
         // Shutdown: abort the idle mail poller task to prevent leaked background work.
         if let Some(handle) = mail_poller_handle.take() {
             handle.abort();
         }
+        // The stale-session sweep persists the registry; stop it before the
+        // shutdown path releases locks and writes its own snapshot.
+        if let Some(handle) = stale_sweep_handle {
+            handle.abort();
+        }
+        if let Some(handle) = thread_map_sweep_handle {
+            handle.abort();
+        }
 
         // Graceful shutdown: request summary from each active thread (FR-7.1).
         self.collect_shutdown_summaries().await;
@@ -797,11 +1251,18 @@ impl ProxyServer {
         let sessions_path = crate::lock::sessions_dir()
             .join(&self.team)
             .join("registry.json");
-        if let Err(e) = Self::persist_registry(&self.registry, &sessions_path).await {
+        if let Err(e) = Self::persist_registry(
+            &self.registry,
+            &sessions_path,
+            self.config.registry_lock_retries,
+        )
+        .await
+        {
             tracing::warn!("failed to persist registry at shutdown: {e:#}");
         }
 
         // Shutdown: signal child and force-kill if it ignores stdin EOF
+        self.child_shutting_down.store(true, Ordering::SeqCst);
         if let Some(mut handle) = self.child.take() {
             // Abort the periodic drain background task (JSON mode only).
             if let Some(drain_handle) = handle.drain_task.take() {
@@ -817,7 +1278,10 @@ impl ProxyServer {
             }
         }
 
-        Ok(())
+        match fatal {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
     }
 
     /// Request a compacted summary from each active Codex thread during
@@ -832,19 +1296,26 @@ impl ProxyServer {
     /// Sessions without a `thread_id` (still in initial codex call) are skipped.
     async fn collect_shutdown_summaries(&mut self) {
         const SUMMARY_TIMEOUT_SECS: u64 = 10;
-        const SUMMARY_PROMPT: &str = "\
-Session ending. Write a concise summary of:\n\
-- What you were working on\n\
-- Current state \u{2014} what is done, what is not\n\
-- Any open questions or blockers\n\
-- Next steps if resumed";
 
-        // Collect active sessions that have a thread_id.
+        // Collect active sessions that have a thread_id and ran long enough
+        // to be worth summarizing.
+        let min_duration = self.config.min_session_duration_for_summary_secs;
+        let now = chrono::Utc::now();
         let sessions: Vec<(String, String, String)> = {
             let reg = self.registry.lock().await;
             reg.list_all()
                 .iter()
                 .filter(|e| e.status == SessionStatus::Active && e.thread_id.is_some())
+                .filter(|e| {
+                    let eligible = summary_duration_reached(&e.started_at, min_duration, now);
+                    if !eligible {
+                        tracing::info!(
+                            agent_id = %e.agent_id,
+                            "session shorter than {min_duration}s; skipping shutdown summary"
+                        );
+                    }
+                    eligible
+                })
                 .map(|e| {
                     (
                         e.agent_id.clone(),
@@ -865,6 +1336,8 @@ Session ending. Write a concise summary of:\n\
             return;
         }
 
+        let summary_prompt = shutdown_summary_prompt(&self.config).await;
+
         // Clone the stdin Arc so we can write to it without holding an immutable
         // borrow on `self.child` across the loop body — we need `&mut self.child`
         // later to receive from `response_rx`.
@@ -882,7 +1355,7 @@ Session ending. Write a concise summary of:\n\
                     "name": "codex-reply",
                     "arguments": {
                         "threadId": thread_id,
-                        "prompt": SUMMARY_PROMPT,
+                        "prompt": summary_prompt,
                     }
                 }
             });
@@ -890,7 +1363,10 @@ Session ending. Write a concise summary of:\n\
             let serialized = serde_json::to_string(&request).unwrap_or_default();
             {
                 let mut stdin = stdin_arc.lock().await;
-                if let Err(e) = write_newline_delimited(&mut *stdin, &serialized).await {
+                if let Err(e) =
+                    write_child_message(&mut *stdin, &serialized, self.config.downstream_framing)
+                        .await
+                {
                     tracing::warn!(
                         agent_id = %agent_id,
                         "failed to send summary request to child: {e}"
@@ -999,7 +1475,9 @@ Session ending. Write a concise summary of:\n\
         if let Some(ref handle) = self.child {
             let serialized = serde_json::to_string(&msg).unwrap_or_default();
             let mut stdin = handle.stdin.lock().await;
-            if let Err(e) = write_newline_delimited(&mut *stdin, &serialized).await {
+            if let Err(e) =
+                write_child_message(&mut *stdin, &serialized, self.config.downstream_framing).await
+            {
                 tracing::warn!("failed to write to child stdin: {e}");
             }
             drop(stdin);
@@ -1094,6 +1572,26 @@ Session ending. Write a concise summary of:\n\
             .unwrap_or("")
             .to_string();
 
+        // agent_interrupt needs the pending-request table and child stdin, so it
+        // is handled here rather than in handle_synthetic_tool.
+        if tool_name == "agent_interrupt" {
+            let args = msg
+                .pointer("/params/arguments")
+                .cloned()
+                .unwrap_or_else(|| json!({}));
+            let resp = self.handle_agent_interrupt(&id, &args, pending).await;
+            let _ = upstream_tx.send(resp).await;
+            return;
+        }
+
+        // agent_metrics reads the pending-request table, so it is handled here
+        // as well; it is read-only and needs no identity.
+        if tool_name == "agent_metrics" {
+            let resp = self.handle_agent_metrics(&id, pending).await;
+            let _ = upstream_tx.send(resp).await;
+            return;
+        }
+
         // Synthetic ATM tool calls — no child needed
         if is_synthetic_tool(&tool_name) {
             let args = msg
@@ -1105,14 +1603,86 @@ Session ending. Write a concise summary of:\n\
                 .and_then(|v| v.as_str())
                 .or_else(|| args.get("threadId").and_then(|v| v.as_str()))
                 .map(ToString::to_string);
-            let resp = self
-                .handle_synthetic_tool(&id, &tool_name, &args, thread_id.as_deref())
-                .await;
+            let limit = synthetic_tool_timeout(&tool_name, self.config.synthetic_tool_timeout_secs);
+            let resp = with_tool_timeout(
+                &id,
+                &tool_name,
+                limit,
+                self.handle_synthetic_tool(&id, &tool_name, &args, thread_id.as_deref()),
+            )
+            .await;
             let _ = upstream_tx.send(resp).await;
             return;
         }
 
         let mut is_codex_tool = tool_name == "codex" || tool_name == "codex-reply";
+
+        // Per-turn model override: validate against the allowlist before any
+        // session state is touched. Accepted values are forwarded unchanged.
+        if is_codex_tool {
+            let requested_model = msg
+                .pointer("/params/arguments/model")
+                .and_then(|v| v.as_str());
+            if let Some(model) = requested_model {
+                if !model_allowed(&self.config.allowed_models, model) {
+                    let _ = upstream_tx
+                        .send(make_error_response(
+                            id,
+                            ERR_MODEL_NOT_ALLOWED,
+                            &format!("model '{model}' is not in allowed_models"),
+                            json!({
+                                "error_source": "proxy",
+                                "model": model,
+                                "allowed_models": self.config.allowed_models,
+                            }),
+                        ))
+                        .await;
+                    return;
+                }
+            }
+
+            // Working-directory policy: resolve `cwd` against `cwd_base` and
+            // reject directories outside `restrict_cwd_to`. The resolved
+            // absolute path replaces the argument. New sessions without a
+            // `cwd` start at `cwd_base`; replies and resumes keep their
+            // stored cwd.
+            let args = msg.pointer("/params/arguments");
+            let requested_cwd = args
+                .and_then(|a| a.get("cwd"))
+                .and_then(|v| v.as_str())
+                .or_else(|| {
+                    let is_new_session =
+                        tool_name == "codex" && args.and_then(|a| a.get("agent_id")).is_none();
+                    is_new_session.then_some(".")
+                })
+                .map(str::to_string);
+            if let Some(cwd) = requested_cwd {
+                match resolve_cwd(&self.config, &cwd) {
+                    Ok(None) => {}
+                    Ok(Some(resolved)) => {
+                        if let Some(args) = msg.pointer_mut("/params/arguments") {
+                            args["cwd"] = json!(resolved.to_string_lossy());
+                        }
+                    }
+                    Err(resolved) => {
+                        let _ = upstream_tx
+                            .send(make_error_response(
+                                id,
+                                ERR_CWD_NOT_ALLOWED,
+                                &format!("cwd '{}' is outside restrict_cwd_to", resolved.display()),
+                                json!({
+                                    "error_source": "proxy",
+                                    "cwd": cwd,
+                                    "resolved_cwd": resolved.to_string_lossy(),
+                                    "restrict_cwd_to": self.config.restrict_cwd_to,
+                                }),
+                            ))
+                            .await;
+                        return;
+                    }
+                }
+            }
+        }
         // effective_tool_name tracks the final routing (may be rewritten to "codex-reply")
         let mut effective_tool_name = tool_name.clone();
 
@@ -1219,9 +1789,7 @@ Session ending. Write a concise summary of:\n\
                         .get("identity")
                         .and_then(|v| v.as_str())
                         .map(|s| s.to_string());
-                    let identity = explicit_identity
-                        .or_else(|| self.config.identity.clone())
-                        .unwrap_or_else(|| "codex".to_string());
+                    let identity = self.resolve_session_identity(explicit_identity).await;
 
                     // Cross-process lock check (FR-20.1)
                     if let Some((pid, conflicting_agent_id)) =
@@ -1289,21 +1857,34 @@ Session ending. Write a concise summary of:\n\
             }
         }
 
-        // Check child health
-        if let Some(ref handle) = self.child {
-            let status = handle.exit_status.lock().await;
-            if let Some(exit) = &*status {
-                let code = exit.code().unwrap_or(-1);
+        // Check child health; a crashed child may be respawned first.
+        let exited = match self.child {
+            Some(ref handle) => *handle.exit_status.lock().await,
+            None => None,
+        };
+        if let Some(exit) = exited {
+            let code = exit.code().unwrap_or(-1);
+            let restarting = !exit.success() && self.schedule_child_restart(pending).await;
+            let err = if restarting {
+                make_error_response(
+                    id,
+                    ERR_CHILD_DEAD,
+                    &format!(
+                        "Codex child process died (exit code: {code}); restarting, retry shortly"
+                    ),
+                    json!({"error_source": "proxy", "exit_code": code, "restarting": true}),
+                )
+            } else {
                 tracing::warn!("child process is dead (exit code: {code})");
-                let err = make_error_response(
+                make_error_response(
                     id,
                     ERR_CHILD_DEAD,
                     &format!("Codex child process died (exit code: {code})"),
                     json!({"error_source": "proxy", "exit_code": code}),
-                );
-                let _ = upstream_tx.send(err).await;
-                return;
-            }
+                )
+            };
+            let _ = upstream_tx.send(err).await;
+            return;
         }
 
         if self.child.is_none() {
@@ -1419,7 +2000,8 @@ Session ending. Write a concise summary of:\n\
                         // Spawn a task that waits for the queued reply to be dispatched
                         // and sends the response upstream.
                         let upstream_tx_clone = upstream_tx.clone();
-                        let timeout_secs = self.config.request_timeout_secs;
+                        let timeout_secs =
+                            effective_request_timeout_secs(&self.config, Some(agent_id));
                         tokio::spawn(async move {
                             match timeout(Duration::from_secs(timeout_secs), rx).await {
                                 Ok(Ok(resp)) => {
@@ -1464,7 +2046,9 @@ Session ending. Write a concise summary of:\n\
         let serialized = serde_json::to_string(&msg_to_forward).unwrap_or_default();
         {
             let mut stdin = handle.stdin.lock().await;
-            if let Err(e) = write_newline_delimited(&mut *stdin, &serialized).await {
+            if let Err(e) =
+                write_child_message(&mut *stdin, &serialized, self.config.downstream_framing).await
+            {
                 tracing::error!("failed to write to child: {e}");
                 // Revert Busy → Idle on write failure.
                 if let Some(ref agent_id_for_state) = resolved_agent_id_for_state {
@@ -1492,12 +2076,16 @@ Session ending. Write a concise summary of:\n\
             if let Some(aid) = expected_agent_id.clone() {
                 p.mark_codex_create(id.clone(), aid);
             }
+            if effective_tool_name == "codex-reply" && self.config.max_child_restarts > 0 {
+                p.mark_retryable(id.clone(), msg_to_forward.clone());
+            }
             if effective_tool_name == "codex" || effective_tool_name == "codex-reply" {
                 let actor_fallback = self.config.identity.as_deref().unwrap_or("upstream-client");
                 let source = infer_upstream_request_source(&msg_to_forward, actor_fallback);
                 p.mark_request_source(id.clone(), source.clone());
                 if let Some(ref aid) = state_agent_id {
                     p.set_last_agent_source(aid.clone(), source);
+                    p.mark_turn(id.clone(), aid.clone());
                 }
             }
         }
@@ -1510,15 +2098,22 @@ Session ending. Write a concise summary of:\n\
                 .set_thread_state(agent_id_for_state, ThreadState::Busy);
         }
 
-        let timeout_secs = self.config.request_timeout_secs;
+        let timeout_secs = effective_request_timeout_secs(
+            &self.config,
+            resolved_agent_id_for_state
+                .as_deref()
+                .or(state_agent_id.as_deref()),
+        );
         let upstream_tx_clone = upstream_tx.clone();
         let req_id = id;
         let child_stdin = Arc::clone(&handle.stdin);
+        let downstream_framing = self.config.downstream_framing;
 
         let thread_to_agent_task = Arc::clone(&self.thread_to_agent);
         let pending_for_thread_map = Arc::clone(pending);
         let registry_for_thread_map = Arc::clone(&self.registry);
         let team_for_thread_map = self.team.clone();
+        let registry_lock_retries_for_task = self.config.registry_lock_retries;
         // Clone state_agent_id for thread state tracking in the spawned task.
         let state_agent_id_for_task = state_agent_id.clone();
         let effective_tool_name_for_task = effective_tool_name.clone();
@@ -1527,8 +2122,10 @@ Session ending. Write a concise summary of:\n\
         let mail_enabled_for_task = self.mail_poller.is_enabled();
         let mail_max_messages = self.mail_poller.max_messages;
         let mail_max_length = self.mail_poller.max_message_length;
+        let mail_role = self.mail_poller.injection_role;
+        let mail_backoff_for_task = Arc::clone(&self.mail_backoff);
         let request_counter_for_task = Arc::clone(&self.request_counter);
-        let per_thread_overrides_for_task = self.config.per_thread_auto_mail.clone();
+        let auto_mail_overrides_for_task = self.mail_poller.clone();
         let shared_stdin_for_task = Arc::clone(&self.shared_child_stdin);
 
         tokio::spawn(async move {
@@ -1572,6 +2169,7 @@ Session ending. Write a concise summary of:\n\
                             if let Err(e) = ProxyServer::persist_registry(
                                 &registry_for_thread_map,
                                 &sessions_path,
+                                registry_lock_retries_for_task,
                             )
                             .await
                             {
@@ -1621,10 +2219,9 @@ Session ending. Write a concise summary of:\n\
                             &completed_identity,
                             &completed_thread_id,
                         ) {
-                            let per_thread_enabled = per_thread_overrides_for_task
-                                .get(agent_id.as_str())
-                                .copied()
-                                .unwrap_or(true);
+                            mail_backoff_for_task.lock().await.reset(agent_id);
+                            let per_thread_enabled =
+                                auto_mail_overrides_for_task.is_enabled_for(agent_id, identity);
 
                             if per_thread_enabled {
                                 dispatch_auto_mail_if_available(
@@ -1634,6 +2231,8 @@ Session ending. Write a concise summary of:\n\
                                     &team_for_thread_map,
                                     mail_max_messages,
                                     mail_max_length,
+                                    mail_role,
+                                    downstream_framing,
                                     &registry_for_thread_map,
                                     &queues_for_task,
                                     &shared_stdin_for_task,
@@ -1641,6 +2240,7 @@ Session ending. Write a concise summary of:\n\
                                     &request_counter_for_task,
                                     None,
                                     None,
+                                    None,
                                 )
                                 .await;
                             }
@@ -1677,10 +2277,11 @@ Session ending. Write a concise summary of:\n\
                 }
                 Err(_elapsed) => {
                     tracing::warn!("request timed out after {timeout_secs}s");
-                    let _ = pending_for_thread_map
-                        .lock()
-                        .await
-                        .take_codex_create(&req_id);
+                    {
+                        let mut p = pending_for_thread_map.lock().await;
+                        let _ = p.take_codex_create(&req_id);
+                        p.clear_turn(&req_id);
+                    }
                     let cancel = json!({
                         "jsonrpc": "2.0",
                         "method": "notifications/cancelled",
@@ -1688,7 +2289,8 @@ Session ending. Write a concise summary of:\n\
                     });
                     if let Ok(serialized) = serde_json::to_string(&cancel) {
                         let mut stdin = child_stdin.lock().await;
-                        let _ = write_newline_delimited(&mut *stdin, &serialized).await;
+                        let _ =
+                            write_child_message(&mut *stdin, &serialized, downstream_framing).await;
                     }
                     let err = make_error_response(
                         req_id,
@@ -1728,10 +2330,8 @@ Session ending. Write a concise summary of:\n\
             .map(String::from);
         let caller_cwd = params.get("cwd").and_then(|v| v.as_str()).map(String::from);
 
-        // Resolve identity: explicit → config.identity → "codex"
-        let identity = explicit_identity
-            .or_else(|| self.config.identity.clone())
-            .unwrap_or_else(|| "codex".to_string());
+        // Resolve identity: explicit → identity pool → config.identity → "codex"
+        let identity = self.resolve_session_identity(explicit_identity).await;
 
         // Detect git context (refreshed per turn)
         let effective_cwd = caller_cwd.as_deref().unwrap_or(".");
@@ -1806,10 +2406,17 @@ Session ending. Write a concise summary of:\n\
         if let Err(e) = acquire_lock(&team, &identity, &entry.agent_id).await {
             // Roll back registry entry
             self.registry.lock().await.close(&entry.agent_id);
+            remove_thread_mappings_for_agent(&self.thread_to_agent, &entry.agent_id).await;
             let sessions_path = crate::lock::sessions_dir()
                 .join(&team)
                 .join("registry.json");
-            if let Err(pe) = Self::persist_registry(&self.registry, &sessions_path).await {
+            if let Err(pe) = Self::persist_registry(
+                &self.registry,
+                &sessions_path,
+                self.config.registry_lock_retries,
+            )
+            .await
+            {
                 tracing::warn!("failed to persist registry after lock-rollback close: {pe}");
             }
             let _ = upstream_tx
@@ -1846,7 +2453,13 @@ Session ending. Write a concise summary of:\n\
         let sessions_path = crate::lock::sessions_dir()
             .join(&team)
             .join("registry.json");
-        if let Err(e) = Self::persist_registry(&self.registry, &sessions_path).await {
+        if let Err(e) = Self::persist_registry(
+            &self.registry,
+            &sessions_path,
+            self.config.registry_lock_retries,
+        )
+        .await
+        {
             tracing::warn!("failed to persist registry after register: {e}");
         }
 
@@ -2027,7 +2640,13 @@ Session ending. Write a concise summary of:\n\
             let sessions_path = crate::lock::sessions_dir()
                 .join(&self.team)
                 .join("registry.json");
-            if let Err(e) = Self::persist_registry(&self.registry, &sessions_path).await {
+            if let Err(e) = Self::persist_registry(
+                &self.registry,
+                &sessions_path,
+                self.config.registry_lock_retries,
+            )
+            .await
+            {
                 tracing::warn!("failed to persist registry after touch: {e:#}");
             }
         }
@@ -2055,7 +2674,17 @@ Session ending. Write a concise summary of:\n\
 
         let mut modified_msg = msg;
         if let Some(args) = modified_msg.pointer_mut("/params/arguments") {
-            inject_developer_instructions(args, &context_str);
+            // FR-2.9: continuation turns may skip the context block, per call
+            // or via config.  The argument is stripped either way.
+            let omit_context = take_omit_context(args) || !self.config.inject_context_on_reply;
+            if omit_context {
+                tracing::debug!(
+                    agent_id = ?resolved_agent_id,
+                    "session context omitted from codex-reply"
+                );
+            } else {
+                inject_developer_instructions(args, &context_str);
+            }
 
             // FR-6: Prepend resume context on first codex-reply if not yet consumed.
             if let Some(resume_ctx) = self.resume_context.take() {
@@ -2158,6 +2787,118 @@ Session ending. Write a concise summary of:\n\
             .map(|entry| entry.identity.clone())
     }
 
+    /// Handle an `agent_metrics` tool call by snapshotting the proxy's
+    /// internal counters.
+    async fn handle_agent_metrics(
+        &self,
+        id: &Value,
+        pending: &Arc<Mutex<PendingRequests>>,
+    ) -> Value {
+        let (pending_requests, auto_mail_turns) = {
+            let p = pending.lock().await;
+            (p.len() as u64, p.auto_mail_turns)
+        };
+        let (elicitation_timeouts, pending_elicitations) = {
+            let reg = self.elicitation_registry.lock().await;
+            (reg.timed_out_count(), reg.pending_count() as u64)
+        };
+        let metrics = crate::atm_tools::ProxyMetrics {
+            dropped_events: self.dropped_events.load(Ordering::Relaxed),
+            pending_requests,
+            auto_mail_turns,
+            elicitation_timeouts,
+            pending_elicitations,
+            upstream_channel: crate::atm_tools::ChannelSnapshot::of(&self.upstream_channel_metrics),
+            child_channel: crate::atm_tools::ChannelSnapshot::of(&self.child_channel_metrics),
+        };
+        crate::atm_tools::handle_agent_metrics(id, Arc::clone(&self.registry), metrics).await
+    }
+
+    /// Handle an `agent_interrupt` tool call.
+    ///
+    /// When the target session has a turn in flight, the pending upstream
+    /// request is answered with [`ERR_TURN_INTERRUPTED`], the child receives
+    /// `notifications/cancelled` for that request, and the thread is reset to
+    /// `Idle`.  Every call is audited.
+    async fn handle_agent_interrupt(
+        &self,
+        id: &Value,
+        args: &Value,
+        pending: &Arc<Mutex<PendingRequests>>,
+    ) -> Value {
+        use crate::atm_tools::{self, InterruptStatus};
+
+        let entry = match atm_tools::resolve_interrupt_target(id, args, &self.registry).await {
+            Ok(entry) => entry,
+            Err(resp) => return resp,
+        };
+
+        let (status, request_id) = match &entry {
+            None => (InterruptStatus::NotFound, None),
+            Some(e) if e.status != SessionStatus::Active || e.thread_state != ThreadState::Busy => {
+                (InterruptStatus::NotBusy, None)
+            }
+            Some(e) => {
+                let request_id = {
+                    let mut p = pending.lock().await;
+                    let request_id = p.in_flight_turn_for_agent(&e.agent_id);
+                    if let Some(ref rid) = request_id {
+                        let _ = p.take_auto_mail(rid);
+                        if let Some(tx) = p.complete(rid) {
+                            let _ = tx.send(make_error_response(
+                                rid.clone(),
+                                ERR_TURN_INTERRUPTED,
+                                "Turn interrupted by agent_interrupt",
+                                json!({"error_source": "proxy", "agent_id": e.agent_id}),
+                            ));
+                        }
+                    }
+                    request_id
+                };
+                if let (Some(rid), Some(handle)) = (&request_id, &self.child) {
+                    let cancel = json!({
+                        "jsonrpc": "2.0",
+                        "method": "notifications/cancelled",
+                        "params": {"requestId": rid, "reason": "agent_interrupt"}
+                    });
+                    if let Ok(serialized) = serde_json::to_string(&cancel) {
+                        let mut stdin = handle.stdin.lock().await;
+                        if let Err(err) = write_child_message(
+                            &mut *stdin,
+                            &serialized,
+                            self.config.downstream_framing,
+                        )
+                        .await
+                        {
+                            tracing::warn!("agent_interrupt: failed to notify child: {err}");
+                        }
+                    }
+                }
+                self.registry
+                    .lock()
+                    .await
+                    .set_thread_state(&e.agent_id, ThreadState::Idle);
+                (InterruptStatus::Cancelled, request_id)
+            }
+        };
+
+        let agent_id = entry.as_ref().map(|e| e.agent_id.as_str());
+        let identity = entry
+            .as_ref()
+            .map(|e| e.identity.as_str())
+            .or_else(|| args.get("identity").and_then(|v| v.as_str()));
+        tracing::info!(
+            agent_id = agent_id.unwrap_or("-"),
+            status = status.as_str(),
+            "agent_interrupt"
+        );
+        self.audit_log
+            .log_agent_interrupt(agent_id, identity, status.as_str())
+            .await;
+
+        atm_tools::make_interrupt_result(id, agent_id, status, request_id.as_ref())
+    }
+
     async fn handle_synthetic_tool(
         &self,
         id: &Value,
@@ -2168,7 +2909,7 @@ Session ending. Write a concise summary of:\n\
         use crate::atm_tools;
 
         match tool_name {
-            "atm_send" | "atm_read" | "atm_broadcast" | "atm_pending_count" => {
+            "atm_send" | "atm_reply" | "atm_read" | "atm_broadcast" | "atm_pending_count" => {
                 let thread_identity = if let Some(tid) = thread_id {
                     self.resolve_identity_from_thread(tid).await
                 } else {
@@ -2186,6 +2927,31 @@ Session ending. Write a concise summary of:\n\
                     );
                 };
                 let team = &self.team;
+                if !self.config.allow_cross_team {
+                    if let Some(target_team) = atm_tools::cross_team_target(tool_name, args, team) {
+                        tracing::warn!(
+                            tool = tool_name,
+                            identity = %identity,
+                            team = %team,
+                            target_team = %target_team,
+                            "ATM tool call rejected: cross-team target"
+                        );
+                        return make_error_response(
+                            id.clone(),
+                            ERR_CROSS_TEAM_DENIED,
+                            &format!(
+                                "{tool_name}: team '{target_team}' is outside this proxy's team \
+                                 '{team}' (set allow_cross_team to permit)"
+                            ),
+                            json!({
+                                "error_source": "proxy",
+                                "tool": tool_name,
+                                "team": team,
+                                "target_team": target_team,
+                            }),
+                        );
+                    }
+                }
                 tracing::info!(
                     tool = tool_name,
                     identity = %identity,
@@ -2193,9 +2959,52 @@ Session ending. Write a concise summary of:\n\
                     "ATM tool call"
                 );
 
-                // FR-9.1: Audit ATM tool call.
+                // Inbox I/O blocks on file locks; run it off the async runtime
+                // so the proxy loop (and the call timeout) keep making progress.
+                let (req_id, call_args, call_team, call_identity) =
+                    (id.clone(), args.clone(), team.clone(), identity.clone());
+                let tool = tool_name.to_string();
+                let allow_cross_team = self.config.allow_cross_team;
+                let result = tokio::task::spawn_blocking(move || {
+                    let (args, team, identity) = (&call_args, &call_team, &call_identity);
+                    match tool.as_str() {
+                        "atm_send" => atm_tools::handle_atm_send(&req_id, args, identity, team),
+                        "atm_reply" => atm_tools::handle_atm_reply(
+                            &req_id,
+                            args,
+                            identity,
+                            team,
+                            allow_cross_team,
+                        ),
+                        "atm_read" => atm_tools::handle_atm_read(&req_id, args, identity, team),
+                        "atm_broadcast" => {
+                            atm_tools::handle_atm_broadcast(&req_id, args, identity, team)
+                        }
+                        "atm_pending_count" => {
+                            atm_tools::handle_atm_pending_count(&req_id, args, identity, team)
+                        }
+                        _ => unreachable!(),
+                    }
+                })
+                .await
+                .unwrap_or_else(|e| {
+                    make_error_response(
+                        id.clone(),
+                        ERR_INTERNAL,
+                        &format!("{tool_name} failed: {e}"),
+                        json!({"error_source": "proxy", "tool": tool_name}),
+                    )
+                });
+
+                // FR-9.1: Audit ATM tool call. An implicit `atm_reply` has no
+                // `to`; its recipient is the one the handler resolved.
                 let recipient = match tool_name {
-                    "atm_send" => args.get("to").and_then(|v| v.as_str()),
+                    "atm_send" | "atm_reply" => args
+                        .get("to")
+                        .and_then(|v| v.as_str())
+                        .filter(|to| !to.is_empty())
+                        .map(str::to_string)
+                        .or_else(|| sent_recipient(&result, team)),
                     _ => None,
                 };
                 let message_summary = args.get("message").and_then(|v| v.as_str());
@@ -2212,23 +3021,14 @@ Session ending. Write a concise summary of:\n\
                         tool_name,
                         agent_id_opt.as_deref(),
                         Some(&identity),
-                        recipient,
+                        recipient.as_deref(),
                         message_summary,
                     )
                     .await;
-
-                match tool_name {
-                    "atm_send" => atm_tools::handle_atm_send(id, args, &identity, team),
-                    "atm_read" => atm_tools::handle_atm_read(id, args, &identity, team),
-                    "atm_broadcast" => atm_tools::handle_atm_broadcast(id, args, &identity, team),
-                    "atm_pending_count" => {
-                        atm_tools::handle_atm_pending_count(id, args, &identity, team)
-                    }
-                    _ => unreachable!(),
-                }
+                result
             }
             "agent_sessions" => {
-                atm_tools::handle_agent_sessions(id, Arc::clone(&self.registry)).await
+                atm_tools::handle_agent_sessions(id, args, Arc::clone(&self.registry)).await
             }
             "agent_status" => {
                 use agent_team_mail_core::home::get_home_dir;
@@ -2238,7 +3038,11 @@ Session ending. Write a concise summary of:\n\
                     .map(|d| d.as_secs())
                     .unwrap_or(0);
                 let uptime_secs = now_secs.saturating_sub(self.started_epoch_secs);
-                let child_alive = self.child.is_some();
+                let child = atm_tools::ChildStatus {
+                    alive: self.child.is_some(),
+                    restarts_succeeded: self.child_restarts.succeeded,
+                    restarts_failed: self.child_restarts.failed,
+                };
                 // Compute aggregate unread mail count across all active sessions.
                 let pending_mail_count: u64 = {
                     let home_opt = get_home_dir().ok();
@@ -2256,7 +3060,7 @@ Session ending. Write a concise summary of:\n\
                 atm_tools::handle_agent_status(
                     id,
                     Arc::clone(&self.registry),
-                    child_alive,
+                    child,
                     &self.team,
                     &self.started_at,
                     uptime_secs,
@@ -2278,11 +3082,18 @@ Session ending. Write a concise summary of:\n\
                     if let Some(agent_id) = args.get("agent_id").and_then(|v| v.as_str()) {
                         self.watch_subscriptions.lock().await.remove(agent_id);
                         let _ = self.detach_watch_stream(agent_id).await;
+                        remove_thread_mappings_for_agent(&self.thread_to_agent, agent_id).await;
                     }
                     let sessions_path = crate::lock::sessions_dir()
                         .join(&self.team)
                         .join("registry.json");
-                    if let Err(e) = Self::persist_registry(&self.registry, &sessions_path).await {
+                    if let Err(e) = Self::persist_registry(
+                        &self.registry,
+                        &sessions_path,
+                        self.config.registry_lock_retries,
+                    )
+                    .await
+                    {
                         tracing::warn!("failed to persist registry after agent_close: {e:#}");
                     }
                 }
@@ -2402,6 +3213,107 @@ Session ending. Write a concise summary of:\n\
         }
     }
 
+    /// Schedule a respawn of a crashed Codex child (`max_child_restarts`).
+    ///
+    /// Returns `true` when a respawn is pending afterwards. A no-op when the
+    /// current child has not exited or an attempt is already scheduled. The
+    /// attempt is due after an exponential backoff and is made by the
+    /// [`Self::run`] loop; once the proxy-lifetime budget is spent, in-flight
+    /// `codex-reply` requests fail with [`ERR_CHILD_DEAD`].
+    async fn schedule_child_restart(&mut self, pending: &Arc<Mutex<PendingRequests>>) -> bool {
+        let Some(ref handle) = self.child else {
+            return false;
+        };
+        if handle.exit_status.lock().await.is_none() {
+            return false;
+        }
+        if self.child_restart_at.is_some() {
+            return true;
+        }
+
+        if self.child_restarts.attempts < self.config.max_child_restarts {
+            let backoff = child_restart_backoff(self.child_restarts.attempts);
+            tracing::warn!(
+                attempt = self.child_restarts.attempts + 1,
+                max = self.config.max_child_restarts,
+                "Codex child exited unexpectedly; respawning in {}ms",
+                backoff.as_millis()
+            );
+            self.child_restart_at = Some(tokio::time::Instant::now() + backoff);
+            return true;
+        }
+
+        let attempts = self.child_restarts.attempts;
+        for (id, tx) in pending.lock().await.take_retryable() {
+            let _ = tx.send(make_error_response(
+                id,
+                ERR_CHILD_DEAD,
+                "Codex child process died and could not be restarted",
+                json!({"error_source": "proxy", "restart_attempts": attempts}),
+            ));
+        }
+        false
+    }
+
+    /// Make the scheduled respawn attempt.
+    ///
+    /// Each attempt counts against the proxy-lifetime budget. After a
+    /// successful respawn `notifications/initialized` is replayed (if upstream
+    /// sent it) and in-flight `codex-reply` requests are re-sent with their
+    /// original `threadId`; a failed attempt schedules the next one.
+    async fn respawn_child(
+        &mut self,
+        pending: &Arc<Mutex<PendingRequests>>,
+        upstream_tx: &mpsc::Sender<Value>,
+        dropped: &Arc<AtomicU64>,
+    ) {
+        if self.child_restart_at.take().is_none() {
+            return;
+        }
+        self.child_restarts.attempts += 1;
+
+        if let Some(drain) = self.child.as_mut().and_then(|h| h.drain_task.take()) {
+            drain.abort();
+        }
+        match self.spawn_child(pending, upstream_tx, dropped).await {
+            Ok(()) => {
+                self.child_restarts.succeeded += 1;
+                self.replay_to_respawned_child(pending).await;
+                tracing::info!("Codex child respawned");
+            }
+            Err(e) => {
+                self.child_restarts.failed += 1;
+                tracing::error!("failed to respawn Codex child: {e}");
+                self.schedule_child_restart(pending).await;
+            }
+        }
+    }
+
+    /// Replay the upstream handshake and in-flight `codex-reply` requests to
+    /// a freshly respawned child.
+    async fn replay_to_respawned_child(&mut self, pending: &Arc<Mutex<PendingRequests>>) {
+        let Some(ref handle) = self.child else {
+            return;
+        };
+        let mut replay = Vec::new();
+        if self.upstream_initialized {
+            replay.push(json!({"jsonrpc": "2.0", "method": "notifications/initialized"}));
+            self.child_initialized = true;
+        }
+        replay.extend(pending.lock().await.retryable_messages());
+
+        let mut stdin = handle.stdin.lock().await;
+        for msg in replay {
+            let serialized = serde_json::to_string(&msg).unwrap_or_default();
+            if let Err(e) =
+                write_child_message(&mut *stdin, &serialized, self.config.downstream_framing).await
+            {
+                tracing::warn!("failed to replay message to respawned child: {e}");
+                return;
+            }
+        }
+    }
+
     /// Spawn the Codex child process via the configured transport.
     ///
     /// Delegates the actual child-process creation to `self.transport.spawn()`,
@@ -2432,7 +3344,8 @@ Session ending. Write a concise summary of:\n\
         let idle_flag = raw.idle_flag;
 
         // Channel for messages from child stdout reader
-        let (child_tx, child_rx) = mpsc::channel::<Value>(UPSTREAM_CHANNEL_CAPACITY);
+        let child_metrics = Arc::clone(&self.child_channel_metrics);
+        let (child_tx, child_rx) = mpsc::channel::<Value>(child_metrics.capacity());
 
         // JSON mode: start a 30-second periodic stdin queue drain timer.
         // Only runs when the transport provides an idle_flag (i.e. JsonCodecTransport).
@@ -2442,6 +3355,7 @@ Session ending. Write a concise summary of:\n\
             let drain_stdin = Arc::clone(&self.shared_child_stdin);
             let drain_thread_to_agent = Arc::clone(&self.thread_to_agent);
             let drain_elicitation_registry = Arc::clone(&self.elicitation_registry);
+            let drain_framing = self.config.downstream_framing;
             Some(tokio::spawn(async move {
                 let mut interval = tokio::time::interval(Duration::from_secs(30));
                 // Skip the first immediate tick
@@ -2460,6 +3374,7 @@ Session ending. Write a concise summary of:\n\
                             &agent_ids,
                             stdin_arc,
                             Duration::from_secs(600),
+                            drain_framing,
                         )
                         .await;
                         drain_elicitation_queue_for_agents(
@@ -2467,6 +3382,7 @@ Session ending. Write a concise summary of:\n\
                             &agent_ids,
                             stdin_arc,
                             &drain_elicitation_registry,
+                            drain_framing,
                         )
                         .await;
                     }
@@ -2493,12 +3409,50 @@ Session ending. Write a concise summary of:\n\
         let mail_enabled_for_reader = self.mail_poller.is_enabled();
         let mail_max_messages_reader = self.mail_poller.max_messages;
         let mail_max_length_reader = self.mail_poller.max_message_length;
-        let per_thread_overrides_reader = self.config.per_thread_auto_mail.clone();
+        let mail_role_reader = self.mail_poller.injection_role;
+        let downstream_framing_reader = self.config.downstream_framing;
+        let mail_backoff_reader = Arc::clone(&self.mail_backoff);
+        let auto_mail_overrides_reader = self.mail_poller.clone();
+        let max_line_bytes = self.config.max_child_line_bytes;
         tokio::spawn(async move {
-            let reader = tokio::io::BufReader::new(stdout);
-            let mut lines = tokio::io::AsyncBufReadExt::lines(reader);
+            let mut lines = ChildLineReader::new(stdout, max_line_bytes)
+                .with_framing(downstream_framing_reader);
 
-            while let Ok(Some(line)) = lines.next_line().await {
+            while let Ok(Some(next)) = lines.next_line().await {
+                let line = match next {
+                    ChildLine::Line(line) => line,
+                    ChildLine::Oversized { len, head } => {
+                        tracing::warn!(
+                            "dropping {len}-byte child output line \
+                             (max_child_line_bytes = {max_line_bytes})"
+                        );
+                        // Fail the request it answered so the caller is not
+                        // left waiting for a response that will never arrive.
+                        if let Some(id) = oversized_response_id(&head) {
+                            let mut pending_guard = pending_clone.lock().await;
+                            let auto_agent_id = pending_guard.take_auto_mail(&id);
+                            let tx = pending_guard.complete(&id);
+                            drop(pending_guard);
+                            if let Some(auto_agent_id) = auto_agent_id {
+                                registry_for_reader
+                                    .lock()
+                                    .await
+                                    .set_thread_state(&auto_agent_id, ThreadState::Idle);
+                            } else if let Some(tx) = tx {
+                                let _ = tx.send(make_error_response(
+                                    id,
+                                    ERR_INTERNAL,
+                                    &format!(
+                                        "child response of {len} bytes exceeds \
+                                         max_child_line_bytes ({max_line_bytes})"
+                                    ),
+                                    json!({"error_source": "proxy"}),
+                                ));
+                            }
+                        }
+                        continue;
+                    }
+                };
                 if line.trim().is_empty() {
                     continue;
                 }
@@ -2534,6 +3488,7 @@ Session ending. Write a concise summary of:\n\
                                         &agent_ids,
                                         stdin_arc,
                                         Duration::from_secs(600),
+                                        downstream_framing_reader,
                                     )
                                     .await;
                                     drain_elicitation_queue_for_agents(
@@ -2541,6 +3496,7 @@ Session ending. Write a concise summary of:\n\
                                         &agent_ids,
                                         stdin_arc,
                                         &drain_elicitation_registry,
+                                        downstream_framing_reader,
                                     )
                                     .await;
                                 }
@@ -2607,14 +3563,13 @@ Session ending. Write a concise summary of:\n\
                             );
 
                             // Chain post-turn mail check (FR-8.1).
+                            mail_backoff_reader.lock().await.reset(&auto_agent_id);
                             if mail_enabled_for_reader {
                                 if let (Some(identity), Some(thread_id)) =
                                     (&completed_identity, &completed_thread_id)
                                 {
-                                    let per_thread_ok = per_thread_overrides_reader
-                                        .get(auto_agent_id.as_str())
-                                        .copied()
-                                        .unwrap_or(true);
+                                    let per_thread_ok = auto_mail_overrides_reader
+                                        .is_enabled_for(&auto_agent_id, identity);
                                     if per_thread_ok {
                                         dispatch_auto_mail_if_available(
                                             &auto_agent_id,
@@ -2623,6 +3578,8 @@ Session ending. Write a concise summary of:\n\
                                             &team_for_reader,
                                             mail_max_messages_reader,
                                             mail_max_length_reader,
+                                            mail_role_reader,
+                                            downstream_framing_reader,
                                             &registry_for_reader,
                                             &queues_for_reader,
                                             &shared_stdin_for_reader,
@@ -2630,6 +3587,7 @@ Session ending. Write a concise summary of:\n\
                                             &request_counter_for_reader,
                                             None,
                                             None,
+                                            None,
                                         )
                                         .await;
                                     }
@@ -2652,11 +3610,13 @@ Session ending. Write a concise summary of:\n\
 
                 // Server-initiated requests from child (e.g. elicitation/create)
                 if method.is_some() {
+                    child_metrics.observe_sender(&child_tx);
                     let _ = child_tx.send(msg).await;
                     continue;
                 }
 
                 // Unmatched response — forward anyway
+                child_metrics.observe_sender(&child_tx);
                 let _ = child_tx.send(msg).await;
             }
 
@@ -2667,7 +3627,11 @@ Session ending. Write a concise summary of:\n\
         let exit_clone = Arc::clone(&exit_status);
         let pending_crash = Arc::clone(pending);
         let process_clone = Arc::clone(&process);
+        let crash_tx = self.child_crash_tx.clone();
+        let shutting_down = Arc::clone(&self.child_shutting_down);
+        let restarts_enabled = self.config.max_child_restarts > 0;
         tokio::spawn(async move {
+            let mut exited: Option<ExitStatus> = None;
             loop {
                 let mut done = false;
                 {
@@ -2676,7 +3640,7 @@ Session ending. Write a concise summary of:\n\
                         Some(child) => match child.try_wait() {
                             Ok(Some(s)) => {
                                 tracing::info!("child process exited: {s}");
-                                *exit_clone.lock().await = Some(s);
+                                exited = Some(s);
                                 *child_guard = None;
                                 done = true;
                             }
@@ -2696,13 +3660,20 @@ Session ending. Write a concise summary of:\n\
                 }
                 tokio::time::sleep(Duration::from_millis(50)).await;
             }
-            // Cancel all pending requests
-            let mut guard = pending_crash.lock().await;
-            guard.map.clear();
-            guard.codex_create_ids.clear();
-            guard.auto_mail_pending.clear();
-            guard.request_sources.clear();
-            guard.last_agent_source.clear();
+            let crashed =
+                exited.is_some_and(|s| !s.success()) && !shutting_down.load(Ordering::SeqCst);
+            // Cancel pending requests before publishing the exit status so a
+            // respawned child's requests are never swept up here.
+            pending_crash
+                .lock()
+                .await
+                .clear_on_child_exit(crashed && restarts_enabled);
+            if let Some(s) = exited {
+                *exit_clone.lock().await = Some(s);
+            }
+            if crashed {
+                let _ = crash_tx.send(());
+            }
         });
 
         // Populate the shared child stdin reference for the idle poller.
@@ -2715,6 +3686,7 @@ Session ending. Write a concise summary of:\n\
             process,
             drain_task: periodic_drain_task,
         });
+        self.child_initialized = false;
 
         Ok(())
     }
@@ -2731,6 +3703,60 @@ enum PrepareResult {
     Error,
 }
 
+/// Request timeout for a turn on `agent_id`: its
+/// [`AgentMcpConfig::per_thread_timeout_secs`] entry, else the global
+/// [`AgentMcpConfig::request_timeout_secs`].
+fn effective_request_timeout_secs(config: &AgentMcpConfig, agent_id: Option<&str>) -> u64 {
+    agent_id
+        .and_then(|aid| config.per_thread_timeout_secs.get(aid).copied())
+        .unwrap_or(config.request_timeout_secs)
+}
+
+/// Whether a session started at `started_at` has run for at least
+/// `min_secs` by `now`. Unparseable timestamps count as long enough so a
+/// summary is never lost to bad metadata.
+fn summary_duration_reached(
+    started_at: &str,
+    min_secs: u64,
+    now: chrono::DateTime<chrono::Utc>,
+) -> bool {
+    if min_secs == 0 {
+        return true;
+    }
+    match chrono::DateTime::parse_from_rfc3339(started_at) {
+        Ok(started) => {
+            let elapsed = now.signed_duration_since(started.with_timezone(&chrono::Utc));
+            elapsed.num_seconds() >= i64::try_from(min_secs).unwrap_or(i64::MAX)
+        }
+        Err(_) => true,
+    }
+}
+
+/// Resolve the shutdown summary prompt: the configured file, then the inline
+/// config value, then [`DEFAULT_SHUTDOWN_SUMMARY_PROMPT`].
+async fn shutdown_summary_prompt(config: &AgentMcpConfig) -> String {
+    if let Some(ref path) = config.shutdown_summary_prompt_file {
+        match tokio::fs::read_to_string(path).await {
+            Ok(contents) if !contents.trim().is_empty() => return contents,
+            Ok(_) => tracing::warn!("shutdown_summary_prompt_file {path} is empty; ignoring"),
+            Err(e) => tracing::warn!("failed to read shutdown_summary_prompt_file {path}: {e}"),
+        }
+    }
+    config
+        .shutdown_summary_prompt
+        .clone()
+        .filter(|prompt| !prompt.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_SHUTDOWN_SUMMARY_PROMPT.to_string())
+}
+
+/// Backoff before automatic respawn attempt `attempt` (0-based).
+fn child_restart_backoff(attempt: u32) -> Duration {
+    let ms = CHILD_RESTART_BACKOFF_BASE_MS
+        .saturating_mul(1u64 << attempt.min(16))
+        .min(CHILD_RESTART_BACKOFF_MAX_MS);
+    Duration::from_millis(ms)
+}
+
 fn infer_upstream_request_source(msg: &Value, actor_fallback: &str) -> SourceEnvelope {
     let kind = msg
         .pointer("/params/source/kind")
@@ -2747,6 +3773,78 @@ fn infer_upstream_request_source(msg: &Value, actor_fallback: &str) -> SourceEnv
     SourceEnvelope::new(kind, actor, channel)
 }
 
+/// Remove every `thread_to_agent` mapping that points at `agent_id`.
+async fn remove_thread_mappings_for_agent(
+    thread_to_agent: &Arc<tokio::sync::Mutex<HashMap<String, String>>>,
+    agent_id: &str,
+) {
+    thread_to_agent
+        .lock()
+        .await
+        .retain(|_, mapped| mapped != agent_id);
+}
+
+/// Drop `thread_to_agent` mappings whose agent is missing from the registry or
+/// whose session is no longer [`SessionStatus::Active`].
+///
+/// Returns the number of mappings removed.
+async fn prune_thread_mappings(
+    thread_to_agent: &Arc<tokio::sync::Mutex<HashMap<String, String>>>,
+    registry: &Arc<Mutex<SessionRegistry>>,
+) -> usize {
+    let active: std::collections::HashSet<String> = registry
+        .lock()
+        .await
+        .list_all()
+        .into_iter()
+        .filter(|e| e.status == SessionStatus::Active)
+        .map(|e| e.agent_id.clone())
+        .collect();
+    let mut map = thread_to_agent.lock().await;
+    let before = map.len();
+    map.retain(|_, agent_id| active.contains(agent_id));
+    before - map.len()
+}
+
+/// Close stale sessions whose `last_active` is at least `ttl_secs` old,
+/// clean up their leftover lock files, and persist the registry.
+///
+/// A lock file is only touched when no live session has since reclaimed the
+/// identity; [`check_lock`] then removes it unless a live process holds it.
+/// Returns the number of sessions closed.
+async fn expire_stale_sessions(
+    registry: &Arc<Mutex<SessionRegistry>>,
+    team: &str,
+    ttl_secs: u64,
+    lock_retries: u32,
+) -> usize {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (closed, unclaimed): (usize, Vec<String>) = {
+        let mut reg = registry.lock().await;
+        let expired = reg.expire_stale(ttl_secs, now);
+        let unclaimed = expired
+            .iter()
+            .filter(|entry| reg.find_by_identity(&entry.identity).is_none())
+            .map(|entry| entry.identity.clone())
+            .collect();
+        (expired.len(), unclaimed)
+    };
+    if closed == 0 {
+        return 0;
+    }
+    for identity in &unclaimed {
+        let _ = check_lock(team, identity).await;
+    }
+    let sessions_path = crate::lock::sessions_dir().join(team).join("registry.json");
+    if let Err(e) = ProxyServer::persist_registry(registry, &sessions_path, lock_retries).await {
+        tracing::warn!("failed to persist registry after stale-session sweep: {e}");
+    }
+    closed
+}
+
 /// Forward a `codex/event` notification upstream, injecting `agent_id` into params.
 ///
 /// Looks up the `agent_id` from `thread_to_agent` using the event's `threadId`
@@ -3206,9 +4304,10 @@ async fn drain_stdin_queue_for_agents(
     agent_ids: &[String],
     shared_stdin: &Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>>,
     ttl: Duration,
+    framing: DownstreamFraming,
 ) {
     for agent_id in agent_ids {
-        match crate::stdin_queue::drain(team, agent_id, shared_stdin, ttl).await {
+        match crate::stdin_queue::drain(team, agent_id, shared_stdin, ttl, framing).await {
             Ok(count) if count > 0 => {
                 tracing::debug!(agent_id, count, "stdin queue drained");
             }
@@ -3233,6 +4332,7 @@ async fn drain_elicitation_queue_for_agents(
     agent_ids: &[String],
     shared_stdin: &Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>>,
     elicitation_registry: &Arc<Mutex<ElicitationRegistry>>,
+    framing: DownstreamFraming,
 ) {
     let home = match agent_team_mail_core::home::get_home_dir() {
         Ok(h) => h,
@@ -3291,7 +4391,7 @@ async fn drain_elicitation_queue_for_agents(
                 }
             };
             let mut stdin = shared_stdin.lock().await;
-            if let Err(e) = write_newline_delimited(&mut *stdin, &serialized).await {
+            if let Err(e) = write_child_message(&mut *stdin, &serialized, framing).await {
                 tracing::warn!(agent_id, error=%e, "failed writing elicitation response to child stdin");
                 continue;
             }
@@ -3331,6 +4431,8 @@ async fn dispatch_auto_mail_if_available(
     team: &str,
     max_messages: usize,
     max_message_length: usize,
+    injection_role: MailInjectionRole,
+    downstream_framing: DownstreamFraming,
     registry: &Arc<Mutex<SessionRegistry>>,
     queues: &Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<ThreadCommandQueue>>>>>,
     shared_stdin: &SharedChildStdin,
@@ -3341,6 +4443,9 @@ async fn dispatch_auto_mail_if_available(
     // preserve the MCP/cli-json path unchanged.
     transport_ref: Option<&dyn CodexTransport>,
     inflight: Option<&Arc<Mutex<InflightMailSet>>>,
+    // Idle-poller coalescing deadline (FR-8.16); post-turn dispatch passes
+    // None so continuation turns start immediately.
+    coalesce_deadline: Option<tokio::time::Instant>,
 ) {
     // Defect 3 partial fix: check the command queue first.  If a ClaudeReply
     // was queued while the thread was Busy, dispatch it instead.
@@ -3377,7 +4482,7 @@ async fn dispatch_auto_mail_if_available(
                                     .await
                                     .set_thread_state(agent_id, ThreadState::Busy);
                                 let mut stdin = child_stdin.lock().await;
-                                if write_newline_delimited(&mut *stdin, &serialized)
+                                if write_child_message(&mut *stdin, &serialized, downstream_framing)
                                     .await
                                     .is_ok()
                                 {
@@ -3430,12 +4535,15 @@ async fn dispatch_auto_mail_if_available(
                     team,
                     max_messages,
                     max_message_length,
+                    injection_role,
+                    downstream_framing,
                     registry,
                     shared_stdin,
                     request_counter,
                     pending,
                     active_turn_id,
                     inf,
+                    coalesce_deadline,
                 )
                 .await;
             } else {
@@ -3455,12 +4563,20 @@ async fn dispatch_auto_mail_if_available(
     }
 
     // Single-flight guard: reserve the thread (Idle -> Busy) before fetching
-    // mail to avoid TOCTOU races with concurrent codex-reply requests.
+    // mail to avoid TOCTOU races with concurrent codex-reply requests.  The
+    // reservation is held across any coalescing wait.
     if !try_reserve_thread_for_auto_mail(agent_id, registry).await {
         return;
     }
 
-    let envelopes = fetch_unread_mail(identity, team, max_messages, max_message_length);
+    let envelopes = fetch_unread_mail_coalesced(
+        identity,
+        team,
+        max_messages,
+        max_message_length,
+        coalesce_deadline,
+    )
+    .await;
     if envelopes.is_empty() {
         registry
             .lock()
@@ -3478,7 +4594,7 @@ async fn dispatch_auto_mail_if_available(
         return;
     };
 
-    let content = format_mail_turn_content(&envelopes);
+    let content = format_mail_turn_content(&envelopes, injection_role);
     let auto_req_id = request_counter.fetch_add(1, Ordering::Relaxed);
     let auto_req_id_val = serde_json::Value::Number(auto_req_id.into());
     let auto_msg = json!({
@@ -3503,7 +4619,7 @@ async fn dispatch_auto_mail_if_available(
 
     let write_ok = {
         let mut stdin = child_stdin.lock().await;
-        write_newline_delimited(&mut *stdin, &serialized)
+        write_child_message(&mut *stdin, &serialized, downstream_framing)
             .await
             .is_ok()
     };
@@ -3575,15 +4691,25 @@ async fn dispatch_auto_mail_app_server(
     team: &str,
     max_messages: usize,
     max_message_length: usize,
+    injection_role: MailInjectionRole,
+    downstream_framing: DownstreamFraming,
     registry: &Arc<Mutex<SessionRegistry>>,
     shared_stdin: &SharedChildStdin,
     request_counter: &Arc<AtomicU64>,
     pending: &Arc<Mutex<PendingRequests>>,
     active_turn_id: Option<String>,
     inflight: &Arc<Mutex<InflightMailSet>>,
+    coalesce_deadline: Option<tokio::time::Instant>,
 ) {
-    // 1. Fetch unread mail.
-    let all_envelopes = fetch_unread_mail(identity, team, max_messages, max_message_length);
+    // 1. Fetch unread mail, coalescing until the deadline when one is set.
+    let all_envelopes = fetch_unread_mail_coalesced(
+        identity,
+        team,
+        max_messages,
+        max_message_length,
+        coalesce_deadline,
+    )
+    .await;
     if all_envelopes.is_empty() {
         registry
             .lock()
@@ -3627,7 +4753,7 @@ async fn dispatch_auto_mail_app_server(
     };
 
     // 4. Build the JSON-RPC request (turn/start or turn/steer).
-    let content = format_mail_turn_content(&envelopes);
+    let content = format_mail_turn_content(&envelopes, injection_role);
     let req_id = request_counter.fetch_add(1, Ordering::Relaxed);
     let req_id_val = serde_json::Value::Number(req_id.into());
 
@@ -3674,7 +4800,7 @@ async fn dispatch_auto_mail_app_server(
     // 6. Write to child stdin.
     let write_ok = {
         let mut stdin = child_stdin.lock().await;
-        write_newline_delimited(&mut *stdin, &serialized)
+        write_child_message(&mut *stdin, &serialized, downstream_framing)
             .await
             .is_ok()
     };
@@ -3687,6 +4813,7 @@ async fn dispatch_auto_mail_app_server(
             let mut p = pending.lock().await;
             p.mark_request_source(serde_json::Value::Number(req_id.into()), source.clone());
             p.set_last_agent_source(agent_id.to_string(), source);
+            p.count_auto_mail_turn();
         }
         // FR-8.12: mark-read only after successful dispatch.
         mark_messages_read(identity, team, &dispatched_ids);
@@ -3806,11 +4933,13 @@ async fn route_child_message(
             // channel for delivery because the app-server path has no equivalent direct-write hook.
             let (response_tx, _response_rx) = tokio::sync::oneshot::channel::<Value>();
 
-            // Register in the elicitation registry
-            elicitation_registry.lock().await.register(
+            // Register in the elicitation registry, honoring a child-supplied
+            // `params.timeoutMs` override.
+            elicitation_registry.lock().await.register_with_timeout(
                 agent_id.clone(),
                 downstream_id.clone(),
                 upstream_request_id.clone(),
+                crate::elicitation::timeout_from_params(msg.get("params")),
                 response_tx,
             );
 
@@ -3854,7 +4983,8 @@ async fn route_child_message(
 }
 
 /// Intercept a `tools/list` response to replace the `codex` tool schema with
-/// the extended proxy schema and append all synthetic ATM tools.
+/// the extended proxy schema, add proxy-only `codex-reply` arguments, and
+/// append all synthetic ATM tools.
 ///
 /// This is called on responses from the child that match a `tools/list` request.
 /// The function mutates the response in-place.
@@ -3871,6 +5001,12 @@ pub fn intercept_tools_list(response: &mut Value) {
         {
             *codex_entry = extended_codex;
         }
+        if let Some(reply_entry) = tools_array
+            .iter_mut()
+            .find(|t| t.get("name").and_then(|n| n.as_str()) == Some("codex-reply"))
+        {
+            crate::tools::extend_codex_reply_schema(reply_entry);
+        }
 
         // Append synthetic ATM tools
         for tool in synthetic_tools() {
@@ -3879,23 +5015,191 @@ pub fn intercept_tools_list(response: &mut Value) {
     }
 }
 
+/// Return `true` when `model` may be requested per turn.
+///
+/// An empty allowlist places no restriction on the requested model.
+fn model_allowed(allowed_models: &[String], model: &str) -> bool {
+    allowed_models.is_empty() || allowed_models.iter().any(|m| m == model)
+}
+
+/// Apply the working-directory policy to a requested `cwd`.
+///
+/// Returns `Ok(None)` when neither `cwd_base` nor `restrict_cwd_to` is
+/// configured (the argument is forwarded unchanged), the resolved absolute
+/// directory when it is permitted, or `Err(resolved)` when it falls outside
+/// every allowed root.
+fn resolve_cwd(config: &AgentMcpConfig, cwd: &str) -> Result<Option<PathBuf>, PathBuf> {
+    if config.cwd_base.is_none() && config.restrict_cwd_to.is_empty() {
+        return Ok(None);
+    }
+    let base = config.cwd_base.as_deref();
+    let resolved = absolutize_cwd(base, cwd);
+    let allowed = config.restrict_cwd_to.is_empty()
+        || config
+            .restrict_cwd_to
+            .iter()
+            .any(|root| resolved.starts_with(absolutize_cwd(base, root)));
+    if allowed {
+        Ok(Some(resolved))
+    } else {
+        Err(resolved)
+    }
+}
+
+/// Make `path` absolute relative to `base` (or the proxy's cwd).
+///
+/// The longest existing ancestor is canonicalized (resolving symlinks) and
+/// the missing remainder is appended with `.`/`..` folded lexically, so
+/// neither a symlink nor a not-yet-created directory can escape its root.
+fn absolutize_cwd(base: Option<&str>, path: &str) -> PathBuf {
+    let path = Path::new(path);
+    let joined = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        base.map(PathBuf::from)
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default()
+            .join(path)
+    };
+
+    let mut existing = joined.as_path();
+    let mut missing = Vec::new();
+    let mut resolved = loop {
+        if let Ok(canonical) = std::fs::canonicalize(existing) {
+            break canonical;
+        }
+        match (existing.components().next_back(), existing.parent()) {
+            (Some(last), Some(parent)) => {
+                missing.push(last);
+                existing = parent;
+            }
+            _ => break PathBuf::new(),
+        }
+    };
+    if resolved.as_os_str().is_empty() {
+        missing = joined.components().rev().collect();
+    }
+    for component in missing.into_iter().rev() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            other => resolved.push(other),
+        }
+    }
+    resolved
+}
+
+/// Await a synthetic tool call, returning a `-32006` timeout error if it does
+/// not finish within `limit` (`None` waits indefinitely).
+async fn with_tool_timeout<F>(
+    id: &Value,
+    tool_name: &str,
+    limit: Option<Duration>,
+    call: F,
+) -> Value
+where
+    F: std::future::Future<Output = Value>,
+{
+    let Some(limit) = limit else {
+        return call.await;
+    };
+    match tokio::time::timeout(limit, call).await {
+        Ok(resp) => resp,
+        Err(_) => {
+            tracing::warn!(
+                tool = tool_name,
+                timeout_secs = limit.as_secs(),
+                "synthetic tool call timed out"
+            );
+            make_error_response(
+                id.clone(),
+                ERR_TIMEOUT,
+                &format!("{tool_name} timed out after {}s", limit.as_secs()),
+                json!({"error_source": "proxy", "tool": tool_name}),
+            )
+        }
+    }
+}
+
+/// Timeout applied around a synthetic tool call (`0` secs disables it).
+///
+/// Tools that write inboxes are never raced against the timeout: the blocking
+/// write would keep running after the caller was told it timed out, so a retry
+/// could deliver the message twice. Their lock waits are bounded inside the
+/// write instead (`acquire_lock` retries), so they always report a definite
+/// outcome.
+fn synthetic_tool_timeout(tool_name: &str, secs: u64) -> Option<Duration> {
+    let writes_inbox = matches!(
+        tool_name,
+        "atm_send" | "atm_reply" | "atm_broadcast" | "atm_read"
+    );
+    (secs > 0 && !writes_inbox).then(|| Duration::from_secs(secs))
+}
+
 /// Check whether a tool name belongs to the synthetic ATM tool set.
 fn is_synthetic_tool(name: &str) -> bool {
     matches!(
         name,
         "atm_send"
+            | "atm_reply"
             | "atm_read"
             | "atm_broadcast"
             | "atm_pending_count"
             | "agent_sessions"
             | "agent_status"
+            | "agent_metrics"
             | "agent_close"
+            | "agent_interrupt"
             | "agent_watch_attach"
             | "agent_watch_poll"
             | "agent_watch_detach"
     )
 }
 
+/// Build the `proxy_start` event summarizing the resolved config.
+///
+/// Only an allowlist of settings is included and prompt text is reduced to
+/// whether it is set, so prompt contents never reach the event log.
+fn proxy_start_event(config: &AgentMcpConfig, team: &str) -> EventFields {
+    let summary = json!({
+        "identity": config.identity,
+        "codex_bin": config.codex_bin,
+        "transport": config.transport.as_deref().unwrap_or("mcp"),
+        "transport_recording": config.transport_recording.is_some(),
+        "downstream_framing": config.downstream_framing,
+        "model": config.model,
+        "fast_model": config.fast_model,
+        "sandbox": config.sandbox,
+        "approval_policy": config.approval_policy,
+        "request_timeout_secs": config.request_timeout_secs,
+        "synthetic_tool_timeout_secs": config.synthetic_tool_timeout_secs,
+        "max_concurrent_threads": config.max_concurrent_threads,
+        "auto_mail": config.auto_mail,
+        "mail_poll_interval_ms": config.mail_poll_interval_ms,
+        "max_mail_messages": config.max_mail_messages,
+        "mail_injection_role": config.mail_injection_role,
+        "allow_cross_team": config.allow_cross_team,
+        "max_child_restarts": config.max_child_restarts,
+        "cwd_base": config.cwd_base,
+        "restrict_cwd_to": config.restrict_cwd_to,
+        "shutdown_summary_prompt_set": config.shutdown_summary_prompt.is_some()
+            || config.shutdown_summary_prompt_file.is_some(),
+    });
+    let mut extra_fields = serde_json::Map::new();
+    extra_fields.insert("config".to_string(), summary);
+    EventFields {
+        level: "info",
+        source: "atm-agent-mcp",
+        action: "proxy_start",
+        team: Some(team.to_string()),
+        agent_name: config.identity.clone(),
+        extra_fields,
+        ..Default::default()
+    }
+}
+
 /// Return the proxy start time as `(iso8601_string, epoch_secs)`.
 fn proxy_start_time() -> (String, u64) {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -3927,6 +5231,44 @@ fn epoch_days_to_ymd(mut days: u64) -> (u64, u64, u64) {
     (y, mo, d)
 }
 
+/// JSON-RPC `id` of a response whose line was too long to read, recovered
+/// from the line's leading bytes.
+///
+/// Returns `None` when the head is not a response (it has a `method`) or the
+/// `id` member is not within it.
+fn oversized_response_id(head: &str) -> Option<Value> {
+    let rest = head.trim_start().strip_prefix('{')?;
+    let mut members = rest;
+    loop {
+        let mut stream = serde_json::Deserializer::from_str(members).into_iter::<String>();
+        let key = stream.next()?.ok()?;
+        let after_key = members[stream.byte_offset()..]
+            .trim_start()
+            .strip_prefix(':')?;
+        let mut stream = serde_json::Deserializer::from_str(after_key).into_iter::<Value>();
+        let value = stream.next()?.ok()?;
+        match key.as_str() {
+            "id" if value.is_string() || value.is_number() => return Some(value),
+            "method" => return None,
+            _ => {}
+        }
+        members = after_key[stream.byte_offset()..]
+            .trim_start()
+            .strip_prefix(',')?;
+    }
+}
+
+/// Recipient of a successful `atm_send`/`atm_reply` result, in `to` notation:
+/// `agent`, or `agent@team` outside the proxy's `team`.
+fn sent_recipient(result: &Value, team: &str) -> Option<String> {
+    let sent = result.pointer("/result/structuredContent")?;
+    let agent = sent.get("agent")?.as_str()?;
+    match sent.get("team").and_then(Value::as_str) {
+        Some(sent_team) if sent_team != team => Some(format!("{agent}@{sent_team}")),
+        _ => Some(agent.to_string()),
+    }
+}
+
 /// Construct a JSON-RPC error response.
 pub fn make_error_response(id: Value, code: i64, message: &str, data: Value) -> Value {
     json!({
@@ -3944,6 +5286,31 @@ pub fn make_error_response(id: Value, code: i64, message: &str, data: Value) ->
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_proxy_start_event_summarizes_config() {
+        let config = AgentMcpConfig {
+            identity: Some("arch-ctm".to_string()),
+            model: Some("o4".to_string()),
+            max_concurrent_threads: 3,
+            shutdown_summary_prompt: Some("secret handoff wording".to_string()),
+            ..Default::default()
+        };
+        let event = proxy_start_event(&config, "atm-dev");
+        assert_eq!(event.action, "proxy_start");
+        assert_eq!(event.source, "atm-agent-mcp");
+        assert_eq!(event.team.as_deref(), Some("atm-dev"));
+        assert_eq!(event.agent_name.as_deref(), Some("arch-ctm"));
+
+        let summary = &event.extra_fields["config"];
+        assert_eq!(summary["identity"], "arch-ctm");
+        assert_eq!(summary["model"], "o4");
+        assert_eq!(summary["transport"], "mcp");
+        assert_eq!(summary["max_concurrent_threads"], 3);
+        assert_eq!(summary["request_timeout_secs"], config.request_timeout_secs);
+        assert_eq!(summary["shutdown_summary_prompt_set"], true);
+        assert!(!summary.to_string().contains("secret handoff wording"));
+    }
+
     #[test]
     fn test_intercept_tools_list_appends_synthetic() {
         let mut response = json!({
@@ -3989,6 +5356,7 @@ mod tests {
         assert!(is_synthetic_tool("atm_send"));
         assert!(is_synthetic_tool("atm_read"));
         assert!(is_synthetic_tool("agent_close"));
+        assert!(is_synthetic_tool("agent_interrupt"));
         assert!(is_synthetic_tool("agent_watch_attach"));
         assert!(is_synthetic_tool("agent_watch_poll"));
         assert!(is_synthetic_tool("agent_watch_detach"));
@@ -4431,10 +5799,151 @@ mod tests {
     }
 
     #[test]
-    fn test_make_error_child_dead() {
-        let resp = make_error_response(
-            json!(1),
-            ERR_CHILD_DEAD,
+    fn test_oversized_response_id_reads_id_from_head() {
+        let cut = |line: &str| line[..line.len() - 8].to_string();
+        assert_eq!(
+            oversized_response_id(&cut(
+                r#"{"jsonrpc":"2.0","id":7,"result":{"content":"xxxxxxxx"}}"#
+            )),
+            Some(json!(7))
+        );
+        assert_eq!(
+            oversized_response_id(&cut(
+                r#"{ "jsonrpc": "2.0", "id": "req-1", "result": "xxxxxxxxxx"}"#
+            )),
+            Some(json!("req-1"))
+        );
+        // A notification or request is not answered, and an id past the
+        // head cannot be recovered.
+        assert_eq!(
+            oversized_response_id(&cut(
+                r#"{"jsonrpc":"2.0","method":"event","params":"xxxxxxxx"}"#
+            )),
+            None
+        );
+        assert_eq!(
+            oversized_response_id(&cut(r#"{"result":"xxxxxxxxxxxxxxxx","id":3}"#)),
+            None
+        );
+        assert_eq!(oversized_response_id(""), None);
+    }
+
+    #[test]
+    fn test_child_restart_backoff_doubles_and_caps() {
+        assert_eq!(child_restart_backoff(0), Duration::from_millis(250));
+        assert_eq!(child_restart_backoff(1), Duration::from_millis(500));
+        assert_eq!(child_restart_backoff(3), Duration::from_millis(2_000));
+        assert_eq!(
+            child_restart_backoff(10),
+            Duration::from_millis(CHILD_RESTART_BACKOFF_MAX_MS)
+        );
+        assert_eq!(
+            child_restart_backoff(u32::MAX),
+            Duration::from_millis(CHILD_RESTART_BACKOFF_MAX_MS)
+        );
+    }
+
+    #[test]
+    fn test_pending_clear_on_child_exit_keeps_retryable_requests() {
+        let mut p = PendingRequests::new();
+        let (reply_tx, _reply_rx) = oneshot::channel();
+        let (other_tx, _other_rx) = oneshot::channel();
+        p.insert(json!(1), reply_tx);
+        p.insert(json!(2), other_tx);
+        let forwarded = json!({"id": 1, "params": {"arguments": {"threadId": "t-1"}}});
+        p.mark_retryable(json!(1), forwarded.clone());
+
+        p.clear_on_child_exit(true);
+        assert!(p.map.contains_key(&json!(1)));
+        assert!(!p.map.contains_key(&json!(2)));
+        assert_eq!(p.retryable_messages(), vec![forwarded]);
+
+        let taken = p.take_retryable();
+        assert_eq!(taken.len(), 1);
+        assert_eq!(taken[0].0, json!(1));
+        assert!(p.map.is_empty());
+        assert!(p.retryable_messages().is_empty());
+    }
+
+    #[test]
+    fn test_pending_clear_on_child_exit_without_restarts_clears_all() {
+        let mut p = PendingRequests::new();
+        let (tx, _rx) = oneshot::channel();
+        p.insert(json!(1), tx);
+        p.mark_retryable(json!(1), json!({}));
+
+        p.clear_on_child_exit(false);
+        assert!(p.map.is_empty());
+        assert!(p.retryable.is_empty());
+    }
+
+    #[test]
+    fn test_pending_complete_forgets_retryable() {
+        let mut p = PendingRequests::new();
+        let (tx, _rx) = oneshot::channel();
+        p.insert(json!(1), tx);
+        p.mark_retryable(json!(1), json!({}));
+        assert!(p.complete(&json!(1)).is_some());
+        assert!(p.retryable.is_empty());
+    }
+
+    #[test]
+    fn test_effective_request_timeout_prefers_per_thread_override() {
+        let config = AgentMcpConfig {
+            request_timeout_secs: 300,
+            per_thread_timeout_secs: [("codex:build".to_string(), 1800)].into_iter().collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            effective_request_timeout_secs(&config, Some("codex:build")),
+            1800
+        );
+        assert_eq!(
+            effective_request_timeout_secs(&config, Some("codex:chat")),
+            300
+        );
+        assert_eq!(effective_request_timeout_secs(&config, None), 300);
+    }
+
+    #[test]
+    fn test_summary_duration_reached_respects_threshold() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-03-01T12:00:30Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let started = "2026-03-01T12:00:00Z";
+        assert!(summary_duration_reached(started, 0, now));
+        assert!(summary_duration_reached(started, 30, now));
+        assert!(!summary_duration_reached(started, 31, now));
+        assert!(summary_duration_reached("not-a-timestamp", 3600, now));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_summary_prompt_prefers_file_then_inline() {
+        let mut config = AgentMcpConfig::default();
+        assert_eq!(
+            shutdown_summary_prompt(&config).await,
+            DEFAULT_SHUTDOWN_SUMMARY_PROMPT
+        );
+
+        config.shutdown_summary_prompt = Some("Inline handoff".to_string());
+        assert_eq!(shutdown_summary_prompt(&config).await, "Inline handoff");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("handoff.md");
+        std::fs::write(&path, "File handoff\n").unwrap();
+        config.shutdown_summary_prompt_file = Some(path.to_string_lossy().to_string());
+        assert_eq!(shutdown_summary_prompt(&config).await, "File handoff\n");
+
+        config.shutdown_summary_prompt_file =
+            Some(dir.path().join("missing.md").to_string_lossy().to_string());
+        assert_eq!(shutdown_summary_prompt(&config).await, "Inline handoff");
+    }
+
+    #[test]
+    fn test_make_error_child_dead() {
+        let resp = make_error_response(
+            json!(1),
+            ERR_CHILD_DEAD,
             "Codex child process died (exit code: 1)",
             json!({"error_source": "proxy", "exit_code": 1}),
         );
@@ -4637,7 +6146,6 @@ mod tests {
 
     #[test]
     fn test_constants() {
-        assert_eq!(UPSTREAM_CHANNEL_CAPACITY, 256);
         assert_eq!(CHILD_DRAIN_GRACE_MS, 100);
     }
 
@@ -4650,6 +6158,61 @@ mod tests {
         assert_eq!(ERR_TIMEOUT, -32006);
         assert_eq!(ERR_INVALID_SESSION_PARAMS, -32007);
         assert_eq!(ERR_AGENT_FILE_NOT_FOUND, -32008);
+        assert_eq!(ERR_TURN_INTERRUPTED, -32010);
+        assert_eq!(ERR_MODEL_NOT_ALLOWED, -32011);
+        assert_eq!(ERR_CROSS_TEAM_DENIED, -32012);
+        assert_eq!(ERR_CWD_NOT_ALLOWED, -32013);
+    }
+
+    #[tokio::test]
+    async fn prune_thread_mappings_drops_closed_and_unknown_agents() {
+        let registry = Arc::new(Mutex::new(SessionRegistry::new(8)));
+        let (live, closed) = {
+            let mut reg = registry.lock().await;
+            let mut register = |identity: &str| {
+                reg.register(
+                    identity.to_string(),
+                    "default".to_string(),
+                    ".".to_string(),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap()
+                .agent_id
+            };
+            let live = register("live-agent");
+            let closed = register("closed-agent");
+            reg.close(&closed);
+            (live, closed)
+        };
+        let thread_to_agent: Arc<tokio::sync::Mutex<HashMap<String, String>>> =
+            Arc::new(tokio::sync::Mutex::new(HashMap::from([
+                ("th-live".to_string(), live.clone()),
+                ("th-closed".to_string(), closed.clone()),
+                ("th-gone".to_string(), "codex:missing".to_string()),
+            ])));
+
+        let removed = prune_thread_mappings(&thread_to_agent, &registry).await;
+        assert_eq!(removed, 2);
+        let map = thread_to_agent.lock().await;
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get("th-live"), Some(&live));
+    }
+
+    #[tokio::test]
+    async fn remove_thread_mappings_for_agent_drops_every_thread() {
+        let thread_to_agent: Arc<tokio::sync::Mutex<HashMap<String, String>>> =
+            Arc::new(tokio::sync::Mutex::new(HashMap::from([
+                ("th-1".to_string(), "codex:a".to_string()),
+                ("th-2".to_string(), "codex:a".to_string()),
+                ("th-3".to_string(), "codex:b".to_string()),
+            ])));
+
+        remove_thread_mappings_for_agent(&thread_to_agent, "codex:a").await;
+        let map = thread_to_agent.lock().await;
+        assert_eq!(map.len(), 1);
+        assert!(map.contains_key("th-3"));
     }
 
     #[tokio::test]
@@ -4686,6 +6249,32 @@ mod tests {
         assert_eq!(state, ThreadState::Busy);
     }
 
+    #[tokio::test]
+    async fn auto_mail_master_switch_disables_poller_and_post_turn_dispatch() {
+        let pending = Arc::new(Mutex::new(PendingRequests::new()));
+        let config = crate::config::AgentMcpConfig {
+            auto_mail: false,
+            per_thread_auto_mail: [("codex:forced-on".to_string(), true)]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let proxy = ProxyServer::new_with_team(config, "atm-dev");
+        assert!(
+            proxy.spawn_idle_mail_poller(&pending).is_none(),
+            "no poller task may be spawned when auto-mail is disabled"
+        );
+        // Post-turn dispatch (FR-8.1) is gated on the same flag.
+        assert!(!proxy.mail_poller.is_enabled());
+
+        let enabled =
+            ProxyServer::new_with_team(crate::config::AgentMcpConfig::default(), "atm-dev");
+        let handle = enabled
+            .spawn_idle_mail_poller(&pending)
+            .expect("poller spawns when auto-mail is enabled");
+        handle.abort();
+    }
+
     #[test]
     fn test_proxy_server_new_with_team() {
         let config = crate::config::AgentMcpConfig::default();
@@ -4700,6 +6289,47 @@ mod tests {
         assert_eq!(proxy.team, "default");
     }
 
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn expire_stale_sessions_closes_and_releases_dead_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        unsafe { std::env::set_var("ATM_HOME", dir.path()) };
+
+        let team = "ttl-team";
+        let lock_dir = crate::lock::sessions_dir().join(team);
+        std::fs::create_dir_all(&lock_dir).unwrap();
+        let lock_path = lock_dir.join("gone.lock");
+        std::fs::write(&lock_path, r#"{"pid":999999999,"agent_id":"codex:gone"}"#).unwrap();
+
+        let registry = Arc::new(Mutex::new(SessionRegistry::new(8)));
+        {
+            let mut reg = registry.lock().await;
+            let mut entry = reg
+                .register(
+                    "gone".to_string(),
+                    team.to_string(),
+                    ".".to_string(),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+            reg.mark_all_stale();
+            entry.status = SessionStatus::Stale;
+            entry.last_active = "2026-01-01T00:00:00Z".to_string();
+            reg.insert_stale(entry);
+        }
+
+        let closed = expire_stale_sessions(&registry, team, 60, 0).await;
+        let persisted = std::fs::read_to_string(lock_dir.join("registry.json"));
+        unsafe { std::env::remove_var("ATM_HOME") };
+
+        assert_eq!(closed, 1);
+        assert!(!lock_path.exists(), "dead-PID lock should be cleaned up");
+        assert!(persisted.unwrap().contains("\"closed\""));
+        assert_eq!(expire_stale_sessions(&registry, team, 60, 0).await, 0);
+    }
+
     /// codex call with both agent_file and prompt returns ERR_INVALID_SESSION_PARAMS.
     #[tokio::test]
     #[serial_test::serial]
@@ -4738,6 +6368,210 @@ mod tests {
         );
     }
 
+    /// codex/codex-reply with a model outside allowed_models is rejected before
+    /// the child is spawned.
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn codex_call_with_disallowed_model_returns_model_not_allowed() {
+        let _dir = tempfile::tempdir().unwrap();
+        unsafe { std::env::set_var("ATM_HOME", _dir.path()) };
+
+        let config = crate::config::AgentMcpConfig {
+            allowed_models: vec!["gpt-5-codex".to_string()],
+            ..Default::default()
+        };
+        let mut proxy = ProxyServer::new(config);
+        let (upstream_tx, mut upstream_rx) = mpsc::channel::<Value>(8);
+        let dropped = Arc::new(AtomicU64::new(0));
+        let pending = Arc::new(Mutex::new(PendingRequests::new()));
+
+        for tool in ["codex", "codex-reply"] {
+            let msg = json!({
+                "jsonrpc": "2.0",
+                "id": 3,
+                "method": "tools/call",
+                "params": {
+                    "name": tool,
+                    "arguments": {"prompt": "hello", "model": "o3"}
+                }
+            });
+            proxy
+                .handle_tools_call(msg, &pending, &upstream_tx, &dropped)
+                .await;
+            let resp = upstream_rx.try_recv().expect("should get error response");
+            assert_eq!(
+                resp.pointer("/error/code").and_then(|v| v.as_i64()),
+                Some(ERR_MODEL_NOT_ALLOWED),
+                "{tool}"
+            );
+            assert_eq!(
+                resp.pointer("/error/data/model").and_then(|v| v.as_str()),
+                Some("o3")
+            );
+        }
+        unsafe { std::env::remove_var("ATM_HOME") };
+
+        assert!(proxy.child.is_none());
+        assert!(proxy.registry.lock().await.list_all().is_empty());
+    }
+
+    #[test]
+    fn resolve_cwd_joins_relative_paths_onto_base() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().canonicalize().unwrap();
+        std::fs::create_dir_all(base.join("repos/app")).unwrap();
+        let config = crate::config::AgentMcpConfig {
+            cwd_base: Some(base.to_string_lossy().into_owned()),
+            restrict_cwd_to: vec!["repos".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            resolve_cwd(&config, "repos/app"),
+            Ok(Some(base.join("repos/app")))
+        );
+        assert_eq!(
+            resolve_cwd(&config, "repos/new/../other"),
+            Ok(Some(base.join("repos/other")))
+        );
+        assert_eq!(resolve_cwd(&config, "."), Err(base.clone()));
+        assert_eq!(
+            resolve_cwd(&config, "repos/../../etc"),
+            Err(base.parent().unwrap().join("etc"))
+        );
+        assert_eq!(
+            resolve_cwd(&crate::config::AgentMcpConfig::default(), "/etc"),
+            Ok(None)
+        );
+    }
+
+    /// A missing directory below a symlink resolves through the link, so it
+    /// cannot be used to escape the allowed root.
+    #[cfg(unix)]
+    #[test]
+    fn resolve_cwd_rejects_symlink_escape_through_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        let base = dir.path().canonicalize().unwrap();
+        let outside = outside.path().canonicalize().unwrap();
+        std::fs::create_dir_all(base.join("repos")).unwrap();
+        std::os::unix::fs::symlink(&outside, base.join("repos/link")).unwrap();
+        let config = crate::config::AgentMcpConfig {
+            cwd_base: Some(base.to_string_lossy().into_owned()),
+            restrict_cwd_to: vec!["repos".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            resolve_cwd(&config, "repos/link/new/sub"),
+            Err(outside.join("new/sub"))
+        );
+        assert_eq!(resolve_cwd(&config, "repos/link"), Err(outside.clone()));
+    }
+
+    /// codex/codex-reply with a cwd outside restrict_cwd_to is rejected before
+    /// the child is spawned.
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn codex_call_with_disallowed_cwd_returns_cwd_not_allowed() {
+        let dir = tempfile::tempdir().unwrap();
+        unsafe { std::env::set_var("ATM_HOME", dir.path()) };
+        let base = dir.path().canonicalize().unwrap();
+
+        let config = crate::config::AgentMcpConfig {
+            cwd_base: Some(base.to_string_lossy().into_owned()),
+            restrict_cwd_to: vec!["repos".to_string()],
+            ..Default::default()
+        };
+        let mut proxy = ProxyServer::new(config);
+        let (upstream_tx, mut upstream_rx) = mpsc::channel::<Value>(8);
+        let dropped = Arc::new(AtomicU64::new(0));
+        let pending = Arc::new(Mutex::new(PendingRequests::new()));
+
+        for (tool, args) in [
+            ("codex", json!({"prompt": "hello"})),
+            ("codex", json!({"prompt": "hello", "cwd": "../elsewhere"})),
+            ("codex-reply", json!({"prompt": "hello", "cwd": "/etc"})),
+        ] {
+            let msg = json!({
+                "jsonrpc": "2.0",
+                "id": 3,
+                "method": "tools/call",
+                "params": {"name": tool, "arguments": args}
+            });
+            proxy
+                .handle_tools_call(msg, &pending, &upstream_tx, &dropped)
+                .await;
+            let resp = upstream_rx.try_recv().expect("should get error response");
+            assert_eq!(
+                resp.pointer("/error/code").and_then(|v| v.as_i64()),
+                Some(ERR_CWD_NOT_ALLOWED),
+                "{tool} {args}"
+            );
+            assert_eq!(
+                resp.pointer("/error/data/restrict_cwd_to/0")
+                    .and_then(|v| v.as_str()),
+                Some("repos")
+            );
+        }
+        unsafe { std::env::remove_var("ATM_HOME") };
+
+        assert!(proxy.child.is_none());
+        assert!(proxy.registry.lock().await.list_all().is_empty());
+    }
+
+    #[test]
+    fn model_allowed_empty_list_permits_any_model() {
+        assert!(model_allowed(&[], "o3"));
+        let allowed = vec!["gpt-5-codex".to_string()];
+        assert!(model_allowed(&allowed, "gpt-5-codex"));
+        assert!(!model_allowed(&allowed, "o3"));
+    }
+
+    /// A synthetic tool call stuck behind blocking I/O (e.g. a contended inbox
+    /// lock) returns ERR_TIMEOUT instead of stalling the caller.
+    #[tokio::test]
+    async fn synthetic_tool_timeout_returns_timeout_error() {
+        let stalled = async {
+            tokio::task::spawn_blocking(|| std::thread::sleep(Duration::from_millis(500)))
+                .await
+                .unwrap();
+            json!({"jsonrpc": "2.0", "id": 7, "result": {}})
+        };
+        let started = std::time::Instant::now();
+        let resp = with_tool_timeout(
+            &json!(7),
+            "atm_pending_count",
+            Some(Duration::from_millis(50)),
+            stalled,
+        )
+        .await;
+        assert!(started.elapsed() < Duration::from_millis(500));
+        assert_eq!(resp["id"], 7);
+        assert_eq!(resp["error"]["code"], ERR_TIMEOUT);
+        assert_eq!(resp["error"]["data"]["tool"], "atm_pending_count");
+
+        let done = with_tool_timeout(&json!(8), "atm_read", None, async {
+            json!({"jsonrpc": "2.0", "id": 8, "result": {}})
+        })
+        .await;
+        assert!(done.get("error").is_none());
+    }
+
+    /// Inbox-writing tools are not raced against the timeout, so a timed-out
+    /// caller never retries a write that still lands.
+    #[test]
+    fn synthetic_tool_timeout_skips_inbox_writes() {
+        for tool in ["atm_send", "atm_reply", "atm_broadcast", "atm_read"] {
+            assert_eq!(synthetic_tool_timeout(tool, 30), None, "{tool}");
+        }
+        assert_eq!(
+            synthetic_tool_timeout("atm_pending_count", 30),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(synthetic_tool_timeout("agent_sessions", 0), None);
+    }
+
     /// codex call with a non-existent agent_file returns ERR_AGENT_FILE_NOT_FOUND.
     #[tokio::test]
     #[serial_test::serial]
@@ -4802,6 +6636,103 @@ mod tests {
         assert_eq!(entry.identity, "explicit-identity");
     }
 
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn agent_interrupt_cancels_in_flight_turn_and_audits() {
+        let dir = tempfile::tempdir().unwrap();
+        // SAFETY: isolated tmp dir, no parallelism risk in serial test
+        unsafe { std::env::set_var("ATM_HOME", dir.path()) };
+
+        let mut proxy = ProxyServer::new(crate::config::AgentMcpConfig::default());
+        let agent_id = {
+            let mut reg = proxy.registry.lock().await;
+            let entry = reg
+                .register(
+                    "busy-agent".to_string(),
+                    "default".to_string(),
+                    ".".to_string(),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+            reg.set_thread_state(&entry.agent_id, ThreadState::Busy);
+            entry.agent_id
+        };
+
+        let (upstream_tx, mut upstream_rx) = mpsc::channel::<Value>(8);
+        let dropped = Arc::new(AtomicU64::new(0));
+        let pending = Arc::new(Mutex::new(PendingRequests::new()));
+        let (turn_tx, turn_rx) = oneshot::channel();
+        {
+            let mut p = pending.lock().await;
+            p.insert(json!(42), turn_tx);
+            p.mark_turn(json!(42), agent_id.clone());
+        }
+
+        let interrupt = |call_id: i64, identity: &str| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": call_id,
+                "method": "tools/call",
+                "params": {"name": "agent_interrupt", "arguments": {"identity": identity}}
+            })
+        };
+        let result_of = |resp: Value| -> Value {
+            serde_json::from_str(resp["result"]["content"][0]["text"].as_str().unwrap()).unwrap()
+        };
+
+        proxy
+            .handle_tools_call(interrupt(1, "busy-agent"), &pending, &upstream_tx, &dropped)
+            .await;
+        let result = result_of(upstream_rx.recv().await.unwrap());
+        assert_eq!(result["status"], "cancelled");
+        assert_eq!(result["agent_id"], json!(agent_id));
+        assert_eq!(result["request_id"], json!(42));
+
+        let turn_resp = turn_rx.await.unwrap();
+        assert_eq!(turn_resp["error"]["code"], json!(ERR_TURN_INTERRUPTED));
+        assert_eq!(
+            proxy.registry.lock().await.get_thread_state(&agent_id),
+            Some(ThreadState::Idle)
+        );
+
+        proxy
+            .handle_tools_call(interrupt(2, "busy-agent"), &pending, &upstream_tx, &dropped)
+            .await;
+        assert_eq!(
+            result_of(upstream_rx.recv().await.unwrap())["status"],
+            "not_busy"
+        );
+
+        proxy
+            .handle_tools_call(interrupt(3, "nobody"), &pending, &upstream_tx, &dropped)
+            .await;
+        assert_eq!(
+            result_of(upstream_rx.recv().await.unwrap())["status"],
+            "not_found"
+        );
+
+        let audit = std::fs::read_to_string(
+            crate::lock::sessions_dir()
+                .join("default")
+                .join("audit.jsonl"),
+        )
+        .unwrap();
+        let statuses: Vec<Value> = audit
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .filter(|entry| entry["event_type"] == "agent_interrupt")
+            .map(|entry| entry["message_summary"].clone())
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![json!("cancelled"), json!("not_busy"), json!("not_found")]
+        );
+
+        unsafe { std::env::remove_var("ATM_HOME") };
+    }
+
     /// FR-4.5: in-thread ATM tools must use the thread-bound identity,
     /// not an arbitrary args.identity override.
     #[tokio::test]
@@ -4882,6 +6813,67 @@ mod tests {
         unsafe { std::env::remove_var("ATM_HOME") };
     }
 
+    /// Persisting merges sessions another proxy wrote and honours the lock.
+    #[tokio::test]
+    async fn persist_registry_merges_concurrent_sessions_under_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let sessions_path = dir.path().join("registry.json");
+        let foreign = serde_json::json!({
+            "version": 1,
+            "sessions": [{
+                "agent_id": "codex:other-proxy",
+                "identity": "other",
+                "team": "atm-dev",
+                "thread_id": null,
+                "cwd": ".",
+                "started_at": "2026-02-18T00:00:00Z",
+                "last_active": "2026-02-18T00:00:00Z",
+                "status": "active"
+            }]
+        });
+        std::fs::write(&sessions_path, foreign.to_string()).unwrap();
+
+        let registry = Arc::new(Mutex::new(SessionRegistry::new(10)));
+        registry
+            .lock()
+            .await
+            .register(
+                "arch-ctm".to_string(),
+                "atm-dev".to_string(),
+                ".".to_string(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        ProxyServer::persist_registry(&registry, &sessions_path, 0)
+            .await
+            .unwrap();
+        let written = crate::session::RegistrySnapshot::from_json(
+            &std::fs::read_to_string(&sessions_path).unwrap(),
+        )
+        .unwrap();
+        let identities: Vec<&str> = written
+            .sessions
+            .iter()
+            .map(|e| e.identity.as_str())
+            .collect();
+        assert_eq!(written.sessions.len(), 2);
+        assert!(identities.contains(&"arch-ctm"));
+        assert!(identities.contains(&"other"));
+
+        let _held = agent_team_mail_core::io::lock::acquire_lock(
+            &sessions_path.with_extension("json.lock"),
+            0,
+        )
+        .unwrap();
+        let err = ProxyServer::persist_registry(&registry, &sessions_path, 0)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().to_lowercase().contains("lock"));
+    }
+
     /// FR-3.2: Persisted active sessions loaded on startup are marked stale.
     #[test]
     #[serial_test::serial]
@@ -5148,6 +7140,69 @@ mod tests {
             has_agent_id,
             "extended codex schema must include agent_id property"
         );
+
+        let reply_tool = tools
+            .iter()
+            .find(|t| t.get("name").and_then(|n| n.as_str()) == Some("codex-reply"))
+            .expect("codex-reply tool must be present");
+        assert_eq!(
+            reply_tool.pointer("/inputSchema/properties/omit_context/type"),
+            Some(&json!("boolean")),
+            "codex-reply schema must advertise omit_context"
+        );
+    }
+
+    /// FR-20.6: sessions without an explicit identity take the next free pool entry.
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn resolve_session_identity_assigns_next_free_pool_identity() {
+        let dir = tempfile::tempdir().unwrap();
+        unsafe { std::env::set_var("ATM_HOME", dir.path()) };
+
+        let config = crate::config::AgentMcpConfig {
+            identity: Some("fallback".to_string()),
+            identity_pool: vec!["persona-a".to_string(), "persona-b".to_string()],
+            ..Default::default()
+        };
+        let proxy = ProxyServer::new(config);
+
+        assert_eq!(proxy.resolve_session_identity(None).await, "persona-a");
+        assert_eq!(
+            proxy
+                .resolve_session_identity(Some("explicit".to_string()))
+                .await,
+            "explicit"
+        );
+
+        let register = |identity: &str| {
+            let registry = Arc::clone(&proxy.registry);
+            let identity = identity.to_string();
+            async move {
+                registry
+                    .lock()
+                    .await
+                    .register(
+                        identity,
+                        "default".to_string(),
+                        ".".to_string(),
+                        None,
+                        None,
+                        None,
+                    )
+                    .unwrap();
+            }
+        };
+        register("persona-a").await;
+        assert_eq!(proxy.resolve_session_identity(None).await, "persona-b");
+
+        // Exhausted pool: the first entry is returned so the caller reports a conflict.
+        register("persona-b").await;
+        assert_eq!(proxy.resolve_session_identity(None).await, "persona-a");
+
+        let no_pool = ProxyServer::new(crate::config::AgentMcpConfig::default());
+        assert_eq!(no_pool.resolve_session_identity(None).await, "codex");
+
+        unsafe { std::env::remove_var("ATM_HOME") };
     }
 
     /// Fix 4: IDENTITY_CONFLICT errors use conflicting_agent_id key.
@@ -5315,7 +7370,6 @@ mod tests {
     #[tokio::test]
     #[serial_test::serial]
     async fn mark_read_only_after_successful_dispatch() {
-        use std::collections::HashMap;
         use tempfile::TempDir;
 
         let dir = TempDir::new().unwrap();
@@ -5327,16 +7381,9 @@ mod tests {
         let inbox_dir = dir.path().join(".claude/teams").join(team).join("inboxes");
         std::fs::create_dir_all(&inbox_dir).unwrap();
         let inbox_path = inbox_dir.join(format!("{identity}.json"));
-        let msg = agent_team_mail_core::InboxMessage {
-            from: "alice".to_string(),
-            source_team: None,
-            text: "hello from alice".to_string(),
-            timestamp: "2026-02-22T10:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("test-msg-1".to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let msg = agent_team_mail_core::InboxMessage::new("alice", "hello from alice")
+            .timestamp("2026-02-22T10:00:00Z")
+            .message_id("test-msg-1");
         std::fs::write(
             &inbox_path,
             serde_json::to_string_pretty(&vec![&msg]).unwrap(),
@@ -5388,12 +7435,15 @@ mod tests {
             team,
             10,
             4096,
+            MailInjectionRole::User,
+            DownstreamFraming::Newline,
             &registry,
             &shared_stdin,
             &request_counter,
             &pending,
             None, // Idle: use turn/start
             &inflight,
+            None,
         )
         .await;
 
@@ -5428,6 +7478,284 @@ mod tests {
         unsafe { std::env::remove_var("ATM_HOME") };
     }
 
+    /// Two messages arriving 50ms apart within a 200ms coalescing window are
+    /// injected as a single codex-reply turn (FR-8.16).
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn coalesce_window_batches_mail_into_one_turn() {
+        use std::collections::HashMap;
+        use tempfile::TempDir;
+        use tokio::io::AsyncReadExt;
+
+        let dir = TempDir::new().unwrap();
+        unsafe { std::env::set_var("ATM_HOME", dir.path()) };
+
+        let team = "test-team";
+        let identity = "test-agent";
+        let inbox_dir = dir.path().join(".claude/teams").join(team).join("inboxes");
+        std::fs::create_dir_all(&inbox_dir).unwrap();
+        let inbox_path = inbox_dir.join(format!("{identity}.json"));
+        let make_msg = |id: &str, text: &str| {
+            agent_team_mail_core::InboxMessage::new("alice", text.to_string())
+                .timestamp("2026-02-22T10:00:00Z")
+                .message_id(id.to_string())
+        };
+        let first = make_msg("coalesce-1", "first message");
+        let second = make_msg("coalesce-2", "second message");
+        std::fs::write(
+            &inbox_path,
+            serde_json::to_string_pretty(&vec![&first]).unwrap(),
+        )
+        .unwrap();
+
+        let (write_half, mut read_half) = tokio::io::duplex(64 * 1024);
+        let child_stdin: Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>> = Arc::new(Mutex::new(
+            Box::new(write_half) as Box<dyn AsyncWrite + Send + Unpin>,
+        ));
+        let shared_stdin: SharedChildStdin = Arc::new(Mutex::new(Some(child_stdin)));
+
+        let registry = Arc::new(Mutex::new(SessionRegistry::new(8)));
+        let agent_id = {
+            let mut reg = registry.lock().await;
+            let entry = reg
+                .register(
+                    "test-agent".to_string(),
+                    identity.to_string(),
+                    ".".to_string(),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+            reg.set_thread_state(&entry.agent_id, ThreadState::Idle);
+            entry.agent_id
+        };
+        let queues = Arc::new(Mutex::new(HashMap::new()));
+        let request_counter = Arc::new(AtomicU64::new(1));
+        let pending = Arc::new(Mutex::new(PendingRequests::new()));
+
+        // Deliver the second message 50ms into the window.
+        let late_path = inbox_path.clone();
+        let late_writer = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            let tmp = late_path.with_extension("json.tmp");
+            std::fs::write(
+                &tmp,
+                serde_json::to_string_pretty(&vec![&first, &second]).unwrap(),
+            )
+            .unwrap();
+            std::fs::rename(&tmp, &late_path).unwrap();
+        });
+
+        dispatch_auto_mail_if_available(
+            &agent_id,
+            identity,
+            "thread-1",
+            team,
+            10,
+            4096,
+            MailInjectionRole::User,
+            DownstreamFraming::Newline,
+            &registry,
+            &queues,
+            &shared_stdin,
+            &pending,
+            &request_counter,
+            None,
+            None,
+            Some(tokio::time::Instant::now() + Duration::from_millis(200)),
+        )
+        .await;
+        late_writer.await.unwrap();
+
+        // Close the write half so the reader sees EOF.
+        shared_stdin.lock().await.take();
+        let mut written = String::new();
+        read_half.read_to_string(&mut written).await.unwrap();
+        let turns: Vec<serde_json::Value> = written
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(turns.len(), 1, "expected exactly one coalesced turn");
+        let prompt = turns[0]["params"]["arguments"]["prompt"].as_str().unwrap();
+        assert!(prompt.contains("first message"));
+        assert!(prompt.contains("second message"));
+
+        let content = std::fs::read_to_string(&inbox_path).unwrap();
+        let messages: Vec<agent_team_mail_core::InboxMessage> =
+            serde_json::from_str(&content).unwrap();
+        assert!(messages.iter().all(|m| m.read));
+
+        unsafe { std::env::remove_var("ATM_HOME") };
+    }
+
+    /// With `downstream_framing = "content-length"`, auto-mail reaches the
+    /// child as a Content-Length frame rather than a newline-delimited line.
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn auto_mail_dispatch_uses_content_length_framing() {
+        use std::collections::HashMap;
+        use tokio::io::AsyncReadExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        unsafe { std::env::set_var("ATM_HOME", dir.path()) };
+
+        let team = "test-team";
+        let identity = "test-agent";
+        let inbox_dir = dir.path().join(".claude/teams").join(team).join("inboxes");
+        std::fs::create_dir_all(&inbox_dir).unwrap();
+        let msg = agent_team_mail_core::InboxMessage::new("alice", "framed hello")
+            .timestamp("2026-02-22T10:00:00Z")
+            .message_id("framed-1");
+        std::fs::write(
+            inbox_dir.join(format!("{identity}.json")),
+            serde_json::to_string_pretty(&vec![&msg]).unwrap(),
+        )
+        .unwrap();
+
+        let (write_half, mut read_half) = tokio::io::duplex(64 * 1024);
+        let child_stdin: Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>> = Arc::new(Mutex::new(
+            Box::new(write_half) as Box<dyn AsyncWrite + Send + Unpin>,
+        ));
+        let shared_stdin: SharedChildStdin = Arc::new(Mutex::new(Some(child_stdin)));
+
+        let registry = Arc::new(Mutex::new(SessionRegistry::new(8)));
+        let agent_id = {
+            let mut reg = registry.lock().await;
+            let entry = reg
+                .register(
+                    "test-agent".to_string(),
+                    identity.to_string(),
+                    ".".to_string(),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+            reg.set_thread_state(&entry.agent_id, ThreadState::Idle);
+            entry.agent_id
+        };
+        let queues = Arc::new(Mutex::new(HashMap::new()));
+        let request_counter = Arc::new(AtomicU64::new(1));
+        let pending = Arc::new(Mutex::new(PendingRequests::new()));
+
+        dispatch_auto_mail_if_available(
+            &agent_id,
+            identity,
+            "thread-1",
+            team,
+            10,
+            4096,
+            MailInjectionRole::User,
+            DownstreamFraming::ContentLength,
+            &registry,
+            &queues,
+            &shared_stdin,
+            &pending,
+            &request_counter,
+            None,
+            None,
+            None,
+        )
+        .await;
+
+        shared_stdin.lock().await.take();
+        let mut written = String::new();
+        read_half.read_to_string(&mut written).await.unwrap();
+        let (header, body) = written
+            .split_once("\r\n\r\n")
+            .expect("Content-Length header terminator");
+        assert_eq!(header, format!("Content-Length: {}", body.len()));
+        let frame: Value = serde_json::from_str(body).unwrap();
+        assert_eq!(frame["params"]["name"], "codex-reply");
+        assert!(
+            frame["params"]["arguments"]["prompt"]
+                .as_str()
+                .unwrap()
+                .contains("framed hello")
+        );
+
+        // The child answers in the same framing; the reply parses and
+        // matches the pending auto-mail turn.
+        let reply = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": frame["id"],
+            "result": {"content": []}
+        })
+        .to_string();
+        let encoded = crate::framing::encode_content_length(&reply);
+        let mut child_out =
+            ChildLineReader::new(&encoded[..], 4096).with_framing(DownstreamFraming::ContentLength);
+        let Some(ChildLine::Line(line)) = child_out.next_line().await.unwrap() else {
+            panic!("expected a framed child reply");
+        };
+        let routed: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(
+            pending.lock().await.take_auto_mail(&routed["id"]),
+            Some(agent_id.clone())
+        );
+
+        unsafe { std::env::remove_var("ATM_HOME") };
+    }
+
+    /// FR-2.9: the session-context block is injected into a normal
+    /// `codex-reply` and omitted from one that opts out.
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn codex_reply_omit_context_skips_developer_instructions() {
+        let dir = tempfile::tempdir().unwrap();
+        unsafe { std::env::set_var("ATM_HOME", dir.path()) };
+        let mut proxy = ProxyServer::new(crate::config::AgentMcpConfig::default());
+        let reply = |id: u64, arguments: Value| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "method": "tools/call",
+                "params": {"name": "codex-reply", "arguments": arguments}
+            })
+        };
+
+        let first = proxy
+            .prepare_codex_reply_message(reply(
+                1,
+                json!({"prompt": "start", "threadId": "thread-1"}),
+            ))
+            .await;
+        let instructions = first
+            .pointer("/params/arguments/developer-instructions")
+            .and_then(|v| v.as_str())
+            .expect("first turn carries developer-instructions");
+        assert!(instructions.contains("<session-context>"));
+
+        let opted_out = proxy
+            .prepare_codex_reply_message(reply(
+                2,
+                json!({"prompt": "continue", "threadId": "thread-1", "omit_context": true}),
+            ))
+            .await;
+        let args = opted_out.pointer("/params/arguments").unwrap();
+        assert!(args.get("developer-instructions").is_none());
+        assert!(
+            args.get("omit_context").is_none(),
+            "proxy-only argument must not reach the child"
+        );
+
+        proxy.config.inject_context_on_reply = false;
+        let configured = proxy
+            .prepare_codex_reply_message(reply(
+                3,
+                json!({"prompt": "continue", "threadId": "thread-1"}),
+            ))
+            .await;
+        assert!(
+            configured
+                .pointer("/params/arguments/developer-instructions")
+                .is_none()
+        );
+
+        unsafe { std::env::remove_var("ATM_HOME") };
+    }
+
     /// When ATM_HOME is set, `watch_feed_path` must produce
     /// `$ATM_HOME/watch-stream/<agent-id>.jsonl` (no `.config/atm/` nesting).
     #[test]
@@ -136,16 +136,180 @@ pub enum RegistryError {
 pub struct RegistrySnapshot {
     /// Schema version for forward compatibility.
     ///
-    /// Version 1 is the current and only defined version.
+    /// See [`REGISTRY_SNAPSHOT_VERSION`]. Older versions are upgraded by
+    /// [`RegistrySnapshot::from_json`].
     #[serde(default = "default_registry_version")]
     pub version: u32,
     /// All session entries captured at snapshot time.
     pub sessions: Vec<SessionEntry>,
 }
 
+/// Registry snapshot schema version written by [`SessionRegistry::to_snapshot`].
+///
+/// Version history:
+/// - `0`: pre-versioned layout (no `version` field) using `backend_id` for the
+///   Codex thread and `status: "idle" | "busy"` for the turn state.
+/// - `1`: `thread_id` plus separate `status` / `thread_state` fields.
+///
+/// When bumping this, append a step to [`SNAPSHOT_MIGRATIONS`] so older
+/// on-disk registries are upgraded instead of discarded.
+pub const REGISTRY_SNAPSHOT_VERSION: u32 = 1;
+
 /// Return the current registry snapshot schema version.
 fn default_registry_version() -> u32 {
-    1
+    REGISTRY_SNAPSHOT_VERSION
+}
+
+/// In-place upgrade of raw snapshot JSON by one schema version.
+type SnapshotMigration = fn(&mut serde_json::Value);
+
+/// Migration steps applied to raw snapshot JSON, in order.
+///
+/// Entry `(v, f)` upgrades a version-`v` snapshot to version `v + 1`.
+const SNAPSHOT_MIGRATIONS: &[(u32, SnapshotMigration)] = &[(0, migrate_snapshot_v0_to_v1)];
+
+/// Upgrade a pre-versioned (v0) snapshot to the v1 session layout.
+fn migrate_snapshot_v0_to_v1(snapshot: &mut serde_json::Value) {
+    use serde_json::Value;
+
+    let Some(sessions) = snapshot.get_mut("sessions").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for entry in sessions.iter_mut().filter_map(Value::as_object_mut) {
+        if let Some(backend_id) = entry.remove("backend_id") {
+            entry.entry("thread_id").or_insert(backend_id);
+        }
+        entry.remove("backend");
+        let legacy_turn_state = match entry.get("status").and_then(Value::as_str) {
+            Some("idle") => Some("idle"),
+            Some("busy") => Some("busy"),
+            _ => None,
+        };
+        if let Some(turn_state) = legacy_turn_state {
+            entry.insert("status".to_string(), Value::from("active"));
+            entry
+                .entry("thread_state")
+                .or_insert(Value::from(turn_state));
+        }
+        entry.entry("cwd").or_insert(Value::from("."));
+        let started_at = entry
+            .get("started_at")
+            .or_else(|| entry.get("last_active"))
+            .cloned()
+            .unwrap_or_else(|| Value::from(""));
+        entry.entry("started_at").or_insert(started_at.clone());
+        entry.entry("last_active").or_insert(started_at);
+    }
+}
+
+impl RegistrySnapshot {
+    /// Parse a persisted snapshot, migrating older schema versions.
+    ///
+    /// A missing `version` field denotes the pre-versioned layout (v0). A bare
+    /// JSON array is accepted as a v0 session list. Individual entries that
+    /// still fail to parse after migration are skipped with a warning rather
+    /// than discarding the whole registry. Snapshots from a newer version are
+    /// parsed best-effort.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only when `json` is not valid JSON or has no session
+    /// list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use atm_agent_mcp::session::{RegistrySnapshot, REGISTRY_SNAPSHOT_VERSION};
+    ///
+    /// let snap = RegistrySnapshot::from_json(r#"{"version": 1, "sessions": []}"#).unwrap();
+    /// assert_eq!(snap.version, REGISTRY_SNAPSHOT_VERSION);
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        use serde::de::Error as _;
+        use serde_json::Value;
+
+        let mut value: Value = serde_json::from_str(json)?;
+        if value.is_array() {
+            value = serde_json::json!({ "sessions": value });
+        }
+        let version = value
+            .get("version")
+            .and_then(Value::as_u64)
+            .map_or(0, |v| u32::try_from(v).unwrap_or(u32::MAX));
+        if version > REGISTRY_SNAPSHOT_VERSION {
+            tracing::warn!(
+                version,
+                supported = REGISTRY_SNAPSHOT_VERSION,
+                "registry snapshot is newer than this build; loading best-effort"
+            );
+        }
+        for (from, migrate) in SNAPSHOT_MIGRATIONS {
+            if version <= *from {
+                migrate(&mut value);
+            }
+        }
+
+        let Some(Value::Array(raw_sessions)) = value.get_mut("sessions").map(Value::take) else {
+            return Err(serde_json::Error::custom(
+                "registry snapshot has no sessions array",
+            ));
+        };
+        let sessions = raw_sessions
+            .into_iter()
+            .filter_map(|raw| match serde_json::from_value::<SessionEntry>(raw) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    tracing::warn!("skipping unreadable registry session entry: {e}");
+                    None
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            version: REGISTRY_SNAPSHOT_VERSION,
+            sessions,
+        })
+    }
+
+    /// Merge a snapshot persisted by a concurrent proxy into this one.
+    ///
+    /// Sessions only present in `on_disk` are kept. When both sides hold the
+    /// same `agent_id`, the entry with the later `last_active` wins; on a tie a
+    /// non-stale entry beats a [`SessionStatus::Stale`] one (stale is the
+    /// downgrade applied when another proxy's sessions are loaded at startup),
+    /// otherwise `self` wins.
+    ///
+    /// Returns the number of entries taken from `on_disk`.
+    pub fn merge_concurrent(&mut self, on_disk: RegistrySnapshot) -> usize {
+        let mut index: HashMap<String, usize> = self
+            .sessions
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.agent_id.clone(), i))
+            .collect();
+        let mut taken = 0;
+        for theirs in on_disk.sessions {
+            match index.get(&theirs.agent_id) {
+                Some(&i) => {
+                    let ours = &self.sessions[i];
+                    let newer = theirs.last_active > ours.last_active;
+                    let fresher_tie = theirs.last_active == ours.last_active
+                        && ours.status == SessionStatus::Stale
+                        && theirs.status != SessionStatus::Stale;
+                    if newer || fresher_tie {
+                        self.sessions[i] = theirs;
+                        taken += 1;
+                    }
+                }
+                None => {
+                    index.insert(theirs.agent_id.clone(), self.sessions.len());
+                    self.sessions.push(theirs);
+                    taken += 1;
+                }
+            }
+        }
+        taken
+    }
 }
 
 /// In-memory registry of all agent sessions.
@@ -352,6 +516,30 @@ impl SessionRegistry {
         self.sessions.get(agent_id)
     }
 
+    /// Close every [`SessionStatus::Stale`] session whose `last_active` is at
+    /// least `ttl_secs` before `now_epoch_secs`, returning the closed entries.
+    ///
+    /// Stale sessions hold no identity slot, so only their status changes;
+    /// the caller releases any leftover lock files. Entries whose
+    /// `last_active` cannot be parsed are left untouched.
+    pub fn expire_stale(&mut self, ttl_secs: u64, now_epoch_secs: u64) -> Vec<SessionEntry> {
+        let mut expired = Vec::new();
+        for entry in self.sessions.values_mut() {
+            if entry.status != SessionStatus::Stale {
+                continue;
+            }
+            let Some(last_active) = iso8601_to_epoch_secs(&entry.last_active) else {
+                continue;
+            };
+            if now_epoch_secs.saturating_sub(last_active) >= ttl_secs {
+                entry.status = SessionStatus::Closed;
+                entry.thread_state = ThreadState::Closed;
+                expired.push(entry.clone());
+            }
+        }
+        expired
+    }
+
     /// Insert a pre-built [`SessionEntry`] directly into the registry.
     ///
     /// This is used on startup to load persisted sessions from disk in their
@@ -421,7 +609,7 @@ impl SessionRegistry {
     /// ```
     pub fn to_snapshot(&self) -> RegistrySnapshot {
         RegistrySnapshot {
-            version: 1,
+            version: REGISTRY_SNAPSHOT_VERSION,
             sessions: self.sessions.values().cloned().collect(),
         }
     }
@@ -471,6 +659,12 @@ fn now_iso8601() -> String {
     epoch_secs_to_iso8601(secs)
 }
 
+/// Parse an ISO 8601 / RFC 3339 timestamp into Unix epoch seconds.
+fn iso8601_to_epoch_secs(timestamp: &str) -> Option<u64> {
+    let parsed = chrono::DateTime::parse_from_rfc3339(timestamp).ok()?;
+    u64::try_from(parsed.timestamp()).ok()
+}
+
 /// Convert Unix epoch seconds to `"YYYY-MM-DDTHH:MM:SSZ"`.
 ///
 /// Handles dates from 1970 through 2999.
@@ -587,6 +781,45 @@ mod tests {
         assert_eq!(r.list_all().len(), 2);
     }
 
+    #[test]
+    fn expire_stale_closes_sessions_at_ttl_edge() {
+        let mut r = make_registry(10);
+        let now = 1_770_000_000;
+        let ttl = 3600;
+        let mut ids = Vec::new();
+        for (identity, age) in [
+            ("at-edge", ttl),
+            ("just-under", ttl - 1),
+            ("older", ttl * 2),
+        ] {
+            let id = reg_entry(&mut r, identity).unwrap().agent_id;
+            r.sessions.get_mut(&id).unwrap().last_active = epoch_secs_to_iso8601(now - age);
+            ids.push(id);
+        }
+        let active = reg_entry(&mut r, "still-active").unwrap().agent_id;
+        r.sessions.get_mut(&active).unwrap().last_active = epoch_secs_to_iso8601(0);
+        for id in &ids {
+            r.sessions.get_mut(id).unwrap().status = SessionStatus::Stale;
+        }
+
+        let mut expired: Vec<String> = r
+            .expire_stale(ttl, now)
+            .into_iter()
+            .map(|e| e.identity)
+            .collect();
+        expired.sort();
+        assert_eq!(expired, vec!["at-edge", "older"]);
+        assert_eq!(r.get(&ids[0]).unwrap().status, SessionStatus::Closed);
+        assert_eq!(r.get(&ids[0]).unwrap().thread_state, ThreadState::Closed);
+        assert_eq!(r.get(&ids[1]).unwrap().status, SessionStatus::Stale);
+        assert_eq!(r.get(&active).unwrap().status, SessionStatus::Active);
+
+        // One second later the session that was just under the TTL expires.
+        let expired = r.expire_stale(ttl, now + 1);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].identity, "just-under");
+    }
+
     #[test]
     fn resume_stale_session_makes_it_active() {
         let mut r = make_registry(10);
@@ -792,6 +1025,63 @@ mod tests {
         assert!(r.list_all().is_empty());
     }
 
+    fn snapshot_entry(agent_id: &str, last_active: &str, status: SessionStatus) -> SessionEntry {
+        SessionEntry {
+            agent_id: agent_id.to_string(),
+            identity: agent_id.to_string(),
+            team: "atm-dev".to_string(),
+            thread_id: None,
+            cwd: ".".to_string(),
+            repo_root: None,
+            repo_name: None,
+            branch: None,
+            started_at: "2026-01-01T00:00:00Z".to_string(),
+            last_active: last_active.to_string(),
+            status,
+            thread_state: ThreadState::Idle,
+            tag: None,
+            agent_source: None,
+        }
+    }
+
+    #[test]
+    fn merge_concurrent_keeps_foreign_sessions_and_newest_entries() {
+        let mut ours = RegistrySnapshot {
+            version: 1,
+            sessions: vec![
+                snapshot_entry("codex:a", "2026-01-01T00:00:05Z", SessionStatus::Active),
+                snapshot_entry("codex:b", "2026-01-01T00:00:01Z", SessionStatus::Active),
+                snapshot_entry("codex:c", "2026-01-01T00:00:01Z", SessionStatus::Stale),
+            ],
+        };
+        let on_disk = RegistrySnapshot {
+            version: 1,
+            sessions: vec![
+                // Older than ours: ignored.
+                snapshot_entry("codex:a", "2026-01-01T00:00:01Z", SessionStatus::Closed),
+                // Newer than ours: taken.
+                snapshot_entry("codex:b", "2026-01-01T00:00:09Z", SessionStatus::Closed),
+                // Same timestamp, ours is a stale load-time copy: taken.
+                snapshot_entry("codex:c", "2026-01-01T00:00:01Z", SessionStatus::Active),
+                // Written by another proxy only: kept.
+                snapshot_entry("codex:d", "2026-01-01T00:00:02Z", SessionStatus::Active),
+            ],
+        };
+
+        assert_eq!(ours.merge_concurrent(on_disk), 3);
+        let status = |id: &str| {
+            ours.sessions
+                .iter()
+                .find(|e| e.agent_id == id)
+                .map(|e| e.status.clone())
+        };
+        assert_eq!(ours.sessions.len(), 4);
+        assert_eq!(status("codex:a"), Some(SessionStatus::Active));
+        assert_eq!(status("codex:b"), Some(SessionStatus::Closed));
+        assert_eq!(status("codex:c"), Some(SessionStatus::Active));
+        assert_eq!(status("codex:d"), Some(SessionStatus::Active));
+    }
+
     #[test]
     fn load_from_snapshot_marks_active_as_stale() {
         let entry = SessionEntry {
@@ -887,6 +1177,57 @@ mod tests {
         assert_eq!(loaded.thread_id, Some("thread-rt-1".to_string()));
     }
 
+    #[test]
+    fn from_json_migrates_pre_versioned_snapshot() {
+        let legacy = r#"{
+            "sessions": [
+                {
+                    "agent_id": "codex:legacy-1",
+                    "backend": "codex",
+                    "backend_id": "thread-legacy",
+                    "identity": "arch-ctm",
+                    "team": "atm-dev",
+                    "repo_root": null,
+                    "repo_name": null,
+                    "branch": null,
+                    "cwd": "/tmp/project",
+                    "started_at": "2026-02-17T10:00:00Z",
+                    "last_active": "2026-02-17T10:45:00Z",
+                    "status": "idle",
+                    "tag": "feature/auth"
+                },
+                {"agent_id": 42}
+            ]
+        }"#;
+        assert!(
+            serde_json::from_str::<RegistrySnapshot>(legacy).is_err(),
+            "v0 layout must not parse directly"
+        );
+
+        let snap = RegistrySnapshot::from_json(legacy).unwrap();
+        assert_eq!(snap.version, REGISTRY_SNAPSHOT_VERSION);
+        assert_eq!(snap.sessions.len(), 1, "unreadable entry is skipped");
+
+        let r = SessionRegistry::load_from_snapshot(snap, 10);
+        let entry = r
+            .get("codex:legacy-1")
+            .expect("legacy session must survive");
+        assert_eq!(entry.thread_id.as_deref(), Some("thread-legacy"));
+        assert_eq!(entry.status, SessionStatus::Stale);
+        assert_eq!(entry.thread_state, ThreadState::Idle);
+        assert_eq!(entry.tag.as_deref(), Some("feature/auth"));
+    }
+
+    #[test]
+    fn from_json_current_version_round_trip() {
+        let mut r = make_registry(10);
+        reg_entry(&mut r, "arch-ctm").unwrap();
+        let json = serde_json::to_string(&r.to_snapshot()).unwrap();
+        let snap = RegistrySnapshot::from_json(&json).unwrap();
+        assert_eq!(snap.sessions.len(), 1);
+        assert!(RegistrySnapshot::from_json("not json").is_err());
+    }
+
     // ─── ThreadState / set_thread_state / get_thread_state ──────────────────
 
     #[test]
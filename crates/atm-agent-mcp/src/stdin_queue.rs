@@ -36,6 +36,7 @@
 //!
 //! Entries (`.json` and `.lock`) older than 10 minutes are deleted on drain.
 
+use crate::config::DownstreamFraming;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
@@ -83,7 +84,8 @@ pub async fn enqueue(team: &str, agent_id: &str, content: &str) -> anyhow::Resul
 /// 1. Attempt to atomically create `{uuid}.lock` with `create_new(true)`.  If
 ///    the lock file already exists, another drainer owns this entry — skip it.
 /// 2. Read `{uuid}.json`.
-/// 3. Write content + `\n` to the provided stdin writer.
+/// 3. Write content to the provided stdin writer with the configured
+///    `framing` (see [`crate::framing::write_child_message`]).
 /// 4. On success: delete `{uuid}.json` then `{uuid}.lock`.
 /// 5. On write failure: delete `{uuid}.lock` only; leave `{uuid}.json` so the
 ///    next drain cycle can retry.
@@ -101,6 +103,7 @@ pub async fn drain(
     agent_id: &str,
     stdin: &Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>>,
     ttl: Duration,
+    framing: DownstreamFraming,
 ) -> anyhow::Result<usize> {
     let dir = queue_dir(team, agent_id)?;
 
@@ -189,7 +192,7 @@ pub async fn drain(
         // Write content to stdin.
         let write_result = {
             let mut guard = stdin.lock().await;
-            crate::framing::write_newline_delimited(&mut **guard, content.trim()).await
+            crate::framing::write_child_message(&mut **guard, content.trim(), framing).await
         };
 
         match write_result {
@@ -346,9 +349,15 @@ mod tests {
         let stdin: Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>> =
             Arc::new(Mutex::new(Box::new(writer)));
 
-        let count = drain(&team, &agent_id, &stdin, Duration::from_secs(600))
-            .await
-            .unwrap();
+        let count = drain(
+            &team,
+            &agent_id,
+            &stdin,
+            Duration::from_secs(600),
+            DownstreamFraming::Newline,
+        )
+        .await
+        .unwrap();
         assert_eq!(count, 2);
 
         let output = captured.lock().unwrap().clone();
@@ -373,6 +382,36 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn drain_uses_content_length_framing() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (team, agent_id) = setup_env(&tmp);
+
+        let msg = r#"{"type":"tool_result","data":"hello"}"#;
+        enqueue(&team, &agent_id, msg).await.unwrap();
+
+        let (writer, captured) = SharedCapWriter::new();
+        let stdin: Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>> =
+            Arc::new(Mutex::new(Box::new(writer)));
+        let count = drain(
+            &team,
+            &agent_id,
+            &stdin,
+            Duration::from_secs(600),
+            DownstreamFraming::ContentLength,
+        )
+        .await
+        .unwrap();
+        assert_eq!(count, 1);
+
+        let output = captured.lock().unwrap().clone();
+        assert_eq!(
+            String::from_utf8_lossy(&output),
+            format!("Content-Length: {}\r\n\r\n{msg}", msg.len())
+        );
+    }
+
     #[tokio::test]
     #[serial_test::serial]
     async fn drain_empty_queue_returns_zero() {
@@ -384,9 +423,15 @@ mod tests {
             Arc::new(Mutex::new(Box::new(writer)));
 
         // Queue dir doesn't exist yet -- should return 0, not error
-        let count = drain(&team, &agent_id, &stdin, Duration::from_secs(600))
-            .await
-            .unwrap();
+        let count = drain(
+            &team,
+            &agent_id,
+            &stdin,
+            Duration::from_secs(600),
+            DownstreamFraming::Newline,
+        )
+        .await
+        .unwrap();
         assert_eq!(count, 0);
     }
 
@@ -420,8 +465,20 @@ mod tests {
         let stdin_b = Arc::clone(&stdin2);
 
         let (count_a, count_b) = tokio::join!(
-            drain(&team_a, &agent_a, &stdin_a, Duration::from_secs(600)),
-            drain(&team_b, &agent_b, &stdin_b, Duration::from_secs(600)),
+            drain(
+                &team_a,
+                &agent_a,
+                &stdin_a,
+                Duration::from_secs(600),
+                DownstreamFraming::Newline
+            ),
+            drain(
+                &team_b,
+                &agent_b,
+                &stdin_b,
+                Duration::from_secs(600),
+                DownstreamFraming::Newline
+            ),
         );
 
         let total = count_a.unwrap() + count_b.unwrap();
@@ -487,8 +544,20 @@ mod tests {
 
         // Drain concurrently.
         let (count_a, count_b) = tokio::join!(
-            drain(&team_a, &agent_a, &stdin_a, Duration::from_secs(600)),
-            drain(&team_b, &agent_b, &stdin_b, Duration::from_secs(600)),
+            drain(
+                &team_a,
+                &agent_a,
+                &stdin_a,
+                Duration::from_secs(600),
+                DownstreamFraming::Newline
+            ),
+            drain(
+                &team_b,
+                &agent_b,
+                &stdin_b,
+                Duration::from_secs(600),
+                DownstreamFraming::Newline
+            ),
         );
 
         let total = count_a.unwrap() + count_b.unwrap();
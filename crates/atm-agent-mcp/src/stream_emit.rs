@@ -55,7 +55,11 @@ async fn emit_stream_event_unix(event: &DaemonStreamEvent) -> anyhow::Result<()>
     let socket_path = agent_team_mail_core::daemon_client::daemon_socket_path()?;
 
     // Attempt connection — return early (without error) if daemon not running.
-    let stream = match UnixStream::connect(&socket_path).await {
+    let stream = match agent_team_mail_core::daemon_client::connect_daemon_socket(&socket_path)
+        .and_then(|s| {
+            s.set_nonblocking(true)?;
+            UnixStream::from_std(s)
+        }) {
         Ok(s) => s,
         Err(_) => {
             // Daemon not running — expected in CI / development without a daemon.
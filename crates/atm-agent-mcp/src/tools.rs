@@ -17,7 +17,7 @@
 use serde_json::{Value, json};
 
 /// Number of synthetic tools that the proxy appends to `tools/list` responses.
-pub const SYNTHETIC_TOOL_COUNT: usize = 10;
+pub const SYNTHETIC_TOOL_COUNT: usize = 13;
 
 /// Extended `codex` tool parameter schema accepted by the proxy layer (FR-16.4).
 ///
@@ -55,12 +55,47 @@ pub fn codex_tool_schema() -> Value {
                 "cwd": {
                     "type": "string",
                     "description": "Working directory for this session (used for git context detection)"
+                },
+                "model": {
+                    "type": "string",
+                    "description": "Model for this turn. Must be listed in allowed_models when that config is set."
                 }
             }
         }
     })
 }
 
+/// Add the proxy-only `codex-reply` arguments to the child's `codex-reply`
+/// tool entry (FR-2.9).
+///
+/// The child owns the rest of the schema; only
+/// [`OMIT_CONTEXT_ARG`](crate::inject::OMIT_CONTEXT_ARG) is added, creating
+/// `inputSchema.properties` when the child omitted it.
+pub fn extend_codex_reply_schema(tool: &mut Value) {
+    let Some(tool) = tool.as_object_mut() else {
+        return;
+    };
+    let schema = tool
+        .entry("inputSchema")
+        .or_insert_with(|| json!({"type": "object"}));
+    let Some(schema) = schema.as_object_mut() else {
+        return;
+    };
+    if let Some(properties) = schema
+        .entry("properties")
+        .or_insert_with(|| json!({}))
+        .as_object_mut()
+    {
+        properties.insert(
+            crate::inject::OMIT_CONTEXT_ARG.to_string(),
+            json!({
+                "type": "boolean",
+                "description": "Skip ATM session context injection for this turn"
+            }),
+        );
+    }
+}
+
 /// Return all synthetic tool definitions as JSON values.
 ///
 /// These are appended to the `result.tools` array in `tools/list` responses
@@ -68,12 +103,15 @@ pub fn codex_tool_schema() -> Value {
 pub fn synthetic_tools() -> Vec<Value> {
     vec![
         atm_send_schema(),
+        atm_reply_schema(),
         atm_read_schema(),
         atm_broadcast_schema(),
         atm_pending_count_schema(),
         agent_sessions_schema(),
         agent_status_schema(),
+        agent_metrics_schema(),
         agent_close_schema(),
+        agent_interrupt_schema(),
         agent_watch_attach_schema(),
         agent_watch_poll_schema(),
         agent_watch_detach_schema(),
@@ -87,9 +125,10 @@ fn atm_send_schema() -> Value {
         "inputSchema": {
             "type": "object",
             "properties": {
-                "to": {"type": "string", "description": "Recipient agent name or agent@team"},
+                "to": {"type": "string", "description": "Recipient agent name or agent@team (other teams require allow_cross_team)"},
                 "message": {"type": "string", "description": "Message text"},
                 "summary": {"type": "string", "description": "Optional message summary"},
+                "priority": {"type": "integer", "minimum": 0, "maximum": 255, "description": "Delivery priority; higher is read first (default: 0)"},
                 "identity": {"type": "string", "description": "Explicit sender identity (required outside thread context)"}
             },
             "required": ["to", "message"]
@@ -97,6 +136,23 @@ fn atm_send_schema() -> Value {
     })
 }
 
+fn atm_reply_schema() -> Value {
+    json!({
+        "name": "atm_reply",
+        "description": "Reply to the sender of the most recent unread (else most recent) inbox message",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "message": {"type": "string", "description": "Reply text"},
+                "to": {"type": "string", "description": "Override recipient (agent or agent@team)"},
+                "summary": {"type": "string", "description": "Optional message summary"},
+                "identity": {"type": "string", "description": "Explicit sender identity (required outside thread context)"}
+            },
+            "required": ["message"]
+        }
+    })
+}
+
 fn atm_read_schema() -> Value {
     json!({
         "name": "atm_read",
@@ -124,7 +180,7 @@ fn atm_broadcast_schema() -> Value {
             "properties": {
                 "message": {"type": "string", "description": "Message text"},
                 "summary": {"type": "string", "description": "Optional message summary"},
-                "team": {"type": "string", "description": "Override target team"},
+                "team": {"type": "string", "description": "Override target team (requires allow_cross_team)"},
                 "identity": {"type": "string", "description": "Explicit sender identity (required outside thread context)"}
             },
             "required": ["message"]
@@ -169,6 +225,17 @@ fn agent_status_schema() -> Value {
     })
 }
 
+fn agent_metrics_schema() -> Value {
+    json!({
+        "name": "agent_metrics",
+        "description": "Get proxy internal counters (dropped events, pending requests, sessions, auto-mail turns, elicitation timeouts, pending elicitations, channel backpressure)",
+        "inputSchema": {
+            "type": "object",
+            "properties": {}
+        }
+    })
+}
+
 fn agent_close_schema() -> Value {
     json!({
         "name": "agent_close",
@@ -183,6 +250,20 @@ fn agent_close_schema() -> Value {
     })
 }
 
+fn agent_interrupt_schema() -> Value {
+    json!({
+        "name": "agent_interrupt",
+        "description": "Cancel an agent session's in-flight turn and return its thread to idle",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "agent_id": {"type": "string", "description": "Agent ID to interrupt"},
+                "identity": {"type": "string", "description": "Identity to interrupt (alternative to agent_id)"}
+            }
+        }
+    })
+}
+
 fn agent_watch_attach_schema() -> Value {
     json!({
         "name": "agent_watch_attach",
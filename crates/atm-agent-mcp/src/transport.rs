@@ -4,7 +4,8 @@
 //! and the underlying child-process implementation.  Production code uses
 //! [`McpTransport`] (spawns `codex mcp-server`) or [`JsonCodecTransport`]
 //! (spawns `codex exec --json`).  [`MockTransport`] is an in-memory test
-//! double for integration tests.
+//! double for integration tests.  [`RecordingTransport`] captures a real
+//! session to JSONL and [`ReplayTransport`] serves it back without Codex.
 //!
 //! # Design notes
 //!
@@ -23,10 +24,11 @@ use std::io;
 use std::pin::Pin;
 use std::process::ExitStatus;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::task::{Context, Poll};
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::process::Child;
@@ -1671,6 +1673,9 @@ impl CodexTransport for AppServerTransport {
 ///
 /// Unknown values fall back to `McpTransport` with a `tracing::warn`.
 ///
+/// When `transport_recording` is set, the selected transport is wrapped in a
+/// [`RecordingTransport`] writing to that path.
+///
 /// Returns a `Box<dyn CodexTransport>` so callers can store the transport
 /// without knowing the concrete type.
 ///
@@ -1681,6 +1686,14 @@ impl CodexTransport for AppServerTransport {
 /// requests must construct [`MockTransport`] directly via
 /// [`MockTransport::new_with_handle`].
 pub(crate) fn make_transport(config: &AgentMcpConfig, team: &str) -> Box<dyn CodexTransport> {
+    let transport = select_transport(config, team);
+    match config.transport_recording.as_deref() {
+        Some(path) => Box::new(RecordingTransport::new(transport, path)),
+        None => transport,
+    }
+}
+
+fn select_transport(config: &AgentMcpConfig, team: &str) -> Box<dyn CodexTransport> {
     match config.transport.as_deref() {
         None | Some("mcp") => Box::new(McpTransport::new(config.clone(), team)),
         Some("cli-json") => Box::new(JsonCodecTransport::new(config.clone(), team)),
@@ -1908,6 +1921,471 @@ impl AsyncWrite for SniffWriter {
     }
 }
 
+// ─── RecordingTransport / ReplayTransport ────────────────────────────────────
+
+/// Direction of a recorded chunk relative to the Codex child.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordDirection {
+    /// Bytes the proxy wrote to the child's stdin.
+    ToChild,
+    /// Bytes the proxy read from the child's stdout.
+    FromChild,
+    /// Marks the start of one spawned child session; `data` is empty.
+    SessionStart,
+}
+
+/// One JSONL record of a transport recording.
+///
+/// Each record holds one newline-terminated line (or a trailing partial line
+/// at shutdown), so concatenating `data` per direction reproduces the exact
+/// byte stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedChunk {
+    /// Which way the bytes travelled, or a session boundary.
+    pub dir: RecordDirection,
+    /// Milliseconds since the session was spawned.
+    pub offset_ms: u64,
+    /// The raw bytes: a JSON string when they are valid UTF-8, otherwise an
+    /// array of byte values.
+    #[serde(with = "chunk_data")]
+    pub data: Vec<u8>,
+}
+
+/// Serde representation of [`RecordedChunk::data`].
+mod chunk_data {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Text(String),
+        Bytes(Vec<u8>),
+    }
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        match std::str::from_utf8(bytes) {
+            Ok(text) => text.serialize(serializer),
+            Err(_) => bytes.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Text(text) => text.into_bytes(),
+            Repr::Bytes(bytes) => bytes,
+        })
+    }
+}
+
+/// Shared sink that appends [`RecordedChunk`]s to a JSONL file.
+///
+/// Bytes are buffered per direction and recorded one complete line at a time.
+/// Records are handed to a writer thread, so the read and write paths being
+/// observed never block on file I/O.
+#[derive(Debug)]
+struct Recorder {
+    started: std::time::Instant,
+    state: std::sync::Mutex<RecorderState>,
+}
+
+#[derive(Debug)]
+struct RecorderState {
+    chunks: std::sync::mpsc::Sender<RecordedChunk>,
+    to_child: Vec<u8>,
+    from_child: Vec<u8>,
+}
+
+impl Recorder {
+    /// Open `path` for appending, start its writer thread, and record a
+    /// [`RecordDirection::SessionStart`] marker.
+    ///
+    /// The file is created with mode `0600` on Unix: recordings hold the full
+    /// session traffic, including message bodies.
+    fn create(path: &std::path::Path) -> io::Result<Self> {
+        let mut options = std::fs::OpenOptions::new();
+        options.create(true).append(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt as _;
+            options.mode(0o600);
+        }
+        let file = options.open(path)?;
+        let (chunks, rx) = std::sync::mpsc::channel();
+        let _ = chunks.send(RecordedChunk {
+            dir: RecordDirection::SessionStart,
+            offset_ms: 0,
+            data: Vec::new(),
+        });
+        std::thread::Builder::new()
+            .name("atm-transport-recorder".to_string())
+            .spawn(move || write_chunks(file, rx))?;
+        Ok(Self {
+            started: std::time::Instant::now(),
+            state: std::sync::Mutex::new(RecorderState {
+                chunks,
+                to_child: Vec::new(),
+                from_child: Vec::new(),
+            }),
+        })
+    }
+
+    fn record(&self, dir: RecordDirection, bytes: &[u8]) {
+        let offset_ms = self.started.elapsed().as_millis() as u64;
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let RecorderState {
+            chunks,
+            to_child,
+            from_child,
+        } = &mut *state;
+        let buf = match dir {
+            RecordDirection::ToChild => to_child,
+            RecordDirection::FromChild => from_child,
+            RecordDirection::SessionStart => return,
+        };
+        buf.extend_from_slice(bytes);
+        while let Some(pos) = buf.iter().position(|&b| b == b'\n') {
+            let data: Vec<u8> = buf.drain(..=pos).collect();
+            let _ = chunks.send(RecordedChunk {
+                dir,
+                offset_ms,
+                data,
+            });
+        }
+    }
+
+    /// Record any buffered partial line for `dir`.
+    fn flush_partial(&self, dir: RecordDirection) {
+        let offset_ms = self.started.elapsed().as_millis() as u64;
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let RecorderState {
+            chunks,
+            to_child,
+            from_child,
+        } = &mut *state;
+        let buf = match dir {
+            RecordDirection::ToChild => to_child,
+            RecordDirection::FromChild => from_child,
+            RecordDirection::SessionStart => return,
+        };
+        if !buf.is_empty() {
+            let data = std::mem::take(buf);
+            let _ = chunks.send(RecordedChunk {
+                dir,
+                offset_ms,
+                data,
+            });
+        }
+    }
+}
+
+/// Writer thread body: append each record until the [`Recorder`] is dropped.
+///
+/// Best-effort; a failing recording never breaks the session it observes.
+fn write_chunks(mut file: std::fs::File, rx: std::sync::mpsc::Receiver<RecordedChunk>) {
+    use std::io::Write as _;
+
+    for chunk in rx {
+        if let Ok(mut line) = serde_json::to_vec(&chunk) {
+            line.push(b'\n');
+            if let Err(e) = file.write_all(&line) {
+                tracing::warn!(error = %e, "failed to write transport recording");
+            }
+        }
+    }
+}
+
+/// [`AsyncWrite`] wrapper that tees accepted bytes to a [`Recorder`].
+struct RecordingWriter {
+    inner: Box<dyn AsyncWrite + Send + Unpin>,
+    recorder: Arc<Recorder>,
+}
+
+impl AsyncWrite for RecordingWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let poll = Pin::new(&mut self.inner).poll_write(cx, buf);
+        if let Poll::Ready(Ok(n)) = &poll {
+            self.recorder.record(RecordDirection::ToChild, &buf[..*n]);
+        }
+        poll
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.recorder.flush_partial(RecordDirection::ToChild);
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+/// [`AsyncRead`] wrapper that tees every byte read to a [`Recorder`].
+struct RecordingReader {
+    inner: Box<dyn AsyncRead + Send + Unpin>,
+    recorder: Arc<Recorder>,
+}
+
+impl AsyncRead for RecordingReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let before = buf.filled().len();
+        let poll = Pin::new(&mut self.inner).poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = &poll {
+            let read = &buf.filled()[before..];
+            if read.is_empty() {
+                self.recorder.flush_partial(RecordDirection::FromChild);
+            } else {
+                self.recorder.record(RecordDirection::FromChild, read);
+            }
+        }
+        poll
+    }
+}
+
+/// Transport decorator that tees every byte exchanged with the child to a
+/// JSONL recording of [`RecordedChunk`]s.
+///
+/// Enabled for any configured transport by setting
+/// `[plugins.atm-agent-mcp].transport_recording`; the resulting file can be
+/// served back by [`ReplayTransport`]. Each spawn appends a
+/// [`RecordDirection::SessionStart`] marker and its session to the recording,
+/// so a respawned child does not erase the session that came before it.
+#[derive(Debug)]
+pub struct RecordingTransport {
+    inner: Box<dyn CodexTransport>,
+    path: std::path::PathBuf,
+}
+
+impl RecordingTransport {
+    /// Wrap `inner`, recording each spawned session to `path`.
+    pub(crate) fn new(inner: Box<dyn CodexTransport>, path: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            inner,
+            path: path.into(),
+        }
+    }
+
+    /// Wrap already-spawned child I/O so every byte in both directions is
+    /// recorded to `path` (created with mode `0600`, or appended to) as a new
+    /// session.
+    ///
+    /// The shared stdin writer is replaced in place, so clones of
+    /// `io.stdin` held elsewhere are recorded too.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recording file cannot be created.
+    pub async fn wrap_io(io: RawChildIo, path: &std::path::Path) -> anyhow::Result<RawChildIo> {
+        let recorder = Arc::new(Recorder::create(path).map_err(|e| {
+            anyhow::anyhow!(
+                "failed to create transport recording {}: {e}",
+                path.display()
+            )
+        })?);
+        {
+            let mut stdin = io.stdin.lock().await;
+            let inner = std::mem::replace(
+                &mut *stdin,
+                Box::new(tokio::io::sink()) as Box<dyn AsyncWrite + Send + Unpin>,
+            );
+            *stdin = Box::new(RecordingWriter {
+                inner,
+                recorder: Arc::clone(&recorder),
+            });
+        }
+        Ok(RawChildIo {
+            stdout: Box::new(RecordingReader {
+                inner: io.stdout,
+                recorder,
+            }),
+            ..io
+        })
+    }
+}
+
+#[async_trait]
+impl CodexTransport for RecordingTransport {
+    async fn spawn(&self) -> anyhow::Result<RawChildIo> {
+        let io = self.inner.spawn().await?;
+        Self::wrap_io(io, &self.path).await
+    }
+
+    fn is_idle(&self) -> bool {
+        self.inner.is_idle()
+    }
+
+    fn set_turn_session_context(&self, ctx: crate::turn_control::SessionContext) {
+        self.inner.set_turn_session_context(ctx);
+    }
+
+    fn set_approval_upstream_tx(&self, tx: tokio::sync::mpsc::Sender<Value>) {
+        self.inner.set_approval_upstream_tx(tx);
+    }
+
+    fn uses_app_server_injection(&self) -> bool {
+        self.inner.uses_app_server_injection()
+    }
+
+    fn active_turn_id_for_thread(&self, thread_id: &str) -> Option<String> {
+        self.inner.active_turn_id_for_thread(thread_id)
+    }
+}
+
+/// Transport that serves a [`RecordingTransport`] capture back as the child.
+///
+/// Recorded `from_child` chunks are written to the proxy in order. Before
+/// moving past a recorded `to_child` line, replay waits for the proxy to
+/// write a line of its own, so responses never overtake the requests that
+/// produced them. Content of the proxy's writes is not compared.
+///
+/// Each [`RecordDirection::SessionStart`] marker begins a new session, and
+/// each spawn serves the next one, so a recording that spans a child respawn
+/// replays as the same sequence of sessions. A recording without markers is a
+/// single session.
+///
+/// By default chunks are served as fast as the proxy consumes them; enable
+/// [`Self::with_delays`] to honor the recorded gaps. Stdout reaches EOF once
+/// the session is exhausted.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    sessions: Vec<Vec<RecordedChunk>>,
+    honor_delays: bool,
+    next_session: AtomicUsize,
+}
+
+impl ReplayTransport {
+    /// Build a replay from recorded chunks, split at session markers.
+    pub fn new(chunks: Vec<RecordedChunk>) -> Self {
+        let mut sessions: Vec<Vec<RecordedChunk>> = Vec::new();
+        for chunk in chunks {
+            if chunk.dir == RecordDirection::SessionStart {
+                sessions.push(Vec::new());
+            } else if let Some(session) = sessions.last_mut() {
+                session.push(chunk);
+            } else {
+                sessions.push(vec![chunk]);
+            }
+        }
+        if sessions.is_empty() {
+            sessions.push(Vec::new());
+        }
+        Self {
+            sessions,
+            honor_delays: false,
+            next_session: AtomicUsize::new(0),
+        }
+    }
+
+    /// Load a JSONL recording written by [`RecordingTransport`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or a line is not a valid
+    /// [`RecordedChunk`].
+    pub fn from_file(path: &std::path::Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            anyhow::anyhow!("failed to read transport recording {}: {e}", path.display())
+        })?;
+        let chunks = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                serde_json::from_str(line).map_err(|e| {
+                    anyhow::anyhow!(
+                        "{}:{}: invalid recording line: {e}",
+                        path.display(),
+                        idx + 1
+                    )
+                })
+            })
+            .collect::<anyhow::Result<Vec<RecordedChunk>>>()?;
+        Ok(Self::new(chunks))
+    }
+
+    /// Sleep for the recorded gap before serving each chunk.
+    pub fn with_delays(mut self, honor_delays: bool) -> Self {
+        self.honor_delays = honor_delays;
+        self
+    }
+
+    /// Spawn the replay and return the raw I/O handles.
+    ///
+    /// Public convenience for integration tests, mirroring
+    /// [`MockTransport::spawn`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error once every recorded session has been spawned.
+    pub async fn spawn(&self) -> anyhow::Result<RawChildIo> {
+        <Self as CodexTransport>::spawn(self).await
+    }
+}
+
+#[async_trait]
+impl CodexTransport for ReplayTransport {
+    async fn spawn(&self) -> anyhow::Result<RawChildIo> {
+        use tokio::io::AsyncWriteExt as _;
+
+        let session = self.next_session.fetch_add(1, Ordering::SeqCst);
+        let Some(chunks) = self.sessions.get(session).cloned() else {
+            anyhow::bail!(
+                "ReplayTransport::spawn: all {} recorded session(s) already replayed",
+                self.sessions.len()
+            );
+        };
+
+        let (mut stdout_write, stdout_read) = tokio::io::duplex(65_536);
+        let (written_tx, mut written_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+        let honor_delays = self.honor_delays;
+
+        tokio::spawn(async move {
+            let mut last_offset_ms = 0;
+            for chunk in chunks {
+                if honor_delays {
+                    let gap = chunk.offset_ms.saturating_sub(last_offset_ms);
+                    tokio::time::sleep(std::time::Duration::from_millis(gap)).await;
+                }
+                last_offset_ms = chunk.offset_ms;
+                match chunk.dir {
+                    RecordDirection::ToChild => {
+                        // SniffWriter forwards non-blank lines only.
+                        if !chunk.data.trim_ascii().is_empty() && written_rx.recv().await.is_none()
+                        {
+                            break;
+                        }
+                    }
+                    RecordDirection::FromChild => {
+                        if stdout_write.write_all(&chunk.data).await.is_err() {
+                            break;
+                        }
+                    }
+                    RecordDirection::SessionStart => {}
+                }
+            }
+            // Dropping stdout_write signals EOF: the recorded session ended.
+        });
+
+        Ok(RawChildIo {
+            stdin: Arc::new(Mutex::new(
+                Box::new(SniffWriter::new(written_tx)) as Box<dyn AsyncWrite + Send + Unpin>
+            )),
+            stdout: Box::new(stdout_read) as Box<dyn AsyncRead + Send + Unpin>,
+            exit_status: Arc::new(Mutex::new(None)),
+            process: Arc::new(Mutex::new(None)),
+            idle_flag: None,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1956,6 +2434,181 @@ mod tests {
         let _t = make_transport(&config, "test-team");
     }
 
+    async fn read_line(stdout: &mut (dyn AsyncRead + Send + Unpin)) -> String {
+        use tokio::io::AsyncBufReadExt as _;
+
+        let mut reader = tokio::io::BufReader::new(stdout);
+        let mut line = String::new();
+        tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            reader.read_line(&mut line),
+        )
+        .await
+        .expect("line within timeout")
+        .unwrap();
+        line
+    }
+
+    async fn write_line(io: &RawChildIo, line: &str) {
+        use tokio::io::AsyncWriteExt as _;
+
+        let mut stdin = io.stdin.lock().await;
+        stdin
+            .write_all(format!("{line}\n").as_bytes())
+            .await
+            .unwrap();
+        stdin.flush().await.unwrap();
+    }
+
+    /// Wait for the recorder's writer thread to append `count` records.
+    async fn wait_for_records(path: &std::path::Path, count: usize) {
+        for _ in 0..100 {
+            let written = std::fs::read_to_string(path)
+                .map(|content| content.lines().count())
+                .unwrap_or(0);
+            if written >= count {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        panic!(
+            "recording at {} never reached {count} records",
+            path.display()
+        );
+    }
+
+    #[tokio::test]
+    async fn recording_keeps_raw_bytes_and_appends_across_spawns() {
+        use tokio::io::AsyncWriteExt as _;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        let raw = b"\xff\xfe not utf-8\n";
+
+        for _ in 0..2 {
+            let (mock, _handle) = MockTransport::new_with_handle();
+            let io = RecordingTransport::wrap_io(mock.spawn().await.unwrap(), &path)
+                .await
+                .unwrap();
+            let mut stdin = io.stdin.lock().await;
+            stdin.write_all(raw).await.unwrap();
+            stdin.flush().await.unwrap();
+        }
+        wait_for_records(&path, 4).await;
+
+        let replay = ReplayTransport::from_file(&path).unwrap();
+        assert_eq!(replay.sessions.len(), 2, "respawn truncated the recording");
+        for session in &replay.sessions {
+            assert_eq!(session.len(), 1);
+            assert_eq!(session[0].dir, RecordDirection::ToChild);
+            assert_eq!(session[0].data, raw);
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt as _;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[tokio::test]
+    async fn replay_serves_one_recorded_session_per_spawn() {
+        let chunk = |dir, data: &str| RecordedChunk {
+            dir,
+            offset_ms: 0,
+            data: data.as_bytes().to_vec(),
+        };
+        let replay = ReplayTransport::new(vec![
+            chunk(RecordDirection::SessionStart, ""),
+            chunk(RecordDirection::FromChild, "first\n"),
+            chunk(RecordDirection::SessionStart, ""),
+            chunk(RecordDirection::FromChild, "second\n"),
+        ]);
+
+        let mut io = replay.spawn().await.unwrap();
+        assert_eq!(read_line(&mut *io.stdout).await, "first\n");
+        assert_eq!(read_line(&mut *io.stdout).await, "");
+
+        let mut io = replay.spawn().await.unwrap();
+        assert_eq!(read_line(&mut *io.stdout).await, "second\n");
+        assert_eq!(read_line(&mut *io.stdout).await, "");
+
+        assert!(replay.spawn().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn recording_then_replay_reproduces_child_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        let request = r#"{"jsonrpc":"2.0","id":1,"method":"initialize"}"#;
+        let response = r#"{"jsonrpc":"2.0","id":1,"result":{"serverInfo":"é"}}"#;
+
+        let (mock, mut handle) = MockTransport::new_with_handle();
+        let mut io = RecordingTransport::wrap_io(mock.spawn().await.unwrap(), &path)
+            .await
+            .unwrap();
+        write_line(&io, request).await;
+        assert_eq!(handle.request_rx.recv().await.unwrap(), request);
+        handle.response_tx.send(response.to_string()).unwrap();
+        assert_eq!(read_line(&mut *io.stdout).await, format!("{response}\n"));
+        wait_for_records(&path, 3).await;
+
+        let replay = ReplayTransport::from_file(&path).unwrap();
+        assert_eq!(replay.sessions.len(), 1);
+        assert_eq!(
+            replay.sessions[0]
+                .iter()
+                .map(|c| (c.dir, c.data.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    RecordDirection::ToChild,
+                    format!("{request}\n").into_bytes()
+                ),
+                (
+                    RecordDirection::FromChild,
+                    format!("{response}\n").into_bytes()
+                ),
+            ]
+        );
+
+        let mut io = replay.spawn().await.unwrap();
+        write_line(&io, request).await;
+        assert_eq!(read_line(&mut *io.stdout).await, format!("{response}\n"));
+        // Recording exhausted: stdout reaches EOF.
+        assert_eq!(read_line(&mut *io.stdout).await, "");
+        assert!(replay.spawn().await.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn replay_waits_for_request_and_honors_delays() {
+        let chunk = |dir, offset_ms, data: &str| RecordedChunk {
+            dir,
+            offset_ms,
+            data: format!("{data}\n").into_bytes(),
+        };
+        let replay = ReplayTransport::new(vec![
+            chunk(RecordDirection::ToChild, 0, "req"),
+            chunk(RecordDirection::FromChild, 250, "resp"),
+        ])
+        .with_delays(true);
+        let mut io = replay.spawn().await.unwrap();
+
+        // The response is held back until the proxy writes its request.
+        let early = tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            read_line(&mut *io.stdout),
+        )
+        .await;
+        assert!(early.is_err());
+
+        write_line(&io, "req").await;
+        let started = tokio::time::Instant::now();
+        assert_eq!(read_line(&mut *io.stdout).await, "resp\n");
+        assert!(started.elapsed() >= std::time::Duration::from_millis(250));
+    }
+
     #[test]
     fn mock_transport_new_with_handle_does_not_panic() {
         let (_transport, _handle) = MockTransport::new_with_handle();
@@ -9,7 +9,7 @@
 //! are serialized with `#[serial]` to prevent env-var races.
 
 use atm_agent_mcp::config::AgentMcpConfig;
-use atm_agent_mcp::proxy::{ERR_IDENTITY_REQUIRED, ProxyServer};
+use atm_agent_mcp::proxy::{ERR_CROSS_TEAM_DENIED, ERR_IDENTITY_REQUIRED, ProxyServer};
 use serde_json::{Value, json};
 use serial_test::serial;
 use tempfile::TempDir;
@@ -603,3 +603,51 @@ async fn integration_atm_broadcast_delivers_to_members() {
         "sender should not receive their own broadcast"
     );
 }
+
+/// Cross-team `atm_send` is rejected unless `allow_cross_team` is set.
+#[tokio::test]
+#[serial]
+async fn integration_atm_send_cross_team_requires_opt_in() {
+    let dir = TempDir::new().unwrap();
+    let _atm_home = EnvGuard::set("ATM_HOME", dir.path());
+
+    let msg = json!({
+        "jsonrpc": "2.0",
+        "id": 10,
+        "method": "tools/call",
+        "params": {
+            "name": "atm_send",
+            "arguments": {"to": "arch-ctm@other-team", "message": "hello"}
+        }
+    });
+    let other_inbox = dir
+        .path()
+        .join(".claude")
+        .join("teams")
+        .join("other-team")
+        .join("inboxes")
+        .join("arch-ctm.json");
+
+    let mut proxy = make_proxy(Some("team-lead"), "atm-dev");
+    let resp = roundtrip_tools_call(&mut proxy, msg.clone()).await;
+    assert_eq!(
+        resp["error"]["code"].as_i64(),
+        Some(ERR_CROSS_TEAM_DENIED),
+        "cross-team send should be denied by default; got: {resp}"
+    );
+    assert_eq!(resp["error"]["data"]["target_team"], "other-team");
+    assert!(!other_inbox.exists(), "denied send must not write an inbox");
+
+    let config = AgentMcpConfig {
+        identity: Some("team-lead".to_string()),
+        allow_cross_team: true,
+        ..Default::default()
+    };
+    let mut proxy = ProxyServer::new_with_team(config, "atm-dev");
+    let resp = roundtrip_tools_call(&mut proxy, msg).await;
+    assert!(
+        resp.get("error").is_none(),
+        "allow_cross_team should permit the send; got: {resp}"
+    );
+    assert!(other_inbox.exists(), "cross-team inbox should be written");
+}
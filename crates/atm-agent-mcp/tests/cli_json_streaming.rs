@@ -28,6 +28,7 @@ use tempfile::tempdir;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 
 use atm_agent_mcp::cli_json_test::{CliJsonEventKind, classify_event};
+use atm_agent_mcp::config::DownstreamFraming;
 use atm_agent_mcp::stdin_queue;
 use atm_agent_mcp::stream_norm::{TurnState, TurnStatus};
 
@@ -283,9 +284,15 @@ async fn mail_enqueued_before_idle_is_drained_on_idle() {
         Arc::new(tokio::sync::Mutex::new(Box::new(writer)));
 
     // Drain (the idle event handler calls this).
-    let drained = stdin_queue::drain(team, agent, &stdin, Duration::from_secs(600))
-        .await
-        .unwrap();
+    let drained = stdin_queue::drain(
+        team,
+        agent,
+        &stdin,
+        Duration::from_secs(600),
+        DownstreamFraming::Newline,
+    )
+    .await
+    .unwrap();
 
     assert_eq!(
         drained, 3,
@@ -327,9 +334,15 @@ async fn mail_enqueued_after_drain_waits_for_next_idle() {
     let (w1, _) = CapWriter::new();
     let stdin1: Arc<tokio::sync::Mutex<Box<dyn tokio::io::AsyncWrite + Send + Unpin>>> =
         Arc::new(tokio::sync::Mutex::new(Box::new(w1)));
-    let count1 = stdin_queue::drain(team, agent, &stdin1, Duration::from_secs(600))
-        .await
-        .unwrap();
+    let count1 = stdin_queue::drain(
+        team,
+        agent,
+        &stdin1,
+        Duration::from_secs(600),
+        DownstreamFraming::Newline,
+    )
+    .await
+    .unwrap();
     assert_eq!(
         count1, 1,
         "first drain must deliver the 1 pre-queued message"
@@ -344,9 +357,15 @@ async fn mail_enqueued_after_drain_waits_for_next_idle() {
     let (w2, cap2) = CapWriter::new();
     let stdin2: Arc<tokio::sync::Mutex<Box<dyn tokio::io::AsyncWrite + Send + Unpin>>> =
         Arc::new(tokio::sync::Mutex::new(Box::new(w2)));
-    let count2 = stdin_queue::drain(team, agent, &stdin2, Duration::from_secs(600))
-        .await
-        .unwrap();
+    let count2 = stdin_queue::drain(
+        team,
+        agent,
+        &stdin2,
+        Duration::from_secs(600),
+        DownstreamFraming::Newline,
+    )
+    .await
+    .unwrap();
 
     assert_eq!(
         count2, 1,
@@ -464,9 +483,15 @@ async fn mid_turn_steering_injection() {
     let stdin: Arc<tokio::sync::Mutex<Box<dyn tokio::io::AsyncWrite + Send + Unpin>>> =
         Arc::new(tokio::sync::Mutex::new(Box::new(writer)));
 
-    let drained = stdin_queue::drain(team, agent, &stdin, Duration::from_secs(600))
-        .await
-        .unwrap();
+    let drained = stdin_queue::drain(
+        team,
+        agent,
+        &stdin,
+        Duration::from_secs(600),
+        DownstreamFraming::Newline,
+    )
+    .await
+    .unwrap();
 
     assert_eq!(drained, 1, "steering message must be drained on idle event");
     let output = captured.lock().unwrap().clone();
@@ -599,9 +624,15 @@ async fn no_idle_event_queue_can_be_drained_after_timeout() {
     let stdin: Arc<tokio::sync::Mutex<Box<dyn tokio::io::AsyncWrite + Send + Unpin>>> =
         Arc::new(tokio::sync::Mutex::new(Box::new(writer)));
 
-    let drained = stdin_queue::drain(team, agent, &stdin, Duration::from_secs(600))
-        .await
-        .unwrap();
+    let drained = stdin_queue::drain(
+        team,
+        agent,
+        &stdin,
+        Duration::from_secs(600),
+        DownstreamFraming::Newline,
+    )
+    .await
+    .unwrap();
 
     assert_eq!(
         drained, 3,
@@ -689,9 +720,15 @@ async fn multi_cycle_idle_windows_no_cross_contamination() {
     let stdin1: Arc<tokio::sync::Mutex<Box<dyn tokio::io::AsyncWrite + Send + Unpin>>> =
         Arc::new(tokio::sync::Mutex::new(Box::new(w1)));
 
-    let count1 = stdin_queue::drain(team, agent, &stdin1, Duration::from_secs(600))
-        .await
-        .unwrap();
+    let count1 = stdin_queue::drain(
+        team,
+        agent,
+        &stdin1,
+        Duration::from_secs(600),
+        DownstreamFraming::Newline,
+    )
+    .await
+    .unwrap();
     assert_eq!(
         count1, 2,
         "idle cycle 1 must drain exactly 2 messages (A and B)"
@@ -717,9 +754,15 @@ async fn multi_cycle_idle_windows_no_cross_contamination() {
     let stdin2: Arc<tokio::sync::Mutex<Box<dyn tokio::io::AsyncWrite + Send + Unpin>>> =
         Arc::new(tokio::sync::Mutex::new(Box::new(w2)));
 
-    let count2 = stdin_queue::drain(team, agent, &stdin2, Duration::from_secs(600))
-        .await
-        .unwrap();
+    let count2 = stdin_queue::drain(
+        team,
+        agent,
+        &stdin2,
+        Duration::from_secs(600),
+        DownstreamFraming::Newline,
+    )
+    .await
+    .unwrap();
 
     assert_eq!(
         count2, 1,
@@ -19,9 +19,18 @@
 //! - When `tools/call` arguments contain `"slow": true`, the server sleeps for
 //!   5 seconds before responding (for timeout testing).
 //! - When `tools/call` targets `crash`, the server exits with code 42.
+//! - When `tools/call` arguments contain `"crash_once": "<path>"` and that
+//!   path does not exist yet, the server creates it and exits with code 42
+//!   (so a replay against a respawned server succeeds).
+//! - When `tools/call` arguments contain `"report_initialized": true`, the
+//!   response's `structuredContent` carries `initializedCount`, the number of
+//!   `notifications/initialized` received so far.
 
 use serde_json::{Value, json};
 use std::io::{BufRead, BufReader, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static INITIALIZED_COUNT: AtomicU64 = AtomicU64::new(0);
 
 fn main() {
     let stdin = std::io::stdin();
@@ -122,6 +131,14 @@ fn handle_message(msg: &Value, writer: &mut impl Write) {
                 std::process::exit(42);
             }
 
+            // Special: crash the first time a marker path is seen
+            if let Some(marker) = arguments.get("crash_once").and_then(|v| v.as_str()) {
+                if !std::path::Path::new(marker).exists() {
+                    let _ = std::fs::write(marker, b"crashed");
+                    std::process::exit(42);
+                }
+            }
+
             // Special: slow mode for timeout testing
             if arguments.get("slow").and_then(|v| v.as_bool()) == Some(true) {
                 std::thread::sleep(std::time::Duration::from_secs(5));
@@ -160,7 +177,7 @@ fn handle_message(msg: &Value, writer: &mut impl Write) {
                 .and_then(|v| v.as_str())
                 .unwrap_or("(no prompt)");
 
-            let resp = json!({
+            let mut resp = json!({
                 "jsonrpc": "2.0",
                 "id": id,
                 "result": {
@@ -174,10 +191,22 @@ fn handle_message(msg: &Value, writer: &mut impl Write) {
                     }
                 }
             });
+            if arguments
+                .get("report_initialized")
+                .and_then(|v| v.as_bool())
+                == Some(true)
+            {
+                resp["result"]["structuredContent"]["initializedCount"] =
+                    json!(INITIALIZED_COUNT.load(Ordering::Relaxed));
+            }
             write_msg(writer, &resp);
         }
 
-        Some("notifications/initialized") | Some("notifications/cancelled") => {
+        Some("notifications/initialized") => {
+            INITIALIZED_COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Some("notifications/cancelled") => {
             // Notifications have no response
         }
 
@@ -632,6 +632,202 @@ async fn test_child_crash_includes_exit_code() {
     let _ = handle.await;
 }
 
+#[tokio::test]
+#[serial]
+async fn test_child_crash_respawns_and_retries_codex_reply() {
+    use atm_agent_mcp::config::AgentMcpConfig;
+
+    let config = AgentMcpConfig {
+        codex_bin: echo_mcp_server_path().to_string_lossy().to_string(),
+        request_timeout_secs: 30,
+        auto_mail: false,
+        max_child_restarts: 1,
+        ..Default::default()
+    };
+    let team = format!("test-{}", uuid::Uuid::new_v4());
+    let (mut writer, proxy_read) = tokio::io::duplex(16384);
+    let (proxy_write, client_read) = tokio::io::duplex(16384);
+    let mut reader = BufReader::new(client_read);
+    let handle = tokio::spawn(async move {
+        let mut proxy = atm_agent_mcp::proxy::ProxyServer::new_with_team(config, team);
+        proxy.run(proxy_read, proxy_write).await
+    });
+
+    let codex_req = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": "codex", "arguments": {"prompt": "start"}}
+    });
+    send_newline(&mut writer, &codex_req).await;
+    let _ = collect_until_id(&mut reader, json!(1), Duration::from_secs(5)).await;
+
+    // The first child crashes on this reply; the proxy respawns and replays it.
+    let marker = tempfile::tempdir().unwrap();
+    let marker_path = marker.path().join("crashed");
+    let reply_req = json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": {
+            "name": "codex-reply",
+            "arguments": {
+                "prompt": "survive",
+                "threadId": "test-thread-001",
+                "crash_once": marker_path.to_string_lossy(),
+            }
+        }
+    });
+    send_newline(&mut writer, &reply_req).await;
+
+    let responses = collect_until_id(&mut reader, json!(2), Duration::from_secs(10)).await;
+    let reply = responses
+        .iter()
+        .find(|r| r.get("id") == Some(&json!(2)))
+        .expect("codex-reply should be answered by the respawned child");
+    assert!(marker_path.exists(), "first child should have crashed");
+    assert_eq!(
+        reply
+            .pointer("/result/structuredContent/threadId")
+            .and_then(|v| v.as_str()),
+        Some("test-thread-001")
+    );
+
+    let status_req = json!({
+        "jsonrpc": "2.0",
+        "id": 3,
+        "method": "tools/call",
+        "params": {"name": "agent_status", "arguments": {}}
+    });
+    send_newline(&mut writer, &status_req).await;
+    let responses = collect_until_id(&mut reader, json!(3), Duration::from_secs(5)).await;
+    let status_resp = responses
+        .iter()
+        .find(|r| r.get("id") == Some(&json!(3)))
+        .expect("agent_status response");
+    let text = status_resp
+        .pointer("/result/content/0/text")
+        .and_then(|v| v.as_str())
+        .unwrap();
+    let status: Value = serde_json::from_str(text).unwrap();
+    assert_eq!(status["child_alive"], json!(true));
+    assert_eq!(status["child_restarts_succeeded"], json!(1));
+    assert_eq!(status["child_restarts_failed"], json!(0));
+
+    drop(writer);
+    let _ = handle.await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_shutdown_summary_uses_configured_prompt() {
+    use atm_agent_mcp::config::AgentMcpConfig;
+
+    let home = tempfile::tempdir().unwrap();
+    // SAFETY: serialized test; restored before returning.
+    unsafe { std::env::set_var("ATM_HOME", home.path()) };
+
+    let config = AgentMcpConfig {
+        codex_bin: echo_mcp_server_path().to_string_lossy().to_string(),
+        request_timeout_secs: 30,
+        auto_mail: false,
+        shutdown_summary_prompt: Some("Write the handoff in team format".to_string()),
+        ..Default::default()
+    };
+    let team = format!("test-{}", uuid::Uuid::new_v4());
+    let (mut writer, proxy_read) = tokio::io::duplex(16384);
+    let (proxy_write, client_read) = tokio::io::duplex(16384);
+    let mut reader = BufReader::new(client_read);
+    let proxy_team = team.clone();
+    let handle = tokio::spawn(async move {
+        let mut proxy = atm_agent_mcp::proxy::ProxyServer::new_with_team(config, proxy_team);
+        proxy.run(proxy_read, proxy_write).await
+    });
+
+    let codex_req = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": "codex", "arguments": {"prompt": "start", "identity": "summarizer"}}
+    });
+    send_newline(&mut writer, &codex_req).await;
+    let _ = collect_until_id(&mut reader, json!(1), Duration::from_secs(5)).await;
+
+    // EOF triggers graceful shutdown, which requests a summary per thread.
+    drop(writer);
+    let _ = tokio::time::timeout(Duration::from_secs(20), handle).await;
+
+    let summary_path = home
+        .path()
+        .join(".config/atm/agent-sessions")
+        .join(&team)
+        .join("summarizer")
+        .join("test-thread-001")
+        .join("summary.md");
+    let summary = std::fs::read_to_string(&summary_path);
+    unsafe { std::env::remove_var("ATM_HOME") };
+
+    // The echo server replies with the prompt it received.
+    assert_eq!(
+        summary.expect("shutdown summary should be written"),
+        "Echo from codex-reply: Write the handoff in team format"
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn test_shutdown_summary_skipped_for_short_session() {
+    use atm_agent_mcp::config::AgentMcpConfig;
+
+    let home = tempfile::tempdir().unwrap();
+    // SAFETY: serialized test; restored before returning.
+    unsafe { std::env::set_var("ATM_HOME", home.path()) };
+
+    let config = AgentMcpConfig {
+        codex_bin: echo_mcp_server_path().to_string_lossy().to_string(),
+        request_timeout_secs: 30,
+        auto_mail: false,
+        min_session_duration_for_summary_secs: 3600,
+        ..Default::default()
+    };
+    let team = format!("test-{}", uuid::Uuid::new_v4());
+    let (mut writer, proxy_read) = tokio::io::duplex(16384);
+    let (proxy_write, client_read) = tokio::io::duplex(16384);
+    let mut reader = BufReader::new(client_read);
+    let proxy_team = team.clone();
+    let handle = tokio::spawn(async move {
+        let mut proxy = atm_agent_mcp::proxy::ProxyServer::new_with_team(config, proxy_team);
+        proxy.run(proxy_read, proxy_write).await
+    });
+
+    let codex_req = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": "codex", "arguments": {"prompt": "start", "identity": "brief"}}
+    });
+    send_newline(&mut writer, &codex_req).await;
+    let _ = collect_until_id(&mut reader, json!(1), Duration::from_secs(5)).await;
+
+    drop(writer);
+    let _ = tokio::time::timeout(Duration::from_secs(20), handle).await;
+
+    let summary_path = home
+        .path()
+        .join(".config/atm/agent-sessions")
+        .join(&team)
+        .join("brief")
+        .join("test-thread-001")
+        .join("summary.md");
+    let exists = summary_path.exists();
+    unsafe { std::env::remove_var("ATM_HOME") };
+
+    assert!(
+        !exists,
+        "short session should not be summarized at shutdown"
+    );
+}
+
 // ─── Timeout tests ──────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -804,6 +1000,60 @@ async fn test_proxy_shuts_down_on_stdin_eof() {
     assert!(result.unwrap().is_ok(), "proxy should exit without panic");
 }
 
+#[tokio::test]
+#[serial]
+async fn test_proxy_shuts_down_after_consecutive_parse_errors() {
+    use atm_agent_mcp::config::AgentMcpConfig;
+
+    let config = AgentMcpConfig {
+        codex_bin: echo_mcp_server_path().to_string_lossy().to_string(),
+        auto_mail: false,
+        max_consecutive_parse_errors: 3,
+        ..Default::default()
+    };
+    let (mut writer, proxy_read) = tokio::io::duplex(16384);
+    let (proxy_write, client_read) = tokio::io::duplex(16384);
+    let mut reader = BufReader::new(client_read);
+    let handle = tokio::spawn(async move {
+        let mut proxy = atm_agent_mcp::proxy::ProxyServer::new_with_team(
+            config,
+            format!("test-{}", uuid::Uuid::new_v4()),
+        );
+        proxy.run(proxy_read, proxy_write).await
+    });
+
+    // Two bad messages, then a good one resets the counter.
+    for _ in 0..2 {
+        writer.write_all(b"not json\n").await.unwrap();
+    }
+    send_newline(
+        &mut writer,
+        &json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}),
+    )
+    .await;
+    let responses = collect_until_id(&mut reader, json!(1), Duration::from_secs(10)).await;
+    assert_eq!(responses.len(), 3);
+    assert!(responses[..2].iter().all(|r| r["error"]["code"] == -32700));
+    assert!(!handle.is_finished(), "good message must reset the counter");
+
+    // Three more in a row hit the limit and stop the proxy.
+    for _ in 0..3 {
+        writer.write_all(b"{garbage\n").await.unwrap();
+    }
+    writer.flush().await.unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(10), handle)
+        .await
+        .expect("proxy should stop after repeated parse errors")
+        .expect("proxy task should not panic");
+    let err = result.expect_err("parse-error shutdown is reported as an error");
+    assert!(err.to_string().contains("3 consecutive"), "{err}");
+
+    let parse_errors = read_all_responses(&mut reader, Duration::from_secs(2)).await;
+    assert_eq!(parse_errors.len(), 3);
+    drop(writer);
+}
+
 #[tokio::test]
 #[serial]
 async fn test_tools_list_schema_valid() {
@@ -913,6 +1163,40 @@ async fn test_synthetic_tool_returns_not_implemented() {
     let _ = handle.await;
 }
 
+#[tokio::test]
+#[serial]
+async fn test_agent_metrics_needs_no_identity() {
+    let (mut writer, mut reader, handle) = spawn_proxy(300);
+
+    let req = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": "agent_metrics", "arguments": {}}
+    });
+    send_newline(&mut writer, &req).await;
+
+    let resp = read_response(&mut reader)
+        .await
+        .expect("agent_metrics response");
+    assert_eq!(resp["id"], 1);
+    assert!(resp.get("error").is_none(), "got: {resp}");
+    let text = resp
+        .pointer("/result/content/0/text")
+        .and_then(|v| v.as_str())
+        .unwrap();
+    let metrics: Value = serde_json::from_str(text).unwrap();
+    assert_eq!(metrics["dropped_events"], json!(0));
+    assert_eq!(metrics["pending_requests"], json!(0));
+    assert_eq!(metrics["active_sessions"], json!(0));
+    assert_eq!(metrics["auto_mail_turns"], json!(0));
+    assert_eq!(metrics["elicitation_timeouts"], json!(0));
+    assert_eq!(metrics["upstream_channel"]["capacity"], json!(256));
+
+    drop(writer);
+    let _ = handle.await;
+}
+
 // ─── Content-Length upstream framing ─────────────────────────────────────
 
 #[tokio::test]
@@ -951,6 +1235,50 @@ async fn test_content_length_upstream_framing() {
     let _ = handle.await;
 }
 
+#[tokio::test]
+#[serial]
+async fn test_oversized_content_length_frame_rejected_and_skipped() {
+    use atm_agent_mcp::config::AgentMcpConfig;
+
+    let config = AgentMcpConfig {
+        codex_bin: echo_mcp_server_path().to_string_lossy().to_string(),
+        auto_mail: false,
+        max_upstream_frame_bytes: 256,
+        ..Default::default()
+    };
+    let (mut writer, proxy_read) = tokio::io::duplex(16384);
+    let (proxy_write, client_read) = tokio::io::duplex(16384);
+    let mut reader = BufReader::new(client_read);
+    let handle = tokio::spawn(async move {
+        let mut proxy = atm_agent_mcp::proxy::ProxyServer::new_with_team(
+            config,
+            format!("test-{}", uuid::Uuid::new_v4()),
+        );
+        proxy.run(proxy_read, proxy_write).await
+    });
+
+    let big =
+        json!({"jsonrpc": "2.0", "id": 1, "method": "ping", "params": {"pad": "x".repeat(1024)}});
+    send_content_length(&mut writer, &big).await;
+    send_content_length(
+        &mut writer,
+        &json!({"jsonrpc": "2.0", "id": 2, "method": "initialize", "params": {}}),
+    )
+    .await;
+
+    let responses = collect_until_id(&mut reader, json!(2), Duration::from_secs(10)).await;
+    assert_eq!(responses.len(), 2, "{responses:?}");
+    assert_eq!(responses[0]["error"]["code"], -32600);
+    assert_eq!(responses[0]["error"]["data"]["max_frame_bytes"], 256);
+    assert!(
+        responses[1].get("result").is_some(),
+        "next frame still parsed"
+    );
+
+    drop(writer);
+    let _ = handle.await;
+}
+
 // ─── Dropped events counter ─────────────────────────────────────────────
 
 #[tokio::test]
@@ -1034,12 +1362,15 @@ async fn test_tools_list_before_child_spawn_returns_synthetic_tools() {
     let names: Vec<&str> = tools.iter().filter_map(|t| t["name"].as_str()).collect();
     for expected in &[
         "atm_send",
+        "atm_reply",
         "atm_read",
         "atm_broadcast",
         "atm_pending_count",
         "agent_sessions",
         "agent_status",
+        "agent_metrics",
         "agent_close",
+        "agent_interrupt",
         "agent_watch_attach",
         "agent_watch_poll",
         "agent_watch_detach",
@@ -1074,3 +1405,51 @@ async fn test_notifications_initialized_does_not_produce_response() {
     drop(writer);
     let _ = handle.await;
 }
+
+#[tokio::test]
+#[serial]
+async fn test_duplicate_notifications_initialized_reaches_child_once() {
+    let (mut writer, mut reader, handle) = spawn_proxy(5);
+
+    let codex_req = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": "codex", "arguments": {"prompt": "start"}}
+    });
+    send_newline(&mut writer, &codex_req).await;
+    let _ = collect_until_id(&mut reader, json!(1), Duration::from_secs(5)).await;
+
+    let initialized = json!({"jsonrpc": "2.0", "method": "notifications/initialized"});
+    send_newline(&mut writer, &initialized).await;
+    send_newline(&mut writer, &initialized).await;
+
+    let reply_req = json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": {
+            "name": "codex-reply",
+            "arguments": {
+                "prompt": "count",
+                "threadId": "test-thread-001",
+                "report_initialized": true,
+            }
+        }
+    });
+    send_newline(&mut writer, &reply_req).await;
+
+    let responses = collect_until_id(&mut reader, json!(2), Duration::from_secs(5)).await;
+    let reply = responses
+        .iter()
+        .find(|r| r.get("id") == Some(&json!(2)))
+        .expect("codex-reply response");
+    assert_eq!(
+        reply.pointer("/result/structuredContent/initializedCount"),
+        Some(&json!(1)),
+        "child must see notifications/initialized exactly once"
+    );
+
+    drop(writer);
+    let _ = handle.await;
+}
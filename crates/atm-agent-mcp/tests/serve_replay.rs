@@ -0,0 +1,98 @@
+//! Verify `atm-agent-mcp serve --replay` feeds recorded upstream frames to the
+//! proxy, writes responses to `--replay-out`, and exits without a client.
+
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+fn atm_agent_mcp_bin_path() -> PathBuf {
+    if let Ok(path) = std::env::var("CARGO_BIN_EXE_atm-agent-mcp") {
+        return PathBuf::from(path);
+    }
+
+    let mut path = std::env::current_exe().expect("resolve current_exe");
+    path.pop(); // test binary
+    if path.ends_with("deps") {
+        path.pop();
+    }
+    path.push("atm-agent-mcp");
+    if cfg!(windows) {
+        path.set_extension("exe");
+    }
+    path
+}
+
+fn replay_fixture() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("replay")
+        .join("initialize_pending_count.jsonl")
+}
+
+fn write_atm_config(path: &Path) {
+    let config = r#"[core]
+default_team = "atm-dev"
+identity = "arch-ctm"
+
+[plugins.atm-agent-mcp]
+identity = "arch-ctm"
+auto_mail = false
+"#;
+    std::fs::write(path, config).expect("write atm config");
+}
+
+#[test]
+fn serve_replay_writes_responses_to_file_and_exits() {
+    let home = TempDir::new().expect("temp ATM_HOME");
+    std::fs::create_dir_all(home.path().join(".claude/teams/atm-dev")).expect("team dir");
+    let config_path = home.path().join("test.atm.toml");
+    write_atm_config(&config_path);
+    let out_path = home.path().join("responses.jsonl");
+
+    let output = Command::new(atm_agent_mcp_bin_path())
+        .arg("--config")
+        .arg(&config_path)
+        .arg("serve")
+        .arg("--replay")
+        .arg(replay_fixture())
+        .arg("--replay-out")
+        .arg(&out_path)
+        .arg("--replay-wait")
+        .arg("0")
+        .env("ATM_HOME", home.path())
+        .output()
+        .expect("run atm-agent-mcp serve --replay");
+    assert!(
+        output.status.success(),
+        "replay exited with {}: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        output.stdout.is_empty(),
+        "responses must go to --replay-out, not stdout"
+    );
+
+    let responses: Vec<Value> = std::fs::read_to_string(&out_path)
+        .expect("read replay output")
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).expect("valid jsonl response"))
+        .collect();
+    let ids: Vec<i64> = responses
+        .iter()
+        .filter_map(|r| r.get("id").and_then(Value::as_i64))
+        .collect();
+    assert_eq!(ids, vec![1, 2, 3], "one response per replayed request");
+
+    let tools = responses[1]["result"]["tools"]
+        .as_array()
+        .expect("tools/list result");
+    assert!(
+        tools.iter().any(|t| t["name"] == "atm_pending_count"),
+        "tools/list should include ATM tools"
+    );
+    assert!(responses[2].get("result").is_some() || responses[2].get("error").is_some());
+}
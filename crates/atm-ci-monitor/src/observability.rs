@@ -916,27 +916,18 @@ fn emit_budget_warning_message(
         .filter(|value| !value.trim().is_empty())
         .unwrap_or_else(|| "team-lead".to_string());
     let inbox_path = team_dir.join("inboxes").join(format!("{lead_agent}.json"));
-    let message = InboxMessage {
-        from: "gh_monitor".to_string(),
-        source_team: None,
-        text: format!(
+    let message = InboxMessage::new("gh_monitor", format!(
             "GitHub monitor budget warning for {} on {}: {}/{} calls used in current window while running `{}`.",
             ctx.team,
             ctx.repo,
             record.budget_used_in_window,
             record.budget_limit_per_hour,
             format_gh_subcommand(metadata)
-        ),
-        timestamp: Utc::now().to_rfc3339(),
-        read: false,
-        summary: Some(format!("gh_monitor budget warning: {}", ctx.repo)),
-        message_id: Some(format!(
+        )).summary(format!("gh_monitor budget warning: {}", ctx.repo)).message_id(format!(
             "gh-budget-warning-{}-{}",
             ctx.team,
             ctx.repo.replace('/', "-")
-        )),
-        unknown_fields: Default::default(),
-    };
+        ));
     let _ = inbox_append(&inbox_path, &message, &ctx.team, &lead_agent);
 }
 
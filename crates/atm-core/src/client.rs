@@ -0,0 +1,507 @@
+//! Library facade for embedding ATM messaging in other tools
+//!
+//! [`AtmClient`] bundles home resolution, team/inbox path construction, and
+//! send/read/broadcast over the conflict-safe [`io`](crate::io) layer, so a
+//! third-party tool can exchange messages with agents without wiring paths by
+//! hand. Messages are written straight to inbox files; a running daemon picks
+//! them up through its inbox watcher like any CLI-sent message.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use agent_team_mail_core::client::{AtmClient, ReadOptions};
+//!
+//! // Identity, team and message-id scheme from ATM config (.atm.toml, env).
+//! let client = AtmClient::from_env().unwrap();
+//!
+//! // "agent" uses the client's team; "agent@team" addresses another team.
+//! let receipt = client.send("arch-ctm", "CI failure detected").unwrap();
+//! println!("sent {} to {}@{}", receipt.message_id, receipt.agent, receipt.team);
+//!
+//! // Unread messages in the client's own inbox, marked read on return.
+//! for message in client.read(&ReadOptions::default()).unwrap() {
+//!     println!("{}: {}", message.from, message.text);
+//! }
+//! ```
+
+use crate::config::{
+    ConfigError, ConfigOverrides, MessageIdScheme, resolve_config, resolve_identity,
+};
+use crate::home::{get_home_dir, inbox_path_for, team_config_path_for};
+use crate::io::{
+    DEFAULT_MAX_CONFLICT_RETRIES, InboxError, WriteOutcome, inbox_append_bounded,
+    inbox_read_file_tolerant, inbox_update, new_message_id,
+};
+use crate::schema::{InboxMessage, TeamConfig};
+use crate::text::{DEFAULT_MAX_MESSAGE_BYTES, truncate_chars_slice, validate_message_text};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Characters of message text used for an auto-generated summary.
+const SUMMARY_MAX_CHARS: usize = 100;
+
+/// Errors returned by [`AtmClient`].
+#[derive(Debug, Error)]
+pub enum ClientError {
+    /// ATM home directory could not be determined
+    #[error("cannot resolve ATM home: {0}")]
+    Home(String),
+
+    /// Configuration could not be loaded
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+
+    /// Inbox I/O failed
+    #[error(transparent)]
+    Inbox(#[from] InboxError),
+
+    /// Address is not `agent` or `agent@team`, or a name is not a single
+    /// path component
+    #[error("invalid address '{0}': expected 'agent' or 'agent@team'")]
+    InvalidAddress(String),
+
+    /// Recipient team does not exist
+    #[error("team '{0}' not found")]
+    UnknownTeam(String),
+
+    /// Recipient is not a member of its team
+    #[error("agent '{agent}' not found in team '{team}'")]
+    UnknownAgent {
+        /// Recipient agent name
+        agent: String,
+        /// Recipient team
+        team: String,
+    },
+
+    /// Message text failed validation (null bytes, size limit)
+    #[error("invalid message: {0}")]
+    InvalidMessage(String),
+
+    /// Team config could not be read or parsed
+    #[error("cannot load team config at {path:?}: {message}")]
+    TeamConfig {
+        /// Path to the team's `config.json`
+        path: PathBuf,
+        /// Underlying error
+        message: String,
+    },
+}
+
+/// Delivery result for a single recipient.
+#[derive(Debug, Clone)]
+pub struct SendReceipt {
+    /// Recipient agent name
+    pub agent: String,
+    /// Recipient team
+    pub team: String,
+    /// Id of the delivered message
+    pub message_id: String,
+    /// How the inbox write completed
+    pub outcome: WriteOutcome,
+}
+
+/// Filters for [`AtmClient::read`].
+#[derive(Debug, Clone)]
+pub struct ReadOptions {
+    /// Include messages that are already read (default: `false`)
+    pub include_read: bool,
+    /// Mark returned messages as read (default: `true`)
+    pub mark_read: bool,
+    /// Only return messages from this sender
+    pub from: Option<String>,
+    /// Maximum number of messages returned (oldest first)
+    pub limit: Option<usize>,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            include_read: false,
+            mark_read: true,
+            from: None,
+            limit: None,
+        }
+    }
+}
+
+/// Send and read ATM messages as one identity on one default team.
+#[derive(Debug, Clone)]
+pub struct AtmClient {
+    home: PathBuf,
+    identity: String,
+    team: String,
+    id_scheme: MessageIdScheme,
+    max_conflict_retries: u32,
+}
+
+impl AtmClient {
+    /// Create a client with explicit home directory, identity and team.
+    ///
+    /// Uses the default message-id scheme and conflict retry budget.
+    pub fn new(
+        home: impl Into<PathBuf>,
+        identity: impl Into<String>,
+        team: impl Into<String>,
+    ) -> Self {
+        Self {
+            home: home.into(),
+            identity: identity.into(),
+            team: team.into(),
+            id_scheme: MessageIdScheme::default(),
+            max_conflict_retries: DEFAULT_MAX_CONFLICT_RETRIES,
+        }
+    }
+
+    /// Create a client from ATM configuration for the current directory.
+    ///
+    /// Resolves the home directory (`ATM_HOME` or platform home), then the
+    /// identity, default team and messaging settings the same way `atm` does.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Home` if no home directory can be determined,
+    /// or `ClientError::Config` if configuration fails to load.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let home = get_home_dir().map_err(|e| ClientError::Home(e.to_string()))?;
+        let current_dir = std::env::current_dir().map_err(ConfigError::Io)?;
+        let config = resolve_config(&ConfigOverrides::default(), &current_dir, &home)?;
+        let identity = resolve_identity(&config.core.identity, &config.roles, &config.aliases);
+        Ok(Self {
+            home,
+            identity,
+            team: config.core.default_team,
+            id_scheme: config.messaging.id_scheme.unwrap_or_default(),
+            max_conflict_retries: config
+                .messaging
+                .max_conflict_retries
+                .unwrap_or(DEFAULT_MAX_CONFLICT_RETRIES),
+        })
+    }
+
+    /// Use `scheme` for new message ids.
+    pub fn with_id_scheme(mut self, scheme: MessageIdScheme) -> Self {
+        self.id_scheme = scheme;
+        self
+    }
+
+    /// ATM home directory.
+    pub fn home(&self) -> &Path {
+        &self.home
+    }
+
+    /// Sender identity.
+    pub fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    /// Default team.
+    pub fn team(&self) -> &str {
+        &self.team
+    }
+
+    /// Inbox path for `agent` on `team`.
+    pub fn inbox_path(&self, team: &str, agent: &str) -> PathBuf {
+        inbox_path_for(&self.home, team, agent)
+    }
+
+    /// Send `text` to `to` (`agent` or `agent@team`).
+    ///
+    /// The summary is generated from the first 100 characters of `text`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::InvalidAddress` or `ClientError::InvalidMessage`
+    /// for bad input, `ClientError::UnknownTeam` or `ClientError::UnknownAgent`
+    /// if the recipient does not exist (like `atm send`), or
+    /// `ClientError::Inbox` if the write fails.
+    pub fn send(&self, to: &str, text: &str) -> Result<SendReceipt, ClientError> {
+        let (agent, team) = self.parse_address(to)?;
+        validate_message_text(text, DEFAULT_MAX_MESSAGE_BYTES)
+            .map_err(ClientError::InvalidMessage)?;
+        if !self
+            .load_team_config(&team)?
+            .members
+            .iter()
+            .any(|m| m.name == agent)
+        {
+            return Err(ClientError::UnknownAgent { agent, team });
+        }
+        let source_team = (team != self.team).then(|| self.team.clone());
+        self.deliver(&team, &agent, text, source_team)
+    }
+
+    /// Send `text` to every member of the client's team except itself.
+    ///
+    /// Each recipient is delivered independently; per-recipient results are
+    /// returned in team-config order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::UnknownTeam` or `ClientError::TeamConfig` if the
+    /// team config cannot be loaded, or `ClientError::InvalidMessage` if `text`
+    /// fails validation.
+    pub fn broadcast(
+        &self,
+        text: &str,
+    ) -> Result<Vec<Result<SendReceipt, ClientError>>, ClientError> {
+        validate_message_text(text, DEFAULT_MAX_MESSAGE_BYTES)
+            .map_err(ClientError::InvalidMessage)?;
+        let team_config = self.load_team_config(&self.team)?;
+
+        Ok(team_config
+            .members
+            .iter()
+            .filter(|member| member.name != self.identity)
+            .map(|member| self.deliver(&self.team, &member.name, text, None))
+            .collect())
+    }
+
+    /// Read messages from the client's own inbox, oldest first.
+    ///
+    /// A missing inbox yields an empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Inbox` if the inbox cannot be read or updated.
+    pub fn read(&self, options: &ReadOptions) -> Result<Vec<InboxMessage>, ClientError> {
+        let path = self.inbox_path(&self.team, &self.identity);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let selected: Vec<InboxMessage> = inbox_read_file_tolerant(&path)?
+            .into_iter()
+            .filter(|m| options.include_read || !m.read)
+            .filter(|m| options.from.as_ref().is_none_or(|from| &m.from == from))
+            .take(options.limit.unwrap_or(usize::MAX))
+            .collect();
+
+        if options.mark_read && selected.iter().any(|m| !m.read) {
+            let ids: HashSet<&str> = selected
+                .iter()
+                .filter_map(|m| m.message_id.as_deref())
+                .collect();
+            let keys: HashSet<(&str, &str)> = selected
+                .iter()
+                .filter(|m| m.message_id.is_none())
+                .map(|m| (m.from.as_str(), m.timestamp.as_str()))
+                .collect();
+            inbox_update(&path, &self.team, &self.identity, |messages| {
+                for m in messages.iter_mut() {
+                    let selected = match m.message_id.as_deref() {
+                        Some(id) => ids.contains(id),
+                        None => keys.contains(&(m.from.as_str(), m.timestamp.as_str())),
+                    };
+                    if selected {
+                        m.read = true;
+                    }
+                }
+            })?;
+        }
+
+        Ok(selected)
+    }
+
+    fn parse_address(&self, to: &str) -> Result<(String, String), ClientError> {
+        let (agent, team) = match to.split_once('@') {
+            Some((agent, team)) => (agent.trim(), team.trim()),
+            None => (to.trim(), self.team.as_str()),
+        };
+        if !is_path_component(agent) || !is_path_component(team) {
+            return Err(ClientError::InvalidAddress(to.to_string()));
+        }
+        Ok((agent.to_string(), team.to_string()))
+    }
+
+    fn load_team_config(&self, team: &str) -> Result<TeamConfig, ClientError> {
+        let path = team_config_path_for(&self.home, team);
+        if !path.parent().is_some_and(Path::is_dir) {
+            return Err(ClientError::UnknownTeam(team.to_string()));
+        }
+        std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|raw| serde_json::from_str(&raw).map_err(|e| e.to_string()))
+            .map_err(|message| ClientError::TeamConfig { path, message })
+    }
+
+    fn deliver(
+        &self,
+        team: &str,
+        agent: &str,
+        text: &str,
+        source_team: Option<String>,
+    ) -> Result<SendReceipt, ClientError> {
+        let path = self.inbox_path(team, agent);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|source| InboxError::Io {
+                path: parent.to_path_buf(),
+                source,
+            })?;
+        }
+        let message_id = new_message_id(self.id_scheme, &path)?;
+        let message = InboxMessage::new(self.identity.clone(), text)
+            .summary(summary_for(text))
+            .message_id(message_id.clone());
+        let message = match source_team {
+            Some(source_team) => message.source_team(source_team),
+            None => message,
+        };
+        let outcome =
+            inbox_append_bounded(&path, &message, team, agent, self.max_conflict_retries)?;
+        Ok(SendReceipt {
+            agent: agent.to_string(),
+            team: team.to_string(),
+            message_id,
+            outcome,
+        })
+    }
+}
+
+/// Whether `name` can be used as a single team or inbox path component.
+fn is_path_component(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('.')
+        && !name.contains(['/', '\\', '@'])
+        && !name.chars().any(char::is_whitespace)
+}
+
+fn summary_for(text: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.chars().count() <= SUMMARY_MAX_CHARS {
+        trimmed.to_string()
+    } else {
+        format!("{}...", truncate_chars_slice(trimmed, SUMMARY_MAX_CHARS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_team(home: &Path, team: &str, members: &[&str]) {
+        let members: Vec<serde_json::Value> = members
+            .iter()
+            .map(|name| {
+                serde_json::json!({
+                    "agentId": format!("{name}@{team}"),
+                    "name": name,
+                    "agentType": "general-purpose",
+                    "model": "claude-opus-4-6",
+                    "joinedAt": 1739284800000u64,
+                    "cwd": "/tmp"
+                })
+            })
+            .collect();
+        let config = serde_json::json!({
+            "name": team,
+            "createdAt": 1739284800000u64,
+            "leadAgentId": format!("team-lead@{team}"),
+            "leadSessionId": "sess",
+            "members": members,
+        });
+        let path = team_config_path_for(home, team);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, serde_json::to_string(&config).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_send_then_read_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        write_team(tmp.path(), "atm-dev", &["team-lead", "arch-ctm"]);
+        let lead = AtmClient::new(tmp.path(), "team-lead", "atm-dev");
+        let worker = AtmClient::new(tmp.path(), "arch-ctm", "atm-dev");
+
+        let receipt = lead.send("arch-ctm", "please review").unwrap();
+        assert_eq!(receipt.team, "atm-dev");
+        assert!(matches!(receipt.outcome, WriteOutcome::Success));
+
+        let unread = worker.read(&ReadOptions::default()).unwrap();
+        assert_eq!(unread.len(), 1);
+        assert_eq!(unread[0].from, "team-lead");
+        assert_eq!(
+            unread[0].message_id.as_deref(),
+            Some(receipt.message_id.as_str())
+        );
+        assert!(unread[0].source_team.is_none());
+
+        assert!(worker.read(&ReadOptions::default()).unwrap().is_empty());
+        let all = ReadOptions {
+            include_read: true,
+            ..Default::default()
+        };
+        assert_eq!(worker.read(&all).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_send_cross_team_sets_source_team_and_rejects_bad_address() {
+        let tmp = TempDir::new().unwrap();
+        write_team(tmp.path(), "other-team", &["qa"]);
+        let client = AtmClient::new(tmp.path(), "team-lead", "atm-dev")
+            .with_id_scheme(MessageIdScheme::Counter);
+
+        let receipt = client.send("qa@other-team", "hi").unwrap();
+        assert_eq!(receipt.team, "other-team");
+        assert!(
+            receipt
+                .message_id
+                .ends_with("/other-team/qa/00000000000000000001"),
+            "{}",
+            receipt.message_id
+        );
+        let stored = inbox_read_file_tolerant(&client.inbox_path("other-team", "qa")).unwrap();
+        assert_eq!(stored[0].source_team.as_deref(), Some("atm-dev"));
+
+        assert!(matches!(
+            client.send("@atm-dev", "hi"),
+            Err(ClientError::InvalidAddress(_))
+        ));
+        assert!(matches!(
+            client.send("qa@other-team", "bad\0text"),
+            Err(ClientError::InvalidMessage(_))
+        ));
+    }
+
+    #[test]
+    fn test_send_rejects_path_names_and_unknown_recipients() {
+        let tmp = TempDir::new().unwrap();
+        write_team(tmp.path(), "atm-dev", &["team-lead", "qa"]);
+        let client = AtmClient::new(tmp.path(), "team-lead", "atm-dev");
+
+        for to in ["..", "../qa", "qa/x", "qa@..", "qa@../atm-dev", "qa@a\\b"] {
+            assert!(
+                matches!(client.send(to, "hi"), Err(ClientError::InvalidAddress(_))),
+                "{to} should be rejected"
+            );
+        }
+        assert!(matches!(
+            client.send("qa@no-team", "hi"),
+            Err(ClientError::UnknownTeam(team)) if team == "no-team"
+        ));
+        assert!(!tmp.path().join(".claude/teams/no-team").exists());
+        assert!(matches!(
+            client.send("ghost", "hi"),
+            Err(ClientError::UnknownAgent { .. })
+        ));
+        assert!(!client.inbox_path("atm-dev", "ghost").exists());
+    }
+
+    #[test]
+    fn test_broadcast_skips_sender() {
+        let tmp = TempDir::new().unwrap();
+        write_team(tmp.path(), "atm-dev", &["team-lead", "arch-ctm", "qa"]);
+        let client = AtmClient::new(tmp.path(), "team-lead", "atm-dev");
+
+        let results = client.broadcast("standup").unwrap();
+        let agents: Vec<String> = results.into_iter().map(|r| r.unwrap().agent).collect();
+        assert_eq!(agents, vec!["arch-ctm", "qa"]);
+        assert!(!client.inbox_path("atm-dev", "team-lead").exists());
+
+        let missing = AtmClient::new(tmp.path(), "team-lead", "no-team");
+        assert!(matches!(
+            missing.broadcast("x"),
+            Err(ClientError::UnknownTeam(_))
+        ));
+    }
+}
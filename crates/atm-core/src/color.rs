@@ -0,0 +1,107 @@
+//! Terminal color policy shared by human-readable formatters
+//!
+//! The policy is process-global: the CLI sets it once from `--color` and
+//! formatters query [`stdout_color_enabled`] before emitting ANSI codes. In
+//! `auto` mode color is used only when stdout is a terminal and neither
+//! `NO_COLOR` (<https://no-color.org>) nor `ATM_NO_COLOR` is set, so piped or
+//! captured output never contains escape sequences.
+
+use std::io::IsTerminal;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// When to emit ANSI color codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Color only when stdout is a TTY and color is not disabled by env
+    #[default]
+    Auto,
+    /// Always emit color, even into pipes
+    Always,
+    /// Never emit color
+    Never,
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            other => Err(format!(
+                "invalid color choice '{other}' (expected auto, always, or never)"
+            )),
+        }
+    }
+}
+
+static COLOR_CHOICE: AtomicU8 = AtomicU8::new(0);
+
+/// Set the process-wide color policy.
+pub fn set_color_choice(choice: ColorChoice) {
+    let raw = match choice {
+        ColorChoice::Auto => 0,
+        ColorChoice::Always => 1,
+        ColorChoice::Never => 2,
+    };
+    COLOR_CHOICE.store(raw, Ordering::Relaxed);
+}
+
+/// Return the process-wide color policy (default: [`ColorChoice::Auto`]).
+pub fn color_choice() -> ColorChoice {
+    match COLOR_CHOICE.load(Ordering::Relaxed) {
+        1 => ColorChoice::Always,
+        2 => ColorChoice::Never,
+        _ => ColorChoice::Auto,
+    }
+}
+
+/// Return `true` when `NO_COLOR` or `ATM_NO_COLOR` requests plain output.
+///
+/// Per the `NO_COLOR` convention an empty value does not disable color.
+pub fn no_color_env() -> bool {
+    ["NO_COLOR", "ATM_NO_COLOR"]
+        .iter()
+        .any(|key| std::env::var_os(key).is_some_and(|v| !v.is_empty()))
+}
+
+/// Return `true` when output written to stdout may contain ANSI color codes.
+pub fn stdout_color_enabled() -> bool {
+    should_color(
+        color_choice(),
+        no_color_env(),
+        std::io::stdout().is_terminal(),
+    )
+}
+
+fn should_color(choice: ColorChoice, no_color: bool, is_tty: bool) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => is_tty && !no_color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_color_matrix() {
+        assert!(should_color(ColorChoice::Auto, false, true));
+        assert!(!should_color(ColorChoice::Auto, false, false));
+        assert!(!should_color(ColorChoice::Auto, true, true));
+        assert!(should_color(ColorChoice::Always, true, false));
+        assert!(!should_color(ColorChoice::Never, false, true));
+    }
+
+    #[test]
+    fn test_color_choice_from_str() {
+        assert_eq!("auto".parse::<ColorChoice>(), Ok(ColorChoice::Auto));
+        assert_eq!("ALWAYS".parse::<ColorChoice>(), Ok(ColorChoice::Always));
+        assert_eq!("never".parse::<ColorChoice>(), Ok(ColorChoice::Never));
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+    }
+}
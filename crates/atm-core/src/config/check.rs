@@ -0,0 +1,459 @@
+//! Semantic validation of config files (`atm config check`)
+//!
+//! [`resolve_config`] only rejects files that fail to parse. These checks look
+//! at each config layer on disk for settings that parse but cannot work:
+//! unknown keys and `[plugins.*]` sections, a `gh_monitor` provider nothing
+//! supplies, retention policies that delete every message, and bridge remotes
+//! missing required fields. Each finding names the file and dotted key.
+
+use super::discovery::{
+    ConfigError, ConfigOverrides, ConfigSource, config_sources, load_config_file, resolve_config,
+};
+use crate::retention::parse_duration;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// `[plugins.<name>]` sections read by ATM components
+pub const KNOWN_PLUGIN_SECTIONS: &[&str] = &[
+    "atm-agent-mcp",
+    "bridge",
+    "cleanup",
+    "gh_monitor",
+    "hooks",
+    "issues",
+    "workers",
+];
+
+/// CI providers available without loading a provider library
+const BUILTIN_CI_PROVIDERS: &[&str] = &["github"];
+
+/// How serious a [`ConfigDiagnostic`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticSeverity {
+    /// Suspicious but usable
+    Warning,
+    /// The setting cannot work as written
+    Error,
+}
+
+/// One problem found in a config file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigDiagnostic {
+    pub severity: DiagnosticSeverity,
+    /// File the problem was found in (`None` for resolution-wide failures)
+    pub file: Option<PathBuf>,
+    /// Dotted key path, e.g. `plugins.bridge.remotes[0].address`
+    pub key: String,
+    pub message: String,
+}
+
+/// Result of [`check_config`]
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigCheckReport {
+    /// Config layers consulted, lowest priority first
+    pub sources: Vec<ConfigSource>,
+    pub diagnostics: Vec<ConfigDiagnostic>,
+}
+
+impl ConfigCheckReport {
+    /// Number of [`DiagnosticSeverity::Error`] diagnostics
+    pub fn error_count(&self) -> usize {
+        self.count(DiagnosticSeverity::Error)
+    }
+
+    /// Number of [`DiagnosticSeverity::Warning`] diagnostics
+    pub fn warning_count(&self) -> usize {
+        self.count(DiagnosticSeverity::Warning)
+    }
+
+    fn count(&self, severity: DiagnosticSeverity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    }
+}
+
+/// Check every existing config layer [`resolve_config`] would read.
+///
+/// Unknown keys are warnings, or errors when `strict_config` is in effect.
+pub fn check_config(
+    overrides: &ConfigOverrides,
+    current_dir: &Path,
+    home_dir: &Path,
+) -> ConfigCheckReport {
+    let sources = config_sources(overrides, current_dir, home_dir);
+    let mut diagnostics = Vec::new();
+
+    let strict = match resolve_config(overrides, current_dir, home_dir) {
+        Ok(config) => config.core.strict_config == Some(true),
+        Err(ConfigError::UnknownKeys { .. }) => true,
+        Err(e) => {
+            diagnostics.push(ConfigDiagnostic {
+                severity: DiagnosticSeverity::Error,
+                file: None,
+                key: String::new(),
+                message: format!("config does not resolve: {e}"),
+            });
+            false
+        }
+    };
+
+    for source in sources.iter().filter(|s| s.exists) {
+        diagnostics.extend(check_config_file(&source.path, home_dir, strict));
+    }
+    ConfigCheckReport {
+        sources,
+        diagnostics,
+    }
+}
+
+/// Check a single config file.
+pub fn check_config_file(path: &Path, home_dir: &Path, strict: bool) -> Vec<ConfigDiagnostic> {
+    let mut out = Diagnostics {
+        file: path.to_path_buf(),
+        found: Vec::new(),
+    };
+
+    let raw = match std::fs::read_to_string(path)
+        .map_err(ConfigError::from)
+        .and_then(|contents| Ok(toml::from_str::<toml::Table>(&contents)?))
+    {
+        Ok(raw) => raw,
+        Err(e) => {
+            out.error("", format!("cannot be read or parsed: {e}"));
+            return out.found;
+        }
+    };
+    match load_config_file(path) {
+        Ok((_, unknown)) => {
+            for key in unknown {
+                let message = "unknown key (ignored)".to_string();
+                if strict {
+                    out.error(&key, message);
+                } else {
+                    out.warn(&key, message);
+                }
+            }
+        }
+        Err(e) => out.error("", format!("does not match the config schema: {e}")),
+    }
+
+    if let Some(retention) = raw.get("retention").and_then(toml::Value::as_table) {
+        let strategy = retention.get("strategy").and_then(toml::Value::as_str);
+        check_retention_caps(&mut out, "retention", retention, strategy);
+        if let Some(per_type) = retention.get("per_type").and_then(toml::Value::as_table) {
+            for (message_type, policy) in per_type {
+                if let Some(policy) = policy.as_table() {
+                    check_retention_caps(
+                        &mut out,
+                        &format!("retention.per_type.{message_type}"),
+                        policy,
+                        strategy,
+                    );
+                }
+            }
+        }
+    }
+
+    let Some(plugins) = raw.get("plugins").and_then(toml::Value::as_table) else {
+        return out.found;
+    };
+    for (name, section) in plugins {
+        let key = format!("plugins.{name}");
+        if !KNOWN_PLUGIN_SECTIONS.contains(&name.as_str()) {
+            out.warn(
+                &key,
+                "unknown plugin section; no ATM component reads it".to_string(),
+            );
+            continue;
+        }
+        let Some(section) = section.as_table() else {
+            out.error(&key, "must be a table".to_string());
+            continue;
+        };
+        match name.as_str() {
+            "gh_monitor" => check_ci_provider(&mut out, section, home_dir),
+            "bridge" => check_bridge_remotes(&mut out, section),
+            _ => {}
+        }
+    }
+    out.found
+}
+
+/// Accumulates diagnostics for one file
+struct Diagnostics {
+    file: PathBuf,
+    found: Vec<ConfigDiagnostic>,
+}
+
+impl Diagnostics {
+    fn push(&mut self, severity: DiagnosticSeverity, key: &str, message: String) {
+        self.found.push(ConfigDiagnostic {
+            severity,
+            file: Some(self.file.clone()),
+            key: key.to_string(),
+            message,
+        });
+    }
+
+    fn warn(&mut self, key: &str, message: String) {
+        self.push(DiagnosticSeverity::Warning, key, message);
+    }
+
+    fn error(&mut self, key: &str, message: String) {
+        self.push(DiagnosticSeverity::Error, key, message);
+    }
+}
+
+/// Flag unparseable ages and caps that keep nothing under the `delete`
+/// strategy (the default).
+fn check_retention_caps(
+    out: &mut Diagnostics,
+    prefix: &str,
+    caps: &toml::Table,
+    strategy: Option<&str>,
+) {
+    let deletes = strategy.is_none_or(|s| s == "delete");
+    let consequence = if deletes {
+        "every message would be deleted"
+    } else {
+        "every message would be archived"
+    };
+
+    if let Some(max_age) = caps.get("max_age") {
+        let key = format!("{prefix}.max_age");
+        match max_age.as_str().map(parse_duration) {
+            None => out.error(&key, "must be a duration string like \"7d\"".to_string()),
+            Some(Err(e)) => out.error(&key, format!("invalid duration: {e}")),
+            Some(Ok(age)) if age <= chrono::Duration::zero() => {
+                push_retention(out, deletes, &key, format!("is zero; {consequence}"));
+            }
+            Some(Ok(_)) => {}
+        }
+    }
+    if caps.get("max_count").and_then(toml::Value::as_integer) == Some(0) {
+        push_retention(
+            out,
+            deletes,
+            &format!("{prefix}.max_count"),
+            format!("is 0; {consequence}"),
+        );
+    }
+}
+
+fn push_retention(out: &mut Diagnostics, deletes: bool, key: &str, message: String) {
+    if deletes {
+        out.error(key, message);
+    } else {
+        out.warn(key, message);
+    }
+}
+
+/// The daemon resolves `provider` against built-ins, `[providers]` libraries,
+/// and libraries in `<home>/providers`. Library provider names are only known
+/// once loaded, so a name that may come from that directory is a warning.
+fn check_ci_provider(out: &mut Diagnostics, section: &toml::Table, home_dir: &Path) {
+    let Some(provider) = section.get("provider").and_then(toml::Value::as_str) else {
+        return;
+    };
+    if BUILTIN_CI_PROVIDERS.contains(&provider) {
+        return;
+    }
+    if section
+        .get("providers")
+        .and_then(toml::Value::as_table)
+        .is_some_and(|libs| libs.contains_key(provider))
+    {
+        return;
+    }
+    let provider_dir = home_dir.join("providers");
+    let has_libraries = std::fs::read_dir(&provider_dir).is_ok_and(|entries| {
+        entries.flatten().any(|entry| {
+            matches!(
+                entry.path().extension().and_then(|e| e.to_str()),
+                Some("so" | "dylib" | "dll")
+            )
+        })
+    });
+    let key = "plugins.gh_monitor.provider";
+    if has_libraries {
+        out.warn(
+            key,
+            format!(
+                "'{provider}' is not built in or listed in [providers]; it must be supplied by a library in {}",
+                provider_dir.display()
+            ),
+        );
+    } else {
+        out.error(
+            key,
+            format!(
+                "no provider named '{provider}' (built in: {}; no [providers] entry or library in {})",
+                BUILTIN_CI_PROVIDERS.join(", "),
+                provider_dir.display()
+            ),
+        );
+    }
+}
+
+fn check_bridge_remotes(out: &mut Diagnostics, section: &toml::Table) {
+    let Some(remotes) = section.get("remotes") else {
+        if section.get("enabled").and_then(toml::Value::as_bool) == Some(true) {
+            out.warn(
+                "plugins.bridge.remotes",
+                "bridge is enabled but has no remotes".to_string(),
+            );
+        }
+        return;
+    };
+    let Some(remotes) = remotes.as_array() else {
+        out.error(
+            "plugins.bridge.remotes",
+            "must be an array of tables".to_string(),
+        );
+        return;
+    };
+    for (index, remote) in remotes.iter().enumerate() {
+        let prefix = format!("plugins.bridge.remotes[{index}]");
+        let Some(remote) = remote.as_table() else {
+            out.error(&prefix, "must be a table".to_string());
+            continue;
+        };
+        for field in ["hostname", "address"] {
+            let present = remote
+                .get(field)
+                .and_then(toml::Value::as_str)
+                .is_some_and(|v| !v.trim().is_empty());
+            if !present {
+                out.error(
+                    &format!("{prefix}.{field}"),
+                    "required field is missing or empty".to_string(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn check(contents: &str) -> (TempDir, Vec<ConfigDiagnostic>) {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".atm.toml");
+        std::fs::write(&path, contents).unwrap();
+        let found = check_config_file(&path, temp.path(), false);
+        (temp, found)
+    }
+
+    fn keyed<'a>(found: &'a [ConfigDiagnostic], key: &str) -> &'a ConfigDiagnostic {
+        found
+            .iter()
+            .find(|d| d.key == key)
+            .unwrap_or_else(|| panic!("no diagnostic for {key}: {found:?}"))
+    }
+
+    #[test]
+    fn test_valid_config_has_no_diagnostics() {
+        let (_temp, found) = check(
+            "[core]\ndefault_team = \"t\"\nidentity = \"me\"\n\n[retention]\nmax_age = \"7d\"\n\n\
+             [plugins.gh_monitor]\nprovider = \"github\"\n\n\
+             [plugins.bridge]\nenabled = true\n\n[[plugins.bridge.remotes]]\n\
+             hostname = \"a\"\naddress = \"user@a\"\n",
+        );
+        assert!(found.is_empty(), "{found:?}");
+    }
+
+    #[test]
+    fn test_unknown_keys_and_plugin_sections_warn() {
+        let (_temp, found) = check("[messaging]\nid_shceme = \"ulid\"\n\n[plugins.gh-monitor]\n");
+        assert_eq!(
+            keyed(&found, "messaging.id_shceme").severity,
+            DiagnosticSeverity::Warning
+        );
+        assert_eq!(
+            keyed(&found, "plugins.gh-monitor").severity,
+            DiagnosticSeverity::Warning
+        );
+    }
+
+    #[test]
+    fn test_retention_that_deletes_everything_is_an_error() {
+        let (_temp, found) =
+            check("[retention]\nmax_count = 0\n\n[retention.per_type.ack]\nmax_age = \"0h\"\n");
+        assert_eq!(
+            keyed(&found, "retention.max_count").severity,
+            DiagnosticSeverity::Error
+        );
+        assert_eq!(
+            keyed(&found, "retention.per_type.ack.max_age").severity,
+            DiagnosticSeverity::Error
+        );
+
+        let (_temp, found) = check("[retention]\nstrategy = \"archive\"\nmax_count = 0\n");
+        assert_eq!(
+            keyed(&found, "retention.max_count").severity,
+            DiagnosticSeverity::Warning
+        );
+
+        let (_temp, found) = check("[retention]\nmax_age = \"7 weeks\"\n");
+        assert_eq!(
+            keyed(&found, "retention.max_age").severity,
+            DiagnosticSeverity::Error
+        );
+    }
+
+    #[test]
+    fn test_unknown_ci_provider_is_an_error_without_libraries() {
+        let (temp, found) = check("[plugins.gh_monitor]\nprovider = \"azure-pipelines\"\n");
+        assert_eq!(
+            keyed(&found, "plugins.gh_monitor.provider").severity,
+            DiagnosticSeverity::Error
+        );
+
+        std::fs::create_dir_all(temp.path().join("providers")).unwrap();
+        std::fs::write(temp.path().join("providers/libatm_ci_azure.so"), b"").unwrap();
+        let found = check_config_file(&temp.path().join(".atm.toml"), temp.path(), false);
+        assert_eq!(
+            keyed(&found, "plugins.gh_monitor.provider").severity,
+            DiagnosticSeverity::Warning
+        );
+
+        let (_temp, found) = check(
+            "[plugins.gh_monitor]\nprovider = \"azure\"\n\n\
+             [plugins.gh_monitor.providers]\nazure = \"/opt/libatm_ci_azure.so\"\n",
+        );
+        assert!(found.is_empty(), "{found:?}");
+    }
+
+    #[test]
+    fn test_bridge_remote_missing_fields_is_an_error() {
+        let (_temp, found) = check(
+            "[plugins.bridge]\nenabled = true\n\n[[plugins.bridge.remotes]]\nhostname = \"a\"\n",
+        );
+        assert_eq!(found.len(), 1, "{found:?}");
+        assert_eq!(found[0].key, "plugins.bridge.remotes[0].address");
+        assert_eq!(found[0].severity, DiagnosticSeverity::Error);
+    }
+
+    #[test]
+    fn test_strict_mode_makes_unknown_keys_errors() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("config.toml");
+        std::fs::write(&path, "[messaging]\nid_shceme = \"ulid\"\n").unwrap();
+        let found = check_config_file(&path, temp.path(), true);
+        assert_eq!(found[0].key, "messaging.id_shceme");
+        assert_eq!(found[0].severity, DiagnosticSeverity::Error);
+    }
+
+    #[test]
+    fn test_unparseable_file_is_an_error() {
+        let (_temp, found) = check("[core\n");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].severity, DiagnosticSeverity::Error);
+        assert!(found[0].message.contains("parsed"));
+    }
+}
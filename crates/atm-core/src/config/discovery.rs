@@ -1,6 +1,6 @@
 //! Configuration discovery and resolution
 
-use super::types::{Config, OutputFormat};
+use super::types::{Config, CoreConfig, OutputFormat};
 use crate::schema::SettingsJson;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -15,6 +15,17 @@ pub struct PluginConfigLocation {
     pub path: PathBuf,
 }
 
+/// A config file layer consulted by [`resolve_config`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ConfigSource {
+    /// Layer label (`global`, `repo`, or `explicit` for `--config`/`ATM_CONFIG`).
+    pub layer: String,
+    /// Path that was (or would have been) read for this layer.
+    pub path: PathBuf,
+    /// Whether the file existed at resolution time.
+    pub exists: bool,
+}
+
 /// Configuration error
 #[derive(Debug, Error)]
 pub enum ConfigError {
@@ -33,6 +44,13 @@ pub enum ConfigError {
     /// Configuration not found
     #[error("Configuration not found")]
     NotFound,
+
+    /// Unrecognized keys found while `strict_config` is enabled
+    #[error("Unknown config keys (strict_config): {}", .keys.join(", "))]
+    UnknownKeys {
+        /// Offending keys as `<file>: <dotted.key>`
+        keys: Vec<String>,
+    },
 }
 
 /// Command-line overrides for configuration
@@ -58,19 +76,25 @@ pub struct ConfigOverrides {
 /// 3. Repo-local config (.atm.toml in current dir or git root)
 /// 4. Global config (~/.config/atm/config.toml)
 /// 5. Defaults
+///
+/// Unrecognized keys in config files are ignored unless `strict_config` is
+/// enabled (`[core] strict_config = true` in any layer, or `ATM_STRICT_CONFIG`),
+/// in which case they are reported as [`ConfigError::UnknownKeys`].
 pub fn resolve_config(
     overrides: &ConfigOverrides,
     current_dir: &Path,
     home_dir: &Path,
 ) -> Result<Config, ConfigError> {
     let mut config = Config::default();
+    let mut unknown_keys = Vec::new();
     let config_path_override = resolve_config_path_override(overrides);
 
     // 4. Try global config
     let global_config_path = home_dir.join(".config/atm/config.toml");
     if global_config_path.exists() {
-        if let Ok(file_config) = load_config_file(&global_config_path) {
+        if let Ok((file_config, unknown)) = load_config_file(&global_config_path) {
             merge_config(&mut config, file_config);
+            note_unknown_keys(&mut unknown_keys, &global_config_path, unknown);
         } else {
             warn!("Failed to parse global config at {global_config_path:?}");
         }
@@ -78,8 +102,9 @@ pub fn resolve_config(
 
     // 3. Try repo-local config (current dir or git root)
     if let Some(repo_config) = find_repo_local_config(current_dir) {
-        if let Ok(file_config) = load_config_file(&repo_config) {
+        if let Ok((file_config, unknown)) = load_config_file(&repo_config) {
             merge_config(&mut config, file_config);
+            note_unknown_keys(&mut unknown_keys, &repo_config, unknown);
         } else {
             warn!("Failed to parse repo config at {repo_config:?}");
         }
@@ -89,8 +114,9 @@ pub fn resolve_config(
     // Explicit path override is strict (no silent fallback): parse/load failures
     // must surface as errors so operators can correct the path immediately.
     if let Some(path) = config_path_override {
-        let file_config = load_config_file(&path)?;
+        let (file_config, unknown) = load_config_file(&path)?;
         merge_config(&mut config, file_config);
+        note_unknown_keys(&mut unknown_keys, &path, unknown);
     }
 
     // 2. Apply environment variables
@@ -99,6 +125,10 @@ pub fn resolve_config(
     // 1. Apply command-line overrides
     apply_cli_overrides(&mut config, overrides);
 
+    if config.core.strict_config == Some(true) && !unknown_keys.is_empty() {
+        return Err(ConfigError::UnknownKeys { keys: unknown_keys });
+    }
+
     Ok(config)
 }
 
@@ -137,6 +167,46 @@ pub fn resolve_plugin_config_location(
     None
 }
 
+/// List the config files [`resolve_config`] consults, lowest priority first.
+///
+/// The repo layer reports the nearest `.atm.toml` up to the git root; when none
+/// exists it reports the path at the git root (or `current_dir` outside a repo).
+pub fn config_sources(
+    overrides: &ConfigOverrides,
+    current_dir: &Path,
+    home_dir: &Path,
+) -> Vec<ConfigSource> {
+    let global = home_dir.join(".config/atm/config.toml");
+    let repo = find_repo_local_config(current_dir).unwrap_or_else(|| {
+        current_dir
+            .ancestors()
+            .find(|dir| dir.join(".git").exists())
+            .unwrap_or(current_dir)
+            .join(".atm.toml")
+    });
+
+    let mut sources = vec![
+        ConfigSource {
+            layer: "global".to_string(),
+            exists: global.exists(),
+            path: global,
+        },
+        ConfigSource {
+            layer: "repo".to_string(),
+            exists: repo.exists(),
+            path: repo,
+        },
+    ];
+    if let Some(path) = resolve_config_path_override(overrides) {
+        sources.push(ConfigSource {
+            layer: "explicit".to_string(),
+            exists: path.exists(),
+            path,
+        });
+    }
+    sources
+}
+
 /// Find repo-local config file
 ///
 /// Searches current directory and parent directories up to git root
@@ -161,11 +231,55 @@ fn find_repo_local_config(current_dir: &Path) -> Option<PathBuf> {
     None
 }
 
-/// Load config from a TOML file
-fn load_config_file(path: &Path) -> Result<Config, ConfigError> {
+/// Load config from a TOML file, also returning keys the schema does not
+/// recognize (dotted paths such as `messaging.id_shceme`).
+pub(super) fn load_config_file(path: &Path) -> Result<(Config, Vec<String>), ConfigError> {
     let contents = std::fs::read_to_string(path)?;
-    let config: Config = toml::from_str(&contents)?;
-    Ok(config)
+
+    // Record every key the schema skips while deserializing; these are the
+    // ones in the file that would otherwise be silently dropped.
+    let mut unknown = Vec::new();
+    let config: Config =
+        serde_ignored::deserialize(toml::Deserializer::new(&contents), |ignored| {
+            let dotted = dotted_key_path(&ignored);
+            let section = dotted.split(['.', '[']).next().unwrap_or_default();
+            if !RAW_CONFIG_SECTIONS.contains(&section) {
+                unknown.push(dotted);
+            }
+        })?;
+    Ok((config, unknown))
+}
+
+/// Top-level tables read directly from raw TOML outside the `Config` schema.
+const RAW_CONFIG_SECTIONS: &[&str] = &["team"];
+
+/// Render an ignored key's path as `section.key`, with `[n]` for array items.
+fn dotted_key_path(path: &serde_ignored::Path<'_>) -> String {
+    match path {
+        serde_ignored::Path::Root => String::new(),
+        serde_ignored::Path::Seq { parent, index } => {
+            format!("{}[{index}]", dotted_key_path(parent))
+        }
+        serde_ignored::Path::Map { parent, key } => {
+            let parent = dotted_key_path(parent);
+            if parent.is_empty() {
+                key.clone()
+            } else {
+                format!("{parent}.{key}")
+            }
+        }
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => dotted_key_path(parent),
+    }
+}
+
+fn note_unknown_keys(acc: &mut Vec<String>, path: &Path, unknown: Vec<String>) {
+    acc.extend(
+        unknown
+            .into_iter()
+            .map(|key| format!("{}: {key}", path.display())),
+    );
 }
 
 fn config_file_declares_plugin(path: &Path, plugin_name: &str) -> bool {
@@ -187,6 +301,15 @@ fn merge_config(base: &mut Config, file: Config) {
     // Merge core config
     base.core.default_team = file.core.default_team;
     base.core.identity = file.core.identity;
+    if file.core.strict_config.is_some() {
+        base.core.strict_config = file.core.strict_config;
+    }
+    if file.core.inbox_file_mode.is_some() {
+        base.core.inbox_file_mode = file.core.inbox_file_mode;
+    }
+    if file.core.inbox_provenance.is_some() {
+        base.core.inbox_provenance = file.core.inbox_provenance;
+    }
 
     // Merge display config
     base.display.format = file.display.format;
@@ -197,6 +320,37 @@ fn merge_config(base: &mut Config, file: Config) {
     if file.messaging.offline_action.is_some() {
         base.messaging.offline_action = file.messaging.offline_action;
     }
+    if file.messaging.max_conflict_retries.is_some() {
+        base.messaging.max_conflict_retries = file.messaging.max_conflict_retries;
+    }
+    if file.messaging.id_scheme.is_some() {
+        base.messaging.id_scheme = file.messaging.id_scheme;
+    }
+    if file.messaging.validate_sender.is_some() {
+        base.messaging.validate_sender = file.messaging.validate_sender;
+    }
+    if file.messaging.broadcast_active_only.is_some() {
+        base.messaging.broadcast_active_only = file.messaging.broadcast_active_only;
+    }
+
+    // Merge daemon config
+    if file.daemon.max_inbox_workers.is_some() {
+        base.daemon.max_inbox_workers = file.daemon.max_inbox_workers;
+    }
+    if file.daemon.provider_load_failure.is_some() {
+        base.daemon.provider_load_failure = file.daemon.provider_load_failure;
+    }
+    if file.daemon.abstract_socket.is_some() {
+        base.daemon.abstract_socket = file.daemon.abstract_socket;
+    }
+
+    // Merge notification routing (later sources override earlier ones)
+    if file.notifications.default_target.is_some() {
+        base.notifications.default_target = file.notifications.default_target;
+    }
+    for (source, target) in file.notifications.overrides {
+        base.notifications.overrides.insert(source, target);
+    }
 
     // Merge retention config
     base.retention = file.retention;
@@ -217,6 +371,29 @@ fn merge_config(base: &mut Config, file: Config) {
     }
 }
 
+/// Install `[core] inbox_file_mode` for every inbox write in this process,
+/// ignoring values that are not valid octal modes.
+///
+/// The mode is process-global, so binaries call this once at startup with
+/// their own resolved config; [`resolve_config`] never installs it, since
+/// the daemon also resolves config for other teams and plugins.
+pub fn install_inbox_file_mode(core: &CoreConfig) {
+    let mode = core.inbox_file_mode.as_deref().and_then(|raw| {
+        let mode = crate::io::inbox::parse_file_mode(raw);
+        if mode.is_none() {
+            warn!("Ignoring invalid inbox_file_mode {raw:?}; expected an octal mode like \"0600\"");
+        }
+        mode
+    });
+    crate::io::inbox::set_inbox_file_mode(mode);
+}
+
+/// Install `[core] inbox_provenance` for every locked inbox write in this
+/// process. Process-global like [`install_inbox_file_mode`].
+pub fn install_inbox_provenance(core: &CoreConfig) {
+    crate::io::inbox::set_inbox_provenance(core.inbox_provenance);
+}
+
 /// Apply environment variable overrides
 fn apply_env_overrides(config: &mut Config) {
     if let Some(team) = env_var_nonempty("ATM_TEAM") {
@@ -227,7 +404,25 @@ fn apply_env_overrides(config: &mut Config) {
         config.core.identity = identity;
     }
 
-    if std::env::var("ATM_NO_COLOR").is_ok() {
+    if let Some(strict) = env_var_nonempty("ATM_STRICT_CONFIG") {
+        config.core.strict_config = Some(matches!(
+            strict.to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes"
+        ));
+    }
+
+    if let Some(mode) = env_var_nonempty("ATM_INBOX_FILE_MODE") {
+        config.core.inbox_file_mode = Some(mode);
+    }
+
+    if let Some(provenance) = env_var_nonempty("ATM_INBOX_PROVENANCE") {
+        config.core.inbox_provenance = Some(matches!(
+            provenance.to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes"
+        ));
+    }
+
+    if std::env::var("ATM_NO_COLOR").is_ok() || crate::color::no_color_env() {
         config.display.color = false;
     }
 }
@@ -402,7 +597,15 @@ mod tests {
         }
     }
 
-    const RESOLVE_ENV_KEYS: &[&str] = &["ATM_TEAM", "ATM_IDENTITY", "ATM_NO_COLOR", "ATM_CONFIG"];
+    const RESOLVE_ENV_KEYS: &[&str] = &[
+        "ATM_TEAM",
+        "ATM_IDENTITY",
+        "ATM_NO_COLOR",
+        "NO_COLOR",
+        "ATM_CONFIG",
+        "ATM_STRICT_CONFIG",
+        "ATM_INBOX_PROVENANCE",
+    ];
 
     #[test]
     #[serial]
@@ -420,6 +623,31 @@ mod tests {
         assert!(config.display.color);
     }
 
+    #[test]
+    #[serial]
+    fn test_resolve_config_does_not_install_inbox_file_mode() {
+        let _env_guard = EnvGuard::isolate(&["ATM_CONFIG", "ATM_INBOX_FILE_MODE"]);
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("atm.toml");
+        std::fs::write(
+            &config_path,
+            "[core]\ndefault_team = \"t\"\nidentity = \"a\"\ninbox_file_mode = \"0600\"\n",
+        )
+        .unwrap();
+        let overrides = ConfigOverrides {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+
+        let config = resolve_config(&overrides, temp_dir.path(), temp_dir.path()).unwrap();
+        assert_eq!(config.core.inbox_file_mode.as_deref(), Some("0600"));
+        assert_eq!(crate::io::inbox::inbox_file_mode(), None);
+
+        install_inbox_file_mode(&config.core);
+        assert_eq!(crate::io::inbox::inbox_file_mode(), Some(0o600));
+        crate::io::inbox::set_inbox_file_mode(None);
+    }
+
     #[test]
     #[serial]
     fn test_env_overrides() {
@@ -525,7 +753,8 @@ timestamps = "iso8601"
 
         std::fs::write(&config_path, toml_content).unwrap();
 
-        let config = load_config_file(&config_path).unwrap();
+        let (config, unknown) = load_config_file(&config_path).unwrap();
+        assert!(unknown.is_empty());
         assert_eq!(config.core.default_team, "file-team");
         assert_eq!(config.core.identity, "file-user");
         assert_eq!(config.display.format, OutputFormat::Json);
@@ -549,6 +778,27 @@ timestamps = "iso8601"
         std::fs::remove_file(&config_path).ok();
     }
 
+    #[test]
+    fn test_unknown_keys_come_from_schema_not_serialization() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        // Empty maps are skipped when serializing but are still schema keys.
+        std::fs::write(
+            &config_path,
+            "[notifications.overrides]\n\n[retention.per_type]\n\n\
+             [plugins.bridge]\nenabled = false\n\n\
+             [[plugins.bridge.remotes]]\nhostname = \"laptop\"\naddress = \"me@laptop\"\n\n\
+             [team.t]\nspawn_policy = \"any-member\"\n\n\
+             [core]\ndefault_team = \"t\"\nidentity = \"me\"\nidentiy = \"typo\"\n",
+        )
+        .unwrap();
+
+        let (_, unknown) = load_config_file(&config_path).unwrap();
+        assert_eq!(unknown, vec!["core.identiy".to_string()]);
+    }
+
     #[test]
     fn test_settings_resolution_from_subdirectory() {
         use tempfile::TempDir;
@@ -775,6 +1025,50 @@ enabled = false
         assert_eq!(config.core.identity, "override-user");
     }
 
+    #[test]
+    #[serial]
+    fn test_unknown_keys_ignored_by_default_and_rejected_when_strict() {
+        use tempfile::TempDir;
+        let _env_guard = EnvGuard::isolate(RESOLVE_ENV_KEYS);
+
+        let temp_dir = TempDir::new().unwrap();
+        let home_dir = temp_dir.path();
+        let repo_dir = temp_dir.path().join("repo");
+        std::fs::create_dir_all(repo_dir.join(".git")).unwrap();
+        let repo_config = repo_dir.join(".atm.toml");
+        std::fs::write(
+            &repo_config,
+            "[core]\ndefault_team = \"t\"\nidentity = \"me\"\ndefualt_team = \"typo\"\n\n\
+             [messaging]\nid_shceme = \"ulid\"\n\n\
+             [team.t]\nspawn_policy = \"any-member\"\n\n\
+             [plugins.gh_monitor]\nanything = true\n",
+        )
+        .unwrap();
+
+        let config = resolve_config(&ConfigOverrides::default(), &repo_dir, home_dir).unwrap();
+        assert_eq!(config.core.default_team, "t");
+
+        let global_cfg_dir = home_dir.join(".config/atm");
+        std::fs::create_dir_all(&global_cfg_dir).unwrap();
+        std::fs::write(
+            global_cfg_dir.join("config.toml"),
+            "[core]\ndefault_team = \"g\"\nidentity = \"g\"\nstrict_config = true\n",
+        )
+        .unwrap();
+
+        let err = resolve_config(&ConfigOverrides::default(), &repo_dir, home_dir).unwrap_err();
+        let ConfigError::UnknownKeys { keys } = err else {
+            panic!("expected UnknownKeys, got {err:?}");
+        };
+        assert_eq!(
+            keys,
+            vec![
+                format!("{}: core.defualt_team", repo_config.display()),
+                format!("{}: messaging.id_shceme", repo_config.display()),
+            ]
+        );
+    }
+
     #[test]
     #[serial]
     fn test_atm_config_env_override_merges_last() {
@@ -834,6 +1128,40 @@ enabled = false
         assert_eq!(location.path, repo_dir.join(".atm.toml"));
     }
 
+    #[test]
+    #[serial]
+    fn test_config_sources_report_each_layer_and_existence() {
+        use tempfile::TempDir;
+        let _env_guard = EnvGuard::isolate(RESOLVE_ENV_KEYS);
+
+        let temp_dir = TempDir::new().unwrap();
+        let home_dir = temp_dir.path();
+        let repo_dir = temp_dir.path().join("repo");
+        std::fs::create_dir_all(repo_dir.join(".git")).unwrap();
+        std::fs::write(
+            repo_dir.join(".atm.toml"),
+            "[core]
+",
+        )
+        .unwrap();
+
+        let overrides = ConfigOverrides {
+            config_path: Some(temp_dir.path().join("missing.toml")),
+            ..Default::default()
+        };
+        let sources = config_sources(&overrides, &repo_dir, home_dir);
+        let summary: Vec<(&str, bool)> = sources
+            .iter()
+            .map(|s| (s.layer.as_str(), s.exists))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("global", false), ("repo", true), ("explicit", false)]
+        );
+        assert_eq!(sources[0].path, home_dir.join(".config/atm/config.toml"));
+        assert_eq!(sources[1].path, repo_dir.join(".atm.toml"));
+    }
+
     #[test]
     #[serial]
     fn test_resolve_plugin_config_location_falls_back_to_global() {
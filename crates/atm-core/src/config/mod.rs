@@ -9,16 +9,23 @@
 
 pub mod aliases;
 mod bridge;
+mod check;
 mod discovery;
 mod types;
 
 pub use aliases::{resolve_alias, resolve_identity};
 pub use bridge::{BridgeConfig, BridgeRole, HostnameRegistry, RemoteConfig};
+pub use check::{
+    ConfigCheckReport, ConfigDiagnostic, DiagnosticSeverity, KNOWN_PLUGIN_SECTIONS, check_config,
+    check_config_file,
+};
 pub use discovery::{
-    ConfigError, ConfigOverrides, PluginConfigLocation, resolve_config,
+    ConfigError, ConfigOverrides, ConfigSource, PluginConfigLocation, config_sources,
+    install_inbox_file_mode, install_inbox_provenance, resolve_config,
     resolve_plugin_config_location, resolve_settings,
 };
 pub use types::{
-    CleanupStrategy, Config, CoreConfig, DisplayConfig, MessagingConfig, OutputFormat,
-    RetentionConfig, TimestampFormat,
+    AbstractSocketSetting, CleanupStrategy, Config, CoreConfig, DaemonConfig, DisplayConfig,
+    MessageIdScheme, MessagingConfig, NotificationsConfig, OutputFormat, ProviderLoadFailurePolicy,
+    RetentionConfig, TimestampFormat, TypeRetentionPolicy,
 };
@@ -18,6 +18,12 @@ pub struct Config {
     /// Retention configuration
     #[serde(default)]
     pub retention: RetentionConfig,
+    /// Daemon runtime configuration
+    #[serde(default)]
+    pub daemon: DaemonConfig,
+    /// Routing for daemon-generated system notifications
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
     /// Identity aliases: map alias-names to actual inbox identities.
     ///
     /// Use aliases for stable name shortcuts (e.g., `arch-atm = "team-lead"`).
@@ -48,6 +54,20 @@ pub struct CoreConfig {
     pub default_team: String,
     /// Sender identity
     pub identity: String,
+    /// Reject unrecognized config keys instead of ignoring them
+    /// (default: `false`; also enabled by `ATM_STRICT_CONFIG`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict_config: Option<bool>,
+    /// Octal permission bits applied to inbox files on write, e.g. `"0600"`
+    /// (Unix only; default: unset, leaving permissions to the umask; also
+    /// set by `ATM_INBOX_FILE_MODE`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_file_mode: Option<String>,
+    /// Record per-message write provenance in an `<agent>.provenance` sidecar
+    /// on every locked inbox write (default: `false`; also set by
+    /// `ATM_INBOX_PROVENANCE`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_provenance: Option<bool>,
 }
 
 impl Default for CoreConfig {
@@ -55,6 +75,9 @@ impl Default for CoreConfig {
         Self {
             default_team: "default".to_string(),
             identity: "human".to_string(),
+            strict_config: None,
+            inbox_file_mode: None,
+            inbox_provenance: None,
         }
     }
 }
@@ -97,6 +120,98 @@ pub struct MessagingConfig {
     /// If set to empty string, disables prepend entirely.
     #[serde(default)]
     pub offline_action: Option<String>,
+    /// Maximum conflict merge passes per inbox append before the write fails
+    /// (default: `io::inbox::DEFAULT_MAX_CONFLICT_RETRIES`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_conflict_retries: Option<u32>,
+    /// Reject `atm send` when the sender identity is neither a member of the
+    /// sender's team nor `ATM_IDENTITY` (override with `--as <identity>`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validate_sender: Option<bool>,
+    /// How new message ids are generated (default: `uuid`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_scheme: Option<MessageIdScheme>,
+    /// Make `atm broadcast` skip members the daemon does not report as
+    /// active or idle, as if `--active-only` were passed (default: `false`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broadcast_active_only: Option<bool>,
+}
+
+/// Message id generation scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageIdScheme {
+    /// Random UUIDv4 (collision-resistant, not sortable)
+    #[default]
+    Uuid,
+    /// ULID: 26-char Crockford base32, lexically sortable by creation time
+    Ulid,
+    /// Monotonic per-inbox counter, zero-padded so ids sort lexically
+    Counter,
+}
+
+/// Daemon runtime configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DaemonConfig {
+    /// Maximum inbox events processed concurrently by the daemon; excess
+    /// events queue until a worker frees up (default: 4).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_inbox_workers: Option<usize>,
+    /// What to do when a provider library fails to load (default: `skip`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_load_failure: Option<ProviderLoadFailurePolicy>,
+    /// Linux only: serve the daemon on an abstract-namespace socket instead
+    /// of the socket file. `true` selects the per-`ATM_HOME` default name; a
+    /// string is used as the name. Read from the global config only, so the
+    /// daemon and every client agree; `ATM_DAEMON_ABSTRACT_SOCKET` overrides.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abstract_socket: Option<AbstractSocketSetting>,
+}
+
+/// `[daemon] abstract_socket` value: on/off or an explicit socket name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AbstractSocketSetting {
+    /// `true` uses the default name; `false` keeps the socket file
+    Enabled(bool),
+    /// Explicit abstract socket name
+    Name(String),
+}
+
+/// Handling of provider libraries that fail to load (bad ABI, missing symbol)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProviderLoadFailurePolicy {
+    /// Log a warning and continue without the provider
+    #[default]
+    Skip,
+    /// Abort daemon startup
+    FailFast,
+}
+
+/// Routing for daemon-generated system notifications (`[notifications]`)
+///
+/// Targets use the same `agent` or `agent@team` form as `atm send`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotificationsConfig {
+    /// Recipient for notifications from any source without an override
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_target: Option<String>,
+    /// Per-source recipients keyed by notification source
+    /// (`[notifications.overrides]`, e.g. `gh_monitor = "ci-lead@dev"`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub overrides: HashMap<String, String>,
+}
+
+impl NotificationsConfig {
+    /// Configured target for `source`: its override, else `default_target`.
+    pub fn target_for(&self, source: &str) -> Option<&str> {
+        self.overrides
+            .get(source)
+            .or(self.default_target.as_ref())
+            .map(String::as_str)
+            .filter(|target| !target.trim().is_empty())
+    }
 }
 
 /// Timestamp display format
@@ -132,6 +247,30 @@ pub struct RetentionConfig {
     /// Interval in seconds between daemon retention runs (default: 300 = 5 minutes)
     #[serde(default = "default_interval_secs")]
     pub interval_secs: u64,
+    /// Cron expression (local time, e.g. `"0 3 * * *"`) for daemon cleanup runs.
+    ///
+    /// When set and `enabled` is true, the daemon runs retention on this
+    /// schedule instead of every `interval_secs`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_cleanup_cron: Option<String>,
+    /// Per-message-type overrides, keyed by the message `type` field
+    /// (`[retention.per_type.<type>]`).
+    ///
+    /// A cap set here replaces the matching global cap for messages of that
+    /// type; unset caps fall back to `max_age`/`max_count` above.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub per_type: HashMap<String, TypeRetentionPolicy>,
+}
+
+/// Retention caps for one message type
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeRetentionPolicy {
+    /// Maximum age for messages of this type (duration string: "1h", "90d")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age: Option<String>,
+    /// Maximum number of messages of this type kept per inbox
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_count: Option<usize>,
 }
 
 impl Default for RetentionConfig {
@@ -143,6 +282,8 @@ impl Default for RetentionConfig {
             archive_dir: None,
             enabled: false,
             interval_secs: default_interval_secs(),
+            auto_cleanup_cron: None,
+            per_type: HashMap::new(),
         }
     }
 }
@@ -171,6 +312,25 @@ impl Config {
     pub fn plugin_config(&self, name: &str) -> Option<&toml::Table> {
         self.plugins.get(name)
     }
+
+    /// Registry of the bridge plugin's remotes, used to recognise per-origin
+    /// inbox files (`<agent>.<hostname>.json`).
+    ///
+    /// Returns None if the bridge plugin is not configured, not enabled, or
+    /// its section does not parse.
+    pub fn hostname_registry(&self) -> Option<super::HostnameRegistry> {
+        let bridge: super::BridgeConfig = toml::Value::Table(self.plugin_config("bridge")?.clone())
+            .try_into()
+            .ok()?;
+        if !bridge.enabled {
+            return None;
+        }
+        let mut registry = super::HostnameRegistry::new();
+        for remote in bridge.remotes {
+            let _ = registry.register(remote);
+        }
+        Some(registry)
+    }
 }
 
 #[cfg(test)]
@@ -259,6 +419,30 @@ poll_interval = 60
         assert!(config.plugin_config("nonexistent").is_none());
     }
 
+    #[test]
+    fn test_hostname_registry_requires_enabled_bridge() {
+        let with_bridge = |enabled: bool| -> Config {
+            toml::from_str(&format!(
+                r#"
+[plugins.bridge]
+enabled = {enabled}
+
+[[plugins.bridge.remotes]]
+hostname = "laptop"
+address = "user@laptop"
+aliases = ["lt"]
+"#
+            ))
+            .unwrap()
+        };
+
+        let registry = with_bridge(true).hostname_registry().unwrap();
+        assert!(registry.is_known_hostname("laptop"));
+        assert!(registry.is_known_hostname("lt"));
+        assert!(with_bridge(false).hostname_registry().is_none());
+        assert!(Config::default().hostname_registry().is_none());
+    }
+
     #[test]
     fn test_plugin_config_empty() {
         let toml_str = r#"
@@ -375,6 +559,66 @@ team-lead = "arch-atm"
         );
     }
 
+    #[test]
+    fn test_messaging_max_conflict_retries_parsed() {
+        let config: Config = toml::from_str("[messaging]\nmax_conflict_retries = 7\n").unwrap();
+        assert_eq!(config.messaging.max_conflict_retries, Some(7));
+        assert_eq!(Config::default().messaging.max_conflict_retries, None);
+    }
+
+    #[test]
+    fn test_messaging_id_scheme_parsed() {
+        let config: Config = toml::from_str("[messaging]\nid_scheme = \"ulid\"\n").unwrap();
+        assert_eq!(config.messaging.id_scheme, Some(MessageIdScheme::Ulid));
+        assert!(toml::from_str::<Config>("[messaging]\nid_scheme = \"nope\"\n").is_err());
+        assert_eq!(Config::default().messaging.id_scheme, None);
+    }
+
+    #[test]
+    fn test_daemon_provider_load_failure_parsed() {
+        let config: Config =
+            toml::from_str("[daemon]\nprovider_load_failure = \"fail-fast\"\n").unwrap();
+        assert_eq!(
+            config.daemon.provider_load_failure,
+            Some(ProviderLoadFailurePolicy::FailFast)
+        );
+        assert!(toml::from_str::<Config>("[daemon]\nprovider_load_failure = \"abort\"\n").is_err());
+        assert_eq!(
+            Config::default()
+                .daemon
+                .provider_load_failure
+                .unwrap_or_default(),
+            ProviderLoadFailurePolicy::Skip
+        );
+    }
+
+    #[test]
+    fn test_messaging_validate_sender_parsed() {
+        let config: Config = toml::from_str("[messaging]\nvalidate_sender = true\n").unwrap();
+        assert_eq!(config.messaging.validate_sender, Some(true));
+        assert_eq!(Config::default().messaging.validate_sender, None);
+    }
+
+    #[test]
+    fn test_notifications_override_wins_over_default_target() {
+        let config: Config = toml::from_str(
+            r#"
+[notifications]
+default_target = "team-lead"
+
+[notifications.overrides]
+gh_monitor = "ci-lead@dev"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.notifications.target_for("gh_monitor"),
+            Some("ci-lead@dev")
+        );
+        assert_eq!(config.notifications.target_for("issues"), Some("team-lead"));
+        assert_eq!(Config::default().notifications.target_for("issues"), None);
+    }
+
     #[test]
     fn test_retention_config_partial() {
         let toml_str = r#"
@@ -389,5 +633,51 @@ interval_secs = 120
         assert_eq!(config.retention.max_age, None);
         assert_eq!(config.retention.max_count, None);
         assert_eq!(config.retention.strategy, CleanupStrategy::Delete);
+        assert!(config.retention.per_type.is_empty());
+        assert_eq!(config.retention.auto_cleanup_cron, None);
+    }
+
+    #[test]
+    fn test_retention_config_auto_cleanup_cron() {
+        let toml_str = r#"
+[retention]
+max_age = "7d"
+auto_cleanup_cron = "0 3 * * *"
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(!config.retention.enabled);
+        assert_eq!(
+            config.retention.auto_cleanup_cron.as_deref(),
+            Some("0 3 * * *")
+        );
+    }
+
+    #[test]
+    fn test_retention_config_per_type_overrides() {
+        let toml_str = r#"
+[retention]
+max_age = "7d"
+
+[retention.per_type.ci_alert]
+max_age = "24h"
+
+[retention.per_type.decision]
+max_age = "365d"
+max_count = 50
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let per_type = &config.retention.per_type;
+        assert_eq!(per_type.len(), 2);
+        assert_eq!(per_type["ci_alert"].max_age, Some("24h".to_string()));
+        assert_eq!(per_type["ci_alert"].max_count, None);
+        assert_eq!(
+            per_type["decision"],
+            TypeRetentionPolicy {
+                max_age: Some("365d".to_string()),
+                max_count: Some(50),
+            }
+        );
     }
 }
@@ -6,7 +6,11 @@
 mod platform;
 mod repo;
 mod system;
+mod template;
 
 pub use platform::Platform;
-pub use repo::{GitProvider, RepoContext};
+pub use repo::{
+    GitProvider, RepoContext, detect_current_branch, detect_repo_name, normalize_remote_url,
+};
 pub use system::SystemContext;
+pub use template::{TEMPLATE_PLACEHOLDERS, TemplateError, TemplateVars, expand_template};
@@ -0,0 +1,169 @@
+//! Message body template expansion
+//!
+//! Expands a small, fixed set of `{placeholder}` variables in message bodies.
+//! Expansion is opt-in at the call site so literal braces in ordinary
+//! messages are never rewritten. Use `{{` and `}}` to emit literal braces
+//! inside a template.
+
+use thiserror::Error;
+
+/// Placeholder names recognized by [`expand_template`].
+pub const TEMPLATE_PLACEHOLDERS: &[&str] = &["team", "from", "now", "branch"];
+
+/// Errors produced while expanding a message template
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TemplateError {
+    /// Placeholder name is not one of [`TEMPLATE_PLACEHOLDERS`]
+    #[error("unknown template placeholder '{{{name}}}' (supported: {supported})")]
+    UnknownPlaceholder { name: String, supported: String },
+
+    /// Placeholder is recognized but has no value in the current context
+    #[error("template placeholder '{{{name}}}' could not be resolved: {reason}")]
+    Unresolved { name: String, reason: String },
+
+    /// `{` without a matching `}` (or a stray `}`)
+    #[error("unbalanced brace in template at byte offset {offset}")]
+    Unbalanced { offset: usize },
+}
+
+/// Values available to template placeholders
+#[derive(Debug, Clone, Default)]
+pub struct TemplateVars {
+    /// Value for `{team}`
+    pub team: String,
+    /// Value for `{from}`
+    pub from: String,
+    /// Value for `{now}` (caller-formatted timestamp)
+    pub now: String,
+    /// Value for `{branch}`; `None` when not inside a git repository
+    pub branch: Option<String>,
+}
+
+impl TemplateVars {
+    fn lookup(&self, name: &str) -> Result<&str, TemplateError> {
+        match name {
+            "team" => Ok(&self.team),
+            "from" => Ok(&self.from),
+            "now" => Ok(&self.now),
+            "branch" => self
+                .branch
+                .as_deref()
+                .ok_or_else(|| TemplateError::Unresolved {
+                    name: name.to_string(),
+                    reason: "not inside a git repository".to_string(),
+                }),
+            _ => Err(TemplateError::UnknownPlaceholder {
+                name: name.to_string(),
+                supported: TEMPLATE_PLACEHOLDERS.join(", "),
+            }),
+        }
+    }
+}
+
+/// Expand `{placeholder}` variables in `body` using `vars`.
+///
+/// `{{` and `}}` produce literal braces. Any unknown placeholder, unresolved
+/// value, or unbalanced brace is an error; the body is never partially
+/// expanded.
+pub fn expand_template(body: &str, vars: &TemplateVars) -> Result<String, TemplateError> {
+    let mut out = String::with_capacity(body.len());
+    let mut chars = body.char_indices().peekable();
+
+    while let Some((offset, ch)) = chars.next() {
+        match ch {
+            '{' => {
+                if matches!(chars.peek(), Some((_, '{'))) {
+                    chars.next();
+                    out.push('{');
+                    continue;
+                }
+                let mut name = String::new();
+                let mut closed = false;
+                for (_, c) in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    if c == '{' {
+                        return Err(TemplateError::Unbalanced { offset });
+                    }
+                    name.push(c);
+                }
+                if !closed {
+                    return Err(TemplateError::Unbalanced { offset });
+                }
+                out.push_str(vars.lookup(name.trim())?);
+            }
+            '}' => {
+                if matches!(chars.peek(), Some((_, '}'))) {
+                    chars.next();
+                    out.push('}');
+                } else {
+                    return Err(TemplateError::Unbalanced { offset });
+                }
+            }
+            other => out.push(other),
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> TemplateVars {
+        TemplateVars {
+            team: "atm-dev".to_string(),
+            from: "arch-ctm".to_string(),
+            now: "2026-02-11T12:00:00Z".to_string(),
+            branch: Some("feature/x".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_expand_all_placeholders() {
+        let out = expand_template("[{team}] {from} on {branch} at {now}", &vars()).unwrap();
+        assert_eq!(
+            out,
+            "[atm-dev] arch-ctm on feature/x at 2026-02-11T12:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_expand_escaped_braces() {
+        let out = expand_template("{{literal}} {team}", &vars()).unwrap();
+        assert_eq!(out, "{literal} atm-dev");
+    }
+
+    #[test]
+    fn test_expand_unknown_placeholder_errors() {
+        let err = expand_template("hello {nope}", &vars()).unwrap_err();
+        assert!(
+            matches!(err, TemplateError::UnknownPlaceholder { ref name, .. } if name == "nope")
+        );
+    }
+
+    #[test]
+    fn test_expand_branch_unresolved_outside_repo() {
+        let mut v = vars();
+        v.branch = None;
+        let err = expand_template("{branch}", &v).unwrap_err();
+        assert!(matches!(err, TemplateError::Unresolved { .. }));
+        // Not referencing {branch} is fine without a repo.
+        assert_eq!(expand_template("{team}", &v).unwrap(), "atm-dev");
+    }
+
+    #[test]
+    fn test_expand_unbalanced_braces_error() {
+        assert_eq!(
+            expand_template("oops {team", &vars()).unwrap_err(),
+            TemplateError::Unbalanced { offset: 5 }
+        );
+        assert_eq!(
+            expand_template("oops }", &vars()).unwrap_err(),
+            TemplateError::Unbalanced { offset: 5 }
+        );
+    }
+}
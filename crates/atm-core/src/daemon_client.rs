@@ -295,6 +295,21 @@ pub struct AgentSummary {
     pub state: String,
 }
 
+/// Per-team summary returned by the `list-teams` command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamDaemonSummary {
+    /// Team name (directory name under the teams root).
+    pub team: String,
+    /// Number of members in team `config.json`.
+    pub member_count: usize,
+    /// Members with an active daemon session, sorted by name.
+    #[serde(default)]
+    pub live_agents: Vec<String>,
+    /// Unread messages across members' local inboxes.
+    #[serde(default)]
+    pub unread_total: usize,
+}
+
 /// Canonical daemon-backed member-state snapshot returned by team-scoped
 /// `list-agents` queries.
 ///
@@ -423,6 +438,64 @@ pub struct LaunchResult {
     pub warning: Option<String>,
 }
 
+/// Lifecycle of a launch request handled by the daemon's worker adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LaunchStatus {
+    /// Queued on the launch channel, not yet picked up by the worker adapter.
+    Pending,
+    /// The worker adapter is spawning the pane and waiting for readiness.
+    Launching,
+    /// The launch completed (possibly with a readiness warning).
+    Ready,
+    /// The launch failed or could not be queued.
+    Failed,
+}
+
+impl std::fmt::Display for LaunchStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Pending => "pending",
+            Self::Launching => "launching",
+            Self::Ready => "ready",
+            Self::Failed => "failed",
+        })
+    }
+}
+
+/// One launch tracked by the daemon, as returned by `launch-status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchStatusEntry {
+    /// Daemon-assigned launch id (monotonic for the daemon's lifetime).
+    pub id: u64,
+    /// Agent identity being launched.
+    pub agent: String,
+    /// Team the agent is launched into.
+    pub team: String,
+    /// Current lifecycle status.
+    pub status: LaunchStatus,
+    /// RFC 3339 time the launch was queued.
+    pub queued_at: String,
+    /// RFC 3339 time of the last status change.
+    pub updated_at: String,
+    /// tmux pane id once the launch is ready.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pane_id: Option<String>,
+    /// Failure reason or readiness warning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Launch queue snapshot returned by the `launch-status` command.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LaunchQueueStatus {
+    /// Launches queued but not yet picked up by the worker adapter.
+    pub queue_depth: usize,
+    /// In-flight launches followed by recently finished ones, oldest first.
+    #[serde(default)]
+    pub launches: Vec<LaunchStatusEntry>,
+}
+
 /// Request the daemon to launch a new agent.
 ///
 /// This is a synchronous call: the function blocks until the daemon responds
@@ -479,6 +552,38 @@ pub fn launch_agent(config: &LaunchConfig) -> anyhow::Result<Option<LaunchResult
     }
 }
 
+/// Query the daemon's agent launch queue (`launch-status`).
+///
+/// Returns `Ok(None)` when the daemon is not reachable or does not support
+/// the `launch-status` command.
+pub fn query_launch_status() -> anyhow::Result<Option<LaunchQueueStatus>> {
+    let request = SocketRequest {
+        version: PROTOCOL_VERSION,
+        request_id: new_request_id(),
+        command: "launch-status".to_string(),
+        payload: serde_json::Value::Object(Default::default()),
+    };
+
+    let response = match query_daemon(&request)? {
+        Some(r) => r,
+        None => return Ok(None),
+    };
+
+    if !response.is_ok() {
+        return Ok(None);
+    }
+
+    let payload = match response.payload {
+        Some(p) => p,
+        None => return Ok(None),
+    };
+
+    match serde_json::from_value::<LaunchQueueStatus>(payload) {
+        Ok(status) => Ok(Some(status)),
+        Err(_) => Ok(None),
+    }
+}
+
 /// Compute the daemon runtime directory.
 ///
 /// The path is `${ATM_HOME}/.atm/daemon`, where `ATM_HOME` is resolved via
@@ -1025,11 +1130,130 @@ pub fn write_daemon_lock_metadata(
     Ok(())
 }
 
+/// Environment variable selecting an abstract-namespace daemon socket.
+pub const ABSTRACT_SOCKET_ENV: &str = "ATM_DAEMON_ABSTRACT_SOCKET";
+
+/// Prefix of the default abstract socket name; see [`default_abstract_socket_name`].
+pub const DEFAULT_ABSTRACT_SOCKET_NAME: &str = "atm-daemon";
+
+/// Abstract-namespace socket name for the daemon, if one is selected.
+///
+/// Set by `atm-daemon --abstract-socket`, [`ABSTRACT_SOCKET_ENV`], or
+/// `[daemon] abstract_socket` in the global config (the variable wins): `1`,
+/// `true`, or `yes` select [`default_abstract_socket_name`]; any other
+/// non-empty value (except `0`/`false`/`no`) is used as the name. Abstract
+/// sockets live in the network namespace rather than the filesystem, so
+/// containers sharing a network namespace reach the daemon without
+/// bind-mounting `atm-daemon.sock`. Linux only: always `None` elsewhere.
+pub fn daemon_abstract_socket_name() -> Option<String> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let home = crate::home::get_home_dir().ok()?;
+    let raw = match std::env::var(ABSTRACT_SOCKET_ENV) {
+        Ok(raw) => raw,
+        Err(_) => configured_abstract_socket(&home)?,
+    };
+    parse_abstract_socket_name(&raw, &home)
+}
+
+/// Default abstract socket name for `home`: `atm-daemon-<hash>`, so daemons
+/// for different `ATM_HOME`s in one network namespace do not collide.
+pub fn default_abstract_socket_name(home: &Path) -> String {
+    let hash = crate::io::hash::compute_hash(home.as_os_str().as_encoded_bytes());
+    format!("{DEFAULT_ABSTRACT_SOCKET_NAME}-{}", &hash[..12])
+}
+
+/// `[daemon] abstract_socket` from the global config, in env-variable form.
+///
+/// Read once per home directory: this runs on every connect and socket
+/// presence check, and the daemon socket does not move while a process runs.
+fn configured_abstract_socket(home: &Path) -> Option<String> {
+    use std::collections::HashMap;
+    use std::sync::{Mutex, OnceLock};
+
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, Option<String>>>> = OnceLock::new();
+    let mut cache = CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    cache
+        .entry(home.to_path_buf())
+        .or_insert_with(|| read_configured_abstract_socket(home))
+        .clone()
+}
+
+fn read_configured_abstract_socket(home: &Path) -> Option<String> {
+    use crate::config::{AbstractSocketSetting, DaemonConfig};
+
+    #[derive(Deserialize)]
+    struct GlobalConfig {
+        #[serde(default)]
+        daemon: DaemonConfig,
+    }
+
+    let content = std::fs::read_to_string(home.join(".config/atm/config.toml")).ok()?;
+    let config: GlobalConfig = toml::from_str(&content).ok()?;
+    match config.daemon.abstract_socket? {
+        AbstractSocketSetting::Enabled(enabled) => Some(enabled.to_string()),
+        AbstractSocketSetting::Name(name) => Some(name),
+    }
+}
+
+fn parse_abstract_socket_name(raw: &str, home: &Path) -> Option<String> {
+    let value = raw.trim();
+    match value.to_ascii_lowercase().as_str() {
+        "" | "0" | "false" | "no" => None,
+        "1" | "true" | "yes" => Some(default_abstract_socket_name(home)),
+        _ => Some(value.to_string()),
+    }
+}
+
+/// Connect to the daemon: the abstract socket when one is selected (see
+/// [`daemon_abstract_socket_name`]), otherwise the socket file at `socket_path`.
+#[cfg(unix)]
+pub fn connect_daemon_socket(
+    socket_path: &std::path::Path,
+) -> std::io::Result<std::os::unix::net::UnixStream> {
+    #[cfg(target_os = "linux")]
+    if let Some(name) = daemon_abstract_socket_name() {
+        use std::os::linux::net::SocketAddrExt;
+        let addr = std::os::unix::net::SocketAddr::from_abstract_name(name.as_bytes())?;
+        return std::os::unix::net::UnixStream::connect_addr(&addr);
+    }
+    std::os::unix::net::UnixStream::connect(socket_path)
+}
+
+/// Whether a daemon socket may be listening: always for an abstract socket
+/// (it has no file), otherwise when the socket file exists.
+#[cfg(unix)]
+fn daemon_socket_present(socket_path: &std::path::Path) -> bool {
+    daemon_abstract_socket_name().is_some() || socket_path.exists()
+}
+
+/// Whether daemon routing is disabled for this process.
+///
+/// Set by `atm --no-daemon` or `ATM_NO_DAEMON=1` (also `true`/`yes`). When
+/// disabled, every query and subscription behaves as if the daemon were not
+/// running, auto-start is skipped, and callers use their filesystem paths.
+pub fn daemon_disabled() -> bool {
+    std::env::var("ATM_NO_DAEMON").is_ok_and(|raw| {
+        matches!(
+            raw.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes"
+        )
+    })
+}
+
 /// Check whether the daemon appears to be running by reading its PID file and
 /// verifying the process is alive.
 ///
-/// Returns `false` on any error (missing file, invalid PID, dead process, etc.).
+/// Returns `false` on any error (missing file, invalid PID, dead process, etc.)
+/// and when [`daemon_disabled`] is set.
 pub fn daemon_is_running() -> bool {
+    if daemon_disabled() {
+        return false;
+    }
     #[cfg(unix)]
     {
         let pid_path = match daemon_pid_path() {
@@ -1056,8 +1280,12 @@ pub fn daemon_is_running() -> bool {
 /// - Delegates to the full Unix implementation used by runtime queries,
 ///   including startup lock coordination, socket probing, and event logging.
 ///
-/// On non-Unix platforms this is a no-op and returns `Ok(())`.
+/// On non-Unix platforms, and when [`daemon_disabled`] is set, this is a
+/// no-op and returns `Ok(())`.
 pub fn ensure_daemon_running() -> anyhow::Result<()> {
+    if daemon_disabled() {
+        return Ok(());
+    }
     #[cfg(unix)]
     {
         ensure_daemon_running_unix()
@@ -1112,6 +1340,75 @@ pub fn query_daemon_with_timeout(
     }
 }
 
+/// Check that the daemon answers a `ping` within `timeout`, returning its PID.
+///
+/// Unlike [`query_daemon`] this never auto-starts the daemon, so a stopped
+/// daemon is reported as an error; intended for liveness probes.
+pub fn ping_daemon(timeout: std::time::Duration) -> anyhow::Result<u32> {
+    #[cfg(unix)]
+    {
+        ping_daemon_unix(timeout)
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = timeout;
+        anyhow::bail!("daemon health probes require Unix domain sockets")
+    }
+}
+
+#[cfg(unix)]
+fn ping_daemon_unix(timeout: std::time::Duration) -> anyhow::Result<u32> {
+    use std::io::{BufRead, BufReader, Write};
+
+    if daemon_disabled() {
+        anyhow::bail!("daemon routing is disabled (ATM_NO_DAEMON)");
+    }
+    let socket_path = daemon_socket_path()?;
+    let stream = connect_daemon_socket(&socket_path).map_err(|e| {
+        anyhow::anyhow!(
+            "daemon socket unreachable at {}: {e}",
+            socket_path.display()
+        )
+    })?;
+    stream.set_read_timeout(Some(timeout)).ok();
+    stream.set_write_timeout(Some(timeout)).ok();
+
+    let request = SocketRequest {
+        version: PROTOCOL_VERSION,
+        request_id: new_request_id(),
+        command: "ping".to_string(),
+        payload: serde_json::json!({}),
+    };
+    let mut writer = &stream;
+    writer.write_all(format!("{}\n", serde_json::to_string(&request)?).as_bytes())?;
+    writer.flush()?;
+
+    let mut response_line = String::new();
+    match BufReader::new(&stream).read_line(&mut response_line) {
+        Ok(0) => anyhow::bail!("daemon closed the connection without responding"),
+        Ok(_) => {}
+        Err(e) => anyhow::bail!(
+            "daemon did not respond within {}ms: {e}",
+            timeout.as_millis()
+        ),
+    }
+    let response: SocketResponse = serde_json::from_str(response_line.trim())
+        .map_err(|e| anyhow::anyhow!("daemon returned an unparseable response: {e}"))?;
+    if !response.is_ok() {
+        let message = response
+            .error
+            .map(|e| e.message)
+            .unwrap_or_else(|| "unknown error".to_string());
+        anyhow::bail!("daemon rejected ping: {message}");
+    }
+    response
+        .payload
+        .and_then(|p| p.get("pid").and_then(|v| v.as_u64()))
+        .and_then(|pid| u32::try_from(pid).ok())
+        .ok_or_else(|| anyhow::anyhow!("daemon ping response is missing its pid"))
+}
+
 /// Query the daemon for the current state of a specific agent.
 ///
 /// Returns `Ok(None)` when the daemon is not reachable or the agent is not tracked.
@@ -1296,6 +1593,98 @@ pub fn query_list_agents_for_team(team: &str) -> anyhow::Result<Option<Vec<Agent
     }
 }
 
+/// Query the daemon for per-team summaries including daemon-known liveness.
+///
+/// Returns `Ok(None)` when the daemon is not reachable or does not support
+/// the `list-teams` command.
+pub fn query_list_teams() -> anyhow::Result<Option<Vec<TeamDaemonSummary>>> {
+    let request = SocketRequest {
+        version: PROTOCOL_VERSION,
+        request_id: new_request_id(),
+        command: "list-teams".to_string(),
+        payload: serde_json::Value::Object(Default::default()),
+    };
+
+    let response = match query_daemon(&request)? {
+        Some(r) => r,
+        None => return Ok(None),
+    };
+
+    if !response.is_ok() {
+        return Ok(None);
+    }
+
+    let payload = match response.payload {
+        Some(p) => p,
+        None => return Ok(None),
+    };
+
+    match serde_json::from_value::<Vec<TeamDaemonSummary>>(payload) {
+        Ok(teams) => Ok(Some(teams)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Ask the daemon to run one spool drain pass immediately.
+///
+/// Returns the pass's delivered/pending/failed counts, or `Ok(None)` when the
+/// daemon is not reachable or does not support the `spool-drain` command.
+pub fn request_spool_drain() -> anyhow::Result<Option<crate::io::SpoolStatus>> {
+    let request = SocketRequest {
+        version: PROTOCOL_VERSION,
+        request_id: new_request_id(),
+        command: "spool-drain".to_string(),
+        payload: serde_json::Value::Object(Default::default()),
+    };
+
+    let response = match query_daemon(&request)? {
+        Some(r) => r,
+        None => return Ok(None),
+    };
+
+    if !response.is_ok() {
+        return Ok(None);
+    }
+
+    Ok(response
+        .payload
+        .and_then(|payload| serde_json::from_value(payload).ok()))
+}
+
+/// Ask the daemon whether `agent@team` has marked `message_id` read.
+///
+/// Returns `Ok(None)` when the daemon is not reachable or does not support
+/// the `message-read` command, so callers can fall back to reading the inbox.
+pub fn query_message_read(
+    team: &str,
+    agent: &str,
+    message_id: &str,
+) -> anyhow::Result<Option<bool>> {
+    let request = SocketRequest {
+        version: PROTOCOL_VERSION,
+        request_id: new_request_id(),
+        command: "message-read".to_string(),
+        payload: serde_json::json!({
+            "team": team,
+            "agent": agent,
+            "message_id": message_id,
+        }),
+    };
+
+    let response = match query_daemon(&request)? {
+        Some(r) => r,
+        None => return Ok(None),
+    };
+
+    if !response.is_ok() {
+        return Ok(None);
+    }
+
+    Ok(response
+        .payload
+        .and_then(|payload| payload.get("read").and_then(|v| v.as_bool())))
+}
+
 /// Query the daemon for canonical member-state snapshots scoped to one team.
 ///
 /// Returns:
@@ -1692,13 +2081,13 @@ pub fn query_agent_stream_state(
 /// Handle for an active daemon stream subscription.
 ///
 /// Dropping this value requests the background reader thread to stop.
-pub struct StreamSubscription {
-    /// Receiver of daemon stream events.
-    pub rx: std::sync::mpsc::Receiver<crate::daemon_stream::DaemonStreamEvent>,
+pub struct StreamSubscription<T = crate::daemon_stream::DaemonStreamEvent> {
+    /// Receiver of streamed items (daemon stream events by default).
+    pub rx: std::sync::mpsc::Receiver<T>,
     stop: std::sync::Arc<std::sync::atomic::AtomicBool>,
 }
 
-impl Drop for StreamSubscription {
+impl<T> Drop for StreamSubscription<T> {
     fn drop(&mut self) {
         self.stop.store(true, std::sync::atomic::Ordering::Relaxed);
     }
@@ -1712,11 +2101,35 @@ impl Drop for StreamSubscription {
 pub fn subscribe_stream_events() -> anyhow::Result<Option<StreamSubscription>> {
     #[cfg(unix)]
     {
-        subscribe_stream_events_unix()
+        open_stream_subscription_unix("stream-subscribe", serde_json::json!({}))
+    }
+
+    #[cfg(not(unix))]
+    {
+        Ok(None)
+    }
+}
+
+/// Subscribe to the daemon's own emitted events (`atm daemon tail-events`).
+///
+/// Each item is a [`LogEventV1`](crate::logging_event::LogEventV1) delivered as
+/// the daemon emits it; events emitted before the subscription opened are not
+/// replayed. A non-empty `actions` list limits the stream to those actions.
+///
+/// Returns:
+/// - `Ok(Some(subscription))` when subscription succeeds.
+/// - `Ok(None)` when daemon/socket is unavailable on this platform/session.
+pub fn subscribe_daemon_events(
+    actions: &[String],
+) -> anyhow::Result<Option<StreamSubscription<crate::logging_event::LogEventV1>>> {
+    #[cfg(unix)]
+    {
+        open_stream_subscription_unix("tail-events", serde_json::json!({ "actions": actions }))
     }
 
     #[cfg(not(unix))]
     {
+        let _ = actions;
         Ok(None)
     }
 }
@@ -2132,13 +2545,16 @@ fn query_daemon_unix(
     read_timeout: std::time::Duration,
 ) -> anyhow::Result<Option<SocketResponse>> {
     use std::io::{BufRead, BufReader, Write};
-    use std::os::unix::net::UnixStream;
     use std::time::{Duration, Instant};
 
+    if daemon_disabled() {
+        return Ok(None);
+    }
+
     let socket_path = daemon_socket_path()?;
 
     // First attempt connection directly.
-    let stream = match UnixStream::connect(&socket_path) {
+    let stream = match connect_daemon_socket(&socket_path) {
         Ok(s) => s,
         Err(_) => {
             // Optional daemon auto-start path, enabled by ATM_DAEMON_AUTOSTART.
@@ -2146,7 +2562,7 @@ fn query_daemon_unix(
                 ensure_daemon_running_unix()?;
                 let deadline = Instant::now() + Duration::from_secs(STARTUP_DEADLINE_SECS);
                 loop {
-                    match UnixStream::connect(&socket_path) {
+                    match connect_daemon_socket(&socket_path) {
                         Ok(s) => break s,
                         Err(e) if Instant::now() < deadline => {
                             let _ = e;
@@ -2165,10 +2581,10 @@ fn query_daemon_unix(
                 // If the socket path already exists the daemon may be mid-startup
                 // (socket bound but not yet accepting). Retry briefly before giving up
                 // so we don't return Ok(None) during that narrow window.
-                if socket_path.exists() {
+                if daemon_socket_present(&socket_path) {
                     let mut connected = None;
                     for _ in 0..3 {
-                        match UnixStream::connect(&socket_path) {
+                        match connect_daemon_socket(&socket_path) {
                             Ok(s) => {
                                 connected = Some(s);
                                 break;
@@ -2569,9 +2985,8 @@ fn ensure_daemon_running_unix() -> anyhow::Result<()> {
 
 #[cfg(unix)]
 fn daemon_socket_connectable(home: &std::path::Path) -> bool {
-    use std::os::unix::net::UnixStream;
     let socket_path = home.join(".atm/daemon/atm-daemon.sock");
-    UnixStream::connect(socket_path).is_ok()
+    connect_daemon_socket(&socket_path).is_ok()
 }
 
 #[cfg(unix)]
@@ -2915,13 +3330,23 @@ fn send_signal(pid: i32, sig: i32) {
     let _ = unsafe { kill(pid, sig) };
 }
 
+/// Open a long-lived streaming `command` connection and decode each line as `T`.
 #[cfg(unix)]
-fn subscribe_stream_events_unix() -> anyhow::Result<Option<StreamSubscription>> {
+fn open_stream_subscription_unix<T>(
+    command: &str,
+    payload: serde_json::Value,
+) -> anyhow::Result<Option<StreamSubscription<T>>>
+where
+    T: serde::de::DeserializeOwned + Send + 'static,
+{
     use std::io::{BufRead, BufReader, Write};
-    use std::os::unix::net::UnixStream;
+
+    if daemon_disabled() {
+        return Ok(None);
+    }
 
     let socket_path = daemon_socket_path()?;
-    let mut stream = match UnixStream::connect(&socket_path) {
+    let mut stream = match connect_daemon_socket(&socket_path) {
         Ok(s) => s,
         Err(_) => return Ok(None),
     };
@@ -2929,8 +3354,8 @@ fn subscribe_stream_events_unix() -> anyhow::Result<Option<StreamSubscription>>
     let req = SocketRequest {
         version: PROTOCOL_VERSION,
         request_id: new_request_id(),
-        command: "stream-subscribe".to_string(),
-        payload: serde_json::json!({}),
+        command: command.to_string(),
+        payload,
     };
     let req_line = serde_json::to_string(&req)?;
     stream.write_all(req_line.as_bytes())?;
@@ -2962,7 +3387,7 @@ fn subscribe_stream_events_unix() -> anyhow::Result<Option<StreamSubscription>>
         }
     }
 
-    let (tx, rx) = std::sync::mpsc::channel::<crate::daemon_stream::DaemonStreamEvent>();
+    let (tx, rx) = std::sync::mpsc::channel::<T>();
     let stop = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
     let stop_thread = std::sync::Arc::clone(&stop);
     stream
@@ -2992,10 +3417,8 @@ fn subscribe_stream_events_unix() -> anyhow::Result<Option<StreamSubscription>>
             if trimmed.is_empty() {
                 continue;
             }
-            if let Ok(event) =
-                serde_json::from_str::<crate::daemon_stream::DaemonStreamEvent>(trimmed)
-            {
-                if tx.send(event).is_err() {
+            if let Ok(item) = serde_json::from_str::<T>(trimmed) {
+                if tx.send(item).is_err() {
                     break;
                 }
             }
@@ -3162,6 +3585,121 @@ mod tests {
         f()
     }
 
+    #[test]
+    #[serial]
+    fn test_daemon_disabled_env_values() {
+        for (value, expected) in [("1", true), ("TRUE", true), ("yes", true), ("0", false)] {
+            let _guard = EnvGuard::set("ATM_NO_DAEMON", value);
+            assert_eq!(daemon_disabled(), expected, "ATM_NO_DAEMON={value}");
+        }
+        let _guard = EnvGuard::unset("ATM_NO_DAEMON");
+        assert!(!daemon_disabled());
+    }
+
+    #[test]
+    fn test_parse_abstract_socket_name() {
+        let home = Path::new("/home/a");
+        for off in ["", " ", "0", "false", "NO"] {
+            assert_eq!(parse_abstract_socket_name(off, home), None, "{off:?}");
+        }
+        for on in ["1", "true", "Yes"] {
+            assert_eq!(
+                parse_abstract_socket_name(on, home),
+                Some(default_abstract_socket_name(home)),
+                "{on:?}"
+            );
+        }
+        assert_eq!(
+            parse_abstract_socket_name(" atm-ci ", home).as_deref(),
+            Some("atm-ci")
+        );
+        assert!(default_abstract_socket_name(home).starts_with("atm-daemon-"));
+        assert_ne!(
+            default_abstract_socket_name(home),
+            default_abstract_socket_name(Path::new("/home/b"))
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[serial]
+    fn test_abstract_socket_name_from_global_config() {
+        let tmp = tempfile::tempdir().unwrap();
+        let _home = EnvGuard::set("ATM_HOME", tmp.path().to_str().unwrap());
+        let _env = EnvGuard::unset(ABSTRACT_SOCKET_ENV);
+        assert_eq!(daemon_abstract_socket_name(), None);
+
+        // The config is cached per home, so configure a fresh one
+        let tmp = tempfile::tempdir().unwrap();
+        let _home = EnvGuard::set("ATM_HOME", tmp.path().to_str().unwrap());
+        let config_dir = tmp.path().join(".config/atm");
+        std::fs::create_dir_all(&config_dir).unwrap();
+        std::fs::write(
+            config_dir.join("config.toml"),
+            "[daemon]\nabstract_socket = true\n",
+        )
+        .unwrap();
+        let home = crate::home::get_home_dir().unwrap();
+        assert_eq!(
+            daemon_abstract_socket_name(),
+            Some(default_abstract_socket_name(&home))
+        );
+
+        // The environment variable overrides the config file
+        let _env = EnvGuard::set(ABSTRACT_SOCKET_ENV, "0");
+        assert_eq!(daemon_abstract_socket_name(), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[serial]
+    fn test_connect_daemon_socket_uses_abstract_name() {
+        use std::os::linux::net::SocketAddrExt;
+        use std::os::unix::net::{SocketAddr, UnixListener};
+
+        let name = format!("atm-test-{}", std::process::id());
+        let addr = SocketAddr::from_abstract_name(name.as_bytes()).unwrap();
+        let _listener = UnixListener::bind_addr(&addr).unwrap();
+        let missing = std::path::Path::new("/nonexistent/atm-daemon.sock");
+
+        let _guard = EnvGuard::set(ABSTRACT_SOCKET_ENV, &name);
+        assert!(daemon_socket_present(missing));
+        assert!(connect_daemon_socket(missing).is_ok());
+
+        let _guard = EnvGuard::unset(ABSTRACT_SOCKET_ENV);
+        assert!(!daemon_socket_present(missing));
+        assert!(connect_daemon_socket(missing).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[serial]
+    fn test_no_daemon_skips_socket_even_when_listening() {
+        use std::os::unix::net::UnixListener;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let _home = EnvGuard::set("ATM_HOME", tmp.path().to_str().unwrap());
+        let _no_daemon = EnvGuard::set("ATM_NO_DAEMON", "1");
+        let socket_path = daemon_socket_path().unwrap();
+        std::fs::create_dir_all(socket_path.parent().unwrap()).unwrap();
+        let listener = UnixListener::bind(&socket_path).unwrap();
+        listener.set_nonblocking(true).unwrap();
+
+        let request = SocketRequest {
+            version: PROTOCOL_VERSION,
+            request_id: new_request_id(),
+            command: "list-agents".to_string(),
+            payload: serde_json::json!({}),
+        };
+        assert!(query_daemon(&request).unwrap().is_none());
+        assert!(subscribe_stream_events().unwrap().is_none());
+        assert!(ensure_daemon_running().is_ok());
+        assert!(
+            listener.accept().is_err(),
+            "--no-daemon must not connect to the daemon socket"
+        );
+    }
+
     #[test]
     fn test_socket_request_serialization() {
         let req = SocketRequest {
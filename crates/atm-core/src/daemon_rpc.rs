@@ -0,0 +1,503 @@
+//! JSON-RPC 2.0 session mode for the daemon socket.
+//!
+//! The default socket protocol is one [`SocketRequest`] line and one
+//! [`SocketResponse`] line per connection. Interactive clients (dashboards,
+//! TUIs) can instead upgrade a connection into a persistent JSON-RPC session
+//! that carries many requests and server-initiated notifications.
+//!
+//! # Handshake
+//!
+//! The client sends a normal [`SocketRequest`] with command
+//! [`RPC_SESSION_COMMAND`]. The daemon answers with an ok [`SocketResponse`]
+//! whose payload is `{"protocol": "jsonrpc-2.0", "topics": [...]}`. A daemon
+//! that predates session mode answers `UNKNOWN_COMMAND` and closes the
+//! connection, so clients can fall back to one-shot queries.
+//!
+//! # Framing
+//!
+//! After the handshake both sides exchange newline-delimited JSON-RPC 2.0
+//! messages until either side closes the connection:
+//!
+//! - Any one-shot socket command is a method: `method` is the command name and
+//!   `params` is its payload. The result is the one-shot response payload.
+//! - [`RPC_SUBSCRIBE_METHOD`] with `{"topic": "events", "actions": [...]}`
+//!   pushes each daemon event ([`LogEventV1`](crate::logging_event::LogEventV1))
+//!   as an `"event"` notification; `{"topic": "stream", "agent": "..."}` pushes
+//!   [`DaemonStreamEvent`](crate::daemon_stream::DaemonStreamEvent)s as
+//!   `"stream-event"` notifications. Notification params are
+//!   `{"subscription": <id>, "event": <item>}`.
+//! - [`RPC_UNSUBSCRIBE_METHOD`] with `{"subscription": <id>}` stops one
+//!   subscription. All subscriptions end when the connection closes.
+//!
+//! Requests on one session are answered in order.
+//!
+//! [`SocketRequest`]: crate::daemon_client::SocketRequest
+//! [`SocketResponse`]: crate::daemon_client::SocketResponse
+
+use crate::daemon_client::SocketResponse;
+use serde::{Deserialize, Serialize};
+
+/// One-shot socket command that upgrades the connection to a JSON-RPC session.
+pub const RPC_SESSION_COMMAND: &str = "rpc-session";
+
+/// Protocol name reported in the handshake payload.
+pub const RPC_PROTOCOL: &str = "jsonrpc-2.0";
+
+/// Method that starts a notification subscription.
+pub const RPC_SUBSCRIBE_METHOD: &str = "rpc.subscribe";
+
+/// Method that stops a notification subscription.
+pub const RPC_UNSUBSCRIBE_METHOD: &str = "rpc.unsubscribe";
+
+/// Subscription topic for daemon-emitted events (agent state changes included).
+pub const RPC_TOPIC_EVENTS: &str = "events";
+
+/// Subscription topic for agent turn stream events.
+pub const RPC_TOPIC_STREAM: &str = "stream";
+
+/// Notification method carrying an item from the `events` topic.
+pub const RPC_EVENT_NOTIFICATION: &str = "event";
+
+/// Notification method carrying an item from the `stream` topic.
+pub const RPC_STREAM_NOTIFICATION: &str = "stream-event";
+
+/// JSON-RPC error: the line was not valid JSON.
+pub const RPC_PARSE_ERROR: i64 = -32700;
+/// JSON-RPC error: the message is not a valid request object.
+pub const RPC_INVALID_REQUEST: i64 = -32600;
+/// JSON-RPC error: unknown method.
+pub const RPC_METHOD_NOT_FOUND: i64 = -32601;
+/// JSON-RPC error: invalid method parameters.
+pub const RPC_INVALID_PARAMS: i64 = -32602;
+/// Daemon-defined error: the command failed; `data.code` holds the socket error code.
+pub const RPC_DAEMON_ERROR: i64 = -32000;
+
+/// A JSON-RPC request, or a notification when `id` is absent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcRequest {
+    /// Always `"2.0"`.
+    pub jsonrpc: String,
+    /// Correlation id echoed in the response; absent for notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<serde_json::Value>,
+    /// Method name (a socket command or an `rpc.*` method).
+    pub method: String,
+    /// Method parameters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Value>,
+}
+
+/// A JSON-RPC response carrying either `result` or `error`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcResponse {
+    /// Always `"2.0"`.
+    pub jsonrpc: String,
+    /// Id of the request being answered; `null` when it could not be read.
+    pub id: serde_json::Value,
+    /// Result on success.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    /// Error on failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+/// JSON-RPC error object.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RpcError {
+    /// Numeric error code (see the `RPC_*` constants).
+    pub code: i64,
+    /// Human-readable error message.
+    pub message: String,
+    /// Extra detail, e.g. `{"code": "AGENT_NOT_FOUND"}` for daemon errors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+/// A server-initiated JSON-RPC notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcNotification {
+    /// Always `"2.0"`.
+    pub jsonrpc: String,
+    /// Notification method ([`RPC_EVENT_NOTIFICATION`] or [`RPC_STREAM_NOTIFICATION`]).
+    pub method: String,
+    /// `{"subscription": <id>, "event": <item>}`.
+    pub params: serde_json::Value,
+}
+
+impl RpcResponse {
+    /// Successful response for `id`.
+    pub fn ok(id: serde_json::Value, result: serde_json::Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    /// Error response for `id`.
+    pub fn err(id: serde_json::Value, code: i64, message: impl Into<String>) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: None,
+            error: Some(RpcError {
+                code,
+                message: message.into(),
+                data: None,
+            }),
+        }
+    }
+
+    /// Translate a one-shot [`SocketResponse`] into a JSON-RPC response.
+    ///
+    /// `UNKNOWN_COMMAND` maps to [`RPC_METHOD_NOT_FOUND`], `INVALID_PAYLOAD`
+    /// to [`RPC_INVALID_PARAMS`], and every other error to [`RPC_DAEMON_ERROR`].
+    /// The socket error code is kept in `error.data.code`.
+    pub fn from_socket_response(id: serde_json::Value, response: SocketResponse) -> Self {
+        if response.is_ok() {
+            return Self::ok(id, response.payload.unwrap_or(serde_json::Value::Null));
+        }
+        let (socket_code, message) = response
+            .error
+            .map(|e| (e.code, e.message))
+            .unwrap_or_else(|| ("INTERNAL_ERROR".to_string(), "unknown daemon error".into()));
+        let code = match socket_code.as_str() {
+            "UNKNOWN_COMMAND" => RPC_METHOD_NOT_FOUND,
+            "INVALID_PAYLOAD" => RPC_INVALID_PARAMS,
+            _ => RPC_DAEMON_ERROR,
+        };
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: None,
+            error: Some(RpcError {
+                code,
+                message,
+                data: Some(serde_json::json!({ "code": socket_code })),
+            }),
+        }
+    }
+}
+
+impl RpcNotification {
+    /// Notification delivering `event` for subscription `subscription`.
+    pub fn new(method: &str, subscription: u64, event: serde_json::Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            method: method.to_string(),
+            params: serde_json::json!({ "subscription": subscription, "event": event }),
+        }
+    }
+
+    /// Subscription id this notification belongs to.
+    pub fn subscription(&self) -> Option<u64> {
+        self.params.get("subscription").and_then(|v| v.as_u64())
+    }
+
+    /// The delivered item.
+    pub fn event(&self) -> Option<&serde_json::Value> {
+        self.params.get("event")
+    }
+}
+
+/// Blocking client for a JSON-RPC session on the daemon socket.
+///
+/// Notifications that arrive while [`call`](Self::call) waits for its
+/// response are queued and returned by
+/// [`next_notification`](Self::next_notification).
+#[cfg(unix)]
+pub struct RpcSession {
+    reader: std::io::BufReader<std::os::unix::net::UnixStream>,
+    writer: std::os::unix::net::UnixStream,
+    next_id: u64,
+    queued: std::collections::VecDeque<RpcNotification>,
+    /// Bytes of a line cut off by a read timeout, completed by the next read.
+    partial: Vec<u8>,
+}
+
+/// Open a JSON-RPC session with the daemon.
+///
+/// Returns:
+/// - `Ok(Some(session))` when the daemon accepted the handshake.
+/// - `Ok(None)` when the daemon is unreachable or does not support session mode.
+#[cfg(unix)]
+pub fn open_rpc_session() -> anyhow::Result<Option<RpcSession>> {
+    use crate::daemon_client::{
+        PROTOCOL_VERSION, SocketRequest, connect_daemon_socket, daemon_disabled, daemon_socket_path,
+    };
+    use std::io::{BufRead, Write};
+
+    if daemon_disabled() {
+        return Ok(None);
+    }
+    let socket_path = daemon_socket_path()?;
+    let mut stream = match connect_daemon_socket(&socket_path) {
+        Ok(s) => s,
+        Err(_) => return Ok(None),
+    };
+    stream.set_read_timeout(Some(std::time::Duration::from_millis(
+        crate::consts::SOCKET_IO_TIMEOUT_MS,
+    )))?;
+
+    let handshake = SocketRequest {
+        version: PROTOCOL_VERSION,
+        request_id: format!("rpc-{}", std::process::id()),
+        command: RPC_SESSION_COMMAND.to_string(),
+        payload: serde_json::json!({ "protocol": RPC_PROTOCOL }),
+    };
+    let mut line = serde_json::to_string(&handshake)?;
+    line.push('\n');
+    stream.write_all(line.as_bytes())?;
+    stream.flush()?;
+
+    let mut reader = std::io::BufReader::new(stream.try_clone()?);
+    let mut ack = String::new();
+    if reader.read_line(&mut ack)? == 0 {
+        return Ok(None);
+    }
+    let accepted = serde_json::from_str::<SocketResponse>(ack.trim()).is_ok_and(|resp| {
+        resp.is_ok()
+            && resp
+                .payload
+                .as_ref()
+                .and_then(|p| p.get("protocol"))
+                .and_then(|p| p.as_str())
+                == Some(RPC_PROTOCOL)
+    });
+    if !accepted {
+        return Ok(None);
+    }
+
+    Ok(Some(RpcSession {
+        reader,
+        writer: stream,
+        next_id: 1,
+        queued: std::collections::VecDeque::new(),
+        partial: Vec::new(),
+    }))
+}
+
+#[cfg(unix)]
+impl RpcSession {
+    /// Call `method` with `params` and wait for its result.
+    ///
+    /// Returns `Err` on I/O failure, timeout, or a JSON-RPC error response.
+    pub fn call(
+        &mut self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> anyhow::Result<serde_json::Value> {
+        use std::io::Write;
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let request = RpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(id)),
+            method: method.to_string(),
+            params: Some(params),
+        };
+        let mut line = serde_json::to_string(&request)?;
+        line.push('\n');
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.flush()?;
+
+        loop {
+            let value = self
+                .read_message()?
+                .ok_or_else(|| anyhow::anyhow!("daemon closed the RPC session"))?;
+            if value.get("id").is_none() {
+                self.queued.push_back(serde_json::from_value(value)?);
+                continue;
+            }
+            let response: RpcResponse = serde_json::from_value(value)?;
+            if response.id != serde_json::json!(id) {
+                continue;
+            }
+            if let Some(error) = response.error {
+                anyhow::bail!("{method} failed ({}): {}", error.code, error.message);
+            }
+            return Ok(response.result.unwrap_or(serde_json::Value::Null));
+        }
+    }
+
+    /// Subscribe to `topic`; `filter` adds `actions` (events) or `agent` (stream).
+    ///
+    /// Returns the subscription id carried by each notification.
+    pub fn subscribe(&mut self, topic: &str, filter: serde_json::Value) -> anyhow::Result<u64> {
+        let mut params = match filter {
+            serde_json::Value::Object(map) => map,
+            _ => serde_json::Map::new(),
+        };
+        params.insert("topic".to_string(), serde_json::json!(topic));
+        let result = self.call(RPC_SUBSCRIBE_METHOD, serde_json::Value::Object(params))?;
+        result
+            .get("subscription")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| anyhow::anyhow!("daemon returned no subscription id"))
+    }
+
+    /// Stop a subscription. Returns `false` when the id was not active.
+    pub fn unsubscribe(&mut self, subscription: u64) -> anyhow::Result<bool> {
+        let result = self.call(
+            RPC_UNSUBSCRIBE_METHOD,
+            serde_json::json!({ "subscription": subscription }),
+        )?;
+        Ok(result
+            .get("unsubscribed")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false))
+    }
+
+    /// Wait up to `timeout` for the next notification.
+    ///
+    /// Returns `Ok(None)` when none arrived in time.
+    pub fn next_notification(
+        &mut self,
+        timeout: std::time::Duration,
+    ) -> anyhow::Result<Option<RpcNotification>> {
+        if let Some(queued) = self.queued.pop_front() {
+            return Ok(Some(queued));
+        }
+        let deadline = std::time::Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            self.reader.get_ref().set_read_timeout(Some(remaining))?;
+            let message = self.read_message();
+            self.reader
+                .get_ref()
+                .set_read_timeout(Some(std::time::Duration::from_millis(
+                    crate::consts::SOCKET_IO_TIMEOUT_MS,
+                )))?;
+            match message {
+                Ok(Some(value)) if value.get("id").is_none() => {
+                    return Ok(Some(serde_json::from_value(value)?));
+                }
+                // Responses to abandoned calls are dropped.
+                Ok(Some(_)) => continue,
+                Ok(None) => anyhow::bail!("daemon closed the RPC session"),
+                Err(e) if is_timeout(&e) => return Ok(None),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn read_message(&mut self) -> anyhow::Result<Option<serde_json::Value>> {
+        use std::io::BufRead;
+
+        // `read_until` keeps bytes read before a timeout in `partial`, so a
+        // retry resumes the same line instead of losing its start.
+        if self.reader.read_until(b'\n', &mut self.partial)? == 0 && self.partial.is_empty() {
+            return Ok(None);
+        }
+        let line = std::mem::take(&mut self.partial);
+        Ok(Some(serde_json::from_slice(line.trim_ascii())?))
+    }
+}
+
+#[cfg(unix)]
+fn is_timeout(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<std::io::Error>().is_some_and(|e| {
+        matches!(
+            e.kind(),
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon_client::{PROTOCOL_VERSION, SocketError};
+
+    fn socket_response(status: &str, error_code: Option<&str>) -> SocketResponse {
+        SocketResponse {
+            version: PROTOCOL_VERSION,
+            request_id: "r1".to_string(),
+            status: status.to_string(),
+            payload: (status == "ok").then(|| serde_json::json!({ "pid": 42 })),
+            error: error_code.map(|code| SocketError {
+                code: code.to_string(),
+                message: "boom".to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_from_socket_response_ok_carries_payload() {
+        let resp =
+            RpcResponse::from_socket_response(serde_json::json!(7), socket_response("ok", None));
+        assert_eq!(resp.id, serde_json::json!(7));
+        assert_eq!(resp.result, Some(serde_json::json!({ "pid": 42 })));
+        assert!(resp.error.is_none());
+    }
+
+    #[test]
+    fn test_from_socket_response_maps_error_codes() {
+        for (socket_code, rpc_code) in [
+            ("UNKNOWN_COMMAND", RPC_METHOD_NOT_FOUND),
+            ("INVALID_PAYLOAD", RPC_INVALID_PARAMS),
+            ("AGENT_NOT_FOUND", RPC_DAEMON_ERROR),
+        ] {
+            let resp = RpcResponse::from_socket_response(
+                serde_json::json!(1),
+                socket_response("error", Some(socket_code)),
+            );
+            let error = resp.error.expect("error response");
+            assert_eq!(error.code, rpc_code);
+            assert_eq!(error.message, "boom");
+            assert_eq!(error.data, Some(serde_json::json!({ "code": socket_code })));
+        }
+    }
+
+    #[test]
+    fn test_request_without_id_is_notification() {
+        let req: RpcRequest = serde_json::from_str(r#"{"jsonrpc":"2.0","method":"ping"}"#).unwrap();
+        assert!(req.id.is_none());
+        assert!(req.params.is_none());
+    }
+
+    #[test]
+    fn test_notification_accessors() {
+        let note = RpcNotification::new(RPC_EVENT_NOTIFICATION, 3, serde_json::json!({"a": 1}));
+        assert_eq!(note.subscription(), Some(3));
+        assert_eq!(note.event(), Some(&serde_json::json!({"a": 1})));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_next_notification_resumes_line_cut_by_timeout() {
+        use std::io::Write;
+        use std::os::unix::net::UnixStream;
+        use std::time::Duration;
+
+        let (client, mut daemon) = UnixStream::pair().unwrap();
+        let mut session = RpcSession {
+            reader: std::io::BufReader::new(client.try_clone().unwrap()),
+            writer: client,
+            next_id: 1,
+            queued: std::collections::VecDeque::new(),
+            partial: Vec::new(),
+        };
+        let line = serde_json::to_string(&RpcNotification::new(
+            RPC_EVENT_NOTIFICATION,
+            3,
+            serde_json::json!({"a": 1}),
+        ))
+        .unwrap();
+        let (head, tail) = line.split_at(line.len() / 2);
+
+        daemon.write_all(head.as_bytes()).unwrap();
+        let timeout = Duration::from_millis(50);
+        assert!(session.next_notification(timeout).unwrap().is_none());
+
+        daemon.write_all(format!("{tail}\n").as_bytes()).unwrap();
+        let note = session.next_notification(timeout).unwrap().unwrap();
+        assert_eq!(note.subscription(), Some(3));
+    }
+}
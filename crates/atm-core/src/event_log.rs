@@ -94,6 +94,51 @@ fn forward_to_unified(event: crate::logging_event::LogEventV1) {
     }
 }
 
+/// Env var naming a JSONL file that receives every event emitted by
+/// [`emit_event_best_effort`] in place of the unified log pipeline.
+///
+/// Intended for test isolation: point it at a temp file, run the code under
+/// test, then read the events back with [`read_event_log`].
+pub const EVENT_LOG_ENV: &str = "ATM_EVENT_LOG";
+
+/// Path from [`EVENT_LOG_ENV`], if set and non-empty.
+pub fn event_log_override_path() -> Option<std::path::PathBuf> {
+    env_nonempty(EVENT_LOG_ENV).map(std::path::PathBuf::from)
+}
+
+/// Read the events in a JSONL event log such as the [`EVENT_LOG_ENV`] file.
+///
+/// A missing file yields no events; unparseable lines are skipped.
+pub fn read_event_log(path: &std::path::Path) -> Vec<LogEventV1> {
+    std::fs::read_to_string(path)
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Append `event` as one JSONL line to `path`; failures are ignored.
+fn append_to_event_log(event: &LogEventV1, path: &std::path::Path) {
+    use std::io::Write;
+    let Ok(mut line) = serde_json::to_string(event) else {
+        return;
+    };
+    line.push('\n');
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if let Ok(mut file) = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+    {
+        let _ = file.write_all(line.as_bytes());
+    }
+}
+
 fn logging_enabled() -> bool {
     !matches!(
         std::env::var("ATM_LOG")
@@ -328,7 +373,8 @@ pub fn emit_event_to_spool_direct(fields: &EventFields, home_dir: &std::path::Pa
 ///    `ATM_HOME` is unresolvable) is the event silently dropped.
 ///
 /// The legacy `events.jsonl` dual-write path was removed in Phase M.1b.
-/// Events flow exclusively through the unified producer channel.
+/// Events flow exclusively through the unified producer channel, unless
+/// [`EVENT_LOG_ENV`] redirects them to a single file.
 pub fn emit_event_best_effort(mut fields: EventFields) {
     if !logging_enabled() {
         return;
@@ -356,6 +402,10 @@ pub fn emit_event_best_effort(mut fields: EventFields) {
 
     let event = fields_to_log_event(&fields);
     notify_event_observer(&event);
+    if let Some(path) = event_log_override_path() {
+        append_to_event_log(&event, &path);
+        return;
+    }
     forward_to_unified(event);
 }
 
@@ -636,6 +686,37 @@ mod tests {
         unsafe { std::env::remove_var("ATM_HOME") };
     }
 
+    #[test]
+    #[serial]
+    fn test_emit_event_best_effort_honors_event_log_override() {
+        let temp = TempDir::new().unwrap();
+        let log_path = temp.path().join("logs/events.jsonl");
+        // SAFETY: test-scoped env mutation guarded by serial execution.
+        unsafe {
+            std::env::set_var("ATM_HOME", temp.path());
+            std::env::set_var(EVENT_LOG_ENV, &log_path);
+        }
+        emit_event_best_effort(EventFields {
+            level: "info",
+            source: "atm",
+            action: "test_event_log_override",
+            team: Some("atm-dev".to_string()),
+            ..Default::default()
+        });
+        // SAFETY: test-scoped env cleanup guarded by serial execution.
+        unsafe {
+            std::env::remove_var(EVENT_LOG_ENV);
+            std::env::remove_var("ATM_HOME");
+        }
+
+        let events = read_event_log(&log_path);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, "test_event_log_override");
+        assert_eq!(events[0].team.as_deref(), Some("atm-dev"));
+        let spool = crate::logging_event::spool_dir_for_tool(temp.path(), "atm");
+        assert!(read_jsonl_events(&spool).is_empty());
+    }
+
     /// Verify that `emit_event_best_effort` drops events with empty required fields.
     #[test]
     fn test_emit_event_best_effort_drops_empty_required_fields() {
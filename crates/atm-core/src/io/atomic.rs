@@ -1,7 +1,9 @@
 //! Platform-specific atomic swap operations
 
 use crate::io::error::InboxError;
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::warn;
 
 #[cfg(target_os = "macos")]
 use std::ffi::CString;
@@ -20,11 +22,132 @@ use std::ffi::CString;
 /// - **Linux**: Uses `renameat2(AT_FDCWD, from, AT_FDCWD, to, RENAME_EXCHANGE)` (kernel 3.15+)
 /// - **Windows**: Best-effort implementation using temporary file
 ///
+/// When the two paths live on different filesystems (`EXDEV`, e.g. a bind
+/// mount inside a container) the swap degrades to [`copy_swap`] unless
+/// `ATM_CROSS_DEVICE_FALLBACK` disables it.
+///
 /// # Errors
 ///
 /// Returns `InboxError::AtomicSwapUnsupported` if the platform doesn't support atomic swap,
 /// or `InboxError::Io` if the underlying syscall fails.
 pub fn atomic_swap(path1: &Path, path2: &Path) -> Result<(), InboxError> {
+    match platform_atomic_swap(path1, path2) {
+        Err(InboxError::Io { source, .. })
+            if is_cross_device(&source) && cross_device_fallback_enabled() =>
+        {
+            warn!(
+                "atomic swap of {} and {} crossed filesystems; using copy fallback",
+                path1.display(),
+                path2.display()
+            );
+            copy_swap(path1, path2)
+        }
+        result => result,
+    }
+}
+
+/// Rename `from` to `to`, degrading to a copy when they are on different
+/// filesystems.
+///
+/// The fallback copies `from` next to `to`, fsyncs it, and renames it into
+/// place, so readers of `to` still never see a partial file. `from` is
+/// removed afterwards.
+///
+/// # Errors
+///
+/// Returns `InboxError::Io` if the rename (or the fallback copy) fails.
+pub fn rename_or_copy(from: &Path, to: &Path) -> Result<(), InboxError> {
+    match fs::rename(from, to) {
+        Ok(()) => Ok(()),
+        Err(e) if is_cross_device(&e) && cross_device_fallback_enabled() => {
+            warn!(
+                "rename of {} to {} crossed filesystems; using copy fallback",
+                from.display(),
+                to.display()
+            );
+            copy_into_place(from, to)?;
+            let _ = fs::remove_file(from);
+            Ok(())
+        }
+        Err(e) => Err(InboxError::Io {
+            path: to.to_path_buf(),
+            source: e,
+        }),
+    }
+}
+
+/// Whether cross-device rename/swap fallback is enabled.
+///
+/// On by default; `ATM_CROSS_DEVICE_FALLBACK=0` (or `false`/`no`) restores
+/// the strict behavior of failing with `EXDEV`.
+pub fn cross_device_fallback_enabled() -> bool {
+    !std::env::var("ATM_CROSS_DEVICE_FALLBACK").is_ok_and(|raw| {
+        matches!(
+            raw.trim().to_ascii_lowercase().as_str(),
+            "0" | "false" | "no"
+        )
+    })
+}
+
+fn is_cross_device(err: &std::io::Error) -> bool {
+    #[cfg(unix)]
+    {
+        err.raw_os_error() == Some(libc::EXDEV)
+    }
+
+    #[cfg(not(unix))]
+    {
+        // ERROR_NOT_SAME_DEVICE
+        err.raw_os_error() == Some(17)
+    }
+}
+
+/// Exchange the contents of two files without a cross-filesystem rename.
+///
+/// Each side is staged on its own filesystem, fsynced, then renamed over its
+/// target. Each replacement is atomic, but the pair is not: callers must hold
+/// the inbox lock.
+fn copy_swap(path1: &Path, path2: &Path) -> Result<(), InboxError> {
+    let staged1 = staging_path(path1);
+    let staged2 = staging_path(path2);
+    copy_synced(path2, &staged1)?;
+    copy_synced(path1, &staged2)?;
+    rename_in_place(&staged1, path1)?;
+    rename_in_place(&staged2, path2)
+}
+
+fn copy_into_place(from: &Path, to: &Path) -> Result<(), InboxError> {
+    let staged = staging_path(to);
+    copy_synced(from, &staged)?;
+    rename_in_place(&staged, to)
+}
+
+fn staging_path(target: &Path) -> PathBuf {
+    let mut name = target.file_name().unwrap_or_default().to_os_string();
+    name.push(".xdev");
+    target.with_file_name(name)
+}
+
+fn copy_synced(from: &Path, to: &Path) -> Result<(), InboxError> {
+    fs::copy(from, to)
+        .and_then(|_| fs::File::open(to)?.sync_all())
+        .map_err(|e| InboxError::Io {
+            path: to.to_path_buf(),
+            source: e,
+        })
+}
+
+fn rename_in_place(staged: &Path, target: &Path) -> Result<(), InboxError> {
+    fs::rename(staged, target).map_err(|e| {
+        let _ = fs::remove_file(staged);
+        InboxError::Io {
+            path: target.to_path_buf(),
+            source: e,
+        }
+    })
+}
+
+fn platform_atomic_swap(path1: &Path, path2: &Path) -> Result<(), InboxError> {
     #[cfg(target_os = "macos")]
     {
         macos_atomic_swap(path1, path2)
@@ -221,6 +344,46 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_copy_swap_exchanges_contents_without_leftovers() {
+        let temp_dir = TempDir::new().unwrap();
+        let inbox = temp_dir.path().join("inbox.json");
+        let tmp = temp_dir.path().join("inbox.tmp");
+        fs::write(&inbox, b"old").unwrap();
+        fs::write(&tmp, b"new").unwrap();
+
+        copy_swap(&inbox, &tmp).unwrap();
+
+        assert_eq!(fs::read(&inbox).unwrap(), b"new");
+        assert_eq!(fs::read(&tmp).unwrap(), b"old");
+        assert!(!staging_path(&inbox).exists());
+        assert!(!staging_path(&tmp).exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_is_cross_device_matches_exdev_only() {
+        assert!(is_cross_device(&std::io::Error::from_raw_os_error(
+            libc::EXDEV
+        )));
+        assert!(!is_cross_device(&std::io::Error::from_raw_os_error(
+            libc::ENOENT
+        )));
+    }
+
+    #[test]
+    fn test_rename_or_copy_same_filesystem() {
+        let temp_dir = TempDir::new().unwrap();
+        let from = temp_dir.path().join("inbox.tmp");
+        let to = temp_dir.path().join("inbox.json");
+        fs::write(&from, b"payload").unwrap();
+
+        rename_or_copy(&from, &to).unwrap();
+
+        assert_eq!(fs::read(&to).unwrap(), b"payload");
+        assert!(!from.exists());
+    }
+
     #[test]
     fn test_atomic_swap_empty_files() {
         let temp_dir = TempDir::new().unwrap();
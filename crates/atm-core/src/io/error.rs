@@ -36,6 +36,10 @@ pub enum InboxError {
     #[error("Conflict detected but merge failed: {message}")]
     MergeFailed { message: String },
 
+    /// Concurrent writers kept replacing the inbox faster than merges converged
+    #[error("Inbox {path} did not converge after {attempts} conflict merge attempts")]
+    ConflictRetriesExhausted { path: PathBuf, attempts: u32 },
+
     /// Spool directory error
     #[error("Spool directory error: {message}")]
     SpoolError { message: String },
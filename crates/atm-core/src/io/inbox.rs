@@ -1,11 +1,35 @@
 //! Inbox file operations with atomic writes and conflict detection
 
 use crate::event_log::{EventFields, emit_event_best_effort};
-use crate::io::{atomic::atomic_swap, error::InboxError, hash::compute_hash, lock::acquire_lock};
+use crate::io::provenance::{self, InboxReadMeta, message_key};
+use crate::io::{
+    atomic::{atomic_swap, rename_or_copy},
+    error::InboxError,
+    hash::compute_hash,
+    lock::acquire_lock,
+};
 use crate::schema::InboxMessage;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU8, AtomicU32, Ordering};
+
+/// Sentinel stored in [`INBOX_FILE_MODE`] while no mode is configured.
+const FILE_MODE_UNSET: u32 = u32::MAX;
+
+/// Process-wide inbox file mode installed by config resolution.
+static INBOX_FILE_MODE: AtomicU32 = AtomicU32::new(FILE_MODE_UNSET);
+
+/// Sentinel stored in [`INBOX_PROVENANCE`] while no setting is installed.
+const PROVENANCE_UNSET: u8 = u8::MAX;
+
+/// Process-wide provenance sidecar switch (`0` off, `1` on).
+static INBOX_PROVENANCE: AtomicU8 = AtomicU8::new(PROVENANCE_UNSET);
+
+/// Default number of merge-and-reswap passes `inbox_append` performs when a
+/// concurrent writer keeps replacing the inbox underneath it.
+pub const DEFAULT_MAX_CONFLICT_RETRIES: u32 = 3;
 
 /// Outcome of an inbox write operation
 #[derive(Debug, Clone, PartialEq)]
@@ -47,9 +71,37 @@ pub fn inbox_append(
     message: &InboxMessage,
     team: &str,
     agent: &str,
+) -> Result<WriteOutcome, InboxError> {
+    inbox_append_bounded(
+        inbox_path,
+        message,
+        team,
+        agent,
+        DEFAULT_MAX_CONFLICT_RETRIES,
+    )
+}
+
+/// Atomically append a message with an explicit conflict-retry bound
+///
+/// Behaves like [`inbox_append`], but performs at most `max_conflict_retries`
+/// checked merge-and-reswap passes when concurrent writers keep replacing the
+/// inbox. When the bound is reached, the concurrent writer's content is
+/// swapped back into place and the message is not delivered. A bound of `0`
+/// treats any detected conflict as a failure.
+///
+/// # Errors
+///
+/// Returns `InboxError::ConflictRetriesExhausted` when the write does not
+/// converge within the bound, in addition to the errors of [`inbox_append`].
+pub fn inbox_append_bounded(
+    inbox_path: &Path,
+    message: &InboxMessage,
+    team: &str,
+    agent: &str,
+    max_conflict_retries: u32,
 ) -> Result<WriteOutcome, InboxError> {
     let msg_clone = message.clone();
-    match atomic_write_with_conflict_check(inbox_path, |messages| {
+    match atomic_write_with_conflict_check(inbox_path, max_conflict_retries, |messages| {
         // Deduplication check
         if let Some(ref msg_id) = msg_clone.message_id
             && messages
@@ -71,6 +123,64 @@ pub fn inbox_append(
     }
 }
 
+/// Atomically append several messages to one inbox under a single lock
+///
+/// Reads the inbox once, appends every message not already present (in the
+/// file or earlier in `messages`), and writes once,
+/// with the same hash-checked swap and conflict merge as [`inbox_append`].
+/// When nothing new remains after deduplication the file is not rewritten.
+///
+/// # Returns
+///
+/// * `Success` - Messages written cleanly (or all were duplicates)
+/// * `ConflictResolved` - Concurrent writes detected; `merged_messages`
+///   counts every message merged back in from them
+///
+/// # Errors
+///
+/// Unlike [`inbox_append`], a lock timeout is returned as
+/// `InboxError::LockTimeout` rather than spooled, so callers can fall back
+/// to per-message delivery. Other errors match [`inbox_append`].
+pub fn inbox_append_many(
+    inbox_path: &Path,
+    messages: &[InboxMessage],
+) -> Result<WriteOutcome, InboxError> {
+    if messages.is_empty() {
+        return Ok(WriteOutcome::Success);
+    }
+    atomic_write_with_conflict_check(inbox_path, DEFAULT_MAX_CONFLICT_RETRIES, |existing| {
+        append_new_messages(existing, messages)
+    })
+}
+
+/// Append each of `messages` not already in `existing`, returning whether
+/// any were added
+///
+/// Messages are matched by `message_id`, or by sender, text and timestamp
+/// when they have none, as in [`merge_messages`].
+fn append_new_messages(existing: &mut Vec<InboxMessage>, messages: &[InboxMessage]) -> bool {
+    let mut seen_ids: HashSet<String> = existing
+        .iter()
+        .filter_map(|m| m.message_id.clone())
+        .collect();
+    let before = existing.len();
+    for message in messages {
+        let already_present = match message.message_id {
+            Some(ref msg_id) => !seen_ids.insert(msg_id.clone()),
+            None => existing.iter().any(|m| {
+                m.message_id.is_none()
+                    && m.from == message.from
+                    && m.text == message.text
+                    && m.timestamp == message.timestamp
+            }),
+        };
+        if !already_present {
+            existing.push(message.clone());
+        }
+    }
+    existing.len() > before
+}
+
 /// Atomically update messages in an inbox using a closure
 ///
 /// Acquires the inbox lock, reads current messages, applies the update
@@ -96,13 +206,34 @@ pub fn inbox_update<F>(
 where
     F: FnOnce(&mut Vec<InboxMessage>),
 {
-    atomic_write_with_conflict_check(inbox_path, |messages| {
+    atomic_write_with_conflict_check(inbox_path, DEFAULT_MAX_CONFLICT_RETRIES, |messages| {
         update_fn(messages);
         true
     })?;
     Ok(())
 }
 
+/// Atomically update messages in an inbox, writing only when asked to
+///
+/// Like [`inbox_update`], but `update_fn` returns whether it changed
+/// anything; when it returns `false` the inbox file is left as it is.
+///
+/// # Errors
+///
+/// Returns the same errors as [`inbox_update`].
+pub fn inbox_update_if<F>(
+    inbox_path: &Path,
+    _team: &str,
+    _agent: &str,
+    update_fn: F,
+) -> Result<(), InboxError>
+where
+    F: FnOnce(&mut Vec<InboxMessage>) -> bool,
+{
+    atomic_write_with_conflict_check(inbox_path, DEFAULT_MAX_CONFLICT_RETRIES, update_fn)?;
+    Ok(())
+}
+
 /// Shared atomic write logic for inbox operations
 ///
 /// Acquires lock, reads current file, applies modification via closure,
@@ -111,8 +242,15 @@ where
 /// The `modify_fn` closure receives the current messages and returns `true`
 /// if modifications were made (triggering a write), or `false` to skip
 /// the write (e.g., duplicate detection).
+///
+/// Each swap is checked against the content we expected to displace; on a
+/// mismatch the displaced messages are merged and swapped back in, at most
+/// `max_conflict_retries` times. Once the retries run out, the displaced
+/// content is swapped back so the concurrent write is kept and ours is
+/// reported as failed rather than silently dropping either.
 fn atomic_write_with_conflict_check<F>(
     inbox_path: &Path,
+    max_conflict_retries: u32,
     modify_fn: F,
 ) -> Result<WriteOutcome, InboxError>
 where
@@ -143,10 +281,17 @@ where
     };
 
     // Step 3: Apply modification
+    let original_keys: HashSet<String> = messages.iter().map(message_key).collect();
     if !modify_fn(&mut messages) {
         // No changes needed (e.g., duplicate message)
         return Ok(WriteOutcome::Success);
     }
+    let written_keys: HashSet<String> = messages
+        .iter()
+        .map(message_key)
+        .filter(|key| !original_keys.contains(key))
+        .collect();
+    let mut merged_keys: HashSet<String> = HashSet::new();
 
     // Step 4: Write to tmp file with fsync
     let new_content = serde_json::to_vec_pretty(&messages).map_err(|e| InboxError::Json {
@@ -159,23 +304,39 @@ where
     // Step 5: Atomic swap
     if !inbox_path.exists() {
         // First time creating inbox - just rename
-        fs::rename(&tmp_path, inbox_path).map_err(|e| InboxError::Io {
-            path: inbox_path.to_path_buf(),
-            source: e,
-        })?;
+        rename_or_copy(&tmp_path, inbox_path)?;
+        if inbox_provenance_enabled() {
+            provenance::record_write(inbox_path, &messages, &written_keys, &merged_keys);
+        }
         return Ok(WriteOutcome::Success);
     }
 
     atomic_swap(inbox_path, &tmp_path)?;
 
-    // Step 6: Check for concurrent writes
-    let displaced_content = fs::read(&tmp_path).map_err(|e| InboxError::Io {
-        path: tmp_path.clone(),
-        source: e,
-    })?;
-    let displaced_hash = compute_hash(&displaced_content);
+    // Step 6: Check for concurrent writes. `expected_hash` is the content we
+    // believe we displaced; anything else was written by a non-locking writer.
+    let mut expected_hash = original_hash;
+    let mut placed_content = new_content;
+    let mut merged_total = 0usize;
+    let mut attempts = 0u32;
+    loop {
+        let displaced_content = fs::read(&tmp_path).map_err(|e| InboxError::Io {
+            path: tmp_path.clone(),
+            source: e,
+        })?;
+        if compute_hash(&displaced_content) == expected_hash {
+            break;
+        }
+        if attempts >= max_conflict_retries {
+            restore_displaced(inbox_path, &tmp_path, &placed_content)?;
+            let _ = fs::remove_file(&tmp_path);
+            return Err(InboxError::ConflictRetriesExhausted {
+                path: inbox_path.to_path_buf(),
+                attempts,
+            });
+        }
+        attempts += 1;
 
-    let outcome = if displaced_hash != original_hash {
         // Step 7: Conflict detected - merge and re-swap
         let displaced_messages: Vec<InboxMessage> = serde_json::from_slice(&displaced_content)
             .map_err(|e| InboxError::Json {
@@ -185,24 +346,41 @@ where
 
         // Merge: add messages from displaced that aren't in our version
         let merged = merge_messages(&messages, &displaced_messages);
-        let merge_count = merged.len() - messages.len();
+        merged_total += merged.len() - messages.len();
+        let current_keys: HashSet<String> = messages.iter().map(message_key).collect();
+        merged_keys.extend(
+            merged
+                .iter()
+                .map(message_key)
+                .filter(|key| !current_keys.contains(key)),
+        );
+        messages = merged;
 
         // Write merged version back
-        let merged_content = serde_json::to_vec_pretty(&merged).map_err(|e| InboxError::Json {
-            path: tmp_path.clone(),
-            source: e,
-        })?;
+        let merged_content =
+            serde_json::to_vec_pretty(&messages).map_err(|e| InboxError::Json {
+                path: tmp_path.clone(),
+                source: e,
+            })?;
 
         write_synced_file(&tmp_path, &merged_content)?;
 
-        // Re-swap
+        // Re-swap; the next pass must displace exactly what we just placed.
         atomic_swap(inbox_path, &tmp_path)?;
+        expected_hash = compute_hash(&placed_content);
+        placed_content = merged_content;
+    }
 
+    if inbox_provenance_enabled() {
+        provenance::record_write(inbox_path, &messages, &written_keys, &merged_keys);
+    }
+
+    let outcome = if attempts == 0 {
+        WriteOutcome::Success
+    } else {
         WriteOutcome::ConflictResolved {
-            merged_messages: merge_count,
+            merged_messages: merged_total,
         }
-    } else {
-        WriteOutcome::Success
     };
 
     // Step 8: Lock released automatically on drop
@@ -212,25 +390,137 @@ where
     Ok(outcome)
 }
 
-fn write_synced_file(path: &Path, content: &[u8]) -> Result<(), InboxError> {
-    let mut file = fs::File::create(path).map_err(|e| InboxError::Io {
-        path: path.to_path_buf(),
+/// Swap the concurrently written content held in `tmp_path` back into place.
+///
+/// The swap normally takes back `placed_content`, our own write. If the inbox
+/// was replaced yet again in the meantime, that newer foreign write is what
+/// came out, so it is swapped back in as well; the newest foreign content
+/// always ends up in place.
+fn restore_displaced(
+    inbox_path: &Path,
+    tmp_path: &Path,
+    placed_content: &[u8],
+) -> Result<(), InboxError> {
+    atomic_swap(inbox_path, tmp_path)?;
+    let taken = fs::read(tmp_path).map_err(|e| InboxError::Io {
+        path: tmp_path.to_path_buf(),
         source: e,
     })?;
+    if compute_hash(&taken) != compute_hash(placed_content) {
+        atomic_swap(inbox_path, tmp_path)?;
+    }
+    Ok(())
+}
 
-    file.write_all(content).map_err(|e| InboxError::Io {
-        path: path.to_path_buf(),
-        source: e,
-    })?;
+/// Install the permission bits applied to inbox files on write (Unix only).
+///
+/// `None` restores the default of leaving permissions to the process umask.
+/// Called by config resolution with `[core] inbox_file_mode`.
+pub fn set_inbox_file_mode(mode: Option<u32>) {
+    INBOX_FILE_MODE.store(mode.unwrap_or(FILE_MODE_UNSET), Ordering::Relaxed);
+}
 
-    file.sync_all().map_err(|e| InboxError::Io {
+/// Permission bits applied to inbox files on write, if any.
+///
+/// Uses the value installed by [`set_inbox_file_mode`], falling back to
+/// `ATM_INBOX_FILE_MODE` for processes that never resolve config.
+pub fn inbox_file_mode() -> Option<u32> {
+    match INBOX_FILE_MODE.load(Ordering::Relaxed) {
+        FILE_MODE_UNSET => std::env::var("ATM_INBOX_FILE_MODE")
+            .ok()
+            .and_then(|raw| parse_file_mode(&raw)),
+        mode => Some(mode),
+    }
+}
+
+/// Install whether locked inbox writes maintain the provenance sidecar.
+///
+/// `None` restores the default (off unless `ATM_INBOX_PROVENANCE` is set).
+/// Called at startup with `[core] inbox_provenance`.
+pub fn set_inbox_provenance(enabled: Option<bool>) {
+    INBOX_PROVENANCE.store(
+        enabled.map_or(PROVENANCE_UNSET, u8::from),
+        Ordering::Relaxed,
+    );
+}
+
+/// Whether locked inbox writes record provenance in `<agent>.provenance`.
+///
+/// Uses the value installed by [`set_inbox_provenance`], falling back to
+/// `ATM_INBOX_PROVENANCE` for processes that never resolve config.
+pub fn inbox_provenance_enabled() -> bool {
+    match INBOX_PROVENANCE.load(Ordering::Relaxed) {
+        PROVENANCE_UNSET => std::env::var("ATM_INBOX_PROVENANCE")
+            .is_ok_and(|raw| matches!(raw.to_ascii_lowercase().as_str(), "1" | "true" | "yes")),
+        enabled => enabled == 1,
+    }
+}
+
+/// Parse an octal permission string such as `"0600"`, `"600"`, or `"0o600"`.
+///
+/// Returns `None` for anything that is not an octal mode of at most `0o7777`.
+pub fn parse_file_mode(raw: &str) -> Option<u32> {
+    let raw = raw.trim();
+    let digits = raw.strip_prefix("0o").unwrap_or(raw);
+    if digits.is_empty() {
+        return None;
+    }
+    u32::from_str_radix(digits, 8)
+        .ok()
+        .filter(|mode| *mode <= 0o7777)
+}
+
+fn write_synced_file(path: &Path, content: &[u8]) -> Result<(), InboxError> {
+    write_inbox_file(path, content).map_err(|e| InboxError::Io {
         path: path.to_path_buf(),
         source: e,
-    })?;
+    })
+}
+
+/// Write `content` to `path` and fsync it, applying [`inbox_file_mode`].
+///
+/// Every writer of inbox and spool files goes through this (or
+/// [`create_inbox_file`]) so the configured mode is honored everywhere.
+pub fn write_inbox_file(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let mut file = create_inbox_file(path)?;
+    file.write_all(content)?;
+    file.sync_all()
+}
 
+/// Apply [`inbox_file_mode`] to a file some other code created (e.g. a
+/// download). A no-op when no mode is configured or off Unix.
+pub fn apply_inbox_file_mode(path: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    if let Some(mode) = inbox_file_mode() {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(mode))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
     Ok(())
 }
 
+/// Create (or truncate) `path`, applying [`inbox_file_mode`] on Unix.
+///
+/// The mode is also set explicitly so it is not narrowed by the umask and
+/// replaces the bits of a stale tmp file; it then travels with the file
+/// through the rename or swap into place.
+pub fn create_inbox_file(path: &Path) -> std::io::Result<fs::File> {
+    #[cfg(unix)]
+    if let Some(mode) = inbox_file_mode() {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(mode)
+            .open(path)?;
+        file.set_permissions(fs::Permissions::from_mode(mode))?;
+        return Ok(file);
+    }
+    fs::File::create(path)
+}
+
 fn parse_inbox_messages_tolerant(
     content: &[u8],
     inbox_path: &Path,
@@ -245,29 +535,33 @@ fn parse_inbox_messages_tolerant(
     for (index, raw_message) in raw_messages.into_iter().enumerate() {
         match serde_json::from_value::<InboxMessage>(raw_message) {
             Ok(message) => messages.push(message),
-            Err(error) => {
-                let mut extra_fields = serde_json::Map::new();
-                extra_fields.insert(
-                    "path".to_string(),
-                    serde_json::Value::String(inbox_path.display().to_string()),
-                );
-                extra_fields.insert("record_index".to_string(), serde_json::json!(index));
-                emit_event_best_effort(EventFields {
-                    level: "warn",
-                    source: "atm-core",
-                    action: "inbox_record_skipped",
-                    result: Some("skipped".to_string()),
-                    error: Some(error.to_string()),
-                    extra_fields,
-                    ..Default::default()
-                });
-            }
+            Err(error) => emit_record_skipped(inbox_path, index, &error),
         }
     }
 
     Ok(messages)
 }
 
+/// Emit the `inbox_record_skipped` event for a record that is valid JSON but
+/// not a valid [`InboxMessage`].
+pub(crate) fn emit_record_skipped(inbox_path: &Path, index: usize, error: &dyn std::fmt::Display) {
+    let mut extra_fields = serde_json::Map::new();
+    extra_fields.insert(
+        "path".to_string(),
+        serde_json::Value::String(inbox_path.display().to_string()),
+    );
+    extra_fields.insert("record_index".to_string(), serde_json::json!(index));
+    emit_event_best_effort(EventFields {
+        level: "warn",
+        source: "atm-core",
+        action: "inbox_record_skipped",
+        result: Some("skipped".to_string()),
+        error: Some(error.to_string()),
+        extra_fields,
+        ..Default::default()
+    });
+}
+
 pub fn inbox_read_file_tolerant(inbox_path: &Path) -> Result<Vec<InboxMessage>, InboxError> {
     let content = fs::read(inbox_path).map_err(|e| InboxError::Io {
         path: inbox_path.to_path_buf(),
@@ -276,6 +570,23 @@ pub fn inbox_read_file_tolerant(inbox_path: &Path) -> Result<Vec<InboxMessage>,
     parse_inbox_messages_tolerant(&content, inbox_path)
 }
 
+/// Read an inbox file along with per-message write provenance
+///
+/// Like [`inbox_read_file_tolerant`], but pairs each message with the
+/// generation of the locked write that placed it and whether it was recovered
+/// through a conflict merge. Messages written outside the locked write path
+/// (e.g. by Claude Code directly), or while `[core] inbox_provenance` is off,
+/// carry no provenance.
+///
+/// # Errors
+///
+/// Returns `InboxError::Io` if the inbox cannot be read or `InboxError::Json`
+/// if it is not a JSON array.
+pub fn inbox_read_with_meta(inbox_path: &Path) -> Result<InboxReadMeta, InboxError> {
+    let messages = inbox_read_file_tolerant(inbox_path)?;
+    Ok(provenance::annotate(inbox_path, messages))
+}
+
 /// Read and merge messages from all inbox files for an agent (local + remote origins)
 ///
 /// This reads the local inbox file (`<agent>.json`) and all per-origin files
@@ -397,6 +708,43 @@ pub fn inbox_read_merged(
     Ok(all_messages)
 }
 
+/// Count unread messages for every inbox in a team in one directory scan.
+///
+/// Lists `team_dir/inboxes` once and streams each `*.json` inbox, keyed by
+/// file stem (the agent name for local inboxes; origin inboxes appear under
+/// `<agent>.<hostname>` and are not merged). Malformed records and unreadable
+/// files are not counted. Returns an empty map when the inboxes directory is
+/// missing. Never fails.
+///
+/// Prefer this over per-member reads when rendering counts for a whole team.
+pub fn team_unread_counts(team_dir: &Path) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    let Ok(entries) = fs::read_dir(team_dir.join("inboxes")) else {
+        return counts;
+    };
+
+    for entry in entries.flatten() {
+        if !entry.file_type().is_ok_and(|t| t.is_file()) {
+            continue;
+        }
+        let path = entry.path();
+        let Some(agent) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_suffix(".json"))
+        else {
+            continue;
+        };
+        let unread = match super::stream::inbox_iter(&path) {
+            Ok(messages) => messages.skip_invalid_records().filter(|m| !m.read).count(),
+            Err(_) => continue,
+        };
+        counts.insert(agent.to_string(), unread);
+    }
+
+    counts
+}
+
 /// Merge two message arrays, preserving order and deduplicating by message_id
 fn merge_messages(
     our_messages: &[InboxMessage],
@@ -432,19 +780,12 @@ fn merge_messages(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
     use tempfile::TempDir;
 
     fn create_test_message(from: &str, text: &str, message_id: Option<String>) -> InboxMessage {
         InboxMessage {
-            from: from.to_string(),
-            source_team: None,
-            text: text.to_string(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: None,
             message_id,
-            unknown_fields: HashMap::new(),
+            ..InboxMessage::new(from, text)
         }
     }
 
@@ -466,6 +807,162 @@ mod tests {
         assert_eq!(messages[0].text, "Test message");
     }
 
+    #[test]
+    fn test_conflict_retries_exhausted_restores_concurrent_write() {
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent.json");
+        fs::write(&inbox_path, "[]").unwrap();
+
+        let ours = create_test_message("team-lead", "ours", Some("msg-ours".to_string()));
+        let rogue = create_test_message("rogue", "theirs", Some("msg-rogue".to_string()));
+        let rogue_path = inbox_path.clone();
+
+        // A writer that ignores the lock replaces the inbox between our read
+        // and our swap; with no retries left the write must fail and leave
+        // the concurrent write in place.
+        let result = atomic_write_with_conflict_check(&inbox_path, 0, |messages| {
+            fs::write(&rogue_path, serde_json::to_vec(&[&rogue]).unwrap()).unwrap();
+            messages.push(ours.clone());
+            true
+        });
+        assert!(matches!(
+            result,
+            Err(InboxError::ConflictRetriesExhausted { attempts: 0, .. })
+        ));
+
+        let on_disk: Vec<InboxMessage> =
+            serde_json::from_slice(&fs::read(&inbox_path).unwrap()).unwrap();
+        let ids: Vec<_> = on_disk
+            .iter()
+            .filter_map(|m| m.message_id.as_deref())
+            .collect();
+        assert_eq!(ids, vec!["msg-rogue"]);
+        assert!(!inbox_path.with_extension("tmp").exists());
+    }
+
+    #[test]
+    fn test_inbox_append_many_writes_batch_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent.json");
+        let existing = create_test_message("team-lead", "existing", Some("msg-000".to_string()));
+        inbox_append(&inbox_path, &existing, "test-team", "test-agent").unwrap();
+
+        let mut batch: Vec<InboxMessage> = (1..=50)
+            .map(|i| {
+                create_test_message("bridge", &format!("batch {i}"), Some(format!("msg-{i:03}")))
+            })
+            .collect();
+        // Duplicates of the file and of the batch itself are dropped.
+        batch.push(existing.clone());
+        batch.push(batch[0].clone());
+
+        let outcome = inbox_append_many(&inbox_path, &batch).unwrap();
+        assert_eq!(outcome, WriteOutcome::Success);
+
+        let messages: Vec<InboxMessage> =
+            serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+        assert_eq!(messages.len(), 51);
+        assert_eq!(messages[0].text, "existing");
+        assert_eq!(messages[50].message_id.as_deref(), Some("msg-050"));
+        let remaining: Vec<_> = fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .filter(|name| name.ends_with(".json") || name.ends_with(".tmp"))
+            .collect();
+        assert_eq!(remaining, vec!["agent.json".to_string()]);
+
+        // Re-delivering the same batch is a no-op.
+        assert_eq!(
+            inbox_append_many(&inbox_path, &batch).unwrap(),
+            WriteOutcome::Success
+        );
+        let messages: Vec<InboxMessage> =
+            serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+        assert_eq!(messages.len(), 51);
+    }
+
+    #[test]
+    fn test_inbox_append_many_counts_merged_concurrent_messages() {
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent.json");
+        fs::write(&inbox_path, "[]").unwrap();
+
+        let batch: Vec<InboxMessage> = (1..=3)
+            .map(|i| create_test_message("bridge", &format!("batch {i}"), Some(format!("b-{i}"))))
+            .collect();
+        let rogue: Vec<InboxMessage> = (1..=2)
+            .map(|i| create_test_message("rogue", &format!("rogue {i}"), Some(format!("r-{i}"))))
+            .collect();
+        let rogue_path = inbox_path.clone();
+
+        // A writer that ignores the lock lands two messages between our read
+        // and our swap; both are merged back and counted.
+        let outcome = atomic_write_with_conflict_check(&inbox_path, 1, |existing| {
+            fs::write(&rogue_path, serde_json::to_vec(&rogue).unwrap()).unwrap();
+            append_new_messages(existing, &batch)
+        })
+        .unwrap();
+        assert_eq!(
+            outcome,
+            WriteOutcome::ConflictResolved { merged_messages: 2 }
+        );
+
+        let messages: Vec<InboxMessage> =
+            serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+        assert_eq!(messages.len(), 5);
+    }
+
+    #[test]
+    fn test_inbox_append_many_skips_known_messages_without_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent.json");
+        let message = create_test_message("team-lead", "no id", None);
+
+        inbox_append_many(&inbox_path, std::slice::from_ref(&message)).unwrap();
+        inbox_append_many(&inbox_path, std::slice::from_ref(&message)).unwrap();
+
+        let messages: Vec<InboxMessage> =
+            serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_file_mode() {
+        assert_eq!(parse_file_mode("0600"), Some(0o600));
+        assert_eq!(parse_file_mode("640"), Some(0o640));
+        assert_eq!(parse_file_mode(" 0o600 "), Some(0o600));
+        assert_eq!(parse_file_mode("0800"), None);
+        assert_eq!(parse_file_mode("17777"), None);
+        assert_eq!(parse_file_mode(""), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[serial_test::serial]
+    fn test_inbox_file_mode_applied_on_create_and_swap() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent.json");
+        let mode_of = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o7777;
+
+        set_inbox_file_mode(Some(0o600));
+        let first = create_test_message("team-lead", "first", Some("msg-001".to_string()));
+        let created = inbox_append(&inbox_path, &first, "test-team", "test-agent");
+        let created_mode = mode_of(&inbox_path);
+
+        fs::set_permissions(&inbox_path, fs::Permissions::from_mode(0o644)).unwrap();
+        let second = create_test_message("team-lead", "second", Some("msg-002".to_string()));
+        let swapped = inbox_append(&inbox_path, &second, "test-team", "test-agent");
+        let swapped_mode = mode_of(&inbox_path);
+        set_inbox_file_mode(None);
+
+        assert_eq!(created.unwrap(), WriteOutcome::Success);
+        assert_eq!(swapped.unwrap(), WriteOutcome::Success);
+        assert_eq!(created_mode, 0o600);
+        assert_eq!(swapped_mode, 0o600);
+    }
+
     #[test]
     fn test_inbox_append_existing_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -606,6 +1103,61 @@ mod tests {
         assert!(messages[0].unknown_fields.contains_key("futureFeature"));
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_inbox_read_with_meta_reports_write_generations() {
+        use crate::io::provenance::ProvenanceOrigin;
+
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent.json");
+
+        let msg1 = create_test_message("user-a", "Message 1", Some("msg-001".to_string()));
+        let msg2 = create_test_message("user-b", "Message 2", Some("msg-002".to_string()));
+        set_inbox_provenance(Some(true));
+        let first = inbox_append(&inbox_path, &msg1, "test-team", "test-agent");
+        let second = inbox_append(&inbox_path, &msg2, "test-team", "test-agent");
+        set_inbox_provenance(None);
+        first.unwrap();
+        second.unwrap();
+
+        // Simulate a writer that bypasses the locked path.
+        let mut raw: Vec<InboxMessage> =
+            serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+        raw.push(create_test_message("claude", "External", None));
+        fs::write(&inbox_path, serde_json::to_vec(&raw).unwrap()).unwrap();
+
+        let meta = inbox_read_with_meta(&inbox_path).unwrap();
+        assert_eq!(meta.generation, 2);
+        assert_eq!(meta.messages.len(), 3);
+        let first = meta.messages[0].provenance.as_ref().unwrap();
+        assert_eq!(first.origin, ProvenanceOrigin::Direct);
+        assert_eq!(first.generation, 1);
+        assert_eq!(first.writer_pid, std::process::id());
+        assert_eq!(meta.messages[1].provenance.as_ref().unwrap().generation, 2);
+        assert!(meta.messages[2].provenance.is_none());
+
+        // Plain reads are unaffected by the sidecar.
+        assert_eq!(inbox_read_file_tolerant(&inbox_path).unwrap().len(), 3);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_inbox_provenance_is_opt_in() {
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent.json");
+
+        set_inbox_provenance(Some(false));
+        let msg = create_test_message("user-a", "Message 1", Some("msg-001".to_string()));
+        let result = inbox_append(&inbox_path, &msg, "test-team", "test-agent");
+        set_inbox_provenance(None);
+
+        assert_eq!(result.unwrap(), WriteOutcome::Success);
+        assert!(!inbox_path.with_extension("provenance").exists());
+        let meta = inbox_read_with_meta(&inbox_path).unwrap();
+        assert_eq!(meta.generation, 0);
+        assert!(meta.messages[0].provenance.is_none());
+    }
+
     #[test]
     fn test_inbox_update_marks_read() {
         let temp_dir = TempDir::new().unwrap();
@@ -699,6 +1251,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_team_unread_counts_single_scan() {
+        let temp_dir = TempDir::new().unwrap();
+        let team_dir = temp_dir.path();
+        assert!(super::team_unread_counts(team_dir).is_empty());
+
+        let inboxes_dir = team_dir.join("inboxes");
+        fs::create_dir_all(&inboxes_dir).unwrap();
+        let mut read = create_test_message("user-a", "seen", Some("msg-001".to_string()));
+        read.read = true;
+        let unread = create_test_message("user-b", "new", Some("msg-002".to_string()));
+        fs::write(
+            inboxes_dir.join("agent-1.json"),
+            serde_json::to_string(&vec![read, unread.clone(), unread]).unwrap(),
+        )
+        .unwrap();
+        fs::write(inboxes_dir.join("agent-2.json"), "[]").unwrap();
+        fs::write(inboxes_dir.join("agent-3.json"), "not json").unwrap();
+        fs::write(inboxes_dir.join("notes.txt"), "ignored").unwrap();
+
+        let counts = super::team_unread_counts(team_dir);
+        assert_eq!(counts.get("agent-1"), Some(&2));
+        assert_eq!(counts.get("agent-2"), Some(&0));
+        assert_eq!(counts.get("agent-3"), Some(&0));
+        assert!(!counts.contains_key("notes"));
+        assert_eq!(counts.len(), 3);
+    }
+
     #[test]
     fn test_inbox_read_merged_empty_directory() {
         let temp_dir = TempDir::new().unwrap();
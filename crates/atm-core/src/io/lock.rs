@@ -6,6 +6,9 @@ use std::fs::File;
 use std::path::Path;
 use std::time::Duration;
 
+/// Largest backoff doubling; later retries keep waiting 12.8s.
+const MAX_BACKOFF_SHIFT: u32 = 8;
+
 /// File lock guard that automatically releases on drop
 pub struct FileLock {
     file: File,
@@ -29,6 +32,8 @@ impl Drop for FileLock {
 /// - Attempt 4: 400ms wait
 /// - Attempt 5: 800ms wait
 ///
+/// The wait stops doubling at 12.8s, so large `max_retries` never overflow.
+///
 /// # Arguments
 ///
 /// * `path` - Path to the file to lock
@@ -72,9 +77,7 @@ pub fn acquire_lock(path: &Path, max_retries: u32) -> Result<FileLock, InboxErro
             {
                 // Someone else has the lock, retry with backoff
                 if attempt < max_retries {
-                    // Exponential backoff: 50ms, 100ms, 200ms, 400ms, 800ms
-                    let wait_ms = 50u64 * (1 << attempt);
-                    std::thread::sleep(Duration::from_millis(wait_ms));
+                    std::thread::sleep(backoff_wait(attempt));
                 }
             }
             Err(e) => {
@@ -93,6 +96,11 @@ pub fn acquire_lock(path: &Path, max_retries: u32) -> Result<FileLock, InboxErro
     })
 }
 
+/// Exponential backoff: 50ms, 100ms, 200ms, 400ms, 800ms, ... capped at 12.8s.
+fn backoff_wait(attempt: u32) -> Duration {
+    Duration::from_millis(50u64 << attempt.min(MAX_BACKOFF_SHIFT))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +185,15 @@ mod tests {
         handle1.join().unwrap();
     }
 
+    #[test]
+    fn test_backoff_wait_doubles_and_caps() {
+        assert_eq!(backoff_wait(0), Duration::from_millis(50));
+        assert_eq!(backoff_wait(4), Duration::from_millis(800));
+        assert_eq!(backoff_wait(8), Duration::from_millis(12_800));
+        assert_eq!(backoff_wait(64), Duration::from_millis(12_800));
+        assert_eq!(backoff_wait(u32::MAX), Duration::from_millis(12_800));
+    }
+
     #[test]
     fn test_lock_auto_release() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,215 @@
+//! Message id generation
+//!
+//! [`new_message_id`] produces ids according to the configured
+//! [`MessageIdScheme`]. `uuid` and `ulid` ids are generated in-process; the
+//! `counter` scheme keeps a per-inbox sequence in a `<inbox>.seq` sidecar file
+//! guarded by its own lock, so ids stay monotonic across processes. Gaps are
+//! possible (e.g. an id allocated for a write that then fails). Writers
+//! allocate only when they are about to write, against the destination inbox.
+//!
+//! Counter values are only unique within one inbox on one host, while merged
+//! reads and the bridge dedup by `message_id` across both, so counter ids are
+//! namespaced as `<host>/<team>/<agent>/<sequence>`.
+
+use super::atomic::rename_or_copy;
+use super::error::InboxError;
+use super::lock::acquire_lock;
+use crate::config::MessageIdScheme;
+use std::fs;
+use std::path::Path;
+
+/// Crockford base32 alphabet used by ULIDs.
+const ULID_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Lock retries when allocating a counter id.
+const COUNTER_LOCK_RETRIES: u32 = 5;
+
+/// Generate a new message id for a message destined for `inbox_path`.
+///
+/// `inbox_path` is only consulted by [`MessageIdScheme::Counter`].
+///
+/// # Errors
+///
+/// Returns `InboxError` if the counter sidecar cannot be locked, read, or
+/// written.
+pub fn new_message_id(scheme: MessageIdScheme, inbox_path: &Path) -> Result<String, InboxError> {
+    match scheme {
+        MessageIdScheme::Uuid => Ok(uuid::Uuid::new_v4().to_string()),
+        MessageIdScheme::Ulid => Ok(ulid_from_parts(
+            chrono::Utc::now().timestamp_millis().max(0) as u64,
+            rand::random::<u128>(),
+        )),
+        MessageIdScheme::Counter => next_counter_id(inbox_path),
+    }
+}
+
+/// The `[messaging] id_scheme` visible from the current directory.
+///
+/// For writers that do not otherwise resolve config; falls back to the
+/// default scheme when config cannot be resolved.
+pub fn configured_id_scheme() -> MessageIdScheme {
+    let (Ok(home_dir), Ok(current_dir)) = (crate::home::get_home_dir(), std::env::current_dir())
+    else {
+        return MessageIdScheme::default();
+    };
+    configured_id_scheme_in(&current_dir, &home_dir)
+}
+
+/// The `[messaging] id_scheme` resolved from `current_dir` and `home_dir`.
+pub fn configured_id_scheme_in(current_dir: &Path, home_dir: &Path) -> MessageIdScheme {
+    crate::config::resolve_config(
+        &crate::config::ConfigOverrides::default(),
+        current_dir,
+        home_dir,
+    )
+    .ok()
+    .and_then(|config| config.messaging.id_scheme)
+    .unwrap_or_default()
+}
+
+/// Encode a ULID from a millisecond timestamp (low 48 bits) and 80 random bits.
+fn ulid_from_parts(timestamp_ms: u64, random: u128) -> String {
+    let value =
+        (u128::from(timestamp_ms & 0xFFFF_FFFF_FFFF) << 80) | (random & ((1u128 << 80) - 1));
+    (0..26)
+        .map(|i| ULID_ALPHABET[((value >> (125 - 5 * i)) & 0x1F) as usize] as char)
+        .collect()
+}
+
+/// Allocate the next value of the inbox's counter sidecar.
+fn next_counter_id(inbox_path: &Path) -> Result<String, InboxError> {
+    let host = hostname::get()
+        .ok()
+        .and_then(|h| h.into_string().ok())
+        .unwrap_or_default();
+    next_counter_id_on(&host, inbox_path)
+}
+
+/// [`next_counter_id`] with an explicit host namespace.
+fn next_counter_id_on(host: &str, inbox_path: &Path) -> Result<String, InboxError> {
+    let seq_path = inbox_path.with_extension("seq");
+    let lock_path = inbox_path.with_extension("seq.lock");
+    let tmp_path = inbox_path.with_extension("seq.tmp");
+    let io_err = |path: &Path| {
+        let path = path.to_path_buf();
+        move |source| InboxError::Io { path, source }
+    };
+
+    if let Some(parent) = seq_path.parent() {
+        fs::create_dir_all(parent).map_err(io_err(parent))?;
+    }
+    let _lock = acquire_lock(&lock_path, COUNTER_LOCK_RETRIES)?;
+
+    let current = match fs::read_to_string(&seq_path) {
+        Ok(content) => content.trim().parse::<u64>().unwrap_or(0),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+        Err(e) => return Err(io_err(&seq_path)(e)),
+    };
+    let next = current + 1;
+    fs::write(&tmp_path, next.to_string()).map_err(io_err(&tmp_path))?;
+    rename_or_copy(&tmp_path, &seq_path)?;
+
+    // `<team>/inboxes/<agent>.json`
+    let agent = inbox_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    let team = inbox_path
+        .parent()
+        .and_then(Path::parent)
+        .and_then(Path::file_name)
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    Ok(format!("{host}/{team}/{agent}/{next:020}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_ulid_encoding_is_time_sortable() {
+        let earlier = ulid_from_parts(1_700_000_000_000, u128::MAX);
+        let later = ulid_from_parts(1_700_000_000_001, 0);
+        assert_eq!(earlier.len(), 26);
+        assert!(earlier < later, "{earlier} should sort before {later}");
+        assert!(earlier.bytes().all(|b| ULID_ALPHABET.contains(&b)));
+        assert_eq!(ulid_from_parts(0, 0), "0".repeat(26));
+    }
+
+    #[test]
+    fn test_counter_ids_are_monotonic_per_inbox() {
+        let tmp = TempDir::new().unwrap();
+        let inbox_a = tmp.path().join("atm-dev/inboxes/arch-ctm.json");
+        let inbox_b = tmp.path().join("atm-dev/inboxes/team-lead.json");
+
+        let first = next_counter_id_on("laptop", &inbox_a).unwrap();
+        let second = next_counter_id_on("laptop", &inbox_a).unwrap();
+        assert_eq!(first, "laptop/atm-dev/arch-ctm/00000000000000000001");
+        assert!(first < second);
+        assert_eq!(
+            next_counter_id_on("laptop", &inbox_b).unwrap(),
+            "laptop/atm-dev/team-lead/00000000000000000001",
+            "counters are independent per inbox"
+        );
+        assert!(
+            !inbox_a.exists(),
+            "allocating ids must not create the inbox"
+        );
+    }
+
+    #[test]
+    fn test_counter_ids_from_two_hosts_survive_merged_read() {
+        use crate::config::{HostnameRegistry, RemoteConfig};
+        use crate::schema::InboxMessage;
+
+        let local = TempDir::new().unwrap();
+        let remote = TempDir::new().unwrap();
+        let team_dir = local.path().join("atm-dev");
+        let inboxes = team_dir.join("inboxes");
+        fs::create_dir_all(&inboxes).unwrap();
+
+        // Each host allocates the first value of its own arch-ctm counter.
+        let local_id = next_counter_id_on("desktop", &inboxes.join("arch-ctm.json")).unwrap();
+        let remote_id = next_counter_id_on(
+            "laptop",
+            &remote.path().join("atm-dev/inboxes/arch-ctm.json"),
+        )
+        .unwrap();
+        assert_ne!(local_id, remote_id);
+
+        let write = |name: &str, from: &str, id: String| {
+            let msg = InboxMessage::new(from, format!("from {from}")).message_id(id);
+            fs::write(
+                inboxes.join(name),
+                serde_json::to_string(&vec![msg]).unwrap(),
+            )
+            .unwrap();
+        };
+        write("arch-ctm.json", "bob", local_id);
+        write("arch-ctm.laptop.json", "carol", remote_id);
+
+        let mut registry = HostnameRegistry::new();
+        registry
+            .register(RemoteConfig {
+                hostname: "laptop".to_string(),
+                address: "user@laptop".to_string(),
+                ssh_key_path: None,
+                aliases: Vec::new(),
+            })
+            .unwrap();
+        let merged =
+            crate::io::inbox::inbox_read_merged(&team_dir, "arch-ctm", Some(&registry)).unwrap();
+        let mut senders: Vec<_> = merged.iter().map(|m| m.from.as_str()).collect();
+        senders.sort_unstable();
+        assert_eq!(senders, ["bob", "carol"]);
+    }
+
+    #[test]
+    fn test_uuid_scheme_is_default() {
+        let tmp = TempDir::new().unwrap();
+        let id = new_message_id(MessageIdScheme::default(), &tmp.path().join("a.json")).unwrap();
+        assert!(uuid::Uuid::parse_str(&id).is_ok());
+    }
+}
@@ -15,19 +15,12 @@
 //! use agent_team_mail_core::io::{inbox_append, WriteOutcome};
 //! use agent_team_mail_core::InboxMessage;
 //! use std::path::Path;
-//! use std::collections::HashMap;
 //!
 //! let inbox_path = Path::new("/home/user/.claude/teams/my-team/inboxes/agent.json");
-//! let message = InboxMessage {
-//!     from: "team-lead".to_string(),
-//!     source_team: None,
-//!     text: "CI failure detected".to_string(),
-//!     timestamp: "2026-02-11T14:30:00Z".to_string(),
-//!     read: false,
-//!     summary: Some("CI failure detected".to_string()),
-//!     message_id: Some("msg-12345".to_string()),
-//!     unknown_fields: HashMap::new(),
-//! };
+//! let message = InboxMessage::new("team-lead", "CI failure detected")
+//!     .timestamp("2026-02-11T14:30:00Z")
+//!     .summary("CI failure detected")
+//!     .message_id("msg-12345");
 //!
 //! match inbox_append(inbox_path, &message, "my-team", "agent").unwrap() {
 //!     WriteOutcome::Success => println!("Message delivered"),
@@ -45,9 +38,19 @@ pub mod error;
 pub mod hash;
 pub mod inbox;
 pub mod lock;
+pub mod message_id;
+pub mod provenance;
 pub mod spool;
+pub mod stream;
 
 // Re-export primary API
 pub use error::InboxError;
-pub use inbox::{WriteOutcome, inbox_append, inbox_read_file_tolerant, inbox_update};
+pub use inbox::{
+    DEFAULT_MAX_CONFLICT_RETRIES, WriteOutcome, inbox_append, inbox_append_bounded,
+    inbox_append_many, inbox_read_file_tolerant, inbox_read_with_meta, inbox_update,
+    inbox_update_if, team_unread_counts,
+};
+pub use message_id::{configured_id_scheme, configured_id_scheme_in, new_message_id};
+pub use provenance::{InboxMessageWithMeta, InboxReadMeta, MessageProvenance, ProvenanceOrigin};
 pub use spool::{SpoolStatus, spool_drain};
+pub use stream::{InboxIter, inbox_iter};
@@ -0,0 +1,217 @@
+//! Write provenance sidecar for inbox files
+//!
+//! When enabled (`[core] inbox_provenance = true` or `ATM_INBOX_PROVENANCE`),
+//! every locked inbox write bumps a generation counter stored next to the
+//! inbox (`<agent>.provenance`) and records which generation placed each new
+//! message, and whether it arrived through a conflict merge. The inbox JSON
+//! itself is never modified, so Claude Code and plain readers are unaffected.
+//!
+//! The sidecar is best-effort debugging metadata: a missing or corrupt file
+//! simply yields messages without provenance.
+
+use crate::io::hash::compute_hash;
+use crate::schema::InboxMessage;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// How a message entered the inbox
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProvenanceOrigin {
+    /// Added by the locked writer's own modification
+    Direct,
+    /// Recovered from content displaced by a concurrent (non-locking) writer
+    ConflictMerge,
+}
+
+/// Which write produced a message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageProvenance {
+    /// How the message entered the inbox
+    pub origin: ProvenanceOrigin,
+    /// Inbox generation of the write that placed the message
+    pub generation: u64,
+    /// PID of the process that performed the write
+    pub writer_pid: u32,
+}
+
+/// A message paired with its recorded provenance, if any
+#[derive(Debug, Clone)]
+pub struct InboxMessageWithMeta {
+    pub message: InboxMessage,
+    /// `None` for messages written outside the locked write path
+    pub provenance: Option<MessageProvenance>,
+}
+
+/// Result of [`crate::io::inbox::inbox_read_with_meta`]
+#[derive(Debug, Clone)]
+pub struct InboxReadMeta {
+    /// Current inbox generation (number of recorded locked writes)
+    pub generation: u64,
+    /// Messages in file order
+    pub messages: Vec<InboxMessageWithMeta>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProvenanceFile {
+    generation: u64,
+    messages: HashMap<String, MessageProvenance>,
+}
+
+fn provenance_path(inbox_path: &Path) -> PathBuf {
+    inbox_path.with_extension("provenance")
+}
+
+/// Stable key for a message: its `message_id`, or a content hash when absent
+pub(crate) fn message_key(message: &InboxMessage) -> String {
+    match &message.message_id {
+        Some(id) => id.clone(),
+        None => format!(
+            "h:{}",
+            compute_hash(
+                format!("{}\0{}\0{}", message.from, message.timestamp, message.text).as_bytes()
+            )
+        ),
+    }
+}
+
+fn load(inbox_path: &Path) -> ProvenanceFile {
+    fs::read(provenance_path(inbox_path))
+        .ok()
+        .and_then(|content| serde_json::from_slice(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Record a completed locked write against the inbox sidecar
+///
+/// Must be called while the inbox lock is held. `messages` is the content
+/// that was written; entries for messages no longer present are dropped so
+/// the sidecar stays proportional to the inbox.
+pub(crate) fn record_write(
+    inbox_path: &Path,
+    messages: &[InboxMessage],
+    written: &HashSet<String>,
+    merged: &HashSet<String>,
+) {
+    let mut file = load(inbox_path);
+    file.generation += 1;
+    let present: HashSet<String> = messages.iter().map(message_key).collect();
+    file.messages.retain(|key, _| present.contains(key));
+
+    let writer_pid = std::process::id();
+    for (keys, origin) in [
+        (written, ProvenanceOrigin::Direct),
+        (merged, ProvenanceOrigin::ConflictMerge),
+    ] {
+        for key in keys {
+            file.messages.insert(
+                key.clone(),
+                MessageProvenance {
+                    origin,
+                    generation: file.generation,
+                    writer_pid,
+                },
+            );
+        }
+    }
+
+    let path = provenance_path(inbox_path);
+    let tmp_path = path.with_extension("provenance.tmp");
+    let result = serde_json::to_vec(&file)
+        .map_err(std::io::Error::other)
+        .and_then(|content| fs::write(&tmp_path, content))
+        .and_then(|()| fs::rename(&tmp_path, &path));
+    if let Err(e) = result {
+        warn!(
+            "Failed to record inbox provenance at {}: {e}",
+            path.display()
+        );
+    }
+}
+
+/// Attach recorded provenance to `messages`
+pub(crate) fn annotate(inbox_path: &Path, messages: Vec<InboxMessage>) -> InboxReadMeta {
+    let mut file = load(inbox_path);
+    InboxReadMeta {
+        generation: file.generation,
+        messages: messages
+            .into_iter()
+            .map(|message| {
+                let provenance = file.messages.remove(&message_key(&message));
+                InboxMessageWithMeta {
+                    message,
+                    provenance,
+                }
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(id: &str) -> InboxMessage {
+        InboxMessage::new("team-lead", format!("body {id}"))
+            .timestamp("2026-02-11T10:00:00Z")
+            .message_id(id.to_string())
+    }
+
+    fn keys(ids: &[&str]) -> HashSet<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_record_write_tracks_merge_origin_and_prunes_removed() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent.json");
+
+        let first = vec![message("a")];
+        record_write(&inbox_path, &first, &keys(&["a"]), &HashSet::new());
+
+        let second = vec![message("a"), message("b"), message("c")];
+        record_write(&inbox_path, &second, &keys(&["b"]), &keys(&["c"]));
+
+        let meta = annotate(&inbox_path, second);
+        assert_eq!(meta.generation, 2);
+        let origins: Vec<_> = meta
+            .messages
+            .iter()
+            .map(|m| {
+                let p = m.provenance.as_ref().unwrap();
+                (p.origin, p.generation)
+            })
+            .collect();
+        assert_eq!(
+            origins,
+            vec![
+                (ProvenanceOrigin::Direct, 1),
+                (ProvenanceOrigin::Direct, 2),
+                (ProvenanceOrigin::ConflictMerge, 2),
+            ]
+        );
+
+        // Entries for messages no longer in the inbox are dropped.
+        record_write(
+            &inbox_path,
+            &[message("c")],
+            &HashSet::new(),
+            &HashSet::new(),
+        );
+        let file = load(&inbox_path);
+        assert_eq!(file.generation, 3);
+        assert_eq!(file.messages.len(), 1);
+        assert!(file.messages.contains_key("c"));
+    }
+
+    #[test]
+    fn test_annotate_without_sidecar_has_no_provenance() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let meta = annotate(&temp_dir.path().join("agent.json"), vec![message("a")]);
+        assert_eq!(meta.generation, 0);
+        assert!(meta.messages[0].provenance.is_none());
+    }
+}
@@ -23,7 +23,7 @@
 
 use crate::io::{
     error::InboxError,
-    inbox::{WriteOutcome, inbox_append},
+    inbox::{WriteOutcome, inbox_append, write_inbox_file},
 };
 use crate::schema::InboxMessage;
 use serde::{Deserialize, Serialize};
@@ -57,7 +57,7 @@ pub struct SpooledMessage {
 }
 
 /// Status report from spool drain operation
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpoolStatus {
     /// Number of messages successfully delivered
     pub delivered: usize,
@@ -124,7 +124,7 @@ fn spool_message_with_base(
         source: e,
     })?;
 
-    fs::write(&spool_path, content).map_err(|e| InboxError::Io {
+    write_inbox_file(&spool_path, &content).map_err(|e| InboxError::Io {
         path: spool_path.clone(),
         source: e,
     })?;
@@ -293,7 +293,7 @@ fn process_spooled_message(
             source: e,
         })?;
 
-        fs::write(&failed_path, failed_content).map_err(|e| InboxError::Io {
+        write_inbox_file(&failed_path, &failed_content).map_err(|e| InboxError::Io {
             path: failed_path.clone(),
             source: e,
         })?;
@@ -308,7 +308,7 @@ fn process_spooled_message(
                 source: e,
             })?;
 
-        fs::write(spool_path, updated_content).map_err(|e| InboxError::Io {
+        write_inbox_file(spool_path, &updated_content).map_err(|e| InboxError::Io {
             path: spool_path.to_path_buf(),
             source: e,
         })?;
@@ -357,19 +357,12 @@ mod tests {
     use super::*;
     use crate::io::WriteOutcome;
     use serial_test::serial;
-    use std::collections::HashMap;
     use tempfile::TempDir;
 
     fn create_test_message(from: &str, text: &str, message_id: Option<String>) -> InboxMessage {
         InboxMessage {
-            from: from.to_string(),
-            source_team: None,
-            text: text.to_string(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: None,
             message_id,
-            unknown_fields: HashMap::new(),
+            ..InboxMessage::new(from, text)
         }
     }
 
@@ -461,6 +454,29 @@ mod tests {
         assert!(!spool_path.exists());
     }
 
+    #[cfg(unix)]
+    #[test]
+    #[serial_test::serial]
+    fn test_spool_file_uses_inbox_file_mode() {
+        use crate::io::inbox::set_inbox_file_mode;
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let message = create_test_message("team-lead", "Test message", Some("msg-001".to_string()));
+
+        set_inbox_file_mode(Some(0o600));
+        let spool_path =
+            spool_message_with_base("test-team", "test-agent", &message, Some(temp_dir.path()));
+        set_inbox_file_mode(None);
+
+        let mode = fs::metadata(spool_path.unwrap())
+            .unwrap()
+            .permissions()
+            .mode()
+            & 0o7777;
+        assert_eq!(mode, 0o600);
+    }
+
     #[test]
     fn test_spool_drain_increments_retry_count() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,257 @@
+//! Streaming inbox reader
+//!
+//! [`inbox_iter`] walks an inbox JSON array one element at a time so callers
+//! that only count or filter messages never hold the whole inbox in memory.
+//! Only the current record is materialized; very large inboxes stay within a
+//! small, constant memory footprint.
+
+use super::error::InboxError;
+use crate::schema::InboxMessage;
+use serde::Deserialize;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// Iterator over the messages of an inbox file, see [`inbox_iter`].
+pub struct InboxIter<R> {
+    reader: R,
+    path: PathBuf,
+    state: IterState,
+    /// Index of the next array element
+    index: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IterState {
+    /// Before the opening `[`
+    Start,
+    /// Expecting the first element or `]`
+    First,
+    /// Expecting `,` or `]`
+    Next,
+    /// Array closed or a structural error was reported
+    Done,
+}
+
+/// Open an inbox file for streaming.
+///
+/// Each item is one message. A record that is valid JSON but not a valid
+/// [`InboxMessage`] yields `Err(InboxError::Json)` and iteration continues with
+/// the next record. Structural errors (not an array, truncated file, I/O
+/// failure) yield a single error and end the iteration.
+///
+/// # Errors
+///
+/// Returns `InboxError::Io` if the file cannot be opened.
+pub fn inbox_iter(path: &Path) -> Result<InboxIter<BufReader<File>>, InboxError> {
+    let file = File::open(path).map_err(|e| InboxError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    Ok(InboxIter::new(BufReader::new(file), path))
+}
+
+impl<R: BufRead> InboxIter<R> {
+    /// Stream messages from any buffered reader; `path` is used in errors.
+    pub fn new(reader: R, path: &Path) -> Self {
+        Self {
+            reader,
+            path: path.to_path_buf(),
+            state: IterState::Start,
+            index: 0,
+        }
+    }
+
+    /// Yield only valid messages, like `flatten()`, but emit the same
+    /// `inbox_record_skipped` event as the tolerant whole-file read for each
+    /// record that is not a valid [`InboxMessage`]. A structural error ends
+    /// the iteration silently.
+    pub fn skip_invalid_records(mut self) -> impl Iterator<Item = InboxMessage> {
+        std::iter::from_fn(move || {
+            loop {
+                let index = self.index;
+                match self.step() {
+                    Ok(Some(Ok(message))) => return Some(message),
+                    Ok(Some(Err(error))) => {
+                        super::inbox::emit_record_skipped(&self.path, index, &error);
+                    }
+                    Ok(None) | Err(_) => {
+                        self.state = IterState::Done;
+                        return None;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Consume whitespace and return the next byte without consuming it.
+    fn peek_non_ws(&mut self) -> Result<Option<u8>, InboxError> {
+        loop {
+            let buf = self.reader.fill_buf().map_err(|e| InboxError::Io {
+                path: self.path.clone(),
+                source: e,
+            })?;
+            let Some(pos) = buf.iter().position(|b| !b.is_ascii_whitespace()) else {
+                if buf.is_empty() {
+                    return Ok(None);
+                }
+                let len = buf.len();
+                self.reader.consume(len);
+                continue;
+            };
+            let byte = buf[pos];
+            self.reader.consume(pos);
+            return Ok(Some(byte));
+        }
+    }
+
+    /// Consume `expected` or fail with a structural error.
+    fn expect_byte(&mut self, expected: u8) -> Result<(), InboxError> {
+        match self.peek_non_ws()? {
+            Some(b) if b == expected => {
+                self.reader.consume(1);
+                Ok(())
+            }
+            Some(b) => Err(self.syntax_error(&format!(
+                "expected '{}' but found '{}'",
+                expected as char, b as char
+            ))),
+            None => Err(self.syntax_error(&format!(
+                "expected '{}' but reached end of file",
+                expected as char
+            ))),
+        }
+    }
+
+    /// Read one array element. The outer error is structural (invalid JSON);
+    /// the inner one means the element is not a valid [`InboxMessage`].
+    fn read_record(&mut self) -> Result<Result<InboxMessage, InboxError>, InboxError> {
+        self.index += 1;
+        let mut de = serde_json::Deserializer::from_reader(&mut self.reader);
+        let value = serde_json::Value::deserialize(&mut de).map_err(|e| InboxError::Json {
+            path: self.path.clone(),
+            source: e,
+        })?;
+        Ok(serde_json::from_value(value).map_err(|e| InboxError::Json {
+            path: self.path.clone(),
+            source: e,
+        }))
+    }
+
+    /// Advance the parser. Structural errors are returned as `Err`; per-record
+    /// errors are returned inside `Some`.
+    fn step(&mut self) -> Result<Option<Result<InboxMessage, InboxError>>, InboxError> {
+        loop {
+            match self.state {
+                IterState::Done => return Ok(None),
+                IterState::Start => {
+                    self.expect_byte(b'[')?;
+                    self.state = IterState::First;
+                }
+                IterState::First => {
+                    if self.peek_non_ws()? == Some(b']') {
+                        self.reader.consume(1);
+                        self.state = IterState::Done;
+                        return Ok(None);
+                    }
+                    self.state = IterState::Next;
+                    return self.read_record().map(Some);
+                }
+                IterState::Next => match self.peek_non_ws()? {
+                    Some(b']') => {
+                        self.reader.consume(1);
+                        self.state = IterState::Done;
+                        return Ok(None);
+                    }
+                    _ => {
+                        self.expect_byte(b',')?;
+                        return self.read_record().map(Some);
+                    }
+                },
+            }
+        }
+    }
+
+    fn syntax_error(&self, message: &str) -> InboxError {
+        InboxError::Json {
+            path: self.path.clone(),
+            source: <serde_json::Error as serde::de::Error>::custom(message),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for InboxIter<R> {
+    type Item = Result<InboxMessage, InboxError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.step() {
+            Ok(item) => item,
+            Err(e) => {
+                self.state = IterState::Done;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn iter(json: &str) -> InboxIter<Cursor<Vec<u8>>> {
+        InboxIter::new(
+            Cursor::new(json.as_bytes().to_vec()),
+            Path::new("test.json"),
+        )
+    }
+
+    #[test]
+    fn test_skip_invalid_records_yields_only_valid_messages() {
+        let json = r#"[
+            {"from": "a", "text": "one", "timestamp": "2026-02-11T10:00:00Z", "read": false},
+            {"from": "b"},
+            {"from": "c", "text": "three", "timestamp": "2026-02-11T10:02:00Z", "read": true}
+        ]"#;
+        let froms: Vec<String> = iter(json).skip_invalid_records().map(|m| m.from).collect();
+        assert_eq!(froms, vec!["a", "c"]);
+
+        // A structural error ends the stream after the records before it.
+        let truncated =
+            r#"[{"from": "a", "text": "one", "timestamp": "2026-02-11T10:00:00Z", "read": false},"#;
+        assert_eq!(iter(truncated).skip_invalid_records().count(), 1);
+    }
+
+    #[test]
+    fn test_inbox_iter_yields_messages_and_skips_bad_records() {
+        let json = r#"[
+            {"from": "a", "text": "one", "timestamp": "2026-02-11T10:00:00Z", "read": false},
+            {"from": "b"},
+            {"from": "c", "text": "three", "timestamp": "2026-02-11T10:02:00Z", "read": true}
+        ]"#;
+        let items: Vec<_> = iter(json).collect();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].as_ref().unwrap().text, "one");
+        assert!(
+            items[1].is_err(),
+            "record missing required fields is an error"
+        );
+        assert_eq!(items[2].as_ref().unwrap().from, "c");
+    }
+
+    #[test]
+    fn test_inbox_iter_empty_and_structural_errors() {
+        assert_eq!(iter("  [ ]  ").count(), 0);
+
+        let not_array: Vec<_> = iter(r#"{"from": "a"}"#).collect();
+        assert_eq!(not_array.len(), 1);
+        assert!(not_array[0].is_err());
+
+        let truncated: Vec<_> =
+            iter(r#"[{"from": "a", "text": "x", "timestamp": "2026-02-11T10:00:00Z"}, {"from""#)
+                .collect();
+        assert_eq!(truncated.len(), 2);
+        assert!(truncated[0].is_ok());
+        assert!(truncated[1].is_err());
+    }
+}
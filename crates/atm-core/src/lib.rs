@@ -8,11 +8,14 @@
 //! - Use proper serde configuration for camelCase ↔ snake_case
 //! - Support round-trip serialization without data loss
 
+pub mod client;
+pub mod color;
 pub mod config;
 pub mod consts;
 pub mod context;
 pub mod control;
 pub mod daemon_client;
+pub mod daemon_rpc;
 pub mod daemon_stream;
 pub mod event_log;
 pub mod gh_command;
@@ -27,6 +30,7 @@ pub mod pid;
 pub mod retention;
 pub mod schema;
 pub mod spawn;
+pub mod storage;
 pub mod team_config_store;
 pub mod text;
 
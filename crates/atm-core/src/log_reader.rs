@@ -252,12 +252,6 @@ mod ansi {
     pub const RESET: &str = "\x1b[0m";
 }
 
-/// Return `true` when stdout is connected to a TTY (ANSI colors are appropriate).
-fn stdout_is_tty() -> bool {
-    use std::io::IsTerminal;
-    std::io::stdout().is_terminal()
-}
-
 /// Format a [`LogEventV1`] as a human-readable line.
 ///
 /// Output format:
@@ -267,14 +261,15 @@ fn stdout_is_tty() -> bool {
 /// 2026-02-23T10:30:02Z ERROR  [atm/arch-ctm pid=12345] dispatch_error: connection refused
 /// ```
 ///
-/// When stdout is a TTY, level names are colorized:
+/// When color is enabled (see [`crate::color::stdout_color_enabled`]), level
+/// names are colorized:
 /// - `error` → red
 /// - `warn` → yellow
 /// - `info` → default
 /// - `debug` → cyan
 /// - `trace` → dim
 pub fn format_event_human(event: &LogEventV1) -> String {
-    let use_color = stdout_is_tty();
+    let use_color = crate::color::stdout_color_enabled();
 
     let level_upper = event.level.to_uppercase();
     // Pad level to 5 chars for alignment.
@@ -295,10 +295,13 @@ fn try_forward_to_socket(
     daemon_socket: &std::path::Path,
 ) -> bool {
     use std::io::{BufRead, BufReader, Write};
-    use std::os::unix::net::UnixStream;
     use std::time::Duration;
 
-    let stream = match UnixStream::connect(daemon_socket) {
+    if crate::daemon_client::daemon_disabled() {
+        return false;
+    }
+
+    let stream = match crate::daemon_client::connect_daemon_socket(daemon_socket) {
         Ok(s) => s,
         Err(_) => return false,
     };
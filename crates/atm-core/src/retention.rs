@@ -4,10 +4,12 @@
 //! Supports age-based and count-based policies with archive or delete strategies.
 
 use crate::config::{CleanupStrategy, RetentionConfig};
-use crate::io::inbox::inbox_update;
+use crate::io::inbox::inbox_update_if;
+use crate::io::stream::inbox_iter;
 use crate::schema::InboxMessage;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -20,6 +22,8 @@ pub struct RetentionResult {
     pub removed: usize,
     /// Number of messages archived (subset of removed)
     pub archived: usize,
+    /// One entry per removed message, naming the policy that evicted it
+    pub evictions: Vec<RetentionEviction>,
 }
 
 impl RetentionResult {
@@ -29,8 +33,75 @@ impl RetentionResult {
             kept,
             removed,
             archived,
+            evictions: Vec::new(),
         }
     }
+
+    /// Count evictions per policy label, sorted by label.
+    pub fn evictions_by_policy(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for eviction in &self.evictions {
+            *counts.entry(eviction.policy.label()).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+/// Policy that evicted a message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetentionPolicySource {
+    /// Global `[retention]` caps
+    Global,
+    /// `[retention.per_type.<type>]` override for the named type
+    PerType(String),
+}
+
+impl RetentionPolicySource {
+    /// Human-readable label: `global` or `per_type.<type>`
+    pub fn label(&self) -> String {
+        match self {
+            Self::Global => "global".to_string(),
+            Self::PerType(message_type) => format!("per_type.{message_type}"),
+        }
+    }
+}
+
+/// Which cap a message exceeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionReason {
+    /// Older than `max_age`
+    MaxAge,
+    /// Beyond `max_count`
+    MaxCount,
+}
+
+/// A single message removed by retention
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionEviction {
+    /// Message ID, when the message has one
+    pub message_id: Option<String>,
+    /// Message `type` field, when present
+    pub message_type: Option<String>,
+    /// Policy whose cap triggered the eviction
+    pub policy: RetentionPolicySource,
+    /// Cap that was exceeded
+    pub reason: EvictionReason,
+}
+
+/// Parsed age/count caps for one policy
+#[derive(Debug, Clone, Copy, Default)]
+struct Caps {
+    max_age: Option<Duration>,
+    max_count: Option<usize>,
+}
+
+impl Caps {
+    fn parse(max_age: Option<&str>, max_count: Option<usize>) -> Result<Self> {
+        Ok(Self {
+            max_age: max_age.map(parse_duration).transpose()?,
+            max_count,
+        })
+    }
 }
 
 /// Apply retention policy to an inbox
@@ -39,6 +110,11 @@ impl RetentionResult {
 /// the configured policy (max age and/or max count), and either deletes them
 /// or archives them based on the cleanup strategy.
 ///
+/// Messages whose `type` has a `per_type` override are checked against that
+/// override first; caps the override leaves unset fall back to the global
+/// policy. Each removal is recorded in [`RetentionResult::evictions`] with the
+/// policy that triggered it.
+///
 /// # Arguments
 ///
 /// * `inbox_path` - Path to the inbox.json file
@@ -66,111 +142,206 @@ pub fn apply_retention(
         return Ok(RetentionResult::new(0, 0, 0));
     }
 
-    // Read current inbox
-    let content = fs::read_to_string(inbox_path)
-        .with_context(|| format!("Failed to read inbox at {}", inbox_path.display()))?;
-    let messages: Vec<InboxMessage> = serde_json::from_str(&content)
-        .with_context(|| format!("Failed to parse inbox at {}", inbox_path.display()))?;
-
     // If no retention policy configured, keep all messages
-    if policy.max_age.is_none() && policy.max_count.is_none() {
-        return Ok(RetentionResult::new(messages.len(), 0, 0));
+    if policy.max_age.is_none() && policy.max_count.is_none() && policy.per_type.is_empty() {
+        return Ok(RetentionResult::new(count_messages(inbox_path)?, 0, 0));
     }
 
+    let global = Caps::parse(policy.max_age.as_deref(), policy.max_count)?;
+    let mut per_type = HashMap::new();
+    for (message_type, type_policy) in &policy.per_type {
+        let caps = Caps::parse(type_policy.max_age.as_deref(), type_policy.max_count)
+            .with_context(|| format!("Invalid retention.per_type.{message_type} policy"))?;
+        per_type.insert(message_type.as_str(), caps);
+    }
     let now = Utc::now();
-    let max_age_duration = if let Some(ref age_str) = policy.max_age {
-        Some(parse_duration(age_str)?)
-    } else {
-        None
-    };
-
-    // Determine which messages to keep
-    let mut to_keep = Vec::new();
-    let mut to_remove = Vec::new();
 
-    for message in messages {
-        let should_remove = should_remove_message(
-            &message,
-            &max_age_duration,
-            now,
-            policy.max_count,
-            to_keep.len(),
-        );
-
-        if should_remove {
-            to_remove.push(message);
-        } else {
-            to_keep.push(message);
-        }
+    // Scan the inbox as a stream first; the inbox is only rewritten when
+    // something actually has to be removed.
+    let mut scan = EvictionPlanner::new(&global, &per_type, now);
+    for message in inbox_iter(inbox_path)? {
+        let message = message
+            .with_context(|| format!("Failed to parse inbox at {}", inbox_path.display()))?;
+        scan.evict(&message);
     }
 
     // If nothing to remove, we're done
-    if to_remove.is_empty() {
-        return Ok(RetentionResult::new(to_keep.len(), 0, 0));
+    if scan.evictions.is_empty() {
+        return Ok(RetentionResult::new(scan.kept, 0, 0));
     }
 
     // In dry-run mode, just return the counts
     if dry_run {
+        let removed = scan.evictions.len();
         let archived = if policy.strategy == CleanupStrategy::Archive {
-            to_remove.len()
+            removed
         } else {
             0
         };
-        return Ok(RetentionResult::new(
-            to_keep.len(),
-            to_remove.len(),
-            archived,
-        ));
+        return Ok(RetentionResult {
+            evictions: scan.evictions,
+            ..RetentionResult::new(scan.kept, removed, archived)
+        });
     }
 
-    // Archive messages if configured
-    let archived = if policy.strategy == CleanupStrategy::Archive {
-        let archive_dir = determine_archive_dir(policy)?;
-        archive_messages(&to_remove, team, agent, &archive_dir)?;
-        to_remove.len()
+    // The scan above ran without the inbox lock, so messages may have been
+    // appended, acked, or removed since. Decide again on the locked contents
+    // and archive what is removed before the rewrite is committed.
+    let archive_dir = if policy.strategy == CleanupStrategy::Archive {
+        Some(determine_archive_dir(policy)?)
     } else {
-        0
+        None
     };
-
-    // Update inbox with only the messages to keep
-    inbox_update(inbox_path, team, agent, |messages| {
-        messages.clear();
-        messages.extend(to_keep.clone());
+    let mut planner = EvictionPlanner::new(&global, &per_type, now);
+    let mut removed = 0;
+    let mut archive_error = None;
+    inbox_update_if(inbox_path, team, agent, |messages| {
+        let evict: Vec<bool> = messages.iter().map(|m| planner.evict(m)).collect();
+        let to_remove: Vec<InboxMessage> = messages
+            .iter()
+            .zip(&evict)
+            .filter(|(_, evicted)| **evicted)
+            .map(|(message, _)| message.clone())
+            .collect();
+        if to_remove.is_empty() {
+            return false;
+        }
+        if let Some(archive_dir) = &archive_dir
+            && let Err(e) = archive_messages(&to_remove, team, agent, archive_dir)
+        {
+            // Leave the inbox untouched rather than drop unarchived messages
+            archive_error = Some(e);
+            return false;
+        }
+        removed = to_remove.len();
+        let mut evicted = evict.into_iter();
+        messages.retain(|_| !evicted.next().unwrap_or(false));
+        true
     })?;
+    if let Some(e) = archive_error {
+        return Err(e);
+    }
 
-    Ok(RetentionResult::new(
-        to_keep.len(),
-        to_remove.len(),
-        archived,
-    ))
+    let archived = if archive_dir.is_some() { removed } else { 0 };
+    Ok(RetentionResult {
+        evictions: planner.evictions,
+        ..RetentionResult::new(planner.kept, removed, archived)
+    })
 }
 
-/// Determine if a message should be removed based on retention policy
-fn should_remove_message(
-    message: &InboxMessage,
-    max_age_duration: &Option<Duration>,
+/// Running retention decision over an inbox in file order.
+struct EvictionPlanner<'a> {
+    global: &'a Caps,
+    per_type: &'a HashMap<&'a str, Caps>,
     now: DateTime<Utc>,
-    max_count: Option<usize>,
-    current_kept_count: usize,
-) -> bool {
-    // Check age-based policy
-    if let Some(max_age) = max_age_duration
-        && is_expired_by_age(message, max_age, now)
-    {
-        return true;
+    kept: usize,
+    kept_by_type: HashMap<String, usize>,
+    evictions: Vec<RetentionEviction>,
+}
+
+impl<'a> EvictionPlanner<'a> {
+    fn new(global: &'a Caps, per_type: &'a HashMap<&'a str, Caps>, now: DateTime<Utc>) -> Self {
+        Self {
+            global,
+            per_type,
+            now,
+            kept: 0,
+            kept_by_type: HashMap::new(),
+            evictions: Vec::new(),
+        }
     }
 
-    // Check count-based policy
-    // We keep the newest messages up to max_count
-    // Since messages are processed in order, once we've kept max_count messages,
-    // all subsequent messages should be removed
-    if let Some(max_count) = max_count
-        && current_kept_count >= max_count
-    {
-        return true;
+    /// Decide whether `message` is removed, recording the eviction or
+    /// counting it as kept. Messages must be fed in inbox order.
+    fn evict(&mut self, message: &InboxMessage) -> bool {
+        let message_type = message.notification_type().map(str::to_string);
+        let type_caps = message_type
+            .as_deref()
+            .and_then(|t| self.per_type.get(t).map(|caps| (t, caps)));
+        let kept_of_type = message_type
+            .as_ref()
+            .and_then(|t| self.kept_by_type.get(t).copied())
+            .unwrap_or(0);
+
+        match eviction_for(
+            message,
+            type_caps,
+            self.global,
+            self.now,
+            self.kept,
+            kept_of_type,
+        ) {
+            Some((policy, reason)) => {
+                self.evictions.push(RetentionEviction {
+                    message_id: message.message_id.clone(),
+                    message_type,
+                    policy,
+                    reason,
+                });
+                true
+            }
+            None => {
+                if let Some(t) = message_type {
+                    *self.kept_by_type.entry(t).or_insert(0) += 1;
+                }
+                self.kept += 1;
+                false
+            }
+        }
     }
+}
+
+/// Count the messages in an inbox without loading it whole.
+fn count_messages(inbox_path: &Path) -> Result<usize> {
+    let mut count = 0;
+    for message in inbox_iter(inbox_path)? {
+        message.with_context(|| format!("Failed to parse inbox at {}", inbox_path.display()))?;
+        count += 1;
+    }
+    Ok(count)
+}
 
-    false
+/// Determine whether a message should be removed, and by which policy.
+///
+/// A per-type cap, when set, replaces the corresponding global cap. The
+/// per-type count cap counts only kept messages of that type; the global count
+/// cap counts all kept messages.
+fn eviction_for(
+    message: &InboxMessage,
+    type_caps: Option<(&str, &Caps)>,
+    global: &Caps,
+    now: DateTime<Utc>,
+    kept_total: usize,
+    kept_of_type: usize,
+) -> Option<(RetentionPolicySource, EvictionReason)> {
+    let per_type = |t: &str| RetentionPolicySource::PerType(t.to_string());
+
+    // Age: the type override wins over the global cap
+    let age_cap = match type_caps {
+        Some((t, caps)) if caps.max_age.is_some() => caps.max_age.map(|age| (per_type(t), age)),
+        _ => global
+            .max_age
+            .map(|age| (RetentionPolicySource::Global, age)),
+    };
+    if let Some((source, max_age)) = age_cap
+        && is_expired_by_age(message, &max_age, now)
+    {
+        return Some((source, EvictionReason::MaxAge));
+    }
+
+    // Count: we keep the first messages up to max_count. Since messages are
+    // processed in order, once max_count messages are kept all subsequent
+    // messages in scope are removed.
+    match type_caps {
+        Some((t, caps)) if caps.max_count.is_some() => caps
+            .max_count
+            .filter(|max| kept_of_type >= *max)
+            .map(|_| (per_type(t), EvictionReason::MaxCount)),
+        _ => global
+            .max_count
+            .filter(|max| kept_total >= *max)
+            .map(|_| (RetentionPolicySource::Global, EvictionReason::MaxCount)),
+    }
 }
 
 /// Check if a message exceeds the maximum age policy
@@ -217,7 +388,7 @@ pub fn parse_duration(s: &str) -> Result<Duration> {
 }
 
 /// Determine the archive directory from config or use default
-fn determine_archive_dir(policy: &RetentionConfig) -> Result<PathBuf> {
+pub fn determine_archive_dir(policy: &RetentionConfig) -> Result<PathBuf> {
     if let Some(ref dir_str) = policy.archive_dir {
         Ok(PathBuf::from(dir_str))
     } else {
@@ -400,29 +571,13 @@ mod tests {
         let max_age = Duration::days(7);
 
         // Message from 10 days ago (expired)
-        let old_message = InboxMessage {
-            from: "test".to_string(),
-            source_team: None,
-            text: "old message".to_string(),
-            timestamp: (now - Duration::days(10)).to_rfc3339(),
-            read: false,
-            summary: None,
-            message_id: None,
-            unknown_fields: std::collections::HashMap::new(),
-        };
+        let old_message = InboxMessage::new("test", "old message")
+            .timestamp((now - Duration::days(10)).to_rfc3339());
         assert!(is_expired_by_age(&old_message, &max_age, now));
 
         // Message from 3 days ago (not expired)
-        let recent_message = InboxMessage {
-            from: "test".to_string(),
-            source_team: None,
-            text: "recent message".to_string(),
-            timestamp: (now - Duration::days(3)).to_rfc3339(),
-            read: false,
-            summary: None,
-            message_id: None,
-            unknown_fields: std::collections::HashMap::new(),
-        };
+        let recent_message = InboxMessage::new("test", "recent message")
+            .timestamp((now - Duration::days(3)).to_rfc3339());
         assert!(!is_expired_by_age(&recent_message, &max_age, now));
     }
 
@@ -655,6 +810,8 @@ mod tests {
             archive_dir: None,
             enabled: true,
             interval_secs: 300,
+            auto_cleanup_cron: None,
+            per_type: HashMap::new(),
         };
 
         let result = apply_retention(&inbox_path, "test-team", "agent", &policy, false).unwrap();
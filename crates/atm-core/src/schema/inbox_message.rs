@@ -10,6 +10,7 @@ pub const IDLE_NOTIFICATION_TYPE: &str = "idle_notification";
 /// Messages are stored in `~/.claude/teams/{team_name}/inboxes/{agent_name}.json`
 /// as an array of InboxMessage objects.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "InboxMessageRecord")]
 pub struct InboxMessage {
     /// Sender agent name or 'team-lead'
     pub from: String,
@@ -38,12 +39,155 @@ pub struct InboxMessage {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub message_id: Option<String>,
 
+    /// Delivery priority; higher values are read first by priority-aware
+    /// readers. Absent means normal priority (`0`). A `priority` written by
+    /// another tool that is not a `0..=255` integer reads as `None` and is
+    /// kept verbatim in `unknown_fields`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+
     /// Unknown fields for forward compatibility
     #[serde(flatten)]
     pub unknown_fields: HashMap<String, serde_json::Value>,
 }
 
+/// On-disk shape of [`InboxMessage`] with `priority` left untyped, so one
+/// unexpected value cannot make the whole record unreadable.
+#[derive(Deserialize)]
+struct InboxMessageRecord {
+    from: String,
+    #[serde(default)]
+    source_team: Option<String>,
+    #[serde(alias = "content")]
+    text: String,
+    timestamp: String,
+    #[serde(default)]
+    read: bool,
+    #[serde(default)]
+    summary: Option<String>,
+    #[serde(default)]
+    message_id: Option<String>,
+    #[serde(default)]
+    priority: Option<serde_json::Value>,
+    #[serde(flatten)]
+    unknown_fields: HashMap<String, serde_json::Value>,
+}
+
+impl From<InboxMessageRecord> for InboxMessage {
+    fn from(record: InboxMessageRecord) -> Self {
+        let mut unknown_fields = record.unknown_fields;
+        let priority = match record.priority {
+            None | Some(serde_json::Value::Null) => None,
+            Some(raw) => match raw.as_u64().and_then(|p| u8::try_from(p).ok()) {
+                Some(priority) => Some(priority),
+                None => {
+                    unknown_fields.insert("priority".to_string(), raw);
+                    None
+                }
+            },
+        };
+        Self {
+            from: record.from,
+            source_team: record.source_team,
+            text: record.text,
+            timestamp: record.timestamp,
+            read: record.read,
+            summary: record.summary,
+            message_id: record.message_id,
+            priority,
+            unknown_fields,
+        }
+    }
+}
+
 impl InboxMessage {
+    /// Unread message from `from` stamped with the current UTC time.
+    ///
+    /// Optional fields start empty and are filled with the chainable
+    /// setters, so call sites stay valid when fields are added.
+    ///
+    /// ```
+    /// use agent_team_mail_core::InboxMessage;
+    ///
+    /// let msg = InboxMessage::new("team-lead", "CI is green")
+    ///     .summary("CI green")
+    ///     .priority(5)
+    ///     .message_id("msg-1");
+    /// assert!(!msg.read);
+    /// assert_eq!(msg.message_id.as_deref(), Some("msg-1"));
+    /// assert!(msg.unknown_fields.is_empty());
+    /// ```
+    pub fn new(from: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            from: from.into(),
+            source_team: None,
+            text: text.into(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            read: false,
+            summary: None,
+            message_id: None,
+            priority: None,
+            unknown_fields: HashMap::new(),
+        }
+    }
+
+    pub fn source_team(mut self, source_team: impl Into<String>) -> Self {
+        self.source_team = Some(source_team.into());
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.timestamp = timestamp.into();
+        self
+    }
+
+    pub fn read(mut self, read: bool) -> Self {
+        self.read = read;
+        self
+    }
+
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    pub fn message_id(mut self, message_id: impl Into<String>) -> Self {
+        self.message_id = Some(message_id.into());
+        self
+    }
+
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.unknown_fields.remove("priority");
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Tag the message; stored as the `labels` array.
+    pub fn labels<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let labels: Vec<String> = labels.into_iter().map(Into::into).collect();
+        self.unknown_fields
+            .insert("labels".to_string(), serde_json::json!(labels));
+        self
+    }
+
+    /// Mark the message as a reply to `message_id`; stored as `replyTo`.
+    pub fn reply_to(mut self, message_id: impl Into<String>) -> Self {
+        self.unknown_fields.insert(
+            "replyTo".to_string(),
+            serde_json::Value::String(message_id.into()),
+        );
+        self
+    }
+
+    /// Priority used for ordering; messages without one rank as `0`.
+    pub fn effective_priority(&self) -> u8 {
+        self.priority.unwrap_or(0)
+    }
+
     pub fn pending_ack_at(&self) -> Option<&str> {
         self.unknown_fields
             .get("pendingAckAt")
@@ -111,6 +255,42 @@ impl InboxMessage {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_inbox_message_new_fills_defaults() {
+        let msg = InboxMessage::new("team-lead", "hello")
+            .source_team("src-gen")
+            .timestamp("2026-02-11T14:30:00Z")
+            .summary("hi")
+            .message_id("msg-1")
+            .read(true);
+
+        assert_eq!(msg.from, "team-lead");
+        assert_eq!(msg.text, "hello");
+        assert_eq!(msg.source_team.as_deref(), Some("src-gen"));
+        assert_eq!(msg.timestamp, "2026-02-11T14:30:00Z");
+        assert_eq!(msg.summary.as_deref(), Some("hi"));
+        assert_eq!(msg.message_id.as_deref(), Some("msg-1"));
+        assert!(msg.read);
+        assert!(msg.unknown_fields.is_empty());
+
+        let fresh = InboxMessage::new("a", "b");
+        assert!(!fresh.read && fresh.summary.is_none() && fresh.message_id.is_none());
+        assert!(chrono::DateTime::parse_from_rfc3339(&fresh.timestamp).is_ok());
+    }
+
+    #[test]
+    fn test_inbox_message_labels_and_reply_to_serialize() {
+        let msg = InboxMessage::new("team-lead", "re: build")
+            .priority(3)
+            .labels(["ci", "urgent"])
+            .reply_to("msg-0");
+
+        let value = serde_json::to_value(&msg).unwrap();
+        assert_eq!(value["priority"], 3);
+        assert_eq!(value["labels"], serde_json::json!(["ci", "urgent"]));
+        assert_eq!(value["replyTo"], "msg-0");
+    }
+
     #[test]
     fn test_inbox_message_roundtrip_minimal() {
         let json = r#"{
@@ -204,6 +384,68 @@ mod tests {
         assert_eq!(reparsed.source_team.as_deref(), Some("src-gen"));
     }
 
+    #[test]
+    fn test_inbox_message_roundtrip_with_priority_and_unknown_fields() {
+        let json = r#"{
+            "from": "team-lead",
+            "text": "Prod is down",
+            "timestamp": "2026-02-11T14:30:00.000Z",
+            "read": false,
+            "priority": 9,
+            "futureFeature": true
+        }"#;
+
+        let msg: InboxMessage = serde_json::from_str(json).unwrap();
+        assert_eq!(msg.priority, Some(9));
+        assert_eq!(msg.effective_priority(), 9);
+        assert_eq!(msg.unknown_fields.len(), 1);
+
+        let serialized = serde_json::to_string(&msg).unwrap();
+        let reparsed: InboxMessage = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(reparsed.priority, Some(9));
+        assert_eq!(
+            reparsed.unknown_fields.get("futureFeature"),
+            Some(&serde_json::Value::Bool(true))
+        );
+
+        // Messages without a priority serialize without the key.
+        let plain = InboxMessage::new("a", "b");
+        assert_eq!(plain.effective_priority(), 0);
+        assert!(!serde_json::to_string(&plain).unwrap().contains("priority"));
+    }
+
+    #[test]
+    fn test_inbox_message_keeps_foreign_priority_verbatim() {
+        let json = r#"[
+            {"from": "a", "text": "x", "timestamp": "2026-02-11T14:30:00Z", "priority": "high"},
+            {"from": "b", "text": "y", "timestamp": "2026-02-11T14:30:00Z", "priority": 300},
+            {"from": "c", "text": "z", "timestamp": "2026-02-11T14:30:00Z", "priority": null}
+        ]"#;
+
+        let messages: Vec<InboxMessage> = serde_json::from_str(json).unwrap();
+        assert!(messages.iter().all(|m| m.priority.is_none()));
+        assert_eq!(messages[0].effective_priority(), 0);
+        assert_eq!(messages[0].unknown_fields["priority"], "high");
+        assert_eq!(messages[1].unknown_fields["priority"], 300);
+        assert!(messages[2].unknown_fields.is_empty());
+
+        let value = serde_json::to_value(&messages).unwrap();
+        assert_eq!(value[0]["priority"], "high");
+        assert_eq!(value[1]["priority"], 300);
+        assert!(value[2].get("priority").is_none());
+
+        // Setting a typed priority replaces the foreign value.
+        let reset = messages[0].clone().priority(2);
+        let serialized = serde_json::to_string(&reset).unwrap();
+        assert_eq!(serialized.matches("\"priority\"").count(), 1);
+        assert_eq!(
+            serde_json::from_str::<InboxMessage>(&serialized)
+                .unwrap()
+                .priority,
+            Some(2)
+        );
+    }
+
     #[test]
     fn test_inbox_message_accepts_content_alias_and_missing_read() {
         let json = r#"{
@@ -268,16 +510,10 @@ mod tests {
 
     #[test]
     fn test_mark_acknowledged_sets_unknown_field() {
-        let mut msg = InboxMessage {
-            from: "team-lead".to_string(),
-            source_team: None,
-            text: "Task assigned".to_string(),
-            timestamp: "2026-02-11T14:30:00.000Z".to_string(),
-            read: true,
-            summary: None,
-            message_id: Some("msg-1".to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let mut msg = InboxMessage::new("team-lead", "Task assigned")
+            .timestamp("2026-02-11T14:30:00.000Z")
+            .read(true)
+            .message_id("msg-1");
 
         msg.mark_pending_ack("2026-02-11T14:30:30.000Z");
         msg.mark_acknowledged("2026-02-11T14:31:00.000Z");
@@ -287,16 +523,10 @@ mod tests {
 
     #[test]
     fn test_idle_notification_helpers_roundtrip() {
-        let mut msg = InboxMessage {
-            from: "daemon".to_string(),
-            source_team: None,
-            text: "[AGENT STATE] arch-ctm is now idle".to_string(),
-            timestamp: "2026-02-11T14:30:00.000Z".to_string(),
-            read: false,
-            summary: Some("Agent arch-ctm → idle".to_string()),
-            message_id: Some("msg-1".to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let mut msg = InboxMessage::new("daemon", "[AGENT STATE] arch-ctm is now idle")
+            .timestamp("2026-02-11T14:30:00.000Z")
+            .summary("Agent arch-ctm → idle")
+            .message_id("msg-1");
 
         msg.mark_idle_notification("arch-ctm");
 
@@ -16,5 +16,5 @@ pub use inbox_message::InboxMessage;
 pub use permissions::Permissions;
 pub use settings::SettingsJson;
 pub use task::{TaskItem, TaskStatus};
-pub use team_config::TeamConfig;
+pub use team_config::{GroupError, TeamConfig, display_name_for};
 pub use version::SchemaVersion;
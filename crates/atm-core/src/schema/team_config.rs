@@ -1,8 +1,9 @@
 //! Team configuration schema
 
 use super::AgentMember;
+use crate::text::suggest_similar;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Team configuration
 ///
@@ -29,11 +30,154 @@ pub struct TeamConfig {
     /// Array of team members (includes team lead as first member)
     pub members: Vec<AgentMember>,
 
+    /// Named recipient groups: group name → member names
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub groups: BTreeMap<String, Vec<String>>,
+
+    /// Friendly names for sender identities: identity → display name.
+    ///
+    /// Display-only; stored `from` values are never rewritten.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub display_names: BTreeMap<String, String>,
+
     /// Unknown fields for forward compatibility
     #[serde(flatten)]
     pub unknown_fields: HashMap<String, serde_json::Value>,
 }
 
+/// Why [`TeamConfig::expand_group`] could not expand a group name.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GroupError {
+    /// No group has this name; `suggestions` are similarly named groups.
+    #[error(
+        "Group '{group}' not found in team '{team}'{}",
+        did_you_mean(suggestions)
+    )]
+    NotFound {
+        group: String,
+        team: String,
+        suggestions: Vec<String>,
+    },
+
+    /// The group lists a name that is not on the team roster.
+    #[error("Group '{group}' in team '{team}' lists '{member}', which is not a team member")]
+    UnknownMember {
+        group: String,
+        team: String,
+        member: String,
+    },
+}
+
+fn did_you_mean(suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        String::new()
+    } else {
+        format!(" (did you mean: {}?)", suggestions.join(", "))
+    }
+}
+
+/// Look up `identity` in the [`TeamConfig::display_names`] map of `team`,
+/// falling back to the identity itself. See [`TeamConfig::display_name`].
+pub fn display_name_for<'a>(
+    display_names: &'a BTreeMap<String, String>,
+    team: &str,
+    identity: &'a str,
+) -> &'a str {
+    display_names
+        .get(identity)
+        .or_else(|| {
+            identity
+                .split_once('@')
+                .filter(|(_, sender_team)| *sender_team == team)
+                .and_then(|(name, _)| display_names.get(name))
+        })
+        .map_or(identity, String::as_str)
+}
+
+impl TeamConfig {
+    /// Members listed in the named recipient group, if the group exists.
+    pub fn group_members(&self, group: &str) -> Option<&[String]> {
+        self.groups.get(group).map(Vec::as_slice)
+    }
+
+    /// Members of the named group, checked against the roster.
+    ///
+    /// Fails when no such group exists or when it lists a name that is not a
+    /// team member.
+    pub fn expand_group(&self, group: &str) -> Result<&[String], GroupError> {
+        let Some(members) = self.group_members(group) else {
+            return Err(GroupError::NotFound {
+                group: group.to_string(),
+                team: self.name.clone(),
+                suggestions: suggest_similar(group, self.groups.keys().map(String::as_str))
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+            });
+        };
+        if let Some(unknown) = members
+            .iter()
+            .find(|name| !self.members.iter().any(|m| &m.name == *name))
+        {
+            return Err(GroupError::UnknownMember {
+                group: group.to_string(),
+                team: self.name.clone(),
+                member: unknown.clone(),
+            });
+        }
+        Ok(members)
+    }
+
+    /// Display name for a sender identity, or the identity itself when unmapped.
+    ///
+    /// An exact match wins; otherwise a `name@<this team>` identity falls back
+    /// to the mapping for its bare `name`. Senders from other teams are only
+    /// renamed by an exact `name@team` entry.
+    pub fn display_name<'a>(&'a self, identity: &'a str) -> &'a str {
+        display_name_for(&self.display_names, &self.name, identity)
+    }
+
+    /// Roster and group names that look like a misspelling of `name`.
+    pub fn suggest_recipients(&self, name: &str) -> Vec<&str> {
+        suggest_similar(
+            name,
+            self.members
+                .iter()
+                .map(|m| m.name.as_str())
+                .chain(self.groups.keys().map(String::as_str)),
+        )
+    }
+
+    /// Rename member `old` to `new`, updating its `agentId`, the lead agent
+    /// id, and every group listing it.
+    ///
+    /// Returns `false` (leaving the config untouched) when `old` is not a
+    /// member or `new` is already taken.
+    pub fn rename_member(&mut self, old: &str, new: &str) -> bool {
+        if self.members.iter().any(|m| m.name == new) {
+            return false;
+        }
+        let Some(member) = self.members.iter_mut().find(|m| m.name == old) else {
+            return false;
+        };
+        let old_agent_id = format!("{old}@{}", self.name);
+        let new_agent_id = format!("{new}@{}", self.name);
+        member.name = new.to_string();
+        if member.agent_id == old_agent_id {
+            member.agent_id = new_agent_id.clone();
+        }
+        if self.lead_agent_id == old_agent_id {
+            self.lead_agent_id = new_agent_id;
+        }
+        for names in self.groups.values_mut() {
+            for name in names.iter_mut().filter(|n| n.as_str() == old) {
+                *name = new.to_string();
+            }
+        }
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,4 +371,111 @@ mod tests {
         assert_eq!(config.name, reparsed.name);
         assert_eq!(config.members.len(), reparsed.members.len());
     }
+
+    #[test]
+    fn test_team_config_groups_roundtrip() {
+        let json = r#"{
+            "name": "test-team",
+            "createdAt": 1770765919076,
+            "leadAgentId": "team-lead@test-team",
+            "leadSessionId": "",
+            "members": [],
+            "groups": {"reviewers": ["arch-ctm", "qa-bot"]}
+        }"#;
+
+        let config: TeamConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.group_members("reviewers"),
+            Some(&["arch-ctm".to_string(), "qa-bot".to_string()][..])
+        );
+        assert!(config.group_members("devs").is_none());
+        assert_eq!(config.suggest_recipients("reviewer"), vec!["reviewers"]);
+
+        // No roster members, so the group lists unknown names.
+        assert_eq!(
+            config.expand_group("reviewers").unwrap_err().to_string(),
+            "Group 'reviewers' in team 'test-team' lists 'arch-ctm', which is not a team member"
+        );
+        assert_eq!(
+            config.expand_group("reviewer").unwrap_err().to_string(),
+            "Group 'reviewer' not found in team 'test-team' (did you mean: reviewers?)"
+        );
+        assert!(!config.unknown_fields.contains_key("groups"));
+
+        let serialized = serde_json::to_string(&config).unwrap();
+        assert!(serialized.contains("\"groups\""));
+
+        let ungrouped: TeamConfig = serde_json::from_str(
+            &serialized.replace(r#","groups":{"reviewers":["arch-ctm","qa-bot"]}"#, ""),
+        )
+        .unwrap();
+        assert!(ungrouped.groups.is_empty());
+        assert!(
+            !serde_json::to_string(&ungrouped)
+                .unwrap()
+                .contains("groups")
+        );
+    }
+
+    #[test]
+    fn test_team_config_display_names() {
+        let json = r#"{
+            "name": "test-team",
+            "createdAt": 1770765919076,
+            "leadAgentId": "team-lead@test-team",
+            "leadSessionId": "",
+            "members": [],
+            "displayNames": {"codex:abc": "Ada (Codex)", "arch-ctm": "Architect"}
+        }"#;
+
+        let config: TeamConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.display_name("codex:abc"), "Ada (Codex)");
+        assert_eq!(config.display_name("arch-ctm@test-team"), "Architect");
+        assert_eq!(
+            config.display_name("arch-ctm@other-team"),
+            "arch-ctm@other-team"
+        );
+        assert_eq!(config.display_name("qa-bot"), "qa-bot");
+        assert!(!config.unknown_fields.contains_key("displayNames"));
+        assert!(
+            serde_json::to_string(&config)
+                .unwrap()
+                .contains("\"displayNames\"")
+        );
+    }
+
+    #[test]
+    fn test_rename_member_updates_agent_id_and_groups() {
+        let json = r#"{
+            "name": "test-team",
+            "createdAt": 1770765919076,
+            "leadAgentId": "team-lead@test-team",
+            "leadSessionId": "",
+            "members": [
+                {"agentId": "team-lead@test-team", "name": "team-lead", "agentType": "general-purpose",
+                 "model": "unknown", "joinedAt": 0, "cwd": ".", "subscriptions": []},
+                {"agentId": "arch-ctm@test-team", "name": "arch-ctm", "agentType": "codex",
+                 "model": "unknown", "joinedAt": 0, "cwd": ".", "subscriptions": []}
+            ],
+            "groups": {"reviewers": ["arch-ctm", "qa-bot"]}
+        }"#;
+
+        let mut config: TeamConfig = serde_json::from_str(json).unwrap();
+        assert!(!config.rename_member("missing", "other"));
+        assert!(!config.rename_member("arch-ctm", "team-lead"));
+        assert!(config.rename_member("arch-ctm", "arch-lead"));
+
+        let member = config
+            .members
+            .iter()
+            .find(|m| m.name == "arch-lead")
+            .unwrap();
+        assert_eq!(member.agent_id, "arch-lead@test-team");
+        assert!(config.members.iter().all(|m| m.name != "arch-ctm"));
+        assert_eq!(
+            config.group_members("reviewers"),
+            Some(&["arch-lead".to_string(), "qa-bot".to_string()][..])
+        );
+        assert_eq!(config.lead_agent_id, "team-lead@test-team");
+    }
 }
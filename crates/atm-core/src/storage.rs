@@ -0,0 +1,257 @@
+//! Per-team storage footprint
+//!
+//! Sums the on-disk bytes ATM keeps for one team so an operator can tell
+//! which team is filling a disk. Covered locations:
+//!
+//! ```text
+//! ~/.claude/teams/<team>/inboxes/        - inbox
+//! ~/.claude/teams/<team>/...             - team (config and other team files)
+//! ~/.claude/teams/.archives/<team>/      - archive (removed-member inboxes)
+//! {retention archive_dir}/<team>/        - archive (retention archives)
+//! ~/.claude/teams/.backups/<team>/       - backup
+//! {spool_dir}/{pending,failed}/*.json    - spool (entries targeting <team>)
+//! ```
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Kind of ATM storage a file belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageCategory {
+    /// Live inbox files under `inboxes/`
+    Inbox,
+    /// Team config and any other file in the team directory
+    Team,
+    /// Retention archives and removed-member inbox archives
+    Archive,
+    /// `atm teams backup` snapshots
+    Backup,
+    /// Spooled messages awaiting (or abandoned after) delivery
+    Spool,
+}
+
+impl StorageCategory {
+    /// Lowercase label used in CLI output
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Inbox => "inbox",
+            Self::Team => "team",
+            Self::Archive => "archive",
+            Self::Backup => "backup",
+            Self::Spool => "spool",
+        }
+    }
+}
+
+/// Size of a single file counted toward a team's footprint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileUsage {
+    /// Absolute path of the file
+    pub path: PathBuf,
+    /// Storage kind the file was counted under
+    pub category: StorageCategory,
+    /// File size in bytes
+    pub bytes: u64,
+}
+
+/// Total storage held by one team, with a per-file breakdown
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageUsage {
+    /// Sum of all file sizes in bytes
+    pub total_bytes: u64,
+    /// Every counted file, largest first
+    pub files: Vec<FileUsage>,
+}
+
+impl StorageUsage {
+    /// Bytes per category (categories with no files are omitted)
+    pub fn by_category(&self) -> BTreeMap<StorageCategory, u64> {
+        let mut totals = BTreeMap::new();
+        for file in &self.files {
+            *totals.entry(file.category).or_insert(0) += file.bytes;
+        }
+        totals
+    }
+}
+
+/// Compute the storage footprint of the team rooted at `team_dir` using
+/// explicit retention archive and spool directories.
+///
+/// Callers resolve `retention_archive_dir` from their own retention config
+/// (`determine_archive_dir`) so a configured `archive_dir` is honored.
+///
+/// The team name is the final component of `team_dir`; `.archives/` and
+/// `.backups/` are looked up next to it. Missing directories count as empty.
+pub fn team_storage_usage_with_dirs(
+    team_dir: &Path,
+    retention_archive_dir: &Path,
+    spool_dir: &Path,
+) -> Result<StorageUsage> {
+    let team = team_dir
+        .file_name()
+        .with_context(|| format!("Invalid team directory: {}", team_dir.display()))?;
+    let teams_root = team_dir.parent().unwrap_or(Path::new(""));
+
+    let mut files = Vec::new();
+    let inboxes_dir = team_dir.join("inboxes");
+    for (path, bytes) in walk_files(team_dir)? {
+        let category = if path.starts_with(&inboxes_dir) {
+            StorageCategory::Inbox
+        } else {
+            StorageCategory::Team
+        };
+        files.push(FileUsage {
+            path,
+            category,
+            bytes,
+        });
+    }
+
+    let archive_roots = [
+        teams_root.join(".archives").join(team),
+        retention_archive_dir.join(team),
+    ];
+    for root in &archive_roots {
+        for (path, bytes) in walk_files(root)? {
+            files.push(FileUsage {
+                path,
+                category: StorageCategory::Archive,
+                bytes,
+            });
+        }
+    }
+
+    for (path, bytes) in walk_files(&teams_root.join(".backups").join(team))? {
+        files.push(FileUsage {
+            path,
+            category: StorageCategory::Backup,
+            bytes,
+        });
+    }
+
+    let team = team.to_string_lossy();
+    for subdir in ["pending", "failed"] {
+        for (path, bytes) in walk_files(&spool_dir.join(subdir))? {
+            if spool_targets_team(&path, &team) {
+                files.push(FileUsage {
+                    path,
+                    category: StorageCategory::Spool,
+                    bytes,
+                });
+            }
+        }
+    }
+
+    files.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.path.cmp(&b.path)));
+    Ok(StorageUsage {
+        total_bytes: files.iter().map(|f| f.bytes).sum(),
+        files,
+    })
+}
+
+/// Recursively list regular files under `dir` with their sizes.
+///
+/// Symlinks are not followed. A missing `dir` yields no files.
+fn walk_files(dir: &Path) -> Result<Vec<(PathBuf, u64)>> {
+    let mut found = Vec::new();
+    if !dir.is_dir() {
+        return Ok(found);
+    }
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        let entries = fs::read_dir(&current)
+            .with_context(|| format!("Failed to read directory: {}", current.display()))?;
+        for entry in entries {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else if metadata.is_file() {
+                found.push((entry.path(), metadata.len()));
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// Whether the spool file at `path` is addressed to `team`.
+fn spool_targets_team(path: &Path, team: &str) -> bool {
+    fs::read(path)
+        .ok()
+        .and_then(|raw| serde_json::from_slice::<serde_json::Value>(&raw).ok())
+        .is_some_and(|value| value.get("target_team").and_then(|t| t.as_str()) == Some(team))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, bytes: usize) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![b'x'; bytes]).unwrap();
+    }
+
+    #[test]
+    fn test_team_storage_usage_sums_each_category() {
+        let temp = TempDir::new().unwrap();
+        let teams_root = temp.path().join("teams");
+        let team_dir = teams_root.join("alpha");
+        let archive_dir = temp.path().join("archive");
+        let spool_dir = temp.path().join("spool");
+
+        write(&team_dir.join("config.json"), 10);
+        write(&team_dir.join("inboxes/a.json"), 100);
+        write(&team_dir.join("inboxes/b.json"), 50);
+        write(
+            &teams_root.join(".archives/alpha/removed-a/inboxes/a.json"),
+            7,
+        );
+        write(&archive_dir.join("alpha/a/archive-1.json"), 20);
+        write(
+            &teams_root.join(".backups/alpha/20260101T000000Z/config.json"),
+            5,
+        );
+        // Another team's files must not be counted.
+        write(&teams_root.join("beta/inboxes/a.json"), 1000);
+        write(&archive_dir.join("beta/a/archive-1.json"), 1000);
+
+        fs::create_dir_all(spool_dir.join("pending")).unwrap();
+        fs::create_dir_all(spool_dir.join("failed")).unwrap();
+        let ours = r#"{"target_team":"alpha","target_agent":"a"}"#;
+        let theirs = r#"{"target_team":"beta","target_agent":"a"}"#;
+        fs::write(spool_dir.join("pending/1.json"), ours).unwrap();
+        fs::write(spool_dir.join("failed/2.json"), theirs).unwrap();
+
+        let usage = team_storage_usage_with_dirs(&team_dir, &archive_dir, &spool_dir).unwrap();
+
+        let by_category = usage.by_category();
+        assert_eq!(by_category[&StorageCategory::Inbox], 150);
+        assert_eq!(by_category[&StorageCategory::Team], 10);
+        assert_eq!(by_category[&StorageCategory::Archive], 27);
+        assert_eq!(by_category[&StorageCategory::Backup], 5);
+        assert_eq!(by_category[&StorageCategory::Spool], ours.len() as u64);
+        assert_eq!(usage.total_bytes, 192 + ours.len() as u64);
+        assert_eq!(usage.files.len(), 7);
+        assert_eq!(usage.files[0].path, team_dir.join("inboxes/a.json"));
+    }
+
+    #[test]
+    fn test_team_storage_usage_missing_directories_are_empty() {
+        let temp = TempDir::new().unwrap();
+        let team_dir = temp.path().join("teams/alpha");
+        let usage = team_storage_usage_with_dirs(
+            &team_dir,
+            &temp.path().join("archive"),
+            &temp.path().join("spool"),
+        )
+        .unwrap();
+        assert_eq!(usage, StorageUsage::default());
+    }
+}
@@ -178,6 +178,8 @@ mod tests {
             created_at: 1,
             lead_agent_id: format!("team-lead@{team}"),
             lead_session_id: String::new(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: members
                 .iter()
                 .map(|name| AgentMember {
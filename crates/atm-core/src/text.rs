@@ -26,6 +26,23 @@ pub fn truncate_chars_slice(text: &str, max_chars: usize) -> &str {
     }
 }
 
+/// Truncate `text` to at most `max_bytes` bytes including `suffix`,
+/// cutting on a char boundary. Returns `None` when `text` already fits.
+///
+/// Uses `is_char_boundary` — compatible with MSRV 1.85.
+pub fn truncate_bytes(text: &str, max_bytes: usize, suffix: &str) -> Option<String> {
+    if text.len() <= max_bytes {
+        return None;
+    }
+    let mut end = max_bytes.saturating_sub(suffix.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let mut result = text[..end].to_string();
+    result.push_str(suffix);
+    Some(result)
+}
+
 /// Validate message text before delivery.
 ///
 /// Returns `Err` with a user-facing error message when:
@@ -55,6 +72,47 @@ pub fn validate_message_text(text: &str, max_bytes: usize) -> Result<(), String>
 /// Default maximum message size in bytes (1 MiB).
 pub const DEFAULT_MAX_MESSAGE_BYTES: usize = 1_048_576;
 
+/// Return the candidates that look like a misspelling of `input`.
+///
+/// A candidate matches when it shares a prefix with `input` or is within a
+/// small edit distance (one edit per three characters, at least one).
+/// Matches are returned closest first, ties in candidate order.
+pub fn suggest_similar<'a>(
+    input: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let needle = input.to_lowercase();
+    let max_distance = (needle.chars().count() / 3).max(1);
+    let mut scored: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let lower = candidate.to_lowercase();
+            let distance = edit_distance(&needle, &lower);
+            let prefix =
+                !needle.is_empty() && (lower.starts_with(&needle) || needle.starts_with(&lower));
+            (distance <= max_distance || prefix).then_some((distance, candidate))
+        })
+        .collect();
+    scored.sort_by_key(|(distance, _)| *distance);
+    scored.dedup_by(|a, b| a.1 == b.1);
+    scored.into_iter().map(|(_, candidate)| candidate).collect()
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,6 +196,27 @@ mod tests {
         assert_eq!(truncate_chars_slice("", 10), "");
     }
 
+    // truncate_bytes tests
+
+    #[test]
+    fn truncate_bytes_fits_returns_none() {
+        assert_eq!(truncate_bytes("hello", 5, "..."), None);
+    }
+
+    #[test]
+    fn truncate_bytes_includes_suffix_in_budget() {
+        assert_eq!(
+            truncate_bytes("hello world", 8, "...").as_deref(),
+            Some("hello...")
+        );
+    }
+
+    #[test]
+    fn truncate_bytes_multibyte_boundary() {
+        // each CJK char is 3 bytes; 7 bytes leaves room for two chars only
+        assert_eq!(truncate_bytes("中文测试", 7, "").as_deref(), Some("中文"));
+    }
+
     // validate_message_text tests
 
     #[test]
@@ -170,4 +249,21 @@ mod tests {
     fn validate_empty_ok() {
         assert!(validate_message_text("", DEFAULT_MAX_MESSAGE_BYTES).is_ok());
     }
+
+    // suggest_similar tests
+
+    #[test]
+    fn suggest_similar_finds_typos_and_prefixes() {
+        let candidates = ["reviewers", "release", "arch-ctm"];
+        assert_eq!(suggest_similar("reviewer", candidates), vec!["reviewers"]);
+        assert_eq!(suggest_similar("reveiwers", candidates), vec!["reviewers"]);
+        assert_eq!(suggest_similar("arch", candidates), vec!["arch-ctm"]);
+        assert!(suggest_similar("qa", candidates).is_empty());
+    }
+
+    #[test]
+    fn suggest_similar_orders_closest_first() {
+        let candidates = ["devs", "dev"];
+        assert_eq!(suggest_similar("dev", candidates), vec!["dev", "devs"]);
+    }
 }
@@ -1,7 +1,7 @@
 //! Integration tests for retention policy implementation
 
-use agent_team_mail_core::config::{CleanupStrategy, RetentionConfig};
-use agent_team_mail_core::retention::apply_retention;
+use agent_team_mail_core::config::{CleanupStrategy, RetentionConfig, TypeRetentionPolicy};
+use agent_team_mail_core::retention::{EvictionReason, RetentionPolicySource, apply_retention};
 use agent_team_mail_core::schema::InboxMessage;
 use chrono::{Duration, Utc};
 use std::collections::HashMap;
@@ -17,14 +17,8 @@ fn create_test_message(
 ) -> InboxMessage {
     let timestamp = (Utc::now() - Duration::days(days_ago)).to_rfc3339();
     InboxMessage {
-        from: from.to_string(),
-        source_team: None,
-        text: text.to_string(),
-        timestamp,
-        read: false,
-        summary: None,
         message_id,
-        unknown_fields: HashMap::new(),
+        ..InboxMessage::new(from, text).timestamp(timestamp)
     }
 }
 
@@ -64,6 +58,8 @@ fn test_retention_by_max_age() {
         archive_dir: None,
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
@@ -115,6 +111,8 @@ fn test_retention_by_max_count() {
         archive_dir: None,
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
@@ -163,6 +161,8 @@ fn test_retention_combined_age_and_count() {
         archive_dir: None,
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
@@ -209,6 +209,8 @@ fn test_archive_strategy() {
         archive_dir: Some(archive_dir.to_str().unwrap().to_string()),
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
@@ -247,6 +249,76 @@ fn test_archive_strategy() {
     }
 }
 
+#[test]
+fn test_archive_failure_leaves_inbox_untouched() {
+    let temp_dir = TempDir::new().unwrap();
+    let inbox_path = temp_dir.path().join("agent.json");
+    // A regular file where the archive directory should be makes archiving fail
+    let archive_dir = temp_dir.path().join("archive");
+    fs::write(&archive_dir, "not a directory").unwrap();
+
+    let messages = vec![
+        create_test_message("user1", "Old message", 10, Some("msg-001".to_string())),
+        create_test_message("user2", "Recent message", 1, Some("msg-002".to_string())),
+    ];
+    write_inbox(&inbox_path, &messages);
+
+    let policy = RetentionConfig {
+        max_age: Some("7d".to_string()),
+        max_count: None,
+        strategy: CleanupStrategy::Archive,
+        archive_dir: Some(archive_dir.to_string_lossy().to_string()),
+        enabled: false,
+        interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
+    };
+
+    assert!(apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).is_err());
+
+    let remaining = read_inbox(&inbox_path);
+    let ids: Vec<_> = remaining
+        .iter()
+        .map(|m| m.message_id.as_deref().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["msg-001", "msg-002"]);
+}
+
+#[test]
+fn test_archive_failure_keeps_order_and_file_when_evicted_message_is_not_first() {
+    let temp_dir = TempDir::new().unwrap();
+    let inbox_path = temp_dir.path().join("agent.json");
+    let archive_dir = temp_dir.path().join("archive");
+    fs::write(&archive_dir, "not a directory").unwrap();
+
+    let messages = vec![
+        create_test_message("user1", "Recent message", 1, Some("msg-001".to_string())),
+        create_test_message("user2", "Old message", 10, Some("msg-002".to_string())),
+        create_test_message("user3", "Recent message", 2, Some("msg-003".to_string())),
+    ];
+    // Compact JSON, unlike the pretty form a rewrite would produce
+    fs::write(&inbox_path, serde_json::to_string(&messages).unwrap()).unwrap();
+    let before = fs::read(&inbox_path).unwrap();
+
+    let policy = RetentionConfig {
+        max_age: Some("7d".to_string()),
+        max_count: None,
+        strategy: CleanupStrategy::Archive,
+        archive_dir: Some(archive_dir.to_string_lossy().to_string()),
+        enabled: false,
+        interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
+    };
+
+    assert!(apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).is_err());
+    assert_eq!(
+        fs::read(&inbox_path).unwrap(),
+        before,
+        "inbox must not be rewritten"
+    );
+}
+
 #[test]
 fn test_delete_strategy() {
     let temp_dir = TempDir::new().unwrap();
@@ -272,6 +344,8 @@ fn test_delete_strategy() {
         archive_dir: Some(archive_dir.to_str().unwrap().to_string()),
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
@@ -312,6 +386,8 @@ fn test_dry_run() {
         archive_dir: None,
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, true).unwrap();
@@ -345,6 +421,8 @@ fn test_empty_inbox() {
         archive_dir: None,
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
@@ -372,6 +450,8 @@ fn test_nonexistent_inbox() {
         archive_dir: None,
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
@@ -404,6 +484,8 @@ fn test_no_data_loss_within_policy() {
         archive_dir: None,
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
@@ -428,26 +510,12 @@ fn test_retention_hours_duration() {
     let timestamp_12h = (Utc::now() - Duration::hours(12)).to_rfc3339();
 
     let messages = vec![
-        InboxMessage {
-            from: "user1".to_string(),
-            source_team: None,
-            text: "Old message".to_string(),
-            timestamp: timestamp_48h,
-            read: false,
-            summary: None,
-            message_id: Some("msg-001".to_string()),
-            unknown_fields: HashMap::new(),
-        },
-        InboxMessage {
-            from: "user2".to_string(),
-            source_team: None,
-            text: "Recent message".to_string(),
-            timestamp: timestamp_12h,
-            read: false,
-            summary: None,
-            message_id: Some("msg-002".to_string()),
-            unknown_fields: HashMap::new(),
-        },
+        InboxMessage::new("user1", "Old message")
+            .timestamp(timestamp_48h)
+            .message_id("msg-001"),
+        InboxMessage::new("user2", "Recent message")
+            .timestamp(timestamp_12h)
+            .message_id("msg-002"),
     ];
 
     write_inbox(&inbox_path, &messages);
@@ -460,6 +528,8 @@ fn test_retention_hours_duration() {
         archive_dir: None,
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
@@ -496,6 +566,8 @@ fn test_no_retention_policy_keeps_all() {
         archive_dir: None,
         enabled: false,
         interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::new(),
     };
 
     let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
@@ -508,3 +580,137 @@ fn test_no_retention_policy_keeps_all() {
     assert_eq!(result.removed, 0);
     assert_eq!(result.archived, 0);
 }
+
+#[test]
+fn test_per_type_policy_overrides_global_and_attributes_evictions() {
+    let temp_dir = TempDir::new().unwrap();
+    let inbox_path = temp_dir.path().join("agent.json");
+
+    let typed = |message_type: &str, days_ago: i64, id: &str| {
+        let mut msg = create_test_message("ci", "typed", days_ago, Some(id.to_string()));
+        msg.unknown_fields
+            .insert("type".to_string(), serde_json::json!(message_type));
+        msg
+    };
+    let messages = vec![
+        typed("ci_alert", 2, "alert-old"),
+        typed("decision", 30, "decision-old"),
+        create_test_message("user1", "Plain old", 10, Some("plain-old".to_string())),
+        typed("ci_alert", 0, "alert-new"),
+        create_test_message("user2", "Plain new", 1, Some("plain-new".to_string())),
+    ];
+    write_inbox(&inbox_path, &messages);
+
+    // Global keeps a week; CI alerts expire after a day; decisions persist.
+    let policy = RetentionConfig {
+        max_age: Some("7d".to_string()),
+        max_count: None,
+        strategy: CleanupStrategy::Delete,
+        archive_dir: None,
+        enabled: false,
+        interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::from([
+            (
+                "ci_alert".to_string(),
+                TypeRetentionPolicy {
+                    max_age: Some("24h".to_string()),
+                    max_count: None,
+                },
+            ),
+            (
+                "decision".to_string(),
+                TypeRetentionPolicy {
+                    max_age: Some("365d".to_string()),
+                    max_count: None,
+                },
+            ),
+        ]),
+    };
+
+    let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, true).unwrap();
+    assert_eq!(result.kept, 3);
+    assert_eq!(result.removed, 2);
+
+    let attributed: Vec<_> = result
+        .evictions
+        .iter()
+        .map(|e| (e.message_id.as_deref().unwrap(), e.policy.clone(), e.reason))
+        .collect();
+    assert_eq!(
+        attributed,
+        vec![
+            (
+                "alert-old",
+                RetentionPolicySource::PerType("ci_alert".to_string()),
+                EvictionReason::MaxAge
+            ),
+            (
+                "plain-old",
+                RetentionPolicySource::Global,
+                EvictionReason::MaxAge
+            ),
+        ]
+    );
+    let by_policy = result.evictions_by_policy();
+    assert_eq!(by_policy.get("per_type.ci_alert"), Some(&1));
+    assert_eq!(by_policy.get("global"), Some(&1));
+
+    // Dry run leaves the inbox untouched
+    assert_eq!(read_inbox(&inbox_path).len(), 5);
+}
+
+#[test]
+fn test_per_type_max_count_counts_only_that_type() {
+    let temp_dir = TempDir::new().unwrap();
+    let inbox_path = temp_dir.path().join("agent.json");
+
+    let mut messages = Vec::new();
+    for i in 0..3 {
+        let mut msg = create_test_message("ci", "alert", 0, Some(format!("alert-{i}")));
+        msg.unknown_fields
+            .insert("type".to_string(), serde_json::json!("ci_alert"));
+        messages.push(msg);
+    }
+    messages.push(create_test_message(
+        "user1",
+        "plain",
+        0,
+        Some("plain".to_string()),
+    ));
+    write_inbox(&inbox_path, &messages);
+
+    let policy = RetentionConfig {
+        max_age: None,
+        max_count: Some(10),
+        strategy: CleanupStrategy::Delete,
+        archive_dir: None,
+        enabled: false,
+        interval_secs: 300,
+        auto_cleanup_cron: None,
+        per_type: HashMap::from([(
+            "ci_alert".to_string(),
+            TypeRetentionPolicy {
+                max_age: None,
+                max_count: Some(1),
+            },
+        )]),
+    };
+
+    let result = apply_retention(&inbox_path, "test-team", "test-agent", &policy, false).unwrap();
+    assert_eq!(result.kept, 2);
+    assert_eq!(result.removed, 2);
+    assert!(
+        result
+            .evictions
+            .iter()
+            .all(|e| e.reason == EvictionReason::MaxCount
+                && e.policy == RetentionPolicySource::PerType("ci_alert".to_string()))
+    );
+
+    let remaining: Vec<_> = read_inbox(&inbox_path)
+        .into_iter()
+        .filter_map(|m| m.message_id)
+        .collect();
+    assert_eq!(remaining, vec!["alert-0", "plain"]);
+}
@@ -13,6 +13,10 @@ pub const DEFAULT_DRAIN_TIMEOUT_SECS: u64 = GH_MONITOR_DEFAULT_DRAIN_TIMEOUT_SEC
 /// Capacity of the watcher-to-dispatch inbox event channel.
 pub const EVENT_CHANNEL_CAPACITY: usize = 100;
 
+/// Default number of inbox events processed concurrently
+/// (`[daemon] max_inbox_workers`).
+pub const DEFAULT_MAX_INBOX_WORKERS: usize = 4;
+
 /// Grace period for background task and plugin shutdown during daemon exit.
 pub const GRACEFUL_SHUTDOWN_TIMEOUT_SECS: u64 = 5;
 
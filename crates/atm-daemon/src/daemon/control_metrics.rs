@@ -0,0 +1,142 @@
+//! Control-request ack latency metrics
+//!
+//! The socket server records how long each `"control"` request (stdin,
+//! interrupt, elicitation response) takes from receipt to ack. The status
+//! writer publishes per-action p50/p95 summaries in `status.json` so callers
+//! such as the TUI can size their retry timeouts from observed latencies.
+//!
+//! Percentiles are computed over the most recent [`LATENCY_WINDOW`] samples per
+//! action; `count` is the total since daemon start.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// Number of recent samples kept per action for percentile computation.
+pub const LATENCY_WINDOW: usize = 512;
+
+static CONTROL_LATENCY: OnceLock<Mutex<ControlLatencyRecorder>> = OnceLock::new();
+
+fn recorder() -> &'static Mutex<ControlLatencyRecorder> {
+    CONTROL_LATENCY.get_or_init(|| Mutex::new(ControlLatencyRecorder::default()))
+}
+
+/// Latency summary for one control action.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ControlLatencyStats {
+    /// Total requests recorded since daemon start
+    pub count: u64,
+    /// Median ack latency in milliseconds over the recent window
+    pub p50_ms: f64,
+    /// 95th percentile ack latency in milliseconds over the recent window
+    pub p95_ms: f64,
+    /// Maximum ack latency in milliseconds over the recent window
+    pub max_ms: f64,
+}
+
+/// Per-action rolling latency samples.
+#[derive(Debug, Default)]
+pub struct ControlLatencyRecorder {
+    actions: HashMap<String, ActionSamples>,
+}
+
+#[derive(Debug, Default)]
+struct ActionSamples {
+    count: u64,
+    recent: VecDeque<Duration>,
+}
+
+impl ControlLatencyRecorder {
+    /// Record one ack latency for `action`.
+    pub fn record(&mut self, action: &str, latency: Duration) {
+        let samples = self.actions.entry(action.to_string()).or_default();
+        samples.count += 1;
+        if samples.recent.len() == LATENCY_WINDOW {
+            samples.recent.pop_front();
+        }
+        samples.recent.push_back(latency);
+    }
+
+    /// Summarize every recorded action.
+    pub fn snapshot(&self) -> BTreeMap<String, ControlLatencyStats> {
+        self.actions
+            .iter()
+            .map(|(action, samples)| {
+                let mut sorted: Vec<Duration> = samples.recent.iter().copied().collect();
+                sorted.sort_unstable();
+                let stats = ControlLatencyStats {
+                    count: samples.count,
+                    p50_ms: percentile_ms(&sorted, 50),
+                    p95_ms: percentile_ms(&sorted, 95),
+                    max_ms: sorted.last().map_or(0.0, duration_ms),
+                };
+                (action.clone(), stats)
+            })
+            .collect()
+    }
+}
+
+/// Record a control ack latency in the process-wide recorder.
+pub fn record_control_latency(action: &str, latency: Duration) {
+    recorder().lock().unwrap().record(action, latency);
+}
+
+/// Snapshot the process-wide recorder for `status.json`.
+pub fn control_latency_snapshot() -> BTreeMap<String, ControlLatencyStats> {
+    recorder().lock().unwrap().snapshot()
+}
+
+/// Nearest-rank percentile of an ascending slice, in milliseconds.
+fn percentile_ms(sorted: &[Duration], pct: usize) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    duration_ms(&sorted[rank - 1])
+}
+
+fn duration_ms(d: &Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_reports_per_action_percentiles() {
+        let mut recorder = ControlLatencyRecorder::default();
+        for ms in 1..=100 {
+            recorder.record("stdin", Duration::from_millis(ms));
+        }
+        recorder.record("interrupt", Duration::from_millis(7));
+
+        let snapshot = recorder.snapshot();
+        let stdin = &snapshot["stdin"];
+        assert_eq!(stdin.count, 100);
+        assert_eq!(stdin.p50_ms, 50.0);
+        assert_eq!(stdin.p95_ms, 95.0);
+        assert_eq!(stdin.max_ms, 100.0);
+
+        let interrupt = &snapshot["interrupt"];
+        assert_eq!(interrupt.count, 1);
+        assert_eq!(interrupt.p50_ms, 7.0);
+        assert_eq!(interrupt.p95_ms, 7.0);
+    }
+
+    #[test]
+    fn test_window_keeps_recent_samples_but_counts_all() {
+        let mut recorder = ControlLatencyRecorder::default();
+        for _ in 0..LATENCY_WINDOW {
+            recorder.record("stdin", Duration::from_millis(500));
+        }
+        for _ in 0..LATENCY_WINDOW {
+            recorder.record("stdin", Duration::from_millis(2));
+        }
+
+        let stats = &recorder.snapshot()["stdin"];
+        assert_eq!(stats.count, 2 * LATENCY_WINDOW as u64);
+        assert_eq!(stats.max_ms, 2.0, "old samples age out of the window");
+    }
+}
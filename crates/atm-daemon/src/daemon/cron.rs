@@ -0,0 +1,224 @@
+//! Minimal 5-field cron expressions for daemon schedules
+//!
+//! Supports `minute hour day-of-month month day-of-week` with `*`, single
+//! values, ranges (`1-5`), lists (`1,15`) and steps (`*/10`, `0-30/5`).
+//! Day-of-week is `0-7` with both `0` and `7` meaning Sunday. As in classic
+//! cron, when both day fields are restricted a day matching either one runs;
+//! a day field starting with `*` (including `*/n`) counts as unrestricted.
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDateTime, TimeZone, Timelike};
+use std::fmt;
+use std::str::FromStr;
+
+/// How far ahead [`CronSchedule::next_after`] searches before giving up
+const SEARCH_HORIZON_DAYS: i64 = 366 * 5;
+
+/// A parsed 5-field cron expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    /// The expression as written (trimmed)
+    pub pattern: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    dom_restricted: bool,
+    dow_restricted: bool,
+}
+
+/// Error returned for a malformed cron expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronParseError(String);
+
+impl fmt::Display for CronParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for CronParseError {}
+
+impl FromStr for CronSchedule {
+    type Err = CronParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pattern = s.trim();
+        let fields: Vec<&str> = pattern.split_whitespace().collect();
+        let [minute, hour, dom, month, dow] = fields[..] else {
+            return Err(CronParseError(format!(
+                "expected 5 fields (minute hour day-of-month month day-of-week), got {}",
+                fields.len()
+            )));
+        };
+
+        let mut days_of_week = parse_field(dow, 0, 7, "day-of-week")?;
+        // 7 is an alias for Sunday
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            minutes: parse_field(minute, 0, 59, "minute")?,
+            hours: parse_field(hour, 0, 23, "hour")?,
+            days_of_month: parse_field(dom, 1, 31, "day-of-month")?,
+            months: parse_field(month, 1, 12, "month")?,
+            days_of_week,
+            dom_restricted: !dom.starts_with('*'),
+            dow_restricted: !dow.starts_with('*'),
+        })
+    }
+}
+
+impl CronSchedule {
+    /// First matching local time strictly after `now`, or `None` if nothing
+    /// matches within the search horizon (e.g. `0 0 31 2 *`).
+    ///
+    /// Local times skipped by a DST transition never match; ambiguous local
+    /// times resolve to their earliest instant.
+    pub fn next_after(&self, now: DateTime<Local>) -> Option<DateTime<Local>> {
+        let start = now.naive_local().with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let horizon = start + Duration::days(SEARCH_HORIZON_DAYS);
+        let mut candidate = start;
+
+        while candidate < horizon {
+            if !self.day_matches(candidate) {
+                candidate = next_day(candidate)?;
+                continue;
+            }
+            if !bit(self.hours, candidate.hour()) {
+                candidate = next_hour(candidate)?;
+                continue;
+            }
+            if bit(self.minutes, candidate.minute())
+                && let Some(at) = Local.from_local_datetime(&candidate).earliest()
+                && at > now
+            {
+                return Some(at);
+            }
+            candidate += Duration::minutes(1);
+        }
+        None
+    }
+
+    fn day_matches(&self, at: NaiveDateTime) -> bool {
+        if !bit(self.months, at.month()) {
+            return false;
+        }
+        let dom = bit(self.days_of_month, at.day());
+        let dow = bit(self.days_of_week, at.weekday().num_days_from_sunday());
+        match (self.dom_restricted, self.dow_restricted) {
+            (true, true) => dom || dow,
+            _ => dom && dow,
+        }
+    }
+}
+
+fn bit(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn next_day(at: NaiveDateTime) -> Option<NaiveDateTime> {
+    at.date().succ_opt()?.and_hms_opt(0, 0, 0)
+}
+
+fn next_hour(at: NaiveDateTime) -> Option<NaiveDateTime> {
+    Some(at.with_minute(0)? + Duration::hours(1))
+}
+
+/// Parse one field into a bitmask of allowed values in `min..=max`.
+fn parse_field(field: &str, min: u32, max: u32, name: &str) -> Result<u64, CronParseError> {
+    let invalid = || CronParseError(format!("invalid {name} field '{field}'"));
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().map_err(|_| invalid())?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            return Err(invalid());
+        }
+        let (lo, hi) = if range == "*" {
+            (min, max)
+        } else if let Some((lo, hi)) = range.split_once('-') {
+            (
+                lo.parse().map_err(|_| invalid())?,
+                hi.parse().map_err(|_| invalid())?,
+            )
+        } else {
+            let value: u32 = range.parse().map_err(|_| invalid())?;
+            // `5/15` means "from 5 to the end in steps of 15"
+            (value, if part.contains('/') { max } else { value })
+        };
+        if lo < min || hi > max || lo > hi {
+            return Err(CronParseError(format!(
+                "{name} field '{field}' is out of range {min}-{max}"
+            )));
+        }
+        for value in (lo..=hi).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+    }
+
+    #[test]
+    fn test_next_after_daily_and_stepped_schedules() {
+        let daily: CronSchedule = "0 3 * * *".parse().unwrap();
+        assert_eq!(
+            daily.next_after(local(2026, 3, 10, 2, 30)),
+            Some(local(2026, 3, 10, 3, 0))
+        );
+        assert_eq!(
+            daily.next_after(local(2026, 3, 10, 3, 0)),
+            Some(local(2026, 3, 11, 3, 0))
+        );
+
+        let stepped: CronSchedule = "*/15 9-17 * * 1-5".parse().unwrap();
+        // 2026-03-14 is a Saturday; the next match is Monday 09:00
+        assert_eq!(
+            stepped.next_after(local(2026, 3, 13, 17, 50)),
+            Some(local(2026, 3, 16, 9, 0))
+        );
+
+        // Day-of-month and day-of-week restricted together match either
+        let either: CronSchedule = "0 0 1 * 0".parse().unwrap();
+        assert_eq!(
+            either.next_after(local(2026, 3, 2, 0, 0)),
+            Some(local(2026, 3, 8, 0, 0))
+        );
+
+        // A stepped `*/n` day field is unrestricted, so both fields must match:
+        // the first odd-numbered Monday, not the next Monday or odd day
+        let stepped_dom: CronSchedule = "0 3 */2 * 1".parse().unwrap();
+        assert_eq!(
+            stepped_dom.next_after(local(2026, 3, 1, 4, 0)),
+            Some(local(2026, 3, 9, 3, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_expressions() {
+        for bad in [
+            "",
+            "0 3 * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "*/0 * * * *",
+            "a * * * *",
+        ] {
+            assert!(bad.parse::<CronSchedule>().is_err(), "{bad:?} should fail");
+        }
+        let never: CronSchedule = "0 0 31 2 *".parse().unwrap();
+        assert_eq!(never.next_after(local(2026, 1, 1, 0, 0)), None);
+    }
+}
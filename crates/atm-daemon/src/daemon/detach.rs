@@ -0,0 +1,159 @@
+//! Fork/detach support for `atm-daemon --daemon`
+//!
+//! Detaching must happen before the tokio runtime (or anything else that
+//! spawns threads) starts, because only the forking thread survives a
+//! `fork()`. The sequence is the classic double fork:
+//!
+//! 1. The launching process forks and waits for the daemon to report its PID
+//!    over a pipe, then returns so the caller can exit.
+//! 2. The first child calls `setsid()` to leave the caller's session and
+//!    controlling terminal, forks again, and exits.
+//! 3. The grandchild (never a session leader, so it can never reacquire a
+//!    terminal) points stdin at `/dev/null` and stdout/stderr at the detached
+//!    stdio log, and carries on as the daemon. It reports its PID through
+//!    [`PidReporter`] once it holds `daemon.lock`; a daemon that loses the
+//!    single-instance race never reports, so the launcher fails instead. The
+//!    daemon writes the PID file itself when its socket server starts.
+
+use anyhow::Result;
+use std::path::Path;
+
+/// File in the daemon runtime directory that receives a detached daemon's
+/// stdout and stderr. Structured logs still go to the JSONL log file.
+pub const DETACHED_STDIO_LOG: &str = "atm-daemon.stdio.log";
+
+/// Which side of the fork the caller is on after [`detach`].
+#[derive(Debug)]
+pub enum Detached {
+    /// The launching process; the daemon runs as this PID. Exit now.
+    Parent(u32),
+    /// The detached daemon process; continue startup and call
+    /// [`PidReporter::report`] once `daemon.lock` is held.
+    Daemon(PidReporter),
+}
+
+/// Write end of the pipe the launching process is waiting on.
+///
+/// Dropping it without reporting (e.g. when startup fails) makes the
+/// launching process exit with an error.
+#[derive(Debug)]
+pub struct PidReporter {
+    #[cfg(unix)]
+    pipe: std::fs::File,
+}
+
+impl PidReporter {
+    /// Tell the launching process this daemon's PID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the launching process has already gone away.
+    pub fn report(self) -> Result<()> {
+        #[cfg(unix)]
+        {
+            use anyhow::Context;
+            use std::io::Write;
+            let mut pipe = self.pipe;
+            writeln!(pipe, "{}", std::process::id()).context("Failed to report daemon PID")?;
+        }
+        Ok(())
+    }
+}
+
+/// Detach the current process from its terminal and session.
+///
+/// # Errors
+///
+/// Returns an error when the stdio log cannot be opened, the pipe or first
+/// fork fails, or the daemon exits before reporting its PID (see the stdio
+/// log for why).
+#[cfg(unix)]
+pub fn detach(stdio_log: &Path) -> Result<Detached> {
+    use anyhow::Context;
+    use std::fs::{File, OpenOptions};
+    use std::io::Read;
+    use std::os::fd::{AsRawFd, FromRawFd};
+
+    if let Some(dir) = stdio_log.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create daemon directory {}", dir.display()))?;
+    }
+    // Open everything the daemon needs before forking so failures still reach
+    // the caller's terminal.
+    let stdio = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(stdio_log)
+        .with_context(|| format!("Failed to open daemon stdio log {}", stdio_log.display()))?;
+    let devnull = File::open("/dev/null").context("Failed to open /dev/null")?;
+
+    let mut fds = [0; 2];
+    // SAFETY: `fds` is a valid two-element buffer for pipe(2).
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+        return Err(std::io::Error::last_os_error()).context("Failed to create detach pipe");
+    }
+    let (read_fd, write_fd) = (fds[0], fds[1]);
+
+    // SAFETY: called before any other thread exists (see module docs).
+    match unsafe { libc::fork() } {
+        -1 => {
+            return Err(std::io::Error::last_os_error()).context("Failed to fork daemon process");
+        }
+        0 => {}
+        child => {
+            // SAFETY: `write_fd` is our copy of the pipe's write end; the
+            // read end is owned by `reader` from here on.
+            unsafe { libc::close(write_fd) };
+            let mut reader = unsafe { File::from_raw_fd(read_fd) };
+            let mut reported = String::new();
+            let read = reader.read_to_string(&mut reported);
+            let mut status = 0;
+            // SAFETY: reaps the short-lived first child.
+            unsafe { libc::waitpid(child, &mut status, 0) };
+            read.context("Failed to read detached daemon PID")?;
+            let pid: u32 = reported.trim().parse().with_context(|| {
+                format!(
+                    "Detached daemon exited during startup; see {}",
+                    stdio_log.display()
+                )
+            })?;
+            return Ok(Detached::Parent(pid));
+        }
+    }
+
+    // First child: new session, then fork again so the daemon is not a
+    // session leader.
+    // SAFETY: plain syscalls; `_exit` skips destructors shared with the parent.
+    unsafe {
+        libc::close(read_fd);
+        if libc::setsid() == -1 {
+            libc::_exit(1);
+        }
+        match libc::fork() {
+            -1 => libc::_exit(1),
+            0 => {}
+            _ => libc::_exit(0),
+        }
+    }
+
+    // Grandchild: the daemon.
+    // SAFETY: `write_fd` is the pipe write end inherited from the parent.
+    let pipe = unsafe { File::from_raw_fd(write_fd) };
+    // SAFETY: the source descriptors are open for the duration of the calls.
+    unsafe {
+        libc::dup2(devnull.as_raw_fd(), libc::STDIN_FILENO);
+        libc::dup2(stdio.as_raw_fd(), libc::STDOUT_FILENO);
+        libc::dup2(stdio.as_raw_fd(), libc::STDERR_FILENO);
+    }
+    Ok(Detached::Daemon(PidReporter { pipe }))
+}
+
+/// Detaching is only supported on Unix.
+///
+/// # Errors
+///
+/// Always returns an error.
+#[cfg(not(unix))]
+pub fn detach(_stdio_log: &Path) -> Result<Detached> {
+    anyhow::bail!("--daemon (fork/detach) is only supported on Unix")
+}
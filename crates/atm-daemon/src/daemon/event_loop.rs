@@ -1,5 +1,6 @@
 //! Main daemon event loop
 
+use crate::daemon::cron::CronSchedule;
 use crate::daemon::observability::{
     export_metric_records_best_effort, export_trace_records_best_effort, otel_config_from_env,
 };
@@ -8,15 +9,17 @@ use crate::daemon::status::{
     LoggingHealth, OtelHealth, PluginStatus, PluginStatusKind, StatusWriter,
 };
 use crate::daemon::{
-    InboxEvent, InboxEventKind, LogEventQueue, SharedDedupeStore, SharedPubSubStore,
-    SharedSessionRegistry, SharedStateStore, SharedStreamEventSender,
+    InboxEvent, InboxEventKind, InboxWorkerPool, LogEventQueue, SharedDedupeStore,
+    SharedPubSubStore, SharedSessionRegistry, SharedStateStore, SharedStreamEventSender,
     consts::{
-        EVENT_CHANNEL_CAPACITY, GRACEFUL_SHUTDOWN_TIMEOUT_SECS, RECONCILE_INTERVAL_SECS,
-        SPOOL_DRAIN_INTERVAL_SECS, STATUS_WRITE_INTERVAL_SECS,
+        DEFAULT_MAX_INBOX_WORKERS, EVENT_CHANNEL_CAPACITY, GRACEFUL_SHUTDOWN_TIMEOUT_SECS,
+        RECONCILE_INTERVAL_SECS, SPOOL_DRAIN_INTERVAL_SECS, STATUS_WRITE_INTERVAL_SECS,
     },
     graceful_shutdown, spool_drain_loop, start_socket_server, watch_inboxes,
 };
-use crate::plugin::{Capability, FailedPluginInit, PluginContext, PluginRegistry};
+use crate::plugin::{
+    Capability, FailedPluginInit, PluginContext, PluginMetadata, PluginRegistry, SharedPlugin,
+};
 use crate::plugins::worker_adapter::AgentState;
 use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
 use agent_team_mail_core::schema::TeamConfig;
@@ -27,11 +30,12 @@ use sc_observability_types::{MetricKind, MetricRecord, TraceRecord, TraceStatus}
 use serde_json::Value;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, error, info, warn};
+use tracing::{Instrument, debug, error, info, warn};
 
 #[derive(Debug, Default)]
 struct ReconcileCycleState {
@@ -347,6 +351,9 @@ where
 /// * `stream_state_store` - Per-agent stream turn state store.
 /// * `stream_event_sender` - Broadcast sender for push-based stream event fanout.
 ///   Create with `crate::daemon::new_stream_event_sender()`.
+/// * `daemon_event_sender` - Broadcast sender carrying the daemon's own emitted
+///   events for `"tail-events"` connections. Create with
+///   `crate::daemon::new_daemon_event_sender()`.
 /// * `log_event_queue` - Bounded queue for `"log-event"` socket commands.
 ///   Create with `crate::daemon::new_log_event_queue()`.
 #[expect(
@@ -366,6 +373,7 @@ pub async fn run(
     dedup_store: SharedDedupeStore,
     stream_state_store: crate::daemon::SharedStreamStateStore,
     stream_event_sender: SharedStreamEventSender,
+    daemon_event_sender: crate::daemon::SharedDaemonEventSender,
     log_event_queue: LogEventQueue,
 ) -> Result<()> {
     info!("Initializing daemon event loop");
@@ -374,8 +382,9 @@ pub async fn run(
     info!("Initializing {} plugin(s)", registry.len());
     // `init_all` is fail-open by contract: plugin init failures are recorded in
     // registry state and surfaced via status/doctor, not propagated as daemon
-    // startup errors.
-    let _ = registry.init_all(ctx).await;
+    // startup errors. The only error it returns is a provider library load
+    // failure under `[daemon] provider_load_failure = "fail-fast"`.
+    registry.init_all(ctx).await?;
     let init_failed_plugins = registry.failed_init_plugins();
     for failed in &init_failed_plugins {
         emit_plugin_lifecycle_event(
@@ -481,7 +490,10 @@ pub async fn run(
             agent_team_mail_core::home::get_home_dir()
                 .unwrap_or_else(|_| ctx.system.claude_root.clone())
         });
-    let socket_cancel = cancel.clone();
+    // The socket outlives `cancel` until plugins have shut down, so clients
+    // get `Busy`/`DAEMON_DRAINING` answers while workers drain.
+    let socket_cancel = CancellationToken::new();
+    let _socket_cancel_guard = socket_cancel.clone().drop_guard();
     let _socket_server_handle = match start_socket_server(
         socket_home_dir,
         state_store.clone(),
@@ -491,6 +503,7 @@ pub async fn run(
         dedup_store,
         stream_state_store,
         stream_event_sender,
+        daemon_event_sender,
         log_event_queue.clone(),
         &daemon_lock,
         socket_cancel,
@@ -541,215 +554,77 @@ pub async fn run(
         }
     });
 
-    // Start event dispatch loop for EventListener plugins
-    let dispatch_plugins = plugins.clone();
+    // Start event dispatch loop for EventListener plugins. Events are
+    // processed by a bounded worker pool; when every worker is busy the loop
+    // stops receiving and further events queue in the watcher channel.
+    let max_inbox_workers = ctx
+        .config
+        .daemon
+        .max_inbox_workers
+        .unwrap_or(DEFAULT_MAX_INBOX_WORKERS);
+    let dispatcher = Arc::new(InboxDispatcher {
+        plugins: plugins.clone(),
+        ctx: ctx.clone(),
+        session_registry: session_registry.clone(),
+        state_store: state_store.clone(),
+        cycle_state: reconcile_cycle_state.clone(),
+        cursors: Arc::new(tokio::sync::Mutex::new(std::collections::HashMap::new())),
+        read_error_count: Arc::new(AtomicU64::new(0)),
+    });
     let dispatch_cancel = cancel.clone();
-    let dispatch_reconcile_ctx = ctx.clone();
-    let dispatch_reconcile_registry = session_registry.clone();
-    let dispatch_reconcile_state_store = state_store.clone();
-    let dispatch_reconcile_cycle_state = reconcile_cycle_state.clone();
     let dispatch_task = tokio::spawn(async move {
-        info!("Starting event dispatch loop");
-        let mut cursors: std::collections::HashMap<std::path::PathBuf, InboxCursor> =
-            std::collections::HashMap::new();
-        let mut read_error_count: u64 = 0;
+        info!("Starting event dispatch loop ({max_inbox_workers} inbox worker(s))");
+        let mut workers = InboxWorkerPool::new(max_inbox_workers);
         loop {
+            // Take a worker slot before pulling an event, so a saturated pool
+            // leaves events queued in the channel and cancellation still wins.
+            let slot = tokio::select! {
+                _ = dispatch_cancel.cancelled() => {
+                    info!("Event dispatch cancelled");
+                    break;
+                }
+                slot = workers.reserve() => slot,
+            };
             tokio::select! {
                 _ = dispatch_cancel.cancelled() => {
                     info!("Event dispatch cancelled");
                     break;
                 }
                 Some(event) = event_rx.recv() => {
-                    let dispatch_span = tracing::info_span!(
-                        "daemon_dispatch",
-                        team = %event.team,
-                        agent = %event.agent,
-                        path = %event.path.display()
-                    );
-                    let _span_guard = dispatch_span.enter();
-                    debug!("Dispatching event: team={}, agent={}, kind={:?}",
-                           event.team, event.agent, event.kind);
-
-                    // Team config watcher event: reconcile immediately on config.json changes.
-                    if event.agent == "__config__" {
-                        let claude_root = dispatch_reconcile_ctx.system.claude_root.clone();
-                        let session_registry = dispatch_reconcile_registry.clone();
-                        let state_store = dispatch_reconcile_state_store.clone();
-                        let cycle_state = dispatch_reconcile_cycle_state.clone();
-                        let result = tokio::task::spawn_blocking(move || {
-                            reconcile_team_member_activity_with_mode(
-                                &claude_root,
-                                &session_registry,
-                                &state_store,
-                                &cycle_state,
-                                false,
-                            )
-                        })
-                        .await;
-                        match result {
-                            Ok(Ok(())) => debug!("config.json reconcile pass completed"),
-                            Ok(Err(e)) => warn!("config.json reconcile pass failed: {e}"),
-                            Err(e) => warn!("config.json reconcile task panicked: {e}"),
-                        }
-
-                        continue;
-                    }
-
-                    // Only dispatch MessageReceived events
-                    if event.kind != InboxEventKind::MessageReceived {
-                        continue;
-                    }
-
-                    let cursor = cursors.entry(event.path.clone()).or_default();
-                    let inbox_msgs = match read_new_inbox_messages(&event.path, cursor).await {
-                        Ok(msgs) => msgs,
-                        Err(e) => {
-                            read_error_count += 1;
-                            // File might be transiently locked, deleted, or malformed
-                            warn!(
-                                "Failed to read inbox at {}: {} (errors={})",
-                                event.path.display(),
-                                e,
-                                read_error_count
-                            );
-                            continue;
-                        }
-                    };
-
-                    if inbox_msgs.is_empty() {
-                        continue;
-                    }
-
-                    for mut inbox_msg in inbox_msgs {
-                        let dispatch_started_at = Instant::now();
-                        let message_id = inbox_msg.message_id.clone();
-                        let dispatch_trace_id = dispatch_trace_id(&event, message_id.as_deref());
-                        let dispatch_root_span_id =
-                            agent_team_mail_core::event_log::span_id_for_action(
-                                &dispatch_trace_id,
-                                "dispatch_message",
-                            );
-                        let otel_config = otel_config_from_env();
-                        let mut dispatch_failed = false;
-
-                        emit_event_best_effort(EventFields {
-                            level: "info",
-                            source: "atm-daemon",
-                            action: "dispatch_message",
-                            team: Some(event.team.clone()),
-                            session_id: std::env::var("CLAUDE_SESSION_ID").ok(),
-                            agent_id: Some(event.agent.clone()),
-                            agent_name: Some(event.agent.clone()),
-                            message_id: inbox_msg.message_id.clone(),
-                            result: Some("received".to_string()),
-                            ..Default::default()
-                        });
-
-                        // Attach routing metadata for plugins
-                        inbox_msg
-                            .unknown_fields
-                            .insert("recipient".to_string(), Value::String(event.agent.clone()));
-                        inbox_msg
-                            .unknown_fields
-                            .insert("team".to_string(), Value::String(event.team.clone()));
-                        inbox_msg.unknown_fields.insert(
-                            "path".to_string(),
-                            Value::String(event.path.display().to_string()),
-                        );
-                        if let Some(origin) = &event.origin {
-                            inbox_msg.unknown_fields.insert(
-                                "origin".to_string(),
-                                Value::String(origin.clone()),
-                            );
-                        }
-
-                        // Dispatch to all plugins with EventListener capability
-                        for (metadata, plugin_arc) in &dispatch_plugins {
-                            if metadata.capabilities.contains(&Capability::EventListener) {
-                                let plugin_dispatch_started_at = Instant::now();
-                                // Await lock to avoid dropping events under load
-                                let mut plugin = plugin_arc.lock().await;
-                                debug!("Dispatching to plugin: {}", metadata.name);
-                                let dispatch_result = plugin.handle_message(&inbox_msg).await;
-                                let (trace_status, trace_error) = match &dispatch_result {
-                                    Ok(_) => (TraceStatus::Ok, None),
-                                    Err(e) => (TraceStatus::Error, Some(e.to_string())),
-                                };
-                                export_trace_records_best_effort(
-                                    &[build_plugin_dispatch_trace_record(
-                                        &event,
-                                        message_id.as_deref(),
-                                        &dispatch_trace_id,
-                                        &dispatch_root_span_id,
-                                        PluginDispatchTrace {
-                                            plugin_name: metadata.name,
-                                            operation: "handle_message",
-                                            duration_ms: plugin_dispatch_started_at.elapsed()
-                                                .as_millis()
-                                                as u64,
-                                            status: trace_status,
-                                            error: trace_error.as_deref(),
-                                        },
-                                    )],
-                                    &otel_config,
-                                );
-                                if let Err(e) = dispatch_result {
-                                    dispatch_failed = true;
-                                    emit_event_best_effort(EventFields {
-                                        level: "error",
-                                        source: "atm-daemon",
-                                        action: "dispatch_plugin_error",
-                                        team: Some(event.team.clone()),
-                                        session_id: std::env::var("CLAUDE_SESSION_ID").ok(),
-                                        agent_id: Some(event.agent.clone()),
-                                        agent_name: Some(event.agent.clone()),
-                                        message_id: inbox_msg.message_id.clone(),
-                                        target: Some(metadata.name.to_string()),
-                                        result: Some("error".to_string()),
-                                        error: Some(e.to_string()),
-                                        ..Default::default()
-                                    });
-                                    error!("Plugin {} handle_message error: {}", metadata.name, e);
-                                }
-                            }
-                        }
-
-                        export_trace_records_best_effort(
-                            &[build_dispatch_root_trace_record(
-                                &event,
-                                message_id.as_deref(),
-                                &dispatch_trace_id,
-                                &dispatch_root_span_id,
-                                dispatch_started_at.elapsed().as_millis() as u64,
-                                if dispatch_failed {
-                                    TraceStatus::Error
-                                } else {
-                                    TraceStatus::Ok
-                                },
-                            )],
-                            &otel_config,
-                        );
-                    }
+                    let dispatcher = dispatcher.clone();
+                    workers.spawn(slot, async move { dispatcher.process(event).await });
                 }
             }
         }
+        workers.join_all().await;
         info!("Event dispatch loop stopped");
     });
 
-    // Start retention task if enabled
-    let retention_task = if ctx.config.retention.enabled {
-        info!(
-            "Starting retention task (interval: {}s)",
-            ctx.config.retention.interval_secs
-        );
-        let retention_cancel = cancel.clone();
-        let retention_ctx = ctx.clone();
-        Some(tokio::spawn(async move {
-            retention_loop(retention_ctx, retention_cancel).await;
-        }))
-    } else {
-        info!("Retention task disabled in config");
-        None
+    // Start retention task if enabled (interval) or scheduled (cron)
+    let retention_task = match RetentionSchedule::from_config(&ctx.config.retention) {
+        Ok(Some(schedule)) => {
+            info!("Starting retention task ({})", schedule.describe());
+            let retention_cancel = cancel.clone();
+            let retention_ctx = ctx.clone();
+            Some(tokio::spawn(async move {
+                retention_loop(retention_ctx, schedule, retention_cancel).await;
+            }))
+        }
+        Ok(None) => {
+            info!("Retention task disabled in config");
+            None
+        }
+        Err(e) => {
+            error!("Retention task not started: {e:#}");
+            emit_event_best_effort(EventFields {
+                level: "error",
+                source: "atm-daemon",
+                action: "retention_schedule_error",
+                error: Some(format!("{e:#}")),
+                ..Default::default()
+            });
+            None
+        }
     };
 
     // Start status writer task
@@ -1289,6 +1164,210 @@ fn is_member_present_in_config(config_path: &std::path::Path, member_name: &str)
         .any(|member| member.name == member_name))
 }
 
+type SharedInboxCursor = Arc<tokio::sync::Mutex<InboxCursor>>;
+
+/// Shared state for inbox event workers.
+struct InboxDispatcher {
+    plugins: Vec<(PluginMetadata, SharedPlugin)>,
+    ctx: PluginContext,
+    session_registry: SharedSessionRegistry,
+    state_store: SharedStateStore,
+    cycle_state: SharedCycleState,
+    cursors: Arc<tokio::sync::Mutex<std::collections::HashMap<PathBuf, SharedInboxCursor>>>,
+    read_error_count: Arc<AtomicU64>,
+}
+
+impl InboxDispatcher {
+    async fn cursor_for(&self, path: &Path) -> SharedInboxCursor {
+        self.cursors
+            .lock()
+            .await
+            .entry(path.to_path_buf())
+            .or_default()
+            .clone()
+    }
+
+    /// Process one watcher event: reconcile on team config changes, otherwise
+    /// read new inbox messages and dispatch them to EventListener plugins.
+    async fn process(&self, event: InboxEvent) {
+        let dispatch_span = tracing::info_span!(
+            "daemon_dispatch",
+            team = %event.team,
+            agent = %event.agent,
+            path = %event.path.display()
+        );
+        self.process_inner(event).instrument(dispatch_span).await;
+    }
+
+    async fn process_inner(&self, event: InboxEvent) {
+        debug!(
+            "Dispatching event: team={}, agent={}, kind={:?}",
+            event.team, event.agent, event.kind
+        );
+
+        // Team config watcher event: reconcile immediately on config.json changes.
+        if event.agent == "__config__" {
+            let claude_root = self.ctx.system.claude_root.clone();
+            let session_registry = self.session_registry.clone();
+            let state_store = self.state_store.clone();
+            let cycle_state = self.cycle_state.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                reconcile_team_member_activity_with_mode(
+                    &claude_root,
+                    &session_registry,
+                    &state_store,
+                    &cycle_state,
+                    false,
+                )
+            })
+            .await;
+            match result {
+                Ok(Ok(())) => debug!("config.json reconcile pass completed"),
+                Ok(Err(e)) => warn!("config.json reconcile pass failed: {e}"),
+                Err(e) => warn!("config.json reconcile task panicked: {e}"),
+            }
+
+            return;
+        }
+
+        // Only dispatch MessageReceived events
+        if event.kind != InboxEventKind::MessageReceived {
+            return;
+        }
+
+        // Holding the per-inbox cursor for the whole dispatch keeps messages
+        // from one inbox in order while other inboxes proceed in parallel.
+        let cursor = self.cursor_for(&event.path).await;
+        let mut cursor = cursor.lock().await;
+        let inbox_msgs = match read_new_inbox_messages(&event.path, &mut cursor).await {
+            Ok(msgs) => msgs,
+            Err(e) => {
+                let read_error_count = self.read_error_count.fetch_add(1, Ordering::Relaxed) + 1;
+                // File might be transiently locked, deleted, or malformed
+                warn!(
+                    "Failed to read inbox at {}: {} (errors={})",
+                    event.path.display(),
+                    e,
+                    read_error_count
+                );
+                return;
+            }
+        };
+
+        if inbox_msgs.is_empty() {
+            return;
+        }
+
+        for mut inbox_msg in inbox_msgs {
+            let dispatch_started_at = Instant::now();
+            let message_id = inbox_msg.message_id.clone();
+            let dispatch_trace_id = dispatch_trace_id(&event, message_id.as_deref());
+            let dispatch_root_span_id = agent_team_mail_core::event_log::span_id_for_action(
+                &dispatch_trace_id,
+                "dispatch_message",
+            );
+            let otel_config = otel_config_from_env();
+            let mut dispatch_failed = false;
+
+            emit_event_best_effort(EventFields {
+                level: "info",
+                source: "atm-daemon",
+                action: "dispatch_message",
+                team: Some(event.team.clone()),
+                session_id: std::env::var("CLAUDE_SESSION_ID").ok(),
+                agent_id: Some(event.agent.clone()),
+                agent_name: Some(event.agent.clone()),
+                message_id: inbox_msg.message_id.clone(),
+                result: Some("received".to_string()),
+                ..Default::default()
+            });
+
+            // Attach routing metadata for plugins
+            inbox_msg
+                .unknown_fields
+                .insert("recipient".to_string(), Value::String(event.agent.clone()));
+            inbox_msg
+                .unknown_fields
+                .insert("team".to_string(), Value::String(event.team.clone()));
+            inbox_msg.unknown_fields.insert(
+                "path".to_string(),
+                Value::String(event.path.display().to_string()),
+            );
+            if let Some(origin) = &event.origin {
+                inbox_msg
+                    .unknown_fields
+                    .insert("origin".to_string(), Value::String(origin.clone()));
+            }
+
+            // Dispatch to all plugins with EventListener capability
+            for (metadata, plugin_arc) in &self.plugins {
+                if metadata.capabilities.contains(&Capability::EventListener) {
+                    let plugin_dispatch_started_at = Instant::now();
+                    // Await lock to avoid dropping events under load
+                    let mut plugin = plugin_arc.lock().await;
+                    debug!("Dispatching to plugin: {}", metadata.name);
+                    let dispatch_result = plugin.handle_message(&inbox_msg).await;
+                    let (trace_status, trace_error) = match &dispatch_result {
+                        Ok(_) => (TraceStatus::Ok, None),
+                        Err(e) => (TraceStatus::Error, Some(e.to_string())),
+                    };
+                    export_trace_records_best_effort(
+                        &[build_plugin_dispatch_trace_record(
+                            &event,
+                            message_id.as_deref(),
+                            &dispatch_trace_id,
+                            &dispatch_root_span_id,
+                            PluginDispatchTrace {
+                                plugin_name: metadata.name,
+                                operation: "handle_message",
+                                duration_ms: plugin_dispatch_started_at.elapsed().as_millis()
+                                    as u64,
+                                status: trace_status,
+                                error: trace_error.as_deref(),
+                            },
+                        )],
+                        &otel_config,
+                    );
+                    if let Err(e) = dispatch_result {
+                        dispatch_failed = true;
+                        emit_event_best_effort(EventFields {
+                            level: "error",
+                            source: "atm-daemon",
+                            action: "dispatch_plugin_error",
+                            team: Some(event.team.clone()),
+                            session_id: std::env::var("CLAUDE_SESSION_ID").ok(),
+                            agent_id: Some(event.agent.clone()),
+                            agent_name: Some(event.agent.clone()),
+                            message_id: inbox_msg.message_id.clone(),
+                            target: Some(metadata.name.to_string()),
+                            result: Some("error".to_string()),
+                            error: Some(e.to_string()),
+                            ..Default::default()
+                        });
+                        error!("Plugin {} handle_message error: {}", metadata.name, e);
+                    }
+                }
+            }
+
+            export_trace_records_best_effort(
+                &[build_dispatch_root_trace_record(
+                    &event,
+                    message_id.as_deref(),
+                    &dispatch_trace_id,
+                    &dispatch_root_span_id,
+                    dispatch_started_at.elapsed().as_millis() as u64,
+                    if dispatch_failed {
+                        TraceStatus::Error
+                    } else {
+                        TraceStatus::Ok
+                    },
+                )],
+                &otel_config,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone)]
 struct InboxCursor {
     last_message_id: Option<String>,
@@ -1368,26 +1447,76 @@ fn extract_hostname_registry(
     Some(std::sync::Arc::new(registry))
 }
 
+/// When the periodic retention task runs
+#[derive(Debug, Clone)]
+enum RetentionSchedule {
+    /// Every `[retention] interval_secs`, starting immediately
+    Interval(Duration),
+    /// At each match of `[retention] auto_cleanup_cron`, in local time
+    Cron(Box<CronSchedule>),
+}
+
+impl RetentionSchedule {
+    /// Build the schedule from config; `None` means `[retention] enabled` is
+    /// false and the task does not run. When enabled, `auto_cleanup_cron`
+    /// takes precedence over `interval_secs`.
+    fn from_config(config: &agent_team_mail_core::config::RetentionConfig) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        if let Some(expr) = config.auto_cleanup_cron.as_deref() {
+            let cron = expr
+                .parse::<CronSchedule>()
+                .with_context(|| format!("invalid [retention] auto_cleanup_cron '{expr}'"))?;
+            return Ok(Some(Self::Cron(Box::new(cron))));
+        }
+        Ok(Some(Self::Interval(Duration::from_secs(
+            config.interval_secs.max(1),
+        ))))
+    }
+
+    /// Delay before the first run.
+    fn initial_delay(&self, now: chrono::DateTime<chrono::Local>) -> Option<Duration> {
+        match self {
+            Self::Interval(_) => Some(Duration::ZERO),
+            Self::Cron(_) => self.next_delay(now),
+        }
+    }
+
+    /// Delay from `now` until the next run; `None` if the cron expression has
+    /// no future match.
+    fn next_delay(&self, now: chrono::DateTime<chrono::Local>) -> Option<Duration> {
+        match self {
+            Self::Interval(interval) => Some(*interval),
+            Self::Cron(cron) => cron
+                .next_after(now)
+                .map(|next| (next - now).to_std().unwrap_or_default()),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Interval(interval) => format!("interval: {}s", interval.as_secs()),
+            Self::Cron(cron) => format!("cron: {}", cron.pattern),
+        }
+    }
+}
+
 /// Periodic retention task
 ///
-/// Runs retention on all team inbox files at configured intervals.
+/// Runs retention on all team inbox files on the configured schedule.
 /// Also cleans up old CI report files if CI monitor plugin is configured.
-async fn retention_loop(ctx: PluginContext, cancel: CancellationToken) {
-    // Extract retention config
-    let config = &ctx.config.retention;
-    let interval_secs = config.interval_secs;
-
+async fn retention_loop(
+    ctx: PluginContext,
+    schedule: RetentionSchedule,
+    cancel: CancellationToken,
+) {
     // Set up defaults for daemon mode
-    let max_age = config.max_age.clone().or_else(|| Some("30d".to_string()));
-    let max_count = config.max_count.or(Some(1000));
-
+    let config = &ctx.config.retention;
     let retention_policy = agent_team_mail_core::config::RetentionConfig {
-        max_age,
-        max_count,
-        strategy: config.strategy,
-        archive_dir: config.archive_dir.clone(),
-        enabled: config.enabled,
-        interval_secs: config.interval_secs,
+        max_age: config.max_age.clone().or_else(|| Some("30d".to_string())),
+        max_count: config.max_count.or(Some(1000)),
+        ..config.clone()
     };
 
     let teams_root = ctx.mail.teams_root().clone();
@@ -1400,18 +1529,23 @@ async fn retention_loop(ctx: PluginContext, cancel: CancellationToken) {
         .and_then(|v| v.as_str())
         .map(PathBuf::from);
 
-    info!("Retention loop started (interval: {}s)", interval_secs);
-
-    let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
-    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    info!("Retention loop started ({})", schedule.describe());
 
+    let mut next_delay = schedule.initial_delay(chrono::Local::now());
     loop {
+        let Some(delay) = next_delay else {
+            warn!(
+                "Retention schedule ({}) has no upcoming run; stopping",
+                schedule.describe()
+            );
+            break;
+        };
         tokio::select! {
             _ = cancel.cancelled() => {
                 info!("Retention loop cancelled");
                 break;
             }
-            _ = interval.tick() => {
+            _ = tokio::time::sleep(delay) => {
                 debug!("Running periodic retention");
 
                 // Run retention work in spawn_blocking to avoid blocking the tokio runtime
@@ -1428,6 +1562,7 @@ async fn retention_loop(ctx: PluginContext, cancel: CancellationToken) {
                 }
             }
         }
+        next_delay = schedule.next_delay(chrono::Local::now());
     }
 
     info!("Retention loop stopped");
@@ -1462,6 +1597,8 @@ fn retention_work(
         }
     };
 
+    let mut total_removed = 0usize;
+    let mut inboxes_cleaned = 0usize;
     for team_entry in team_dirs {
         let team_entry = match team_entry {
             Ok(e) => e,
@@ -1571,14 +1708,16 @@ fn retention_work(
             ) {
                 Ok(result) => {
                     if result.removed > 0 {
+                        let by_policy = result.evictions_by_policy();
                         tracing::info!(
-                            "Retention: {}/{}/{}: kept={}, removed={}, archived={}",
+                            "Retention: {}/{}/{}: kept={}, removed={}, archived={} ({})",
                             team_name,
                             agent_name,
                             file_name,
                             result.kept,
                             result.removed,
-                            result.archived
+                            result.archived,
+                            format_evictions_by_policy(&by_policy)
                         );
                         emit_event_best_effort(EventFields {
                             level: "info",
@@ -1588,8 +1727,19 @@ fn retention_work(
                             agent_id: Some(agent_name.clone()),
                             count: Some(result.removed as u64),
                             result: Some("ok".to_string()),
+                            extra_fields: {
+                                let mut fields = serde_json::Map::new();
+                                fields.insert("archived".to_string(), result.archived.into());
+                                fields.insert(
+                                    "removed_by_policy".to_string(),
+                                    serde_json::to_value(&by_policy).unwrap_or_default(),
+                                );
+                                fields
+                            },
                             ..Default::default()
                         });
+                        total_removed += result.removed;
+                        inboxes_cleaned += 1;
                     }
                 }
                 Err(e) => {
@@ -1614,6 +1764,14 @@ fn retention_work(
         }
     }
 
+    if total_removed > 0 {
+        tracing::info!(
+            "Retention run removed {} message(s) from {} inbox(es)",
+            total_removed,
+            inboxes_cleaned
+        );
+    }
+
     // Clean up old report files if configured
     if let Some(dir) = report_dir {
         tracing::debug!("Cleaning old report files from {}", dir.display());
@@ -1660,6 +1818,15 @@ fn retention_work(
     }
 }
 
+/// Render a per-policy eviction breakdown, e.g. `global=3, per_type.ci_alert=2`.
+fn format_evictions_by_policy(by_policy: &std::collections::BTreeMap<String, usize>) -> String {
+    by_policy
+        .iter()
+        .map(|(policy, count)| format!("{policy}={count}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Periodic status writer task
 ///
 /// Writes daemon status to status.json at regular intervals.
@@ -2000,9 +2167,9 @@ fn format_timestamp(time: SystemTime) -> String {
 #[cfg(test)]
 mod tests {
     use super::{
-        InboxCursor, PluginDispatchTrace, build_dispatch_root_trace_record,
+        InboxCursor, PluginDispatchTrace, RetentionSchedule, build_dispatch_root_trace_record,
         build_logging_health_snapshot, build_plugin_dispatch_trace_record, dispatch_trace_id,
-        read_new_inbox_messages,
+        format_evictions_by_policy, read_new_inbox_messages,
     };
     use crate::daemon::InboxEventKind;
     use crate::daemon::session_registry::new_session_registry;
@@ -2011,7 +2178,6 @@ mod tests {
     use agent_team_mail_core::event_log::span_id_for_action;
     use agent_team_mail_core::schema::InboxMessage;
     use sc_observability_types::TraceStatus;
-    use std::collections::HashMap;
     use std::fs as stdfs;
     use std::time::Duration;
     use tempfile::TempDir;
@@ -2032,6 +2198,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_retention_schedule_from_config() {
+        use agent_team_mail_core::config::RetentionConfig;
+        use chrono::TimeZone;
+
+        assert!(
+            RetentionSchedule::from_config(&RetentionConfig::default())
+                .unwrap()
+                .is_none()
+        );
+
+        let interval = RetentionSchedule::from_config(&RetentionConfig {
+            enabled: true,
+            interval_secs: 600,
+            ..Default::default()
+        })
+        .unwrap()
+        .unwrap();
+        let now = chrono::Local::now();
+        assert_eq!(interval.initial_delay(now), Some(Duration::ZERO));
+        assert_eq!(interval.next_delay(now), Some(Duration::from_secs(600)));
+
+        // A cron expression alone does not enable the task
+        assert!(
+            RetentionSchedule::from_config(&RetentionConfig {
+                auto_cleanup_cron: Some("0 3 * * *".to_string()),
+                ..Default::default()
+            })
+            .unwrap()
+            .is_none()
+        );
+
+        // Cron wins over the interval once enabled
+        let cron = RetentionSchedule::from_config(&RetentionConfig {
+            enabled: true,
+            auto_cleanup_cron: Some("0 3 * * *".to_string()),
+            ..Default::default()
+        })
+        .unwrap()
+        .unwrap();
+        let before = chrono::Local
+            .with_ymd_and_hms(2026, 3, 10, 2, 30, 0)
+            .unwrap();
+        assert_eq!(cron.initial_delay(before), Some(Duration::from_secs(1800)));
+        assert_eq!(cron.describe(), "cron: 0 3 * * *");
+
+        let err = RetentionSchedule::from_config(&RetentionConfig {
+            enabled: true,
+            auto_cleanup_cron: Some("not a cron".to_string()),
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("auto_cleanup_cron"));
+    }
+
+    #[test]
+    fn test_format_evictions_by_policy() {
+        let by_policy = std::collections::BTreeMap::from([
+            ("global".to_string(), 3),
+            ("per_type.ci_alert".to_string(), 2),
+        ]);
+        assert_eq!(
+            format_evictions_by_policy(&by_policy),
+            "global=3, per_type.ci_alert=2"
+        );
+    }
+
     #[test]
     fn test_build_logging_health_snapshot_healthy() {
         let tmp = TempDir::new().expect("temp dir");
@@ -2174,27 +2407,13 @@ mod tests {
         let path = temp_dir.path().join("inbox.json");
         let mut cursor = InboxCursor::default();
 
-        let msg1 = InboxMessage {
-            from: "a".to_string(),
-            source_team: None,
-            text: "first".to_string(),
-            timestamp: "2026-02-11T10:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("msg-1".to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let msg1 = InboxMessage::new("a", "first")
+            .timestamp("2026-02-11T10:00:00Z")
+            .message_id("msg-1");
 
-        let msg2 = InboxMessage {
-            from: "b".to_string(),
-            source_team: None,
-            text: "second".to_string(),
-            timestamp: "2026-02-11T10:05:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("msg-2".to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let msg2 = InboxMessage::new("b", "second")
+            .timestamp("2026-02-11T10:05:00Z")
+            .message_id("msg-2");
 
         let msgs = vec![msg1.clone(), msg2.clone()];
         write_inbox(&path, &msgs).await;
@@ -2204,16 +2423,9 @@ mod tests {
         assert_eq!(new_msgs[0].text, "first");
         assert_eq!(new_msgs[1].text, "second");
 
-        let msg3 = InboxMessage {
-            from: "c".to_string(),
-            source_team: None,
-            text: "third".to_string(),
-            timestamp: "2026-02-11T10:10:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("msg-3".to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let msg3 = InboxMessage::new("c", "third")
+            .timestamp("2026-02-11T10:10:00Z")
+            .message_id("msg-3");
 
         let msgs = vec![msg1, msg2, msg3.clone()];
         write_inbox(&path, &msgs).await;
@@ -2239,27 +2451,13 @@ mod tests {
         let path = temp_dir.path().join("inbox.json");
         let mut cursor = InboxCursor::default();
 
-        let msg1 = InboxMessage {
-            from: "a".to_string(),
-            source_team: None,
-            text: "first".to_string(),
-            timestamp: "2026-02-11T10:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("msg-1".to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let msg1 = InboxMessage::new("a", "first")
+            .timestamp("2026-02-11T10:00:00Z")
+            .message_id("msg-1");
 
-        let msg2 = InboxMessage {
-            from: "b".to_string(),
-            source_team: None,
-            text: "second".to_string(),
-            timestamp: "2026-02-11T10:05:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("msg-2".to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let msg2 = InboxMessage::new("b", "second")
+            .timestamp("2026-02-11T10:05:00Z")
+            .message_id("msg-2");
 
         write_inbox(&path, &[msg1.clone(), msg2]).await;
         let _ = read_new_inbox_messages(&path, &mut cursor).await.unwrap();
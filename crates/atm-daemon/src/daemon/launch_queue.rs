@@ -0,0 +1,194 @@
+//! Agent launch queue tracking
+//!
+//! Every `"launch"` socket request funnels through the single worker-adapter
+//! launch channel. The socket handler registers each request here when it is
+//! queued, and the worker adapter advances it to `launching` when it picks the
+//! request up and to `ready`/`failed` when the launch finishes. The
+//! `"launch-status"` command returns a snapshot so bulk launches show what is
+//! queued versus stuck.
+//!
+//! Finished launches are kept for the most recent [`FINISHED_WINDOW`] entries.
+
+use agent_team_mail_core::daemon_client::{
+    LaunchQueueStatus, LaunchResult, LaunchStatus, LaunchStatusEntry,
+};
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+/// Number of finished (ready/failed) launches retained for status queries.
+pub const FINISHED_WINDOW: usize = 32;
+
+static LAUNCH_QUEUE: OnceLock<Mutex<LaunchQueue>> = OnceLock::new();
+
+fn queue() -> &'static Mutex<LaunchQueue> {
+    LAUNCH_QUEUE.get_or_init(|| Mutex::new(LaunchQueue::default()))
+}
+
+/// Launch entries in queue order.
+#[derive(Debug, Default)]
+pub struct LaunchQueue {
+    next_id: u64,
+    entries: VecDeque<LaunchStatusEntry>,
+}
+
+impl LaunchQueue {
+    /// Register a newly queued launch and return its id.
+    pub fn enqueue(&mut self, agent: &str, team: &str) -> u64 {
+        self.next_id += 1;
+        let now = now_rfc3339();
+        self.entries.push_back(LaunchStatusEntry {
+            id: self.next_id,
+            agent: agent.to_string(),
+            team: team.to_string(),
+            status: LaunchStatus::Pending,
+            queued_at: now.clone(),
+            updated_at: now,
+            pane_id: None,
+            message: None,
+        });
+        self.next_id
+    }
+
+    /// Mark launch `id` as picked up by the worker adapter.
+    pub fn mark_launching(&mut self, id: u64) {
+        if let Some(entry) = self.entry_mut(id) {
+            entry.status = LaunchStatus::Launching;
+            entry.updated_at = now_rfc3339();
+        }
+    }
+
+    /// Record the outcome of launch `id`.
+    pub fn mark_finished(&mut self, id: u64, result: &Result<LaunchResult, String>) {
+        if let Some(entry) = self.entry_mut(id) {
+            match result {
+                Ok(launched) => {
+                    entry.status = LaunchStatus::Ready;
+                    entry.pane_id = Some(launched.pane_id.clone());
+                    entry.message = launched.warning.clone();
+                }
+                Err(err) => {
+                    entry.status = LaunchStatus::Failed;
+                    entry.message = Some(err.clone());
+                }
+            }
+            entry.updated_at = now_rfc3339();
+        }
+        self.trim_finished();
+    }
+
+    /// Snapshot for the `"launch-status"` command.
+    pub fn snapshot(&self) -> LaunchQueueStatus {
+        LaunchQueueStatus {
+            queue_depth: self
+                .entries
+                .iter()
+                .filter(|e| e.status == LaunchStatus::Pending)
+                .count(),
+            launches: self.entries.iter().cloned().collect(),
+        }
+    }
+
+    fn entry_mut(&mut self, id: u64) -> Option<&mut LaunchStatusEntry> {
+        self.entries.iter_mut().find(|e| e.id == id)
+    }
+
+    /// Drop the oldest finished entries beyond [`FINISHED_WINDOW`].
+    fn trim_finished(&mut self) {
+        let mut excess = self
+            .entries
+            .iter()
+            .filter(|e| is_finished(e.status))
+            .count()
+            .saturating_sub(FINISHED_WINDOW);
+        self.entries.retain(|e| {
+            if excess > 0 && is_finished(e.status) {
+                excess -= 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+fn is_finished(status: LaunchStatus) -> bool {
+    matches!(status, LaunchStatus::Ready | LaunchStatus::Failed)
+}
+
+fn now_rfc3339() -> String {
+    chrono::Utc::now().to_rfc3339()
+}
+
+/// Register a queued launch in the process-wide queue.
+pub fn enqueue_launch(agent: &str, team: &str) -> u64 {
+    queue().lock().unwrap().enqueue(agent, team)
+}
+
+/// Mark a launch as picked up by the worker adapter.
+pub fn mark_launch_started(id: u64) {
+    queue().lock().unwrap().mark_launching(id);
+}
+
+/// Record the outcome of a launch.
+pub fn mark_launch_finished(id: u64, result: &Result<LaunchResult, String>) {
+    queue().lock().unwrap().mark_finished(id, result);
+}
+
+/// Snapshot the process-wide launch queue.
+pub fn launch_queue_snapshot() -> LaunchQueueStatus {
+    queue().lock().unwrap().snapshot()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn launched(agent: &str) -> LaunchResult {
+        LaunchResult {
+            agent: agent.to_string(),
+            pane_id: "%7".to_string(),
+            state: "idle".to_string(),
+            warning: None,
+        }
+    }
+
+    #[test]
+    fn test_launch_lifecycle_and_queue_depth() {
+        let mut queue = LaunchQueue::default();
+        let first = queue.enqueue("a", "team");
+        let second = queue.enqueue("b", "team");
+        assert_eq!(queue.snapshot().queue_depth, 2);
+
+        queue.mark_launching(first);
+        let snapshot = queue.snapshot();
+        assert_eq!(snapshot.queue_depth, 1);
+        assert_eq!(snapshot.launches[0].status, LaunchStatus::Launching);
+
+        queue.mark_finished(first, &Ok(launched("a")));
+        queue.mark_finished(second, &Err("tmux missing".to_string()));
+        let snapshot = queue.snapshot();
+        assert_eq!(snapshot.queue_depth, 0);
+        assert_eq!(snapshot.launches[0].status, LaunchStatus::Ready);
+        assert_eq!(snapshot.launches[0].pane_id.as_deref(), Some("%7"));
+        assert_eq!(snapshot.launches[1].status, LaunchStatus::Failed);
+        assert_eq!(
+            snapshot.launches[1].message.as_deref(),
+            Some("tmux missing")
+        );
+    }
+
+    #[test]
+    fn test_finished_launches_are_trimmed_but_pending_kept() {
+        let mut queue = LaunchQueue::default();
+        let pending = queue.enqueue("waiting", "team");
+        for i in 0..FINISHED_WINDOW + 5 {
+            let id = queue.enqueue(&format!("agent-{i}"), "team");
+            queue.mark_finished(id, &Ok(launched("x")));
+        }
+
+        let snapshot = queue.snapshot();
+        assert_eq!(snapshot.launches.len(), FINISHED_WINDOW + 1);
+        assert_eq!(snapshot.launches[0].id, pending);
+        assert_eq!(snapshot.launches[1].agent, "agent-5");
+    }
+}
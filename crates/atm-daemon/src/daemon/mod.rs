@@ -1,7 +1,11 @@
 pub mod consts;
+pub mod control_metrics;
+pub mod cron;
 pub mod dedup;
+pub mod detach;
 pub mod event_loop;
 pub mod gh_monitor_router;
+pub mod launch_queue;
 pub mod log_writer;
 pub mod observability;
 pub mod pid_backend_validation;
@@ -14,7 +18,9 @@ pub mod startup_auth;
 pub mod status;
 pub mod watcher;
 
+pub use control_metrics::{ControlLatencyStats, control_latency_snapshot};
 pub use event_loop::run;
+pub use launch_queue::launch_queue_snapshot;
 pub use log_writer::{
     BoundedQueue, LogEventQueue, LogWriterConfig, new_log_event_queue, run_log_writer_task,
 };
@@ -24,11 +30,12 @@ pub use session_registry::{
 };
 pub use shutdown::graceful_shutdown;
 pub use socket::{
-    LaunchRequest, LaunchSender, SharedDedupeStore, SharedPubSubStore, SharedStateStore,
-    SharedStreamEventSender, SharedStreamStateStore, SocketServerHandle, new_dedup_store,
-    new_launch_sender, new_pubsub_store, new_state_store, new_stream_event_sender,
-    new_stream_state_store, start_socket_server,
+    LaunchRequest, LaunchSender, SharedDaemonEventSender, SharedDedupeStore, SharedPubSubStore,
+    SharedStateStore, SharedStreamEventSender, SharedStreamStateStore, SocketServerHandle,
+    install_daemon_event_tee, new_daemon_event_sender, new_dedup_store, new_launch_sender,
+    new_pubsub_store, new_state_store, new_stream_event_sender, new_stream_state_store,
+    start_socket_server,
 };
-pub use spool_task::spool_drain_loop;
+pub use spool_task::{drain_spool_now, spool_drain_loop};
 pub use status::{DaemonStatus, PluginStatus, PluginStatusKind, StatusWriter};
-pub use watcher::{InboxEvent, InboxEventKind, watch_inboxes};
+pub use watcher::{InboxEvent, InboxEventKind, InboxWorkerPool, watch_inboxes};
@@ -13,6 +13,10 @@
 //! 3. Server writes one JSON line (newline-terminated response)
 //! 4. Server closes the connection
 //!
+//! Long-lived exceptions: `"stream-subscribe"` and `"tail-events"` stream JSON
+//! lines after an ACK, and `"rpc-session"` upgrades the connection to a
+//! persistent JSON-RPC 2.0 session (see [`agent_team_mail_core::daemon_rpc`]).
+//!
 //! See [`agent_team_mail_core::daemon_client`] for the corresponding client
 //! implementation and protocol type definitions.
 //!
@@ -49,6 +53,7 @@ use crate::daemon::pid_backend_validation::{
 
 use crate::daemon::dedup::{DedupeKey, DurableDedupeStore};
 use crate::daemon::gh_monitor_router;
+use crate::daemon::launch_queue;
 use crate::daemon::session_registry::{MarkDeadForSessionOutcome, SharedSessionRegistry};
 use crate::plugins::worker_adapter::AgentState;
 
@@ -159,6 +164,10 @@ fn build_daemon_request_metric_records(
 /// * `stream_state_store` - Shared per-agent stream turn state store.
 /// * `stream_event_sender` - Broadcast sender for push-based stream event fanout.
 ///   Create with [`new_stream_event_sender()`].
+/// * `daemon_event_sender` - Broadcast sender carrying the daemon's own emitted
+///   events for `"tail-events"` connections. Create with
+///   [`new_daemon_event_sender()`] and tee events into it with
+///   [`install_daemon_event_tee()`].
 /// * `log_event_queue` - Bounded queue for incoming `"log-event"` commands.
 ///   Create with [`crate::daemon::new_log_event_queue()`].
 /// * `cancel` - Cancellation token; server stops accepting when cancelled
@@ -179,6 +188,7 @@ pub async fn start_socket_server(
     dedup_store: SharedDedupeStore,
     stream_state_store: SharedStreamStateStore,
     stream_event_sender: SharedStreamEventSender,
+    daemon_event_sender: SharedDaemonEventSender,
     log_event_queue: LogEventQueue,
     _daemon_lock: &agent_team_mail_core::io::lock::FileLock,
     cancel: tokio_util::sync::CancellationToken,
@@ -194,6 +204,7 @@ pub async fn start_socket_server(
             dedup_store,
             stream_state_store,
             stream_event_sender,
+            daemon_event_sender,
             log_event_queue,
             _daemon_lock,
             cancel,
@@ -204,7 +215,7 @@ pub async fn start_socket_server(
 
     #[cfg(not(unix))]
     {
-        let _ = log_event_queue;
+        let _ = (daemon_event_sender, log_event_queue);
         info!("Unix socket server not available on this platform");
         Ok(None)
     }
@@ -316,12 +327,48 @@ pub fn new_stream_event_sender() -> SharedStreamEventSender {
     std::sync::Arc::new(tx)
 }
 
+// ── Daemon event broadcast channel ───────────────────────────────────────────
+
+/// Sender half of the daemon's own event broadcast channel.
+///
+/// Every event the daemon emits through
+/// [`emit_event_best_effort`](agent_team_mail_core::event_log::emit_event_best_effort)
+/// is teed onto this channel by [`install_daemon_event_tee`], so
+/// `"tail-events"` connections (`atm daemon tail-events`) can stream them live
+/// without reading the shared log file.
+///
+/// Capacity of 256 events: lagged subscribers skip the events they missed.
+pub type SharedDaemonEventSender = std::sync::Arc<tokio::sync::broadcast::Sender<LogEventV1>>;
+
+/// Create a new broadcast channel for the daemon's own events.
+pub fn new_daemon_event_sender() -> SharedDaemonEventSender {
+    let (tx, _rx) = tokio::sync::broadcast::channel(256);
+    std::sync::Arc::new(tx)
+}
+
+/// Tee every event emitted in this process onto `sender`.
+///
+/// Installs the process-wide event observer hook. Publishing never blocks and
+/// is skipped entirely while no `"tail-events"` connection is open.
+pub fn install_daemon_event_tee(sender: SharedDaemonEventSender) {
+    agent_team_mail_core::event_log::install_event_observer_hook(std::sync::Arc::new(
+        move |event| {
+            if sender.receiver_count() > 0 {
+                let _ = sender.send(event.clone());
+            }
+        },
+    ));
+}
+
 // ── Launch channel types ──────────────────────────────────────────────────────
 
 /// A request to launch a new agent, sent from the socket handler to the
 /// [`WorkerAdapterPlugin`](crate::plugins::worker_adapter::WorkerAdapterPlugin)
 /// via an mpsc channel.
 pub struct LaunchRequest {
+    /// Id of this launch in the daemon launch queue
+    /// ([`launch_queue`](crate::daemon::launch_queue)).
+    pub launch_id: u64,
     /// Launch configuration received from the CLI.
     pub config: LaunchConfig,
     /// One-shot channel for the plugin to send the launch result back.
@@ -360,12 +407,11 @@ async fn start_unix_socket_server(
     dedup_store: SharedDedupeStore,
     stream_state_store: SharedStreamStateStore,
     stream_event_sender: SharedStreamEventSender,
+    daemon_event_sender: SharedDaemonEventSender,
     log_event_queue: LogEventQueue,
     _daemon_lock: &agent_team_mail_core::io::lock::FileLock,
     cancel: tokio_util::sync::CancellationToken,
 ) -> Result<SocketServerHandle> {
-    use tokio::net::UnixListener;
-
     let daemon_dir = home_dir.join(".atm/daemon");
     let socket_path = daemon_dir.join("atm-daemon.sock");
     let pid_path = daemon_dir.join("atm-daemon.pid");
@@ -385,15 +431,21 @@ async fn start_unix_socket_server(
     debug!("Wrote PID {pid} to {}", pid_path.display());
 
     // Bind the Unix listener
-    let listener = UnixListener::bind(&socket_path)?;
-    info!("Unix socket server listening on {}", socket_path.display());
+    let abstract_name = agent_team_mail_core::daemon_client::daemon_abstract_socket_name();
+    let listener = bind_daemon_listener(&socket_path, abstract_name.as_deref())?;
+    match &abstract_name {
+        Some(name) => info!("Unix socket server listening on abstract socket @{name}"),
+        None => info!("Unix socket server listening on {}", socket_path.display()),
+    }
 
     // Spawn the accept loop
     let accept_socket_path = socket_path.clone();
     let accept_pid_path = pid_path.clone();
+    let require_same_uid = abstract_name.is_some();
     tokio::spawn(async move {
         run_accept_loop(
             listener,
+            require_same_uid,
             home_dir,
             state_store,
             pubsub_store,
@@ -402,6 +454,7 @@ async fn start_unix_socket_server(
             dedup_store,
             stream_state_store,
             stream_event_sender,
+            daemon_event_sender,
             log_event_queue,
             cancel,
             &accept_socket_path,
@@ -416,6 +469,39 @@ async fn start_unix_socket_server(
     })
 }
 
+/// Bind the daemon listener: on Linux, an abstract-namespace socket when
+/// `abstract_name` is set (no file is created); otherwise the socket file.
+#[cfg(unix)]
+fn bind_daemon_listener(
+    socket_path: &std::path::Path,
+    abstract_name: Option<&str>,
+) -> std::io::Result<tokio::net::UnixListener> {
+    #[cfg(target_os = "linux")]
+    if let Some(name) = abstract_name {
+        use std::os::linux::net::SocketAddrExt;
+        let addr = std::os::unix::net::SocketAddr::from_abstract_name(name.as_bytes())?;
+        let listener = std::os::unix::net::UnixListener::bind_addr(&addr)?;
+        listener.set_nonblocking(true)?;
+        return tokio::net::UnixListener::from_std(listener);
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = abstract_name;
+    tokio::net::UnixListener::bind(socket_path)
+}
+
+/// Whether the connected peer runs as the daemon's effective uid.
+///
+/// Abstract sockets have no filesystem permissions, so this check is what
+/// keeps other users in the same network namespace out.
+#[cfg(unix)]
+fn peer_uid_matches(stream: &tokio::net::UnixStream) -> bool {
+    // SAFETY: geteuid has no preconditions and cannot fail.
+    let daemon_uid = unsafe { libc::geteuid() };
+    stream
+        .peer_cred()
+        .is_ok_and(|cred| cred.uid() == daemon_uid)
+}
+
 #[cfg(unix)]
 #[expect(
     clippy::too_many_arguments,
@@ -423,6 +509,7 @@ async fn start_unix_socket_server(
 )]
 async fn run_accept_loop(
     listener: tokio::net::UnixListener,
+    require_same_uid: bool,
     home_dir: std::path::PathBuf,
     state_store: SharedStateStore,
     pubsub_store: SharedPubSubStore,
@@ -431,6 +518,7 @@ async fn run_accept_loop(
     dedup_store: SharedDedupeStore,
     stream_state_store: SharedStreamStateStore,
     stream_event_sender: SharedStreamEventSender,
+    daemon_event_sender: SharedDaemonEventSender,
     log_event_queue: LogEventQueue,
     cancel: tokio_util::sync::CancellationToken,
     socket_path: &std::path::Path,
@@ -447,6 +535,10 @@ async fn run_accept_loop(
             result = listener.accept() => {
                 match result {
                     Ok((stream, _addr)) => {
+                        if require_same_uid && !peer_uid_matches(&stream) {
+                            warn!("Rejected abstract socket connection from another user");
+                            continue;
+                        }
                         let home = home_dir.clone();
                         let store = state_store.clone();
                         let ps = pubsub_store.clone();
@@ -455,9 +547,10 @@ async fn run_accept_loop(
                         let dd = dedup_store.clone();
                         let ss = stream_state_store.clone();
                         let ses = stream_event_sender.clone();
+                        let des = daemon_event_sender.clone();
                         let leq = log_event_queue.clone();
                         tokio::spawn(async move {
-                            if let Err(e) = handle_connection(stream, home, store, ps, tx, sr, dd, ss, ses, leq).await {
+                            if let Err(e) = handle_connection(stream, home, store, ps, tx, sr, dd, ss, ses, des, leq).await {
                                 error!("Socket connection handler error: {e}");
                             }
                         });
@@ -493,6 +586,7 @@ async fn handle_connection(
     dedup_store: SharedDedupeStore,
     stream_state_store: SharedStreamStateStore,
     stream_event_sender: SharedStreamEventSender,
+    daemon_event_sender: SharedDaemonEventSender,
     log_event_queue: LogEventQueue,
 ) -> Result<()> {
     use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
@@ -517,50 +611,122 @@ async fn handle_connection(
 
     let request_str = request_line.trim();
 
-    // Long-lived "stream-subscribe" connections are handled before the normal
-    // one-shot request/response path.
+    // Long-lived "stream-subscribe" and "tail-events" connections are handled
+    // before the normal one-shot request/response path.
     if is_stream_subscribe_command(request_str) {
         let mut stream = reader.into_inner();
         handle_stream_subscribe(&mut stream, request_str, &stream_event_sender).await;
         return Ok(());
     }
+    if is_tail_events_command(request_str) {
+        let mut stream = reader.into_inner();
+        handle_tail_events(&mut stream, request_str, &daemon_event_sender).await;
+        return Ok(());
+    }
+
+    let ctx = DispatchContext {
+        home: &home,
+        state_store: &state_store,
+        pubsub_store: &pubsub_store,
+        launch_tx: &launch_tx,
+        session_registry: &session_registry,
+        dedup_store: &dedup_store,
+        stream_state_store: &stream_state_store,
+        stream_event_sender: &stream_event_sender,
+        daemon_event_sender: &daemon_event_sender,
+        log_event_queue: &log_event_queue,
+    };
+
+    if is_rpc_session_command(request_str) {
+        return serve_rpc_session(reader, request_str, &ctx).await;
+    }
+
+    let response = dispatch_request(request_str, &ctx).await;
+
+    // Write response line
+    let mut response_json = serde_json::to_string(&response)?;
+    response_json.push('\n');
+
+    // Recover the stream from the BufReader to write the response
+    let mut stream = reader.into_inner();
+    stream.write_all(response_json.as_bytes()).await?;
+    stream.flush().await?;
+
+    debug!(
+        "Socket response sent for request_id={}",
+        response.request_id
+    );
+    Ok(())
+}
+
+/// Shared daemon resources borrowed by one connection for command dispatch.
+#[cfg(unix)]
+struct DispatchContext<'a> {
+    home: &'a std::path::Path,
+    state_store: &'a SharedStateStore,
+    pubsub_store: &'a SharedPubSubStore,
+    launch_tx: &'a LaunchSender,
+    session_registry: &'a SharedSessionRegistry,
+    dedup_store: &'a SharedDedupeStore,
+    stream_state_store: &'a SharedStreamStateStore,
+    stream_event_sender: &'a SharedStreamEventSender,
+    daemon_event_sender: &'a SharedDaemonEventSender,
+    log_event_queue: &'a LogEventQueue,
+}
 
+/// Dispatch one one-shot request line to its handler and return the response.
+///
+/// Shared by the one-shot protocol and JSON-RPC sessions.
+#[cfg(unix)]
+async fn dispatch_request(request_str: &str, ctx: &DispatchContext<'_>) -> SocketResponse {
     // Check whether this is a launch command before sync dispatch so we can
     // use async channel communication with the WorkerAdapterPlugin.
-    let response = if is_launch_command(request_str) {
-        handle_launch_command(request_str, &launch_tx).await
+    if is_launch_command(request_str) {
+        if ctx.state_store.lock().unwrap().is_draining() {
+            let request_id = serde_json::from_str::<serde_json::Value>(request_str)
+                .ok()
+                .and_then(|v| v.get("request_id")?.as_str().map(str::to_string))
+                .unwrap_or_else(|| "unknown".to_string());
+            return make_error_response(
+                &request_id,
+                "DAEMON_DRAINING",
+                "Daemon is draining workers for shutdown; launch rejected",
+            );
+        }
+        handle_launch_command(request_str, ctx.launch_tx).await
     } else if let Some(response) =
-        gh_monitor_router::maybe_route_async_command(request_str, &home).await
+        gh_monitor_router::maybe_route_async_command(request_str, ctx.home).await
     {
         response
     } else if is_control_command(request_str) {
         handle_control_command(
             request_str,
-            &home,
-            &state_store,
-            &session_registry,
-            &dedup_store,
+            ctx.home,
+            ctx.state_store,
+            ctx.session_registry,
+            ctx.dedup_store,
         )
         .await
     } else if is_hook_event_command(request_str) {
         handle_hook_event_command_with_dedup(
             request_str,
-            &state_store,
-            &session_registry,
-            &dedup_store,
+            ctx.state_store,
+            ctx.session_registry,
+            ctx.dedup_store,
         )
         .await
     } else if is_stream_event_command(request_str) {
-        handle_stream_event_command(request_str, &stream_state_store, &stream_event_sender).await
+        handle_stream_event_command(request_str, ctx.stream_state_store, ctx.stream_event_sender)
+            .await
     } else if is_log_event_command(request_str) {
-        handle_log_event_command(request_str, &log_event_queue).await
+        handle_log_event_command(request_str, ctx.log_event_queue).await
     } else {
         match parse_and_dispatch(
             request_str,
-            &state_store,
-            &pubsub_store,
-            &session_registry,
-            &stream_state_store,
+            ctx.state_store,
+            ctx.pubsub_store,
+            ctx.session_registry,
+            ctx.stream_state_store,
         ) {
             Ok(resp) => resp,
             Err(e) => {
@@ -572,22 +738,7 @@ async fn handle_connection(
                 )
             }
         }
-    };
-
-    // Write response line
-    let mut response_json = serde_json::to_string(&response)?;
-    response_json.push('\n');
-
-    // Recover the stream from the BufReader to write the response
-    let mut stream = reader.into_inner();
-    stream.write_all(response_json.as_bytes()).await?;
-    stream.flush().await?;
-
-    debug!(
-        "Socket response sent for request_id={}",
-        response.request_id
-    );
-    Ok(())
+    }
 }
 
 /// Quickly determine if a raw JSON line is a `"launch"` command without full
@@ -627,6 +778,20 @@ fn is_stream_subscribe_command(request_str: &str) -> bool {
         || request_str.contains(r#""command": "stream-subscribe""#)
 }
 
+/// Quickly determine if a raw JSON line is a `"tail-events"` command.
+#[cfg(unix)]
+fn is_tail_events_command(request_str: &str) -> bool {
+    request_str.contains(r#""command":"tail-events""#)
+        || request_str.contains(r#""command": "tail-events""#)
+}
+
+/// Quickly determine if a raw JSON line is an `"rpc-session"` handshake.
+#[cfg(unix)]
+fn is_rpc_session_command(request_str: &str) -> bool {
+    request_str.contains(r#""command":"rpc-session""#)
+        || request_str.contains(r#""command": "rpc-session""#)
+}
+
 /// Quickly determine if a raw JSON line is a `"log-event"` command.
 #[cfg(unix)]
 fn is_log_event_command(request_str: &str) -> bool {
@@ -645,15 +810,14 @@ fn is_log_event_command(request_str: &str) -> bool {
 /// agent; when absent all events are forwarded.
 ///
 /// Lagged subscribers (more than 256 unconsumed events) receive a warning log
-/// and continue — they will miss events but the connection stays open.
+/// and continue — they will miss events but the connection stays open, and
+/// must re-sync via `agent-stream-state`.
 #[cfg(unix)]
 async fn handle_stream_subscribe(
     stream: &mut tokio::net::UnixStream,
     request_str: &str,
     stream_event_sender: &SharedStreamEventSender,
 ) {
-    use tokio::io::AsyncWriteExt;
-
     // Parse optional agent filter from payload.
     let agent_filter: Option<String> = serde_json::from_str::<serde_json::Value>(request_str)
         .ok()
@@ -663,6 +827,67 @@ async fn handle_stream_subscribe(
                 .and_then(|a| a.as_str().map(str::to_string))
         });
 
+    serve_broadcast_stream(
+        stream,
+        stream_event_sender.subscribe(),
+        "stream-subscribe",
+        |event| match &agent_filter {
+            Some(filter) => event.agent() == filter,
+            None => true,
+        },
+    )
+    .await;
+}
+
+/// Handle a `"tail-events"` command: long-lived connection that streams each
+/// event the daemon emits, as a [`LogEventV1`] JSON line, as it happens.
+///
+/// Uses the same ACK and framing as `"stream-subscribe"`. An optional
+/// `actions` array in the request payload limits the stream to those event
+/// actions; when absent or empty all events are forwarded. Events emitted
+/// before the connection opened are not replayed.
+#[cfg(unix)]
+async fn handle_tail_events(
+    stream: &mut tokio::net::UnixStream,
+    request_str: &str,
+    daemon_event_sender: &SharedDaemonEventSender,
+) {
+    let actions: Vec<String> = serde_json::from_str::<serde_json::Value>(request_str)
+        .ok()
+        .and_then(|v| {
+            v.get("payload")
+                .and_then(|p| p.get("actions"))
+                .and_then(|a| serde_json::from_value(a.clone()).ok())
+        })
+        .unwrap_or_default();
+
+    serve_broadcast_stream(
+        stream,
+        daemon_event_sender.subscribe(),
+        "tail-events",
+        |event| actions.is_empty() || actions.contains(&event.action),
+    )
+    .await;
+}
+
+/// Drive a long-lived streaming connection from a broadcast receiver.
+///
+/// Writes the streaming ACK line, then one JSON line per received item that
+/// passes `matches`, until the client disconnects or the channel closes.
+/// `rx` is subscribed by the caller before the ACK so no item published after
+/// the ACK is missed. Lagging logs a warning and keeps the connection open.
+#[cfg(unix)]
+async fn serve_broadcast_stream<T, F>(
+    stream: &mut tokio::net::UnixStream,
+    mut rx: tokio::sync::broadcast::Receiver<T>,
+    label: &str,
+    matches: F,
+) where
+    T: Clone + serde::Serialize,
+    F: Fn(&T) -> bool,
+{
+    use tokio::io::AsyncWriteExt;
+
     // Send initial ACK so the subscriber knows the channel is live.
     let ack = serde_json::json!({"version": 1, "status": "ok", "streaming": true});
     let ack_line = format!("{ack}\n");
@@ -673,21 +898,13 @@ async fn handle_stream_subscribe(
         return;
     }
 
-    // Subscribe to the broadcast channel.
-    let mut rx = stream_event_sender.subscribe();
-
     loop {
         match rx.recv().await {
-            Ok(event) => {
-                // Apply optional agent filter.
-                let matches = match &agent_filter {
-                    Some(filter) => event.agent() == filter,
-                    None => true,
-                };
-                if !matches {
+            Ok(item) => {
+                if !matches(&item) {
                     continue;
                 }
-                match serde_json::to_string(&event) {
+                match serde_json::to_string(&item) {
                     Ok(line) => {
                         let line = format!("{line}\n");
                         if stream.write_all(line.as_bytes()).await.is_err() {
@@ -698,24 +915,318 @@ async fn handle_stream_subscribe(
                         }
                     }
                     Err(e) => {
-                        warn!("stream-subscribe: failed to serialize event: {e}");
+                        warn!("{label}: failed to serialize event: {e}");
                     }
                 }
             }
             Err(tokio::sync::broadcast::error::RecvError::Closed) => {
-                debug!("stream-subscribe: broadcast channel closed");
+                debug!("{label}: broadcast channel closed");
                 break;
             }
             Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
-                warn!(
-                    "stream-subscribe: lagged by {n} events; subscriber must re-sync via agent-stream-state"
-                );
+                warn!("{label}: lagged by {n} events; skipped events are not replayed");
                 // Continue — subscriber misses events but stays connected.
             }
         }
     }
 }
 
+/// Outgoing notification lines buffered per JSON-RPC session before the
+/// subscription tasks wait on the client.
+#[cfg(unix)]
+const RPC_NOTIFICATION_BUFFER: usize = 256;
+
+/// Serve a persistent JSON-RPC 2.0 session after an `"rpc-session"` handshake.
+///
+/// Acknowledges the handshake with a one-shot ok response, then answers
+/// newline-delimited JSON-RPC requests in order and interleaves notifications
+/// from `rpc.subscribe` subscriptions until the client disconnects. See
+/// [`agent_team_mail_core::daemon_rpc`] for the wire contract.
+#[cfg(unix)]
+async fn serve_rpc_session(
+    reader: tokio::io::BufReader<tokio::net::UnixStream>,
+    request_str: &str,
+    ctx: &DispatchContext<'_>,
+) -> Result<()> {
+    use agent_team_mail_core::daemon_client::SocketRequest;
+    use agent_team_mail_core::daemon_rpc::{RPC_PROTOCOL, RPC_TOPIC_EVENTS, RPC_TOPIC_STREAM};
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
+
+    let request: SocketRequest = match serde_json::from_str(request_str) {
+        Ok(r) => r,
+        Err(e) => {
+            let response = make_error_response(
+                "unknown",
+                "INVALID_REQUEST",
+                &format!("Failed to parse request: {e}"),
+            );
+            let mut stream = reader.into_inner();
+            stream
+                .write_all(format!("{}\n", serde_json::to_string(&response)?).as_bytes())
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // Keep any bytes the client pipelined after the handshake.
+    let buffered = reader.buffer().to_vec();
+    let (read_half, mut write_half) = reader.into_inner().into_split();
+    let mut lines =
+        tokio::io::BufReader::new(std::io::Cursor::new(buffered).chain(read_half)).lines();
+
+    let ack = make_ok_response(
+        &request.request_id,
+        serde_json::json!({
+            "protocol": RPC_PROTOCOL,
+            "topics": [RPC_TOPIC_EVENTS, RPC_TOPIC_STREAM],
+        }),
+    );
+    write_half
+        .write_all(format!("{}\n", serde_json::to_string(&ack)?).as_bytes())
+        .await?;
+    debug!("rpc-session opened (request_id={})", request.request_id);
+
+    let (note_tx, mut note_rx) = tokio::sync::mpsc::channel::<String>(RPC_NOTIFICATION_BUFFER);
+    let mut session = RpcSessionState {
+        note_tx,
+        subscriptions: std::collections::HashMap::new(),
+        next_subscription: 1,
+    };
+
+    loop {
+        let outgoing = tokio::select! {
+            line = lines.next_line() => match line {
+                Ok(Some(line)) => {
+                    let line = line.trim();
+                    if line.is_empty() {
+                        continue;
+                    }
+                    match handle_rpc_line(line, ctx, &mut session).await {
+                        Some(response) => serde_json::to_string(&response)?,
+                        None => continue,
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    debug!("rpc-session read error: {e}");
+                    break;
+                }
+            },
+            Some(note) = note_rx.recv() => note,
+        };
+        if write_half
+            .write_all(format!("{outgoing}\n").as_bytes())
+            .await
+            .is_err()
+        {
+            break; // Client disconnected.
+        }
+    }
+
+    for handle in session.subscriptions.values() {
+        handle.abort();
+    }
+    debug!("rpc-session closed (request_id={})", request.request_id);
+    Ok(())
+}
+
+/// Per-connection state of a JSON-RPC session.
+#[cfg(unix)]
+struct RpcSessionState {
+    note_tx: tokio::sync::mpsc::Sender<String>,
+    subscriptions: std::collections::HashMap<u64, tokio::task::JoinHandle<()>>,
+    next_subscription: u64,
+}
+
+/// Handle one JSON-RPC message; returns `None` for client notifications.
+#[cfg(unix)]
+async fn handle_rpc_line(
+    line: &str,
+    ctx: &DispatchContext<'_>,
+    session: &mut RpcSessionState,
+) -> Option<agent_team_mail_core::daemon_rpc::RpcResponse> {
+    use agent_team_mail_core::daemon_client::{PROTOCOL_VERSION, SocketRequest};
+    use agent_team_mail_core::daemon_rpc::{
+        RPC_INVALID_REQUEST, RPC_METHOD_NOT_FOUND, RPC_PARSE_ERROR, RPC_SESSION_COMMAND,
+        RPC_SUBSCRIBE_METHOD, RPC_UNSUBSCRIBE_METHOD, RpcRequest, RpcResponse,
+    };
+
+    let value: serde_json::Value = match serde_json::from_str(line) {
+        Ok(v) => v,
+        Err(e) => {
+            return Some(RpcResponse::err(
+                serde_json::Value::Null,
+                RPC_PARSE_ERROR,
+                format!("Parse error: {e}"),
+            ));
+        }
+    };
+    let id_hint = value.get("id").cloned().unwrap_or(serde_json::Value::Null);
+    let request: RpcRequest = match serde_json::from_value(value) {
+        Ok(r) => r,
+        Err(e) => {
+            return Some(RpcResponse::err(
+                id_hint,
+                RPC_INVALID_REQUEST,
+                format!("Invalid request: {e}"),
+            ));
+        }
+    };
+    if request.jsonrpc != "2.0" {
+        return request
+            .id
+            .map(|id| RpcResponse::err(id, RPC_INVALID_REQUEST, "jsonrpc must be \"2.0\""));
+    }
+    let id = request.id.clone().unwrap_or(serde_json::Value::Null);
+    let params = request.params.unwrap_or_else(|| serde_json::json!({}));
+
+    let response = match request.method.as_str() {
+        RPC_SUBSCRIBE_METHOD => rpc_subscribe(id, &params, ctx, session),
+        RPC_UNSUBSCRIBE_METHOD => {
+            let removed = params
+                .get("subscription")
+                .and_then(|v| v.as_u64())
+                .and_then(|sub| session.subscriptions.remove(&sub));
+            let unsubscribed = removed.is_some();
+            if let Some(handle) = removed {
+                handle.abort();
+            }
+            RpcResponse::ok(id, serde_json::json!({ "unsubscribed": unsubscribed }))
+        }
+        method @ (RPC_SESSION_COMMAND | "stream-subscribe" | "tail-events") => RpcResponse::err(
+            id,
+            RPC_METHOD_NOT_FOUND,
+            format!("'{method}' is not available in an RPC session; use {RPC_SUBSCRIBE_METHOD}"),
+        ),
+        method => {
+            let socket_request = SocketRequest {
+                version: PROTOCOL_VERSION,
+                request_id: match &id {
+                    serde_json::Value::String(id) => format!("rpc-{id}"),
+                    id => format!("rpc-{id}"),
+                },
+                command: method.to_string(),
+                payload: params,
+            };
+            let request_str = match serde_json::to_string(&socket_request) {
+                Ok(s) => s,
+                Err(e) => {
+                    return Some(RpcResponse::err(
+                        id,
+                        RPC_INVALID_REQUEST,
+                        format!("Invalid request: {e}"),
+                    ));
+                }
+            };
+            RpcResponse::from_socket_response(id, dispatch_request(&request_str, ctx).await)
+        }
+    };
+    request.id.map(|_| response)
+}
+
+/// Start an `rpc.subscribe` subscription that forwards broadcast items as
+/// notifications on the session.
+#[cfg(unix)]
+fn rpc_subscribe(
+    id: serde_json::Value,
+    params: &serde_json::Value,
+    ctx: &DispatchContext<'_>,
+    session: &mut RpcSessionState,
+) -> agent_team_mail_core::daemon_rpc::RpcResponse {
+    use agent_team_mail_core::daemon_rpc::{
+        RPC_EVENT_NOTIFICATION, RPC_INVALID_PARAMS, RPC_STREAM_NOTIFICATION, RPC_TOPIC_EVENTS,
+        RPC_TOPIC_STREAM, RpcResponse,
+    };
+
+    let subscription = session.next_subscription;
+    let tx = session.note_tx.clone();
+    let handle = match params.get("topic").and_then(|t| t.as_str()) {
+        Some(RPC_TOPIC_EVENTS) => {
+            let actions: Vec<String> = params
+                .get("actions")
+                .and_then(|a| serde_json::from_value(a.clone()).ok())
+                .unwrap_or_default();
+            tokio::spawn(forward_rpc_notifications(
+                ctx.daemon_event_sender.subscribe(),
+                RPC_EVENT_NOTIFICATION,
+                subscription,
+                tx,
+                move |event: &LogEventV1| actions.is_empty() || actions.contains(&event.action),
+            ))
+        }
+        Some(RPC_TOPIC_STREAM) => {
+            let agent = params
+                .get("agent")
+                .and_then(|a| a.as_str())
+                .map(str::to_string);
+            tokio::spawn(forward_rpc_notifications(
+                ctx.stream_event_sender.subscribe(),
+                RPC_STREAM_NOTIFICATION,
+                subscription,
+                tx,
+                move |event: &agent_team_mail_core::daemon_stream::DaemonStreamEvent| {
+                    agent.as_deref().is_none_or(|a| event.agent() == a)
+                },
+            ))
+        }
+        other => {
+            return RpcResponse::err(
+                id,
+                RPC_INVALID_PARAMS,
+                format!(
+                    "Unknown subscription topic {other:?}; expected \"{RPC_TOPIC_EVENTS}\" or \"{RPC_TOPIC_STREAM}\""
+                ),
+            );
+        }
+    };
+    session.next_subscription += 1;
+    session.subscriptions.insert(subscription, handle);
+    RpcResponse::ok(id, serde_json::json!({ "subscription": subscription }))
+}
+
+/// Forward matching broadcast items to a session as JSON-RPC notification
+/// lines until the channel closes or the session goes away.
+#[cfg(unix)]
+async fn forward_rpc_notifications<T, F>(
+    mut rx: tokio::sync::broadcast::Receiver<T>,
+    method: &'static str,
+    subscription: u64,
+    tx: tokio::sync::mpsc::Sender<String>,
+    matches: F,
+) where
+    T: Clone + serde::Serialize,
+    F: Fn(&T) -> bool,
+{
+    use agent_team_mail_core::daemon_rpc::RpcNotification;
+
+    loop {
+        match rx.recv().await {
+            Ok(item) => {
+                if !matches(&item) {
+                    continue;
+                }
+                let note = match serde_json::to_value(&item) {
+                    Ok(event) => RpcNotification::new(method, subscription, event),
+                    Err(e) => {
+                        warn!("rpc-session: failed to serialize {method}: {e}");
+                        continue;
+                    }
+                };
+                let Ok(line) = serde_json::to_string(&note) else {
+                    continue;
+                };
+                if tx.send(line).await.is_err() {
+                    break; // Session closed.
+                }
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                warn!("rpc-session: subscription {subscription} lagged by {n} events");
+            }
+        }
+    }
+}
+
 /// Handle a `"stream-event"` command: parse the [`DaemonStreamEvent`], validate
 /// sender authorization, update the per-agent stream state store, publish to
 /// the broadcast channel, and return an `{ok: true}` response.
@@ -1209,6 +1720,38 @@ fn session_identity_change_flags(
     (session_changed, process_changed)
 }
 
+/// Return the reason a hook event type is filtered out by `[plugins.hooks]`
+/// config, or `None` when the event should be processed.
+///
+/// `enabled_events` (when present) is an allowlist; `disabled_events` always
+/// wins. Config resolution failures fail open so hooks keep working.
+#[cfg(unix)]
+fn hook_event_filter_reason(team: &str, event_type: &str) -> Option<String> {
+    let home_dir = agent_team_mail_core::home::get_home_dir().ok()?;
+    let config = agent_team_mail_core::config::resolve_config(
+        &agent_team_mail_core::config::ConfigOverrides {
+            team: Some(team.to_string()),
+            ..Default::default()
+        },
+        &home_dir,
+        &home_dir,
+    )
+    .ok()?;
+    let table = config.plugin_config("hooks")?;
+    let listed = |key: &str| {
+        table.get(key).and_then(|v| v.as_array()).map(|events| {
+            events
+                .iter()
+                .filter_map(|e| e.as_str())
+                .any(|e| e.trim() == event_type)
+        })
+    };
+    if listed("disabled_events") == Some(true) || listed("enabled_events") == Some(false) {
+        return Some(format!("event type disabled: {event_type}"));
+    }
+    None
+}
+
 #[cfg(unix)]
 fn hook_action_name(event_type: &str) -> Option<&'static str> {
     match event_type {
@@ -1482,6 +2025,27 @@ async fn handle_hook_event_command_with_dedup(
         );
     }
 
+    if let Some(reason) = hook_event_filter_reason(&team, &event_type) {
+        emit_hook_event(
+            "info",
+            hook_action_name(&event_type).unwrap_or("hook.unknown"),
+            HookLogContext {
+                team: Some(team.as_str()),
+                agent: Some(agent.as_str()),
+                session_id: Some(session_id.as_str()),
+                process_id,
+            },
+            "ignored_disabled_event",
+            Some(reason.clone()),
+            Some(event_type.as_str()),
+        );
+        debug!("hook_event {reason}");
+        return make_ok_response(
+            &request.request_id,
+            serde_json::json!({"processed": false, "reason": reason}),
+        );
+    }
+
     let auth = match authorize_hook_event(&team, &agent, source_kind) {
         Ok(auth) => auth,
         Err(reason) => {
@@ -2195,13 +2759,19 @@ async fn handle_launch_command(request_str: &str, launch_tx: &LaunchSender) -> S
     // Create a oneshot channel for the response
     let (response_tx, response_rx) = tokio::sync::oneshot::channel();
 
+    let launch_id = launch_queue::enqueue_launch(&launch_config.agent, &launch_config.team);
     let launch_req = LaunchRequest {
+        launch_id,
         config: launch_config,
         response_tx,
     };
 
     // Send the launch request to the plugin
     if sender.send(launch_req).await.is_err() {
+        launch_queue::mark_launch_finished(
+            launch_id,
+            &Err("Launch channel closed before the request was queued".to_string()),
+        );
         return make_error_response(
             &request.request_id,
             "LAUNCH_UNAVAILABLE",
@@ -2280,8 +2850,11 @@ async fn handle_control_command(
         }
     };
 
+    let action = control_action_name(&control.action).trim_start_matches("control_");
+    let started = std::time::Instant::now();
     let ack =
         process_control_request(control, home, state_store, session_registry, dedup_store).await;
+    crate::daemon::control_metrics::record_control_latency(action, started.elapsed());
     make_ok_response(
         &request.request_id,
         serde_json::to_value(ack).unwrap_or_else(|_| serde_json::json!({})),
@@ -2578,6 +3151,18 @@ async fn process_control_request(
         return ack;
     }
 
+    // Rejected before dedupe so the caller can retry after restart.
+    if state_store.lock().unwrap().is_draining() {
+        let ack = control_ack(
+            &control.request_id,
+            ControlResult::Busy,
+            false,
+            Some("daemon is draining workers for shutdown".to_string()),
+        );
+        emit_control_ack_event(&control, &ack);
+        return ack;
+    }
+
     // Only accepted actions consume dedupe slots.
     let key = DedupeKey::new(
         &control.team,
@@ -2758,6 +3343,7 @@ fn parse_and_dispatch(
     let response = match request.command.as_str() {
         "agent-state" => handle_agent_state(&request, state_store, session_registry),
         "list-agents" => handle_list_agents(&request, state_store, session_registry),
+        "list-teams" => handle_list_teams(&request, session_registry),
         "agent-pane" => handle_agent_pane(&request, state_store),
         "subscribe" => handle_subscribe(&request, pubsub_store),
         "unsubscribe" => handle_unsubscribe(&request, pubsub_store),
@@ -2765,6 +3351,16 @@ fn parse_and_dispatch(
         "session-query" => handle_session_query(&request, session_registry),
         "session-query-team" => handle_session_query_team(&request, session_registry),
         "agent-stream-state" => handle_agent_stream_state(&request, stream_state_store),
+        "spool-drain" => handle_spool_drain(&request),
+        "message-read" => handle_message_read(&request),
+        "ping" => make_ok_response(
+            &request.request_id,
+            serde_json::json!({ "pid": std::process::id() }),
+        ),
+        "launch-status" => make_ok_response(
+            &request.request_id,
+            serde_json::to_value(launch_queue::launch_queue_snapshot()).unwrap_or_default(),
+        ),
         // "launch" is handled asynchronously before parse_and_dispatch is called.
         // If it somehow reaches here, return a clear internal error.
         "launch" => make_error_response(
@@ -2820,6 +3416,105 @@ fn parse_and_dispatch(
     Ok(response)
 }
 
+/// Handle the `message-read` command.
+///
+/// Payload: `{"team": "<team>", "agent": "<agent>", "message_id": "<id>"}`
+/// Response: `{"read": <bool>}` — whether the agent's merged inbox (local plus
+/// origin files) holds `message_id` with its read flag set. A message that is
+/// not (yet) in the inbox, e.g. still spooled, reports `false`.
+fn handle_message_read(
+    request: &agent_team_mail_core::daemon_client::SocketRequest,
+) -> SocketResponse {
+    let field = |name: &str| {
+        request
+            .payload
+            .get(name)
+            .and_then(|v| v.as_str())
+            .filter(|v| !v.is_empty())
+    };
+    let (Some(team), Some(agent), Some(message_id)) =
+        (field("team"), field("agent"), field("message_id"))
+    else {
+        return make_error_response(
+            &request.request_id,
+            "MISSING_PARAMETER",
+            "Missing required payload field: 'team', 'agent' and 'message_id' are required",
+        );
+    };
+    if [team, agent]
+        .iter()
+        .any(|segment| segment.contains(['/', '\\']) || *segment == "..")
+    {
+        return make_error_response(
+            &request.request_id,
+            SOCKET_ERROR_INVALID_PAYLOAD,
+            "'team' and 'agent' must be plain names",
+        );
+    }
+    let home = match agent_team_mail_core::home::get_home_dir() {
+        Ok(h) => h,
+        Err(e) => {
+            return make_error_response(
+                &request.request_id,
+                SOCKET_ERROR_INTERNAL_ERROR,
+                &format!("Failed to resolve ATM home: {e}"),
+            );
+        }
+    };
+    let team_dir = agent_team_mail_core::home::teams_root_dir_for(&home).join(team);
+    match agent_team_mail_core::io::inbox::inbox_read_merged(&team_dir, agent, None) {
+        Ok(messages) => {
+            let read = messages
+                .iter()
+                .any(|m| m.read && m.message_id.as_deref() == Some(message_id));
+            make_ok_response(&request.request_id, serde_json::json!({ "read": read }))
+        }
+        Err(e) => make_error_response(
+            &request.request_id,
+            SOCKET_ERROR_INTERNAL_ERROR,
+            &format!("Failed to read inbox for {agent}@{team}: {e}"),
+        ),
+    }
+}
+
+/// Handle the `spool-drain` command.
+///
+/// Payload: `{}`
+/// Response: `{"delivered": N, "pending": N, "failed": N}` for one immediate
+/// drain pass over the teams root.
+fn handle_spool_drain(
+    request: &agent_team_mail_core::daemon_client::SocketRequest,
+) -> SocketResponse {
+    let home = match agent_team_mail_core::home::get_home_dir() {
+        Ok(h) => h,
+        Err(e) => {
+            return make_error_response(
+                &request.request_id,
+                SOCKET_ERROR_INTERNAL_ERROR,
+                &format!("Failed to resolve ATM home: {e}"),
+            );
+        }
+    };
+    let teams_root = agent_team_mail_core::home::teams_root_dir_for(&home);
+    match crate::daemon::drain_spool_now(&teams_root) {
+        Ok(status) => {
+            info!(
+                "On-demand spool drain: delivered={}, pending={}, failed={}",
+                status.delivered, status.pending, status.failed
+            );
+            make_ok_response(
+                &request.request_id,
+                serde_json::to_value(status).unwrap_or_default(),
+            )
+        }
+        Err(e) => make_error_response(
+            &request.request_id,
+            SOCKET_ERROR_INTERNAL_ERROR,
+            &format!("Spool drain failed: {e}"),
+        ),
+    }
+}
+
 /// Handle the `agent-stream-state` command.
 ///
 /// Payload: `{"agent": "<agent-name>"}`
@@ -3321,8 +4016,9 @@ fn handle_agent_state(
 
 /// Handle the `list-agents` command.
 ///
-/// Payload: `{}`
-/// Response: array of `{"agent": "<name>", "state": "<state>"}`
+/// Payload: `{}`, or `{"team": "<team>"}`
+/// Response: array of `{"agent": "<name>", "state": "<state>"}`; team-scoped
+/// queries return [`CanonicalMemberState`] objects.
 fn handle_list_agents(
     request: &agent_team_mail_core::daemon_client::SocketRequest,
     state_store: &SharedStateStore,
@@ -3403,6 +4099,92 @@ fn handle_list_agents(
     make_ok_response(&request.request_id, serde_json::json!(agents))
 }
 
+/// Handle the `list-teams` command.
+///
+/// Payload: `{}`
+/// Response: array of [`TeamDaemonSummary`] for every team directory with a
+/// readable `config.json`, sorted by team name. `live_agents` counts members
+/// whose daemon session is active after a liveness refresh; `unread_total`
+/// sums unread messages across members' local inboxes.
+///
+/// [`TeamDaemonSummary`]: agent_team_mail_core::daemon_client::TeamDaemonSummary
+fn handle_list_teams(
+    request: &agent_team_mail_core::daemon_client::SocketRequest,
+    session_registry: &SharedSessionRegistry,
+) -> SocketResponse {
+    use agent_team_mail_core::daemon_client::TeamDaemonSummary;
+
+    let home = match agent_team_mail_core::home::get_home_dir() {
+        Ok(h) => h,
+        Err(e) => {
+            return make_error_response(
+                &request.request_id,
+                SOCKET_ERROR_INTERNAL_ERROR,
+                &format!("Failed to resolve ATM home: {e}"),
+            );
+        }
+    };
+    let teams_root = agent_team_mail_core::home::teams_root_dir_for(&home);
+    let mut team_names: Vec<String> = std::fs::read_dir(&teams_root)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().join("config.json").is_file())
+                .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    team_names.sort();
+
+    let teams: Vec<(String, Vec<agent_team_mail_core::schema::AgentMember>)> = team_names
+        .into_iter()
+        .filter_map(|team| load_team_members(&home, &team).map(|members| (team, members)))
+        .collect();
+
+    // Only the liveness snapshot needs the registry; inbox reads happen after
+    // the guard is dropped so other socket handlers are not blocked on disk.
+    let live_by_team: Vec<std::collections::BTreeSet<String>> = {
+        let mut session_guard = session_registry.lock().unwrap();
+        teams
+            .iter()
+            .map(|(team, _)| {
+                session_guard
+                    .sessions_for_team_with_liveness(team)
+                    .into_iter()
+                    .filter(|s| s.state == crate::daemon::session_registry::SessionState::Active)
+                    .map(|s| s.agent_name)
+                    .collect()
+            })
+            .collect()
+    };
+
+    let summaries: Vec<TeamDaemonSummary> = teams
+        .into_iter()
+        .zip(live_by_team)
+        .map(|((team, members), live)| {
+            let inboxes_dir = teams_root.join(&team).join("inboxes");
+            let unread_total = members
+                .iter()
+                .map(|m| inboxes_dir.join(format!("{}.json", m.name)))
+                .filter(|path| path.is_file())
+                .filter_map(|path| agent_team_mail_core::io::inbox_read_file_tolerant(&path).ok())
+                .map(|messages| messages.iter().filter(|m| !m.read).count())
+                .sum();
+            TeamDaemonSummary {
+                member_count: members.len(),
+                live_agents: live.into_iter().collect(),
+                unread_total,
+                team,
+            }
+        })
+        .collect();
+
+    make_ok_response(
+        &request.request_id,
+        serde_json::to_value(summaries).unwrap_or_else(|_| serde_json::json!([])),
+    )
+}
+
 fn load_team_members(
     home: &std::path::Path,
     team: &str,
@@ -4126,6 +4908,31 @@ mod tests {
     use tempfile::TempDir;
     use tracing_test::traced_test;
 
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_bind_daemon_listener_abstract_creates_no_socket_file() {
+        use std::os::linux::net::SocketAddrExt;
+
+        let temp = TempDir::new().unwrap();
+        let socket_path = temp.path().join("atm-daemon.sock");
+        let name = format!("atm-daemon-test-{}", std::process::id());
+        let listener = bind_daemon_listener(&socket_path, Some(&name)).unwrap();
+        assert!(!socket_path.exists());
+
+        let addr = std::os::unix::net::SocketAddr::from_abstract_name(name.as_bytes()).unwrap();
+        let _client = std::os::unix::net::UnixStream::connect_addr(&addr).unwrap();
+        tokio::time::timeout(Duration::from_secs(2), listener.accept())
+            .await
+            .expect("accept timed out")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_peer_uid_matches_same_user() {
+        let (a, _b) = tokio::net::UnixStream::pair().unwrap();
+        assert!(peer_uid_matches(&a));
+    }
+
     fn make_store() -> SharedStateStore {
         std::sync::Arc::new(std::sync::Mutex::new(AgentStateTracker::new()))
     }
@@ -4511,16 +5318,60 @@ mod tests {
 
     #[test]
     #[serial]
-    fn test_list_agents_team_scope_includes_daemon_only_sessions_as_unregistered() {
-        let _fixture = setup_hook_auth_fixture("atm-dev", "team-lead", &["team-lead"]);
-        let store = make_store();
+    fn test_list_agents_team_scope_includes_daemon_only_sessions_as_unregistered() {
+        let _fixture = setup_hook_auth_fixture("atm-dev", "team-lead", &["team-lead"]);
+        let store = make_store();
+        let sr = make_sr();
+        {
+            let mut reg = sr.lock().unwrap();
+            reg.upsert_runtime_for_team(
+                "atm-dev",
+                "arch-ctm",
+                "sess-ghost-1",
+                std::process::id(),
+                None,
+                None,
+                None,
+                None,
+            );
+        }
+
+        let req = make_request("list-agents", serde_json::json!({"team": "atm-dev"}));
+        let resp = handle_list_agents(&req, &store, &sr);
+        assert_eq!(resp.status, "ok");
+        let arr = resp.payload.unwrap().as_array().unwrap().clone();
+
+        let ghost = arr
+            .iter()
+            .find(|a| a["agent"].as_str() == Some("arch-ctm"))
+            .expect("daemon-only member missing");
+        assert_eq!(ghost["in_config"].as_bool(), Some(false));
+        assert_eq!(ghost["state"].as_str(), Some("active"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_list_teams_reports_members_live_agents_and_unread() {
+        let _fixture = setup_hook_auth_fixture("atm-dev", "team-lead", &["team-lead", "arch-ctm"]);
+        let home = agent_team_mail_core::home::get_home_dir().unwrap();
+        let inboxes = agent_team_mail_core::home::teams_root_dir_for(&home)
+            .join("atm-dev")
+            .join("inboxes");
+        std::fs::create_dir_all(&inboxes).unwrap();
+        std::fs::write(
+            inboxes.join("arch-ctm.json"),
+            r#"[{"from":"team-lead","text":"a","timestamp":"2026-02-11T10:00:00Z","read":false},
+                {"from":"team-lead","text":"b","timestamp":"2026-02-11T10:01:00Z","read":true}]"#,
+        )
+        .unwrap();
+
         let sr = make_sr();
         {
             let mut reg = sr.lock().unwrap();
             reg.upsert_runtime_for_team(
                 "atm-dev",
                 "arch-ctm",
-                "sess-ghost-1",
+                "sess-live-1",
                 std::process::id(),
                 None,
                 None,
@@ -4529,17 +5380,16 @@ mod tests {
             );
         }
 
-        let req = make_request("list-agents", serde_json::json!({"team": "atm-dev"}));
-        let resp = handle_list_agents(&req, &store, &sr);
+        let req = make_request("list-teams", serde_json::json!({}));
+        let resp = handle_list_teams(&req, &sr);
         assert_eq!(resp.status, "ok");
-        let arr = resp.payload.unwrap().as_array().unwrap().clone();
-
-        let ghost = arr
-            .iter()
-            .find(|a| a["agent"].as_str() == Some("arch-ctm"))
-            .expect("daemon-only member missing");
-        assert_eq!(ghost["in_config"].as_bool(), Some(false));
-        assert_eq!(ghost["state"].as_str(), Some("active"));
+        let teams: Vec<agent_team_mail_core::daemon_client::TeamDaemonSummary> =
+            serde_json::from_value(resp.payload.unwrap()).unwrap();
+        assert_eq!(teams.len(), 1);
+        assert_eq!(teams[0].team, "atm-dev");
+        assert_eq!(teams[0].member_count, 2);
+        assert_eq!(teams[0].live_agents, vec!["arch-ctm".to_string()]);
+        assert_eq!(teams[0].unread_total, 1);
     }
 
     #[test]
@@ -5096,6 +5946,68 @@ mod tests {
         assert!(!is_launch_command(
             r#"{"version":1,"request_id":"r1","command":"list-agents","payload":{}}"#
         ));
+        assert!(!is_launch_command(
+            r#"{"version":1,"request_id":"r1","command":"launch-status","payload":{}}"#
+        ));
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn test_launch_status_tracks_queued_launch_until_ready() {
+        use agent_team_mail_core::daemon_client::{LaunchQueueStatus, LaunchStatus};
+
+        let launch_tx = new_launch_sender();
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<LaunchRequest>(4);
+        *launch_tx.lock().await = Some(tx);
+
+        let req_json = r#"{"version":1,"request_id":"r1","command":"launch","payload":{"agent":"queue-probe","team":"atm-dev","command":"codex","timeout_secs":30,"env_vars":{}}}"#;
+        let launch = tokio::spawn({
+            let launch_tx = launch_tx.clone();
+            async move { handle_launch_command(req_json, &launch_tx).await }
+        });
+        let queued = rx.recv().await.expect("launch request queued");
+
+        let status_of = |id: u64| {
+            let req_json =
+                r#"{"version":1,"request_id":"r2","command":"launch-status","payload":{}}"#;
+            let resp = parse_and_dispatch(
+                req_json,
+                &make_store(),
+                &make_ps(),
+                &make_sr(),
+                &new_stream_state_store(),
+            )
+            .unwrap();
+            assert_eq!(resp.status, "ok");
+            let snapshot: LaunchQueueStatus =
+                serde_json::from_value(resp.payload.unwrap()).unwrap();
+            snapshot
+                .launches
+                .into_iter()
+                .find(|entry| entry.id == id)
+                .expect("launch tracked")
+        };
+
+        let entry = status_of(queued.launch_id);
+        assert_eq!(entry.agent, "queue-probe");
+        assert_eq!(entry.status, LaunchStatus::Pending);
+
+        launch_queue::mark_launch_started(queued.launch_id);
+        assert_eq!(status_of(queued.launch_id).status, LaunchStatus::Launching);
+
+        let result = Ok(agent_team_mail_core::daemon_client::LaunchResult {
+            agent: "queue-probe".to_string(),
+            pane_id: "%9".to_string(),
+            state: "idle".to_string(),
+            warning: None,
+        });
+        launch_queue::mark_launch_finished(queued.launch_id, &result);
+        let _ = queued.response_tx.send(result);
+        assert_eq!(launch.await.unwrap().status, "ok");
+
+        let entry = status_of(queued.launch_id);
+        assert_eq!(entry.status, LaunchStatus::Ready);
+        assert_eq!(entry.pane_id.as_deref(), Some("%9"));
     }
 
     #[test]
@@ -5124,6 +6036,93 @@ mod tests {
         assert_eq!(resp.error.unwrap().code, "UNKNOWN_COMMAND");
     }
 
+    #[test]
+    fn test_parse_and_dispatch_ping_reports_pid() {
+        let store = make_store();
+        let ps = make_ps();
+        let sr = make_sr();
+        let req_json = r#"{"version":1,"request_id":"r1","command":"ping","payload":{}}"#;
+        let resp =
+            parse_and_dispatch(req_json, &store, &ps, &sr, &new_stream_state_store()).unwrap();
+        assert!(resp.is_ok());
+        assert_eq!(resp.payload.unwrap()["pid"], std::process::id());
+    }
+
+    #[test]
+    #[serial]
+    fn test_parse_and_dispatch_spool_drain_delivers_pending_messages() {
+        let temp = TempDir::new().unwrap();
+        let _atm_home_guard = EnvGuard::set("ATM_HOME", temp.path().to_str().unwrap());
+        let message = agent_team_mail_core::schema::InboxMessage::new("team-lead", "spooled hello")
+            .message_id("msg-spool-1");
+        agent_team_mail_core::io::spool::spool_message("atm-dev", "arch-ctm", &message).unwrap();
+
+        let store = make_store();
+        let ps = make_ps();
+        let sr = make_sr();
+        let req_json = r#"{"version":1,"request_id":"r1","command":"spool-drain","payload":{}}"#;
+        let resp =
+            parse_and_dispatch(req_json, &store, &ps, &sr, &new_stream_state_store()).unwrap();
+        assert_eq!(resp.status, "ok");
+        let payload = resp.payload.unwrap();
+        assert_eq!(payload["delivered"], 1);
+        assert_eq!(payload["pending"], 0);
+        assert_eq!(payload["failed"], 0);
+
+        let inbox = temp
+            .path()
+            .join(".claude/teams/atm-dev/inboxes/arch-ctm.json");
+        let content = std::fs::read_to_string(inbox).unwrap();
+        assert!(content.contains("spooled hello"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_parse_and_dispatch_message_read_reports_read_flag() {
+        let temp = TempDir::new().unwrap();
+        let _atm_home_guard = EnvGuard::set("ATM_HOME", temp.path().to_str().unwrap());
+        let inboxes = temp.path().join(".claude/teams/atm-dev/inboxes");
+        std::fs::create_dir_all(&inboxes).unwrap();
+        std::fs::write(
+            inboxes.join("arch-ctm.json"),
+            serde_json::json!([
+                {"from": "team-lead", "text": "a", "timestamp": "2026-01-01T00:00:00Z", "read": true, "message_id": "m-read"},
+                {"from": "team-lead", "text": "b", "timestamp": "2026-01-01T00:01:00Z", "read": false, "message_id": "m-unread"}
+            ])
+            .to_string(),
+        )
+        .unwrap();
+
+        let store = make_store();
+        let ps = make_ps();
+        let sr = make_sr();
+        let dispatch = |payload: serde_json::Value| {
+            let req_json = serde_json::json!({
+                "version": 1,
+                "request_id": "r1",
+                "command": "message-read",
+                "payload": payload,
+            })
+            .to_string();
+            parse_and_dispatch(&req_json, &store, &ps, &sr, &new_stream_state_store()).unwrap()
+        };
+
+        for (id, expected) in [("m-read", true), ("m-unread", false), ("m-missing", false)] {
+            let resp = dispatch(
+                serde_json::json!({"team": "atm-dev", "agent": "arch-ctm", "message_id": id}),
+            );
+            assert_eq!(resp.status, "ok");
+            assert_eq!(resp.payload.unwrap()["read"], expected, "{id}");
+        }
+
+        let resp = dispatch(serde_json::json!({"team": "atm-dev", "agent": "arch-ctm"}));
+        assert_eq!(resp.status, "error");
+        let resp = dispatch(
+            serde_json::json!({"team": "..", "agent": "arch-ctm", "message_id": "m-read"}),
+        );
+        assert_eq!(resp.status, "error");
+    }
+
     #[test]
     fn test_parse_and_dispatch_register_hint_missing_team() {
         let store = make_store();
@@ -5830,6 +6829,57 @@ mod tests {
         assert_eq!(files, 1);
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_control_request_while_draining_returns_busy() {
+        use crate::plugins::worker_adapter::AgentState;
+        use uuid::Uuid;
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let state_store = make_store();
+        {
+            let mut tracker = state_store.lock().unwrap();
+            tracker.register_agent("arch-ctm");
+            tracker.set_state("arch-ctm", AgentState::Idle);
+            tracker.set_draining(true);
+        }
+        let sr = make_sr();
+        sr.lock()
+            .unwrap()
+            .upsert("arch-ctm", "sess-1", std::process::id());
+
+        let req = ControlRequest {
+            v: CONTROL_SCHEMA_VERSION,
+            request_id: Uuid::new_v4().to_string(),
+            msg_type: "control.stdin.request".to_string(),
+            signal: None,
+            sent_at: chrono::Utc::now().to_rfc3339(),
+            team: "atm-dev".to_string(),
+            session_id: "sess-1".to_string(),
+            agent_id: "arch-ctm".to_string(),
+            sender: "team-lead".to_string(),
+            action: ControlAction::Stdin,
+            payload: Some("hello".to_string()),
+            content_ref: None,
+            elicitation_id: None,
+            decision: None,
+        };
+
+        let dd = make_dd_in(&tmp);
+        let ack = process_control_request(req, tmp.path(), &state_store, &sr, &dd).await;
+        assert_eq!(
+            ack.result,
+            agent_team_mail_core::control::ControlResult::Busy
+        );
+        assert!(ack.detail.unwrap().contains("draining"));
+        assert!(
+            !tmp.path()
+                .join(".config/atm/agent-sessions/atm-dev/arch-ctm/stdin_queue")
+                .exists(),
+            "nothing is enqueued while draining"
+        );
+    }
+
     #[cfg(unix)]
     #[tokio::test]
     async fn test_control_stdin_updates_session_heartbeat() {
@@ -6189,6 +7239,7 @@ mod tests {
             dd,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             crate::daemon::new_log_event_queue(),
             &daemon_lock,
             cancel.clone(),
@@ -6265,6 +7316,7 @@ mod tests {
             dd,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             crate::daemon::new_log_event_queue(),
             &daemon_lock,
             cancel.clone(),
@@ -6332,6 +7384,7 @@ mod tests {
             dd,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             crate::daemon::new_log_event_queue(),
             &daemon_lock,
             cancel.clone(),
@@ -6420,6 +7473,7 @@ mod tests {
             dd,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             crate::daemon::new_log_event_queue(),
             &daemon_lock,
             cancel.clone(),
@@ -6500,6 +7554,7 @@ mod tests {
             dd,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             crate::daemon::new_log_event_queue(),
             &daemon_lock,
             cancel.clone(),
@@ -6810,6 +7865,41 @@ mod tests {
         );
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    #[serial]
+    async fn test_hook_event_disabled_event_type_is_not_processed() {
+        let fixture = setup_hook_auth_fixture("atm-dev", "team-lead", &["team-lead", "arch-ctm"]);
+        let config_dir = fixture._temp.path().join(".config/atm");
+        std::fs::create_dir_all(&config_dir).unwrap();
+        std::fs::write(
+            config_dir.join("config.toml"),
+            "[plugins.hooks]\ndisabled_events = [\"teammate_idle\"]\n",
+        )
+        .unwrap();
+        let store = make_store();
+        let sr = make_sr();
+        {
+            let mut tracker = store.lock().unwrap();
+            tracker.register_agent("arch-ctm");
+            tracker.set_state("arch-ctm", AgentState::Active);
+        }
+        let req_json = r#"{"version":1,"request_id":"r3-disabled","command":"hook-event","payload":{"event":"teammate_idle","agent":"arch-ctm","session_id":"","team":"atm-dev"}}"#;
+        let resp = handle_hook_event_command(req_json, &store, &sr).await;
+        assert_eq!(resp.status, "ok");
+        let payload = resp.payload.unwrap();
+        assert!(!payload["processed"].as_bool().unwrap());
+        assert_eq!(
+            payload["reason"].as_str().unwrap(),
+            "event type disabled: teammate_idle"
+        );
+        assert_eq!(
+            store.lock().unwrap().get_state("arch-ctm"),
+            Some(AgentState::Active),
+            "disabled teammate_idle must not change agent state"
+        );
+    }
+
     #[cfg(unix)]
     #[tokio::test]
     #[serial]
@@ -7600,6 +8690,7 @@ mod tests {
             dd,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             crate::daemon::new_log_event_queue(),
             &daemon_lock,
             cancel.clone(),
@@ -7743,6 +8834,7 @@ mod tests {
             dd,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             crate::daemon::new_log_event_queue(),
             &daemon_lock,
             cancel.clone(),
@@ -7880,6 +8972,7 @@ mod tests {
                 dd,
                 new_stream_state_store(),
                 new_stream_event_sender(),
+                new_daemon_event_sender(),
                 crate::daemon::new_log_event_queue(),
                 &daemon_lock,
                 cancel.clone(),
@@ -8738,6 +9831,208 @@ mod tests {
         );
     }
 
+    /// A `"tail-events"` connection streams the daemon's own events, filtered
+    /// by the requested actions.
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_socket_server_tail_events_streams_filtered_events() {
+        use agent_team_mail_core::logging_event::{LogEventV1, new_log_event};
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+        use tokio_util::sync::CancellationToken;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let home_dir = temp_dir.path().to_path_buf();
+        let cancel = CancellationToken::new();
+        let daemon_lock = {
+            let path = home_dir.join(".atm/daemon/daemon.lock");
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            agent_team_mail_core::io::lock::acquire_lock(&path, 0).unwrap()
+        };
+
+        let daemon_events = new_daemon_event_sender();
+        let (dd, _dd_dir) = make_dd();
+        let _handle = start_socket_server(
+            home_dir.clone(),
+            make_store(),
+            new_pubsub_store(),
+            new_launch_sender(),
+            make_sr(),
+            dd,
+            new_stream_state_store(),
+            new_stream_event_sender(),
+            daemon_events.clone(),
+            crate::daemon::new_log_event_queue(),
+            &daemon_lock,
+            cancel.clone(),
+        )
+        .await
+        .unwrap()
+        .expect("Expected socket server handle on unix");
+
+        let socket_path = home_dir.join(".atm/daemon/atm-daemon.sock");
+        let stream = tokio::net::UnixStream::connect(&socket_path).await.unwrap();
+        let request = serde_json::json!({
+            "version": PROTOCOL_VERSION,
+            "request_id": "tail-1",
+            "command": "tail-events",
+            "payload": {"actions": ["daemon_start"]}
+        });
+        let mut reader = BufReader::new(stream);
+        reader
+            .get_mut()
+            .write_all(format!("{request}\n").as_bytes())
+            .await
+            .unwrap();
+
+        let mut ack = String::new();
+        reader.read_line(&mut ack).await.unwrap();
+        let ack: serde_json::Value = serde_json::from_str(ack.trim()).unwrap();
+        assert_eq!(ack["streaming"], true);
+
+        // The server subscribed before acknowledging, so both sends are seen.
+        daemon_events
+            .send(new_log_event("atm-daemon", "spool_drain", "test", "info"))
+            .unwrap();
+        daemon_events
+            .send(new_log_event("atm-daemon", "daemon_start", "test", "info"))
+            .unwrap();
+
+        let mut line = String::new();
+        tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            reader.read_line(&mut line),
+        )
+        .await
+        .expect("tail-events line should arrive")
+        .unwrap();
+        let event: LogEventV1 = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(event.action, "daemon_start");
+
+        cancel.cancel();
+    }
+
+    /// An `"rpc-session"` connection answers several JSON-RPC requests and
+    /// pushes subscribed events as notifications on the same connection.
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_socket_server_rpc_session_requests_and_notifications() {
+        use agent_team_mail_core::daemon_rpc::RPC_METHOD_NOT_FOUND;
+        use agent_team_mail_core::logging_event::new_log_event;
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+        use tokio_util::sync::CancellationToken;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let home_dir = temp_dir.path().to_path_buf();
+        let cancel = CancellationToken::new();
+        let daemon_lock = {
+            let path = home_dir.join(".atm/daemon/daemon.lock");
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            agent_team_mail_core::io::lock::acquire_lock(&path, 0).unwrap()
+        };
+
+        let daemon_events = new_daemon_event_sender();
+        let (dd, _dd_dir) = make_dd();
+        let _handle = start_socket_server(
+            home_dir.clone(),
+            make_store(),
+            new_pubsub_store(),
+            new_launch_sender(),
+            make_sr(),
+            dd,
+            new_stream_state_store(),
+            new_stream_event_sender(),
+            daemon_events.clone(),
+            crate::daemon::new_log_event_queue(),
+            &daemon_lock,
+            cancel.clone(),
+        )
+        .await
+        .unwrap()
+        .expect("Expected socket server handle on unix");
+
+        let socket_path = home_dir.join(".atm/daemon/atm-daemon.sock");
+        let stream = tokio::net::UnixStream::connect(&socket_path).await.unwrap();
+        let mut reader = BufReader::new(stream);
+        let handshake = serde_json::json!({
+            "version": PROTOCOL_VERSION,
+            "request_id": "rpc-1",
+            "command": "rpc-session",
+            "payload": {"protocol": "jsonrpc-2.0"}
+        });
+        // Pipeline the first request with the handshake.
+        let ping = serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "ping"});
+        reader
+            .get_mut()
+            .write_all(format!("{handshake}\n{ping}\n").as_bytes())
+            .await
+            .unwrap();
+
+        async fn next_json(reader: &mut BufReader<tokio::net::UnixStream>) -> serde_json::Value {
+            let mut line = String::new();
+            tokio::time::timeout(
+                std::time::Duration::from_secs(5),
+                reader.read_line(&mut line),
+            )
+            .await
+            .expect("rpc line should arrive")
+            .unwrap();
+            serde_json::from_str(line.trim()).unwrap()
+        }
+
+        let ack = next_json(&mut reader).await;
+        assert_eq!(ack["status"], "ok");
+        assert_eq!(ack["payload"]["protocol"], "jsonrpc-2.0");
+
+        let pong = next_json(&mut reader).await;
+        assert_eq!(pong["id"], 1);
+        assert_eq!(pong["result"]["pid"], std::process::id());
+
+        let requests = [
+            serde_json::json!({"jsonrpc": "2.0", "id": 2, "method": "no-such-command"}),
+            serde_json::json!({"jsonrpc": "2.0", "id": 3, "method": "rpc.subscribe",
+                "params": {"topic": "events", "actions": ["daemon_start"]}}),
+        ];
+        for request in requests {
+            reader
+                .get_mut()
+                .write_all(format!("{request}\n").as_bytes())
+                .await
+                .unwrap();
+        }
+        let unknown = next_json(&mut reader).await;
+        assert_eq!(unknown["id"], 2);
+        assert_eq!(unknown["error"]["code"], RPC_METHOD_NOT_FOUND);
+        let subscribed = next_json(&mut reader).await;
+        assert_eq!(subscribed["id"], 3);
+        let subscription = subscribed["result"]["subscription"].as_u64().unwrap();
+
+        daemon_events
+            .send(new_log_event("atm-daemon", "spool_drain", "test", "info"))
+            .unwrap();
+        daemon_events
+            .send(new_log_event("atm-daemon", "daemon_start", "test", "info"))
+            .unwrap();
+
+        let note = next_json(&mut reader).await;
+        assert!(note.get("id").is_none(), "notification has no id: {note}");
+        assert_eq!(note["method"], "event");
+        assert_eq!(note["params"]["subscription"], subscription);
+        assert_eq!(note["params"]["event"]["action"], "daemon_start");
+
+        let unsubscribe = serde_json::json!({"jsonrpc": "2.0", "id": 4,
+            "method": "rpc.unsubscribe", "params": {"subscription": subscription}});
+        reader
+            .get_mut()
+            .write_all(format!("{unsubscribe}\n").as_bytes())
+            .await
+            .unwrap();
+        let unsubscribed = next_json(&mut reader).await;
+        assert_eq!(unsubscribed["id"], 4);
+        assert_eq!(unsubscribed["result"]["unsubscribed"], true);
+
+        cancel.cancel();
+    }
+
     // ── handle_log_event_command tests ───────────────────────────────────────
 
     /// Build a valid log-event socket request JSON string.
@@ -1,12 +1,26 @@
 //! Periodic spool drain task for guaranteed message delivery
 
+use agent_team_mail_core::io::{InboxError, SpoolStatus};
 use anyhow::Result;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::time::Duration;
 use tokio::time::interval;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info};
 
+/// Serializes drain passes so an on-demand drain never races the loop.
+static DRAIN_LOCK: Mutex<()> = Mutex::new(());
+
+/// Run one spool drain pass now.
+///
+/// Shared by the periodic loop and the `spool-drain` socket command; passes
+/// never overlap.
+pub fn drain_spool_now(inbox_base: &Path) -> Result<SpoolStatus, InboxError> {
+    let _guard = DRAIN_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    agent_team_mail_core::io::spool_drain(inbox_base)
+}
+
 /// Run a periodic spool drain loop until cancelled.
 ///
 /// Calls agent_team_mail_core::io::spool_drain() on the given inbox base directory
@@ -33,7 +47,7 @@ pub async fn spool_drain_loop(
         tokio::select! {
             _ = ticker.tick() => {
                 debug!("Running spool drain");
-                match agent_team_mail_core::io::spool_drain(&inbox_base) {
+                match drain_spool_now(&inbox_base) {
                     Ok(status) => {
                         if status.delivered > 0 || status.failed > 0 {
                             info!(
@@ -3,12 +3,14 @@
 //! Writes daemon status to `${ATM_HOME}/.atm/daemon/status.json` for CLI consumption.
 //! Status includes daemon PID, uptime, plugin states, and last update timestamp.
 
+use super::control_metrics::{ControlLatencyStats, control_latency_snapshot};
 use agent_team_mail_core::daemon_client::{
     DaemonTouchEntry, DaemonTouchSnapshot, RuntimeOwnerMetadata, daemon_touch_path_for,
 };
 pub use agent_team_mail_core::observability::{OtelHealthSnapshot as OtelHealth, OtelLastError};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -36,6 +38,10 @@ pub struct DaemonStatus {
     /// OTel exporter health snapshot.
     #[serde(default)]
     pub otel: OtelHealth,
+    /// Control-request ack latency keyed by action (`stdin`, `interrupt`,
+    /// `elicitation_response`).
+    #[serde(default)]
+    pub control_latency: BTreeMap<String, ControlLatencyStats>,
 }
 
 /// Logging pipeline health snapshot.
@@ -248,6 +254,7 @@ impl StatusWriter {
             teams,
             logging,
             otel,
+            control_latency: control_latency_snapshot(),
         };
 
         // Serialize to JSON
@@ -421,6 +428,30 @@ mod tests {
         assert_ne!(first_content, second_content);
     }
 
+    #[test]
+    fn test_status_writer_includes_control_latency() {
+        let temp_dir = TempDir::new().unwrap();
+        let writer = StatusWriter::new(
+            temp_dir.path().to_path_buf(),
+            "0.8.0".to_string(),
+            runtime_owner(temp_dir.path()),
+        );
+        super::super::control_metrics::record_control_latency(
+            "status_test_action",
+            Duration::from_millis(12),
+        );
+
+        writer
+            .write_status(vec![], vec![], logging_health(), otel_health())
+            .unwrap();
+
+        let content = std::fs::read_to_string(writer.status_path()).unwrap();
+        let status: DaemonStatus = serde_json::from_str(&content).unwrap();
+        let stats = &status.control_latency["status_test_action"];
+        assert!(stats.count >= 1);
+        assert!(stats.p50_ms >= 12.0);
+    }
+
     #[test]
     fn test_status_writer_correct_json_structure() {
         let temp_dir = TempDir::new().unwrap();
@@ -2,9 +2,12 @@
 
 use anyhow::{Context, Result};
 use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::future::Future;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::sync::mpsc::channel;
-use tokio::sync::mpsc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, mpsc};
+use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
@@ -118,6 +121,78 @@ pub async fn watch_inboxes(
     Ok(())
 }
 
+/// Bounded pool of inbox event workers.
+///
+/// Every job runs in a slot taken with [`reserve`](Self::reserve), so at most
+/// `max_workers` jobs run at once. The dispatch loop reserves a slot before
+/// pulling the next event, so while the pool is saturated excess events queue
+/// in the watcher channel instead of fanning out into unbounded tasks.
+pub struct InboxWorkerPool {
+    permits: Arc<Semaphore>,
+    tasks: JoinSet<()>,
+}
+
+impl InboxWorkerPool {
+    /// Create a pool running at most `max_workers` jobs (minimum 1).
+    pub fn new(max_workers: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(max_workers.max(1))),
+            tasks: JoinSet::new(),
+        }
+    }
+
+    /// Wait for a free worker slot.
+    ///
+    /// Cancel-safe: dropping the future gives up the wait without taking a
+    /// slot, so it can sit in a `select!` next to a cancellation branch.
+    pub async fn reserve(&self) -> WorkerSlot {
+        WorkerSlot {
+            _permit: self
+                .permits
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("inbox worker semaphore is never closed"),
+        }
+    }
+
+    /// Start `job` in `slot`, which is freed when the job finishes.
+    pub fn spawn<F>(&mut self, slot: WorkerSlot, job: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.reap_finished();
+        self.tasks.spawn(async move {
+            job.await;
+            drop(slot);
+        });
+    }
+
+    /// Wait for all in-flight jobs to finish.
+    pub async fn join_all(mut self) {
+        while let Some(result) = self.tasks.join_next().await {
+            log_worker_result(result);
+        }
+    }
+
+    fn reap_finished(&mut self) {
+        while let Some(result) = self.tasks.try_join_next() {
+            log_worker_result(result);
+        }
+    }
+}
+
+/// A free [`InboxWorkerPool`] slot, taken with [`InboxWorkerPool::reserve`].
+pub struct WorkerSlot {
+    _permit: OwnedSemaphorePermit,
+}
+
+fn log_worker_result(result: std::result::Result<(), tokio::task::JoinError>) {
+    if let Err(e) = result {
+        warn!("Inbox worker task failed: {e}");
+    }
+}
+
 /// Parse a notify Event into zero or more InboxEvents.
 ///
 /// Returns None if the event is not relevant (non-inbox/config file).
@@ -260,6 +335,63 @@ fn parse_agent_and_origin(
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_worker_pool_never_exceeds_cap() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let max_workers = 3;
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let completed = Arc::new(AtomicUsize::new(0));
+        let mut pool = InboxWorkerPool::new(max_workers);
+
+        for _ in 0..50 {
+            let running = running.clone();
+            let peak = peak.clone();
+            let completed = completed.clone();
+            let slot = pool.reserve().await;
+            pool.spawn(slot, async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                completed.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        pool.join_all().await;
+
+        assert_eq!(completed.load(Ordering::SeqCst), 50);
+        assert_eq!(peak.load(Ordering::SeqCst), max_workers);
+    }
+
+    #[tokio::test]
+    async fn test_worker_pool_reserve_is_cancel_safe() {
+        let pool = InboxWorkerPool::new(1);
+        let held = pool.reserve().await;
+
+        // Saturated: the wait can be abandoned without taking a slot.
+        let waited =
+            tokio::time::timeout(std::time::Duration::from_millis(20), pool.reserve()).await;
+        assert!(waited.is_err(), "reserve returned while the pool was full");
+
+        drop(held);
+        tokio::time::timeout(std::time::Duration::from_secs(1), pool.reserve())
+            .await
+            .expect("slot was not returned after the abandoned wait");
+    }
+
+    #[tokio::test]
+    async fn test_worker_pool_zero_cap_still_makes_progress() {
+        let mut pool = InboxWorkerPool::new(0);
+        let (tx, mut rx) = mpsc::channel(1);
+        let slot = pool.reserve().await;
+        pool.spawn(slot, async move {
+            tx.send(()).await.unwrap();
+        });
+        pool.join_all().await;
+        assert!(rx.recv().await.is_some());
+    }
+
     #[test]
     fn test_parse_event_inbox_create() {
         let teams_root = std::env::temp_dir().join("teams");
@@ -4,9 +4,9 @@ use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
 use agent_team_mail_core::logging;
 use agent_team_mail_daemon::daemon;
 use agent_team_mail_daemon::daemon::{
-    LogWriterConfig, StatusWriter, new_dedup_store, new_launch_sender, new_log_event_queue,
-    new_pubsub_store, new_session_registry, new_state_store, new_stream_event_sender,
-    new_stream_state_store, run_log_writer_task,
+    LogWriterConfig, StatusWriter, new_daemon_event_sender, new_dedup_store, new_launch_sender,
+    new_log_event_queue, new_pubsub_store, new_session_registry, new_state_store,
+    new_stream_event_sender, new_stream_state_store, run_log_writer_task,
 };
 use agent_team_mail_daemon::plugin::{MailService, PluginContext, PluginRegistry};
 use agent_team_mail_daemon::roster::RosterService;
@@ -104,20 +104,55 @@ struct Args {
     #[arg(short, long)]
     verbose: bool,
 
-    /// Run in background/daemon mode (future: fork/detach)
+    /// Fork and detach from the terminal (Unix only). The launching process
+    /// exits once the daemon PID is written to the PID file.
     #[arg(short, long)]
     daemon: bool,
+
+    /// Listen on a Linux abstract-namespace socket instead of the socket file
+    /// (default name: atm-daemon-<hash of ATM_HOME>). Clients must set
+    /// ATM_DAEMON_ABSTRACT_SOCKET or `[daemon] abstract_socket` to match.
+    #[arg(long, value_name = "NAME", num_args = 0..=1, default_missing_value = "1")]
+    abstract_socket: Option<String>,
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
+fn main() -> Result<()> {
     let args = Args::parse();
 
+    // Detach before the runtime starts: only the forking thread survives fork().
+    let pid_reporter = if args.daemon {
+        let stdio_log = agent_team_mail_core::daemon_client::daemon_runtime_dir()?
+            .join(daemon::detach::DETACHED_STDIO_LOG);
+        match daemon::detach::detach(&stdio_log)? {
+            daemon::detach::Detached::Parent(_) => return Ok(()),
+            daemon::detach::Detached::Daemon(reporter) => Some(reporter),
+        }
+    } else {
+        None
+    };
+
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .context("Failed to build tokio runtime")?
+        .block_on(run_daemon(args, pid_reporter))
+}
+
+async fn run_daemon(args: Args, pid_reporter: Option<daemon::detach::PidReporter>) -> Result<()> {
     // Initialize shared logging. --verbose maps to ATM_LOG=debug.
     if args.verbose {
         // SAFETY: process-local env mutation during startup before worker tasks spawn.
         unsafe { std::env::set_var("ATM_LOG", "debug") };
     }
+    if let Some(name) = &args.abstract_socket {
+        // SAFETY: process-local env mutation during startup before worker tasks spawn.
+        unsafe {
+            std::env::set_var(
+                agent_team_mail_core::daemon_client::ABSTRACT_SOCKET_ENV,
+                name,
+            )
+        };
+    }
 
     // Determine home directory early for lock/log path resolution.
     let home_dir =
@@ -202,6 +237,10 @@ async fn main() -> Result<()> {
         &runtime_owner,
     )
     .context("Failed to write daemon lock metadata")?;
+    // A detached launcher exits successfully only once this instance owns the lock.
+    if let Some(reporter) = pid_reporter {
+        reporter.report()?;
+    }
     daemon::startup_auth::persist_runtime_metadata_from_token(&home_dir, &launch_token)
         .context("Failed to persist launch lease metadata")?;
     daemon::startup_auth::log_launch_accepted(&home_dir, &launch_token);
@@ -222,7 +261,7 @@ async fn main() -> Result<()> {
     info!("ATM Daemon starting...");
 
     if args.daemon {
-        info!("Daemon mode requested (note: fork/detach not yet implemented)");
+        info!("Running detached (pid {})", std::process::id());
     }
 
     // Merge any spool files written by producers while the daemon was offline.
@@ -255,6 +294,8 @@ async fn main() -> Result<()> {
     let config =
         agent_team_mail_core::config::resolve_config(&config_overrides, &current_dir, &home_dir)
             .context("Failed to resolve configuration")?;
+    agent_team_mail_core::config::install_inbox_file_mode(&config.core);
+    agent_team_mail_core::config::install_inbox_provenance(&config.core);
     emit_event_best_effort(EventFields {
         level: "info",
         source: "atm-daemon",
@@ -449,6 +490,10 @@ async fn main() -> Result<()> {
     // Create the broadcast sender for push-based stream event fanout.
     let stream_event_sender = new_stream_event_sender();
 
+    // Tee the daemon's own emitted events to `atm daemon tail-events` streams.
+    let daemon_event_sender = new_daemon_event_sender();
+    daemon::install_daemon_event_tee(daemon_event_sender.clone());
+
     // Create the bounded log event queue and async writer task.
     let log_event_queue = new_log_event_queue();
     let log_cancel = cancel_token.clone();
@@ -480,6 +525,7 @@ async fn main() -> Result<()> {
         dedup_store,
         stream_state_store,
         stream_event_sender,
+        daemon_event_sender,
         log_event_queue,
     )
     .await;
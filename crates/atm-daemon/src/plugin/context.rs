@@ -1,4 +1,4 @@
-use super::MailService;
+use super::{MailService, NotifyTarget, PluginError, resolve_notification_target};
 use crate::roster::RosterService;
 use agent_team_mail_core::config::Config;
 use agent_team_mail_core::context::SystemContext;
@@ -37,4 +37,9 @@ impl PluginContext {
     pub fn plugin_config(&self, plugin_name: &str) -> Option<&toml::Table> {
         self.config.plugin_config(plugin_name)
     }
+
+    /// Resolve the `[notifications]` recipient configured for `source`
+    pub fn notification_target(&self, source: &str) -> Result<Option<NotifyTarget>, PluginError> {
+        resolve_notification_target(&self.config.notifications, source)
+    }
 }
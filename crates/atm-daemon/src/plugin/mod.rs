@@ -1,11 +1,13 @@
 pub mod context;
 pub mod mail_service;
+pub mod notifications;
 pub mod registry;
 pub mod traits;
 pub mod types;
 
 pub use context::PluginContext;
 pub use mail_service::MailService;
+pub use notifications::{NotifyTarget, resolve_notification_target};
 pub use registry::{FailedPluginInit, PluginRegistry, SharedPlugin};
 pub use traits::{ErasedPlugin, Plugin};
 pub use types::{Capability, PluginError, PluginMetadata, PluginState};
@@ -0,0 +1,120 @@
+//! Central routing for daemon-generated system notifications
+//!
+//! Plugins that deliver notifications through [`MailService`](super::MailService)
+//! resolve their recipient here so `[notifications]` routing applies uniformly.
+//! Precedence: the plugin's own explicit target setting, then
+//! `[notifications.overrides] <source>`, then `[notifications] default_target`,
+//! then the plugin's built-in fallback.
+
+use super::PluginError;
+use agent_team_mail_core::config::NotificationsConfig;
+
+/// Notification recipient in `agent` or `agent@team` form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotifyTarget {
+    /// Agent name to notify
+    pub agent: String,
+    /// Team name (None = use config team)
+    pub team: Option<String>,
+}
+
+impl NotifyTarget {
+    /// Parse a notify target from a string in the format "agent" or "agent@team"
+    ///
+    /// # Errors
+    ///
+    /// Returns `PluginError::Config` if the format is invalid (e.g., empty, multiple @)
+    pub fn parse(s: &str) -> Result<Self, PluginError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(PluginError::Config {
+                message: "notify_target cannot be empty".to_string(),
+            });
+        }
+
+        let parts: Vec<&str> = s.split('@').collect();
+        match parts.len() {
+            1 => Ok(Self {
+                agent: parts[0].to_string(),
+                team: None,
+            }),
+            2 => {
+                if parts[0].is_empty() || parts[1].is_empty() {
+                    return Err(PluginError::Config {
+                        message: format!("Invalid notify_target format: '{s}'"),
+                    });
+                }
+                Ok(Self {
+                    agent: parts[0].to_string(),
+                    team: Some(parts[1].to_string()),
+                })
+            }
+            _ => Err(PluginError::Config {
+                message: format!("Invalid notify_target format (multiple @): '{s}'"),
+            }),
+        }
+    }
+}
+
+/// Resolve the configured `[notifications]` recipient for `source`.
+///
+/// Returns `Ok(None)` when neither an override nor `default_target` is set,
+/// leaving the caller to apply its own fallback.
+///
+/// # Errors
+///
+/// Returns `PluginError::Config` if the configured target is malformed.
+pub fn resolve_notification_target(
+    notifications: &NotificationsConfig,
+    source: &str,
+) -> Result<Option<NotifyTarget>, PluginError> {
+    notifications
+        .target_for(source)
+        .map(|target| {
+            NotifyTarget::parse(target).map_err(|e| PluginError::Config {
+                message: format!("[notifications] target for '{source}': {e}"),
+            })
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_prefers_source_override() {
+        let mut notifications = NotificationsConfig {
+            default_target: Some("team-lead".to_string()),
+            ..Default::default()
+        };
+        notifications
+            .overrides
+            .insert("issues".to_string(), "triage@ops".to_string());
+
+        let target = resolve_notification_target(&notifications, "issues")
+            .unwrap()
+            .unwrap();
+        assert_eq!(target.agent, "triage");
+        assert_eq!(target.team.as_deref(), Some("ops"));
+
+        let target = resolve_notification_target(&notifications, "gh_monitor")
+            .unwrap()
+            .unwrap();
+        assert_eq!(target.agent, "team-lead");
+        assert_eq!(target.team, None);
+    }
+
+    #[test]
+    fn test_resolve_unset_and_malformed() {
+        let mut notifications = NotificationsConfig::default();
+        assert!(
+            resolve_notification_target(&notifications, "issues")
+                .unwrap()
+                .is_none()
+        );
+
+        notifications.default_target = Some("a@b@c".to_string());
+        assert!(resolve_notification_target(&notifications, "issues").is_err());
+    }
+}
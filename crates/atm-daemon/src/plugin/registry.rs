@@ -42,6 +42,11 @@ impl PluginRegistry {
     }
 
     /// Initialize all registered plugins
+    ///
+    /// Init failures disable the failing plugin and are recorded for
+    /// status/doctor. The exception is [`PluginError::ProviderLoad`]
+    /// (`provider_load_failure = "fail-fast"`), which is returned immediately so
+    /// daemon startup aborts.
     pub async fn init_all(&mut self, ctx: &PluginContext) -> Result<(), PluginError> {
         for entry in &mut self.plugins {
             match entry.plugin.init(ctx).await {
@@ -52,6 +57,9 @@ impl PluginRegistry {
                 Err(err) => {
                     entry.state = PluginState::Failed;
                     entry.init_error = Some(err.to_string());
+                    if matches!(err, PluginError::ProviderLoad { .. }) {
+                        return Err(err);
+                    }
                 }
             }
         }
@@ -165,6 +173,7 @@ mod tests {
 
     struct OkPlugin;
     struct FailPlugin;
+    struct ProviderLoadFailPlugin;
     struct RuntimeErrPlugin;
     struct RuntimePanicPlugin;
     struct ToggleInitPlugin {
@@ -227,6 +236,34 @@ mod tests {
         }
     }
 
+    impl Plugin for ProviderLoadFailPlugin {
+        fn metadata(&self) -> PluginMetadata {
+            PluginMetadata {
+                name: "provider_load_fail_plugin",
+                version: "0.1.0",
+                description: "provider load fail",
+                capabilities: vec![],
+            }
+        }
+
+        async fn init(&mut self, _ctx: &PluginContext) -> Result<(), PluginError> {
+            Err(PluginError::ProviderLoad {
+                message: "Failed to load provider from broken.so".to_string(),
+            })
+        }
+
+        async fn run(
+            &mut self,
+            _cancel: tokio_util::sync::CancellationToken,
+        ) -> Result<(), PluginError> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), PluginError> {
+            Ok(())
+        }
+    }
+
     impl Plugin for RuntimeErrPlugin {
         fn metadata(&self) -> PluginMetadata {
             PluginMetadata {
@@ -371,6 +408,19 @@ identity = "team-lead"
         assert_eq!(runnable[0].0.name, "ok_plugin");
     }
 
+    #[tokio::test]
+    async fn test_init_all_aborts_on_fail_fast_provider_load() {
+        let mut registry = PluginRegistry::new();
+        registry.register(FailPlugin);
+        registry.register(ProviderLoadFailPlugin);
+        registry.register(OkPlugin);
+        let ctx = test_context();
+
+        let err = registry.init_all(&ctx).await.unwrap_err();
+        assert!(matches!(err, PluginError::ProviderLoad { .. }));
+        assert!(err.to_string().contains("broken.so"));
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_runtime_faults_are_isolated_to_failing_plugins() {
         let mut registry = PluginRegistry::new();
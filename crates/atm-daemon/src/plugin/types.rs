@@ -80,4 +80,9 @@ pub enum PluginError {
         #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
+
+    /// A provider library failed to load under `provider_load_failure =
+    /// "fail-fast"`; unlike other init errors this aborts daemon startup.
+    #[error("provider load failed: {message}")]
+    ProviderLoad { message: String },
 }
@@ -8,8 +8,9 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::{Path, PathBuf};
 use tokio::fs;
-use uuid::Uuid;
 
+use agent_team_mail_core::config::MessageIdScheme;
+use agent_team_mail_core::io::{InboxError, new_message_id};
 use agent_team_mail_core::schema::InboxMessage;
 
 /// Maximum number of message_ids to keep in dedup cache
@@ -211,20 +212,31 @@ impl Default for SyncState {
 /// Assign message_id to messages that don't have one
 ///
 /// This ensures all messages have a unique identifier for deduplication.
+/// Ids come from the shared allocator for `scheme` against `inbox_path`.
 /// Messages that already have a message_id are not modified.
-pub fn assign_message_ids(messages: &mut [InboxMessage]) {
+///
+/// # Errors
+///
+/// Returns an error if the `counter` scheme cannot advance the inbox's
+/// sequence file.
+pub fn assign_message_ids(
+    messages: &mut [InboxMessage],
+    scheme: MessageIdScheme,
+    inbox_path: &Path,
+) -> Result<(), InboxError> {
     for msg in messages {
         if msg.message_id.is_none() {
-            msg.message_id = Some(Uuid::new_v4().to_string());
+            msg.message_id = Some(new_message_id(scheme, inbox_path)?);
         }
     }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
     use tempfile::TempDir;
+    use uuid::Uuid;
 
     #[test]
     fn test_sync_state_new() {
@@ -313,39 +325,16 @@ mod tests {
     #[test]
     fn test_assign_message_ids() {
         let mut messages = vec![
-            InboxMessage {
-                from: "user-a".to_string(),
-                source_team: None,
-                text: "Message 1".to_string(),
-                timestamp: "2026-02-16T10:00:00Z".to_string(),
-                read: false,
-                summary: None,
-                message_id: None,
-                unknown_fields: HashMap::new(),
-            },
-            InboxMessage {
-                from: "user-b".to_string(),
-                source_team: None,
-                text: "Message 2".to_string(),
-                timestamp: "2026-02-16T10:05:00Z".to_string(),
-                read: false,
-                summary: None,
-                message_id: Some("existing-id".to_string()),
-                unknown_fields: HashMap::new(),
-            },
-            InboxMessage {
-                from: "user-c".to_string(),
-                source_team: None,
-                text: "Message 3".to_string(),
-                timestamp: "2026-02-16T10:10:00Z".to_string(),
-                read: false,
-                summary: None,
-                message_id: None,
-                unknown_fields: HashMap::new(),
-            },
+            InboxMessage::new("user-a", "Message 1").timestamp("2026-02-16T10:00:00Z"),
+            InboxMessage::new("user-b", "Message 2")
+                .timestamp("2026-02-16T10:05:00Z")
+                .message_id("existing-id"),
+            InboxMessage::new("user-c", "Message 3").timestamp("2026-02-16T10:10:00Z"),
         ];
 
-        assign_message_ids(&mut messages);
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent-1.json");
+        assign_message_ids(&mut messages, MessageIdScheme::Uuid, &inbox_path).unwrap();
 
         // First message should get a new UUID
         assert!(messages[0].message_id.is_some());
@@ -367,8 +356,31 @@ mod tests {
     #[test]
     fn test_assign_message_ids_empty_vec() {
         let mut messages: Vec<InboxMessage> = Vec::new();
-        assign_message_ids(&mut messages);
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent-1.json");
+        assign_message_ids(&mut messages, MessageIdScheme::Counter, &inbox_path).unwrap();
         assert!(messages.is_empty());
+        assert!(!temp_dir.path().join("agent-1.json.seq").exists());
+    }
+
+    #[test]
+    fn test_assign_message_ids_uses_configured_scheme() {
+        let message = |message_id: Option<&str>| InboxMessage {
+            message_id: message_id.map(str::to_string),
+            ..InboxMessage::new("user-a", "Message").timestamp("2026-02-16T10:00:00Z")
+        };
+        let mut messages = vec![message(None), message(Some("existing-id")), message(None)];
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("agent-1.json");
+
+        assign_message_ids(&mut messages, MessageIdScheme::Counter, &inbox_path).unwrap();
+
+        let ids: Vec<_> = messages
+            .iter()
+            .map(|m| m.message_id.clone().unwrap())
+            .collect();
+        assert_eq!(ids[1], "existing-id");
+        assert!(ids[0] < ids[2], "counter ids must be monotonic: {ids:?}");
     }
 
     #[test]
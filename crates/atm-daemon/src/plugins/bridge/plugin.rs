@@ -150,7 +150,8 @@ impl Plugin for BridgePlugin {
         .map_err(|e| PluginError::Runtime {
             message: format!("Failed to create sync engine: {e}"),
             source: None,
-        })?;
+        })?
+        .with_id_scheme(ctx.config.messaging.id_scheme.unwrap_or_default());
 
         self.sync_engine = Some(Arc::new(Mutex::new(sync_engine)));
         self.team_dir = Some(team_dir);
@@ -15,6 +15,10 @@ use super::metrics::BridgeMetrics;
 use super::self_write_filter::SelfWriteFilter;
 use super::team_config_sync::sync_team_config;
 use super::transport::Transport;
+use agent_team_mail_core::config::MessageIdScheme;
+use agent_team_mail_core::io::InboxError;
+use agent_team_mail_core::io::inbox::{apply_inbox_file_mode, inbox_append_many};
+use agent_team_mail_core::io::spool::spool_message;
 use agent_team_mail_core::schema::{InboxMessage, TeamConfig};
 use std::collections::HashSet;
 
@@ -73,6 +77,9 @@ pub struct SyncEngine {
 
     /// Known agent names from team config (if available)
     agent_names: Option<HashSet<String>>,
+
+    /// Scheme for ids assigned to local messages that lack one
+    id_scheme: MessageIdScheme,
 }
 
 impl SyncEngine {
@@ -111,9 +118,16 @@ impl SyncEngine {
             metrics_path,
             self_write_filter,
             agent_names,
+            id_scheme: MessageIdScheme::default(),
         })
     }
 
+    /// Use `id_scheme` for ids assigned to local messages that lack one
+    pub fn with_id_scheme(mut self, id_scheme: MessageIdScheme) -> Self {
+        self.id_scheme = id_scheme;
+        self
+    }
+
     /// Get a reference to the sync state
     ///
     /// Exposed for testing and monitoring purposes
@@ -450,6 +464,7 @@ impl SyncEngine {
         let temp_path = path.with_extension("retention-tmp");
         let content = serde_json::to_vec_pretty(kept_messages)?;
         fs::write(&temp_path, &content).await?;
+        apply_inbox_file_mode(&temp_path)?;
         fs::rename(&temp_path, path).await?;
 
         Ok(trimmed_count)
@@ -497,7 +512,7 @@ impl SyncEngine {
             serde_json::from_slice(&content).context("Failed to parse inbox file")?;
 
         // Assign message_ids to messages that don't have one
-        assign_message_ids(&mut messages);
+        assign_message_ids(&mut messages, self.id_scheme, local_path)?;
 
         // Get cursor for this file + remote combination
         let rel_path = local_path.strip_prefix(&self.team_dir)?;
@@ -696,19 +711,25 @@ impl SyncEngine {
     }
 
     /// Pull a single file from remote
+    ///
+    /// The downloaded messages are delivered into the per-origin file in one
+    /// locked batch, so messages already there (and their local read state)
+    /// are kept. If the inbox lock cannot be taken, the messages are spooled
+    /// for later delivery instead.
     async fn pull_file(
         &mut self,
         remote_path: &Path,
         local_path: &Path,
         transport: &tokio::sync::MutexGuard<'_, dyn Transport>,
     ) -> Result<usize> {
-        // Download to temp file first
-        let temp_path = local_path.with_extension("tmp");
-        transport.download(remote_path, &temp_path).await?;
+        // Download to a staging file outside the inbox's own `.tmp` path
+        let staging_path = local_path.with_extension("pull");
+        transport.download(remote_path, &staging_path).await?;
 
         // Read messages
-        let content = fs::read(&temp_path).await?;
-        let messages: Vec<InboxMessage> = serde_json::from_slice(&content)?;
+        let content = fs::read(&staging_path).await;
+        let _ = fs::remove_file(&staging_path).await;
+        let messages: Vec<InboxMessage> = serde_json::from_slice(&content?)?;
 
         // Register self-write to avoid watcher feedback
         {
@@ -716,10 +737,32 @@ impl SyncEngine {
             filter.register(local_path.to_path_buf());
         }
 
-        // Atomic rename to final path
-        fs::rename(&temp_path, local_path).await?;
+        let team = self
+            .team_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let agent = local_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let inbox_path = local_path.to_path_buf();
+        let count = messages.len();
+        tokio::task::spawn_blocking(move || match inbox_append_many(&inbox_path, &messages) {
+            Ok(_) => Ok(()),
+            Err(InboxError::LockTimeout { .. }) => {
+                // The spool drains `<agent>@<team>` into `inboxes/<agent>.json`,
+                // which for a per-origin stem is this same file.
+                for message in &messages {
+                    spool_message(&team, &agent, message)?;
+                }
+                Ok(())
+            }
+            Err(e) => Err(e),
+        })
+        .await??;
 
-        Ok(messages.len())
+        Ok(count)
     }
 
     /// Check if a path is a local inbox file (not a per-origin file)
@@ -881,14 +924,8 @@ mod tests {
 
     fn create_test_message(from: &str, text: &str, message_id: Option<String>) -> InboxMessage {
         InboxMessage {
-            from: from.to_string(),
-            source_team: None,
-            text: text.to_string(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: None,
             message_id,
-            unknown_fields: HashMap::new(),
+            ..InboxMessage::new(from, text)
         }
     }
 
@@ -969,7 +1006,9 @@ mod tests {
             create_test_message("user-b", "Message 2", Some("existing-id".to_string())),
         ];
 
-        assign_message_ids(&mut messages);
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_path = temp_dir.path().join("user-a.json");
+        assign_message_ids(&mut messages, MessageIdScheme::Uuid, &inbox_path).unwrap();
 
         assert!(messages[0].message_id.is_some());
         assert_eq!(messages[1].message_id, Some("existing-id".to_string()));
@@ -1207,6 +1246,68 @@ mod tests {
         assert_eq!(local.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_sync_pull_merges_into_per_origin_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let team_dir = temp_dir.path().join("test-team");
+        let inboxes_dir = team_dir.join("inboxes");
+        fs::create_dir_all(&inboxes_dir).await.unwrap();
+
+        // The remote's base inbox holds two messages.
+        let mock = MockTransport::new();
+        let mut remote = mock.clone();
+        remote.connect().await.unwrap();
+        let remote_messages = vec![
+            create_test_message("user-a", "first", Some("msg-1".to_string())),
+            create_test_message("user-b", "second", Some("msg-2".to_string())),
+        ];
+        let upload_path = temp_dir.path().join("upload.json");
+        fs::write(
+            &upload_path,
+            serde_json::to_vec_pretty(&remote_messages).unwrap(),
+        )
+        .await
+        .unwrap();
+        remote
+            .upload(&upload_path, Path::new("test-team/inboxes/agent-1.json"))
+            .await
+            .unwrap();
+
+        // The first message was already pulled and has been read locally.
+        let mut already_read = remote_messages[0].clone();
+        already_read.read = true;
+        let origin_file = inboxes_dir.join("agent-1.hub.json");
+        fs::write(
+            &origin_file,
+            serde_json::to_vec_pretty(&[already_read]).unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let mut transports: HashMap<String, Arc<tokio::sync::Mutex<dyn Transport>>> =
+            HashMap::new();
+        transports.insert("hub".to_string(), Arc::new(tokio::sync::Mutex::new(mock)));
+        let mut engine = SyncEngine::new(
+            create_test_config("laptop", "hub"),
+            transports,
+            team_dir.clone(),
+            new_filter(),
+        )
+        .await
+        .unwrap();
+
+        let stats = engine.sync_pull().await.unwrap();
+        assert_eq!(stats.errors, 0);
+
+        let content = fs::read(&origin_file).await.unwrap();
+        let merged: Vec<InboxMessage> = serde_json::from_slice(&content).unwrap();
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].message_id.as_deref(), Some("msg-1"));
+        assert!(merged[0].read, "local read state is kept");
+        assert_eq!(merged[1].message_id.as_deref(), Some("msg-2"));
+        assert!(!inboxes_dir.join("agent-1.hub.pull").exists());
+    }
+
     #[tokio::test]
     async fn test_dotted_agent_names_in_pull_filtering() {
         let temp_dir = TempDir::new().unwrap();
@@ -233,6 +233,8 @@ mod tests {
             created_at: 1000000,
             lead_agent_id: format!("team-lead@{name}"),
             lead_session_id: "test-session-id".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members,
             unknown_fields: Default::default(),
         }
@@ -1,7 +1,9 @@
 //! Configuration for the CI Monitor plugin
 
 use super::types::CiRunConclusion;
-use crate::plugin::PluginError;
+pub use crate::plugin::NotifyTarget;
+use crate::plugin::{PluginError, resolve_notification_target};
+use agent_team_mail_core::config::NotificationsConfig;
 use agent_team_mail_core::toml;
 use globset::{GlobSet, GlobSetBuilder};
 use std::collections::HashMap;
@@ -16,53 +18,6 @@ pub enum DedupStrategy {
     PerRun,
 }
 
-/// Notification routing target for CI alerts
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct NotifyTarget {
-    /// Agent name to notify
-    pub agent: String,
-    /// Team name (None = use config team)
-    pub team: Option<String>,
-}
-
-impl NotifyTarget {
-    /// Parse a notify target from a string in the format "agent" or "agent@team"
-    ///
-    /// # Errors
-    ///
-    /// Returns `PluginError::Config` if the format is invalid (e.g., empty, multiple @)
-    pub fn parse(s: &str) -> Result<Self, PluginError> {
-        let s = s.trim();
-        if s.is_empty() {
-            return Err(PluginError::Config {
-                message: "notify_target cannot be empty".to_string(),
-            });
-        }
-
-        let parts: Vec<&str> = s.split('@').collect();
-        match parts.len() {
-            1 => Ok(Self {
-                agent: parts[0].to_string(),
-                team: None,
-            }),
-            2 => {
-                if parts[0].is_empty() || parts[1].is_empty() {
-                    return Err(PluginError::Config {
-                        message: format!("Invalid notify_target format: '{s}'"),
-                    });
-                }
-                Ok(Self {
-                    agent: parts[0].to_string(),
-                    team: Some(parts[1].to_string()),
-                })
-            }
-            _ => Err(PluginError::Config {
-                message: format!("Invalid notify_target format (multiple @): '{s}'"),
-            }),
-        }
-    }
-}
-
 /// Configuration for the CI Monitor plugin, parsed from [plugins.gh_monitor]
 #[derive(Debug, Clone)]
 pub struct CiMonitorConfig {
@@ -102,6 +57,10 @@ pub struct CiMonitorConfig {
     pub runtime_history_limit: usize,
     /// Cooldown window between repeated drift alerts for the same key (seconds)
     pub alert_cooldown_secs: u64,
+    /// Minimum seconds between failure notifications for the same run (0 = no cooldown)
+    pub notify_cooldown_secs: u64,
+    /// Team-wide cap on CI failure notifications per rolling hour (0 = unlimited)
+    pub max_notifications_per_hour: u32,
     /// Provider-specific configuration (passed to external providers)
     pub provider_config: Option<toml::Table>,
     /// Notification routing targets (empty = send as ci-monitor agent)
@@ -123,6 +82,19 @@ impl CiMonitorConfig {
     /// - `team` is empty (required field)
     /// - `poll_interval_secs` is less than 10 (too aggressive)
     pub fn from_toml(table: &toml::Table) -> Result<Self, PluginError> {
+        Self::from_toml_with_notifications(table, &NotificationsConfig::default())
+    }
+
+    /// Parse configuration, falling back to `[notifications]` routing when
+    /// `notify_target` is not set in the plugin section.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::from_toml`], plus a malformed `[notifications]` target.
+    pub fn from_toml_with_notifications(
+        table: &toml::Table,
+        notifications: &NotificationsConfig,
+    ) -> Result<Self, PluginError> {
         let enabled = table
             .get("enabled")
             .and_then(|v| v.as_bool())
@@ -336,6 +308,18 @@ impl CiMonitorConfig {
             });
         }
 
+        let notify_cooldown_secs = table
+            .get("notify_cooldown_secs")
+            .and_then(|v| v.as_integer())
+            .map(|v| v.max(0) as u64)
+            .unwrap_or(0);
+
+        let max_notifications_per_hour = table
+            .get("max_notifications_per_hour")
+            .and_then(|v| v.as_integer())
+            .map(|v| v.clamp(0, i64::from(u32::MAX)) as u32)
+            .unwrap_or(0);
+
         // Parse notify_target (can be single string or array of strings)
         let mut notify_target = match table.get("notify_target") {
             Some(toml::Value::String(s)) => vec![NotifyTarget::parse(s)?],
@@ -350,6 +334,11 @@ impl CiMonitorConfig {
             }
             _ => Vec::new(),
         };
+        if notify_target.is_empty()
+            && let Some(target) = resolve_notification_target(notifications, "gh_monitor")?
+        {
+            notify_target.push(target);
+        }
         if notify_target.is_empty() && !team.trim().is_empty() {
             notify_target.push(NotifyTarget {
                 agent: "team-lead".to_string(),
@@ -379,6 +368,8 @@ impl CiMonitorConfig {
             runtime_drift_min_samples,
             runtime_history_limit,
             alert_cooldown_secs,
+            notify_cooldown_secs,
+            max_notifications_per_hour,
             provider_config: Some(provider_config),
             notify_target,
             branch_matcher,
@@ -407,6 +398,8 @@ impl Default for CiMonitorConfig {
             runtime_drift_min_samples: 3,
             runtime_history_limit: 50,
             alert_cooldown_secs: 300,
+            notify_cooldown_secs: 0,
+            max_notifications_per_hour: 0,
             provider_config: None,
             notify_target: Vec::new(),
             branch_matcher: None,
@@ -441,6 +434,8 @@ mod tests {
         assert_eq!(config.runtime_drift_min_samples, 3);
         assert_eq!(config.runtime_history_limit, 50);
         assert_eq!(config.alert_cooldown_secs, 300);
+        assert_eq!(config.notify_cooldown_secs, 0);
+        assert_eq!(config.max_notifications_per_hour, 0);
     }
 
     #[test]
@@ -475,6 +470,8 @@ runtime_drift_threshold_percent = 80
 runtime_drift_min_samples = 4
 runtime_history_limit = 120
 alert_cooldown = 45
+notify_cooldown_secs = 900
+max_notifications_per_hour = 6
 "#;
         let table: toml::Table = toml::from_str(toml_str).unwrap();
         let config = CiMonitorConfig::from_toml(&table).unwrap();
@@ -498,10 +495,39 @@ alert_cooldown = 45
         assert!(config.runtime_drift_enabled);
         assert_eq!(config.runtime_drift_threshold_percent, 80);
         assert_eq!(config.runtime_drift_min_samples, 4);
+        assert_eq!(config.notify_cooldown_secs, 900);
+        assert_eq!(config.max_notifications_per_hour, 6);
         assert_eq!(config.runtime_history_limit, 120);
         assert_eq!(config.alert_cooldown_secs, 45);
     }
 
+    #[test]
+    fn test_config_notify_target_falls_back_to_notifications_section() {
+        let table: toml::Table = toml::from_str("team = \"qa-team\"").unwrap();
+        let mut notifications = NotificationsConfig {
+            default_target: Some("lead".to_string()),
+            ..Default::default()
+        };
+        notifications
+            .overrides
+            .insert("gh_monitor".to_string(), "ci-lead@ops".to_string());
+
+        let config = CiMonitorConfig::from_toml_with_notifications(&table, &notifications).unwrap();
+        assert_eq!(
+            config.notify_target,
+            vec![NotifyTarget {
+                agent: "ci-lead".to_string(),
+                team: Some("ops".to_string()),
+            }]
+        );
+
+        // An explicit plugin-level notify_target still wins.
+        let table: toml::Table =
+            toml::from_str("team = \"qa-team\"\nnotify_target = \"qa-bot\"").unwrap();
+        let config = CiMonitorConfig::from_toml_with_notifications(&table, &notifications).unwrap();
+        assert_eq!(config.notify_target[0].agent, "qa-bot");
+    }
+
     #[test]
     fn test_config_repo_slug_populates_owner_and_repo() {
         let toml_str = r#"
@@ -4,6 +4,7 @@ use super::CiMonitorConfig;
 use super::helpers::normalize_repo_scope;
 use super::types::{CiMonitorStatus, CiMonitorTargetKind, GhAlertTargets};
 use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
+use agent_team_mail_core::io::{InboxError, configured_id_scheme_in, new_message_id};
 use agent_team_mail_core::schema::InboxMessage;
 use tracing::warn;
 
@@ -23,14 +24,8 @@ pub(crate) fn emit_ci_monitor_message(
             .join("inboxes")
             .join(format!("{agent}.json"));
         let message = InboxMessage {
-            from: from_agent.to_string(),
-            source_team: None,
-            text: text.to_string(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some(summary.to_string()),
             message_id: message_id.clone(),
-            unknown_fields: std::collections::HashMap::new(),
+            ..InboxMessage::new(from_agent, text).summary(summary.to_string())
         };
         if let Err(e) =
             agent_team_mail_core::io::inbox::inbox_append(&inbox_path, &message, team, agent)
@@ -71,19 +66,8 @@ pub(crate) fn emit_ci_not_started_alert(
             .join(&team)
             .join("inboxes")
             .join(format!("{agent}.json"));
-        let message = InboxMessage {
-            from: from_agent.clone(),
-            source_team: None,
-            text: text.clone(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some(summary.clone()),
-            message_id: Some(uuid::Uuid::new_v4().to_string()),
-            unknown_fields: std::collections::HashMap::new(),
-        };
-        if let Err(e) =
-            agent_team_mail_core::io::inbox::inbox_append(&inbox_path, &message, &team, &agent)
-        {
+        let message = InboxMessage::new(from_agent.clone(), text.clone()).summary(summary.clone());
+        if let Err(e) = append_alert(home, config_cwd, &inbox_path, message, &team, &agent) {
             warn!(
                 team = %team,
                 agent = %agent,
@@ -180,19 +164,8 @@ pub(crate) fn emit_merge_conflict_alert(
             .join(&team)
             .join("inboxes")
             .join(format!("{agent}.json"));
-        let message = InboxMessage {
-            from: from_agent.clone(),
-            source_team: None,
-            text: text.clone(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some(summary.clone()),
-            message_id: Some(uuid::Uuid::new_v4().to_string()),
-            unknown_fields: std::collections::HashMap::new(),
-        };
-        if let Err(e) =
-            agent_team_mail_core::io::inbox::inbox_append(&inbox_path, &message, &team, &agent)
-        {
+        let message = InboxMessage::new(from_agent.clone(), text.clone()).summary(summary.clone());
+        if let Err(e) = append_alert(home, config_cwd, &inbox_path, message, &team, &agent) {
             warn!(
                 team = %team,
                 agent = %agent,
@@ -202,6 +175,27 @@ pub(crate) fn emit_merge_conflict_alert(
     }
 }
 
+/// Append an alert under a message id allocated for `inbox_path` using the
+/// id scheme configured for `config_cwd`.
+#[cfg(unix)]
+pub(crate) fn append_alert(
+    home: &std::path::Path,
+    config_cwd: Option<&str>,
+    inbox_path: &std::path::Path,
+    mut message: InboxMessage,
+    team: &str,
+    agent: &str,
+) -> Result<(), InboxError> {
+    let current_dir = config_cwd
+        .map(str::trim)
+        .filter(|cwd| !cwd.is_empty())
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| home.to_path_buf());
+    let scheme = configured_id_scheme_in(&current_dir, home);
+    message.message_id = Some(new_message_id(scheme, inbox_path)?);
+    agent_team_mail_core::io::inbox::inbox_append(inbox_path, &message, team, agent).map(|_| ())
+}
+
 #[cfg(unix)]
 pub(crate) fn resolve_ci_alert_routing(
     home: &std::path::Path,
@@ -234,12 +228,13 @@ pub(crate) fn resolve_ci_alert_routing(
         return default_command_routing("gh-monitor", team, alert_targets);
     };
 
-    let parsed = match CiMonitorConfig::from_toml(plugin_table) {
-        Ok(cfg) => cfg,
-        Err(_) => {
-            return default_command_routing("gh-monitor", team, alert_targets);
-        }
-    };
+    let parsed =
+        match CiMonitorConfig::from_toml_with_notifications(plugin_table, &config.notifications) {
+            Ok(cfg) => cfg,
+            Err(_) => {
+                return default_command_routing("gh-monitor", team, alert_targets);
+            }
+        };
 
     let from_agent = if parsed.agent.trim().is_empty() {
         "gh-monitor".to_string()
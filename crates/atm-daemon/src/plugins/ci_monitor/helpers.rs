@@ -130,7 +130,10 @@ pub(crate) fn evaluate_gh_monitor_config(
     };
     state.configured = true;
 
-    let parsed = match crate::plugins::ci_monitor::CiMonitorConfig::from_toml(table) {
+    let parsed = match crate::plugins::ci_monitor::CiMonitorConfig::from_toml_with_notifications(
+        table,
+        &config.notifications,
+    ) {
         Ok(parsed) => parsed,
         Err(e) => {
             state.error = Some(e.to_string());
@@ -2,6 +2,7 @@
 
 use super::registry::CiProviderFactory;
 use crate::plugin::PluginError;
+use agent_team_mail_core::config::ProviderLoadFailurePolicy;
 use libloading::{Library, Symbol};
 use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
@@ -13,6 +14,8 @@ use tracing::{debug, warn};
 pub struct CiProviderLoader {
     /// Keep loaded libraries alive (they must not be dropped while factories exist)
     libraries: Vec<Library>,
+    /// How individual library load failures are handled
+    failure_policy: ProviderLoadFailurePolicy,
 }
 
 impl CiProviderLoader {
@@ -20,9 +23,16 @@ impl CiProviderLoader {
     pub fn new() -> Self {
         Self {
             libraries: Vec::new(),
+            failure_policy: ProviderLoadFailurePolicy::default(),
         }
     }
 
+    /// Set how individual library load failures are handled (default: skip)
+    pub fn with_failure_policy(mut self, policy: ProviderLoadFailurePolicy) -> Self {
+        self.failure_policy = policy;
+        self
+    }
+
     /// Scan a directory and load all CI provider libraries
     ///
     /// # Arguments
@@ -32,11 +42,13 @@ impl CiProviderLoader {
     /// # Returns
     ///
     /// A vector of successfully loaded provider factories.
-    /// Errors for individual libraries are logged as warnings and do not fail the entire operation.
+    /// Errors for individual libraries are logged as warnings and do not fail the
+    /// entire operation unless the failure policy is `fail-fast`.
     ///
     /// # Errors
     ///
-    /// Returns `PluginError::Provider` if the directory cannot be read.
+    /// Returns `PluginError::Provider` if the directory cannot be read, or
+    /// `PluginError::ProviderLoad` if a library fails to load under `fail-fast`.
     pub fn load_from_directory(
         &mut self,
         dir: &Path,
@@ -88,9 +100,7 @@ impl CiProviderLoader {
                     );
                     factories.push(factory);
                 }
-                Err(e) => {
-                    warn!("Failed to load CI provider from {}: {}", path.display(), e);
-                }
+                Err(e) => self.handle_load_failure(&path, e)?,
             }
         }
 
@@ -106,8 +116,16 @@ impl CiProviderLoader {
     /// # Returns
     ///
     /// A vector of successfully loaded provider factories.
-    /// Errors for individual libraries are logged as warnings.
-    pub fn load_libraries(&mut self, paths: &[PathBuf]) -> Vec<CiProviderFactory> {
+    /// Errors for individual libraries are logged as warnings unless the
+    /// failure policy is `fail-fast`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PluginError::ProviderLoad` if a library fails to load under `fail-fast`.
+    pub fn load_libraries(
+        &mut self,
+        paths: &[PathBuf],
+    ) -> Result<Vec<CiProviderFactory>, PluginError> {
         let mut factories = Vec::new();
 
         for path in paths {
@@ -123,13 +141,29 @@ impl CiProviderLoader {
                     );
                     factories.push(factory);
                 }
-                Err(e) => {
-                    warn!("Failed to load CI provider from {}: {}", path.display(), e);
-                }
+                Err(e) => self.handle_load_failure(path, e)?,
             }
         }
 
-        factories
+        Ok(factories)
+    }
+
+    /// Apply the failure policy to a library that failed to load
+    fn handle_load_failure(&self, path: &Path, err: PluginError) -> Result<(), PluginError> {
+        match self.failure_policy {
+            ProviderLoadFailurePolicy::Skip => {
+                warn!(
+                    path = %path.display(),
+                    error = %err,
+                    "SKIPPING CI provider library that failed to load; set \
+                     [daemon] provider_load_failure = \"fail-fast\" to abort startup instead"
+                );
+                Ok(())
+            }
+            ProviderLoadFailurePolicy::FailFast => Err(PluginError::ProviderLoad {
+                message: format!("Failed to load CI provider from {}: {err}", path.display()),
+            }),
+        }
     }
 
     /// Expand tilde (~) in path to home directory
@@ -280,10 +314,35 @@ mod tests {
     #[test]
     fn test_load_libraries_empty() {
         let mut loader = CiProviderLoader::new();
-        let factories = loader.load_libraries(&[]);
+        let factories = loader.load_libraries(&[]).unwrap();
         assert_eq!(factories.len(), 0);
     }
 
+    #[test]
+    fn test_broken_library_skipped_or_fatal_per_policy() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let broken = vec![temp_dir.path().join("broken.so")];
+        std::fs::write(&broken[0], "not a shared library").unwrap();
+
+        let mut loader = CiProviderLoader::new();
+        assert!(
+            loader
+                .load_from_directory(temp_dir.path())
+                .unwrap()
+                .is_empty()
+        );
+        assert!(loader.load_libraries(&broken).unwrap().is_empty());
+
+        let mut loader =
+            CiProviderLoader::new().with_failure_policy(ProviderLoadFailurePolicy::FailFast);
+        assert!(matches!(
+            loader.load_from_directory(temp_dir.path()),
+            Err(PluginError::ProviderLoad { .. })
+        ));
+        let err = loader.load_libraries(&broken).unwrap_err();
+        assert!(err.to_string().contains("broken.so"), "{err}");
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_expand_tilde_with_atm_home() {
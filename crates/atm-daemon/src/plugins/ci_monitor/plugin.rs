@@ -13,10 +13,11 @@ use super::types::{CiJob, CiRunConclusion};
 use super::types::{CiMonitorHealth, GhMonitorHealthFile, GhMonitorStateFile};
 use crate::plugin::{Capability, Plugin, PluginContext, PluginError, PluginMetadata};
 use crate::roster::RosterError;
+use agent_team_mail_core::config::ProviderLoadFailurePolicy;
 use agent_team_mail_core::context::RepoContext;
 use agent_team_mail_core::schema::{AgentMember, InboxMessage, TeamConfig};
 use chrono::{DateTime, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -46,6 +47,61 @@ struct RuntimeDriftEvent {
     baseline_secs: u64,
 }
 
+/// Outcome of [`NotifyThrottle::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ThrottleDecision {
+    Allow,
+    /// The same run was notified less than `notify_cooldown_secs` ago.
+    RunCooldown,
+    /// The team-wide `max_notifications_per_hour` budget is exhausted.
+    RateLimited,
+}
+
+/// Tracks recent failure notifications to suppress flapping runs and bursts.
+#[derive(Debug, Default)]
+struct NotifyThrottle {
+    /// Last notification time per run id.
+    last_by_run: HashMap<u64, DateTime<Utc>>,
+    /// Notification times within the last hour, oldest first.
+    recent: VecDeque<DateTime<Utc>>,
+}
+
+impl NotifyThrottle {
+    fn check(
+        &mut self,
+        run_id: u64,
+        now: DateTime<Utc>,
+        cooldown_secs: u64,
+        max_per_hour: u32,
+    ) -> ThrottleDecision {
+        if cooldown_secs > 0
+            && let Some(last) = self.last_by_run.get(&run_id)
+            && now.signed_duration_since(*last).num_seconds() < cooldown_secs as i64
+        {
+            return ThrottleDecision::RunCooldown;
+        }
+        let hour_ago = now - chrono::Duration::hours(1);
+        while self.recent.front().is_some_and(|t| *t <= hour_ago) {
+            self.recent.pop_front();
+        }
+        if max_per_hour > 0 && self.recent.len() >= max_per_hour as usize {
+            return ThrottleDecision::RateLimited;
+        }
+        ThrottleDecision::Allow
+    }
+
+    fn record(&mut self, run_id: u64, now: DateTime<Utc>) {
+        self.last_by_run.insert(run_id, now);
+        self.recent.push_back(now);
+    }
+
+    /// Drop per-run entries whose cooldown has expired.
+    fn evict(&mut self, now: DateTime<Utc>, cooldown_secs: u64) {
+        let cutoff = now - chrono::Duration::seconds(cooldown_secs as i64);
+        self.last_by_run.retain(|_, last| *last > cutoff);
+    }
+}
+
 /// CI Monitor plugin — bridges CI provider runs to agent team messaging
 pub struct CiMonitorPlugin {
     /// The CI provider (GitHub Actions, Azure Pipelines, etc.)
@@ -60,6 +116,8 @@ pub struct CiMonitorPlugin {
     ctx: Option<PluginContext>,
     /// Tracking: seen run dedup keys with their timestamps
     seen_runs: HashMap<String, DateTime<Utc>>,
+    /// Per-run cooldown and team-wide rate limit for failure notifications.
+    notify_throttle: NotifyThrottle,
     /// Runtime duration baselines and processed-run dedup state.
     runtime_history: RuntimeHistory,
     /// Persisted runtime history path (initialized in init when enabled).
@@ -86,6 +144,7 @@ impl CiMonitorPlugin {
             loader: None,
             ctx: None,
             seen_runs: HashMap::new(),
+            notify_throttle: NotifyThrottle::default(),
             runtime_history: RuntimeHistory::default(),
             runtime_history_path: None,
             #[cfg(test)]
@@ -164,7 +223,14 @@ impl CiMonitorPlugin {
     }
 
     /// Build the provider registry with built-in and external providers
-    fn build_registry(&mut self, atm_home: &std::path::Path) -> Box<dyn CiProviderRegistryPort> {
+    ///
+    /// Fails only when a provider library fails to load under the
+    /// `fail-fast` policy.
+    fn build_registry(
+        &mut self,
+        atm_home: &std::path::Path,
+        failure_policy: ProviderLoadFailurePolicy,
+    ) -> Result<Box<dyn CiProviderRegistryPort>, PluginError> {
         let mut registry = super::registry::CiProviderRegistry::new();
 
         // Register built-in GitHub Actions provider
@@ -181,7 +247,7 @@ impl CiMonitorPlugin {
 
         // Load external providers from provider directory
         let provider_dir = atm_home.join("providers");
-        let mut loader = CiProviderLoader::new();
+        let mut loader = CiProviderLoader::new().with_failure_policy(failure_policy);
         match loader.load_from_directory(&provider_dir) {
             Ok(factories) => {
                 debug!("Loaded {} external CI providers", factories.len());
@@ -189,6 +255,7 @@ impl CiMonitorPlugin {
                     registry.register(factory);
                 }
             }
+            Err(e @ PluginError::ProviderLoad { .. }) => return Err(e),
             Err(e) => {
                 warn!("Failed to load external CI providers: {}", e);
             }
@@ -197,7 +264,7 @@ impl CiMonitorPlugin {
         // Load config-specified provider libraries
         if !self.config.provider_libraries.is_empty() {
             let paths: Vec<PathBuf> = self.config.provider_libraries.values().cloned().collect();
-            let factories = loader.load_libraries(&paths);
+            let factories = loader.load_libraries(&paths)?;
             for factory in factories {
                 registry.register(factory);
             }
@@ -206,7 +273,7 @@ impl CiMonitorPlugin {
         // Keep loader alive so dynamic libraries stay loaded
         self.loader = Some(loader);
 
-        Box::new(registry)
+        Ok(Box::new(registry))
     }
 
     /// Generate a deduplication key for a run based on configured strategy
@@ -229,12 +296,44 @@ impl CiMonitorPlugin {
         }
     }
 
+    /// Apply the notification throttle to a failed run not yet in `seen_runs`.
+    ///
+    /// Returns `true` when the notification should be sent. A run inside its
+    /// cooldown is marked seen and dropped; a run over the hourly budget is
+    /// left out of `seen_runs` so a later poll reports it once budget frees up.
+    fn admit_failure_notification(&mut self, key: &str, run_id: u64, now: DateTime<Utc>) -> bool {
+        match self.notify_throttle.check(
+            run_id,
+            now,
+            self.config.notify_cooldown_secs,
+            self.config.max_notifications_per_hour,
+        ) {
+            ThrottleDecision::Allow => true,
+            ThrottleDecision::RunCooldown => {
+                debug!(
+                    "CI Monitor: Suppressing notification for run #{run_id} (within notify_cooldown_secs)"
+                );
+                self.seen_runs.insert(key.to_string(), now);
+                false
+            }
+            ThrottleDecision::RateLimited => {
+                warn!(
+                    "CI Monitor: Deferring notification for run #{run_id} (max_notifications_per_hour={} reached); will retry on a later poll",
+                    self.config.max_notifications_per_hour
+                );
+                false
+            }
+        }
+    }
+
     /// Evict old entries from the dedup cache based on TTL
     fn evict_old_dedup_entries(&mut self) {
         let ttl = chrono::Duration::hours(self.config.dedup_ttl_hours as i64);
         let cutoff = Utc::now() - ttl;
 
         self.seen_runs.retain(|_key, timestamp| *timestamp > cutoff);
+        self.notify_throttle
+            .evict(Utc::now(), self.config.notify_cooldown_secs);
     }
 
     /// Generate failure reports (JSON + Markdown) in the report directory
@@ -444,19 +543,12 @@ impl CiMonitorPlugin {
 
         let message_id = self.dedup_key(run);
 
-        InboxMessage {
-            from: self.config.agent.clone(),
-            source_team: None,
-            text: content,
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some(format!(
+        InboxMessage::new(self.config.agent.clone(), content)
+            .summary(format!(
                 "CI {} on {}: {}",
                 conclusion_display, run.head_branch, run.name
-            )),
-            message_id: Some(message_id),
-            unknown_fields: std::collections::HashMap::new(),
-        }
+            ))
+            .message_id(message_id)
     }
 
     fn runtime_history_file_path(report_dir: &std::path::Path) -> PathBuf {
@@ -682,22 +774,18 @@ impl CiMonitorPlugin {
             details.push_str(&line);
         }
 
-        Some(InboxMessage {
-            from: self.config.agent.clone(),
-            source_team: None,
-            text: format!(
-                "[runtime-drift:{}] Significant runtime drift detected\nWorkflow: {}\nBranch: {}\nRun URL: {}\n{}",
-                run.id, run.name, run.head_branch, run.url, details
-            ),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some(format!(
-                "Runtime drift detected for {} (#{}).",
-                run.name, run.id
-            )),
-            message_id: Some(format!("ci-drift-{}", run.id)),
-            unknown_fields: std::collections::HashMap::new(),
-        })
+        let text = format!(
+            "[runtime-drift:{}] Significant runtime drift detected\nWorkflow: {}\nBranch: {}\nRun URL: {}\n{}",
+            run.id, run.name, run.head_branch, run.url, details
+        );
+        Some(
+            InboxMessage::new(self.config.agent.clone(), text)
+                .summary(format!(
+                    "Runtime drift detected for {} (#{}).",
+                    run.name, run.id
+                ))
+                .message_id(format!("ci-drift-{}", run.id)),
+        )
     }
 
     fn trim_processed_ids(ids: &mut Vec<u64>, limit: usize) {
@@ -918,34 +1006,37 @@ impl CiMonitorPlugin {
     }
 
     fn notify_disabled_transition(&self, ctx: &PluginContext, team: &str, message: &str) {
-        let lead_agent =
-            std::fs::read_to_string(ctx.mail.teams_root().join(team).join("config.json"))
+        let configured = ctx.notification_target("gh_monitor").ok().flatten();
+        let team = configured
+            .as_ref()
+            .and_then(|target| target.team.as_deref())
+            .unwrap_or(team);
+        let lead_agent = match configured {
+            Some(ref target) => target.agent.clone(),
+            None => std::fs::read_to_string(ctx.mail.teams_root().join(team).join("config.json"))
                 .ok()
                 .and_then(|raw| serde_json::from_str::<TeamConfig>(&raw).ok())
                 .and_then(|cfg| cfg.lead_agent_id.split('@').next().map(|s| s.to_string()))
                 .filter(|name| !name.trim().is_empty())
-                .unwrap_or_else(|| "team-lead".to_string());
+                .unwrap_or_else(|| "team-lead".to_string()),
+        };
 
         let text = format!(
             "[gh_monitor] availability transition healthy -> disabled_config_error\nreason: {message}"
         );
-        let msg = InboxMessage {
-            from: if self.config.agent.is_empty() {
+        let msg = InboxMessage::new(
+            if self.config.agent.is_empty() {
                 "ci-monitor".to_string()
             } else {
                 self.config.agent.clone()
             },
-            source_team: None,
             text,
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some("gh_monitor: disabled_config_error".to_string()),
-            message_id: Some(format!(
-                "gh-monitor-config-error-{}",
-                Utc::now().timestamp_millis()
-            )),
-            unknown_fields: std::collections::HashMap::new(),
-        };
+        )
+        .summary("gh_monitor: disabled_config_error")
+        .message_id(format!(
+            "gh-monitor-config-error-{}",
+            Utc::now().timestamp_millis()
+        ));
 
         if let Err(e) = ctx.mail.send(team, &lead_agent, &msg) {
             warn!(
@@ -992,7 +1083,7 @@ impl Plugin for CiMonitorPlugin {
         // Parse config from context
         let config_table = ctx.plugin_config("gh_monitor");
         self.config = if let Some(table) = config_table {
-            match CiMonitorConfig::from_toml(table) {
+            match CiMonitorConfig::from_toml_with_notifications(table, &ctx.config.notifications) {
                 Ok(config) => config,
                 Err(e) => {
                     self.project_disabled_config_error(ctx, config_table, &e.to_string());
@@ -1051,7 +1142,8 @@ impl Plugin for CiMonitorPlugin {
             };
 
             if self.registry.is_none() {
-                self.registry = Some(self.build_registry(&atm_home));
+                let failure_policy = ctx.config.daemon.provider_load_failure.unwrap_or_default();
+                self.registry = Some(self.build_registry(&atm_home, failure_policy)?);
             }
             let registry = self
                 .registry
@@ -1363,6 +1455,11 @@ impl Plugin for CiMonitorPlugin {
                                             continue;
                                         }
 
+                                        let now = Utc::now();
+                                        if !self.admit_failure_notification(&key, full_run.id, now) {
+                                            continue;
+                                        }
+
                                         // Generate failure reports
                                         if let Err(e) = self.generate_reports(&full_run) {
                                             warn!("CI Monitor: Failed to generate reports for run #{}: {e}", run.id);
@@ -1372,7 +1469,8 @@ impl Plugin for CiMonitorPlugin {
                                         let msg = self.run_to_message(&full_run);
                                         if self.send_message_to_targets(&ctx, &msg, run.id) {
                                             debug!("CI Monitor: Notified about run #{}", run.id);
-                                            self.seen_runs.insert(key, Utc::now());
+                                            self.seen_runs.insert(key, now);
+                                            self.notify_throttle.record(full_run.id, now);
                                         }
                                     }
                                 }
@@ -1624,6 +1722,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_notify_throttle_run_cooldown_and_hourly_limit() {
+        let mut throttle = NotifyThrottle::default();
+        let t0 = Utc::now();
+
+        assert_eq!(throttle.check(1, t0, 600, 2), ThrottleDecision::Allow);
+        throttle.record(1, t0);
+
+        // Same run inside the cooldown is suppressed; other runs are not.
+        let t1 = t0 + chrono::Duration::seconds(60);
+        assert_eq!(throttle.check(1, t1, 600, 2), ThrottleDecision::RunCooldown);
+        assert_eq!(throttle.check(2, t1, 600, 2), ThrottleDecision::Allow);
+        throttle.record(2, t1);
+
+        // Team-wide budget of 2/hour is exhausted.
+        assert_eq!(throttle.check(3, t1, 600, 2), ThrottleDecision::RateLimited);
+
+        // After an hour both the cooldown and budget have recovered.
+        let t2 = t0 + chrono::Duration::minutes(61);
+        throttle.evict(t2, 600);
+        assert!(throttle.last_by_run.is_empty());
+        assert_eq!(throttle.check(1, t2, 600, 2), ThrottleDecision::Allow);
+
+        // Zero disables both limits.
+        let mut unlimited = NotifyThrottle::default();
+        for _ in 0..5 {
+            assert_eq!(unlimited.check(9, t0, 0, 0), ThrottleDecision::Allow);
+            unlimited.record(9, t0);
+        }
+    }
+
+    #[test]
+    fn test_rate_limited_run_is_not_marked_seen_and_retries() {
+        let mut plugin = CiMonitorPlugin::new().with_config(CiMonitorConfig {
+            notify_cooldown_secs: 600,
+            max_notifications_per_hour: 1,
+            ..Default::default()
+        });
+        let t0 = Utc::now();
+
+        assert!(plugin.admit_failure_notification("ci-1-Failure", 1, t0));
+        plugin.seen_runs.insert("ci-1-Failure".to_string(), t0);
+        plugin.notify_throttle.record(1, t0);
+
+        // Budget exhausted: run 2 is deferred, not swallowed by the dedup cache.
+        let t1 = t0 + chrono::Duration::minutes(5);
+        assert!(!plugin.admit_failure_notification("ci-2-Failure", 2, t1));
+        assert!(!plugin.seen_runs.contains_key("ci-2-Failure"));
+
+        // Once the hourly window rolls over, the deferred run is reported.
+        let t2 = t0 + chrono::Duration::minutes(61);
+        assert!(plugin.admit_failure_notification("ci-2-Failure", 2, t2));
+
+        // A run inside its cooldown is dropped and marked seen.
+        plugin.notify_throttle.record(3, t2);
+        let t3 = t2 + chrono::Duration::seconds(30);
+        plugin.config.max_notifications_per_hour = 0;
+        assert!(!plugin.admit_failure_notification("ci-3-Failure", 3, t3));
+        assert!(plugin.seen_runs.contains_key("ci-3-Failure"));
+    }
+
     #[test]
     fn test_runtime_drift_alert_message_deterministic() {
         use crate::plugins::ci_monitor::mock_support::{create_test_job, create_test_run};
@@ -2072,6 +2231,8 @@ notify_target = "team-lead"
             created_at: 1234567890,
             lead_agent_id: "lead@dev-team".to_string(),
             lead_session_id: "session-123".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![AgentMember {
                 agent_id: "lead@dev-team".to_string(),
                 name: "lead".to_string(),
@@ -2136,6 +2297,8 @@ notify_target = "nonexistent-agent"
             created_at: 1234567890,
             lead_agent_id: "lead@dev-team".to_string(),
             lead_session_id: "session-123".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![AgentMember {
                 agent_id: "lead@dev-team".to_string(),
                 name: "lead".to_string(),
@@ -2260,6 +2423,8 @@ repo = "config-owner/config-repo"
             created_at: 1234567890,
             lead_agent_id: "lead@dev-team".to_string(),
             lead_session_id: "session-123".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![ci_monitor_member],
             unknown_fields: std::collections::HashMap::new(),
         };
@@ -2368,6 +2533,8 @@ repo = "config-owner/config-repo"
             created_at: 1234567890,
             lead_agent_id: "lead@dev-team".to_string(),
             lead_session_id: "session-123".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![conflicting_member],
             unknown_fields: std::collections::HashMap::new(),
         };
@@ -2585,6 +2752,8 @@ provider = "custom-missing"
             created_at: 1234567890,
             lead_agent_id: "lead@dev-team".to_string(),
             lead_session_id: "session-123".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![AgentMember {
                 agent_id: "lead@dev-team".to_string(),
                 name: "lead".to_string(),
@@ -2662,6 +2831,8 @@ agent = "ci-monitor"
             created_at: 1234567890,
             lead_agent_id: "lead@dev-team".to_string(),
             lead_session_id: "session-123".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![
                 AgentMember {
                     agent_id: "lead@dev-team".to_string(),
@@ -2753,6 +2924,8 @@ notify_target = "team-lead"
             created_at: 1234567890,
             lead_agent_id: "team-lead@dev-team".to_string(),
             lead_session_id: "session-123".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![AgentMember {
                 agent_id: "team-lead@dev-team".to_string(),
                 name: "team-lead".to_string(),
@@ -2918,6 +3091,8 @@ notify_target = ["lead", "qa-bot@qa-team"]
             created_at: 1_234_567_890,
             lead_agent_id: format!("lead@{team_name}"),
             lead_session_id: "session-test".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![synthetic_member],
             unknown_fields: std::collections::HashMap::new(),
         };
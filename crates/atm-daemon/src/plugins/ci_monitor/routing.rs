@@ -73,20 +73,14 @@ pub(crate) fn notify_gh_monitor_health_transition(
         if !inbox_path.exists() {
             let _ = std::fs::write(&inbox_path, "[]");
         }
-        let message = InboxMessage {
-            from: from_agent.clone(),
-            // local system message — source_team intentionally None
-            source_team: None,
-            text: text.clone(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some(format!("gh_monitor: {new_state}")),
-            message_id: Some(uuid::Uuid::new_v4().to_string()),
-            unknown_fields: std::collections::HashMap::new(),
-        };
-        if let Err(e) = agent_team_mail_core::io::inbox::inbox_append(
+        // local system message — source_team intentionally None
+        let message = InboxMessage::new(from_agent.clone(), text.clone())
+            .summary(format!("gh_monitor: {new_state}"));
+        if let Err(e) = super::gh_alerts::append_alert(
+            home,
+            config_cwd,
             &inbox_path,
-            &message,
+            message,
             &target_team,
             &agent,
         ) {
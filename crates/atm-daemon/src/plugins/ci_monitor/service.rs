@@ -85,24 +85,21 @@ fn notify_team_lead_of_monitor_control(
         .unwrap_or_else(|| "team-lead".to_string());
     let inbox_path = team_dir.join("inboxes").join(format!("{lead_agent}.json"));
     let now = chrono::Utc::now().to_rfc3339();
-    let message = InboxMessage {
-        from: actor.to_string(),
-        source_team: None,
-        text: format!(
+    let message = InboxMessage::new(
+        actor,
+        format!(
             "your gh monitor was {action_word} by {actor}@{actor_team} for {}",
             reason.trim()
         ),
-        timestamp: now.clone(),
-        read: false,
-        summary: Some(format!("gh monitor {action_word} by {actor}@{actor_team}")),
-        message_id: Some(format!(
-            "gh-monitor-{}-{}-{}",
-            action_word,
-            target_team,
-            chrono::Utc::now().timestamp_millis()
-        )),
-        unknown_fields: std::collections::HashMap::new(),
-    };
+    )
+    .timestamp(now.clone())
+    .summary(format!("gh monitor {action_word} by {actor}@{actor_team}"))
+    .message_id(format!(
+        "gh-monitor-{}-{}-{}",
+        action_word,
+        target_team,
+        chrono::Utc::now().timestamp_millis()
+    ));
     let _ = inbox_append(&inbox_path, &message, target_team, &lead_agent)?;
     Ok(())
 }
@@ -692,7 +689,9 @@ pub(crate) fn create_provider_from_registry(
                     ),
                 ));
             }
-            GitProviderType::GitLab { namespace, repo } => {
+            GitProviderType::GitLab {
+                namespace, repo, ..
+            } => {
                 return Err(CiMonitorServiceError::new(
                     "PROVIDER_ERROR",
                     format!("GitLab not yet supported (namespace: {namespace}, repo: {repo})"),
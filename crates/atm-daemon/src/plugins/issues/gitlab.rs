@@ -0,0 +1,680 @@
+//! GitLab issue provider using the GitLab REST API v4
+
+use super::provider::IssueProvider;
+use super::types::{Issue, IssueComment, IssueFilter, IssueLabel, IssueState};
+use crate::plugin::PluginError;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+
+/// Default GitLab API root
+pub const GITLAB_API_URL: &str = "https://gitlab.com/api/v4";
+
+/// API root for the GitLab instance at `host` (gitlab.com or self-hosted)
+pub fn api_url_for_host(host: &str) -> String {
+    if host == "gitlab.com" {
+        GITLAB_API_URL.to_string()
+    } else {
+        format!("https://{host}/api/v4")
+    }
+}
+
+/// Page size requested from paginated endpoints (GitLab maximum)
+const PER_PAGE: u32 = 100;
+
+/// HTTP method used by [`GitLabRequest`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitLabMethod {
+    /// HTTP `GET`
+    Get,
+    /// HTTP `POST`
+    Post,
+}
+
+/// A single GitLab API request, relative to the API root
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitLabRequest {
+    /// HTTP method
+    pub method: GitLabMethod,
+    /// Path below the API root, e.g. `/projects/group%2Frepo/issues`
+    pub path: String,
+    /// Query string parameters, in order
+    pub query: Vec<(String, String)>,
+    /// JSON request body (`None` for bodiless requests)
+    pub body: Option<serde_json::Value>,
+}
+
+/// Raw response from a GitLab API request
+#[derive(Debug, Clone)]
+pub struct GitLabResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Raw response body
+    pub body: String,
+    /// Value of the `X-Next-Page` header (`None` on the last page)
+    pub next_page: Option<u32>,
+}
+
+/// Blocking HTTP transport for GitLab API calls.
+///
+/// The provider runs requests on a blocking thread; tests substitute a
+/// transport that returns canned responses.
+pub trait GitLabTransport: Send + Sync + std::fmt::Debug {
+    fn send(&self, request: &GitLabRequest) -> Result<GitLabResponse, PluginError>;
+}
+
+/// [`GitLabTransport`] backed by `reqwest`, authenticating with `GITLAB_TOKEN`
+#[derive(Debug, Clone)]
+pub struct HttpTransport {
+    base_url: String,
+    token: Option<String>,
+}
+
+impl HttpTransport {
+    /// Create a transport for `base_url` using the given private token
+    pub fn new(base_url: impl Into<String>, token: Option<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            token,
+        }
+    }
+
+    /// Create a transport for the GitLab instance at `host` using
+    /// `GITLAB_TOKEN` from the environment
+    pub fn from_env(host: &str) -> Self {
+        let token = std::env::var("GITLAB_TOKEN")
+            .ok()
+            .filter(|t| !t.trim().is_empty());
+        Self::new(api_url_for_host(host), token)
+    }
+}
+
+impl GitLabTransport for HttpTransport {
+    fn send(&self, request: &GitLabRequest) -> Result<GitLabResponse, PluginError> {
+        let client = reqwest::blocking::Client::new();
+        let url = format!("{}{}", self.base_url, request.path);
+        let mut builder = match request.method {
+            GitLabMethod::Get => client.get(&url),
+            GitLabMethod::Post => client.post(&url),
+        }
+        .query(&request.query);
+        if let Some(token) = &self.token {
+            builder = builder.header("PRIVATE-TOKEN", token);
+        }
+        if let Some(body) = &request.body {
+            builder = builder.json(body);
+        }
+
+        let response = builder.send().map_err(|e| PluginError::Provider {
+            message: format!("GitLab request failed: {e}"),
+            source: Some(Box::new(e)),
+        })?;
+        let status = response.status().as_u16();
+        let next_page = response
+            .headers()
+            .get("x-next-page")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok());
+        let body = response.text().map_err(|e| PluginError::Provider {
+            message: format!("Failed to read GitLab response: {e}"),
+            source: Some(Box::new(e)),
+        })?;
+        Ok(GitLabResponse {
+            status,
+            body,
+            next_page,
+        })
+    }
+}
+
+/// GitLab issue provider that talks to the REST API v4
+#[derive(Debug)]
+pub struct GitLabProvider {
+    namespace: String,
+    repo: String,
+    transport: Arc<dyn GitLabTransport>,
+}
+
+impl GitLabProvider {
+    /// Create a new GitLab provider for the given namespace/repo on the
+    /// instance at `host`
+    pub fn new(host: &str, namespace: String, repo: String) -> Self {
+        Self::with_transport(namespace, repo, Arc::new(HttpTransport::from_env(host)))
+    }
+
+    /// Create a provider that sends its requests through `transport`
+    ///
+    /// The transport decides which instance is contacted and how requests
+    /// are authenticated; tests use this to supply canned responses.
+    pub fn with_transport(
+        namespace: String,
+        repo: String,
+        transport: Arc<dyn GitLabTransport>,
+    ) -> Self {
+        Self {
+            namespace,
+            repo,
+            transport,
+        }
+    }
+
+    /// `/projects/:id` path, with the full project path URL-encoded as the id
+    fn project_path(&self) -> String {
+        let id = format!("{}/{}", self.namespace, self.repo).replace('/', "%2F");
+        format!("/projects/{id}")
+    }
+
+    /// Send a request on a blocking thread and map non-2xx statuses to errors
+    async fn send(&self, request: GitLabRequest) -> Result<GitLabResponse, PluginError> {
+        let transport = Arc::clone(&self.transport);
+        let response = tokio::task::spawn_blocking(move || transport.send(&request))
+            .await
+            .map_err(|e| PluginError::Runtime {
+                message: format!("Task join error: {e}"),
+                source: Some(Box::new(e)),
+            })??;
+        if !(200..300).contains(&response.status) {
+            return Err(PluginError::Provider {
+                message: format!(
+                    "GitLab API returned {}: {}",
+                    response.status,
+                    error_message(&response.body)
+                ),
+                source: None,
+            });
+        }
+        Ok(response)
+    }
+
+    /// GET every page of a list endpoint
+    async fn get_all<T: DeserializeOwned>(
+        &self,
+        path: String,
+        query: Vec<(String, String)>,
+    ) -> Result<Vec<T>, PluginError> {
+        let mut items = Vec::new();
+        let mut page = 1;
+        loop {
+            let mut page_query = query.clone();
+            page_query.push(("per_page".to_string(), PER_PAGE.to_string()));
+            page_query.push(("page".to_string(), page.to_string()));
+            let response = self
+                .send(GitLabRequest {
+                    method: GitLabMethod::Get,
+                    path: path.clone(),
+                    query: page_query,
+                    body: None,
+                })
+                .await?;
+            items.extend(parse_json::<Vec<T>>(&response.body)?);
+            match response.next_page {
+                Some(next) if next > page => page = next,
+                _ => return Ok(items),
+            }
+        }
+    }
+
+    fn parse_issue(&self, gl: &GlIssue) -> Issue {
+        Issue {
+            id: gl.id.to_string(),
+            number: gl.iid,
+            title: gl.title.clone(),
+            body: gl.description.clone(),
+            state: if gl.state == "opened" {
+                IssueState::Open
+            } else {
+                IssueState::Closed
+            },
+            labels: gl.labels.iter().map(GlLabel::to_label).collect(),
+            assignees: gl.assignees.iter().map(|a| a.username.clone()).collect(),
+            author: gl.author.username.clone(),
+            created_at: gl.created_at.clone(),
+            updated_at: gl.updated_at.clone(),
+            url: gl.web_url.clone(),
+        }
+    }
+}
+
+impl IssueProvider for GitLabProvider {
+    async fn list_issues(&self, filter: &IssueFilter) -> Result<Vec<Issue>, PluginError> {
+        let state = match filter.state {
+            Some(IssueState::Open) => "opened",
+            Some(IssueState::Closed) => "closed",
+            None => "all",
+        };
+        let mut query = vec![
+            ("state".to_string(), state.to_string()),
+            ("with_labels_details".to_string(), "true".to_string()),
+        ];
+        if !filter.labels.is_empty() {
+            query.push(("labels".to_string(), filter.labels.join(",")));
+        }
+        // The API only filters by a single assignee; more are matched locally.
+        if let [assignee] = filter.assignees.as_slice() {
+            query.push(("assignee_username".to_string(), assignee.clone()));
+        }
+        if let Some(since) = &filter.since {
+            query.push(("updated_after".to_string(), since.clone()));
+        }
+
+        let gl_issues: Vec<GlIssue> = self
+            .get_all(format!("{}/issues", self.project_path()), query)
+            .await?;
+        let mut issues: Vec<Issue> = gl_issues.iter().map(|gl| self.parse_issue(gl)).collect();
+        if filter.assignees.len() > 1 {
+            issues.retain(|issue| issue.assignees.iter().any(|a| filter.assignees.contains(a)));
+        }
+        Ok(issues)
+    }
+
+    async fn get_issue(&self, number: u64) -> Result<Issue, PluginError> {
+        let response = self
+            .send(GitLabRequest {
+                method: GitLabMethod::Get,
+                path: format!("{}/issues/{number}", self.project_path()),
+                query: vec![("with_labels_details".to_string(), "true".to_string())],
+                body: None,
+            })
+            .await?;
+        let gl_issue: GlIssue = parse_json(&response.body)?;
+        Ok(self.parse_issue(&gl_issue))
+    }
+
+    async fn add_comment(
+        &self,
+        issue_number: u64,
+        body: &str,
+    ) -> Result<IssueComment, PluginError> {
+        let response = self
+            .send(GitLabRequest {
+                method: GitLabMethod::Post,
+                path: format!("{}/issues/{issue_number}/notes", self.project_path()),
+                query: Vec::new(),
+                body: Some(serde_json::json!({ "body": body })),
+            })
+            .await?;
+        let note: GlNote = parse_json(&response.body)?;
+        Ok(note.into_comment())
+    }
+
+    async fn list_comments(&self, issue_number: u64) -> Result<Vec<IssueComment>, PluginError> {
+        let notes: Vec<GlNote> = self
+            .get_all(
+                format!("{}/issues/{issue_number}/notes", self.project_path()),
+                vec![
+                    ("sort".to_string(), "asc".to_string()),
+                    ("order_by".to_string(), "created_at".to_string()),
+                ],
+            )
+            .await?;
+        // System notes record events (label changes, closes), not comments.
+        Ok(notes
+            .into_iter()
+            .filter(|n| !n.system)
+            .map(GlNote::into_comment)
+            .collect())
+    }
+
+    fn provider_name(&self) -> &str {
+        "GitLab"
+    }
+}
+
+fn parse_json<T: DeserializeOwned>(body: &str) -> Result<T, PluginError> {
+    serde_json::from_str(body).map_err(|e| PluginError::Provider {
+        message: format!("Failed to parse GitLab JSON: {e}"),
+        source: Some(Box::new(e)),
+    })
+}
+
+/// Extract the `message`/`error` field from a GitLab error body
+fn error_message(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| {
+            v.get("message")
+                .or_else(|| v.get("error"))
+                .map(|m| m.as_str().map_or_else(|| m.to_string(), str::to_string))
+        })
+        .unwrap_or_else(|| body.trim().to_string())
+}
+
+/// GitLab issue JSON schema (from `/projects/:id/issues`)
+#[derive(Debug, Deserialize)]
+struct GlIssue {
+    id: u64,
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    state: String,
+    #[serde(default)]
+    labels: Vec<GlLabel>,
+    #[serde(default)]
+    assignees: Vec<GlUser>,
+    author: GlUser,
+    created_at: String,
+    updated_at: String,
+    web_url: String,
+}
+
+/// Labels are plain names unless `with_labels_details=true` is requested
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum GlLabel {
+    Name(String),
+    Detailed { name: String, color: Option<String> },
+}
+
+impl GlLabel {
+    fn to_label(&self) -> IssueLabel {
+        match self {
+            Self::Name(name) => IssueLabel {
+                name: name.clone(),
+                color: None,
+            },
+            Self::Detailed { name, color } => IssueLabel {
+                name: name.clone(),
+                // GitHub colors carry no leading '#'; match that.
+                color: color
+                    .as_ref()
+                    .map(|c| c.trim_start_matches('#').to_string()),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GlUser {
+    username: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlNote {
+    id: u64,
+    body: String,
+    author: GlUser,
+    created_at: String,
+    #[serde(default)]
+    system: bool,
+}
+
+impl GlNote {
+    fn into_comment(self) -> IssueComment {
+        IssueComment {
+            id: self.id.to_string(),
+            body: self.body,
+            author: self.author.username,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    /// Transport that returns queued responses and records every request
+    #[derive(Debug, Default)]
+    struct MockTransport {
+        responses: Mutex<VecDeque<GitLabResponse>>,
+        requests: Mutex<Vec<GitLabRequest>>,
+    }
+
+    impl MockTransport {
+        fn with_responses(responses: Vec<(u16, serde_json::Value, Option<u32>)>) -> Arc<Self> {
+            Arc::new(Self {
+                responses: Mutex::new(
+                    responses
+                        .into_iter()
+                        .map(|(status, body, next_page)| GitLabResponse {
+                            status,
+                            body: body.to_string(),
+                            next_page,
+                        })
+                        .collect(),
+                ),
+                requests: Mutex::default(),
+            })
+        }
+
+        fn requests(&self) -> Vec<GitLabRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    impl GitLabTransport for MockTransport {
+        fn send(&self, request: &GitLabRequest) -> Result<GitLabResponse, PluginError> {
+            self.requests.lock().unwrap().push(request.clone());
+            self.responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .ok_or_else(|| PluginError::Provider {
+                    message: "unexpected request".to_string(),
+                    source: None,
+                })
+        }
+    }
+
+    fn provider(transport: &Arc<MockTransport>) -> GitLabProvider {
+        GitLabProvider::with_transport(
+            "group/sub".to_string(),
+            "repo".to_string(),
+            transport.clone(),
+        )
+    }
+
+    fn issue_json(id: u64, iid: u64, state: &str, assignee: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "iid": iid,
+            "title": format!("Issue {iid}"),
+            "description": "Body text",
+            "state": state,
+            "labels": [{"name": "bug", "color": "#ff0000"}],
+            "assignees": [{"username": assignee}],
+            "author": {"username": "author1"},
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-02T00:00:00Z",
+            "web_url": format!("https://gitlab.com/group/sub/repo/-/issues/{iid}")
+        })
+    }
+
+    fn query_value<'a>(request: &'a GitLabRequest, key: &str) -> Option<&'a str> {
+        request
+            .query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    #[test]
+    fn test_gitlab_provider_creation() {
+        let provider =
+            GitLabProvider::new("gitlab.com", "namespace".to_string(), "repo".to_string());
+        assert_eq!(provider.provider_name(), "GitLab");
+        assert_eq!(provider.project_path(), "/projects/namespace%2Frepo");
+    }
+
+    #[test]
+    fn test_self_hosted_host_uses_its_own_api_root() {
+        assert_eq!(api_url_for_host("gitlab.com"), GITLAB_API_URL);
+        assert_eq!(
+            HttpTransport::from_env("gitlab.example.com").base_url,
+            "https://gitlab.example.com/api/v4"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_paginates_and_maps_filter() {
+        let transport = MockTransport::with_responses(vec![
+            (
+                200,
+                serde_json::json!([issue_json(9001, 1, "opened", "user1")]),
+                Some(2),
+            ),
+            (
+                200,
+                serde_json::json!([issue_json(9002, 2, "opened", "user1")]),
+                None,
+            ),
+        ]);
+        let filter = IssueFilter {
+            labels: vec!["bug".to_string(), "urgent".to_string()],
+            assignees: vec!["user1".to_string()],
+            state: Some(IssueState::Open),
+            since: Some("2026-01-01T00:00:00Z".to_string()),
+        };
+
+        let issues = provider(&transport).list_issues(&filter).await.unwrap();
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].id, "9001");
+        assert_eq!(issues[0].number, 1);
+        assert_eq!(issues[0].state, IssueState::Open);
+        assert_eq!(issues[0].labels[0].name, "bug");
+        assert_eq!(issues[0].labels[0].color.as_deref(), Some("ff0000"));
+        assert_eq!(issues[0].assignees, vec!["user1"]);
+        assert_eq!(issues[0].author, "author1");
+        assert_eq!(issues[1].number, 2);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, GitLabMethod::Get);
+        assert_eq!(requests[0].path, "/projects/group%2Fsub%2Frepo/issues");
+        assert_eq!(query_value(&requests[0], "state"), Some("opened"));
+        assert_eq!(query_value(&requests[0], "labels"), Some("bug,urgent"));
+        assert_eq!(
+            query_value(&requests[0], "assignee_username"),
+            Some("user1")
+        );
+        assert_eq!(
+            query_value(&requests[0], "updated_after"),
+            Some("2026-01-01T00:00:00Z")
+        );
+        assert_eq!(query_value(&requests[0], "page"), Some("1"));
+        assert_eq!(query_value(&requests[1], "page"), Some("2"));
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_multiple_assignees_filtered_locally() {
+        let transport = MockTransport::with_responses(vec![(
+            200,
+            serde_json::json!([
+                issue_json(1, 1, "opened", "user1"),
+                issue_json(2, 2, "closed", "user2"),
+                issue_json(3, 3, "opened", "user3")
+            ]),
+            None,
+        )]);
+        let filter = IssueFilter {
+            assignees: vec!["user1".to_string(), "user2".to_string()],
+            ..Default::default()
+        };
+
+        let issues = provider(&transport).list_issues(&filter).await.unwrap();
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[1].state, IssueState::Closed);
+        let request = &transport.requests()[0];
+        assert_eq!(query_value(request, "state"), Some("all"));
+        assert_eq!(query_value(request, "assignee_username"), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_uses_iid() {
+        let transport = MockTransport::with_responses(vec![(
+            200,
+            issue_json(123456, 42, "closed", "user1"),
+            None,
+        )]);
+
+        let issue = provider(&transport).get_issue(42).await.unwrap();
+
+        assert_eq!(issue.number, 42);
+        assert_eq!(issue.id, "123456");
+        assert_eq!(issue.state, IssueState::Closed);
+        assert_eq!(
+            transport.requests()[0].path,
+            "/projects/group%2Fsub%2Frepo/issues/42"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_plain_labels() {
+        let mut json = issue_json(1, 1, "opened", "user1");
+        json["labels"] = serde_json::json!(["bug"]);
+        let transport = MockTransport::with_responses(vec![(200, json, None)]);
+
+        let issue = provider(&transport).get_issue(1).await.unwrap();
+
+        assert_eq!(issue.labels[0].name, "bug");
+        assert!(issue.labels[0].color.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_add_comment_posts_note() {
+        let transport = MockTransport::with_responses(vec![(
+            201,
+            serde_json::json!({
+                "id": 77,
+                "body": "Looks good",
+                "author": {"username": "bot"},
+                "created_at": "2026-01-03T00:00:00Z",
+                "system": false
+            }),
+            None,
+        )]);
+
+        let comment = provider(&transport)
+            .add_comment(42, "Looks good")
+            .await
+            .unwrap();
+
+        assert_eq!(comment.id, "77");
+        assert_eq!(comment.author, "bot");
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, GitLabMethod::Post);
+        assert_eq!(request.path, "/projects/group%2Fsub%2Frepo/issues/42/notes");
+        assert_eq!(
+            request.body,
+            Some(serde_json::json!({"body": "Looks good"}))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_comments_skips_system_notes() {
+        let transport = MockTransport::with_responses(vec![(
+            200,
+            serde_json::json!([
+                {"id": 1, "body": "added ~bug label", "author": {"username": "a"},
+                 "created_at": "2026-01-01T00:00:00Z", "system": true},
+                {"id": 2, "body": "real comment", "author": {"username": "b"},
+                 "created_at": "2026-01-02T00:00:00Z", "system": false}
+            ]),
+            None,
+        )]);
+
+        let comments = provider(&transport).list_comments(5).await.unwrap();
+
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].body, "real comment");
+    }
+
+    #[tokio::test]
+    async fn test_error_status_maps_to_provider_error() {
+        let transport = MockTransport::with_responses(vec![(
+            404,
+            serde_json::json!({"message": "404 Project Not Found"}),
+            None,
+        )]);
+
+        let err = provider(&transport).get_issue(1).await.unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("404"), "{message}");
+        assert!(message.contains("Project Not Found"), "{message}");
+    }
+}
@@ -2,6 +2,7 @@
 
 mod config;
 mod github;
+mod gitlab;
 mod loader;
 mod mock_provider;
 mod plugin;
@@ -11,6 +12,10 @@ mod types;
 
 pub use config::IssuesConfig;
 pub use github::GitHubProvider;
+pub use gitlab::{
+    GITLAB_API_URL, GitLabMethod, GitLabProvider, GitLabRequest, GitLabResponse, GitLabTransport,
+    HttpTransport, api_url_for_host,
+};
 pub use loader::ProviderLoader;
 pub use mock_provider::{MockCall, MockProvider};
 pub use plugin::IssuesPlugin;
@@ -29,7 +34,7 @@ use agent_team_mail_core::context::GitProvider;
 ///
 /// # Arguments
 ///
-/// * `provider` - The git provider (GitHub, etc.)
+/// * `provider` - The git provider (GitHub, GitLab, etc.)
 /// * `_config` - Optional plugin config (reserved for future use)
 ///
 /// # Errors
@@ -49,12 +54,15 @@ pub fn create_provider(
             ),
             source: None,
         }),
-        GitProvider::GitLab { namespace, repo } => Err(PluginError::Provider {
-            message: format!(
-                "GitLab issue provider not yet implemented (namespace: {namespace}, repo: {repo})"
-            ),
-            source: None,
-        }),
+        GitProvider::GitLab {
+            host,
+            namespace,
+            repo,
+        } => Ok(Box::new(GitLabProvider::new(
+            host,
+            namespace.clone(),
+            repo.clone(),
+        ))),
         GitProvider::Bitbucket { workspace, repo } => Err(PluginError::Provider {
             message: format!(
                 "Bitbucket issue provider not yet implemented (workspace: {workspace}, repo: {repo})"
@@ -102,19 +110,15 @@ mod tests {
     }
 
     #[test]
-    fn test_create_provider_gitlab_not_implemented() {
+    fn test_create_provider_gitlab() {
         let provider = GitProvider::GitLab {
+            host: "gitlab.com".to_string(),
             namespace: "namespace".to_string(),
             repo: "repo".to_string(),
         };
         let result = create_provider(&provider, None);
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("GitLab issue provider not yet implemented")
-        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().provider_name(), "GitLab");
     }
 
     #[test]
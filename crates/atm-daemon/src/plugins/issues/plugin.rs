@@ -2,11 +2,13 @@
 
 use super::config::IssuesConfig;
 use super::github::GitHubProvider;
+use super::gitlab::GitLabProvider;
 use super::loader::ProviderLoader;
 use super::provider::ErasedIssueProvider;
 use super::registry::{ProviderFactory, ProviderRegistry};
 use super::types::{Issue, IssueFilter, IssueState};
-use crate::plugin::{Capability, Plugin, PluginContext, PluginError, PluginMetadata};
+use crate::plugin::{Capability, NotifyTarget, Plugin, PluginContext, PluginError, PluginMetadata};
+use agent_team_mail_core::config::ProviderLoadFailurePolicy;
 use agent_team_mail_core::context::GitProvider as GitProviderType;
 use agent_team_mail_core::schema::{AgentMember, InboxMessage};
 use std::collections::HashMap;
@@ -31,6 +33,8 @@ pub struct IssuesPlugin {
     ctx: Option<PluginContext>,
     /// Tracking: last poll timestamp for incremental fetching
     last_poll: Option<String>,
+    /// `[notifications]` recipient for issue messages (None = the plugin's own inbox)
+    notify_target: Option<NotifyTarget>,
 }
 
 impl IssuesPlugin {
@@ -43,6 +47,7 @@ impl IssuesPlugin {
             loader: None,
             ctx: None,
             last_poll: None,
+            notify_target: None,
         }
     }
 
@@ -65,7 +70,14 @@ impl IssuesPlugin {
     }
 
     /// Build the provider registry with built-in and external providers
-    fn build_registry(&mut self, atm_home: &std::path::Path) -> ProviderRegistry {
+    ///
+    /// Fails only when a provider library fails to load under the
+    /// `fail-fast` policy.
+    fn build_registry(
+        &mut self,
+        atm_home: &std::path::Path,
+        failure_policy: ProviderLoadFailurePolicy,
+    ) -> Result<ProviderRegistry, PluginError> {
         let mut registry = ProviderRegistry::new();
 
         // Register built-in GitHub provider
@@ -84,7 +96,7 @@ impl IssuesPlugin {
 
         // Load external providers from provider directory
         let provider_dir = atm_home.join("providers");
-        let mut loader = ProviderLoader::new();
+        let mut loader = ProviderLoader::new().with_failure_policy(failure_policy);
         match loader.load_from_directory(&provider_dir) {
             Ok(factories) => {
                 debug!("Loaded {} external providers", factories.len());
@@ -92,6 +104,7 @@ impl IssuesPlugin {
                     registry.register(factory);
                 }
             }
+            Err(e @ PluginError::ProviderLoad { .. }) => return Err(e),
             Err(e) => {
                 warn!("Failed to load external providers: {}", e);
             }
@@ -100,7 +113,7 @@ impl IssuesPlugin {
         // Load config-specified provider libraries
         if !self.config.provider_libraries.is_empty() {
             let paths: Vec<PathBuf> = self.config.provider_libraries.values().cloned().collect();
-            let factories = loader.load_libraries(&paths);
+            let factories = loader.load_libraries(&paths)?;
             for factory in factories {
                 registry.register(factory);
             }
@@ -109,7 +122,7 @@ impl IssuesPlugin {
         // Keep loader alive so dynamic libraries stay loaded
         self.loader = Some(loader);
 
-        registry
+        Ok(registry)
     }
 
     /// Select and create a provider from the registry
@@ -173,17 +186,21 @@ impl IssuesPlugin {
                     })
                 }
             }
-            GitProviderType::GitLab { namespace, repo } => {
+            GitProviderType::GitLab {
+                host,
+                namespace,
+                repo,
+            } => {
                 if registry.has_provider("gitlab") {
                     debug!("Using gitlab provider from registry");
                     registry.create_provider("gitlab", config_table)
                 } else {
-                    Err(PluginError::Provider {
-                        message: format!(
-                            "GitLab provider not found in registry (namespace: {namespace}, repo: {repo})"
-                        ),
-                        source: None,
-                    })
+                    debug!("Auto-detected GitLab provider from git remote");
+                    Ok(Box::new(GitLabProvider::new(
+                        host,
+                        namespace.clone(),
+                        repo.clone(),
+                    )))
                 }
             }
             GitProviderType::Bitbucket { workspace, repo } => {
@@ -240,17 +257,10 @@ impl IssuesPlugin {
             format!("issue-{}-{}", issue.number, issue.updated_at)
         };
 
-        InboxMessage {
-            from: self.config.agent.clone(),
-            // local system message — source_team intentionally None
-            source_team: None,
-            text: content,
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some(format!("Issue #{}: {}", issue.number, issue.title)),
-            message_id: Some(message_id),
-            unknown_fields: HashMap::new(),
-        }
+        // local system message — source_team intentionally None
+        InboxMessage::new(self.config.agent.clone(), content)
+            .summary(format!("Issue #{}: {}", issue.number, issue.title))
+            .message_id(message_id)
     }
 
     /// Parse issue number from message content
@@ -299,6 +309,7 @@ impl Plugin for IssuesPlugin {
         } else {
             IssuesConfig::default()
         };
+        self.notify_target = ctx.notification_target("issues")?;
 
         // If disabled, skip provider setup
         if !self.config.enabled {
@@ -321,7 +332,8 @@ impl Plugin for IssuesPlugin {
         let atm_config_root = home_dir.join(".config/atm");
 
         // Build the provider registry
-        let registry = self.build_registry(&atm_config_root);
+        let failure_policy = ctx.config.daemon.provider_load_failure.unwrap_or_default();
+        let registry = self.build_registry(&atm_config_root, failure_policy)?;
         debug!(
             "Provider registry initialized with {} providers: {:?}",
             registry.len(),
@@ -412,6 +424,13 @@ impl Plugin for IssuesPlugin {
         })?;
 
         let mut ticker = interval(Duration::from_secs(self.config.poll_interval));
+        let (target_team, target_agent) = match &self.notify_target {
+            Some(target) => (
+                target.team.as_deref().unwrap_or(&self.config.team),
+                target.agent.as_str(),
+            ),
+            None => (self.config.team.as_str(), self.config.agent.as_str()),
+        };
 
         loop {
             tokio::select! {
@@ -433,7 +452,7 @@ impl Plugin for IssuesPlugin {
                             // Process each new issue
                             for issue in issues {
                                 let msg = self.issue_to_message(&issue);
-                                if let Err(e) = ctx.mail.send(&self.config.team, &self.config.agent, &msg) {
+                                if let Err(e) = ctx.mail.send(target_team, target_agent, &msg) {
                                     warn!("Issues plugin: Failed to send message for issue #{}: {e}", issue.number);
                                 }
                             }
@@ -714,7 +733,9 @@ mod tests {
         let temp_dir = tempfile::tempdir().unwrap();
         let mut plugin = IssuesPlugin::new();
 
-        let _registry = plugin.build_registry(temp_dir.path());
+        let _registry = plugin
+            .build_registry(temp_dir.path(), ProviderLoadFailurePolicy::Skip)
+            .unwrap();
         assert!(plugin.loader.is_some());
     }
 
@@ -732,16 +753,7 @@ mod tests {
                 ..IssuesConfig::default()
             });
 
-        let msg = InboxMessage {
-            from: "issues-bot".to_string(),
-            source_team: None,
-            text: "[issue:42]\nThis should be ignored".to_string(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            read: false,
-            summary: None,
-            message_id: None,
-            unknown_fields: HashMap::new(),
-        };
+        let msg = InboxMessage::new("issues-bot", "[issue:42]\nThis should be ignored");
 
         plugin.handle_message(&msg).await.unwrap();
 
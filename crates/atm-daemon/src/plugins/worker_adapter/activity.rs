@@ -5,8 +5,9 @@
 
 use crate::plugin::PluginError;
 use agent_team_mail_core::team_config_store::TeamConfigStore;
+use std::collections::HashMap;
 use std::path::Path;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tracing::{debug, warn};
 
 /// Agent activity tracker
@@ -95,6 +96,63 @@ impl Default for ActivityTracker {
     }
 }
 
+/// Tracks how long each worker has been continuously idle and reports
+/// workers whose idle time exceeds their `max_idle_before_stop_secs` limit.
+///
+/// Time is supplied by the caller so the policy can be driven by a test clock.
+#[derive(Debug, Default)]
+pub struct IdleRetirement {
+    /// Per-member idle limit; members without an entry are never retired
+    limits: HashMap<String, Duration>,
+    /// Instant at which each member was first observed idle
+    idle_since: HashMap<String, Instant>,
+}
+
+impl IdleRetirement {
+    /// Create a tracker with per-member idle limits
+    pub fn new(limits: HashMap<String, Duration>) -> Self {
+        Self {
+            limits,
+            idle_since: HashMap::new(),
+        }
+    }
+
+    /// Record the member's current activity at `now`.
+    ///
+    /// The idle clock starts on the first idle observation and resets on any
+    /// non-idle observation.
+    pub fn observe(&mut self, member_name: &str, is_idle: bool, now: Instant) {
+        if is_idle {
+            self.idle_since
+                .entry(member_name.to_string())
+                .or_insert(now);
+        } else {
+            self.idle_since.remove(member_name);
+        }
+    }
+
+    /// Members whose continuous idle time at `now` meets their limit,
+    /// paired with how long they have been idle. Sorted by member name.
+    pub fn due(&self, now: Instant) -> Vec<(String, Duration)> {
+        let mut due: Vec<(String, Duration)> = self
+            .idle_since
+            .iter()
+            .filter_map(|(member, since)| {
+                let limit = self.limits.get(member)?;
+                let idle_for = now.saturating_duration_since(*since);
+                (idle_for >= *limit).then(|| (member.clone(), idle_for))
+            })
+            .collect();
+        due.sort_by(|a, b| a.0.cmp(&b.0));
+        due
+    }
+
+    /// Stop tracking a member (after retirement or unregister)
+    pub fn forget(&mut self, member_name: &str) {
+        self.idle_since.remove(member_name);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,6 +168,8 @@ mod tests {
             created_at: 1234567890,
             lead_agent_id: "team-lead@test-team".to_string(),
             lead_session_id: "session-123".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![AgentMember {
                 agent_id: "agent1@test-team".to_string(),
                 name: "agent1".to_string(),
@@ -214,4 +274,40 @@ mod tests {
             .unwrap();
         assert_eq!(agent.is_active, None);
     }
+
+    #[test]
+    fn test_idle_retirement_due_after_clock_advances_past_threshold() {
+        let limits = HashMap::from([("worker-1".to_string(), Duration::from_secs(600))]);
+        let mut retirement = IdleRetirement::new(limits);
+        let t0 = Instant::now();
+
+        retirement.observe("worker-1", true, t0);
+        // Re-observing idle must not restart the idle clock.
+        retirement.observe("worker-1", true, t0 + Duration::from_secs(300));
+        assert!(retirement.due(t0 + Duration::from_secs(599)).is_empty());
+
+        let due = retirement.due(t0 + Duration::from_secs(601));
+        assert_eq!(
+            due,
+            vec![("worker-1".to_string(), Duration::from_secs(601))]
+        );
+    }
+
+    #[test]
+    fn test_idle_retirement_resets_on_activity_and_ignores_unlimited_members() {
+        let limits = HashMap::from([("worker-1".to_string(), Duration::from_secs(60))]);
+        let mut retirement = IdleRetirement::new(limits);
+        let t0 = Instant::now();
+
+        retirement.observe("worker-1", true, t0);
+        retirement.observe("worker-2", true, t0);
+        retirement.observe("worker-1", false, t0 + Duration::from_secs(30));
+        retirement.observe("worker-1", true, t0 + Duration::from_secs(40));
+
+        assert!(retirement.due(t0 + Duration::from_secs(90)).is_empty());
+        assert_eq!(retirement.due(t0 + Duration::from_secs(100)).len(), 1);
+
+        retirement.forget("worker-1");
+        assert!(retirement.due(t0 + Duration::from_secs(1000)).is_empty());
+    }
 }
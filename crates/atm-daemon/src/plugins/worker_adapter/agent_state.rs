@@ -104,6 +104,8 @@ pub struct AgentStateTracker {
     transition_meta: HashMap<String, TransitionMeta>,
     /// Pane and log path information per agent, stored for socket queries.
     pane_info: HashMap<String, AgentPaneInfo>,
+    /// Set while the daemon drains busy workers before shutdown.
+    draining: bool,
 }
 
 impl AgentStateTracker {
@@ -114,9 +116,23 @@ impl AgentStateTracker {
             last_transition: HashMap::new(),
             transition_meta: HashMap::new(),
             pane_info: HashMap::new(),
+            draining: false,
         }
     }
 
+    /// Mark whether the daemon is draining workers before shutdown.
+    ///
+    /// The socket server reads this to reject control and launch requests
+    /// while in-flight turns finish.
+    pub fn set_draining(&mut self, draining: bool) {
+        self.draining = draining;
+    }
+
+    /// Whether the daemon is draining workers before shutdown.
+    pub fn is_draining(&self) -> bool {
+        self.draining
+    }
+
     /// Register a newly spawned agent in `Unknown` state.
     pub fn register_agent(&mut self, agent_id: &str) {
         self.set_state_inner(
@@ -3,6 +3,7 @@
 //! Captures worker output by tailing log files written by worker backends.
 //! CRITICAL: Requires explicit writer contract — backend must tee output to log file.
 
+use super::config::DEFAULT_CAPTURE_TIMEOUT_MS;
 use crate::plugin::PluginError;
 use std::fs::File;
 use std::io::{Read, Seek, SeekFrom};
@@ -10,10 +11,14 @@ use std::path::Path;
 use std::time::{Duration, Instant};
 use tracing::{debug, warn};
 
+/// Marker appended to a response delivered after the capture timeout expired.
+pub const CAPTURE_TIMEOUT_MARKER: &str = "[truncated/timeout]";
+
 /// Response capture configuration
 #[derive(Debug, Clone)]
 pub struct CaptureConfig {
-    /// Maximum time to wait for response (milliseconds)
+    /// Maximum time to wait for a complete response (milliseconds). On expiry
+    /// whatever was captured so far is returned with [`CAPTURE_TIMEOUT_MARKER`].
     pub timeout_ms: u64,
     /// Poll interval for log file (milliseconds)
     pub poll_interval_ms: u64,
@@ -26,10 +31,10 @@ pub struct CaptureConfig {
 impl Default for CaptureConfig {
     fn default() -> Self {
         Self {
-            timeout_ms: 60_000,            // 60 seconds
-            poll_interval_ms: 100,         // 100ms polling
-            max_response_bytes: 1_048_576, // 1MB
-            idle_timeout_ms: 2_000,        // 2 seconds idle timeout
+            timeout_ms: DEFAULT_CAPTURE_TIMEOUT_MS, // 60 seconds
+            poll_interval_ms: 100,                  // 100ms polling
+            max_response_bytes: 1_048_576,          // 1MB
+            idle_timeout_ms: 2_000,                 // 2 seconds idle timeout
         }
     }
 }
@@ -39,8 +44,11 @@ impl Default for CaptureConfig {
 pub struct CapturedResponse {
     /// Raw output from worker
     pub raw_output: String,
-    /// Parsed response text (after stripping prompt echo)
+    /// Parsed response text (after stripping prompt echo); ends with
+    /// [`CAPTURE_TIMEOUT_MARKER`] when `timed_out` is set
     pub response_text: String,
+    /// Capture stopped at `timeout_ms` before the response looked complete
+    pub timed_out: bool,
 }
 
 /// Log file tailer for capturing worker responses
@@ -69,7 +77,9 @@ impl LogTailer {
     /// Tail a log file for new output after a message is sent
     ///
     /// Opens the log file, seeks to the end, waits for new content, and captures
-    /// output until timeout or max size reached.
+    /// output until the response looks complete, goes idle, or hits max size.
+    /// If `timeout_ms` expires first, the partial output (possibly empty) is
+    /// returned with `timed_out` set so a silent agent cannot block the caller.
     ///
     /// # Arguments
     ///
@@ -82,7 +92,7 @@ impl LogTailer {
     ///
     /// # Errors
     ///
-    /// Returns `PluginError` if file doesn't exist, I/O fails, or timeout reached
+    /// Returns `PluginError` if the file doesn't exist or I/O fails
     pub fn capture_response(
         &self,
         log_path: &Path,
@@ -117,25 +127,18 @@ impl LogTailer {
         let mut buffer = Vec::new();
         let mut last_size = start_pos;
         let mut last_activity = Instant::now();
+        let mut timed_out = false;
 
         loop {
             // Check timeout
             if start_time.elapsed() > timeout {
-                if buffer.is_empty() {
-                    return Err(PluginError::Runtime {
-                        message: format!(
-                            "Timeout waiting for response after {} ms",
-                            self.config.timeout_ms
-                        ),
-                        source: None,
-                    });
-                } else {
-                    warn!(
-                        "Response capture timed out but got {} bytes, returning partial",
-                        buffer.len()
-                    );
-                    break;
-                }
+                warn!(
+                    "Response capture timed out after {} ms with {} bytes, returning partial",
+                    self.config.timeout_ms,
+                    buffer.len()
+                );
+                timed_out = true;
+                break;
             }
 
             // Check idle timeout (no new content for idle_timeout duration)
@@ -206,11 +209,18 @@ impl LogTailer {
         }
 
         let raw_output = String::from_utf8_lossy(&buffer).to_string();
-        let response_text = self.strip_prompt_echo(&raw_output, prompt_text);
+        let mut response_text = self.strip_prompt_echo(&raw_output, prompt_text);
+        if timed_out {
+            if !response_text.is_empty() {
+                response_text.push_str("\n\n");
+            }
+            response_text.push_str(CAPTURE_TIMEOUT_MARKER);
+        }
 
         Ok(CapturedResponse {
             raw_output,
             response_text,
+            timed_out,
         })
     }
 
@@ -348,4 +358,52 @@ mod tests {
         assert!(captured.raw_output.contains("Received prompt"));
         assert!(captured.raw_output.contains("Response complete"));
     }
+
+    #[test]
+    fn test_capture_timeout_returns_partial_with_marker() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let log_path = temp_file.path().to_path_buf();
+
+        let log_path_clone = log_path.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            let mut file = std::fs::OpenOptions::new()
+                .append(true)
+                .open(&log_path_clone)
+                .unwrap();
+            writeln!(file, "Thinking about it").unwrap();
+            file.flush().unwrap();
+        });
+
+        let tailer = LogTailer::with_config(CaptureConfig {
+            timeout_ms: 200,
+            poll_interval_ms: 10,
+            max_response_bytes: 1024,
+            idle_timeout_ms: 10_000,
+        });
+
+        let captured = tailer.capture_response(&log_path, "prompt").unwrap();
+        assert!(captured.timed_out);
+        assert_eq!(
+            captured.response_text,
+            format!("Thinking about it\n\n{CAPTURE_TIMEOUT_MARKER}")
+        );
+    }
+
+    #[test]
+    fn test_capture_timeout_silent_agent_returns_marker_only() {
+        let temp_file = NamedTempFile::new().unwrap();
+
+        let tailer = LogTailer::with_config(CaptureConfig {
+            timeout_ms: 50,
+            poll_interval_ms: 10,
+            max_response_bytes: 1024,
+            idle_timeout_ms: 10,
+        });
+
+        let captured = tailer.capture_response(temp_file.path(), "prompt").unwrap();
+        assert!(captured.timed_out);
+        assert!(captured.raw_output.is_empty());
+        assert_eq!(captured.response_text, CAPTURE_TIMEOUT_MARKER);
+    }
 }
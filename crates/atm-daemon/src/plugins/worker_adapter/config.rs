@@ -8,6 +8,18 @@ use std::path::PathBuf;
 /// Default startup command for worker agents
 pub const DEFAULT_COMMAND: &str = "codex --yolo";
 
+/// Default worker log size that triggers rotation (10 MiB)
+pub const DEFAULT_LOG_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Default number of rotated worker logs kept per worker
+pub const DEFAULT_LOG_KEEP_ROTATED: u32 = 3;
+
+/// Default worker response capture timeout (60 seconds)
+pub const DEFAULT_CAPTURE_TIMEOUT_MS: u64 = 60_000;
+
+/// Default wait for busy workers to finish their turn during shutdown (30 seconds)
+pub const DEFAULT_DRAIN_TIMEOUT_SECS: u64 = 30;
+
 /// Default nudge message template.
 ///
 /// `{count}` is replaced with the number of unread messages.
@@ -45,6 +57,10 @@ pub struct NudgeConfig {
     pub text_template: String,
     /// Sentinel tier injected into nudge template (`info|urgent|blocked`).
     pub sentinel_tier: String,
+    /// Senders whose messages never trigger a nudge (e.g. CI or system
+    /// identities). Their unread messages are ignored when deciding whether
+    /// to nudge and in the unread count.
+    pub ignore_senders: Vec<String>,
 }
 
 impl Default for NudgeConfig {
@@ -54,6 +70,7 @@ impl Default for NudgeConfig {
             cooldown_secs: DEFAULT_NUDGE_COOLDOWN_SECS,
             text_template: DEFAULT_NUDGE_TEXT.to_string(),
             sentinel_tier: DEFAULT_NUDGE_SENTINEL_TIER.to_string(),
+            ignore_senders: Vec::new(),
         }
     }
 }
@@ -88,11 +105,24 @@ impl NudgeConfig {
             .unwrap_or(DEFAULT_NUDGE_SENTINEL_TIER)
             .to_string();
 
+        let ignore_senders = t
+            .get("ignore_senders")
+            .and_then(|v| v.as_array())
+            .map(|senders| {
+                senders
+                    .iter()
+                    .filter_map(|v| v.as_str())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
         Self {
             enabled,
             cooldown_secs,
             text_template,
             sentinel_tier,
+            ignore_senders,
         }
     }
 }
@@ -110,6 +140,9 @@ pub struct AgentConfig {
     pub prompt_template: String,
     /// Concurrency policy: "queue" (default), "reject", or "concurrent"
     pub concurrency_policy: String,
+    /// Gracefully stop the worker after it has been idle this long
+    /// (seconds). `None` (default) keeps idle workers running.
+    pub max_idle_before_stop_secs: Option<u64>,
 }
 
 impl Default for AgentConfig {
@@ -120,6 +153,7 @@ impl Default for AgentConfig {
             command: None,
             prompt_template: "{message}".to_string(),
             concurrency_policy: "queue".to_string(),
+            max_idle_before_stop_secs: None,
         }
     }
 }
@@ -140,8 +174,19 @@ pub struct WorkersConfig {
     pub tmux_session: String,
     /// Directory for worker log files
     pub log_dir: PathBuf,
+    /// Worker log size that triggers rotation, in bytes (default: 10 MiB)
+    pub log_max_bytes: u64,
+    /// Number of rotated logs kept per worker (`<name>.log.1` is newest;
+    /// default: 3, `0` truncates without keeping a copy)
+    pub log_keep_rotated: u32,
+    /// Delete rotated logs older than this many seconds (unset = keep until
+    /// pushed out by `log_keep_rotated`)
+    pub log_max_age_secs: Option<u64>,
     /// Inactivity timeout in milliseconds (default: 5 minutes)
     pub inactivity_timeout_ms: u64,
+    /// Maximum wait for a worker response before the partial capture is
+    /// delivered with a timeout marker, in milliseconds (default: 60 seconds)
+    pub capture_timeout_ms: u64,
     /// Health check interval in seconds (default: 30)
     pub health_check_interval_secs: u64,
     /// Maximum restart attempts before giving up (default: 3)
@@ -150,6 +195,14 @@ pub struct WorkersConfig {
     pub restart_backoff_secs: u64,
     /// Graceful shutdown timeout in seconds (default: 10)
     pub shutdown_timeout_secs: u64,
+    /// Longest wait for busy workers to finish their turn before teardown,
+    /// in seconds (default: 30, 0 disables draining)
+    pub drain_timeout_secs: u64,
+    /// Send an `agent_started` notice when an agent is launched (default: true)
+    pub launch_notice: bool,
+    /// Recipient of `agent_started` notices (default: the team lead from the
+    /// team config's `leadAgentId`)
+    pub launch_notice_to: Option<String>,
     /// Nudge engine configuration
     pub nudge: NudgeConfig,
     /// Per-agent configuration
@@ -446,12 +499,39 @@ impl WorkersConfig {
             .map(PathBuf::from)
             .unwrap_or(default_log_dir);
 
+        let log_max_bytes = table
+            .get("log_max_bytes")
+            .and_then(|v| v.as_integer())
+            .filter(|i| *i > 0)
+            .map(|i| i as u64)
+            .unwrap_or(DEFAULT_LOG_MAX_BYTES);
+
+        let log_keep_rotated = table
+            .get("log_keep_rotated")
+            .and_then(|v| v.as_integer())
+            .filter(|i| *i >= 0)
+            .map(|i| i as u32)
+            .unwrap_or(DEFAULT_LOG_KEEP_ROTATED);
+
+        let log_max_age_secs = table
+            .get("log_max_age_secs")
+            .and_then(|v| v.as_integer())
+            .filter(|i| *i > 0)
+            .map(|i| i as u64);
+
         let inactivity_timeout_ms = table
             .get("inactivity_timeout_ms")
             .and_then(|v| v.as_integer())
             .map(|i| i as u64)
             .unwrap_or(5 * 60 * 1000); // 5 minutes default
 
+        let capture_timeout_ms = table
+            .get("capture_timeout_ms")
+            .and_then(|v| v.as_integer())
+            .filter(|i| *i > 0)
+            .map(|i| i as u64)
+            .unwrap_or(DEFAULT_CAPTURE_TIMEOUT_MS);
+
         let health_check_interval_secs = table
             .get("health_check_interval_secs")
             .and_then(|v| v.as_integer())
@@ -476,6 +556,24 @@ impl WorkersConfig {
             .map(|i| i as u64)
             .unwrap_or(10); // 10 seconds default
 
+        let drain_timeout_secs = table
+            .get("drain_timeout_secs")
+            .and_then(|v| v.as_integer())
+            .map(|i| i.max(0) as u64)
+            .unwrap_or(DEFAULT_DRAIN_TIMEOUT_SECS);
+
+        let launch_notice = table
+            .get("launch_notice")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let launch_notice_to = table
+            .get("launch_notice_to")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+
         // Parse nudge configuration from [workers.nudge]
         let nudge = NudgeConfig::from_toml(table.get("nudge"));
 
@@ -508,6 +606,11 @@ impl WorkersConfig {
                             .and_then(|v| v.as_str())
                             .unwrap_or("queue")
                             .to_string(),
+                        max_idle_before_stop_secs: agent_table
+                            .get("max_idle_before_stop_secs")
+                            .and_then(|v| v.as_integer())
+                            .filter(|i| *i > 0)
+                            .map(|i| i as u64),
                     }
                 } else {
                     AgentConfig::default()
@@ -523,11 +626,18 @@ impl WorkersConfig {
             command,
             tmux_session,
             log_dir,
+            log_max_bytes,
+            log_keep_rotated,
+            log_max_age_secs,
             inactivity_timeout_ms,
+            capture_timeout_ms,
             health_check_interval_secs,
             max_restart_attempts,
             restart_backoff_secs,
             shutdown_timeout_secs,
+            drain_timeout_secs,
+            launch_notice,
+            launch_notice_to,
             nudge,
             agents,
         };
@@ -551,11 +661,18 @@ impl Default for WorkersConfig {
             command: DEFAULT_COMMAND.to_string(),
             tmux_session: "atm-workers".to_string(),
             log_dir: default_log_dir,
+            log_max_bytes: DEFAULT_LOG_MAX_BYTES,
+            log_keep_rotated: DEFAULT_LOG_KEEP_ROTATED,
+            log_max_age_secs: None,
             inactivity_timeout_ms: 5 * 60 * 1000,
+            capture_timeout_ms: DEFAULT_CAPTURE_TIMEOUT_MS,
             health_check_interval_secs: 30,
             max_restart_attempts: 3,
             restart_backoff_secs: 5,
             shutdown_timeout_secs: 10,
+            drain_timeout_secs: DEFAULT_DRAIN_TIMEOUT_SECS,
+            launch_notice: true,
+            launch_notice_to: None,
             nudge: NudgeConfig::default(),
             agents: HashMap::new(),
         }
@@ -591,6 +708,7 @@ enabled = false
 cooldown_secs = 60
 text_template = "You have {count} messages waiting."
 sentinel_tier = "blocked"
+ignore_senders = ["ci-bot", "atm-daemon"]
 "#;
         let table: toml::Table = toml::from_str(toml_str).unwrap();
         let value = toml::Value::Table(table);
@@ -599,6 +717,7 @@ sentinel_tier = "blocked"
         assert_eq!(nudge.cooldown_secs, 60);
         assert_eq!(nudge.text_template, "You have {count} messages waiting.");
         assert_eq!(nudge.sentinel_tier, "blocked");
+        assert_eq!(nudge.ignore_senders, vec!["ci-bot", "atm-daemon"]);
     }
 
     #[test]
@@ -657,6 +776,9 @@ backend = "codex-tmux"
 team_name = "test-team"
 tmux_session = "my-workers"
 log_dir = "/var/log/atm-workers"
+log_max_bytes = 1048576
+log_keep_rotated = 5
+log_max_age_secs = 86400
 "#;
         let table: toml::Table = toml::from_str(toml_str).unwrap();
         let config = WorkersConfig::from_toml(&table).unwrap();
@@ -666,6 +788,9 @@ log_dir = "/var/log/atm-workers"
         assert_eq!(config.team_name, "test-team");
         assert_eq!(config.tmux_session, "my-workers");
         assert_eq!(config.log_dir, PathBuf::from("/var/log/atm-workers"));
+        assert_eq!(config.log_max_bytes, 1_048_576);
+        assert_eq!(config.log_keep_rotated, 5);
+        assert_eq!(config.log_max_age_secs, Some(86_400));
     }
 
     #[test]
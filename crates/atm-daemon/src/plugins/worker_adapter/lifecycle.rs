@@ -1,16 +1,23 @@
 //! Worker lifecycle management — startup, health checks, crash recovery, shutdown
 
+use super::activity::IdleRetirement;
+use super::agent_state::{AgentState, AgentStateTracker};
 use super::config::WorkersConfig;
 use super::trait_def::{WorkerAdapter, WorkerHandle};
 use crate::plugin::PluginError;
+use agent_team_mail_core::schema::InboxMessage;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
-/// Maximum log file size before rotation (10 MB)
-const MAX_LOG_SIZE: u64 = 10 * 1024 * 1024;
+/// Bytes of trailing worker output captured as the shutdown summary when an
+/// idle worker is auto-retired
+pub const RETIRE_SUMMARY_MAX_BYTES: u64 = 2048;
+
+/// How often a draining shutdown re-checks busy workers
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 /// Worker state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -70,6 +77,12 @@ pub struct LifecycleManager {
     max_restart_attempts: u32,
     /// Backoff duration between restart attempts (seconds)
     restart_backoff_secs: u64,
+    /// Idle-time tracking for `max_idle_before_stop_secs` auto-retirement
+    idle_retirement: IdleRetirement,
+    /// Longest wait for busy workers to finish their turn on shutdown
+    drain_timeout: Duration,
+    /// Set once shutdown begins; no new messages are routed while draining
+    draining: bool,
 }
 
 impl LifecycleManager {
@@ -80,17 +93,32 @@ impl LifecycleManager {
             health_check_interval: 30, // 30 seconds
             max_restart_attempts: 3,
             restart_backoff_secs: 5,
+            idle_retirement: IdleRetirement::default(),
+            drain_timeout: Duration::from_secs(super::config::DEFAULT_DRAIN_TIMEOUT_SECS),
+            draining: false,
         }
     }
 
     /// Create a lifecycle manager from config
     pub fn from_config(config: &WorkersConfig) -> Self {
+        let idle_limits = config
+            .agents
+            .values()
+            .filter_map(|agent| {
+                agent
+                    .max_idle_before_stop_secs
+                    .map(|secs| (agent.member_name.clone(), Duration::from_secs(secs)))
+            })
+            .collect();
         // Extract lifecycle settings from config
         Self {
             metadata: HashMap::new(),
             health_check_interval: config.health_check_interval_secs,
             max_restart_attempts: config.max_restart_attempts,
             restart_backoff_secs: config.restart_backoff_secs,
+            idle_retirement: IdleRetirement::new(idle_limits),
+            drain_timeout: Duration::from_secs(config.drain_timeout_secs),
+            draining: false,
         }
     }
 
@@ -175,12 +203,44 @@ impl LifecycleManager {
         }
     }
 
+    /// Record whether a worker is currently idle (at `now`) for auto-retirement
+    pub fn observe_idle(&mut self, agent_id: &str, is_idle: bool, now: Instant) {
+        if self.metadata.contains_key(agent_id) {
+            self.idle_retirement.observe(agent_id, is_idle, now);
+        }
+    }
+
+    /// Workers idle at least their `max_idle_before_stop_secs`, with idle time
+    pub fn idle_workers_to_retire(&self, now: Instant) -> Vec<(String, Duration)> {
+        self.idle_retirement.due(now)
+    }
+
     /// Remove worker from tracking
     pub fn unregister_worker(&mut self, agent_id: &str) {
         self.metadata.remove(agent_id);
+        self.idle_retirement.forget(agent_id);
         debug!("Unregistered worker for agent {agent_id}");
     }
 
+    /// Enter the drain phase: stop routing new messages ahead of shutdown
+    pub fn begin_drain(&mut self) {
+        self.draining = true;
+        debug!(
+            "Lifecycle manager draining (timeout {:?})",
+            self.drain_timeout
+        );
+    }
+
+    /// Whether shutdown has begun draining workers
+    pub fn is_draining(&self) -> bool {
+        self.draining
+    }
+
+    /// Longest wait for busy workers during the drain phase
+    pub fn drain_timeout(&self) -> Duration {
+        self.drain_timeout
+    }
+
     /// Get all worker states for status reporting
     pub fn get_all_states(&self) -> HashMap<String, WorkerState> {
         self.metadata
@@ -357,59 +417,175 @@ pub async fn restart_worker(
     }
 }
 
-/// Rotate log file if it exceeds size limit
+/// Rotate a worker log once it exceeds `config.log_max_bytes`, then apply
+/// rotated-log retention.
 ///
-/// # Arguments
+/// Rotation is copy-truncate: the current contents are copied to
+/// `<log>.1` (older copies shift to `.2`, `.3`, ...) and the live file is
+/// truncated in place. The worker's `tee -a` keeps its append-mode descriptor
+/// and resumes writing at offset 0, and readers that tail the log by byte
+/// offset observe `len < offset`, the truncation signal they reset on. Renaming
+/// the live file instead would leave the writer appending to the renamed copy.
+///
+/// Output the worker writes between the copy and the truncate lands in
+/// neither file and is lost; the window is one file copy long.
 ///
-/// * `log_path` - Path to the log file
+/// Rotated logs beyond `config.log_keep_rotated`, or older than
+/// `config.log_max_age_secs`, are deleted.
 ///
 /// # Errors
 ///
-/// Returns error if rotation fails
-pub fn rotate_log_if_needed(log_path: &PathBuf) -> Result<(), PluginError> {
-    if !log_path.exists() {
-        return Ok(());
+/// Returns error if copying or truncating the live log fails
+pub fn rotate_log_if_needed(log_path: &Path, config: &WorkersConfig) -> Result<(), PluginError> {
+    let len = match std::fs::metadata(log_path) {
+        Ok(metadata) => metadata.len(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => {
+            warn!("Failed to check log file size: {e}");
+            return Ok(());
+        }
+    };
+
+    if len > config.log_max_bytes {
+        debug!(
+            "Rotating log file {} ({len} bytes, keeping {})",
+            log_path.display(),
+            config.log_keep_rotated
+        );
+        let keep = config.log_keep_rotated;
+        if keep > 0 {
+            for index in (1..keep).rev() {
+                let from = rotated_log_path(log_path, index);
+                if from.exists() {
+                    let to = rotated_log_path(log_path, index + 1);
+                    let _ = std::fs::remove_file(&to);
+                    if let Err(e) = std::fs::rename(&from, &to) {
+                        warn!("Failed to shift rotated log {}: {e}", from.display());
+                    }
+                }
+            }
+            std::fs::copy(log_path, rotated_log_path(log_path, 1)).map_err(|e| {
+                PluginError::Runtime {
+                    message: format!("Failed to copy log file for rotation: {e}"),
+                    source: Some(Box::new(e)),
+                }
+            })?;
+        }
+
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(log_path)
+            .and_then(|file| file.set_len(0))
+            .map_err(|e| PluginError::Runtime {
+                message: format!("Failed to truncate log file: {e}"),
+                source: Some(Box::new(e)),
+            })?;
+
+        info!("Log file rotated: {}", log_path.display());
     }
 
-    match std::fs::metadata(log_path) {
-        Ok(metadata) if metadata.len() > MAX_LOG_SIZE => {
-            // Rotate by renaming to .log.old
-            let old_path = log_path.with_extension("log.old");
-            debug!(
-                "Rotating log file {} ({} bytes) to {}",
-                log_path.display(),
-                metadata.len(),
-                old_path.display()
-            );
+    prune_rotated_logs(log_path, config);
+    Ok(())
+}
 
-            if let Err(e) = std::fs::rename(log_path, &old_path) {
-                warn!("Failed to rotate log file: {e}");
-                return Err(PluginError::Runtime {
-                    message: format!("Failed to rotate log file: {e}"),
-                    source: Some(Box::new(e)),
-                });
-            }
+/// Path of the `index`-th rotated copy of `log_path` (`<log>.<index>`).
+fn rotated_log_path(log_path: &Path, index: u32) -> PathBuf {
+    let mut name = log_path.as_os_str().to_owned();
+    name.push(format!(".{index}"));
+    PathBuf::from(name)
+}
 
-            // Create new empty log file
-            if let Err(e) = std::fs::File::create(log_path) {
-                warn!("Failed to create new log file: {e}");
-                return Err(PluginError::Runtime {
-                    message: format!("Failed to create new log file: {e}"),
-                    source: Some(Box::new(e)),
-                });
+/// Delete rotated logs past the keep count or older than the max age.
+fn prune_rotated_logs(log_path: &Path, config: &WorkersConfig) {
+    let max_age = config.log_max_age_secs.map(Duration::from_secs);
+    let mut index = 1;
+    loop {
+        let path = rotated_log_path(log_path, index);
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            break;
+        };
+        let expired = max_age.is_some_and(|max_age| {
+            metadata
+                .modified()
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age > max_age)
+        });
+        if index > config.log_keep_rotated || expired {
+            match std::fs::remove_file(&path) {
+                Ok(()) => debug!("Removed rotated log {}", path.display()),
+                Err(e) => warn!("Failed to remove rotated log {}: {e}", path.display()),
             }
-
-            info!("Log file rotated: {}", log_path.display());
-        }
-        Ok(_) => {
-            // Size is OK, no rotation needed
         }
-        Err(e) => {
-            warn!("Failed to check log file size: {e}");
+        index += 1;
+    }
+}
+
+/// Read the trailing `max_bytes` of a worker log as a shutdown summary
+///
+/// Returns `None` if the log is missing, unreadable, or empty.
+pub fn read_log_tail(log_path: &std::path::Path, max_bytes: u64) -> Option<String> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = std::fs::File::open(log_path).ok()?;
+    let len = file.metadata().ok()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(max_bytes)))
+        .ok()?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf).ok()?;
+    let tail = String::from_utf8_lossy(&buf).trim().to_string();
+    (!tail.is_empty()).then_some(tail)
+}
+
+/// Wait for busy workers to finish their current turn
+///
+/// Polls `agent_state` until none of `agents` is [`AgentState::Active`] or
+/// `timeout` expires.
+///
+/// # Returns
+///
+/// The agents still busy when the timeout expired (empty when all finished)
+pub async fn wait_for_busy_workers(
+    agent_state: &std::sync::Mutex<AgentStateTracker>,
+    agents: &[String],
+    timeout: Duration,
+) -> Vec<String> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let busy: Vec<String> = {
+            let tracker = agent_state.lock().unwrap();
+            agents
+                .iter()
+                .filter(|agent| tracker.get_state(agent) == Some(AgentState::Active))
+                .cloned()
+                .collect()
+        };
+        if busy.is_empty() || Instant::now() >= deadline {
+            return busy;
         }
+        sleep(DRAIN_POLL_INTERVAL.min(deadline.saturating_duration_since(Instant::now()))).await;
     }
+}
 
-    Ok(())
+/// Build the `agent_started` notice sent to the team lead after a launch
+///
+/// The `message_id` is derived from team, agent, and runtime session, so a
+/// re-delivered notice for the same session (e.g. after a daemon restart
+/// resumes it) is deduplicated by `inbox_append`.
+pub fn agent_started_message(
+    team: &str,
+    agent: &str,
+    pane_id: &str,
+    cwd: Option<&str>,
+    runtime_session_id: &str,
+) -> InboxMessage {
+    let cwd = cwd.unwrap_or("unknown");
+    InboxMessage::new(
+        "worker-adapter",
+        format!("agent_started: {agent} is running in pane {pane_id} (cwd: {cwd})"),
+    )
+    .summary(format!("agent_started: {agent}"))
+    .message_id(format!("agent-started:{team}:{agent}:{runtime_session_id}"))
 }
 
 /// Gracefully shutdown a worker with timeout
@@ -484,6 +660,37 @@ pub fn get_pane_pid(pane_id: &str) -> Option<u32> {
     trimmed.parse::<u32>().ok()
 }
 
+/// Get the current working directory of a tmux pane.
+///
+/// Runs `tmux display-message -t <pane_id> -p "#{pane_current_path}"`.
+#[cfg(unix)]
+pub fn get_pane_cwd(pane_id: &str) -> Option<String> {
+    let output = std::process::Command::new("tmux")
+        .args([
+            "display-message",
+            "-t",
+            pane_id,
+            "-p",
+            "#{pane_current_path}",
+        ])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let trimmed = stdout.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// Non-Unix stub for pane cwd lookup.
+#[cfg(not(unix))]
+pub fn get_pane_cwd(_pane_id: &str) -> Option<String> {
+    None
+}
+
 /// Non-Unix stub for pane PID lookup.
 #[cfg(not(unix))]
 pub fn get_pane_pid(_pane_id: &str) -> Option<u32> {
@@ -633,6 +840,128 @@ mod tests {
         assert_eq!(states.get("agent2"), Some(&WorkerState::Crashed));
     }
 
+    #[test]
+    fn test_idle_workers_to_retire_uses_per_agent_limit() {
+        let toml_str = r#"
+enabled = false
+[agents."worker"]
+member_name = "worker-1"
+max_idle_before_stop_secs = 120
+[agents."other"]
+member_name = "worker-2"
+"#;
+        let table: toml::Table = toml::from_str(toml_str).unwrap();
+        let config = WorkersConfig::from_toml(&table).unwrap();
+        let mut manager = LifecycleManager::from_config(&config);
+        manager.register_worker("worker-1");
+        manager.register_worker("worker-2");
+
+        let t0 = Instant::now();
+        manager.observe_idle("worker-1", true, t0);
+        manager.observe_idle("worker-2", true, t0);
+        assert!(
+            manager
+                .idle_workers_to_retire(t0 + Duration::from_secs(60))
+                .is_empty()
+        );
+
+        let due = manager.idle_workers_to_retire(t0 + Duration::from_secs(121));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].0, "worker-1");
+
+        manager.unregister_worker("worker-1");
+        assert!(
+            manager
+                .idle_workers_to_retire(t0 + Duration::from_secs(500))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_read_log_tail_returns_trailing_bytes() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let log = temp.path().join("worker.log");
+        assert!(read_log_tail(&log, 16).is_none());
+        std::fs::write(&log, "early output\nfinal summary line\n").unwrap();
+        assert_eq!(
+            read_log_tail(&log, 19).as_deref(),
+            Some("final summary line")
+        );
+    }
+
+    /// Copy-truncate rotation must surface to byte-offset readers as the file
+    /// shrinking below their position, while an append-mode writer (the
+    /// worker's `tee -a`) keeps writing to the live file. The TUI reader's
+    /// side of this contract is covered by
+    /// `test_tail_log_file_follows_copy_truncate_rotation` in `atm-tui`.
+    #[test]
+    fn test_rotate_log_truncates_in_place_for_tailing_readers() {
+        use std::io::Write;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let log = temp.path().join("worker.log");
+        let config = WorkersConfig {
+            log_max_bytes: 16,
+            log_keep_rotated: 2,
+            ..WorkersConfig::default()
+        };
+        let mut writer = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log)
+            .unwrap();
+
+        writer.write_all(b"first generation output\n").unwrap();
+        let reader_pos = std::fs::metadata(&log).unwrap().len();
+        rotate_log_if_needed(&log, &config).unwrap();
+        writer.write_all(b"after\n").unwrap();
+
+        let live_len = std::fs::metadata(&log).unwrap().len();
+        assert!(live_len < reader_pos, "reader must observe truncation");
+        assert_eq!(std::fs::read_to_string(&log).unwrap(), "after\n");
+        assert_eq!(
+            std::fs::read_to_string(rotated_log_path(&log, 1)).unwrap(),
+            "first generation output\n"
+        );
+
+        // Two more rotations shift copies and drop the oldest beyond keep=2.
+        for generation in ["second generation output\n", "third generation output\n"] {
+            writer.write_all(generation.as_bytes()).unwrap();
+            rotate_log_if_needed(&log, &config).unwrap();
+        }
+        assert!(
+            std::fs::read_to_string(rotated_log_path(&log, 1))
+                .unwrap()
+                .ends_with("third generation output\n")
+        );
+        assert!(rotated_log_path(&log, 2).exists());
+        assert!(!rotated_log_path(&log, 3).exists());
+    }
+
+    #[test]
+    fn test_prune_rotated_logs_by_age() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let log = temp.path().join("worker.log");
+        std::fs::write(&log, "live\n").unwrap();
+        let rotated = rotated_log_path(&log, 1);
+        std::fs::write(&rotated, "old\n").unwrap();
+        let file = std::fs::File::options().write(true).open(&rotated).unwrap();
+        file.set_modified(std::time::SystemTime::now() - Duration::from_secs(7200))
+            .unwrap();
+
+        let keep_forever = WorkersConfig::default();
+        rotate_log_if_needed(&log, &keep_forever).unwrap();
+        assert!(rotated.exists());
+
+        let one_hour = WorkersConfig {
+            log_max_age_secs: Some(3600),
+            ..WorkersConfig::default()
+        };
+        rotate_log_if_needed(&log, &one_hour).unwrap();
+        assert!(!rotated.exists());
+        assert!(log.exists(), "live log is never pruned");
+    }
+
     #[test]
     fn test_worker_state_display() {
         assert_eq!(WorkerState::Running.to_string(), "running");
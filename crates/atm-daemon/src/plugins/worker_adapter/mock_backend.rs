@@ -6,8 +6,12 @@
 use super::trait_def::{WorkerAdapter, WorkerHandle};
 use crate::plugin::PluginError;
 use std::collections::HashMap;
+#[cfg(any(test, feature = "test-support"))]
+use std::collections::VecDeque;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+#[cfg(any(test, feature = "test-support"))]
+use std::time::Duration;
 use tracing::debug;
 
 /// Mock backend payload for testing
@@ -38,6 +42,89 @@ pub enum MockCall {
     },
 }
 
+/// Scripted behavior for a single `send_message` turn
+#[cfg(any(test, feature = "test-support"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockTurn {
+    /// Accept the message without producing output
+    Accept,
+    /// Accept the message and append `text` to the worker's log file
+    Respond { text: String },
+    /// Wait `delay` before accepting, then append `text` to the log file
+    SlowRespond { delay: Duration, text: String },
+    /// Fail the send with `message`; the worker stays spawned
+    Fail { message: String },
+    /// Fail the send and drop the worker, as if its pane died mid-turn
+    Crash,
+}
+
+/// Ordered per-turn script for one agent
+///
+/// Turns are consumed one per `send_message` call. Once the script runs out,
+/// sends fall back to the default behavior (record and accept).
+///
+/// ```ignore
+/// let scenario = MockScenario::new()
+///     .respond("first answer")
+///     .crash()
+///     .slow_respond(Duration::from_millis(200), "after restart");
+/// backend.load_scenario("agent-1", scenario);
+/// ```
+#[cfg(any(test, feature = "test-support"))]
+#[derive(Debug, Clone, Default)]
+pub struct MockScenario {
+    turns: VecDeque<MockTurn>,
+}
+
+#[cfg(any(test, feature = "test-support"))]
+impl MockScenario {
+    /// Create an empty scenario
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an arbitrary turn
+    pub fn turn(mut self, turn: MockTurn) -> Self {
+        self.turns.push_back(turn);
+        self
+    }
+
+    /// Append a turn that accepts the message silently
+    pub fn accept(self) -> Self {
+        self.turn(MockTurn::Accept)
+    }
+
+    /// Append a turn that writes `text` as the captured response
+    pub fn respond(self, text: impl Into<String>) -> Self {
+        self.turn(MockTurn::Respond { text: text.into() })
+    }
+
+    /// Append a turn that responds with `text` after `delay`
+    pub fn slow_respond(self, delay: Duration, text: impl Into<String>) -> Self {
+        self.turn(MockTurn::SlowRespond {
+            delay,
+            text: text.into(),
+        })
+    }
+
+    /// Append a turn whose send fails with `message`
+    pub fn fail(self, message: impl Into<String>) -> Self {
+        self.turn(MockTurn::Fail {
+            message: message.into(),
+        })
+    }
+
+    /// Append a turn that crashes the worker
+    pub fn crash(self) -> Self {
+        self.turn(MockTurn::Crash)
+    }
+
+    /// Number of turns still queued
+    pub fn remaining(&self) -> usize {
+        self.turns.len()
+    }
+}
+
 /// Shared state for mock backend
 #[derive(Debug, Default)]
 struct MockState {
@@ -46,6 +133,10 @@ struct MockState {
     send_message_error: Option<String>,
     spawn_error: Option<String>,
     shutdown_error: Option<String>,
+    #[cfg(any(test, feature = "test-support"))]
+    scenarios: HashMap<String, MockScenario>,
+    #[cfg(any(test, feature = "test-support"))]
+    turn_counts: HashMap<String, usize>,
 }
 
 /// Mock worker backend for testing without real tmux/Codex
@@ -109,6 +200,39 @@ impl MockTmuxBackend {
         self.state.lock().unwrap().spawned_workers.len()
     }
 
+    /// Install a per-turn script for `agent_id`, replacing any previous one
+    #[cfg(any(test, feature = "test-support"))]
+    pub fn load_scenario(&self, agent_id: &str, scenario: MockScenario) {
+        self.state
+            .lock()
+            .unwrap()
+            .scenarios
+            .insert(agent_id.to_string(), scenario);
+    }
+
+    /// Number of scripted turns not yet consumed for `agent_id`
+    #[cfg(any(test, feature = "test-support"))]
+    pub fn remaining_turns(&self, agent_id: &str) -> usize {
+        self.state
+            .lock()
+            .unwrap()
+            .scenarios
+            .get(agent_id)
+            .map_or(0, MockScenario::remaining)
+    }
+
+    /// Number of `send_message` turns attempted for `agent_id`, including failures
+    #[cfg(any(test, feature = "test-support"))]
+    pub fn turn_count(&self, agent_id: &str) -> usize {
+        self.state
+            .lock()
+            .unwrap()
+            .turn_counts
+            .get(agent_id)
+            .copied()
+            .unwrap_or(0)
+    }
+
     /// Write a mock response to a worker's log file
     ///
     /// Used to simulate worker responses for testing response capture.
@@ -197,6 +321,25 @@ impl WorkerAdapter for MockTmuxBackend {
         handle: &WorkerHandle,
         message: &str,
     ) -> Result<(), PluginError> {
+        #[cfg(any(test, feature = "test-support"))]
+        let scripted = {
+            let mut state = self.state.lock().unwrap();
+            *state
+                .turn_counts
+                .entry(handle.agent_id.clone())
+                .or_default() += 1;
+            state
+                .scenarios
+                .get_mut(&handle.agent_id)
+                .and_then(|scenario| scenario.turns.pop_front())
+        };
+
+        // Slow turns sleep without holding the state lock.
+        #[cfg(any(test, feature = "test-support"))]
+        if let Some(MockTurn::SlowRespond { delay, .. }) = &scripted {
+            tokio::time::sleep(*delay).await;
+        }
+
         let mut state = self.state.lock().unwrap();
 
         // Check for injected error
@@ -209,6 +352,28 @@ impl WorkerAdapter for MockTmuxBackend {
             });
         }
 
+        #[cfg(any(test, feature = "test-support"))]
+        match scripted {
+            Some(MockTurn::Fail { message }) => {
+                return Err(PluginError::Runtime {
+                    message,
+                    source: None,
+                });
+            }
+            Some(MockTurn::Crash) => {
+                state.spawned_workers.remove(&handle.agent_id);
+                debug!("Mock backend crashed worker {}", handle.agent_id);
+                return Err(PluginError::Runtime {
+                    message: format!("mock worker {} crashed", handle.agent_id),
+                    source: None,
+                });
+            }
+            Some(MockTurn::Respond { text }) | Some(MockTurn::SlowRespond { text, .. }) => {
+                append_log(&handle.log_file_path, &text)?;
+            }
+            Some(MockTurn::Accept) | None => {}
+        }
+
         // Record call
         state.calls.push(MockCall::SendMessage {
             agent_id: handle.agent_id.clone(),
@@ -261,6 +426,22 @@ impl WorkerAdapter for MockTmuxBackend {
     }
 }
 
+/// Append scripted response text to a worker log file
+#[cfg(any(test, feature = "test-support"))]
+fn append_log(path: &std::path::Path, text: &str) -> Result<(), PluginError> {
+    use std::io::Write;
+
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(text.as_bytes()))
+        .map_err(|e| PluginError::Runtime {
+            message: format!("Failed to write mock response: {e}"),
+            source: Some(Box::new(e)),
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,6 +585,65 @@ mod tests {
         assert_eq!(content, "Mock response text");
     }
 
+    #[tokio::test]
+    async fn test_scenario_scripts_turns_in_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut backend = MockTmuxBackend::new(temp_dir.path().to_path_buf());
+        let handle = backend.spawn("test-agent", "{}").await.unwrap();
+
+        backend.load_scenario(
+            "test-agent",
+            MockScenario::new()
+                .respond("turn one\n")
+                .fail("busy")
+                .slow_respond(Duration::from_millis(20), "turn three\n"),
+        );
+        assert_eq!(backend.remaining_turns("test-agent"), 3);
+
+        backend.send_message(&handle, "m1").await.unwrap();
+        let err = backend.send_message(&handle, "m2").await.unwrap_err();
+        assert!(err.to_string().contains("busy"));
+
+        let started = std::time::Instant::now();
+        backend.send_message(&handle, "m3").await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(20));
+
+        // Script exhausted: default accept behavior resumes.
+        backend.send_message(&handle, "m4").await.unwrap();
+
+        assert_eq!(backend.turn_count("test-agent"), 4);
+        assert_eq!(backend.remaining_turns("test-agent"), 0);
+        let content = std::fs::read_to_string(&handle.log_file_path).unwrap();
+        assert_eq!(content, "turn one\nturn three\n");
+
+        let sent: Vec<_> = backend
+            .get_calls()
+            .into_iter()
+            .filter_map(|c| match c {
+                MockCall::SendMessage { message, .. } => Some(message),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(sent, vec!["m1", "m3", "m4"]);
+    }
+
+    #[tokio::test]
+    async fn test_scenario_crash_drops_worker() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut backend = MockTmuxBackend::new(temp_dir.path().to_path_buf());
+        let handle = backend.spawn("test-agent", "{}").await.unwrap();
+
+        backend.load_scenario("test-agent", MockScenario::new().accept().crash());
+
+        backend.send_message(&handle, "ok").await.unwrap();
+        assert!(backend.is_spawned("test-agent"));
+
+        let err = backend.send_message(&handle, "boom").await.unwrap_err();
+        assert!(err.to_string().contains("crashed"));
+        assert!(!backend.is_spawned("test-agent"));
+        assert_eq!(backend.turn_count("test-agent"), 2);
+    }
+
     #[tokio::test]
     async fn test_payload_present_on_spawn() {
         let temp_dir = TempDir::new().unwrap();
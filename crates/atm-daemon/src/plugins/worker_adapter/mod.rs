@@ -39,12 +39,14 @@ pub mod trait_def;
 
 pub use activity::ActivityTracker;
 pub use agent_state::{AgentPaneInfo, AgentState, AgentStateTracker, TransitionMeta};
-pub use capture::{CaptureConfig, CapturedResponse, LogTailer};
+pub use capture::{CAPTURE_TIMEOUT_MARKER, CaptureConfig, CapturedResponse, LogTailer};
 pub use codex_tmux::CodexTmuxBackend;
 pub use config::{AgentConfig, DEFAULT_COMMAND, DEFAULT_NUDGE_TEXT, NudgeConfig, WorkersConfig};
 pub use hook_watcher::HookWatcher;
 pub use lifecycle::{LifecycleManager, WorkerState};
 pub use mock_backend::{MockCall, MockTmuxBackend};
+#[cfg(any(test, feature = "test-support"))]
+pub use mock_backend::{MockScenario, MockTurn};
 pub use nudge::{InboxEntry, NudgeDecision, NudgeEngine};
 pub use plugin::WorkerAdapterPlugin;
 pub use pubsub::{PubSub, PubSubError, Subscription};
@@ -144,8 +144,11 @@ impl NudgeEngine {
             }
         }
 
-        // Collect unread messages
-        let unread: Vec<&InboxEntry> = inbox_messages.iter().filter(|e| !e.read).collect();
+        // Collect unread messages, ignoring senders configured not to wake agents
+        let unread: Vec<&InboxEntry> = inbox_messages
+            .iter()
+            .filter(|e| !e.read && !self.is_ignored_sender(e))
+            .collect();
 
         if unread.is_empty() {
             return NudgeDecision::SkippedNoUnread;
@@ -176,6 +179,14 @@ impl NudgeEngine {
         }
     }
 
+    /// Whether `entry` was sent by one of the configured `ignore_senders`.
+    fn is_ignored_sender(&self, entry: &InboxEntry) -> bool {
+        entry
+            .from
+            .as_deref()
+            .is_some_and(|from| self.config.ignore_senders.iter().any(|s| s == from))
+    }
+
     /// Record that a nudge was sent to `agent_id` with `message_id` as the watermark.
     ///
     /// Updates the cooldown timer and the watermark. Must be called after
@@ -260,14 +271,16 @@ impl NudgeEngine {
 
 /// Lightweight inbox message representation used by [`NudgeEngine`].
 ///
-/// The nudge engine only needs `read` status and `message_id`; full
-/// deserialization of the inbox JSON is done by the caller.
+/// The nudge engine only needs `read` status, `message_id`, and the sender;
+/// full deserialization of the inbox JSON is done by the caller.
 #[derive(Debug, Clone)]
 pub struct InboxEntry {
     /// Whether the message has been read.
     pub read: bool,
     /// Unique message identifier (may be `None` for old-format messages).
     pub message_id: Option<String>,
+    /// Sender identity, matched against `ignore_senders`.
+    pub from: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -301,6 +314,7 @@ fn load_inbox_entries(path: &Path) -> Vec<InboxEntry> {
                 .get("message_id")
                 .and_then(|v| v.as_str())
                 .map(String::from),
+            from: msg.get("from").and_then(|v| v.as_str()).map(String::from),
         })
         .collect()
 }
@@ -321,6 +335,7 @@ mod tests {
         InboxEntry {
             read: false,
             message_id: Some(id.to_string()),
+            from: None,
         }
     }
 
@@ -328,6 +343,15 @@ mod tests {
         InboxEntry {
             read: true,
             message_id: Some(id.to_string()),
+            from: None,
+        }
+    }
+
+    fn unread_from(id: &str, from: &str) -> InboxEntry {
+        InboxEntry {
+            read: false,
+            message_id: Some(id.to_string()),
+            from: Some(from.to_string()),
         }
     }
 
@@ -340,6 +364,7 @@ mod tests {
             cooldown_secs: 30,
             text_template: "You have {count} messages.".to_string(),
             sentinel_tier: "urgent".to_string(),
+            ignore_senders: Vec::new(),
         };
         let engine = NudgeEngine::new(config);
         let entries = vec![unread_entry("msg-1")];
@@ -390,6 +415,38 @@ mod tests {
         assert_eq!(decision, NudgeDecision::SkippedNoUnread);
     }
 
+    // ── Ignored senders ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_nudge_ignores_configured_senders() {
+        let engine = NudgeEngine::new(NudgeConfig {
+            ignore_senders: vec!["ci-bot".to_string()],
+            ..NudgeConfig::default()
+        });
+
+        let only_ignored = vec![unread_from("msg-1", "ci-bot")];
+        assert_eq!(
+            engine.should_nudge("arch-ctm", AgentState::Idle, &only_ignored),
+            NudgeDecision::SkippedNoUnread
+        );
+
+        let mixed = vec![
+            unread_from("msg-1", "team-lead"),
+            unread_from("msg-2", "ci-bot"),
+        ];
+        match engine.should_nudge("arch-ctm", AgentState::Idle, &mixed) {
+            NudgeDecision::Nudge {
+                unread_count,
+                newest_message_id,
+                ..
+            } => {
+                assert_eq!(unread_count, 1);
+                assert_eq!(newest_message_id, "msg-1");
+            }
+            other => panic!("Expected Nudge, got {other:?}"),
+        }
+    }
+
     // ── Happy path ────────────────────────────────────────────────────────
 
     #[test]
@@ -419,6 +476,7 @@ mod tests {
             cooldown_secs: 30,
             text_template: "Hey! {count} messages waiting.".to_string(),
             sentinel_tier: "blocked".to_string(),
+            ignore_senders: Vec::new(),
         };
         let engine = NudgeEngine::new(config);
         let text = engine.format_nudge_text(5);
@@ -439,6 +497,7 @@ mod tests {
             cooldown_secs: 30,
             text_template: "[agent-team-msg:{tier}] unread={count}".to_string(),
             sentinel_tier: "blocked".to_string(),
+            ignore_senders: Vec::new(),
         };
         let engine = NudgeEngine::new(config);
         let text = engine.format_nudge_text(2);
@@ -454,6 +513,7 @@ mod tests {
             cooldown_secs: 9999, // very long cooldown
             text_template: "{count}".to_string(),
             sentinel_tier: "urgent".to_string(),
+            ignore_senders: Vec::new(),
         };
         let mut engine = NudgeEngine::new(config);
 
@@ -473,6 +533,7 @@ mod tests {
             cooldown_secs: 9999,
             text_template: "{count}".to_string(),
             sentinel_tier: "urgent".to_string(),
+            ignore_senders: Vec::new(),
         };
         let mut engine = NudgeEngine::new(config);
 
@@ -505,6 +566,7 @@ mod tests {
             cooldown_secs: 0, // no cooldown
             text_template: "{count}".to_string(),
             sentinel_tier: "urgent".to_string(),
+            ignore_senders: Vec::new(),
         };
         let mut engine2 = NudgeEngine::new(config);
         engine2.record_nudge("arch-ctm", "msg-1".to_string());
@@ -522,6 +584,7 @@ mod tests {
             cooldown_secs: 0,
             text_template: "{count}".to_string(),
             sentinel_tier: "urgent".to_string(),
+            ignore_senders: Vec::new(),
         };
         let mut engine = NudgeEngine::new(config);
         engine.record_nudge("arch-ctm", "msg-1".to_string());
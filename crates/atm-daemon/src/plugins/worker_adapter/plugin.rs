@@ -2,7 +2,7 @@
 
 use super::activity::ActivityTracker;
 use super::agent_state::{AgentState, AgentStateTracker};
-use super::capture::LogTailer;
+use super::capture::{CaptureConfig, LogTailer};
 use super::codex_tmux::{CodexTmuxBackend, TmuxPayload};
 use super::config::WorkersConfig;
 use super::hook_watcher::HookWatcher;
@@ -11,20 +11,23 @@ use super::nudge::NudgeEngine;
 use super::pubsub::PubSub;
 use super::router::{ConcurrencyPolicy, MessageRouter};
 use super::trait_def::{WorkerAdapter, WorkerHandle};
+use crate::daemon::launch_queue;
 use crate::daemon::session_registry::SharedSessionRegistry;
 use crate::daemon::socket::LaunchRequest;
 use crate::plugin::{Capability, Plugin, PluginContext, PluginError, PluginMetadata};
 use crate::plugins::consts::{
     INACTIVITY_CHECK_INTERVAL_SECS, LOG_ROTATION_INTERVAL_SECS, NUDGE_SCAN_INTERVAL_SECS,
 };
+use agent_team_mail_core::config::MessageIdScheme;
 use agent_team_mail_core::daemon_client::{LaunchConfig, LaunchResult};
 use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
+use agent_team_mail_core::io::InboxError;
 use agent_team_mail_core::io::inbox::{inbox_append, inbox_update};
+use agent_team_mail_core::io::new_message_id;
 use agent_team_mail_core::schema::InboxMessage;
 use agent_team_mail_core::team_config_store::TeamConfigStore;
-use chrono::Utc;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use tokio::time::{Duration, interval};
 use tokio_util::sync::CancellationToken;
@@ -393,16 +396,8 @@ impl WorkerAdapterPlugin {
             "Warning: worker_adapter could not route your message. {details}\n\nAction: Please specify a valid recipient (agent member_name)."
         );
 
-        let warn_msg = InboxMessage {
-            from: "worker-adapter".to_string(),
-            source_team: None,
-            text: warning_text,
-            timestamp: Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some("Worker adapter routing warning".to_string()),
-            message_id: Some(Uuid::new_v4().to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let warn_msg = InboxMessage::new("worker-adapter", warning_text)
+            .summary("Worker adapter routing warning");
 
         let (sender_team, sender_name) = match self.resolve_sender_route(ctx, message) {
             Ok(route) => route,
@@ -416,18 +411,78 @@ impl WorkerAdapterPlugin {
         let sender_inbox = team_root
             .join("inboxes")
             .join(format!("{sender_name}.json"));
-        if let Err(e) = inbox_append(&sender_inbox, &warn_msg, &sender_team, &sender_name) {
+        if let Err(e) =
+            append_with_new_id(ctx, &sender_inbox, &warn_msg, &sender_team, &sender_name)
+        {
             error!("Failed to warn sender {sender_name}: {e}");
         }
 
         if sender_name != "team-lead" {
             let lead_inbox = team_root.join("inboxes").join("team-lead.json");
-            if let Err(e) = inbox_append(&lead_inbox, &warn_msg, &sender_team, "team-lead") {
+            if let Err(e) =
+                append_with_new_id(ctx, &lead_inbox, &warn_msg, &sender_team, "team-lead")
+            {
                 error!("Failed to warn team-lead: {e}");
             }
         }
     }
 
+    /// Send the `agent_started` notice for a newly launched agent.
+    ///
+    /// Goes to `launch_notice_to`, or the team lead named by the team config's
+    /// `leadAgentId` (`team-lead` when unset). Skipped when disabled or when
+    /// the lead launched itself. Best-effort: failures are logged.
+    fn notify_agent_started(
+        &self,
+        team_name: &str,
+        agent: &str,
+        pane_id: &str,
+        runtime_session_id: &str,
+    ) {
+        if !self.config.launch_notice {
+            return;
+        }
+        let Some(ctx) = self.ctx.as_ref() else {
+            return;
+        };
+        let team_config =
+            TeamConfigStore::open(&ctx.system.claude_root.join("teams").join(team_name))
+                .read()
+                .ok();
+        let recipient = self.config.launch_notice_to.clone().unwrap_or_else(|| {
+            team_config
+                .as_ref()
+                .and_then(|cfg| cfg.lead_agent_id.split('@').next())
+                .filter(|name| !name.is_empty())
+                .unwrap_or("team-lead")
+                .to_string()
+        });
+        if recipient == agent {
+            return;
+        }
+        let cwd = lifecycle::get_pane_cwd(pane_id).or_else(|| {
+            team_config.as_ref().and_then(|cfg| {
+                cfg.members
+                    .iter()
+                    .find(|m| m.name == agent)
+                    .map(|m| m.cwd.clone())
+            })
+        });
+
+        let message = lifecycle::agent_started_message(
+            team_name,
+            agent,
+            pane_id,
+            cwd.as_deref(),
+            runtime_session_id,
+        );
+        let inbox = self.agent_inbox_path(ctx, team_name, &recipient);
+        match inbox_append(&inbox, &message, team_name, &recipient) {
+            Ok(_) => debug!("Sent agent_started notice for {agent} to {recipient}"),
+            Err(e) => warn!("Failed to send agent_started notice for {agent} to {recipient}: {e}"),
+        }
+    }
+
     /// Build the inbox path for an agent member by name.
     ///
     /// Path: `{claude_root}/teams/{team_name}/inboxes/{member_name}.json`
@@ -621,27 +676,28 @@ impl WorkerAdapterPlugin {
             captured.response_text.len()
         );
 
+        // A timed-out capture means the agent never finished its turn; treat it
+        // as idle so the router and nudge engine stop waiting on it.
+        if captured.timed_out {
+            warn!(
+                "Response capture from {member_name} timed out; delivering partial response and marking idle"
+            );
+            let mut state = self.agent_state.lock().unwrap();
+            state.set_state(&member_name, AgentState::Idle);
+        }
+
         // Record activity after successful response capture
         self.record_activity(ctx, team_name, &member_name);
 
         // Build response message (use member_name as sender)
-        let response = InboxMessage {
-            from: member_name.clone(),
-            source_team: None,
-            text: captured.response_text,
-            timestamp: Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some(format!("Response from {member_name}")),
-            message_id: Some(Uuid::new_v4().to_string()),
-            unknown_fields: if let Some(request_id) = message.unknown_fields.get("requestId") {
-                // Correlate with Request-ID if present
-                let mut fields = HashMap::new();
-                fields.insert("requestId".to_string(), request_id.clone());
-                fields
-            } else {
-                HashMap::new()
-            },
-        };
+        let mut response = InboxMessage::new(member_name.clone(), captured.response_text)
+            .summary(format!("Response from {member_name}"));
+        if let Some(request_id) = message.unknown_fields.get("requestId") {
+            // Correlate with Request-ID if present
+            response
+                .unknown_fields
+                .insert("requestId".to_string(), request_id.clone());
+        }
 
         // Write response to sender's inbox
         let ctx = self.ctx.as_ref().ok_or_else(|| PluginError::Runtime {
@@ -657,7 +713,13 @@ impl WorkerAdapterPlugin {
             .join("inboxes")
             .join(format!("{sender_name}.json"));
 
-        if let Err(e) = inbox_append(&sender_inbox_path, &response, &sender_team, &sender_name) {
+        if let Err(e) = append_with_new_id(
+            ctx,
+            &sender_inbox_path,
+            &response,
+            &sender_team,
+            &sender_name,
+        ) {
             error!("Failed to write response to {sender_name} inbox: {e}");
         } else {
             debug!("Wrote response to {sender_name} inbox");
@@ -713,6 +775,48 @@ impl WorkerAdapterPlugin {
         Ok(())
     }
 
+    /// Drain phase of shutdown: stop routing new messages and give busy
+    /// workers up to `drain_timeout_secs` to finish their current turn.
+    async fn drain_workers(&mut self) {
+        self.lifecycle.begin_drain();
+        self.agent_state.lock().unwrap().set_draining(true);
+
+        let agents: Vec<String> = self.workers.keys().cloned().collect();
+        let timeout = self.lifecycle.drain_timeout();
+        let started = std::time::Instant::now();
+        let still_busy =
+            lifecycle::wait_for_busy_workers(&self.agent_state, &agents, timeout).await;
+        if still_busy.is_empty() {
+            debug!("Worker drain complete after {:?}", started.elapsed());
+        } else {
+            warn!(
+                "Drain timeout ({}s) expired with busy workers: {}",
+                timeout.as_secs(),
+                still_busy.join(", ")
+            );
+        }
+        emit_event_best_effort(EventFields {
+            level: if still_busy.is_empty() {
+                "info"
+            } else {
+                "warn"
+            },
+            source: "atm-daemon",
+            action: "worker_drain",
+            target: Some("worker_adapter".to_string()),
+            result: Some(
+                if still_busy.is_empty() {
+                    "ok"
+                } else {
+                    "timeout"
+                }
+                .to_string(),
+            ),
+            count: Some(still_busy.len() as u64),
+            ..Default::default()
+        });
+    }
+
     /// Perform health check on all workers
     async fn health_check_all_workers(&mut self) -> Result<(), PluginError> {
         let member_names: Vec<String> = self.workers.keys().cloned().collect();
@@ -778,7 +882,7 @@ impl WorkerAdapterPlugin {
     /// Rotate log files for all workers if needed
     fn rotate_logs_if_needed(&self) {
         for handle in self.workers.values() {
-            if let Err(e) = lifecycle::rotate_log_if_needed(&handle.log_file_path) {
+            if let Err(e) = lifecycle::rotate_log_if_needed(&handle.log_file_path, &self.config) {
                 error!("Failed to rotate log for {}: {e}", handle.agent_id);
             }
         }
@@ -831,20 +935,20 @@ impl WorkerAdapterPlugin {
 
         for subscriber in &subscribers {
             let notification_text = format!("[AGENT STATE] {} is now {}", agent, new_state);
-            let mut msg = InboxMessage {
-                from: "daemon".to_string(),
-                source_team: None,
-                text: notification_text,
-                timestamp: Utc::now().to_rfc3339(),
-                read: false,
-                summary: Some(format!("Agent {} → {}", agent, new_state)),
-                message_id: Some(Uuid::new_v4().to_string()),
-                unknown_fields: HashMap::new(),
+            let inbox_path = self.agent_inbox_path(ctx, team_name, subscriber);
+            let message_id = match new_message_id(id_scheme(ctx), &inbox_path) {
+                Ok(id) => id,
+                Err(e) => {
+                    warn!("Failed to allocate pubsub notification id for {subscriber}: {e}");
+                    continue;
+                }
             };
+            let mut msg = InboxMessage::new("daemon", notification_text)
+                .summary(format!("Agent {} → {}", agent, new_state))
+                .message_id(message_id);
             if new_state == "idle" {
                 msg.mark_idle_notification(agent.to_string());
             }
-            let inbox_path = self.agent_inbox_path(ctx, team_name, subscriber);
             let write_result = if msg.is_idle_notification() {
                 inbox_update(&inbox_path, team_name, subscriber, |messages| {
                     messages.retain(|existing| {
@@ -1022,6 +1126,8 @@ impl WorkerAdapterPlugin {
             ..Default::default()
         });
 
+        self.notify_agent_started(&config.team, &config.agent, &pane_id, &runtime_session_id);
+
         // Poll for Idle state transition
         let timeout = Duration::from_secs(u64::from(config.timeout_secs));
         let poll_interval = Duration::from_millis(500);
@@ -1120,6 +1226,80 @@ impl WorkerAdapterPlugin {
 
         Ok(())
     }
+
+    /// Gracefully stop workers that have been idle past `max_idle_before_stop_secs`.
+    ///
+    /// The tail of the worker log is captured as a shutdown summary and
+    /// recorded on the `worker_auto_retired` event.
+    async fn retire_idle_workers(&mut self) {
+        let now = std::time::Instant::now();
+        {
+            let state = self.agent_state.lock().unwrap();
+            for member_name in self.workers.keys() {
+                let is_idle = matches!(state.get_state(member_name), Some(AgentState::Idle));
+                self.lifecycle.observe_idle(member_name, is_idle, now);
+            }
+        }
+
+        for (member_name, idle_for) in self.lifecycle.idle_workers_to_retire(now) {
+            let Some(backend) = self.backend.as_mut() else {
+                return;
+            };
+            let Some(handle) = self.workers.remove(&member_name) else {
+                continue;
+            };
+            let summary = lifecycle::read_log_tail(
+                &handle.log_file_path,
+                lifecycle::RETIRE_SUMMARY_MAX_BYTES,
+            );
+            info!(
+                "Auto-retiring worker {member_name} after {}s idle",
+                idle_for.as_secs()
+            );
+            let result = match lifecycle::graceful_shutdown(
+                &member_name,
+                backend.as_mut(),
+                &handle,
+                self.config.shutdown_timeout_secs,
+            )
+            .await
+            {
+                Ok(()) => "ok".to_string(),
+                Err(e) => {
+                    error!("Failed to auto-retire worker for {member_name}: {e}");
+                    "error".to_string()
+                }
+            };
+
+            self.lifecycle.unregister_worker(&member_name);
+            self.agent_state
+                .lock()
+                .unwrap()
+                .unregister_agent(&member_name);
+
+            let team_name = if self.config.team_name.is_empty() {
+                self.ctx
+                    .as_ref()
+                    .map(|c| c.system.default_team.clone())
+                    .unwrap_or_default()
+            } else {
+                self.config.team_name.clone()
+            };
+            emit_event_best_effort(EventFields {
+                level: "info",
+                source: "atm-daemon",
+                action: "worker_auto_retired",
+                team: Some(team_name),
+                agent_id: Some(member_name),
+                target: Some("worker_adapter".to_string()),
+                result: Some(result),
+                runtime: Some(Self::runtime_from_handle(&handle)),
+                message_text: summary,
+                count: Some(idle_for.as_secs()),
+                ..Default::default()
+            });
+        }
+    }
 }
 
 impl Default for WorkerAdapterPlugin {
@@ -1181,6 +1361,12 @@ impl Plugin for WorkerAdapterPlugin {
         // Initialize activity tracker with configured timeout
         self.activity_tracker = ActivityTracker::new(self.config.inactivity_timeout_ms);
 
+        // Bound response capture so a silent agent cannot block routing
+        self.log_tailer = LogTailer::with_config(CaptureConfig {
+            timeout_ms: self.config.capture_timeout_ms,
+            ..CaptureConfig::default()
+        });
+
         // Initialize lifecycle manager with config
         self.lifecycle = LifecycleManager::from_config(&self.config);
 
@@ -1237,6 +1423,9 @@ impl Plugin for WorkerAdapterPlugin {
 
         debug!("Worker Adapter plugin running with lifecycle management enabled");
 
+        let hook_watcher_cancel = CancellationToken::new();
+        let _hook_watcher_guard = hook_watcher_cancel.clone().drop_guard();
+
         // Start hook event watcher as a background task
         if let Some(ctx) = &self.ctx {
             let events_path = Self::hook_events_path(ctx);
@@ -1261,7 +1450,9 @@ impl Plugin for WorkerAdapterPlugin {
                 HookWatcher::new(events_path, Arc::clone(&self.agent_state))
                     .with_claude_root(claude_root)
             };
-            let watcher_cancel = cancel.clone();
+            // The watcher outlives `cancel` so Active -> Idle transitions are
+            // still observed while draining.
+            let watcher_cancel = hook_watcher_cancel.clone();
             tokio::spawn(async move {
                 watcher.run(watcher_cancel).await;
             });
@@ -1295,12 +1486,14 @@ impl Plugin for WorkerAdapterPlugin {
             tokio::select! {
                 _ = cancel.cancelled() => {
                     debug!("Worker Adapter plugin shutting down");
+                    self.drain_workers().await;
                     break;
                 }
                 _ = inactivity_timer.tick() => {
                     if let Err(e) = self.check_inactivity().await {
                         error!("Failed to check agent inactivity: {e}");
                     }
+                    self.retire_idle_workers().await;
                 }
                 _ = health_check_timer.tick() => {
                     if let Err(e) = self.health_check_all_workers().await {
@@ -1333,7 +1526,9 @@ impl Plugin for WorkerAdapterPlugin {
                         std::future::pending::<Option<LaunchRequest>>().await
                     }
                 } => {
+                    launch_queue::mark_launch_started(launch_req.launch_id);
                     let result = self.handle_launch(launch_req.config).await;
+                    launch_queue::mark_launch_finished(launch_req.launch_id, &result);
                     // Best-effort: ignore send error (CLI may have timed out)
                     let _ = launch_req.response_tx.send(result);
                 }
@@ -1420,6 +1615,13 @@ impl Plugin for WorkerAdapterPlugin {
         if !self.config.enabled {
             return Ok(());
         }
+        if self.lifecycle.is_draining() {
+            debug!(
+                "Draining for shutdown; not routing message from {}",
+                msg.from
+            );
+            return Ok(());
+        }
 
         let ctx = self.ctx.as_ref().ok_or_else(|| PluginError::Runtime {
             message: "Plugin context not initialized".to_string(),
@@ -1466,9 +1668,27 @@ impl Plugin for WorkerAdapterPlugin {
     }
 }
 
+/// Message id scheme from the daemon's `[messaging]` config.
+fn id_scheme(ctx: &PluginContext) -> MessageIdScheme {
+    ctx.config.messaging.id_scheme.unwrap_or_default()
+}
+
+/// Append `message` to `inbox_path` under an id allocated for that inbox.
+fn append_with_new_id(
+    ctx: &PluginContext,
+    inbox_path: &Path,
+    message: &InboxMessage,
+    team: &str,
+    agent: &str,
+) -> Result<(), InboxError> {
+    let mut message = message.clone();
+    message.message_id = Some(new_message_id(id_scheme(ctx), inbox_path)?);
+    inbox_append(inbox_path, &message, team, agent).map(|_| ())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::super::capture::CaptureConfig;
+    use super::super::capture::CAPTURE_TIMEOUT_MARKER;
     use super::super::config::AgentConfig;
     use super::super::mock_backend::{MockCall, MockTmuxBackend};
     use super::*;
@@ -1515,6 +1735,8 @@ mod tests {
             created_at: 1,
             lead_agent_id: format!("team-lead@{team}"),
             lead_session_id: String::new(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: members
                 .iter()
                 .map(|member| AgentMember {
@@ -1617,14 +1839,10 @@ mod tests {
             serde_json::Value::String("atm-dev".to_string()),
         );
         let message = InboxMessage {
-            from: "team-lead@src-dev".to_string(),
-            source_team: None,
-            text: "route me".to_string(),
-            timestamp: "2026-03-20T00:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some(Uuid::new_v4().to_string()),
             unknown_fields,
+            ..InboxMessage::new("team-lead@src-dev", "route me")
+                .timestamp("2026-03-20T00:00:00Z")
+                .message_id("msg-1")
         };
 
         plugin.notify_routing_issue(
@@ -1669,6 +1887,7 @@ mod tests {
                 command: None,
                 prompt_template: "{message}".to_string(),
                 concurrency_policy: "queue".to_string(),
+                max_idle_before_stop_secs: None,
             },
         );
         plugin.set_log_tailer(LogTailer::with_config(CaptureConfig {
@@ -1685,16 +1904,10 @@ mod tests {
                 .expect("mock response should write");
         });
 
-        let message = InboxMessage {
-            from: "team-lead".to_string(),
-            source_team: Some("src-dev".to_string()),
-            text: "hello".to_string(),
-            timestamp: "2026-03-20T00:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some(Uuid::new_v4().to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let message = InboxMessage::new("team-lead", "hello")
+            .source_team("src-dev")
+            .timestamp("2026-03-20T00:00:00Z")
+            .message_id("msg-1");
 
         plugin
             .process_message("architect", message)
@@ -1720,6 +1933,153 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_process_message_silent_agent_delivers_timeout_marker_and_marks_idle() {
+        let temp = TempDir::new().unwrap();
+        write_test_team(temp.path(), "atm-dev", &["team-lead", "arch-ctm"]);
+
+        let mut plugin = WorkerAdapterPlugin::new();
+        plugin.backend = Some(Box::new(MockTmuxBackend::new(temp.path().join("logs"))));
+        plugin.ctx = Some(make_test_context(temp.path()));
+        plugin.config.enabled = true;
+        plugin.config.team_name = "atm-dev".to_string();
+        plugin.config.agents.insert(
+            "architect".to_string(),
+            AgentConfig {
+                enabled: true,
+                member_name: "arch-ctm".to_string(),
+                command: None,
+                prompt_template: "{message}".to_string(),
+                concurrency_policy: "queue".to_string(),
+                max_idle_before_stop_secs: None,
+            },
+        );
+        plugin.set_log_tailer(LogTailer::with_config(CaptureConfig {
+            timeout_ms: 50,
+            poll_interval_ms: 10,
+            max_response_bytes: 4096,
+            idle_timeout_ms: 20,
+        }));
+
+        let message = InboxMessage::new("team-lead", "hello")
+            .timestamp("2026-03-20T00:00:00Z")
+            .message_id("msg-1");
+
+        plugin
+            .process_message("architect", message)
+            .await
+            .expect("silent agent should not fail routing");
+
+        let sender_inbox = temp
+            .path()
+            .join(".claude/teams/atm-dev/inboxes/team-lead.json");
+        let messages: Vec<InboxMessage> =
+            serde_json::from_str(&std::fs::read_to_string(&sender_inbox).unwrap()).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].text, CAPTURE_TIMEOUT_MARKER);
+        assert_eq!(
+            plugin.agent_state.lock().unwrap().get_state("arch-ctm"),
+            Some(AgentState::Idle)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_busy_worker_before_killing_pane() {
+        let temp = TempDir::new().unwrap();
+        write_test_team(temp.path(), "atm-dev", &["team-lead", "arch-ctm"]);
+
+        let backend = MockTmuxBackend::new(temp.path().join("logs"));
+        let mut plugin = WorkerAdapterPlugin::new();
+        plugin.backend = Some(Box::new(backend.clone()));
+        plugin.ctx = Some(make_test_context(temp.path()));
+        plugin.config.enabled = true;
+        plugin.config.team_name = "atm-dev".to_string();
+        plugin.config.agents.insert(
+            "architect".to_string(),
+            AgentConfig {
+                enabled: true,
+                member_name: "arch-ctm".to_string(),
+                command: None,
+                prompt_template: "{message}".to_string(),
+                concurrency_policy: "queue".to_string(),
+                max_idle_before_stop_secs: None,
+            },
+        );
+        plugin.spawn_worker("architect").await.unwrap();
+        let state = plugin.state_store();
+        state
+            .lock()
+            .unwrap()
+            .set_state("arch-ctm", AgentState::Active);
+
+        let cancel = CancellationToken::new();
+        let run_cancel = cancel.clone();
+        let task = tokio::spawn(async move {
+            plugin.run(run_cancel).await.unwrap();
+            plugin.shutdown().await.unwrap();
+            plugin
+        });
+
+        cancel.cancel();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(
+            !task.is_finished(),
+            "shutdown must wait for the busy worker"
+        );
+        assert!(state.lock().unwrap().is_draining());
+        assert!(
+            backend.is_spawned("arch-ctm"),
+            "pane is alive while draining"
+        );
+
+        // The worker finishes its turn; teardown proceeds well before the
+        // default drain timeout.
+        state
+            .lock()
+            .unwrap()
+            .set_state("arch-ctm", AgentState::Idle);
+        let plugin = tokio::time::timeout(Duration::from_secs(10), task)
+            .await
+            .expect("drain should end once the worker is idle")
+            .unwrap();
+        assert!(plugin.workers.is_empty());
+        assert!(plugin.lifecycle.get_state("arch-ctm").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_handle_message_ignored_while_draining() {
+        let temp = TempDir::new().unwrap();
+        write_test_team(temp.path(), "atm-dev", &["team-lead", "arch-ctm"]);
+
+        let backend = MockTmuxBackend::new(temp.path().join("logs"));
+        let mut plugin = WorkerAdapterPlugin::new();
+        plugin.backend = Some(Box::new(backend.clone()));
+        plugin.ctx = Some(make_test_context(temp.path()));
+        plugin.config.enabled = true;
+        plugin.config.team_name = "atm-dev".to_string();
+        plugin.config.agents.insert(
+            "architect".to_string(),
+            AgentConfig {
+                enabled: true,
+                member_name: "arch-ctm".to_string(),
+                command: None,
+                prompt_template: "{message}".to_string(),
+                concurrency_policy: "queue".to_string(),
+                max_idle_before_stop_secs: None,
+            },
+        );
+        plugin.lifecycle.begin_drain();
+
+        let mut unknown_fields = HashMap::new();
+        unknown_fields.insert("recipient".to_string(), serde_json::json!("arch-ctm"));
+        let message = InboxMessage {
+            unknown_fields,
+            ..InboxMessage::new("team-lead", "hello").timestamp("2026-03-20T00:00:00Z")
+        };
+        plugin.handle_message(&message).await.unwrap();
+        assert!(backend.get_calls().is_empty());
+    }
+
     #[tokio::test]
     async fn test_handle_launch_empty_agent_returns_error() {
         let mut plugin = make_plugin_without_backend();
@@ -1796,16 +2156,7 @@ mod tests {
     #[test]
     fn test_format_message_default_template() {
         let plugin = WorkerAdapterPlugin::new();
-        let msg = InboxMessage {
-            from: "sender".to_string(),
-            source_team: None,
-            text: "Hello, agent!".to_string(),
-            timestamp: "2026-02-14T00:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: None,
-            unknown_fields: HashMap::new(),
-        };
+        let msg = InboxMessage::new("sender", "Hello, agent!").timestamp("2026-02-14T00:00:00Z");
 
         let formatted = plugin.format_message(&msg, "test-agent");
         assert_eq!(formatted, "Hello, agent!");
@@ -1864,6 +2215,84 @@ mod tests {
         );
     }
 
+    fn launch_config_for(agent: &str, resume_session_id: &str) -> LaunchConfig {
+        LaunchConfig {
+            agent: agent.to_string(),
+            team: "atm-dev".to_string(),
+            command: "codex --yolo".to_string(),
+            prompt: None,
+            timeout_secs: 0,
+            env_vars: HashMap::new(),
+            runtime: Some("codex".to_string()),
+            resume_session_id: Some(resume_session_id.to_string()),
+        }
+    }
+
+    fn read_inbox(root: &std::path::Path, member: &str) -> Vec<InboxMessage> {
+        let path = root.join(format!(".claude/teams/atm-dev/inboxes/{member}.json"));
+        std::fs::read_to_string(path)
+            .map(|raw| serde_json::from_str(&raw).unwrap())
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_handle_launch_sends_agent_started_notice_once_per_session() {
+        let temp = TempDir::new().unwrap();
+        write_test_team(temp.path(), "atm-dev", &["team-lead", "arch-ctm"]);
+        let mut plugin = WorkerAdapterPlugin::new();
+        plugin.backend = Some(Box::new(MockTmuxBackend::new(temp.path().join("logs"))));
+        plugin.ctx = Some(make_test_context(temp.path()));
+
+        let first = plugin
+            .handle_launch(launch_config_for("arch-ctm", "sess-abc"))
+            .await
+            .expect("launch should succeed with mock backend");
+        // A relaunch resuming the same runtime session (e.g. after a daemon
+        // restart) must not repeat the notice.
+        plugin
+            .handle_launch(launch_config_for("arch-ctm", "sess-abc"))
+            .await
+            .unwrap();
+
+        let lead_inbox = read_inbox(temp.path(), "team-lead");
+        assert_eq!(lead_inbox.len(), 1, "notice should be idempotent");
+        let notice = &lead_inbox[0];
+        assert_eq!(notice.from, "worker-adapter");
+        assert_eq!(notice.summary.as_deref(), Some("agent_started: arch-ctm"));
+        assert!(notice.text.contains(&first.pane_id), "{}", notice.text);
+        assert!(notice.text.contains("cwd: ."), "{}", notice.text);
+
+        plugin
+            .handle_launch(launch_config_for("arch-ctm", "sess-def"))
+            .await
+            .unwrap();
+        assert_eq!(read_inbox(temp.path(), "team-lead").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_handle_launch_agent_started_notice_is_config_driven() {
+        let temp = TempDir::new().unwrap();
+        write_test_team(temp.path(), "atm-dev", &["team-lead", "arch-ctm", "ops"]);
+        let mut plugin = WorkerAdapterPlugin::new();
+        plugin.backend = Some(Box::new(MockTmuxBackend::new(temp.path().join("logs"))));
+        plugin.ctx = Some(make_test_context(temp.path()));
+
+        plugin.config.launch_notice_to = Some("ops".to_string());
+        plugin
+            .handle_launch(launch_config_for("arch-ctm", "sess-1"))
+            .await
+            .unwrap();
+        assert_eq!(read_inbox(temp.path(), "ops").len(), 1);
+        assert!(read_inbox(temp.path(), "team-lead").is_empty());
+
+        plugin.config.launch_notice = false;
+        plugin
+            .handle_launch(launch_config_for("arch-ctm", "sess-2"))
+            .await
+            .unwrap();
+        assert_eq!(read_inbox(temp.path(), "ops").len(), 1);
+    }
+
     #[tokio::test]
     async fn test_handle_launch_gemini_shapes_runtime_home_and_persists_metadata() {
         let temp = TempDir::new().unwrap();
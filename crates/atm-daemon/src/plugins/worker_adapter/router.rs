@@ -161,19 +161,9 @@ impl Default for MessageRouter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
 
     fn make_test_message(from: &str, text: &str) -> InboxMessage {
-        InboxMessage {
-            from: from.to_string(),
-            source_team: None,
-            text: text.to_string(),
-            timestamp: "2026-02-14T00:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: None,
-            unknown_fields: HashMap::new(),
-        }
+        InboxMessage::new(from, text).timestamp("2026-02-14T00:00:00Z")
     }
 
     #[test]
@@ -16,16 +16,7 @@ use tokio::fs;
 use tokio::sync::Mutex as TokioMutex;
 
 fn create_test_message(from: &str, text: &str) -> InboxMessage {
-    InboxMessage {
-        from: from.to_string(),
-        source_team: None,
-        text: text.to_string(),
-        timestamp: chrono::Utc::now().to_rfc3339(),
-        read: false,
-        summary: None,
-        message_id: None,
-        unknown_fields: HashMap::new(),
-    }
+    InboxMessage::new(from, text)
 }
 
 fn create_team_config(name: &str, hostname: &str) -> TeamConfig {
@@ -36,6 +27,8 @@ fn create_team_config(name: &str, hostname: &str) -> TeamConfig {
         created_at: 1000000,
         lead_agent_id: format!("team-lead@{name}"),
         lead_session_id: "test-session-id".to_string(),
+        groups: Default::default(),
+        display_names: Default::default(),
         members: vec![AgentMember {
             agent_id: format!("{agent_name}@{name}"),
             name: agent_name,
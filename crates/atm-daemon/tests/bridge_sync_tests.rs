@@ -15,14 +15,8 @@ use tokio::sync::Mutex as TokioMutex;
 /// Helper to create a test message
 fn create_test_message(from: &str, text: &str, message_id: Option<String>) -> InboxMessage {
     InboxMessage {
-        from: from.to_string(),
-        source_team: None,
-        text: text.to_string(),
-        timestamp: chrono::Utc::now().to_rfc3339(),
-        read: false,
-        summary: None,
         message_id,
-        unknown_fields: HashMap::new(),
+        ..InboxMessage::new(from, text)
     }
 }
 
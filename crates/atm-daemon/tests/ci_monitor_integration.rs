@@ -284,6 +284,8 @@ async fn test_ci_deduplication() {
         runtime_drift_min_samples: 3,
         runtime_history_limit: 50,
         alert_cooldown_secs: 300,
+        notify_cooldown_secs: 0,
+        max_notifications_per_hour: 0,
         provider_config: None,
         notify_target: Vec::new(),
         branch_matcher: None,
@@ -6,9 +6,9 @@ use agent_team_mail_core::daemon_client::{BuildProfile, RuntimeKind, RuntimeOwne
 use agent_team_mail_core::logging_event::LogEventV1;
 use agent_team_mail_daemon::daemon;
 use agent_team_mail_daemon::daemon::{
-    SessionRegistry, StatusWriter, new_dedup_store, new_launch_sender, new_log_event_queue,
-    new_pubsub_store, new_session_registry, new_state_store, new_stream_event_sender,
-    new_stream_state_store,
+    SessionRegistry, StatusWriter, new_daemon_event_sender, new_dedup_store, new_launch_sender,
+    new_log_event_queue, new_pubsub_store, new_session_registry, new_state_store,
+    new_stream_event_sender, new_stream_state_store,
 };
 use agent_team_mail_daemon::plugin::{
     Capability, MailService, Plugin, PluginContext, PluginError, PluginMetadata, PluginRegistry,
@@ -529,6 +529,7 @@ async fn test_daemon_starts_and_loads_mock_plugin() {
             dedup_store,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             new_log_event_queue(),
         )
         .await
@@ -599,6 +600,7 @@ async fn test_signal_triggers_graceful_shutdown() {
             dedup_store,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             new_log_event_queue(),
         )
         .await
@@ -660,6 +662,7 @@ async fn test_plugin_lifecycle_order() {
             dedup_store,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             new_log_event_queue(),
         )
         .await
@@ -716,6 +719,7 @@ async fn test_spool_drain_runs_on_interval() {
             dedup_store,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             new_log_event_queue(),
         )
         .await
@@ -794,6 +798,7 @@ async fn test_startup_reconcile_seeds_roster_without_interval_delay() {
             dedup_store,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             new_log_event_queue(),
         )
         .await
@@ -882,6 +887,7 @@ async fn test_config_watch_event_updates_and_removes_members() {
             dedup_store,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             new_log_event_queue(),
         )
         .await
@@ -995,6 +1001,7 @@ async fn test_graceful_shutdown_with_timeout() {
             dedup_store,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             new_log_event_queue(),
         )
         .await
@@ -1059,6 +1066,7 @@ async fn test_empty_registry_runs_successfully() {
             dedup_store,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             new_log_event_queue(),
         )
         .await
@@ -1108,6 +1116,7 @@ async fn test_multiple_plugins_run_concurrently() {
             dedup_store,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             new_log_event_queue(),
         )
         .await
@@ -1181,6 +1190,7 @@ async fn test_plugin_run_failure_isolated_from_sibling_plugins() {
             dedup_store,
             new_stream_state_store(),
             new_stream_event_sender(),
+            new_daemon_event_sender(),
             new_log_event_queue(),
         )
         .await
@@ -1278,6 +1288,76 @@ fn test_second_daemon_start_rejected_when_first_is_running() {
     drop(first);
 }
 
+#[cfg(unix)]
+#[test]
+#[serial]
+fn test_daemon_flag_detaches_and_parent_returns_immediately() {
+    let temp_dir = TempDir::new().unwrap();
+    let bin = env!("CARGO_BIN_EXE_atm-daemon");
+    let daemon_dir = temp_dir.path().join(".atm/daemon");
+
+    let mut cmd = std::process::Command::new(bin);
+    cmd.arg("--daemon")
+        .env("ATM_HOME", temp_dir.path())
+        .env("ATM_OTEL_ENABLED", "0")
+        .env_remove("ATM_DAEMON_BIN")
+        .stdin(Stdio::null());
+    let token = issue_isolated_test_launch_token(temp_dir.path(), "daemon_tests::detach");
+    attach_launch_token(&mut cmd, &token).expect("encode detach daemon token");
+
+    let started = Instant::now();
+    let output = cmd.output().expect("failed to run atm-daemon --daemon");
+    let parent_elapsed = started.elapsed();
+    assert!(
+        output.status.success(),
+        "launching process should exit 0, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        parent_elapsed < Duration::from_secs(5),
+        "launching process should return without waiting for startup: {parent_elapsed:?}"
+    );
+
+    let guard = daemon_process_guard::DaemonProcessGuard::adopt_from_pid_file(
+        &daemon_dir.join("atm-daemon.pid"),
+        Path::new(bin),
+        temp_dir.path(),
+        Duration::from_secs(5),
+    )
+    .expect("daemon should write a PID file naming the live detached daemon");
+
+    let socket_path = daemon_dir.join("atm-daemon.sock");
+    let deadline = Instant::now() + Duration::from_secs(15);
+    while std::os::unix::net::UnixStream::connect(&socket_path).is_err() {
+        assert!(
+            Instant::now() < deadline,
+            "detached daemon socket never became available at {}",
+            socket_path.display()
+        );
+        thread::sleep(Duration::from_millis(50));
+    }
+    assert!(daemon_dir.join(daemon::detach::DETACHED_STDIO_LOG).exists());
+
+    // A second detached launch loses the lock race: the launcher fails and the
+    // PID file keeps naming the running daemon.
+    let mut second = std::process::Command::new(bin);
+    second
+        .arg("--daemon")
+        .env("ATM_HOME", temp_dir.path())
+        .env("ATM_OTEL_ENABLED", "0")
+        .env_remove("ATM_DAEMON_BIN")
+        .stdin(Stdio::null());
+    let token = issue_isolated_test_launch_token(temp_dir.path(), "daemon_tests::detach2");
+    attach_launch_token(&mut second, &token).expect("encode second detach token");
+    let output = second
+        .output()
+        .expect("failed to run second atm-daemon --daemon");
+    assert!(!output.status.success(), "second detached launch must fail");
+    let pid_file = std::fs::read_to_string(daemon_dir.join("atm-daemon.pid")).unwrap();
+    assert_eq!(pid_file.trim(), guard.pid().to_string());
+    drop(guard);
+}
+
 #[test]
 #[serial]
 fn test_daemon_start_requires_launch_token() {
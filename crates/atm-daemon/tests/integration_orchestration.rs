@@ -11,8 +11,8 @@ use agent_team_mail_core::config::aliases::resolve_alias;
 use agent_team_mail_daemon::daemon::log_writer::new_log_event_queue;
 use agent_team_mail_daemon::daemon::session_registry::SessionRegistry;
 use agent_team_mail_daemon::daemon::socket::{
-    new_dedup_store, new_launch_sender, new_pubsub_store, new_state_store, new_stream_event_sender,
-    new_stream_state_store, start_socket_server,
+    new_daemon_event_sender, new_dedup_store, new_launch_sender, new_pubsub_store, new_state_store,
+    new_stream_event_sender, new_stream_state_store, start_socket_server,
 };
 use agent_team_mail_daemon::plugins::worker_adapter::{AgentState, AgentStateTracker, PubSub};
 use std::collections::HashMap;
@@ -175,6 +175,7 @@ async fn test_socket_query_agent_state() {
         new_dedup_store(&home_dir).unwrap(),
         new_stream_state_store(),
         new_stream_event_sender(),
+        new_daemon_event_sender(),
         new_log_event_queue(),
         &daemon_lock,
         cancel.clone(),
@@ -236,6 +237,7 @@ async fn test_socket_query_agent_not_found() {
         new_dedup_store(&home_dir).unwrap(),
         new_stream_state_store(),
         new_stream_event_sender(),
+        new_daemon_event_sender(),
         new_log_event_queue(),
         &daemon_lock,
         cancel.clone(),
@@ -299,6 +301,7 @@ async fn test_pubsub_subscription_roundtrip() {
         new_dedup_store(&home_dir).unwrap(),
         new_stream_state_store(),
         new_stream_event_sender(),
+        new_daemon_event_sender(),
         new_log_event_queue(),
         &daemon_lock,
         cancel.clone(),
@@ -468,6 +471,7 @@ async fn test_launch_gemini_runtime_metadata_roundtrip() {
         new_dedup_store(&home_dir).unwrap(),
         new_stream_state_store(),
         new_stream_event_sender(),
+        new_daemon_event_sender(),
         new_log_event_queue(),
         &daemon_lock,
         cancel.clone(),
@@ -11,7 +11,6 @@ use agent_team_mail_daemon::roster::RosterService;
 #[allow(dead_code)]
 mod env_guard;
 use serial_test::serial;
-use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
@@ -236,16 +235,7 @@ async fn test_handle_message_with_invalid_format() {
     plugin.init(&ctx).await.unwrap();
 
     // Test with message that doesn't have [issue:NUMBER] prefix
-    let msg = InboxMessage {
-        from: "test-user".to_string(),
-        source_team: None,
-        text: "This is not an issue reply".to_string(),
-        timestamp: chrono::Utc::now().to_rfc3339(),
-        read: false,
-        summary: None,
-        message_id: None,
-        unknown_fields: HashMap::new(),
-    };
+    let msg = InboxMessage::new("test-user", "This is not an issue reply");
 
     // Should handle gracefully (ignore non-issue messages)
     let result = plugin.handle_message(&msg).await;
@@ -255,16 +245,7 @@ async fn test_handle_message_with_invalid_format() {
     );
 
     // Test with invalid issue number
-    let msg2 = InboxMessage {
-        from: "test-user".to_string(),
-        source_team: None,
-        text: "[issue:abc] Invalid number".to_string(),
-        timestamp: chrono::Utc::now().to_rfc3339(),
-        read: false,
-        summary: None,
-        message_id: None,
-        unknown_fields: HashMap::new(),
-    };
+    let msg2 = InboxMessage::new("test-user", "[issue:abc] Invalid number");
 
     let result2 = plugin.handle_message(&msg2).await;
     assert!(
@@ -292,16 +273,7 @@ async fn test_handle_message_with_empty_body() {
     plugin.init(&ctx).await.unwrap();
 
     // Test with message that has [issue:NUMBER] but empty body
-    let msg = InboxMessage {
-        from: "test-user".to_string(),
-        source_team: None,
-        text: "[issue:42]".to_string(), // No reply body
-        timestamp: chrono::Utc::now().to_rfc3339(),
-        read: false,
-        summary: None,
-        message_id: None,
-        unknown_fields: HashMap::new(),
-    };
+    let msg = InboxMessage::new("test-user", "[issue:42]"); // No reply body
 
     // Should handle gracefully (skip posting empty comment)
     let result = plugin.handle_message(&msg).await;
@@ -13,7 +13,6 @@ use agent_team_mail_daemon::roster::RosterService;
 #[allow(dead_code)]
 mod env_guard;
 use serial_test::serial;
-use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
@@ -272,16 +271,10 @@ async fn test_inbox_reply_posts_comment() {
     plugin.init(&ctx).await.unwrap();
 
     // Send a message with issue reference (on its own line)
-    let msg = InboxMessage {
-        from: "test-user".to_string(),
-        source_team: None,
-        text: "[issue:42]\nThis is my reply\nWith multiple lines".to_string(),
-        timestamp: chrono::Utc::now().to_rfc3339(),
-        read: false,
-        summary: None,
-        message_id: None,
-        unknown_fields: HashMap::new(),
-    };
+    let msg = InboxMessage::new(
+        "test-user",
+        "[issue:42]\nThis is my reply\nWith multiple lines",
+    );
 
     // Handle the message
     plugin.handle_message(&msg).await.unwrap();
@@ -6,7 +6,6 @@ use agent_team_mail_daemon::plugin::{
     PluginState,
 };
 use agent_team_mail_daemon::roster::RosterService;
-use std::collections::HashMap;
 use std::sync::Arc;
 use tempfile::TempDir;
 use tokio_util::sync::CancellationToken;
@@ -127,16 +126,7 @@ fn create_test_context(teams_root: std::path::PathBuf) -> PluginContext {
 }
 
 fn create_test_message(from: &str, text: &str) -> InboxMessage {
-    InboxMessage {
-        from: from.to_string(),
-        source_team: None,
-        text: text.to_string(),
-        timestamp: chrono::Utc::now().to_rfc3339(),
-        read: false,
-        summary: None,
-        message_id: Some(uuid::Uuid::new_v4().to_string()),
-        unknown_fields: HashMap::new(),
-    }
+    InboxMessage::new(from, text).message_id(uuid::Uuid::new_v4().to_string())
 }
 
 async fn wait_until(
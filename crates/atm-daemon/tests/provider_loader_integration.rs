@@ -87,7 +87,9 @@ fn test_provider_loader_loads_stub_library() {
     );
 
     let mut loader = ProviderLoader::new();
-    let factories = loader.load_libraries(&[lib_path]);
+    let factories = loader
+        .load_libraries(&[lib_path])
+        .expect("stub provider should load");
     assert_eq!(factories.len(), 1);
 
     let factory = &factories[0];
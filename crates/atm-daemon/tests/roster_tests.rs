@@ -20,6 +20,8 @@ fn setup_team(temp_dir: &TempDir, team_name: &str) -> std::path::PathBuf {
         created_at: 1770765919076,
         lead_agent_id: format!("team-lead@{team_name}"),
         lead_session_id: "test-session-id".to_string(),
+        groups: Default::default(),
+        display_names: Default::default(),
         members: vec![create_lead_member(team_name)],
         unknown_fields: HashMap::new(),
     };
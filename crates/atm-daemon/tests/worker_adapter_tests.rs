@@ -423,6 +423,9 @@ async fn test_worker_adapter_config_defaults() {
     assert_eq!(config.max_restart_attempts, 3);
     assert_eq!(config.restart_backoff_secs, 5);
     assert_eq!(config.shutdown_timeout_secs, 10);
+    assert_eq!(config.drain_timeout_secs, 30);
+    assert!(config.launch_notice);
+    assert_eq!(config.launch_notice_to, None);
 }
 
 #[test]
@@ -729,14 +732,8 @@ async fn test_handle_message_routes_to_agent() {
     );
 
     let message = InboxMessage {
-        from: "sender".to_string(),
-        source_team: None,
-        text: "Hello, test agent!".to_string(),
-        timestamp: "2026-02-14T00:00:00Z".to_string(),
-        read: false,
-        summary: None,
-        message_id: None,
         unknown_fields,
+        ..InboxMessage::new("sender", "Hello, test agent!").timestamp("2026-02-14T00:00:00Z")
     };
 
     // Handle the message.  Without a real worker producing log output the
@@ -5,6 +5,7 @@
 
 use agent_team_mail_core::daemon_client::AgentSummary;
 use agent_team_mail_core::schema::InboxMessage;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use crate::config::TuiConfig;
@@ -16,7 +17,7 @@ pub struct MemberRow {
     pub agent: String,
     /// Current state string (e.g. `"idle"`, `"busy"`).
     pub state: String,
-    /// Number of messages currently in the agent's inbox file.
+    /// Number of unread messages in the agent's inbox file.
     pub inbox_count: usize,
 }
 
@@ -81,6 +82,8 @@ pub struct App {
     pub team: String,
     /// Member rows shown in the dashboard left panel.
     pub members: Vec<MemberRow>,
+    /// Sender display names from the team config (identity → name).
+    pub display_names: BTreeMap<String, String>,
     /// Recent inbox message previews for the selected agent.
     pub inbox_preview: Vec<String>,
     /// Recent inbox messages for the selected agent (newest first).
@@ -203,6 +206,7 @@ impl App {
         Self {
             team,
             members: Vec::new(),
+            display_names: BTreeMap::new(),
             inbox_preview: Vec::new(),
             inbox_messages: Vec::new(),
             selected_message_index: 0,
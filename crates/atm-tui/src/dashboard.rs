@@ -1,8 +1,9 @@
-//! Dashboard panel helpers: inbox count reads and session log path resolution.
+//! Dashboard panel helpers: team member and inbox reads and session log path resolution.
 //!
 //! This module does not own any rendering code — that lives in [`crate::ui`].
 //! It provides pure functions for computing the data shown in the left panel.
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use agent_team_mail_core::home::{get_home_dir, teams_root_dir_for};
@@ -11,32 +12,6 @@ use agent_team_mail_core::io::lock::acquire_lock;
 use agent_team_mail_core::schema::InboxMessage;
 use serde_json::Value;
 
-/// Read the number of messages in an agent's inbox file.
-///
-/// Returns `0` when the inbox does not exist, is empty, or cannot be parsed.
-/// Never panics or propagates errors — silently returns `0` on any failure.
-///
-/// # Arguments
-///
-/// * `home` - ATM home directory (resolved via [`get_home_dir`]).
-/// * `team` - Team name.
-/// * `agent` - Agent name.
-pub fn get_inbox_count(home: &Path, team: &str, agent: &str) -> usize {
-    let inbox_path = teams_root_dir_for(home)
-        .join(team)
-        .join("inboxes")
-        .join(format!("{agent}.json"));
-
-    if !inbox_path.exists() {
-        return 0;
-    }
-
-    match inbox_read_file_tolerant(&inbox_path) {
-        Ok(messages) => messages.len(),
-        Err(_) => 0,
-    }
-}
-
 /// Read team member names from `~/.claude/teams/{team}/config.json`.
 ///
 /// Returns an empty vector when the config is missing or malformed.
@@ -70,6 +45,25 @@ pub fn read_team_members(home: &Path, team: &str) -> Vec<String> {
         .unwrap_or_default()
 }
 
+/// Read the team's sender display-name map (`displayNames` in `config.json`).
+///
+/// Returns an empty map when the config is missing, locked, or has no
+/// mapping. Names are display-only; stored `from` values are untouched.
+pub fn read_display_names(home: &Path, team: &str) -> BTreeMap<String, String> {
+    let config_path = teams_root_dir_for(home).join(team).join("config.json");
+    let lock_path = config_path.with_extension("lock");
+    let _lock = match acquire_lock(&lock_path, 5) {
+        Ok(lock) => lock,
+        Err(_) => return BTreeMap::new(),
+    };
+    std::fs::read_to_string(config_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+        .and_then(|root| root.get("displayNames").cloned())
+        .and_then(|names| serde_json::from_value(names).ok())
+        .unwrap_or_default()
+}
+
 /// Read recent inbox message previews for an agent.
 ///
 /// Returns up to `max_items` lines formatted for compact dashboard display.
@@ -244,56 +238,36 @@ mod tests {
         });
     }
 
-    // ── test_inbox_count_empty ───────────────────────────────────────────────
-
-    #[test]
-    fn test_inbox_count_empty() {
-        with_tmp_home(|home| {
-            // Inbox file does not exist yet.
-            let count = get_inbox_count(home, "atm-dev", "arch-ctm");
-            assert_eq!(count, 0, "missing inbox should return 0");
-
-            // Create an empty inbox file.
-            let inbox_dir = home.join(".claude/teams/atm-dev/inboxes");
-            fs::create_dir_all(&inbox_dir).unwrap();
-            let inbox_path = inbox_dir.join("arch-ctm.json");
-            fs::write(&inbox_path, b"").unwrap();
-            let count = get_inbox_count(home, "atm-dev", "arch-ctm");
-            assert_eq!(count, 0, "empty inbox file should return 0");
-        });
-    }
-
-    // ── test_inbox_count_with_messages ──────────────────────────────────────
-
     #[test]
-    fn test_inbox_count_with_messages() {
+    fn test_read_team_members_from_config() {
         with_tmp_home(|home| {
-            let inbox_dir = home.join(".claude/teams/atm-dev/inboxes");
-            fs::create_dir_all(&inbox_dir).unwrap();
-            let inbox_path = inbox_dir.join("arch-ctm.json");
-
-            // Write a valid JSON array with 3 minimally valid messages.
-            let payload = r#"[{"message_id":"m1","from":"a","text":"one","timestamp":"2026-01-01T00:00:00Z"},{"message_id":"m2","from":"b","text":"two","timestamp":"2026-01-01T00:00:01Z"},{"message_id":"m3","from":"c","text":"three","timestamp":"2026-01-01T00:00:02Z"}]"#;
-            fs::write(&inbox_path, payload).unwrap();
+            let team_dir = home.join(".claude/teams/atm-dev");
+            fs::create_dir_all(&team_dir).unwrap();
+            fs::write(
+                team_dir.join("config.json"),
+                r#"{"members":[{"name":"team-lead"},{"name":"arch-ctm"}]}"#,
+            )
+            .unwrap();
 
-            let count = get_inbox_count(home, "atm-dev", "arch-ctm");
-            assert_eq!(count, 3, "expected 3 messages in inbox");
+            let members = read_team_members(home, "atm-dev");
+            assert_eq!(members, vec!["team-lead", "arch-ctm"]);
         });
     }
 
     #[test]
-    fn test_read_team_members_from_config() {
+    fn test_read_display_names_from_config() {
         with_tmp_home(|home| {
             let team_dir = home.join(".claude/teams/atm-dev");
             fs::create_dir_all(&team_dir).unwrap();
+            assert!(read_display_names(home, "atm-dev").is_empty());
+
             fs::write(
                 team_dir.join("config.json"),
-                r#"{"members":[{"name":"team-lead"},{"name":"arch-ctm"}]}"#,
+                r#"{"members":[],"displayNames":{"codex:abc":"Ada"}}"#,
             )
             .unwrap();
-
-            let members = read_team_members(home, "atm-dev");
-            assert_eq!(members, vec!["team-lead", "arch-ctm"]);
+            let names = read_display_names(home, "atm-dev");
+            assert_eq!(names.get("codex:abc").map(String::as_str), Some("Ada"));
         });
     }
 
@@ -390,26 +390,15 @@ mod tests {
         let mut app = app_with_members();
         app.selected_index = 0;
         app.inbox_messages = vec![
-            agent_team_mail_core::schema::InboxMessage {
-                from: "team-lead".to_string(),
-                source_team: None,
-                text: "review this change".to_string(),
-                timestamp: "2026-03-01T00:00:00Z".to_string(),
-                read: false,
-                summary: Some("review".to_string()),
-                message_id: Some("m-1".to_string()),
-                unknown_fields: std::collections::HashMap::new(),
-            },
-            agent_team_mail_core::schema::InboxMessage {
-                from: "arch-atm".to_string(),
-                source_team: None,
-                text: "follow-up".to_string(),
-                timestamp: "2026-03-01T00:01:00Z".to_string(),
-                read: true,
-                summary: Some("follow-up".to_string()),
-                message_id: Some("m-2".to_string()),
-                unknown_fields: std::collections::HashMap::new(),
-            },
+            agent_team_mail_core::schema::InboxMessage::new("team-lead", "review this change")
+                .timestamp("2026-03-01T00:00:00Z")
+                .summary("review")
+                .message_id("m-1"),
+            agent_team_mail_core::schema::InboxMessage::new("arch-atm", "follow-up")
+                .timestamp("2026-03-01T00:01:00Z")
+                .read(true)
+                .summary("follow-up")
+                .message_id("m-2"),
         ];
         app
     }
@@ -50,7 +50,8 @@ use agent_team_mail_core::{
     control::{CONTROL_SCHEMA_VERSION, ControlAck, ControlAction, ControlRequest, ControlResult},
     daemon_client::{AgentSummary, query_agent_stream_state, query_list_agents, send_control},
     event_log::{EventFields, emit_event_best_effort},
-    home::get_home_dir,
+    home::{get_home_dir, teams_root_dir_for},
+    io::team_unread_counts,
     logging,
 };
 
@@ -58,7 +59,7 @@ use agent_team_mail_tui::app::{App, MemberRow, PendingControl};
 use agent_team_mail_tui::codex_adapter::CodexAdapter;
 use agent_team_mail_tui::config::{TuiConfig, load_tui_config};
 use agent_team_mail_tui::dashboard::{
-    get_inbox_count, mark_inbox_message_read, read_inbox_messages, read_inbox_preview,
+    mark_inbox_message_read, read_display_names, read_inbox_messages, read_inbox_preview,
     read_team_members, session_log_path,
 };
 use agent_team_mail_tui::{events, ui};
@@ -222,6 +223,7 @@ async fn run_app<B: ratatui::backend::Backend>(
 
             app.agent_list = agent_list;
             app.members = members;
+            app.display_names = read_display_names(&home, &team);
 
             // Clamp selected_index within bounds after list refresh.
             if !app.members.is_empty() && app.selected_index >= app.members.len() {
@@ -457,7 +459,10 @@ fn refresh_agent_list() -> Vec<AgentSummary> {
     }
 }
 
-/// Build [`MemberRow`] entries from the agent list with current inbox counts.
+/// Build [`MemberRow`] entries from the agent list with current unread counts.
+///
+/// Counts come from a single scan of the team's inboxes directory rather than
+/// one inbox read per member.
 fn build_member_rows(
     daemon_agents: &[AgentSummary],
     configured_members: &[String],
@@ -476,10 +481,12 @@ fn build_member_rows(
             .or_insert_with(|| "unknown".to_string());
     }
 
+    let unread_counts = team_unread_counts(&teams_root_dir_for(home).join(team));
+
     states_by_agent
         .into_iter()
         .map(|(agent, state)| MemberRow {
-            inbox_count: get_inbox_count(home, team, &agent),
+            inbox_count: unread_counts.get(&agent).copied().unwrap_or(0),
             agent,
             state,
         })
@@ -492,9 +499,15 @@ fn build_member_rows(
 /// # Truncation detection
 ///
 /// When `file_len < pos` the file has been truncated (e.g., the daemon
-/// restarted and cleared its log). In that case the function returns
-/// `Ok((vec![], 0))` — a `new_pos` of `0` signals to the caller that the
-/// stream position should be reset to the beginning of the file.
+/// restarted and cleared its log, or rotated it). In that case the function
+/// returns `Ok((vec![], 0))` — a `new_pos` of `0` signals to the caller that
+/// the stream position should be reset to the beginning of the file.
+///
+/// This is the contract the daemon's worker log rotation relies on: rotation
+/// copies the log aside and truncates the live file in place, so the path
+/// stays the same and the reader sees it shrink. If the live file grows past
+/// the reader's old position again before the next read, the truncation goes
+/// undetected.
 ///
 /// # No-op conditions
 ///
@@ -1123,6 +1136,44 @@ mod tests {
         );
     }
 
+    /// Reader side of the daemon's copy-truncate worker log rotation
+    /// (`rotate_log_if_needed`): an append-mode writer keeps its descriptor,
+    /// the live file is copied aside and truncated in place, and the reader
+    /// resets and picks up post-rotation output from offset 0.
+    #[tokio::test]
+    async fn test_tail_log_file_follows_copy_truncate_rotation() {
+        use std::io::Write;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("worker.log");
+        let mut writer = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .unwrap();
+
+        writer.write_all(b"before rotation\n").unwrap();
+        let (lines, pos) = tail_log_file(&path, 0).await.unwrap();
+        assert_eq!(lines, vec!["before rotation"]);
+
+        // Rotate exactly as the daemon does: copy aside, truncate in place.
+        std::fs::copy(&path, dir.path().join("worker.log.1")).unwrap();
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(0)
+            .unwrap();
+        writer.write_all(b"after\n").unwrap();
+
+        let (lines, reset_pos) = tail_log_file(&path, pos).await.unwrap();
+        assert!(lines.is_empty());
+        assert_eq!(reset_pos, 0, "rotation must surface as a truncation reset");
+
+        let (lines, _) = tail_log_file(&path, reset_pos).await.unwrap();
+        assert_eq!(lines, vec!["after"]);
+    }
+
     // ── tail_log_events tests ─────────────────────────────────────────────────
 
     fn make_jsonl_event(agent: Option<&str>, level: &str, action: &str) -> String {
@@ -18,6 +18,7 @@
 //! └──────────────────────────────────────────────────────────────────┘
 //! ```
 
+use agent_team_mail_core::schema::display_name_for;
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -180,7 +181,10 @@ fn draw_dashboard(frame: &mut Frame, area: Rect, app: &App) {
             let status = if msg.read { "read" } else { "unread" };
             let detail = vec![
                 Line::from(Span::styled(
-                    format!("From: {}  [{status}]", msg.from),
+                    format!(
+                        "From: {}  [{status}]",
+                        display_name_for(&app.display_names, &app.team, &msg.from)
+                    ),
                     Style::default().add_modifier(Modifier::BOLD),
                 )),
                 Line::from(Span::styled(
@@ -206,7 +210,8 @@ fn draw_dashboard(frame: &mut Frame, area: Rect, app: &App) {
                 let summary = m.summary.as_deref().unwrap_or(m.text.as_str());
                 ListItem::new(Line::from(Span::raw(format!(
                     "{marker} {}: {}",
-                    m.from, summary
+                    display_name_for(&app.display_names, &app.team, &m.from),
+                    summary
                 ))))
             })
             .collect();
@@ -772,7 +777,6 @@ mod tests {
     use crate::config::TuiConfig;
     use agent_team_mail_core::schema::InboxMessage;
     use ratatui::{Terminal, backend::TestBackend};
-    use std::collections::HashMap;
 
     fn render_text(app: &App) -> String {
         let backend = TestBackend::new(100, 30);
@@ -829,26 +833,15 @@ mod tests {
         }];
         app.selected_index = 0;
         app.inbox_messages = vec![
-            InboxMessage {
-                from: "team-lead".to_string(),
-                source_team: None,
-                text: "Please investigate the CI failure.".to_string(),
-                timestamp: "2026-03-02T00:00:00Z".to_string(),
-                read: false,
-                summary: Some("CI failure investigation".to_string()),
-                message_id: Some("msg-1".to_string()),
-                unknown_fields: HashMap::new(),
-            },
-            InboxMessage {
-                from: "quality-mgr".to_string(),
-                source_team: None,
-                text: "Smoke tests passed.".to_string(),
-                timestamp: "2026-03-02T00:01:00Z".to_string(),
-                read: true,
-                summary: Some("Smoke tests passed".to_string()),
-                message_id: Some("msg-2".to_string()),
-                unknown_fields: HashMap::new(),
-            },
+            InboxMessage::new("team-lead", "Please investigate the CI failure.")
+                .timestamp("2026-03-02T00:00:00Z")
+                .summary("CI failure investigation")
+                .message_id("msg-1"),
+            InboxMessage::new("quality-mgr", "Smoke tests passed.")
+                .timestamp("2026-03-02T00:01:00Z")
+                .read(true)
+                .summary("Smoke tests passed")
+                .message_id("msg-2"),
         ];
 
         // Step 1: list view shows unread marker.
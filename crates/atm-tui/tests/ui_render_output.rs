@@ -3,7 +3,6 @@ use agent_team_mail_tui::app::{App, MemberRow};
 use agent_team_mail_tui::config::TuiConfig;
 use agent_team_mail_tui::ui::draw;
 use ratatui::{Terminal, backend::TestBackend};
-use std::collections::HashMap;
 
 fn render_text(app: &App) -> String {
     let backend = TestBackend::new(100, 30);
@@ -30,26 +29,18 @@ fn sample_app() -> App {
     app.selected_index = 0;
     app.streaming_agent = Some("arch-ctm".to_string());
     app.inbox_messages = vec![
-        InboxMessage {
-            from: "team-lead".to_string(),
-            source_team: None,
-            text: "Please investigate CI failure and report findings.".to_string(),
-            timestamp: "2026-03-02T00:00:00Z".to_string(),
-            read: false,
-            summary: Some("CI failure investigation".to_string()),
-            message_id: Some("msg-1".to_string()),
-            unknown_fields: HashMap::new(),
-        },
-        InboxMessage {
-            from: "quality-mgr".to_string(),
-            source_team: None,
-            text: "Smoke tests passed.".to_string(),
-            timestamp: "2026-03-02T00:01:00Z".to_string(),
-            read: true,
-            summary: Some("Smoke tests passed".to_string()),
-            message_id: Some("msg-2".to_string()),
-            unknown_fields: HashMap::new(),
-        },
+        InboxMessage::new(
+            "team-lead",
+            "Please investigate CI failure and report findings.",
+        )
+        .timestamp("2026-03-02T00:00:00Z")
+        .summary("CI failure investigation")
+        .message_id("msg-1"),
+        InboxMessage::new("quality-mgr", "Smoke tests passed.")
+            .timestamp("2026-03-02T00:01:00Z")
+            .read(true)
+            .summary("Smoke tests passed")
+            .message_id("msg-2"),
     ];
     app
 }
@@ -91,3 +82,20 @@ fn panel_consistency_uses_same_snapshot() {
     assert!(rendered.contains("busy"));
     assert!(rendered.contains("[LIVE]"));
 }
+
+#[test]
+fn inbox_renders_display_names_without_touching_stored_from() {
+    let mut app = sample_app();
+    app.display_names
+        .insert("team-lead".to_string(), "Lead Human".to_string());
+
+    let list_render = render_text(&app);
+    assert!(list_render.contains("Lead Human: "));
+    assert!(list_render.contains("quality-mgr"));
+
+    app.inbox_detail_open = true;
+    app.selected_message_index = 0;
+    let detail_render = render_text(&app);
+    assert!(detail_render.contains("From: Lead Human  [unread]"));
+    assert_eq!(app.inbox_messages[0].from, "team-lead");
+}
@@ -2,7 +2,9 @@ use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
 use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
 use agent_team_mail_core::io::atomic::atomic_swap;
 use agent_team_mail_core::io::error::InboxError;
+use agent_team_mail_core::io::inbox::write_inbox_file;
 use agent_team_mail_core::io::lock::acquire_lock;
+use agent_team_mail_core::io::new_message_id;
 use agent_team_mail_core::schema::{InboxMessage, TeamConfig};
 use agent_team_mail_core::text::{DEFAULT_MAX_MESSAGE_BYTES, validate_message_text};
 use anyhow::{Context, Result};
@@ -10,9 +12,7 @@ use chrono::Utc;
 use clap::Args;
 use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::io::Write;
 use std::path::Path;
-use uuid::Uuid;
 
 use crate::commands::send::generate_summary;
 use crate::util::addressing::parse_address;
@@ -142,6 +142,10 @@ pub fn execute(args: AckArgs) -> Result<()> {
         team_name.clone(),
         args.reply.clone(),
         args.message_id.clone(),
+        new_message_id(
+            config.messaging.id_scheme.unwrap_or_default(),
+            &target_inbox_path,
+        )?,
     );
     apply_ack_transaction(
         &mut inbox_states,
@@ -205,6 +209,7 @@ fn build_reply_message(
     source_team: String,
     text: String,
     acked_message_id: String,
+    message_id: String,
 ) -> InboxMessage {
     let mut unknown_fields = HashMap::new();
     unknown_fields.insert(
@@ -213,14 +218,11 @@ fn build_reply_message(
     );
 
     InboxMessage {
-        from,
-        source_team: Some(source_team),
-        text: text.clone(),
-        timestamp: Utc::now().to_rfc3339(),
-        read: false,
-        summary: Some(generate_summary(&text)),
-        message_id: Some(Uuid::new_v4().to_string()),
         unknown_fields,
+        ..InboxMessage::new(from, text.clone())
+            .source_team(source_team)
+            .summary(generate_summary(&text))
+            .message_id(message_id)
     }
 }
 
@@ -413,15 +415,7 @@ fn restore_inbox(path: &Path, path_exists: bool, original_bytes: &[u8]) -> Resul
 }
 
 fn write_synced_file(path: &Path, content: &[u8]) -> Result<()> {
-    let mut file = fs::File::create(path).map_err(|source| InboxError::Io {
-        path: path.to_path_buf(),
-        source,
-    })?;
-    file.write_all(content).map_err(|source| InboxError::Io {
-        path: path.to_path_buf(),
-        source,
-    })?;
-    file.sync_all().map_err(|source| InboxError::Io {
+    write_inbox_file(path, content).map_err(|source| InboxError::Io {
         path: path.to_path_buf(),
         source,
     })?;
@@ -431,19 +425,14 @@ fn write_synced_file(path: &Path, content: &[u8]) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
     use tempfile::TempDir;
 
     fn message(from: &str, source_team: Option<&str>) -> InboxMessage {
         InboxMessage {
-            from: from.to_string(),
             source_team: source_team.map(str::to_string),
-            text: "task".to_string(),
-            timestamp: "2026-03-20T00:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("msg-1".to_string()),
-            unknown_fields: HashMap::new(),
+            ..InboxMessage::new(from, "task")
+                .timestamp("2026-03-20T00:00:00Z")
+                .message_id("msg-1")
         }
     }
 
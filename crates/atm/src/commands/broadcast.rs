@@ -1,26 +1,30 @@
 //! Broadcast command implementation
 
-use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
+use agent_team_mail_core::config::{ConfigOverrides, MessageIdScheme, resolve_config};
+use agent_team_mail_core::daemon_client::{
+    CanonicalMemberState, canonical_liveness_bool, query_team_member_states,
+};
 use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
 use agent_team_mail_core::io::inbox::{WriteOutcome, inbox_append};
+use agent_team_mail_core::io::new_message_id;
 use agent_team_mail_core::schema::{InboxMessage, TeamConfig};
 use anyhow::Result;
-use chrono::Utc;
 use clap::Args;
-use std::collections::HashMap;
-use uuid::Uuid;
+use std::path::PathBuf;
 
 use agent_team_mail_core::text::{
     DEFAULT_MAX_MESSAGE_BYTES, truncate_chars_slice, validate_message_text,
 };
 
 use crate::consts::MESSAGE_MAX_LEN;
+use crate::util::file_policy::{embed_file_body, read_file_body};
 use crate::util::settings::{get_home_dir, teams_root_dir_for};
 
 /// Broadcast a message to all agents in a team
 #[derive(Args, Debug)]
 pub struct BroadcastArgs {
-    /// Message text (or omit to use --stdin)
+    /// Message text (or omit to use --stdin or --file)
+    #[arg(conflicts_with = "file")]
     message: Option<String>,
 
     /// Override default team
@@ -28,13 +32,44 @@ pub struct BroadcastArgs {
     team: Option<String>,
 
     /// Read message from stdin
-    #[arg(long)]
+    #[arg(long, conflicts_with = "file")]
     stdin: bool,
 
+    /// Send the contents of this file as the message (truncated to the
+    /// message size limit with a warning)
+    #[arg(long, value_name = "PATH")]
+    file: Option<PathBuf>,
+
+    /// Only broadcast to the members of this team group
+    #[arg(long, value_name = "NAME")]
+    group: Option<String>,
+
+    /// Skip this member (repeatable)
+    #[arg(long, value_name = "NAME")]
+    exclude: Vec<String>,
+
+    /// Also deliver to the sender's own inbox
+    #[arg(long)]
+    include_self: bool,
+
+    /// Only deliver to members the daemon reports as active or idle (all
+    /// members when the daemon is unreachable)
+    #[arg(long, conflicts_with = "all_members")]
+    active_only: bool,
+
+    /// Deliver to every member, overriding `[messaging] broadcast_active_only`
+    #[arg(long)]
+    all_members: bool,
+
     /// Explicit summary (otherwise auto-generated)
     #[arg(long)]
     summary: Option<String>,
 
+    /// Delivery priority 0-255; higher is read first by priority-aware
+    /// readers (default: normal, 0)
+    #[arg(long, value_name = "0-255")]
+    priority: Option<u8>,
+
     /// Output as JSON
     #[arg(long)]
     json: bool,
@@ -52,6 +87,7 @@ pub struct BroadcastArgs {
 #[derive(Debug)]
 struct DeliveryStatus {
     agent_name: String,
+    message_id: Option<String>,
     outcome: Result<WriteOutcome>,
 }
 
@@ -92,7 +128,16 @@ pub fn execute(args: BroadcastArgs) -> Result<()> {
         serde_json::from_str(&std::fs::read_to_string(&team_config_path)?)?;
 
     // Get message text from appropriate source
-    let message_text = get_message_text(&args)?;
+    let message_text = match args.file {
+        Some(ref path) => embed_file_body(
+            path,
+            "",
+            &read_file_body(path)?,
+            0,
+            DEFAULT_MAX_MESSAGE_BYTES,
+        ),
+        None => get_message_text(&args)?,
+    };
 
     validate_message_text(&message_text, DEFAULT_MAX_MESSAGE_BYTES)
         .map_err(|e| anyhow::anyhow!("{e}"))?;
@@ -102,29 +147,84 @@ pub fn execute(args: BroadcastArgs) -> Result<()> {
         .summary
         .unwrap_or_else(|| generate_summary(&message_text));
 
+    // Counter ids are allocated per recipient inbox at delivery time; other
+    // schemes share one id across the whole broadcast.
+    let id_scheme = config.messaging.id_scheme.unwrap_or_default();
+    let shared_message_id = match id_scheme {
+        MessageIdScheme::Counter => None,
+        scheme => Some(new_message_id(scheme, &team_dir)?),
+    };
+
     // Create inbox message
+    // local system message — source_team intentionally None
     let inbox_message = InboxMessage {
-        from: config.core.identity.clone(),
-        // local system message — source_team intentionally None
-        source_team: None,
-        text: message_text.clone(),
-        timestamp: Utc::now().to_rfc3339(),
-        read: false,
-        summary: Some(summary.clone()),
-        message_id: Some(Uuid::new_v4().to_string()),
-        unknown_fields: HashMap::new(),
+        message_id: shared_message_id,
+        priority: args.priority,
+        ..InboxMessage::new(config.core.identity.clone(), message_text.clone())
+            .summary(summary.clone())
     };
 
-    // Collect target agents (all members except self)
-    let target_agents: Vec<String> = team_config
-        .members
-        .iter()
-        .filter(|m| m.name != config.core.identity)
-        .map(|m| m.name.clone())
-        .collect();
+    // Collect target agents (all members, or the group's members), then drop
+    // --exclude names and the sender unless --include-self
+    let candidates: Vec<String> = match args.group.as_deref() {
+        Some(group) => team_config.expand_group(group)?.to_vec(),
+        None => team_config.members.iter().map(|m| m.name.clone()).collect(),
+    };
+    for name in &args.exclude {
+        if !team_config.members.iter().any(|m| &m.name == name) {
+            eprintln!("Warning: --exclude '{name}' is not a member of team '{team_name}'");
+        }
+    }
+    let mut target_agents = filter_targets(
+        candidates,
+        &config.core.identity,
+        args.include_self,
+        &args.exclude,
+    );
+    let active_only = !args.all_members
+        && (args.active_only || config.messaging.broadcast_active_only == Some(true));
+    let mut skipped_inactive = Vec::new();
+    if active_only && !target_agents.is_empty() {
+        match query_team_member_states(team_name) {
+            Ok(Some(states)) => {
+                (target_agents, skipped_inactive) =
+                    filter_active(target_agents, &states, &config.core.identity);
+                if !skipped_inactive.is_empty() {
+                    eprintln!(
+                        "Note: skipping inactive members: {}",
+                        skipped_inactive.join(", ")
+                    );
+                }
+            }
+            Ok(None) => {
+                eprintln!("Warning: daemon unreachable; broadcasting to all members");
+            }
+            Err(e) => {
+                eprintln!("Warning: {e}; broadcasting to all members");
+            }
+        }
+    }
+    if let Some(group) = args.group.as_deref() {
+        eprintln!(
+            "Note: group '{group}' expanded to {}",
+            target_agents.join(", ")
+        );
+    }
 
     if target_agents.is_empty() {
-        anyhow::bail!("No agents to broadcast to (team has no other members besides self)");
+        if !skipped_inactive.is_empty() {
+            anyhow::bail!(
+                "No recipients: no member is active or idle (inactive: {}); use --all-members to deliver anyway",
+                skipped_inactive.join(", ")
+            );
+        }
+        if args.exclude.is_empty() {
+            anyhow::bail!("No recipients: team has no other members besides self");
+        }
+        anyhow::bail!(
+            "No recipients: every member was excluded ({})",
+            args.exclude.join(", ")
+        );
     }
 
     // Dry run output
@@ -134,6 +234,7 @@ pub fn execute(args: BroadcastArgs) -> Result<()> {
                 "action": "broadcast",
                 "team": team_name,
                 "targets": target_agents,
+                "skipped_inactive": skipped_inactive,
                 "message": inbox_message,
                 "dry_run": true
             });
@@ -160,11 +261,21 @@ pub fn execute(args: BroadcastArgs) -> Result<()> {
 
     for agent_name in &target_agents {
         let inbox_path = inboxes_dir.join(format!("{agent_name}.json"));
-        let outcome = inbox_append(&inbox_path, &inbox_message, team_name, agent_name)
-            .map_err(|e| anyhow::anyhow!(e));
+        let mut message = inbox_message.clone();
+        let outcome = match id_scheme {
+            MessageIdScheme::Counter => new_message_id(id_scheme, &inbox_path)
+                .map(|id| message.message_id = Some(id))
+                .map_err(|e| anyhow::anyhow!(e)),
+            _ => Ok(()),
+        }
+        .and_then(|()| {
+            inbox_append(&inbox_path, &message, team_name, agent_name)
+                .map_err(|e| anyhow::anyhow!(e))
+        });
 
         delivery_statuses.push(DeliveryStatus {
             agent_name: agent_name.clone(),
+            message_id: message.message_id,
             outcome,
         });
     }
@@ -203,6 +314,33 @@ pub fn execute(args: BroadcastArgs) -> Result<()> {
     Ok(())
 }
 
+/// Drop excluded names, and the sender unless `include_self`, keeping order.
+fn filter_targets(
+    candidates: Vec<String>,
+    sender: &str,
+    include_self: bool,
+    exclude: &[String],
+) -> Vec<String> {
+    candidates
+        .into_iter()
+        .filter(|name| include_self || name != sender)
+        .filter(|name| !exclude.contains(name))
+        .collect()
+}
+
+/// Split targets into members the daemon reports as live (active or idle)
+/// and the rest, keeping order. The sender is always kept.
+fn filter_active(
+    targets: Vec<String>,
+    states: &[CanonicalMemberState],
+    sender: &str,
+) -> (Vec<String>, Vec<String>) {
+    targets.into_iter().partition(|name| {
+        name == sender
+            || canonical_liveness_bool(states.iter().find(|s| &s.agent == name)) == Some(true)
+    })
+}
+
 /// Get message text from args or stdin
 fn get_message_text(args: &BroadcastArgs) -> Result<String> {
     if args.stdin {
@@ -215,7 +353,7 @@ fn get_message_text(args: &BroadcastArgs) -> Result<String> {
         // Direct message argument
         Ok(message.clone())
     } else {
-        anyhow::bail!("Message required: provide message text or --stdin");
+        anyhow::bail!("Message required: provide message text, --stdin, or --file");
     }
 }
 
@@ -244,6 +382,14 @@ fn output_json_results(
     let mut failures = Vec::new();
     let mut queued = Vec::new();
     let mut conflicts = Vec::new();
+    let message_ids: serde_json::Map<String, serde_json::Value> = statuses
+        .iter()
+        .filter_map(|s| {
+            s.message_id
+                .as_ref()
+                .map(|id| (s.agent_name.clone(), serde_json::Value::from(id.clone())))
+        })
+        .collect();
 
     for status in statuses {
         match &status.outcome {
@@ -275,8 +421,10 @@ fn output_json_results(
         "action": "broadcast",
         "team": team_name,
         "message_id": inbox_message.message_id,
+        "message_ids": message_ids,
         "summary": {
             "total": statuses.len(),
+            "written": successes.len() + conflicts.len(),
             "succeeded": successes.len(),
             "queued": queued.len(),
             "conflicts": conflicts.len(),
@@ -327,7 +475,9 @@ fn output_human_results(statuses: &[DeliveryStatus], team_name: &str) -> Result<
         println!("Broadcast sent to {success_count} agents in team '{team_name}'");
     } else {
         println!(
-            "Broadcast completed for team '{team_name}': {success_count} succeeded, {conflict_count} conflicts resolved, {queued_count} queued, {failed_count} failed"
+            "Broadcast written to {} of {} agents in team '{team_name}': {success_count} succeeded, {conflict_count} conflicts resolved, {queued_count} queued, {failed_count} failed",
+            success_count + conflict_count,
+            statuses.len()
         );
     }
 
@@ -352,6 +502,46 @@ mod tests {
         assert!(summary.ends_with("..."));
     }
 
+    #[test]
+    fn test_filter_targets_excludes_sender_and_named_members() {
+        let names = || vec!["human".to_string(), "a".to_string(), "b".to_string()];
+        assert_eq!(filter_targets(names(), "human", false, &[]), vec!["a", "b"]);
+        assert_eq!(
+            filter_targets(names(), "human", true, &["a".to_string()]),
+            vec!["human", "b"]
+        );
+        assert!(
+            filter_targets(names(), "human", false, &["a".to_string(), "b".to_string()]).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_filter_active_keeps_live_members_and_sender() {
+        let state = |agent: &str, state: &str| CanonicalMemberState {
+            agent: agent.to_string(),
+            state: state.to_string(),
+            activity: String::new(),
+            session_id: None,
+            process_id: None,
+            last_alive_at: None,
+            reason: String::new(),
+            source: String::new(),
+            in_config: true,
+        };
+        let states = vec![
+            state("a", "active"),
+            state("b", "idle"),
+            state("c", "offline"),
+            state("d", "unknown"),
+        ];
+        let targets = ["human", "a", "b", "c", "d", "e"]
+            .map(String::from)
+            .to_vec();
+        let (live, skipped) = filter_active(targets, &states, "human");
+        assert_eq!(live, vec!["human", "a", "b"]);
+        assert_eq!(skipped, vec!["c", "d", "e"]);
+    }
+
     #[test]
     fn test_generate_summary_whitespace() {
         let text = "   Broadcast message with whitespace   ";
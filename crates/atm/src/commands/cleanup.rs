@@ -1,13 +1,13 @@
 //! Cleanup command implementation - apply retention policies to inboxes
 
-use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
+use agent_team_mail_core::config::{ConfigOverrides, MessageIdScheme, resolve_config};
 use agent_team_mail_core::io::inbox::inbox_append;
-use agent_team_mail_core::retention::apply_retention;
+use agent_team_mail_core::io::new_message_id;
+use agent_team_mail_core::retention::{RetentionResult, apply_retention};
 use agent_team_mail_core::schema::{InboxMessage, TeamConfig};
 use anyhow::{Context, Result};
 use chrono::Utc;
 use clap::Args;
-use std::collections::HashMap;
 use std::path::Path;
 use std::time::{Duration, Instant};
 use uuid::Uuid;
@@ -78,6 +78,7 @@ pub fn execute(args: CleanupArgs) -> Result<()> {
             args.force,
             args.kill,
             args.timeout.max(1),
+            config.messaging.id_scheme.unwrap_or_default(),
         );
     }
 
@@ -88,9 +89,13 @@ pub fn execute(args: CleanupArgs) -> Result<()> {
     }
 
     // Check if retention policy is configured
-    if config.retention.max_age.is_none() && config.retention.max_count.is_none() {
+    if config.retention.max_age.is_none()
+        && config.retention.max_count.is_none()
+        && config.retention.per_type.is_empty()
+    {
         println!(
-            "No retention policy configured. Set retention.max_age and/or retention.max_count in .atm.toml"
+            "No retention policy configured. Set retention.max_age, retention.max_count, \
+             and/or [retention.per_type.<type>] in .atm.toml"
         );
         return Ok(());
     }
@@ -135,6 +140,7 @@ fn execute_agent_cleanup(
     force: bool,
     kill_mode: bool,
     timeout_secs: u64,
+    id_scheme: MessageIdScheme,
 ) -> Result<()> {
     if agent_name == "team-lead" {
         anyhow::bail!("team-lead is protected and cannot be removed by cleanup");
@@ -164,7 +170,7 @@ fn execute_agent_cleanup(
                         agent_name
                     );
                 }
-                send_shutdown_request(home_dir, team_name, agent_name)?;
+                send_shutdown_request(home_dir, team_name, agent_name, id_scheme)?;
 
                 if !wait_for_session_dead(team_name, agent_name, timeout_secs) {
                     #[cfg(unix)]
@@ -269,7 +275,12 @@ fn ensure_daemon_running() -> Result<()> {
     }
 }
 
-fn send_shutdown_request(home_dir: &Path, team_name: &str, agent_name: &str) -> Result<()> {
+fn send_shutdown_request(
+    home_dir: &Path,
+    team_name: &str,
+    agent_name: &str,
+    id_scheme: MessageIdScheme,
+) -> Result<()> {
     let request_id = Uuid::new_v4().to_string();
     let shutdown_payload = serde_json::json!({
         "type": "shutdown_request",
@@ -279,21 +290,13 @@ fn send_shutdown_request(home_dir: &Path, team_name: &str, agent_name: &str) ->
         "timestamp": Utc::now().to_rfc3339(),
     });
 
-    let msg = InboxMessage {
-        from: "atm".to_string(),
-        source_team: None,
-        text: shutdown_payload.to_string(),
-        timestamp: Utc::now().to_rfc3339(),
-        read: false,
-        summary: Some("shutdown_request".to_string()),
-        message_id: Some(Uuid::new_v4().to_string()),
-        unknown_fields: HashMap::new(),
-    };
-
     let inbox_path = teams_root_dir_for(home_dir)
         .join(team_name)
         .join("inboxes")
         .join(format!("{agent_name}.json"));
+    let msg = InboxMessage::new("atm", shutdown_payload.to_string())
+        .summary("shutdown_request")
+        .message_id(new_message_id(id_scheme, &inbox_path)?);
     inbox_append(&inbox_path, &msg, team_name, "atm")?;
     Ok(())
 }
@@ -405,10 +408,7 @@ fn cleanup_team(
 
         // Only show agents where something happened
         if result.removed > 0 || result.kept > 0 {
-            println!(
-                "  {:<20} {:>8} {:>8} {:>10}",
-                member.name, result.kept, result.removed, result.archived
-            );
+            print_retention_row(&member.name, &result, dry_run);
 
             total_kept += result.kept;
             total_removed += result.removed;
@@ -458,10 +458,7 @@ fn cleanup_team(
                 apply_retention(&path, team_name, &display_name, retention_config, dry_run)?;
 
             if result.removed > 0 || result.kept > 0 {
-                println!(
-                    "  {:<20} {:>8} {:>8} {:>10}",
-                    display_name, result.kept, result.removed, result.archived
-                );
+                print_retention_row(&display_name, &result, dry_run);
 
                 total_kept += result.kept;
                 total_removed += result.removed;
@@ -485,6 +482,28 @@ fn cleanup_team(
     Ok(())
 }
 
+/// Print one agent row; in dry-run mode also list which policy would evict
+/// how many messages.
+fn print_retention_row(name: &str, result: &RetentionResult, dry_run: bool) {
+    println!(
+        "  {:<20} {:>8} {:>8} {:>10}",
+        name, result.kept, result.removed, result.archived
+    );
+    if dry_run {
+        for line in format_eviction_breakdown(result) {
+            println!("{line}");
+        }
+    }
+}
+
+fn format_eviction_breakdown(result: &RetentionResult) -> Vec<String> {
+    result
+        .evictions_by_policy()
+        .into_iter()
+        .map(|(policy, count)| format!("      {count} by {policy}"))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -550,6 +569,31 @@ mod tests {
         team_dir
     }
 
+    #[test]
+    fn test_format_eviction_breakdown_groups_by_policy() {
+        use agent_team_mail_core::retention::{
+            EvictionReason, RetentionEviction, RetentionPolicySource,
+        };
+        let eviction = |policy: RetentionPolicySource| RetentionEviction {
+            message_id: None,
+            message_type: None,
+            policy,
+            reason: EvictionReason::MaxAge,
+        };
+        let result = RetentionResult {
+            evictions: vec![
+                eviction(RetentionPolicySource::PerType("ci_alert".to_string())),
+                eviction(RetentionPolicySource::Global),
+                eviction(RetentionPolicySource::PerType("ci_alert".to_string())),
+            ],
+            ..RetentionResult::new(1, 3, 0)
+        };
+        assert_eq!(
+            format_eviction_breakdown(&result),
+            vec!["      1 by global", "      2 by per_type.ci_alert"]
+        );
+    }
+
     #[test]
     #[serial]
     fn test_execute_agent_cleanup_refuses_active_without_kill() {
@@ -573,8 +617,15 @@ mod tests {
             query_error: None,
         });
 
-        let result =
-            execute_agent_cleanup(temp_dir.path(), "atm-dev", "publisher", false, false, 1);
+        let result = execute_agent_cleanup(
+            temp_dir.path(),
+            "atm-dev",
+            "publisher",
+            false,
+            false,
+            1,
+            MessageIdScheme::default(),
+        );
         test_daemon_state::clear();
 
         assert!(result.is_err());
@@ -599,8 +650,15 @@ mod tests {
             query_result: None,
             query_error: None,
         });
-        let result =
-            execute_agent_cleanup(temp_dir.path(), "atm-dev", "publisher", false, false, 1);
+        let result = execute_agent_cleanup(
+            temp_dir.path(),
+            "atm-dev",
+            "publisher",
+            false,
+            false,
+            1,
+            MessageIdScheme::default(),
+        );
         test_daemon_state::clear();
         restore_autostart_env(original_autostart);
 
@@ -632,7 +690,15 @@ mod tests {
             query_result: None,
             query_error: None,
         });
-        let result = execute_agent_cleanup(temp_dir.path(), "atm-dev", "publisher", true, false, 1);
+        let result = execute_agent_cleanup(
+            temp_dir.path(),
+            "atm-dev",
+            "publisher",
+            true,
+            false,
+            1,
+            MessageIdScheme::default(),
+        );
         test_daemon_state::clear();
         // SAFETY: test-only cleanup.
         unsafe {
@@ -1,8 +1,10 @@
 //! Config command implementation
 
-use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
+use agent_team_mail_core::config::{
+    ConfigCheckReport, ConfigOverrides, DiagnosticSeverity, check_config, resolve_config,
+};
 use anyhow::Result;
-use clap::Args;
+use clap::{Args, Subcommand};
 use serde_json::json;
 
 use crate::util::settings::get_home_dir;
@@ -16,6 +18,22 @@ use crate::util::settings::get_home_dir;
 /// command-line overrides that may have been applied at runtime.
 #[derive(Args, Debug)]
 pub struct ConfigArgs {
+    #[command(subcommand)]
+    command: Option<ConfigCommand>,
+
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum ConfigCommand {
+    /// Validate config files; exits 1 when any error is found
+    Check(ConfigCheckArgs),
+}
+
+#[derive(Args, Debug)]
+struct ConfigCheckArgs {
     /// Output as JSON
     #[arg(long)]
     json: bool,
@@ -26,6 +44,19 @@ pub fn execute(args: ConfigArgs) -> Result<()> {
     let home_dir = get_home_dir()?;
     let current_dir = std::env::current_dir()?;
 
+    if let Some(ConfigCommand::Check(check_args)) = args.command {
+        let report = check_config(&ConfigOverrides::default(), &current_dir, &home_dir);
+        if check_args.json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            print_check_report(&report);
+        }
+        if report.error_count() > 0 {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Resolve configuration
     let overrides = ConfigOverrides::default();
     let config = resolve_config(&overrides, &current_dir, &home_dir)?;
@@ -110,6 +141,35 @@ pub fn execute(args: ConfigArgs) -> Result<()> {
     Ok(())
 }
 
+/// Print `atm config check` diagnostics, one per line
+fn print_check_report(report: &ConfigCheckReport) {
+    for diagnostic in &report.diagnostics {
+        let severity = match diagnostic.severity {
+            DiagnosticSeverity::Error => "error",
+            DiagnosticSeverity::Warning => "warning",
+        };
+        let mut location = diagnostic
+            .file
+            .as_ref()
+            .map(|file| file.display().to_string())
+            .unwrap_or_else(|| "config".to_string());
+        if !diagnostic.key.is_empty() {
+            location.push_str(&format!(" [{}]", diagnostic.key));
+        }
+        println!("{severity}: {location}: {}", diagnostic.message);
+    }
+    let checked = report.sources.iter().filter(|s| s.exists).count();
+    if checked == 0 {
+        println!("No config files found");
+        return;
+    }
+    println!(
+        "Checked {checked} config file(s): {} error(s), {} warning(s)",
+        report.error_count(),
+        report.warning_count()
+    );
+}
+
 /// Format source name for display
 fn format_source(source: &str) -> String {
     match source {
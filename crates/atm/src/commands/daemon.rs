@@ -1,16 +1,17 @@
 //! Daemon management commands
 
-use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
+use agent_team_mail_core::config::{ConfigOverrides, MessageIdScheme, resolve_config};
 use agent_team_mail_core::consts::ISOLATED_RUNTIME_DEFAULT_TTL_SECS;
 use agent_team_mail_core::daemon_client::{
     DaemonTouchSnapshot, RuntimeOwnerMetadata, daemon_touch_path_for,
 };
 use agent_team_mail_core::io::inbox::inbox_append;
+use agent_team_mail_core::io::new_message_id;
+use agent_team_mail_core::log_reader::format_event_human;
 use agent_team_mail_core::schema::InboxMessage;
 use anyhow::{Context, Result};
 use chrono::Utc;
 use clap::{Args, Subcommand};
-use std::collections::HashMap;
 use std::time::{Duration, Instant, SystemTime};
 use sysinfo::{Pid, ProcessStatus, System};
 use uuid::Uuid;
@@ -56,6 +57,40 @@ enum DaemonCommands {
     Restart(RestartArgs),
     /// Create an explicit isolated ATM runtime root for smoke/debug/test work
     Isolated(IsolatedArgs),
+    /// Stream the daemon's own events live as they are emitted
+    TailEvents(TailEventsArgs),
+    /// Deliver spooled messages now instead of waiting for the drain loop
+    Drain(DrainArgs),
+    /// Exit 0 if the daemon answers a ping, non-zero otherwise (for probes)
+    Health(HealthArgs),
+}
+
+/// Probe daemon health for external monitors
+#[derive(Args, Debug)]
+pub struct HealthArgs {
+    /// Seconds to wait for the daemon to respond
+    #[arg(long, default_value_t = 5)]
+    timeout: u64,
+}
+
+/// Run one immediate spool drain pass in the daemon
+#[derive(Args, Debug)]
+pub struct DrainArgs {
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+/// Stream the daemon's own events live
+#[derive(Args, Debug)]
+pub struct TailEventsArgs {
+    /// Only show events with this action (repeatable; default: all actions)
+    #[arg(long = "action", value_name = "ACTION")]
+    actions: Vec<String>,
+
+    /// Output raw JSON lines instead of human-readable format
+    #[arg(long)]
+    json: bool,
 }
 
 /// Stop the running daemon
@@ -102,6 +137,14 @@ pub struct StatusArgs {
     json: bool,
 }
 
+impl DaemonArgs {
+    /// Whether this is `atm daemon health`, which runs without logging or
+    /// telemetry so its output stays probe-friendly.
+    pub fn is_health(&self) -> bool {
+        matches!(self.command, Some(DaemonCommands::Health(_)))
+    }
+}
+
 /// Execute daemon command
 pub fn execute(args: DaemonArgs) -> Result<()> {
     if let Some(agent) = args.kill.as_deref() {
@@ -116,9 +159,18 @@ pub fn execute(args: DaemonArgs) -> Result<()> {
         DaemonCommands::Stop(stop_args) => execute_stop(stop_args.timeout.max(1)),
         DaemonCommands::Restart(restart_args) => execute_restart(restart_args.timeout.max(1)),
         DaemonCommands::Isolated(isolated_args) => execute_isolated(isolated_args),
+        DaemonCommands::TailEvents(tail_args) => execute_tail_events(tail_args),
+        DaemonCommands::Drain(drain_args) => execute_drain(drain_args),
+        DaemonCommands::Health(health_args) => execute_health(health_args),
     }
 }
 
+/// Silent on success; the error (printed by `main`) explains a failed probe.
+fn execute_health(args: HealthArgs) -> Result<()> {
+    agent_team_mail_core::daemon_client::ping_daemon(Duration::from_secs(args.timeout.max(1)))?;
+    Ok(())
+}
+
 fn execute_isolated(args: IsolatedArgs) -> Result<()> {
     let reaped = reap_expired_isolated_runtime_roots()?;
     let created = create_isolated_runtime_root(
@@ -215,7 +267,12 @@ fn execute_kill(agent: &str, team_override: Option<&str>, timeout_secs: u64) ->
         )
     })?;
 
-    send_shutdown_request(&home_dir, team_name, agent)?;
+    send_shutdown_request(
+        &home_dir,
+        team_name,
+        agent,
+        config.messaging.id_scheme.unwrap_or_default(),
+    )?;
     if wait_for_session_dead(team_name, agent, timeout_secs) {
         crate::commands::teams::cleanup_single_agent(
             team_name.to_string(),
@@ -302,6 +359,7 @@ fn send_shutdown_request(
     home_dir: &std::path::Path,
     team_name: &str,
     agent_name: &str,
+    id_scheme: MessageIdScheme,
 ) -> Result<()> {
     let payload = serde_json::json!({
         "type": "shutdown_request",
@@ -310,20 +368,13 @@ fn send_shutdown_request(
         "reason": "daemon --kill",
         "timestamp": Utc::now().to_rfc3339(),
     });
-    let msg = InboxMessage {
-        from: "atm".to_string(),
-        source_team: None,
-        text: payload.to_string(),
-        timestamp: Utc::now().to_rfc3339(),
-        read: false,
-        summary: Some("shutdown_request".to_string()),
-        message_id: Some(Uuid::new_v4().to_string()),
-        unknown_fields: HashMap::new(),
-    };
     let inbox_path = teams_root_dir_for(home_dir)
         .join(team_name)
         .join("inboxes")
         .join(format!("{agent_name}.json"));
+    let msg = InboxMessage::new("atm", payload.to_string())
+        .summary("shutdown_request")
+        .message_id(new_message_id(id_scheme, &inbox_path)?);
     inbox_append(&inbox_path, &msg, team_name, "atm")?;
     Ok(())
 }
@@ -331,6 +382,44 @@ fn send_shutdown_request(
 /// Stop the running daemon by sending SIGTERM to the PID recorded in the PID file.
 ///
 /// On non-Unix platforms this is a no-op (returns `Ok(())`).
+/// Follow the daemon's event stream until the daemon closes it or the user
+/// interrupts. Only events emitted after the connection opens are shown.
+fn execute_tail_events(args: TailEventsArgs) -> Result<()> {
+    ensure_daemon_running()?;
+    let Some(subscription) =
+        agent_team_mail_core::daemon_client::subscribe_daemon_events(&args.actions)?
+    else {
+        anyhow::bail!("daemon event stream is unavailable (daemon socket not reachable)");
+    };
+
+    while let Ok(event) = subscription.rx.recv() {
+        if args.json {
+            println!("{}", serde_json::to_string(&event)?);
+        } else {
+            println!("{}", format_event_human(&event));
+        }
+    }
+    eprintln!("Daemon event stream closed.");
+    Ok(())
+}
+
+fn execute_drain(args: DrainArgs) -> Result<()> {
+    ensure_daemon_running()?;
+    let Some(status) = agent_team_mail_core::daemon_client::request_spool_drain()? else {
+        anyhow::bail!("spool drain unavailable (daemon socket not reachable)");
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+    } else {
+        println!(
+            "Spool drain: delivered {}, pending {}, failed {}",
+            status.delivered, status.pending, status.failed
+        );
+    }
+    Ok(())
+}
+
 fn execute_stop(timeout_secs: u64) -> Result<()> {
     #[cfg(unix)]
     {
@@ -998,6 +1087,17 @@ fn execute_status(args: StatusArgs) -> Result<()> {
         if let Some(last_at) = &otel_health.last_error.at {
             println!("  last_error.at:   {last_at}");
         }
+
+        if !status.control_latency.is_empty() {
+            println!();
+            println!("Control ack latency:");
+            for (action, stats) in &status.control_latency {
+                println!(
+                    "  {action:<20}  n={:<6}  p50={:.1}ms  p95={:.1}ms  max={:.1}ms",
+                    stats.count, stats.p50_ms, stats.p95_ms, stats.max_ms
+                );
+            }
+        }
     }
 
     // Exit with error code if stale
@@ -1081,6 +1181,16 @@ struct DaemonStatus {
     logging: LoggingHealthSnapshot,
     #[serde(default)]
     otel: OtelHealthSnapshot,
+    #[serde(default)]
+    control_latency: std::collections::BTreeMap<String, ControlLatencyStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ControlLatencyStats {
+    count: u64,
+    p50_ms: f64,
+    p95_ms: f64,
+    max_ms: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -2141,6 +2141,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "sess-0".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("team-lead", Some(false), 0)],
             unknown_fields: HashMap::new(),
         };
@@ -2271,6 +2273,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: format!("team-lead@{team}"),
             lead_session_id: "sess".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("team-lead", Some(true), 1772216400000)], // ~2026-02-27T19:00:00Z
             unknown_fields: HashMap::new(),
         };
@@ -2330,6 +2334,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("team-lead", Some(true), 0)],
             unknown_fields: HashMap::new(),
         };
@@ -2346,6 +2352,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![
                 member("team-lead", Some(true), 0),
                 member("arch-ctm", Some(true), 0),
@@ -2394,6 +2402,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![
                 member("team-lead", Some(true), 0),
                 member("shared-agent", None, 0),
@@ -2461,6 +2471,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("team-lead", Some(true), 0)],
             unknown_fields: HashMap::new(),
         };
@@ -2504,6 +2516,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![
                 member("team-lead", Some(true), 0),
                 member("arch-ctm", Some(true), 0),
@@ -2591,6 +2605,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("worker-a", Some(true), 0)],
             unknown_fields: HashMap::new(),
         };
@@ -2610,6 +2626,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("worker-a", Some(true), 0)],
             unknown_fields: HashMap::new(),
         };
@@ -2632,6 +2650,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("team-lead", Some(false), 0)],
             unknown_fields: HashMap::new(),
         };
@@ -2663,6 +2683,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("worker-a", None, 0)],
             unknown_fields: HashMap::new(),
         };
@@ -2692,6 +2714,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("worker-a", Some(false), 0)],
             unknown_fields: HashMap::new(),
         };
@@ -2721,6 +2745,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("worker-a", Some(true), 0)],
             unknown_fields: HashMap::new(),
         };
@@ -2803,6 +2829,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "s".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("team-lead", Some(false), 0)],
             unknown_fields: HashMap::new(),
         };
@@ -1829,30 +1829,27 @@ fn notify_team_lead_of_monitor_control(
         .join("inboxes")
         .join(format!("{lead_agent}.json"));
     let now = chrono::Utc::now().to_rfc3339();
-    let message = InboxMessage {
-        from: actor.to_string(),
-        source_team: None,
-        text: format!(
+    let message = InboxMessage::new(
+        actor,
+        format!(
             "your gh monitor was {} by {}@{} for {}",
             action_word,
             actor,
             actor_team,
             reason.trim()
         ),
-        timestamp: now.clone(),
-        read: false,
-        summary: Some(format!(
-            "gh monitor {} by {}@{}",
-            action_word, actor, actor_team
-        )),
-        message_id: Some(format!(
-            "gh-monitor-{}-{}-{}",
-            action_word,
-            target_team,
-            chrono::Utc::now().timestamp_millis()
-        )),
-        unknown_fields: std::collections::HashMap::new(),
-    };
+    )
+    .timestamp(now.clone())
+    .summary(format!(
+        "gh monitor {} by {}@{}",
+        action_word, actor, actor_team
+    ))
+    .message_id(format!(
+        "gh-monitor-{}-{}-{}",
+        action_word,
+        target_team,
+        chrono::Utc::now().timestamp_millis()
+    ));
     let _ = inbox_append(&inbox_path, &message, target_team, &lead_agent)?;
     Ok(())
 }
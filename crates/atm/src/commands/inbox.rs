@@ -1,14 +1,19 @@
 //! Inbox command implementation - show inbox summaries and targeted cleanup
 
-use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
+use agent_team_mail_core::config::{ConfigOverrides, OutputFormat, resolve_config};
 use agent_team_mail_core::retention::parse_duration;
 use agent_team_mail_core::schema::InboxMessage;
 use agent_team_mail_core::schema::TeamConfig;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use clap::{ArgAction, Args, Subcommand};
+use notify::{Config as NotifyConfig, Event, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::Serialize;
-use std::path::Path;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{RecvTimeoutError, channel};
+use std::time::{Duration, Instant};
 
 use crate::util::settings::{get_home_dir, teams_root_dir_for};
 use crate::util::state::{get_last_seen, load_seen_state};
@@ -32,14 +37,18 @@ pub struct InboxArgs {
     #[arg(long = "no-since-last-seen", action = ArgAction::SetTrue, overrides_with = "since_last_seen")]
     no_since_last_seen: bool,
 
-    /// Watch inboxes and log changes (polling)
+    /// Stream new messages until Ctrl-C
     #[arg(long)]
     watch: bool,
 
-    /// Poll interval for --watch (milliseconds)
+    /// Poll interval for --watch when file watching is unavailable (milliseconds)
     #[arg(long, default_value_t = 200)]
     interval_ms: u64,
 
+    /// Output per-member counts as JSON
+    #[arg(long, conflicts_with = "watch")]
+    json: bool,
+
     #[command(subcommand)]
     command: Option<InboxCommand>,
 }
@@ -48,6 +57,21 @@ pub struct InboxArgs {
 enum InboxCommand {
     /// Clear selected messages from an inbox
     Clear(ClearArgs),
+    /// Print the number of messages in an inbox as a bare integer
+    Count(CountArgs),
+}
+
+#[derive(Args, Debug)]
+struct CountArgs {
+    /// Team name
+    team: String,
+
+    /// Agent whose inbox to count
+    agent: String,
+
+    /// Count only unread messages
+    #[arg(long)]
+    unread: bool,
 }
 
 #[derive(Args, Debug)]
@@ -80,6 +104,25 @@ struct ClearArgs {
     json: bool,
 }
 
+/// Per-team inbox summary (`atm inbox --json`)
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+struct TeamInboxSummary {
+    team: String,
+    /// `ok`, `not_found`, or `config_not_found`
+    status: &'static str,
+    /// Whether `new` counts messages since last seen or only pending ones
+    since_last_seen: bool,
+    members: Vec<MemberInboxSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+struct MemberInboxSummary {
+    agent: String,
+    new: usize,
+    total: usize,
+    latest_timestamp: Option<String>,
+}
+
 #[derive(Debug, Default, Clone, Serialize, PartialEq, Eq)]
 struct InboxClearResult {
     team: String,
@@ -101,16 +144,21 @@ pub fn execute(args: InboxArgs) -> Result<()> {
         }
         return execute_clear(clear_args);
     }
+    if let Some(InboxCommand::Count(count_args)) = args.command {
+        return execute_count(count_args);
+    }
 
     let home_dir = get_home_dir()?;
     let current_dir = std::env::current_dir()?;
 
     let overrides = ConfigOverrides {
         team: args.team.clone(),
+        format: args.json.then_some(OutputFormat::Json),
         ..Default::default()
     };
 
     let config = resolve_config(&overrides, &current_dir, &home_dir)?;
+    let json_output = !args.watch && config.display.format == OutputFormat::Json;
 
     let teams_dir = teams_root_dir_for(&home_dir);
     if !teams_dir.exists() {
@@ -146,14 +194,31 @@ pub fn execute(args: InboxArgs) -> Result<()> {
 
         team_names.sort();
 
+        let mut summaries = Vec::with_capacity(team_names.len());
         for team_name in team_names {
-            show_team_summary(&home_dir, &team_name, use_since_last_seen)?;
-            println!();
+            summaries.push(collect_team_summary(
+                &home_dir,
+                &team_name,
+                use_since_last_seen,
+            )?);
+        }
+        if json_output {
+            println!("{}", serde_json::to_string_pretty(&summaries)?);
+        } else {
+            for summary in &summaries {
+                print_team_summary(summary);
+                println!();
+            }
         }
     } else {
         // Show summary for single team
         let team_name = &config.core.default_team;
-        show_team_summary(&home_dir, team_name, use_since_last_seen)?;
+        let summary = collect_team_summary(&home_dir, team_name, use_since_last_seen)?;
+        if json_output {
+            println!("{}", serde_json::to_string_pretty(&summary)?);
+        } else {
+            print_team_summary(&summary);
+        }
     }
 
     Ok(())
@@ -200,6 +265,33 @@ fn execute_clear(args: ClearArgs) -> Result<()> {
     Ok(())
 }
 
+/// Print a single integer so `$(atm inbox count ...)` needs no parsing.
+///
+/// A missing team or inbox counts as `0`.
+fn execute_count(args: CountArgs) -> Result<()> {
+    let home_dir = get_home_dir()?;
+    let config = resolve_config(
+        &ConfigOverrides::default(),
+        &std::env::current_dir()?,
+        &home_dir,
+    )?;
+    let hostname_registry = extract_hostname_registry(&config);
+    let team_dir = teams_root_dir_for(&home_dir).join(&args.team);
+    let messages = agent_team_mail_core::io::inbox::inbox_read_merged(
+        &team_dir,
+        &args.agent,
+        hostname_registry.as_ref(),
+    )?;
+
+    let count = if args.unread {
+        messages.iter().filter(|m| !m.read).count()
+    } else {
+        messages.len()
+    };
+    println!("{count}");
+    Ok(())
+}
+
 fn print_clear_counts(result: &InboxClearResult) {
     println!(
         "  idle_notifications: {}",
@@ -210,20 +302,30 @@ fn print_clear_counts(result: &InboxClearResult) {
     println!("  remaining_total: {}", result.remaining_total);
 }
 
-/// Show inbox summary for a single team
-fn show_team_summary(home_dir: &Path, team_name: &str, use_since_last_seen: bool) -> Result<()> {
+/// Collect the inbox summary for a single team
+fn collect_team_summary(
+    home_dir: &Path,
+    team_name: &str,
+    use_since_last_seen: bool,
+) -> Result<TeamInboxSummary> {
     let team_dir = teams_root_dir_for(home_dir).join(team_name);
+    let mut summary = TeamInboxSummary {
+        team: team_name.to_string(),
+        status: "ok",
+        since_last_seen: use_since_last_seen,
+        members: Vec::new(),
+    };
 
     if !team_dir.exists() {
-        println!("Team: {team_name} (not found)");
-        return Ok(());
+        summary.status = "not_found";
+        return Ok(summary);
     }
 
     // Load team config
     let team_config_path = team_dir.join("config.json");
     if !team_config_path.exists() {
-        println!("Team: {team_name} (config not found)");
-        return Ok(());
+        summary.status = "config_not_found";
+        return Ok(summary);
     }
 
     let team_config: TeamConfig =
@@ -237,22 +339,6 @@ fn show_team_summary(home_dir: &Path, team_name: &str, use_since_last_seen: bool
     )?;
     let hostname_registry = extract_hostname_registry(&config);
 
-    println!("Team: {team_name}\n");
-    if use_since_last_seen {
-        println!(
-            "  {:<20} {:>8} {:>8} {:>12}",
-            "Agent", "New", "Total", "Latest"
-        );
-    } else {
-        println!(
-            "  {:<20} {:>8} {:>8} {:>12}",
-            "Agent", "Pending", "Total", "Latest"
-        );
-    }
-    println!("  {}", "─".repeat(52));
-
-    // Collect agent summaries
-    let mut summaries = Vec::new();
     for member in &team_config.members {
         // Read merged messages (local + all origin files)
         let messages = agent_team_mail_core::io::inbox::inbox_read_merged(
@@ -261,45 +347,74 @@ fn show_team_summary(home_dir: &Path, team_name: &str, use_since_last_seen: bool
             hostname_registry.as_ref(),
         )?;
 
-        let (pending, total, latest) = if !messages.is_empty() {
-            let pending_count = if use_since_last_seen {
-                let state = load_seen_state().unwrap_or_default();
-                let last_seen = get_last_seen(&state, team_name, &member.name);
-                match last_seen {
-                    Some(last_seen_dt) => messages
-                        .iter()
-                        .filter(|m| {
-                            m.is_pending_action()
-                                || DateTime::parse_from_rfc3339(&m.timestamp)
-                                    .map(|dt| dt > last_seen_dt)
-                                    .unwrap_or(false)
-                        })
-                        .count(),
-                    None => messages.iter().filter(|m| m.is_pending_action()).count(),
-                }
-            } else {
-                messages.iter().filter(|m| m.is_pending_action()).count()
-            };
-            let total_count = messages.len();
-            let latest_time = messages
-                .last()
-                .map(|m| format_relative_time(&m.timestamp))
-                .unwrap_or_else(|| "-".to_string());
-
-            (pending_count, total_count, latest_time)
+        let new = if use_since_last_seen {
+            let state = load_seen_state().unwrap_or_default();
+            let last_seen = get_last_seen(&state, team_name, &member.name);
+            match last_seen {
+                Some(last_seen_dt) => messages
+                    .iter()
+                    .filter(|m| {
+                        m.is_pending_action()
+                            || DateTime::parse_from_rfc3339(&m.timestamp)
+                                .map(|dt| dt > last_seen_dt)
+                                .unwrap_or(false)
+                    })
+                    .count(),
+                None => messages.iter().filter(|m| m.is_pending_action()).count(),
+            }
         } else {
-            (0, 0, "-".to_string())
+            messages.iter().filter(|m| m.is_pending_action()).count()
         };
 
-        summaries.push((member.name.clone(), pending, total, latest));
+        summary.members.push(MemberInboxSummary {
+            agent: member.name.clone(),
+            new,
+            total: messages.len(),
+            latest_timestamp: messages.last().map(|m| m.timestamp.clone()),
+        });
     }
 
-    // Display summaries
-    for (agent_name, pending, total, latest) in summaries {
-        println!("  {agent_name:<20} {pending:>8} {total:>8} {latest:>12}");
+    Ok(summary)
+}
+
+/// Print the human-readable inbox summary table for a single team
+fn print_team_summary(summary: &TeamInboxSummary) {
+    let team_name = &summary.team;
+    match summary.status {
+        "not_found" => {
+            println!("Team: {team_name} (not found)");
+            return;
+        }
+        "config_not_found" => {
+            println!("Team: {team_name} (config not found)");
+            return;
+        }
+        _ => {}
     }
 
-    Ok(())
+    println!("Team: {team_name}\n");
+    let new_label = if summary.since_last_seen {
+        "New"
+    } else {
+        "Pending"
+    };
+    println!(
+        "  {:<20} {:>8} {:>8} {:>12}",
+        "Agent", new_label, "Total", "Latest"
+    );
+    println!("  {}", "─".repeat(52));
+
+    for member in &summary.members {
+        let latest = member
+            .latest_timestamp
+            .as_deref()
+            .map(format_relative_time)
+            .unwrap_or_else(|| "-".to_string());
+        println!(
+            "  {:<20} {:>8} {:>8} {:>12}",
+            member.agent, member.new, member.total, latest
+        );
+    }
 }
 
 fn clear_inbox_messages(
@@ -382,31 +497,113 @@ fn message_is_older_than(
         .unwrap_or(true)
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-struct InboxSnapshot {
-    pending: usize,
-    total: usize,
-    latest: String,
+/// Safety-net full rescan interval while OS file watching is active.
+const WATCH_RESCAN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Upper bound on a single wait so Ctrl-C is noticed promptly.
+const WATCH_INTERRUPT_CHECK: Duration = Duration::from_millis(200);
+
+/// Set by the SIGINT handler installed for `--watch`.
+static WATCH_INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Let Ctrl-C end `--watch` through the normal return path (exit 0).
+#[cfg(unix)]
+fn install_watch_interrupt_handler() {
+    extern "C" fn on_sigint(_: libc::c_int) {
+        WATCH_INTERRUPTED.store(true, Ordering::SeqCst);
+    }
+    // SAFETY: the handler only stores to an atomic, which is async-signal-safe.
+    unsafe { libc::signal(libc::SIGINT, on_sigint as *const () as libc::sighandler_t) };
 }
 
-#[derive(Clone, Debug)]
-struct MessageState {
-    read: bool,
-    summary: Option<String>,
-    from: String,
+#[cfg(not(unix))]
+fn install_watch_interrupt_handler() {}
+
+/// Messages already seen by `--watch`, keyed by team, agent and message id.
+#[derive(Default)]
+struct InboxWatchState {
+    seen: HashSet<(String, String, String)>,
 }
 
+impl InboxWatchState {
+    /// Re-read every member inbox of `team_name` and return one line per
+    /// `message_id` not seen by an earlier scan. With `report == false` the
+    /// scan only records the current messages, so messages already present at
+    /// startup are not echoed.
+    fn scan_team(
+        &mut self,
+        home_dir: &Path,
+        team_name: &str,
+        hostname_registry: Option<&agent_team_mail_core::config::HostnameRegistry>,
+        report: bool,
+    ) -> Result<Vec<String>> {
+        let team_dir = teams_root_dir_for(home_dir).join(team_name);
+        let team_config_path = team_dir.join("config.json");
+        if !team_config_path.exists() {
+            return Ok(Vec::new());
+        }
+        let team_config: TeamConfig =
+            serde_json::from_str(&std::fs::read_to_string(&team_config_path)?)?;
+
+        let mut lines = Vec::new();
+        for member in &team_config.members {
+            // Read merged messages (local + all origin files)
+            let messages = agent_team_mail_core::io::inbox::inbox_read_merged(
+                &team_dir,
+                &member.name,
+                hostname_registry,
+            )?;
+
+            for msg in &messages {
+                let key = (
+                    team_name.to_string(),
+                    member.name.clone(),
+                    msg.message_id
+                        .clone()
+                        .unwrap_or_else(|| msg.timestamp.clone()),
+                );
+                if self.seen.insert(key) {
+                    lines.push(format!(
+                        "{}@{} new message read={} from={} summary={}",
+                        member.name,
+                        team_name,
+                        msg.read,
+                        msg.from,
+                        msg.summary.clone().unwrap_or_else(|| message_preview(msg))
+                    ));
+                }
+            }
+        }
+
+        Ok(if report { lines } else { Vec::new() })
+    }
+}
+
+/// First line of the message text, shortened for a single watch line
+fn message_preview(msg: &InboxMessage) -> String {
+    let first_line = msg.text.lines().next().unwrap_or_default();
+    agent_team_mail_core::text::truncate_chars(first_line, 80, "...")
+}
+
+/// Team whose inboxes directory contains `path`, if any
+fn watched_team_for_path(teams_root: &Path, path: &Path) -> Option<String> {
+    let mut components = path.strip_prefix(teams_root).ok()?.components();
+    let team = components.next()?.as_os_str().to_str()?.to_string();
+    (components.next()?.as_os_str() == "inboxes").then_some(team)
+}
+
+/// Stream inbox changes until Ctrl-C.
+///
+/// Watches the teams directory with OS file notifications (inotify on Linux,
+/// kqueue on macOS) and re-reads only the team whose inbox changed; a full
+/// rescan every [`WATCH_RESCAN_INTERVAL`] covers missed events. When the
+/// watcher cannot be initialised it falls back to polling every `interval_ms`.
 fn watch_inboxes(
     home_dir: &Path,
     default_team: &str,
     all_teams: bool,
     interval_ms: u64,
 ) -> Result<()> {
-    let mut previous: std::collections::HashMap<(String, String), InboxSnapshot> =
-        std::collections::HashMap::new();
-    let mut message_states: std::collections::HashMap<(String, String, String), MessageState> =
-        std::collections::HashMap::new();
-
     // Load config to extract hostname registry
     let config = agent_team_mail_core::config::resolve_config(
         &agent_team_mail_core::config::ConfigOverrides::default(),
@@ -414,128 +611,118 @@ fn watch_inboxes(
         home_dir,
     )?;
     let hostname_registry = extract_hostname_registry(&config);
+    let teams_root = teams_root_dir_for(home_dir);
 
-    loop {
-        let team_names = if all_teams {
-            let entries = std::fs::read_dir(teams_root_dir_for(home_dir))?;
-            let mut names = Vec::new();
-            for entry in entries {
-                let entry = entry?;
-                if entry.path().is_dir()
-                    && let Some(name_str) = entry.file_name().to_str()
-                {
-                    names.push(name_str.to_string());
+    let team_names = || -> Result<Vec<String>> {
+        if !all_teams {
+            return Ok(vec![default_team.to_string()]);
+        }
+        let mut names = Vec::new();
+        for entry in std::fs::read_dir(&teams_root)? {
+            let entry = entry?;
+            if entry.path().is_dir()
+                && let Some(name_str) = entry.file_name().to_str()
+            {
+                names.push(name_str.to_string());
+            }
+        }
+        names.sort();
+        Ok(names)
+    };
+
+    let (tx, rx) = channel::<PathBuf>();
+    let watcher = RecommendedWatcher::new(
+        move |res: Result<Event, notify::Error>| {
+            if let Ok(event) = res {
+                for path in event.paths {
+                    let _ = tx.send(path);
                 }
             }
-            names.sort();
-            names
-        } else {
-            vec![default_team.to_string()]
-        };
+        },
+        NotifyConfig::default(),
+    )
+    .and_then(|mut watcher| {
+        watcher.watch(&teams_root, RecursiveMode::Recursive)?;
+        Ok(watcher)
+    });
+    let (_watcher, rescan_interval) = match watcher {
+        Ok(watcher) => (Some(watcher), WATCH_RESCAN_INTERVAL),
+        Err(e) => {
+            eprintln!("Warning: File watching failed ({e}), falling back to polling");
+            (None, Duration::from_millis(interval_ms))
+        }
+    };
 
-        for team_name in team_names {
-            let team_dir = teams_root_dir_for(home_dir).join(&team_name);
-            let team_config_path = team_dir.join("config.json");
-            if !team_config_path.exists() {
-                continue;
+    install_watch_interrupt_handler();
+
+    // Seed after the watcher is live so nothing lands unobserved in between.
+    let mut state = InboxWatchState::default();
+    for team_name in team_names()? {
+        if let Err(e) = state.scan_team(home_dir, &team_name, hostname_registry.as_ref(), false) {
+            eprintln!("Warning: failed to read inboxes for team {team_name}: {e}");
+        }
+    }
+    let mut last_rescan = Instant::now();
+
+    // A transient read/parse error (e.g. an inbox caught mid-rewrite) is
+    // logged and retried on the next tick instead of ending the watch.
+    let emit = |team_name: &str, scanned: Result<Vec<String>>| match scanned {
+        Ok(lines) => {
+            for line in lines {
+                println!("[{}] {line}", Utc::now().to_rfc3339());
             }
+        }
+        Err(e) => eprintln!("Warning: failed to read inboxes for team {team_name}: {e}"),
+    };
 
-            let team_config: TeamConfig =
-                serde_json::from_str(&std::fs::read_to_string(&team_config_path)?)?;
-
-            for member in &team_config.members {
-                // Read merged messages (local + all origin files)
-                let messages = agent_team_mail_core::io::inbox::inbox_read_merged(
-                    &team_dir,
-                    &member.name,
-                    hostname_registry.as_ref(),
-                )?;
-
-                let snapshot = if !messages.is_empty() {
-                    let pending = messages.iter().filter(|m| m.is_pending_action()).count();
-                    let total = messages.len();
-                    let latest = messages
-                        .last()
-                        .map(|m| format_relative_time(&m.timestamp))
-                        .unwrap_or_else(|| "-".to_string());
-
-                    for msg in &messages {
-                        let key = (
-                            team_name.clone(),
-                            member.name.clone(),
-                            msg.message_id
-                                .clone()
-                                .unwrap_or_else(|| msg.timestamp.clone()),
-                        );
-                        let state = MessageState {
-                            read: msg.read,
-                            summary: msg.summary.clone(),
-                            from: msg.from.clone(),
-                        };
-
-                        if let Some(prev) = message_states.get(&key) {
-                            if prev.read != state.read {
-                                println!(
-                                    "[{}] {}@{} message read {}->{} from={} summary={}",
-                                    chrono::Utc::now().to_rfc3339(),
-                                    member.name,
-                                    team_name,
-                                    prev.read,
-                                    state.read,
-                                    state.from,
-                                    state.summary.as_deref().unwrap_or("-")
-                                );
-                            }
-                        } else {
-                            println!(
-                                "[{}] {}@{} new message read={} from={} summary={}",
-                                chrono::Utc::now().to_rfc3339(),
-                                member.name,
-                                team_name,
-                                state.read,
-                                state.from,
-                                state.summary.as_deref().unwrap_or("-")
-                            );
-                        }
-
-                        message_states.insert(key, state);
+    while !WATCH_INTERRUPTED.load(Ordering::SeqCst) {
+        let wait_for = rescan_interval
+            .saturating_sub(last_rescan.elapsed())
+            .min(WATCH_INTERRUPT_CHECK);
+        let changed_teams = match rx.recv_timeout(wait_for) {
+            Ok(path) => {
+                let mut teams = BTreeSet::new();
+                for path in std::iter::once(path).chain(rx.try_iter()) {
+                    if let Some(team) = watched_team_for_path(&teams_root, &path)
+                        && (all_teams || team == default_team)
+                    {
+                        teams.insert(team);
                     }
+                }
+                teams
+            }
+            Err(RecvTimeoutError::Timeout) => BTreeSet::new(),
+            // Without a watcher the sender is dropped immediately; just sleep.
+            Err(RecvTimeoutError::Disconnected) => {
+                std::thread::sleep(wait_for);
+                BTreeSet::new()
+            }
+        };
 
-                    InboxSnapshot {
-                        pending,
-                        total,
-                        latest,
-                    }
-                } else {
-                    InboxSnapshot {
-                        pending: 0,
-                        total: 0,
-                        latest: "-".to_string(),
+        for team_name in &changed_teams {
+            emit(
+                team_name,
+                state.scan_team(home_dir, team_name, hostname_registry.as_ref(), true),
+            );
+        }
+
+        if last_rescan.elapsed() >= rescan_interval {
+            match team_names() {
+                Ok(names) => {
+                    for team_name in names {
+                        emit(
+                            &team_name,
+                            state.scan_team(home_dir, &team_name, hostname_registry.as_ref(), true),
+                        );
                     }
-                };
-
-                let key = (team_name.clone(), member.name.clone());
-                if let Some(prev) = previous.get(&key)
-                    && prev != &snapshot
-                {
-                    println!(
-                        "[{}] {}@{} pending {}->{} total {}->{} latest {}",
-                        chrono::Utc::now().to_rfc3339(),
-                        member.name,
-                        team_name,
-                        prev.pending,
-                        snapshot.pending,
-                        prev.total,
-                        snapshot.total,
-                        snapshot.latest
-                    );
                 }
-                previous.insert(key, snapshot);
+                Err(e) => eprintln!("Warning: failed to list teams: {e}"),
             }
+            last_rescan = Instant::now();
         }
-
-        std::thread::sleep(std::time::Duration::from_millis(interval_ms));
     }
+
+    Ok(())
 }
 
 /// Format timestamp as relative time (e.g., "2m ago", "1h ago")
@@ -838,6 +838,8 @@ fn ensure_team_config(home_dir: &Path, team: &str, cwd: &Path) -> Result<TeamSta
         created_at: now_ms,
         lead_agent_id: format!("team-lead@{team}"),
         lead_session_id: String::new(),
+        groups: Default::default(),
+        display_names: Default::default(),
         members: vec![lead_member],
         unknown_fields: HashMap::new(),
     };
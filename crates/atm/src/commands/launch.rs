@@ -19,22 +19,30 @@
 //!
 //! # Launch with extra environment variables and JSON output
 //! atm launch arch-ctm --env MY_VAR=hello --json
+//!
+//! # Show the daemon launch queue (pending/launching/ready/failed)
+//! atm launch status
 //! ```
 
 use anyhow::Result;
-use clap::Args;
+use clap::{Args, Subcommand};
 use std::collections::HashMap;
 
 use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
-use agent_team_mail_core::daemon_client::{LaunchConfig, LaunchResult};
+use agent_team_mail_core::daemon_client::{LaunchConfig, LaunchQueueStatus, LaunchResult};
 
 use crate::util::settings::get_home_dir;
 
 /// Launch a new Codex agent via the daemon
 #[derive(Args, Debug)]
+#[command(subcommand_negates_reqs = true)]
 pub struct LaunchArgs {
+    #[command(subcommand)]
+    pub subcommand: Option<LaunchCommand>,
+
     /// Agent identity name (e.g., "arch-ctm")
-    pub agent_name: String,
+    #[arg(required = true)]
+    pub agent_name: Option<String>,
 
     /// Command to run in the tmux pane (default: "codex --yolo")
     #[arg(long, default_value = "codex --yolo")]
@@ -64,8 +72,25 @@ pub struct LaunchArgs {
     pub env: Vec<String>,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum LaunchCommand {
+    /// Show the daemon launch queue depth and per-launch status
+    Status(LaunchStatusArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct LaunchStatusArgs {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
 /// Execute the `atm launch` command.
 pub fn execute(args: LaunchArgs) -> Result<()> {
+    if let Some(LaunchCommand::Status(status_args)) = args.subcommand {
+        return execute_status(status_args);
+    }
+
     // Platform guard — launch requires Unix (tmux + Unix socket)
     #[cfg(not(unix))]
     {
@@ -101,8 +126,9 @@ fn execute_unix(args: LaunchArgs) -> Result<()> {
     // Parse --env KEY=VALUE pairs
     let env_vars = parse_env_vars(&args.env)?;
 
+    let agent_name = args.agent_name.clone().unwrap_or_default();
     let launch_config = LaunchConfig {
-        agent: args.agent_name.clone(),
+        agent: agent_name,
         team: team_name.clone(),
         command: args.command.clone(),
         prompt: args.prompt.clone(),
@@ -137,6 +163,51 @@ fn execute_unix(args: LaunchArgs) -> Result<()> {
     Ok(())
 }
 
+/// Execute `atm launch status`.
+fn execute_status(args: LaunchStatusArgs) -> Result<()> {
+    use agent_team_mail_core::daemon_client::query_launch_status;
+
+    let Some(status) = query_launch_status()? else {
+        if args.json {
+            println!("{{\"error\": \"Daemon is not running. Start it with: atm-daemon\"}}");
+        } else {
+            eprintln!("Error: Daemon is not running. Start it with: atm-daemon");
+        }
+        std::process::exit(1);
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+    } else {
+        print_launch_queue(&status);
+    }
+    Ok(())
+}
+
+/// Print the launch queue as a table.
+fn print_launch_queue(status: &LaunchQueueStatus) {
+    println!("Launch queue depth: {}", status.queue_depth);
+    if status.launches.is_empty() {
+        println!("No launches recorded");
+        return;
+    }
+    println!(
+        "  {:>4}  {:<20} {:<16} {:<10} {:<8} Detail",
+        "ID", "Agent", "Team", "Status", "Pane"
+    );
+    for launch in &status.launches {
+        println!(
+            "  {:>4}  {:<20} {:<16} {:<10} {:<8} {}",
+            launch.id,
+            launch.agent,
+            launch.team,
+            launch.status.to_string(),
+            launch.pane_id.as_deref().unwrap_or("-"),
+            launch.message.as_deref().unwrap_or("")
+        );
+    }
+}
+
 /// Print the launch result in human-readable or JSON format.
 fn print_result(result: &LaunchResult, json: bool) {
     if json {
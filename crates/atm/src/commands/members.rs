@@ -1,16 +1,22 @@
 //! Members command implementation
 
-use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
+use agent_team_mail_core::config::{ConfigOverrides, OutputFormat, resolve_config};
 use agent_team_mail_core::daemon_client::{
     canonical_activity_label, canonical_liveness_bool, canonical_status_label, query_list_agents,
     query_team_member_states,
 };
-use agent_team_mail_core::schema::TeamConfig;
-use anyhow::Result;
-use clap::Args;
+use agent_team_mail_core::home::sessions_dir_for;
+use agent_team_mail_core::io::inbox::inbox_update;
+use agent_team_mail_core::io::lock::acquire_lock;
+use agent_team_mail_core::schema::{InboxMessage, TeamConfig};
+use agent_team_mail_core::team_config_store::TeamConfigStore;
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use serde::Serialize;
 use serde_json::json;
 use std::collections::{BTreeSet, HashMap};
 use std::fs;
+use std::path::{Path, PathBuf};
 
 use crate::util::member_labels::{GHOST_SUFFIX, UNREGISTERED_MARKER};
 use crate::util::settings::{get_home_dir, teams_root_dir_for};
@@ -18,6 +24,9 @@ use crate::util::settings::{get_home_dir, teams_root_dir_for};
 /// List agents in a team
 #[derive(Args, Debug)]
 pub struct MembersArgs {
+    #[command(subcommand)]
+    command: Option<MembersCommand>,
+
     /// Team name (optional, uses default team if not specified)
     #[arg(long)]
     team: Option<String>,
@@ -27,6 +36,41 @@ pub struct MembersArgs {
     json: bool,
 }
 
+#[derive(Subcommand, Debug)]
+enum MembersCommand {
+    /// Rename a member, migrating its inbox and session lock
+    Rename(RenameArgs),
+}
+
+/// Rename a team member
+#[derive(Args, Debug)]
+struct RenameArgs {
+    /// Team name
+    team: String,
+
+    /// Current member name
+    old: String,
+
+    /// New member name
+    new: String,
+
+    /// Rewrite `from` on messages sent by the member in every team inbox
+    #[arg(long)]
+    rewrite_from: bool,
+
+    /// Rename even when the member has a live session
+    #[arg(long)]
+    force: bool,
+
+    /// Show what would change without modifying anything
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+}
+
 struct MemberRow {
     name: String,
     agent_type: String,
@@ -107,6 +151,10 @@ fn render_members_json(team_name: &str, member_rows: &[MemberRow]) -> serde_json
 
 /// Execute the members command
 pub fn execute(args: MembersArgs) -> Result<()> {
+    if let Some(MembersCommand::Rename(rename_args)) = args.command {
+        return execute_rename(rename_args);
+    }
+
     // Prime daemon connectivity so daemon-backed liveness can be queried.
     let _ = query_list_agents();
 
@@ -116,9 +164,11 @@ pub fn execute(args: MembersArgs) -> Result<()> {
     // Resolve configuration to get default team
     let overrides = ConfigOverrides {
         team: args.team.clone(),
+        format: args.json.then_some(OutputFormat::Json),
         ..Default::default()
     };
     let config = resolve_config(&overrides, &current_dir, &home_dir)?;
+    let json_output = config.display.format == OutputFormat::Json;
     let team_name = &config.core.default_team;
 
     // Load team config
@@ -144,7 +194,7 @@ pub fn execute(args: MembersArgs) -> Result<()> {
     let member_rows = build_member_rows(&team_config, &daemon_states);
 
     // Output results
-    if args.json {
+    if json_output {
         let output = render_members_json(team_name, &member_rows);
         println!("{}", serde_json::to_string_pretty(&output)?);
     } else {
@@ -154,6 +204,362 @@ pub fn execute(args: MembersArgs) -> Result<()> {
     Ok(())
 }
 
+/// A file or directory moved as part of a rename.
+#[derive(Debug, Serialize)]
+struct PathMove {
+    from: PathBuf,
+    to: PathBuf,
+}
+
+/// Messages whose `from` is rewritten in one inbox.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FromRewrite {
+    inbox: String,
+    messages: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RenamePlan {
+    team: String,
+    old: String,
+    new: String,
+    dry_run: bool,
+    live_session: Option<String>,
+    moves: Vec<PathMove>,
+    session_lock: Option<PathMove>,
+    from_rewrites: Vec<FromRewrite>,
+}
+
+fn execute_rename(args: RenameArgs) -> Result<()> {
+    let home_dir = get_home_dir()?;
+    let team_dir = teams_root_dir_for(&home_dir).join(&args.team);
+    if !team_dir.join("config.json").exists() {
+        anyhow::bail!("No team '{}' found.", args.team);
+    }
+    validate_member_name(&args.new)?;
+    if args.old == "team-lead" {
+        anyhow::bail!("team-lead is protected and cannot be renamed");
+    }
+
+    let store = TeamConfigStore::open(&team_dir);
+    let mut team_config = store.read()?;
+    if !team_config.members.iter().any(|m| m.name == args.old) {
+        anyhow::bail!("Member '{}' not found in team '{}'.", args.old, args.team);
+    }
+    if !team_config.rename_member(&args.old, &args.new) {
+        anyhow::bail!(
+            "Member '{}' already exists in team '{}'.",
+            args.new,
+            args.team
+        );
+    }
+
+    let session_lock_dir = sessions_dir_for(&home_dir).join(&args.team);
+    let other_members: Vec<&str> = team_config
+        .members
+        .iter()
+        .map(|m| m.name.as_str())
+        .filter(|name| *name != args.new)
+        .collect();
+    let plan = plan_rename(&team_dir, &session_lock_dir, &other_members, &args)?;
+    if let Some(reason) = plan.live_session.as_deref()
+        && !args.force
+        && !args.dry_run
+    {
+        anyhow::bail!(
+            "Member '{}' has a live session ({reason}). Use --force to override.",
+            args.old
+        );
+    }
+
+    if !args.dry_run {
+        apply_rename(&store, &team_dir, &plan)?;
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&plan)?);
+    } else {
+        print!("{}", render_rename_plan(&plan));
+    }
+    Ok(())
+}
+
+fn validate_member_name(name: &str) -> Result<()> {
+    if name.trim().is_empty()
+        || name.starts_with('.')
+        || name.contains(['/', '\\', '@'])
+        || name.chars().any(char::is_whitespace)
+    {
+        anyhow::bail!("Invalid member name '{name}'");
+    }
+    Ok(())
+}
+
+/// Compute every change a rename makes, failing if any target already exists.
+///
+/// `other_members` are the remaining roster names; their inboxes are never
+/// mistaken for `<old>.<host>.json` origin inboxes.
+fn plan_rename(
+    team_dir: &Path,
+    session_lock_dir: &Path,
+    other_members: &[&str],
+    args: &RenameArgs,
+) -> Result<RenamePlan> {
+    let inboxes_dir = team_dir.join("inboxes");
+    // `<name>.lock` files are lock sentinels held during the rename, not moved
+    let mut moves: Vec<PathMove> = ["json", "provenance", "seq"]
+        .into_iter()
+        .map(|ext| PathMove {
+            from: inboxes_dir.join(format!("{}.{ext}", args.old)),
+            to: inboxes_dir.join(format!("{}.{ext}", args.new)),
+        })
+        .collect();
+    moves.extend(
+        origin_inbox_hosts(&inboxes_dir, &args.old, other_members)
+            .into_iter()
+            .map(|host| PathMove {
+                from: inboxes_dir.join(format!("{}.{host}.json", args.old)),
+                to: inboxes_dir.join(format!("{}.{host}.json", args.new)),
+            }),
+    );
+    let mailboxes_dir = team_dir.join("mailboxes");
+    moves.push(PathMove {
+        from: mailboxes_dir.join(&args.old),
+        to: mailboxes_dir.join(&args.new),
+    });
+    moves.retain(|m| m.from.exists());
+
+    let session_lock = Some(PathMove {
+        from: session_lock_dir.join(format!("{}.lock", args.old)),
+        to: session_lock_dir.join(format!("{}.lock", args.new)),
+    })
+    .filter(|m| m.from.exists());
+
+    for target in moves.iter().chain(session_lock.iter()).map(|m| &m.to) {
+        if target.exists() {
+            anyhow::bail!(
+                "Cannot rename '{}' to '{}': {} already exists",
+                args.old,
+                args.new,
+                target.display()
+            );
+        }
+    }
+
+    let from_rewrites = if args.rewrite_from {
+        count_messages_from(&inboxes_dir, &args.old)
+    } else {
+        Vec::new()
+    };
+
+    Ok(RenamePlan {
+        team: args.team.clone(),
+        old: args.old.clone(),
+        new: args.new.clone(),
+        dry_run: args.dry_run,
+        live_session: live_session_reason(&args.team, &args.old, session_lock.as_ref()),
+        moves,
+        session_lock,
+        from_rewrites,
+    })
+}
+
+/// List the hostnames of `<member>.<host>.json` origin inboxes bridged in
+/// from remote machines, skipping stems that are themselves roster members.
+fn origin_inbox_hosts(inboxes_dir: &Path, member: &str, other_members: &[&str]) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(inboxes_dir) else {
+        return Vec::new();
+    };
+    let prefix = format!("{member}.");
+    let mut hosts: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let stem = name.strip_suffix(".json")?;
+            let host = stem.strip_prefix(&prefix)?;
+            (!host.is_empty() && !other_members.contains(&stem)).then(|| host.to_string())
+        })
+        .collect();
+    hosts.sort();
+    hosts
+}
+
+/// Describe why `member` counts as live: a session lock held by a running PID
+/// or a daemon session reported alive.
+fn live_session_reason(team: &str, member: &str, lock: Option<&PathMove>) -> Option<String> {
+    #[derive(serde::Deserialize)]
+    struct LockPayload {
+        pid: u32,
+    }
+
+    if let Some(pid) = lock
+        .and_then(|l| fs::read_to_string(&l.from).ok())
+        .and_then(|c| serde_json::from_str::<LockPayload>(&c).ok())
+        .map(|l| l.pid)
+        .filter(|pid| agent_team_mail_core::pid::is_pid_alive(*pid))
+    {
+        return Some(format!("session lock held by pid {pid}"));
+    }
+    match agent_team_mail_core::daemon_client::query_session_for_team(team, member) {
+        Ok(Some(info)) if info.alive => Some(format!("daemon session in pid {}", info.process_id)),
+        _ => None,
+    }
+}
+
+/// Count messages sent by `sender` in each inbox under `inboxes_dir`.
+fn count_messages_from(inboxes_dir: &Path, sender: &str) -> Vec<FromRewrite> {
+    let Ok(entries) = fs::read_dir(inboxes_dir) else {
+        return Vec::new();
+    };
+    let mut rewrites: Vec<FromRewrite> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("json"))
+        .filter_map(|path| {
+            let inbox = path.file_stem()?.to_str()?.to_string();
+            let messages: Vec<InboxMessage> =
+                serde_json::from_str(&fs::read_to_string(&path).ok()?).ok()?;
+            let count = messages.iter().filter(|m| m.from == sender).count();
+            (count > 0).then_some(FromRewrite {
+                inbox,
+                messages: count,
+            })
+        })
+        .collect();
+    rewrites.sort_by(|a, b| a.inbox.cmp(&b.inbox));
+    rewrites
+}
+
+/// Apply a rename plan.
+///
+/// The old and new inbox locks are held while files move so no writer sees a
+/// half-moved inbox. If a move or the config update fails, completed moves
+/// are undone and the error is returned.
+fn apply_rename(store: &TeamConfigStore, team_dir: &Path, plan: &RenamePlan) -> Result<()> {
+    let inboxes_dir = team_dir.join("inboxes");
+    {
+        let old_lock_path = inboxes_dir.join(format!("{}.lock", plan.old));
+        let _old_lock = acquire_lock(&old_lock_path, 5)
+            .with_context(|| format!("Failed to lock inbox '{}'", plan.old))?;
+        let _new_lock = acquire_lock(&inboxes_dir.join(format!("{}.lock", plan.new)), 5)
+            .with_context(|| format!("Failed to lock inbox '{}'", plan.new))?;
+        // Counter id allocation takes `<name>.seq.lock`, not the inbox lock
+        let old_seq_lock_path = inboxes_dir.join(format!("{}.seq.lock", plan.old));
+        let _old_seq_lock = acquire_lock(&old_seq_lock_path, 5)
+            .with_context(|| format!("Failed to lock id counter for '{}'", plan.old))?;
+        let _new_seq_lock = acquire_lock(&inboxes_dir.join(format!("{}.seq.lock", plan.new)), 5)
+            .with_context(|| format!("Failed to lock id counter for '{}'", plan.new))?;
+
+        let moves: Vec<&PathMove> = plan.moves.iter().chain(plan.session_lock.iter()).collect();
+        for (done, path_move) in moves.iter().enumerate() {
+            if let Err(e) = move_path(path_move) {
+                undo_moves(&moves[..done]);
+                return Err(e);
+            }
+        }
+
+        let renamed = store.update(|mut current| {
+            if !current.rename_member(&plan.old, &plan.new) {
+                anyhow::bail!(
+                    "Member '{}' changed while renaming to '{}'.",
+                    plan.old,
+                    plan.new
+                );
+            }
+            Ok(Some(current))
+        });
+        if let Err(e) = renamed {
+            undo_moves(&moves);
+            return Err(e);
+        }
+        let _ = fs::remove_file(&old_lock_path);
+        let _ = fs::remove_file(&old_seq_lock_path);
+    }
+
+    for rewrite in &plan.from_rewrites {
+        let inbox = if rewrite.inbox == plan.old {
+            &plan.new
+        } else {
+            &rewrite.inbox
+        };
+        let inbox_path = team_dir.join("inboxes").join(format!("{inbox}.json"));
+        inbox_update(&inbox_path, &plan.team, inbox, |messages| {
+            for message in messages.iter_mut().filter(|m| m.from == plan.old) {
+                message.from = plan.new.clone();
+            }
+        })
+        .with_context(|| format!("Failed to rewrite senders in {}", inbox_path.display()))?;
+    }
+    Ok(())
+}
+
+/// Move one path, refusing to replace a target created since planning.
+fn move_path(path_move: &PathMove) -> Result<()> {
+    if path_move.to.exists() {
+        anyhow::bail!("{} already exists", path_move.to.display());
+    }
+    fs::rename(&path_move.from, &path_move.to).with_context(|| {
+        format!(
+            "Failed to move {} to {}",
+            path_move.from.display(),
+            path_move.to.display()
+        )
+    })
+}
+
+/// Reverse completed moves, newest first, warning on any that cannot be undone.
+fn undo_moves(moves: &[&PathMove]) {
+    for path_move in moves.iter().rev() {
+        if let Err(e) = fs::rename(&path_move.to, &path_move.from) {
+            eprintln!(
+                "Warning: failed to move {} back to {}: {e}",
+                path_move.to.display(),
+                path_move.from.display()
+            );
+        }
+    }
+}
+
+fn render_rename_plan(plan: &RenamePlan) -> String {
+    let mut out = if plan.dry_run {
+        format!(
+            "Dry run: would rename '{}' to '{}' in team '{}'\n",
+            plan.old, plan.new, plan.team
+        )
+    } else {
+        format!(
+            "Renamed '{}' to '{}' in team '{}'\n",
+            plan.old, plan.new, plan.team
+        )
+    };
+    if let Some(reason) = &plan.live_session {
+        out.push_str(&format!("  warning: live session ({reason})\n"));
+    }
+    for path_move in &plan.moves {
+        out.push_str(&format!(
+            "  move: {} -> {}\n",
+            path_move.from.display(),
+            path_move.to.display()
+        ));
+    }
+    if let Some(lock) = &plan.session_lock {
+        out.push_str(&format!(
+            "  session lock: {} -> {}\n",
+            lock.from.display(),
+            lock.to.display()
+        ));
+    }
+    for rewrite in &plan.from_rewrites {
+        out.push_str(&format!(
+            "  rewrite from: {} message(s) in {}\n",
+            rewrite.messages, rewrite.inbox
+        ));
+    }
+    out
+}
+
 fn build_member_rows(
     team_config: &TeamConfig,
     daemon_states: &HashMap<String, agent_team_mail_core::daemon_client::CanonicalMemberState>,
@@ -234,6 +640,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn apply_rename_rolls_back_moves_when_a_later_move_fails() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let team_dir = temp_dir.path().join("atm-dev");
+        fs::create_dir_all(team_dir.join("inboxes")).unwrap();
+        let cfg = TeamConfig {
+            name: "atm-dev".to_string(),
+            description: None,
+            created_at: 0,
+            lead_agent_id: "team-lead@atm-dev".to_string(),
+            lead_session_id: "sess".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
+            members: vec![member("team-lead"), member("arch-ctm")],
+            unknown_fields: HashMap::new(),
+        };
+        fs::write(
+            team_dir.join("config.json"),
+            serde_json::to_string(&cfg).unwrap(),
+        )
+        .unwrap();
+        let inbox = team_dir.join("inboxes/arch-ctm.json");
+        fs::write(&inbox, "[]").unwrap();
+
+        // The mailbox move fails because its source vanished after planning
+        let plan = RenamePlan {
+            team: "atm-dev".to_string(),
+            old: "arch-ctm".to_string(),
+            new: "arch-lead".to_string(),
+            dry_run: false,
+            live_session: None,
+            moves: vec![
+                PathMove {
+                    from: inbox.clone(),
+                    to: team_dir.join("inboxes/arch-lead.json"),
+                },
+                PathMove {
+                    from: team_dir.join("mailboxes/arch-ctm"),
+                    to: team_dir.join("mailboxes/arch-lead"),
+                },
+            ],
+            session_lock: None,
+            from_rewrites: Vec::new(),
+        };
+        let store = TeamConfigStore::open(&team_dir);
+        assert!(apply_rename(&store, &team_dir, &plan).is_err());
+
+        assert!(inbox.exists());
+        assert!(!team_dir.join("inboxes/arch-lead.json").exists());
+        let names: Vec<String> = store
+            .read()
+            .unwrap()
+            .members
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        assert_eq!(names, vec!["team-lead", "arch-ctm"]);
+    }
+
     #[test]
     fn build_member_rows_includes_daemon_only_member() {
         let cfg = TeamConfig {
@@ -242,6 +707,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "sess".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("team-lead")],
             unknown_fields: HashMap::new(),
         };
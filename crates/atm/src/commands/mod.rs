@@ -1,5 +1,6 @@
 //! CLI command dispatch and execution
 
+use agent_team_mail_core::color::{ColorChoice, set_color_choice};
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 
@@ -19,6 +20,7 @@ mod logs;
 mod mcp;
 mod members;
 mod monitor;
+mod prompt_status;
 mod read;
 mod register;
 mod request;
@@ -30,6 +32,7 @@ mod subscribe;
 mod tail;
 mod teams;
 mod wait;
+mod watch;
 
 /// atm - Mail-like messaging for Claude agent teams
 #[derive(Parser, Debug)]
@@ -40,10 +43,43 @@ mod wait;
     long_about = "A thin CLI over the ~/.claude/teams/ file-based API for agent team messaging"
 )]
 pub struct Cli {
+    /// When to colorize human-readable output (respects NO_COLOR in auto mode)
+    #[arg(
+        long,
+        alias = "color-output",
+        global = true,
+        value_enum,
+        default_value_t = ColorMode::Auto
+    )]
+    color: ColorMode,
+
+    /// Skip the daemon entirely and operate directly on the filesystem
+    /// (also `ATM_NO_DAEMON=1`)
+    #[arg(long, global = true)]
+    no_daemon: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+/// `--color` values
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+impl From<ColorMode> for ColorChoice {
+    fn from(mode: ColorMode) -> Self {
+        match mode {
+            ColorMode::Auto => ColorChoice::Auto,
+            ColorMode::Always => ColorChoice::Always,
+            ColorMode::Never => ColorChoice::Never,
+        }
+    }
+}
+
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Acknowledge previously-read ATM messages as actioned
@@ -73,6 +109,12 @@ enum Commands {
     /// Show team status overview
     Status(status::StatusArgs),
 
+    /// Watch a periodically refreshed team snapshot (no alternate screen)
+    Watch(watch::WatchArgs),
+
+    /// Print a terse unread count for shell prompts (e.g. `atm:3`)
+    PromptStatus(prompt_status::PromptStatusArgs),
+
     /// Interactive wrapper for spawning a new runtime teammate
     Spawn(spawn::SpawnArgs),
 
@@ -134,6 +176,8 @@ impl Cli {
             Commands::Teams(_) => "teams",
             Commands::Members(_) => "members",
             Commands::Status(_) => "status",
+            Commands::Watch(_) => "watch",
+            Commands::PromptStatus(_) => "prompt-status",
             Commands::Spawn(_) => "spawn",
             Commands::Doctor(_) => "doctor",
             Commands::Gh(_) => "gh",
@@ -153,8 +197,25 @@ impl Cli {
         }
     }
 
+    /// Whether the command runs on a shell-prompt hot path (or is a daemon
+    /// health probe) and must skip logging, daemon, and telemetry setup in
+    /// `main`.
+    pub fn is_hot_path(&self) -> bool {
+        match &self.command {
+            Commands::PromptStatus(_) => true,
+            Commands::Daemon(args) => args.is_health(),
+            _ => false,
+        }
+    }
+
+    /// Whether `--no-daemon` was passed.
+    pub fn no_daemon(&self) -> bool {
+        self.no_daemon
+    }
+
     /// Execute the CLI command
     pub fn execute(self) -> Result<()> {
+        set_color_choice(self.color.into());
         match self.command {
             Commands::Ack(args) => ack::execute(args),
             Commands::Send(args) => send::execute(args),
@@ -165,6 +226,8 @@ impl Cli {
             Commands::Teams(args) => teams::execute(args),
             Commands::Members(args) => members::execute(args),
             Commands::Status(args) => status::execute(args),
+            Commands::Watch(args) => watch::execute(args),
+            Commands::PromptStatus(args) => prompt_status::execute(args),
             Commands::Spawn(args) => spawn::execute(args),
             Commands::Doctor(args) => doctor::execute(args),
             Commands::Gh(args) => gh::execute(args),
@@ -5,8 +5,9 @@ use clap::Args;
 use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
 
-use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
+use agent_team_mail_core::config::{ConfigOverrides, MessageIdScheme, resolve_config};
 use agent_team_mail_core::io::inbox::inbox_append;
+use agent_team_mail_core::io::new_message_id;
 use agent_team_mail_core::schema::InboxMessage;
 
 use crate::commands::doctor::monitor_report_json;
@@ -83,6 +84,7 @@ pub fn execute(args: MonitorArgs) -> Result<()> {
         &home_dir,
     )?;
     let team = config.core.default_team;
+    let id_scheme = config.messaging.id_scheme.unwrap_or_default();
     let recipients: Vec<String> = args
         .notify
         .split(',')
@@ -106,7 +108,7 @@ pub fn execute(args: MonitorArgs) -> Result<()> {
 
         for finding in &critical_findings {
             if tracker.should_emit(&finding.key, cooldown, now) {
-                send_alerts(&home_dir, &team, &recipients, finding)?;
+                send_alerts(&home_dir, &team, &recipients, id_scheme, finding)?;
                 tracker.last_sent.insert(finding.key.clone(), now);
             }
         }
@@ -177,6 +179,7 @@ fn send_alerts(
     home_dir: &std::path::Path,
     team: &str,
     recipients: &[String],
+    id_scheme: MessageIdScheme,
     finding: &MonitorFinding,
 ) -> Result<()> {
     let timestamp = chrono::Utc::now().to_rfc3339();
@@ -210,16 +213,10 @@ fn send_alerts(
         if !inbox.exists() {
             continue;
         }
-        let msg = InboxMessage {
-            from: "atm-monitor".to_string(),
-            source_team: None,
-            text: human.clone(),
-            timestamp: timestamp.clone(),
-            read: false,
-            summary: Some(format!("{} {}", finding.key.severity, finding.key.code)),
-            message_id: Some(uuid::Uuid::new_v4().to_string()),
-            unknown_fields: std::collections::HashMap::new(),
-        };
+        let msg = InboxMessage::new("atm-monitor", human.clone())
+            .timestamp(timestamp.clone())
+            .summary(format!("{} {}", finding.key.severity, finding.key.code))
+            .message_id(new_message_id(id_scheme, &inbox)?);
         inbox_append(&inbox, &msg, team, "atm-monitor")
             .with_context(|| format!("failed to send alert to {recipient}@{team}"))?;
     }
@@ -0,0 +1,115 @@
+//! `atm prompt-status` — terse unread count for shell prompts.
+//!
+//! Prints `<prefix>:<unread>` (e.g. `atm:3`) for the current identity and
+//! team. The count is a pure filesystem read of the local inbox: no daemon
+//! query, no logging, no telemetry, so it is cheap enough to run on every
+//! prompt render.
+//!
+//! Exits `0` whenever configuration resolves, including when the inbox is
+//! missing or unreadable (reported as zero unread). Only configuration errors
+//! exit nonzero.
+//!
+//! # Examples
+//!
+//! ```text
+//! # bash
+//! PS1='$(atm prompt-status --hide-zero) \w \$ '
+//!
+//! # zsh
+//! setopt PROMPT_SUBST
+//! PROMPT='$(atm prompt-status --hide-zero) %~ %# '
+//! ```
+
+use agent_team_mail_core::config::{ConfigOverrides, resolve_config, resolve_identity};
+use agent_team_mail_core::io::inbox_iter;
+use anyhow::Result;
+use clap::Args;
+use std::path::Path;
+
+use crate::util::settings::{get_home_dir, teams_root_dir_for};
+
+/// Print a terse unread-count string for shell prompts
+#[derive(Args, Debug)]
+pub struct PromptStatusArgs {
+    /// Override default team
+    #[arg(long)]
+    team: Option<String>,
+
+    /// Text printed before the count
+    #[arg(long, default_value = "atm")]
+    prefix: String,
+
+    /// Print nothing when there are no unread messages
+    #[arg(long)]
+    hide_zero: bool,
+}
+
+/// Execute `atm prompt-status`.
+pub fn execute(args: PromptStatusArgs) -> Result<()> {
+    let home_dir = get_home_dir()?;
+    let current_dir = std::env::current_dir()?;
+    let overrides = ConfigOverrides {
+        team: args.team.clone(),
+        ..Default::default()
+    };
+    let config = resolve_config(&overrides, &current_dir, &home_dir)?;
+    let identity = resolve_identity(&config.core.identity, &config.roles, &config.aliases);
+
+    let inbox_path = teams_root_dir_for(&home_dir)
+        .join(&config.core.default_team)
+        .join("inboxes")
+        .join(format!("{identity}.json"));
+    let unread = count_unread(&inbox_path);
+
+    if let Some(line) = render(&args.prefix, unread, args.hide_zero) {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+/// Count unread messages by streaming the inbox; any read error counts as 0.
+fn count_unread(inbox_path: &Path) -> usize {
+    match inbox_iter(inbox_path) {
+        Ok(messages) => messages.skip_invalid_records().filter(|m| !m.read).count(),
+        Err(_) => 0,
+    }
+}
+
+fn render(prefix: &str, unread: usize, hide_zero: bool) -> Option<String> {
+    if unread == 0 && hide_zero {
+        return None;
+    }
+    Some(format!("{prefix}:{unread}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_count_unread_streams_inbox_and_tolerates_missing_file() {
+        let tmp = TempDir::new().unwrap();
+        let inbox = tmp.path().join("arch-ctm.json");
+        assert_eq!(count_unread(&inbox), 0);
+
+        std::fs::write(
+            &inbox,
+            r#"[
+                {"from": "a", "text": "x", "timestamp": "2026-02-11T10:00:00Z", "read": false},
+                {"from": "b", "text": "y", "timestamp": "2026-02-11T10:01:00Z", "read": true},
+                {"from": "c", "text": "z", "timestamp": "2026-02-11T10:02:00Z"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(count_unread(&inbox), 2);
+    }
+
+    #[test]
+    fn test_render_prefix_and_hide_zero() {
+        assert_eq!(render("atm", 3, false), Some("atm:3".to_string()));
+        assert_eq!(render("atm", 0, false), Some("atm:0".to_string()));
+        assert_eq!(render("atm", 0, true), None);
+        assert_eq!(render("mail", 1, true), Some("mail:1".to_string()));
+    }
+}
@@ -1,6 +1,8 @@
 //! Read command implementation
 
-use agent_team_mail_core::config::{ConfigOverrides, resolve_config, resolve_identity};
+use agent_team_mail_core::config::{
+    ConfigOverrides, OutputFormat, resolve_config, resolve_identity,
+};
 use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
 use agent_team_mail_core::schema::{InboxMessage, TeamConfig};
 use anyhow::Result;
@@ -69,6 +71,10 @@ pub struct ReadArgs {
     #[arg(long)]
     since: Option<String>,
 
+    /// Order unread messages by priority (highest first) before recency
+    #[arg(long)]
+    priority_first: bool,
+
     /// Filter by sender
     #[arg(long)]
     from: Option<String>,
@@ -93,10 +99,12 @@ pub fn execute(args: ReadArgs) -> Result<()> {
 
     let overrides = ConfigOverrides {
         team: args.team.clone(),
+        format: args.json.then_some(OutputFormat::Json),
         ..Default::default()
     };
 
     let mut config = resolve_config(&overrides, &current_dir, &home_dir)?;
+    let json_output = config.display.format == OutputFormat::Json;
 
     if let Some(ref name) = args.reader_as {
         config.core.identity = name.clone();
@@ -195,7 +203,7 @@ pub fn execute(args: ReadArgs) -> Result<()> {
         });
     }
 
-    let mut buckets = bucket_messages(filtered_messages.clone());
+    let mut buckets = bucket_messages(filtered_messages.clone(), args.priority_first);
     let mut displayed_messages = select_display_messages(&buckets, &args);
     apply_limit(&mut displayed_messages, args.limit);
 
@@ -234,12 +242,12 @@ pub fn execute(args: ReadArgs) -> Result<()> {
                 }
 
                 filtered_messages = new_filtered;
-                buckets = bucket_messages(filtered_messages.clone());
+                buckets = bucket_messages(filtered_messages.clone(), args.priority_first);
                 displayed_messages = select_display_messages(&buckets, &args);
                 apply_limit(&mut displayed_messages, args.limit);
             }
             WaitResult::Timeout => {
-                if args.json {
+                if json_output {
                     let output = serde_json::json!({
                         "action": "read",
                         "agent": agent_name,
@@ -352,7 +360,7 @@ pub fn execute(args: ReadArgs) -> Result<()> {
         });
     }
 
-    if args.json {
+    if json_output {
         let output = serde_json::json!({
             "action": "read",
             "agent": agent_name,
@@ -378,11 +386,11 @@ pub fn execute(args: ReadArgs) -> Result<()> {
             buckets.history.len()
         );
 
-        let bucket_views = display_bucket_views(&displayed_messages);
-        print_bucket("Unread", &bucket_views.unread);
-        print_bucket("Pending Ack", &bucket_views.pending_ack);
+        let bucket_views = display_bucket_views(&displayed_messages, args.priority_first);
+        print_bucket("Unread", &bucket_views.unread, &team_config);
+        print_bucket("Pending Ack", &bucket_views.pending_ack, &team_config);
         if args.history || args.all {
-            print_bucket("History", &bucket_views.history);
+            print_bucket("History", &bucket_views.history, &team_config);
         } else if !buckets.history.is_empty() {
             println!(
                 "{} historical message(s) hidden (use --history to expand)\n",
@@ -428,7 +436,7 @@ struct MessageBuckets {
 
 type DisplayBuckets = MessageBuckets;
 
-fn bucket_messages(messages: Vec<InboxMessage>) -> MessageBuckets {
+fn bucket_messages(messages: Vec<InboxMessage>, priority_first: bool) -> MessageBuckets {
     let mut buckets = MessageBuckets {
         unread: Vec::new(),
         pending_ack: Vec::new(),
@@ -448,6 +456,9 @@ fn bucket_messages(messages: Vec<InboxMessage>) -> MessageBuckets {
     sort_bucket_newest_first(&mut buckets.unread);
     sort_bucket_newest_first(&mut buckets.pending_ack);
     sort_bucket_newest_first(&mut buckets.history);
+    if priority_first {
+        sort_bucket_priority_first(&mut buckets.unread);
+    }
     buckets
 }
 
@@ -462,6 +473,12 @@ fn sort_bucket_newest_first(messages: &mut [InboxMessage]) {
     });
 }
 
+/// Stable re-sort by descending priority; equal priorities keep their
+/// newest-first order.
+fn sort_bucket_priority_first(messages: &mut [InboxMessage]) {
+    messages.sort_by_key(|message| std::cmp::Reverse(message.effective_priority()));
+}
+
 fn select_display_messages(buckets: &MessageBuckets, args: &ReadArgs) -> Vec<InboxMessage> {
     let mut displayed = Vec::new();
 
@@ -490,11 +507,14 @@ fn apply_limit(displayed_messages: &mut Vec<InboxMessage>, limit: Option<usize>)
     }
 }
 
-fn display_bucket_views(displayed_messages: &[InboxMessage]) -> DisplayBuckets {
-    bucket_messages(displayed_messages.to_vec())
+fn display_bucket_views(
+    displayed_messages: &[InboxMessage],
+    priority_first: bool,
+) -> DisplayBuckets {
+    bucket_messages(displayed_messages.to_vec(), priority_first)
 }
 
-fn print_bucket(name: &str, messages: &[InboxMessage]) {
+fn print_bucket(name: &str, messages: &[InboxMessage], team_config: &TeamConfig) {
     if messages.is_empty() {
         return;
     }
@@ -515,7 +535,11 @@ fn print_bucket(name: &str, messages: &[InboxMessage]) {
             "[unread]"
         };
 
-        println!("From: {} | {} | {} {}", msg.from, time_ago, summary, status);
+        let from = match team_config.display_name(&msg.from) {
+            name if name == msg.from => msg.from.clone(),
+            name => format!("{name} ({})", msg.from),
+        };
+        println!("From: {from} | {time_ago} | {summary} {status}");
         if let Some(message_id) = msg.message_id.as_deref() {
             println!("Message ID: {message_id}");
         }
@@ -568,14 +592,11 @@ mod tests {
             );
         }
         InboxMessage {
-            from: "team-lead".to_string(),
-            source_team: None,
-            text: format!("message {message_id}"),
-            timestamp: timestamp.to_string(),
-            read,
-            summary: None,
-            message_id: Some(message_id.to_string()),
             unknown_fields,
+            ..InboxMessage::new("team-lead", format!("message {message_id}"))
+                .timestamp(timestamp.to_string())
+                .read(read)
+                .message_id(message_id.to_string())
         }
     }
 
@@ -634,6 +655,33 @@ mod tests {
         assert_eq!(ids, vec!["msg-003", "msg-002", "msg-001"]);
     }
 
+    #[test]
+    fn priority_first_orders_unread_by_priority_then_recency() {
+        let mut urgent = inbox_message("msg-urgent", "2026-02-11T09:00:00Z", false, false);
+        urgent.priority = Some(9);
+        let messages = vec![
+            inbox_message("msg-old", "2026-02-11T10:00:00Z", false, false),
+            urgent,
+            inbox_message("msg-new", "2026-02-11T11:00:00Z", false, false),
+        ];
+
+        let ids = |buckets: &MessageBuckets| -> Vec<String> {
+            buckets
+                .unread
+                .iter()
+                .map(|message| message.message_id.clone().unwrap())
+                .collect()
+        };
+        assert_eq!(
+            ids(&bucket_messages(messages.clone(), false)),
+            vec!["msg-new", "msg-old", "msg-urgent"]
+        );
+        assert_eq!(
+            ids(&bucket_messages(messages, true)),
+            vec!["msg-urgent", "msg-new", "msg-old"]
+        );
+    }
+
     #[test]
     fn history_flag_expands_active_view_instead_of_filtering() {
         let buckets = MessageBuckets {
@@ -660,6 +708,7 @@ mod tests {
             no_update_seen: true,
             limit: None,
             since: None,
+            priority_first: false,
             from: None,
             json: false,
             timeout: None,
@@ -13,15 +13,13 @@
 
 use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
 use agent_team_mail_core::io::inbox::inbox_append;
+use agent_team_mail_core::io::new_message_id;
 use agent_team_mail_core::schema::InboxMessage;
 use agent_team_mail_core::team_config_store::TeamConfigStore;
 use anyhow::Result;
-use chrono::Utc;
 use clap::Args;
-use std::collections::HashMap;
 use std::path::PathBuf;
 use tracing::warn;
-use uuid::Uuid;
 
 use crate::util::caller_identity::resolve_caller_session_id_required;
 use crate::util::settings::{get_home_dir, teams_root_dir_for};
@@ -156,6 +154,7 @@ fn register_team_lead(
         std::fs::create_dir_all(&inboxes_dir)?;
     }
 
+    let id_scheme = config.messaging.id_scheme.unwrap_or_default();
     let mut notified = 0usize;
     let notify_text = "Team-lead has registered for this session. Context may have been reset. Please provide a brief status update.";
 
@@ -164,16 +163,16 @@ fn register_team_lead(
             continue;
         }
         let inbox_path = inboxes_dir.join(format!("{}.json", member.name));
-        let msg = InboxMessage {
-            from: "team-lead".to_string(),
-            source_team: None,
-            text: notify_text.to_string(),
-            timestamp: Utc::now().to_rfc3339(),
-            read: false,
-            summary: Some("Team lead session registered".to_string()),
-            message_id: Some(Uuid::new_v4().to_string()),
-            unknown_fields: HashMap::new(),
+        let message_id = match new_message_id(id_scheme, &inbox_path) {
+            Ok(id) => id,
+            Err(e) => {
+                warn!("Failed to notify {}: {e}", member.name);
+                continue;
+            }
         };
+        let msg = InboxMessage::new("team-lead", notify_text.to_string())
+            .summary("Team lead session registered")
+            .message_id(message_id);
         match inbox_append(&inbox_path, &msg, team, &member.name) {
             Ok(_) => notified += 1,
             Err(e) => warn!("Failed to notify {}: {e}", member.name),
@@ -7,9 +7,7 @@ use agent_team_mail_core::text::{
     DEFAULT_MAX_MESSAGE_BYTES, truncate_chars, truncate_chars_slice, validate_message_text,
 };
 use anyhow::Result;
-use chrono::Utc;
 use clap::Args;
-use std::collections::HashMap;
 use std::thread::sleep;
 use std::time::{Duration, Instant};
 use uuid::Uuid;
@@ -109,16 +107,9 @@ pub fn execute(args: RequestArgs) -> Result<()> {
         .map_err(|e| anyhow::anyhow!("{e}"))?;
     let summary = generate_summary(&request_text);
 
-    let inbox_message = InboxMessage {
-        from: from_agent.clone(),
-        source_team: None,
-        text: request_text.clone(),
-        timestamp: Utc::now().to_rfc3339(),
-        read: false,
-        summary: Some(summary),
-        message_id: Some(request_id.clone()),
-        unknown_fields: HashMap::new(),
-    };
+    let inbox_message = InboxMessage::new(from_agent.clone(), request_text.clone())
+        .summary(summary)
+        .message_id(request_id.clone());
 
     // Send to destination inbox
     let inboxes_dir = to_team_dir.join("inboxes");
@@ -372,14 +363,8 @@ mod tests {
         );
 
         let msg = InboxMessage {
-            from: "responder".to_string(),
-            source_team: None,
-            text: "response body".to_string(),
-            timestamp: "2026-02-14T00:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: None,
             unknown_fields: fields,
+            ..InboxMessage::new("responder", "response body").timestamp("2026-02-14T00:00:00Z")
         };
 
         write_inbox(&inbox_path, &[msg]);
@@ -396,16 +381,9 @@ mod tests {
         let temp = TempDir::new().unwrap();
         let inbox_path = temp.path().join("inbox.json");
 
-        let msg = InboxMessage {
-            from: "responder".to_string(),
-            source_team: None,
-            text: "response body".to_string(),
-            timestamp: "2026-02-14T00:00:00Z".to_string(),
-            read: false,
-            summary: None,
-            message_id: Some("req-456".to_string()),
-            unknown_fields: HashMap::new(),
-        };
+        let msg = InboxMessage::new("responder", "response body")
+            .timestamp("2026-02-14T00:00:00Z")
+            .message_id("req-456");
 
         write_inbox(&inbox_path, &[msg]);
 
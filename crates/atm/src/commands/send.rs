@@ -1,17 +1,22 @@
 //! Send command implementation
 
 use agent_team_mail_core::config::{Config, ConfigOverrides, resolve_config, resolve_identity};
+use agent_team_mail_core::context::{TemplateVars, detect_current_branch, expand_template};
 use agent_team_mail_core::daemon_client::{RegisterHintOutcome, SessionQueryResult};
 use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
-use agent_team_mail_core::io::inbox::{WriteOutcome, inbox_append};
-use agent_team_mail_core::schema::{AgentMember, BackendType, InboxMessage, TeamConfig};
-use anyhow::Result;
+use agent_team_mail_core::io::inbox::{
+    DEFAULT_MAX_CONFLICT_RETRIES, WriteOutcome, inbox_append_bounded,
+};
+use agent_team_mail_core::io::new_message_id;
+use agent_team_mail_core::schema::{
+    AgentMember, BackendType, GroupError, InboxMessage, TeamConfig,
+};
+use anyhow::{Context, Result};
 use chrono::Utc;
 use clap::Args;
-use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tracing::{debug, info};
-use uuid::Uuid;
 
 use agent_team_mail_core::text::{
     DEFAULT_MAX_MESSAGE_BYTES, truncate_chars_slice, validate_message_text,
@@ -20,17 +25,22 @@ use agent_team_mail_core::text::{
 use crate::consts::MESSAGE_MAX_LEN;
 use crate::util::addressing::parse_address;
 use crate::util::caller_identity::resolve_caller_session_id_optional;
-use crate::util::file_policy::check_file_reference;
+use crate::util::file_policy::{check_file_reference, embed_file_body, read_file_body};
 use crate::util::hook_identity::{read_hook_file, read_hook_file_identity};
 use crate::util::settings::{get_home_dir, teams_root_dir_for};
 
-/// Send a message to a specific agent
+use super::wait::{ReadWaitResult, wait_for_read};
+
+/// Send a message to one or more specific agents
 #[derive(Args, Debug)]
 pub struct SendArgs {
-    /// Target agent (name or name@team)
-    agent: String,
+    /// Target agent(s) (name or name@team), followed by the message text
+    /// unless --message, --file, or --stdin supplies it
+    #[arg(required = true, value_name = "AGENT")]
+    targets: Vec<String>,
 
-    /// Message text (or omit to use --file or --stdin)
+    /// Message text; every positional argument is then a recipient
+    #[arg(short = 'm', long, conflicts_with = "stdin")]
     message: Option<String>,
 
     /// Override default team
@@ -41,6 +51,11 @@ pub struct SendArgs {
     #[arg(long, conflicts_with = "stdin")]
     file: Option<PathBuf>,
 
+    /// Send the --file contents as the message body instead of a reference
+    /// (the body is truncated to fit the message size limit with a warning)
+    #[arg(long, requires = "file")]
+    embed: bool,
+
     /// Read message from stdin
     #[arg(long, conflicts_with = "file")]
     stdin: bool,
@@ -49,6 +64,11 @@ pub struct SendArgs {
     #[arg(long)]
     summary: Option<String>,
 
+    /// Delivery priority 0-255; higher is read first by priority-aware
+    /// readers (default: normal, 0)
+    #[arg(long, value_name = "0-255")]
+    priority: Option<u8>,
+
     /// Output as JSON
     #[arg(long)]
     json: bool,
@@ -64,6 +84,53 @@ pub struct SendArgs {
     /// Override sender identity (default: ATM_IDENTITY env or config identity)
     #[arg(long)]
     from: Option<String>,
+
+    /// Admin override: send as this identity, bypassing sender validation
+    #[arg(long = "as", value_name = "IDENTITY", conflicts_with = "from")]
+    as_identity: Option<String>,
+
+    /// Expand {team}, {from}, {now}, and {branch} placeholders in the message
+    /// body (use {{ and }} for literal braces)
+    #[arg(long)]
+    template: bool,
+
+    /// Block until the recipient marks the message read; exits nonzero on timeout
+    #[arg(long)]
+    confirm_read: bool,
+
+    /// How long --confirm-read waits (e.g. 90, 60s, 5m, 1h)
+    #[arg(
+        long,
+        requires = "confirm_read",
+        default_value = "60s",
+        value_parser = parse_confirm_timeout
+    )]
+    timeout: Duration,
+}
+
+/// A validated recipient: the agent exists in its team's roster.
+struct Recipient {
+    agent_name: String,
+    team_name: String,
+    team_dir: PathBuf,
+    team_config: TeamConfig,
+}
+
+/// A message fully prepared for one recipient, ready to append.
+struct PreparedSend {
+    recipient: Recipient,
+    inbox_path: PathBuf,
+    inbox_message: InboxMessage,
+    final_message_text: String,
+    summary: String,
+}
+
+/// Delivery result for one recipient of a multi-recipient send.
+struct DeliveryStatus {
+    agent_name: String,
+    team_name: String,
+    message_id: Option<String>,
+    outcome: Result<WriteOutcome>,
 }
 
 /// Execute the send command
@@ -82,8 +149,20 @@ pub fn execute(args: SendArgs) -> Result<()> {
 
     let mut config = resolve_config(&overrides, &current_dir, &home_dir)?;
 
-    // Override sender identity if --from provided; otherwise resolve via hook file.
-    if let Some(ref from) = args.from {
+    // Override sender identity if --as/--from provided; otherwise resolve via hook file.
+    if let Some(ref as_identity) = args.as_identity {
+        config.core.identity = as_identity.clone();
+        emit_event_best_effort(EventFields {
+            level: "warn",
+            source: "atm",
+            action: "send_identity_override",
+            team: Some(sender_team.clone()),
+            agent_id: Some(as_identity.clone()),
+            agent_name: Some(as_identity.clone()),
+            result: Some("ok".to_string()),
+            ..Default::default()
+        });
+    } else if let Some(ref from) = args.from {
         // --from always wins.
         config.core.identity = from.clone();
     } else if config.core.identity == "human" {
@@ -109,10 +188,403 @@ pub fn execute(args: SendArgs) -> Result<()> {
     }
     // else: identity was explicitly configured (ATM_IDENTITY, .atm.toml) — use as-is.
 
+    if config.messaging.validate_sender == Some(true) && args.as_identity.is_none() {
+        validate_sender_identity(&config.core.identity, &sender_team, &home_dir)?;
+    }
+
+    let (targets, inline_message) = split_targets(&args)?;
+
+    // Validate every recipient against its roster before anything is written,
+    // so an unknown agent never leaves a partial delivery behind.
+    let mut recipients: Vec<Recipient> = Vec::with_capacity(targets.len());
+    for (index, target) in targets.iter().enumerate() {
+        let resolved = resolve_recipients(target, &args, &config, &sender_team, &home_dir);
+        // With --stdin an extra positional is more likely a stray inline
+        // message than a second address, so say so rather than just
+        // reporting an unknown agent.
+        let resolved = if args.stdin && index > 0 {
+            resolved.with_context(|| {
+                format!(
+                    "Cannot combine a positional message ({target:?}) with --stdin; \
+                     extra positionals must name team members or groups"
+                )
+            })?
+        } else {
+            resolved?
+        };
+        for recipient in resolved {
+            if !recipients
+                .iter()
+                .any(|r| r.agent_name == recipient.agent_name && r.team_name == recipient.team_name)
+            {
+                recipients.push(recipient);
+            }
+        }
+    }
+    if recipients.len() > 1 && args.confirm_read {
+        anyhow::bail!("--confirm-read supports a single recipient");
+    }
+
+    // Get message text from appropriate source
+    let message_text = get_message_text(&args, inline_message)?;
+    let embedded_body = match args.file {
+        Some(ref path) if args.embed => Some(read_file_body(path)?),
+        _ => None,
+    };
+
+    // Resolve sender session once so concurrent same-identity sessions can be
+    // disambiguated deterministically.
+    let sender_session_id =
+        resolve_sender_session_id_with_context(Some(&sender_team), Some(&config.core.identity))?;
+
+    let mut prepared: Vec<PreparedSend> = Vec::with_capacity(recipients.len());
+    for recipient in recipients {
+        prepared.push(prepare_send(
+            recipient,
+            &message_text,
+            embedded_body.as_deref(),
+            &args,
+            &config,
+            &sender_team,
+            sender_session_id.as_deref(),
+            &current_dir,
+            &home_dir,
+        )?);
+    }
+
+    // One hint per send, in the (first) recipient's team; a dry run registers
+    // nothing.
+    if !args.dry_run
+        && let Some(first) = prepared.first()
+    {
+        register_sender_hint(
+            &first.recipient.team_name,
+            &config.core.identity,
+            &first.recipient.team_config,
+            sender_session_id.as_deref(),
+        )?;
+    }
+
+    if prepared.len() > 1 {
+        return execute_multi(&prepared, &args, &config, &sender_team, sender_session_id);
+    }
+    let PreparedSend {
+        recipient,
+        inbox_path,
+        inbox_message,
+        final_message_text,
+        summary,
+    } = prepared.remove(0);
+    let Recipient {
+        agent_name,
+        team_name,
+        team_config,
+        ..
+    } = recipient;
+
+    // Dry run output
+    if args.dry_run {
+        let destination = destination_target(&agent_name, &team_name);
+        emit_event_best_effort(EventFields {
+            level: "info",
+            source: "atm",
+            action: "send_dry_run",
+            team: Some(team_name.clone()),
+            session_id: sender_session_id.clone(),
+            agent_id: Some(config.core.identity.clone()),
+            agent_name: Some(config.core.identity.clone()),
+            target: Some(destination),
+            result: Some("ok".to_string()),
+            message_text: Some(final_message_text.clone()),
+            ..Default::default()
+        });
+        if args.json {
+            let output = serde_json::json!({
+                "action": "send",
+                "agent": agent_name,
+                "team": team_name,
+                "message": inbox_message,
+                "dry_run": true
+            });
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        } else {
+            println!("Dry run - would send message:");
+            println!("  To: {agent_name}@{team_name}");
+            println!("  From: {}", inbox_message.from);
+            println!("  Summary: {summary}");
+            println!("  Message: {final_message_text}");
+        }
+        return Ok(());
+    }
+
+    let outcome = deliver(
+        &inbox_path,
+        &inbox_message,
+        &agent_name,
+        &team_name,
+        &team_config,
+        &final_message_text,
+        &config,
+        &sender_team,
+        sender_session_id.as_deref(),
+    )?;
+
+    // Wait for the recipient to read the message when requested.
+    let read_confirmed = if args.confirm_read {
+        let message_id = inbox_message.message_id.as_deref().unwrap_or_default();
+        if !args.json {
+            eprintln!(
+                "Waiting for {agent_name}@{team_name} to read message {message_id} (timeout: {}s)...",
+                args.timeout.as_secs()
+            );
+        }
+        let result = wait_for_read(
+            &team_name,
+            &agent_name,
+            &inbox_path,
+            message_id,
+            args.timeout,
+        )?;
+        let confirmed = result == ReadWaitResult::Read;
+        emit_event_best_effort(EventFields {
+            level: if confirmed { "info" } else { "warn" },
+            source: "atm",
+            action: "send_confirm_read",
+            team: Some(team_name.clone()),
+            agent_id: Some(config.core.identity.clone()),
+            target: Some(destination_target(&agent_name, &team_name)),
+            message_id: inbox_message.message_id.clone(),
+            result: Some(if confirmed { "read" } else { "timeout" }.to_string()),
+            ..Default::default()
+        });
+        Some(confirmed)
+    } else {
+        None
+    };
+
+    // Query the daemon for agent state to enrich the output (best-effort, silent fallback).
+    let agent_state_info =
+        agent_team_mail_core::daemon_client::query_agent_state(&agent_name, &team_name)
+            .unwrap_or(None); // Ignore errors; daemon may not be running
+
+    // Output result
+    if args.json {
+        let mut output = serde_json::json!({
+            "action": "send",
+            "agent": agent_name,
+            "team": team_name,
+            "outcome": outcome_label(&outcome),
+            "message_id": inbox_message.message_id,
+        });
+        if let Some(confirmed) = read_confirmed {
+            output["read_confirmed"] = serde_json::json!(confirmed);
+        }
+        if let Some(ref info) = agent_state_info {
+            output["agent_state"] = serde_json::json!({
+                "state": info.state,
+                "last_transition": info.last_transition,
+            });
+        }
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        match outcome {
+            WriteOutcome::Success => {
+                println!("Message sent to {agent_name}@{team_name}");
+            }
+            WriteOutcome::ConflictResolved { merged_messages } => {
+                println!(
+                    "Message sent to {agent_name}@{team_name} (merged {merged_messages} concurrent messages)"
+                );
+            }
+            WriteOutcome::Queued { ref spool_path } => {
+                eprintln!(
+                    "Warning: Message queued for delivery (could not write to inbox immediately)"
+                );
+                eprintln!("Spool path: {spool_path:?}");
+            }
+        }
+        // Print enriched agent state info when daemon is running
+        if let Some(ref info) = agent_state_info {
+            println!("  agent-state: {}", info.state);
+        }
+        if read_confirmed == Some(true) {
+            println!("Message read by {agent_name}@{team_name}");
+        }
+    }
+
+    if read_confirmed == Some(false) {
+        anyhow::bail!(
+            "Timed out after {}s waiting for {agent_name}@{team_name} to read message {}",
+            args.timeout.as_secs(),
+            inbox_message.message_id.as_deref().unwrap_or_default()
+        );
+    }
+
+    Ok(())
+}
+
+/// Deliver prepared messages to several recipients and report each outcome.
+///
+/// A failed delivery does not stop the remaining ones; the command exits
+/// nonzero afterwards if any recipient failed.
+fn execute_multi(
+    prepared: &[PreparedSend],
+    args: &SendArgs,
+    config: &Config,
+    sender_team: &str,
+    sender_session_id: Option<String>,
+) -> Result<()> {
+    if args.dry_run {
+        if args.json {
+            let messages: Vec<serde_json::Value> = prepared
+                .iter()
+                .map(|p| {
+                    serde_json::json!({
+                        "agent": p.recipient.agent_name,
+                        "team": p.recipient.team_name,
+                        "message": p.inbox_message,
+                    })
+                })
+                .collect();
+            let output = serde_json::json!({
+                "action": "send",
+                "recipients": messages,
+                "dry_run": true
+            });
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        } else {
+            println!("Dry run - would send message:");
+            for p in prepared {
+                println!("  To: {}@{}", p.recipient.agent_name, p.recipient.team_name);
+            }
+            if let Some(first) = prepared.first() {
+                println!("  From: {}", first.inbox_message.from);
+                println!("  Summary: {}", first.summary);
+                println!("  Message: {}", first.final_message_text);
+            }
+        }
+        return Ok(());
+    }
+
+    let statuses: Vec<DeliveryStatus> = prepared
+        .iter()
+        .map(|p| DeliveryStatus {
+            agent_name: p.recipient.agent_name.clone(),
+            team_name: p.recipient.team_name.clone(),
+            message_id: p.inbox_message.message_id.clone(),
+            outcome: deliver(
+                &p.inbox_path,
+                &p.inbox_message,
+                &p.recipient.agent_name,
+                &p.recipient.team_name,
+                &p.recipient.team_config,
+                &p.final_message_text,
+                config,
+                sender_team,
+                sender_session_id.as_deref(),
+            ),
+        })
+        .collect();
+
+    let failed_count = statuses.iter().filter(|s| s.outcome.is_err()).count();
+    if args.json {
+        let recipients: Vec<serde_json::Value> = statuses
+            .iter()
+            .map(|s| {
+                let mut entry = serde_json::json!({
+                    "agent": s.agent_name,
+                    "team": s.team_name,
+                    "message_id": s.message_id,
+                });
+                match &s.outcome {
+                    Ok(outcome) => entry["outcome"] = serde_json::json!(outcome_label(outcome)),
+                    Err(e) => {
+                        entry["outcome"] = serde_json::json!("failed");
+                        entry["error"] = serde_json::json!(e.to_string());
+                    }
+                }
+                entry
+            })
+            .collect();
+        let output = serde_json::json!({
+            "action": "send",
+            "recipients": recipients,
+            "summary": {
+                "total": statuses.len(),
+                "failed": failed_count,
+            },
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        for status in &statuses {
+            let target = destination_target(&status.agent_name, &status.team_name);
+            match &status.outcome {
+                Ok(WriteOutcome::Success) => println!("  ✓ {target}"),
+                Ok(WriteOutcome::ConflictResolved { merged_messages }) => {
+                    println!("  ✓ {target} (merged {merged_messages} concurrent messages)")
+                }
+                Ok(WriteOutcome::Queued { spool_path }) => {
+                    println!("  ⏳ {target} (queued: {spool_path:?})")
+                }
+                Err(e) => println!("  ✗ {target}: {e}"),
+            }
+        }
+        println!(
+            "Message sent to {}/{} recipients",
+            statuses.len() - failed_count,
+            statuses.len()
+        );
+    }
+
+    if failed_count > 0 {
+        anyhow::bail!("Send completed with {failed_count} failed deliveries");
+    }
+    Ok(())
+}
+
+/// Split positional arguments into recipients and an optional inline message.
+///
+/// With `--message` or `--stdin` every positional is a recipient, as is a
+/// lone positional with `--file`. Otherwise the last positional is the
+/// message text (`atm send <agent>... <message>`). A positional containing
+/// whitespace cannot be an address, so with `--stdin` it is rejected as a
+/// conflicting inline message; `execute` rejects the other extra positionals
+/// that name no roster member or group.
+fn split_targets(args: &SendArgs) -> Result<(&[String], Option<&str>)> {
+    if args.stdin
+        && let Some(text) = args
+            .targets
+            .iter()
+            .find(|t| t.chars().any(char::is_whitespace))
+    {
+        anyhow::bail!(
+            "Cannot combine a positional message ({text:?}) with --stdin; \
+             pipe the whole body on stdin instead"
+        );
+    }
+    if args.message.is_some() || args.stdin || (args.file.is_some() && args.targets.len() == 1) {
+        return Ok((&args.targets, args.message.as_deref()));
+    }
+    match args.targets.split_last() {
+        Some((message, targets)) if !targets.is_empty() => Ok((targets, Some(message.as_str()))),
+        _ => anyhow::bail!("Message required: provide message text, --message, --file, or --stdin"),
+    }
+}
+
+/// Resolve a target address to its recipients and verify each is on the roster.
+///
+/// A name that is not a roster member but matches one of the team's `groups`
+/// expands to the group's members, excluding the sender.
+fn resolve_recipients(
+    target: &str,
+    args: &SendArgs,
+    config: &Config,
+    sender_team: &str,
+    home_dir: &Path,
+) -> Result<Vec<Recipient>> {
     // Parse addressing (agent@team or just agent) first so alias lookup runs on
     // only the agent token, even when input uses @team suffix.
-    let (parsed_agent, team_name) =
-        parse_address(&args.agent, &args.team, &config.core.default_team)?;
+    let (parsed_agent, team_name) = parse_address(target, &args.team, &config.core.default_team)?;
     let agent_name = resolve_identity(&parsed_agent, &config.roles, &config.aliases);
     if agent_name != parsed_agent {
         eprintln!(
@@ -122,7 +594,7 @@ pub fn execute(args: SendArgs) -> Result<()> {
     }
 
     // Resolve team directory
-    let team_dir = teams_root_dir_for(&home_dir).join(&team_name);
+    let team_dir = teams_root_dir_for(home_dir).join(&team_name);
     if !team_dir.exists() {
         anyhow::bail!("Team '{team_name}' not found (directory {team_dir:?} doesn't exist)");
     }
@@ -137,32 +609,94 @@ pub fn execute(args: SendArgs) -> Result<()> {
         serde_json::from_str(&std::fs::read_to_string(&team_config_path)?)?;
 
     // Verify agent exists in team
-    if !team_config.members.iter().any(|m| m.name == agent_name) {
-        anyhow::bail!("Agent '{agent_name}' not found in team '{team_name}'");
-    }
+    if team_config.members.iter().any(|m| m.name == agent_name) {
+        return Ok(vec![Recipient {
+            agent_name,
+            team_name,
+            team_dir,
+            team_config,
+        }]);
+    }
+
+    let group = match team_config.expand_group(&agent_name) {
+        Ok(group) => group,
+        Err(GroupError::NotFound { .. }) => {
+            let suggestions = team_config.suggest_recipients(&agent_name);
+            if suggestions.is_empty() {
+                anyhow::bail!("Agent '{agent_name}' not found in team '{team_name}'");
+            }
+            anyhow::bail!(
+                "Agent '{agent_name}' not found in team '{team_name}' (did you mean: {}?)",
+                suggestions.join(", ")
+            );
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let members: Vec<String> = group
+        .iter()
+        .filter(|name| !(**name == config.core.identity && team_name == sender_team))
+        .cloned()
+        .collect();
+    if members.is_empty() {
+        anyhow::bail!("Group '{agent_name}' in team '{team_name}' has no members besides self");
+    }
+    eprintln!(
+        "Note: group '{agent_name}' expanded to {}",
+        members.join(", ")
+    );
 
-    // Get message text from appropriate source
-    let message_text = get_message_text(&args)?;
+    Ok(members
+        .into_iter()
+        .map(|member| Recipient {
+            agent_name: member,
+            team_name: team_name.clone(),
+            team_dir: team_dir.clone(),
+            team_config: team_config.clone(),
+        })
+        .collect())
+}
 
-    // Resolve sender session once so concurrent same-identity sessions can be
-    // disambiguated deterministically.
-    let sender_session_id =
-        resolve_sender_session_id_with_context(Some(&sender_team), Some(&config.core.identity))?;
+/// Build the final message for one recipient: template expansion, self-send
+/// warning, file reference, offline call-to-action, and validation.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "per-recipient preparation needs sender context resolved once in execute"
+)]
+fn prepare_send(
+    recipient: Recipient,
+    message_text: &str,
+    embedded_body: Option<&str>,
+    args: &SendArgs,
+    config: &Config,
+    sender_team: &str,
+    sender_session_id: Option<&str>,
+    current_dir: &Path,
+    home_dir: &Path,
+) -> Result<PreparedSend> {
+    let Recipient {
+        ref agent_name,
+        ref team_name,
+        ref team_dir,
+        ..
+    } = recipient;
+
+    let message_text = if args.template {
+        expand_message_template(message_text, team_name, &config.core.identity, current_dir)?
+    } else {
+        message_text.to_string()
+    };
 
     // Self-send check: warn and prepend warning only when sender/recipient are
     // same identity in same team and daemon session ownership points to this
     // session (or ownership is unknown).
     let message_text = if should_warn_self_send(
         &config.core.identity,
-        &sender_team,
-        &agent_name,
-        &team_name,
-        sender_session_id.as_deref(),
+        sender_team,
+        agent_name,
+        team_name,
+        sender_session_id,
     ) {
-        let session_id = sender_session_id
-            .as_deref()
-            .map(str::to_string)
-            .unwrap_or_else(|| "unknown".to_string());
+        let session_id = sender_session_id.unwrap_or("unknown");
         let session_short = &session_id[..8.min(session_id.len())];
         let warning = format!(
             "[WARNING: Sent to self — identity={}, session={}. Check ATM_IDENTITY.]",
@@ -174,134 +708,148 @@ pub fn execute(args: SendArgs) -> Result<()> {
         message_text
     };
 
-    // Process file reference if provided
-    let mut final_message_text = if let Some(ref file_path) = args.file {
-        process_file_reference(
-            file_path,
-            &message_text,
-            &team_name,
-            &current_dir,
-            &home_dir,
-        )?
-    } else {
-        message_text
-    };
-
-    // Check if recipient is offline and prepend action text.
+    // Check if recipient is offline and build the action text prefix.
     // Liveness truth comes from daemon session state; isActive is activity-only.
-    let recipient_offline = recipient_has_dead_session(&team_name, &agent_name);
-
-    if recipient_offline {
-        let action_text = resolve_offline_action(&args, &config);
+    let mut offline_prefix = String::new();
+    if recipient_has_dead_session(team_name, agent_name) {
+        let action_text = resolve_offline_action(args, config);
         if !action_text.is_empty() {
             eprintln!(
                 "Warning: Agent '{agent_name}' appears offline. Message will be queued with call-to-action."
             );
-            final_message_text = format!("[{action_text}] {final_message_text}");
+            offline_prefix = format!("[{action_text}] ");
         }
     }
 
+    // Process file reference if provided. Embedded contents are cut to leave
+    // room for every prefix and notice, so only the file body is truncated.
+    let final_message_text = match (&args.file, embedded_body) {
+        (Some(file_path), Some(body)) => {
+            let head = if message_text.is_empty() {
+                String::new()
+            } else {
+                format!("{message_text}\n\n")
+            };
+            embed_file_body(
+                file_path,
+                &head,
+                body,
+                offline_prefix.len(),
+                DEFAULT_MAX_MESSAGE_BYTES,
+            )
+        }
+        (Some(file_path), None) => {
+            process_file_reference(file_path, &message_text, team_name, current_dir, home_dir)?
+        }
+        (None, _) => message_text,
+    };
+    let final_message_text = format!("{offline_prefix}{final_message_text}");
+
     // Validate final payload text after all expansions/rewrites.
     validate_message_text(&final_message_text, DEFAULT_MAX_MESSAGE_BYTES)
         .map_err(|e| anyhow::anyhow!("{e}"))?;
 
-    register_sender_hint(
-        &team_name,
-        &config.core.identity,
-        &team_config,
-        sender_session_id.as_deref(),
-    )?;
-
     // Generate summary
     let summary = args
         .summary
+        .clone()
         .unwrap_or_else(|| generate_summary(&final_message_text));
 
     // Create inbox message
+    let inbox_path = team_dir.join("inboxes").join(format!("{agent_name}.json"));
+    // Ids are only allocated for a real write; counter ids would otherwise
+    // advance the recipient's sequence on every dry run.
+    let message_id = if args.dry_run {
+        None
+    } else {
+        Some(new_message_id(
+            config.messaging.id_scheme.unwrap_or_default(),
+            &inbox_path,
+        )?)
+    };
     let inbox_message = build_inbox_message(
         config.core.identity.clone(),
-        Some(sender_team.clone()),
+        Some(sender_team.to_string()),
         final_message_text.clone(),
         Some(summary.clone()),
+        message_id,
+        args.priority,
     );
 
-    // Dry run output
-    if args.dry_run {
-        let destination = destination_target(&agent_name, &team_name);
-        emit_event_best_effort(EventFields {
-            level: "info",
-            source: "atm",
-            action: "send_dry_run",
-            team: Some(team_name.clone()),
-            session_id: sender_session_id.clone(),
-            agent_id: Some(config.core.identity.clone()),
-            agent_name: Some(config.core.identity.clone()),
-            target: Some(destination),
-            result: Some("ok".to_string()),
-            message_text: Some(final_message_text.clone()),
-            ..Default::default()
-        });
-        if args.json {
-            let output = serde_json::json!({
-                "action": "send",
-                "agent": agent_name,
-                "team": team_name,
-                "message": inbox_message,
-                "dry_run": true
-            });
-            println!("{}", serde_json::to_string_pretty(&output)?);
-        } else {
-            println!("Dry run - would send message:");
-            println!("  To: {agent_name}@{team_name}");
-            println!("  From: {}", inbox_message.from);
-            println!("  Summary: {summary}");
-            println!("  Message: {final_message_text}");
-        }
-        return Ok(());
-    }
-
-    // Write to inbox
-    let inbox_path = team_dir.join("inboxes").join(format!("{agent_name}.json"));
+    Ok(PreparedSend {
+        recipient,
+        inbox_path,
+        inbox_message,
+        final_message_text,
+        summary,
+    })
+}
 
+/// Append a prepared message to the recipient inbox, emit the `send` event,
+/// and refresh the sender's daemon session on successful delivery.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "delivery reports sender and recipient context in the send event"
+)]
+fn deliver(
+    inbox_path: &Path,
+    inbox_message: &InboxMessage,
+    agent_name: &str,
+    team_name: &str,
+    team_config: &TeamConfig,
+    final_message_text: &str,
+    config: &Config,
+    sender_team: &str,
+    sender_session_id: Option<&str>,
+) -> Result<WriteOutcome> {
     // Ensure inboxes directory exists
-    let inboxes_dir = team_dir.join("inboxes");
-    if !inboxes_dir.exists() {
-        std::fs::create_dir_all(&inboxes_dir)?;
-    }
-
-    let outcome = inbox_append(&inbox_path, &inbox_message, &team_name, &agent_name)?;
-    let (result_text, conflict_count): (&str, Option<u64>) = match &outcome {
-        WriteOutcome::Success => ("success", None),
-        WriteOutcome::ConflictResolved { merged_messages } => {
-            ("conflict_resolved", Some(*merged_messages as u64))
-        }
-        WriteOutcome::Queued { .. } => ("queued", None),
+    if let Some(inboxes_dir) = inbox_path.parent()
+        && !inboxes_dir.exists()
+    {
+        std::fs::create_dir_all(inboxes_dir)?;
+    }
+
+    let max_conflict_retries = config
+        .messaging
+        .max_conflict_retries
+        .unwrap_or(DEFAULT_MAX_CONFLICT_RETRIES);
+    let outcome = inbox_append_bounded(
+        inbox_path,
+        inbox_message,
+        team_name,
+        agent_name,
+        max_conflict_retries,
+    )?;
+    let conflict_count = match &outcome {
+        WriteOutcome::ConflictResolved { merged_messages } => Some(*merged_messages as u64),
+        _ => None,
     };
-    let destination = destination_target(&agent_name, &team_name);
+    let result_text = outcome_label(&outcome);
+    let destination = destination_target(agent_name, team_name);
     let sender_pid = team_config
         .members
         .iter()
         .find(|m| m.name == config.core.identity)
         .and_then(detect_sender_process_pid);
-    let recipient_pid = member_pid_hint(&team_config, &agent_name);
+    let recipient_pid = member_pid_hint(team_config, agent_name);
     emit_event_best_effort(EventFields {
         level: "info",
         source: "atm",
         action: "send",
-        team: Some(team_name.clone()),
-        session_id: sender_session_id.clone(),
+        team: Some(team_name.to_string()),
+        session_id: sender_session_id.map(str::to_string),
         agent_id: Some(config.core.identity.clone()),
         agent_name: Some(config.core.identity.clone()),
         target: Some(destination),
         result: Some(result_text.to_string()),
         message_id: inbox_message.message_id.clone(),
         count: conflict_count,
-        message_text: Some(final_message_text.clone()),
+        message_text: Some(final_message_text.to_string()),
         sender_agent: Some(config.core.identity.clone()),
-        sender_team: Some(sender_team.clone()),
+        sender_team: Some(sender_team.to_string()),
         sender_pid,
-        recipient_agent: Some(agent_name.clone()),
-        recipient_team: Some(team_name.clone()),
+        recipient_agent: Some(agent_name.to_string()),
+        recipient_team: Some(team_name.to_string()),
         recipient_pid,
         ..Default::default()
     });
@@ -317,11 +865,11 @@ pub fn execute(args: SendArgs) -> Result<()> {
         outcome,
         WriteOutcome::Success | WriteOutcome::ConflictResolved { .. }
     ) {
-        let _ = touch_sender_session_heartbeat(&team_name, &config.core.identity);
+        let _ = touch_sender_session_heartbeat(team_name, &config.core.identity);
         if should_emit_post_send_idle_transition() {
-            if let Some(ref session_id) = sender_session_id {
+            if let Some(session_id) = sender_session_id {
                 let _ = agent_team_mail_core::daemon_client::emit_teammate_idle_best_effort(
-                    &team_name,
+                    team_name,
                     &config.core.identity,
                     session_id,
                 );
@@ -329,79 +877,117 @@ pub fn execute(args: SendArgs) -> Result<()> {
         }
     }
 
-    // Query the daemon for agent state to enrich the output (best-effort, silent fallback).
-    let agent_state_info =
-        agent_team_mail_core::daemon_client::query_agent_state(&agent_name, &team_name)
-            .unwrap_or(None); // Ignore errors; daemon may not be running
+    Ok(outcome)
+}
 
-    // Output result
-    if args.json {
-        let mut output = serde_json::json!({
-            "action": "send",
-            "agent": agent_name,
-            "team": team_name,
-            "outcome": match outcome {
-                WriteOutcome::Success => "success",
-                WriteOutcome::ConflictResolved { .. } => "conflict_resolved",
-                WriteOutcome::Queued { .. } => "queued",
-            },
-            "message_id": inbox_message.message_id,
-        });
-        if let Some(ref info) = agent_state_info {
-            output["agent_state"] = serde_json::json!({
-                "state": info.state,
-                "last_transition": info.last_transition,
-            });
-        }
-        println!("{}", serde_json::to_string_pretty(&output)?);
-    } else {
-        match outcome {
-            WriteOutcome::Success => {
-                println!("Message sent to {agent_name}@{team_name}");
-            }
-            WriteOutcome::ConflictResolved { merged_messages } => {
-                println!(
-                    "Message sent to {agent_name}@{team_name} (merged {merged_messages} concurrent messages)"
-                );
-            }
-            WriteOutcome::Queued { ref spool_path } => {
-                eprintln!(
-                    "Warning: Message queued for delivery (could not write to inbox immediately)"
-                );
-                eprintln!("Spool path: {spool_path:?}");
-            }
-        }
-        // Print enriched agent state info when daemon is running
-        if let Some(ref info) = agent_state_info {
-            println!("  agent-state: {}", info.state);
-        }
+/// Machine-readable label for a write outcome.
+fn outcome_label(outcome: &WriteOutcome) -> &'static str {
+    match outcome {
+        WriteOutcome::Success => "success",
+        WriteOutcome::ConflictResolved { .. } => "conflict_resolved",
+        WriteOutcome::Queued { .. } => "queued",
     }
+}
 
-    Ok(())
+/// Parse a `--timeout` value: bare seconds or a number with an `s`, `m`, or
+/// `h` suffix.
+fn parse_confirm_timeout(raw: &str) -> std::result::Result<Duration, String> {
+    let raw = raw.trim();
+    let split = raw.find(|c: char| !c.is_ascii_digit()).unwrap_or(raw.len());
+    let (digits, unit) = raw.split_at(split);
+    let value: u64 = digits
+        .parse()
+        .map_err(|_| format!("invalid timeout '{raw}' (expected e.g. 60, 60s, 5m, 1h)"))?;
+    let secs = match unit {
+        "" | "s" => value,
+        "m" => value.saturating_mul(60),
+        "h" => value.saturating_mul(3600),
+        _ => return Err(format!("unknown timeout unit '{unit}' (use s, m, or h)")),
+    };
+    if secs == 0 {
+        return Err("timeout must be greater than zero".to_string());
+    }
+    Ok(Duration::from_secs(secs))
 }
 
-/// Get message text from args, stdin, or file
-fn get_message_text(args: &SendArgs) -> Result<String> {
+/// Reject sender identities that are neither a member of `sender_team` nor
+/// the process's `ATM_IDENTITY`.
+fn validate_sender_identity(identity: &str, sender_team: &str, home_dir: &Path) -> Result<()> {
+    if std::env::var("ATM_IDENTITY")
+        .ok()
+        .is_some_and(|env_identity| env_identity == identity)
+    {
+        return Ok(());
+    }
+
+    let team_config_path = teams_root_dir_for(home_dir)
+        .join(sender_team)
+        .join("config.json");
+    let is_member = std::fs::read_to_string(&team_config_path)
+        .ok()
+        .and_then(|raw| serde_json::from_str::<TeamConfig>(&raw).ok())
+        .is_some_and(|cfg| cfg.members.iter().any(|m| m.name == identity));
+    if is_member {
+        return Ok(());
+    }
+
+    anyhow::bail!(
+        "Sender identity '{identity}' is not a member of team '{sender_team}' and does not \
+         match ATM_IDENTITY. Use --as <identity> to override."
+    )
+}
+
+/// Get message text from args or stdin
+///
+/// With `--file` the text is only the accompanying message; the reference or
+/// embedded contents are added per recipient in [`prepare_send`].
+fn get_message_text(args: &SendArgs, inline_message: Option<&str>) -> Result<String> {
     if args.stdin {
-        // Read from stdin
-        use std::io::Read;
-        let mut buffer = String::new();
-        std::io::stdin().read_to_string(&mut buffer)?;
-        Ok(buffer)
-    } else if let Some(ref message) = args.message {
+        read_message_body(std::io::stdin().lock())
+    } else if let Some(message) = inline_message {
         // Direct message argument — reject blank messages at CLI layer
         if message.trim().is_empty() {
             anyhow::bail!("Message text cannot be empty");
         }
-        Ok(message.clone())
+        Ok(message.to_string())
     } else if args.file.is_some() {
-        // Message is file path reference
+        // Message is the file reference (or, with --embed, its contents)
         Ok(String::new())
     } else {
         anyhow::bail!("Message required: provide message text, --file, or --stdin");
     }
 }
 
+/// Read a message body until EOF, byte-for-byte.
+///
+/// Trailing newlines are kept so code blocks arrive intact.
+fn read_message_body(mut reader: impl std::io::Read) -> Result<String> {
+    let mut buffer = String::new();
+    reader
+        .read_to_string(&mut buffer)
+        .context("Failed to read message body from stdin")?;
+    Ok(buffer)
+}
+
+/// Expand template placeholders in the message body (`--template`).
+///
+/// `{branch}` is resolved from the git repository containing `current_dir`
+/// and is only required when the template actually references it.
+fn expand_message_template(
+    body: &str,
+    team_name: &str,
+    identity: &str,
+    current_dir: &Path,
+) -> Result<String> {
+    let vars = TemplateVars {
+        team: team_name.to_string(),
+        from: identity.to_string(),
+        now: Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        branch: detect_current_branch(current_dir),
+    };
+    expand_template(body, &vars).map_err(|e| anyhow::anyhow!("--template: {e}"))
+}
+
 /// Process file reference and check access policy
 fn process_file_reference(
     file_path: &Path,
@@ -562,16 +1148,15 @@ fn build_inbox_message(
     source_team: Option<String>,
     text: String,
     summary: Option<String>,
+    message_id: Option<String>,
+    priority: Option<u8>,
 ) -> InboxMessage {
     InboxMessage {
-        from,
         source_team,
-        text,
-        timestamp: Utc::now().to_rfc3339(),
-        read: false,
         summary,
-        message_id: Some(Uuid::new_v4().to_string()),
-        unknown_fields: HashMap::new(),
+        message_id,
+        priority,
+        ..InboxMessage::new(from, text)
     }
 }
 
@@ -778,6 +1363,8 @@ mod tests {
             Some("src-gen".to_string()),
             "cross-team note".to_string(),
             Some("cross-team note".to_string()),
+            Some("msg-1".to_string()),
+            None,
         );
 
         assert_eq!(msg.from, "team-lead");
@@ -793,6 +1380,8 @@ mod tests {
             Some("atm-dev".to_string()),
             "same-team note".to_string(),
             Some("same-team note".to_string()),
+            Some("msg-1".to_string()),
+            None,
         );
 
         assert_eq!(msg.from, "team-lead");
@@ -801,18 +1390,47 @@ mod tests {
         assert!(!msg.read);
     }
 
+    #[test]
+    fn test_parse_confirm_timeout_units() {
+        assert_eq!(
+            parse_confirm_timeout("90").unwrap(),
+            Duration::from_secs(90)
+        );
+        assert_eq!(
+            parse_confirm_timeout("60s").unwrap(),
+            Duration::from_secs(60)
+        );
+        assert_eq!(
+            parse_confirm_timeout("5m").unwrap(),
+            Duration::from_secs(300)
+        );
+        assert_eq!(
+            parse_confirm_timeout("1h").unwrap(),
+            Duration::from_secs(3600)
+        );
+        assert!(parse_confirm_timeout("0s").is_err());
+        assert!(parse_confirm_timeout("10d").is_err());
+        assert!(parse_confirm_timeout("soon").is_err());
+    }
+
     fn make_send_args(offline_action: Option<String>) -> SendArgs {
         SendArgs {
-            agent: "test-agent".to_string(),
+            targets: vec!["test-agent".to_string()],
             message: Some("test".to_string()),
             team: None,
             file: None,
+            embed: false,
             stdin: false,
             summary: None,
+            priority: None,
             json: false,
             dry_run: false,
             offline_action,
             from: None,
+            as_identity: None,
+            template: false,
+            confirm_read: false,
+            timeout: Duration::from_secs(60),
         }
     }
 
@@ -920,6 +1538,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_split_targets_last_positional_is_message() {
+        let mut args = make_send_args(None);
+        args.message = None;
+        args.targets = vec!["a".into(), "b@other".into(), "hello".into()];
+        let (targets, message) = split_targets(&args).unwrap();
+        assert_eq!(targets, ["a".to_string(), "b@other".to_string()]);
+        assert_eq!(message, Some("hello"));
+    }
+
+    #[test]
+    fn test_split_targets_message_flag_makes_all_positionals_recipients() {
+        let mut args = make_send_args(None);
+        args.targets = vec!["a".into(), "b".into()];
+        let (targets, message) = split_targets(&args).unwrap();
+        assert_eq!(targets.len(), 2);
+        assert_eq!(message, Some("test"));
+    }
+
+    #[test]
+    fn test_split_targets_lone_positional_with_file() {
+        let mut args = make_send_args(None);
+        args.message = None;
+        args.file = Some(PathBuf::from("notes.md"));
+        let (targets, message) = split_targets(&args).unwrap();
+        assert_eq!(targets, ["test-agent".to_string()]);
+        assert_eq!(message, None);
+    }
+
+    #[test]
+    fn test_split_targets_requires_message() {
+        let mut args = make_send_args(None);
+        args.message = None;
+        assert!(split_targets(&args).is_err());
+    }
+
+    #[test]
+    fn test_split_targets_rejects_positional_message_with_stdin() {
+        let mut args = make_send_args(None);
+        args.message = None;
+        args.stdin = true;
+        args.targets = vec!["test-agent".into(), "hello there".into()];
+        let err = split_targets(&args).unwrap_err();
+        assert!(err.to_string().contains("--stdin"));
+
+        args.targets = vec!["test-agent".into(), "b@other".into()];
+        let (targets, message) = split_targets(&args).unwrap();
+        assert_eq!(targets.len(), 2);
+        assert_eq!(message, None);
+    }
+
+    #[test]
+    fn test_read_message_body_preserves_trailing_newlines() {
+        let input = b"```rust\nfn main() {}\n```\n\n".as_slice();
+        let body = read_message_body(input).unwrap();
+        assert_eq!(body, "```rust\nfn main() {}\n```\n\n");
+    }
+
+    #[test]
+    fn test_read_message_body_rejects_invalid_utf8() {
+        assert!(read_message_body([0xff, 0xfe].as_slice()).is_err());
+    }
+
     #[test]
     fn test_resolve_offline_action_default() {
         let args = make_send_args(None);
@@ -1,16 +1,19 @@
 //! Status command implementation
 
-use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
+use agent_team_mail_core::config::{ConfigOverrides, OutputFormat, resolve_config};
 use agent_team_mail_core::daemon_client::{
-    canonical_liveness_bool, query_list_agents, query_team_member_states,
+    AgentSummary, CanonicalMemberState, SessionQueryResult, canonical_liveness_bool,
+    query_list_agents, query_list_agents_for_team, query_session_for_team,
+    query_team_member_states,
 };
 use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
-use agent_team_mail_core::{io::inbox_read_file_tolerant, schema::TeamConfig};
+use agent_team_mail_core::{io::inbox_iter, schema::TeamConfig};
 use anyhow::Result;
-use clap::Args;
+use clap::{Args, ValueEnum};
 use serde_json::json;
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
+use std::path::Path;
 
 use crate::commands::logging_health::{
     build_logging_health_contract, build_otel_health_contract, logging_remediation,
@@ -22,6 +25,10 @@ use crate::util::settings::{get_home_dir, teams_root_dir_for};
 /// Show combined team overview
 #[derive(Args, Debug)]
 pub struct StatusArgs {
+    /// Team name (positional form of `--team`)
+    #[arg(value_name = "TEAM", conflicts_with = "team")]
+    team_name: Option<String>,
+
     /// Team name (optional, uses default team if not specified)
     #[arg(long)]
     team: Option<String>,
@@ -29,6 +36,37 @@ pub struct StatusArgs {
     /// Output as JSON
     #[arg(long)]
     json: bool,
+
+    /// Show which process holds each identity (lock files + daemon sessions)
+    #[arg(long)]
+    locks: bool,
+
+    /// Output format; `text` overrides `[display] format`, `json` prints one
+    /// machine-readable object per agent
+    #[arg(long, value_enum, conflicts_with = "json")]
+    format: Option<StatusFormat>,
+
+    /// Group members by repository, then branch, of their working directory
+    #[arg(long)]
+    tree: bool,
+}
+
+/// `--format` values
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StatusFormat {
+    /// Human-readable overview (default)
+    Text,
+    /// Per-agent JSON for dashboards
+    Json,
+}
+
+impl From<StatusFormat> for OutputFormat {
+    fn from(format: StatusFormat) -> Self {
+        match format {
+            StatusFormat::Text => OutputFormat::Text,
+            StatusFormat::Json => OutputFormat::Json,
+        }
+    }
 }
 
 struct StatusMemberRow {
@@ -40,10 +78,22 @@ struct StatusMemberRow {
 
 #[derive(Clone, Copy, Debug, Default, serde::Serialize)]
 struct InboxCounts {
+    total: usize,
     unread: usize,
     pending: usize,
 }
 
+/// One agent in `atm status --format json` output.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+struct AgentStatusEntry {
+    name: String,
+    /// Daemon-reported state, or `unknown` when the daemon is unreachable
+    state: String,
+    inbox_count: usize,
+    unread_count: usize,
+    last_active: Option<String>,
+}
+
 /// Execute the status command
 pub fn execute(args: StatusArgs) -> Result<()> {
     // Prime daemon connectivity so daemon-backed liveness fields are available.
@@ -54,10 +104,15 @@ pub fn execute(args: StatusArgs) -> Result<()> {
 
     // Resolve configuration to get default team
     let overrides = ConfigOverrides {
-        team: args.team.clone(),
+        team: args.team.clone().or_else(|| args.team_name.clone()),
+        format: args
+            .format
+            .map(OutputFormat::from)
+            .or(args.json.then_some(OutputFormat::Json)),
         ..Default::default()
     };
     let config = resolve_config(&overrides, &current_dir, &home_dir)?;
+    let json_output = config.display.format == OutputFormat::Json;
     let team_name = &config.core.default_team;
 
     // Load team config
@@ -89,6 +144,28 @@ pub fn execute(args: StatusArgs) -> Result<()> {
     // Count inbox message states for each member
     let inbox_counts = count_inbox_messages(&team_dir, &member_rows)?;
 
+    let lock_rows = if args.locks {
+        let names: Vec<String> = member_rows.iter().map(|m| m.name.clone()).collect();
+        let daemon_sessions: HashMap<String, SessionQueryResult> = names
+            .iter()
+            .filter_map(|name| {
+                query_session_for_team(team_name, name)
+                    .ok()
+                    .flatten()
+                    .map(|s| (name.clone(), s))
+            })
+            .collect();
+        let locks = read_identity_locks(&identity_locks_dir(&home_dir, team_name));
+        Some(build_lock_rows(
+            &names,
+            &locks,
+            &daemon_sessions,
+            agent_team_mail_core::pid::is_pid_alive,
+        ))
+    } else {
+        None
+    };
+
     // Count tasks if tasks directory exists
     let tasks_dir = crate::util::settings::claude_root_dir_for(&home_dir)
         .join("tasks")
@@ -103,21 +180,82 @@ pub fn execute(args: StatusArgs) -> Result<()> {
     let age = format_age(team_config.created_at);
 
     // Output results
-    if args.json {
+    if args.tree {
+        let agents = query_list_agents_for_team(team_name).ok().flatten();
+        let cwds: HashMap<&str, &str> = team_config
+            .members
+            .iter()
+            .map(|m| (m.name.as_str(), m.cwd.as_str()))
+            .collect();
+        let tree = build_status_tree(&member_rows, agents.as_deref(), &daemon_states, |name| {
+            cwds.get(name)
+                .map_or((None, None), |cwd| probe_location(cwd))
+        });
+        if json_output {
+            let output = json!({
+                "team": team_name,
+                "daemonAvailable": agents.is_some(),
+                "repos": tree.iter().map(|(repo, branches)| json!({
+                    "repo": repo,
+                    "branches": branches.iter().map(|(branch, agents)| json!({
+                        "branch": branch,
+                        "agents": agents,
+                    })).collect::<Vec<_>>(),
+                })).collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        } else {
+            println!("Team: {team_name}");
+            for (repo, branches) in &tree {
+                println!("{repo}");
+                for (branch, agents) in branches {
+                    println!("  {branch}");
+                    for agent in agents {
+                        println!("    {:<20} {}", agent.name, agent.state);
+                    }
+                }
+            }
+        }
+    } else if args.format == Some(StatusFormat::Json) {
+        let agents = query_list_agents_for_team(team_name).ok().flatten();
+        let entries = build_agent_status_entries(
+            &member_rows,
+            agents.as_deref(),
+            &daemon_states,
+            &inbox_counts,
+        );
         let output = json!({
+            "team": team_name,
+            "daemonAvailable": agents.is_some(),
+            "agents": entries,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else if json_output {
+        let agents = query_list_agents_for_team(team_name).ok().flatten();
+        let entries = build_agent_status_entries(
+            &member_rows,
+            agents.as_deref(),
+            &daemon_states,
+            &inbox_counts,
+        );
+        let mut output = json!({
             "team": team_name,
             "description": team_config.description,
             "createdAt": team_config.created_at,
-            "members": member_rows.iter().map(|m| {
+            "daemonAvailable": agents.is_some(),
+            "members": member_rows.iter().zip(&entries).map(|(m, entry)| {
                 let counts = inbox_counts.get(&m.name).copied().unwrap_or_default();
                 json!({
                     "name": m.name,
                     "type": m.agent_type,
+                    "state": entry.state,
                     "liveness": m.liveness,
                     "inConfig": m.in_config,
                     "ghost": !m.in_config,
+                    "inboxCount": entry.inbox_count,
                     "unreadCount": counts.unread,
                     "pendingCount": counts.pending,
+                    "lastActive": entry.last_active,
                 })
             }).collect::<Vec<_>>(),
             "inboxCounts": inbox_counts,
@@ -130,6 +268,9 @@ pub fn execute(args: StatusArgs) -> Result<()> {
             "otel_health": serde_json::to_value(&otel_health)
                 .expect("otel_health should serialize"),
         });
+        if let Some(rows) = &lock_rows {
+            output["locks"] = serde_json::to_value(rows)?;
+        }
         println!("{}", serde_json::to_string_pretty(&output)?);
     } else {
         println!("Team: {team_name}");
@@ -165,6 +306,14 @@ pub fn execute(args: StatusArgs) -> Result<()> {
             println!("Tasks: {pending_tasks} pending, {completed_tasks} completed");
         }
 
+        if let Some(rows) = &lock_rows {
+            println!();
+            println!("Locks:");
+            for row in rows {
+                println!("  {:<20} {}", row.identity, format_lock_row(row));
+            }
+        }
+
         println!();
         println!("Logging:");
         println!("  state:           {}", logging.state);
@@ -229,7 +378,7 @@ pub fn execute(args: StatusArgs) -> Result<()> {
         session_id: std::env::var("CLAUDE_SESSION_ID").ok(),
         agent_id: Some(config.core.identity.clone()),
         agent_name: Some(config.core.identity.clone()),
-        result: Some(if args.json { "ok_json" } else { "ok_human" }.to_string()),
+        result: Some(if json_output { "ok_json" } else { "ok_human" }.to_string()),
         count: Some(member_rows.len() as u64),
         ..Default::default()
     });
@@ -276,6 +425,99 @@ fn build_status_member_rows(
         .collect()
 }
 
+/// Build the per-member daemon state and inbox counts for `--format json`
+/// (also merged into the `--json` member rows).
+///
+/// `agents` is the daemon's `list-agents` result; `None` means the daemon is
+/// unreachable, in which case every state is `unknown` and only filesystem
+/// inbox counts are reported.
+fn build_agent_status_entries(
+    members: &[StatusMemberRow],
+    agents: Option<&[AgentSummary]>,
+    daemon_states: &HashMap<String, CanonicalMemberState>,
+    inbox_counts: &HashMap<String, InboxCounts>,
+) -> Vec<AgentStatusEntry> {
+    members
+        .iter()
+        .map(|member| {
+            let counts = inbox_counts.get(&member.name).copied().unwrap_or_default();
+            let canonical = agents.and(daemon_states.get(&member.name));
+            let state = match agents {
+                None => "unknown".to_string(),
+                Some(agents) => agents
+                    .iter()
+                    .find(|a| a.agent == member.name)
+                    .map(|a| a.state.clone())
+                    .or_else(|| canonical.map(|s| s.state.clone()))
+                    .unwrap_or_else(|| "unknown".to_string()),
+            };
+            AgentStatusEntry {
+                name: member.name.clone(),
+                state,
+                inbox_count: counts.total,
+                unread_count: counts.unread,
+                last_active: canonical.and_then(|s| s.last_alive_at.clone()),
+            }
+        })
+        .collect()
+}
+
+/// Label for members whose repository or branch could not be determined.
+const UNKNOWN_LOCATION: &str = "(unknown)";
+
+/// One agent leaf in `atm status --tree`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+struct TreeAgent {
+    name: String,
+    state: String,
+}
+
+/// Group members by repo name, then branch, for `--tree`.
+///
+/// States come from the daemon's `list-agents` result when available; repo and
+/// branch always come from `locate`, which probes each member's configured
+/// `cwd` locally. Members with no detectable location are grouped under
+/// [`UNKNOWN_LOCATION`].
+fn build_status_tree(
+    members: &[StatusMemberRow],
+    agents: Option<&[AgentSummary]>,
+    daemon_states: &HashMap<String, CanonicalMemberState>,
+    locate: impl Fn(&str) -> (Option<String>, Option<String>),
+) -> BTreeMap<String, BTreeMap<String, Vec<TreeAgent>>> {
+    let mut tree: BTreeMap<String, BTreeMap<String, Vec<TreeAgent>>> = BTreeMap::new();
+    for member in members {
+        let summary = agents.and_then(|agents| agents.iter().find(|a| a.agent == member.name));
+        let state = summary
+            .map(|a| a.state.clone())
+            .or_else(|| daemon_states.get(&member.name).map(|s| s.state.clone()))
+            .unwrap_or_else(|| "unknown".to_string());
+        let (repo, branch) = locate(&member.name);
+        let repo = repo.unwrap_or_else(|| UNKNOWN_LOCATION.to_string());
+        let branch = branch.unwrap_or_else(|| UNKNOWN_LOCATION.to_string());
+        tree.entry(repo)
+            .or_default()
+            .entry(branch)
+            .or_default()
+            .push(TreeAgent {
+                name: member.name.clone(),
+                state,
+            });
+    }
+    tree
+}
+
+/// Repository name and branch for a member working directory, probed locally.
+fn probe_location(cwd: &str) -> (Option<String>, Option<String>) {
+    let dir = Path::new(cwd);
+    if cwd.is_empty() || !dir.is_dir() {
+        return (None, None);
+    }
+    (
+        agent_team_mail_core::context::detect_repo_name(dir),
+        agent_team_mail_core::context::detect_current_branch(dir),
+    )
+}
+
 /// Count unread and pending-action messages in inboxes.
 fn count_inbox_messages(
     team_dir: &std::path::Path,
@@ -291,17 +533,17 @@ fn count_inbox_messages(
     for member in members {
         let inbox_path = inboxes_dir.join(format!("{}.json", member.name));
         if inbox_path.exists() {
-            match inbox_read_file_tolerant(&inbox_path) {
+            // Stream the inbox: only counts are needed, so large inboxes are
+            // never held in memory.
+            match inbox_iter(&inbox_path) {
                 Ok(messages) => {
-                    let unread_count = messages.iter().filter(|m| !m.read).count();
-                    let pending_count = messages.iter().filter(|m| m.is_pending_action()).count();
-                    counts.insert(
-                        member.name.clone(),
-                        InboxCounts {
-                            unread: unread_count,
-                            pending: pending_count,
-                        },
-                    );
+                    let mut member_counts = InboxCounts::default();
+                    for message in messages.skip_invalid_records() {
+                        member_counts.total += 1;
+                        member_counts.unread += usize::from(!message.read);
+                        member_counts.pending += usize::from(message.is_pending_action());
+                    }
+                    counts.insert(member.name.clone(), member_counts);
                 }
                 Err(_) => {
                     // Ignore read/parse errors.
@@ -313,6 +555,122 @@ fn count_inbox_messages(
     Ok(counts)
 }
 
+/// Identity lock file contents written by `atm-agent-mcp`
+/// (`<home>/.config/atm/agent-sessions/<team>/<identity>.lock`).
+#[derive(Debug, Clone, serde::Deserialize)]
+struct IdentityLock {
+    pid: u32,
+    agent_id: String,
+    /// Lock file modification time (RFC 3339), i.e. when it was acquired.
+    #[serde(skip)]
+    since: Option<String>,
+    #[serde(skip)]
+    path: String,
+}
+
+/// Who holds an identity, combining lock files and daemon sessions.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LockStatusRow {
+    identity: String,
+    /// `held` (live PID), `stale` (dead PID; safe to remove), or `none`
+    lock_state: &'static str,
+    lock_pid: Option<u32>,
+    lock_agent_id: Option<String>,
+    lock_since: Option<String>,
+    lock_path: Option<String>,
+    daemon_pid: Option<u32>,
+    daemon_alive: Option<bool>,
+    daemon_last_seen_at: Option<String>,
+}
+
+fn identity_locks_dir(home_dir: &Path, team: &str) -> std::path::PathBuf {
+    home_dir.join(".config/atm/agent-sessions").join(team)
+}
+
+/// Read all `<identity>.lock` files in `dir`, skipping unreadable ones.
+fn read_identity_locks(dir: &Path) -> BTreeMap<String, IdentityLock> {
+    let mut locks = BTreeMap::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return locks;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("lock") {
+            continue;
+        }
+        let Some(identity) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let Some(mut lock) = fs::read_to_string(&path)
+            .ok()
+            .and_then(|c| serde_json::from_str::<IdentityLock>(&c).ok())
+        else {
+            continue;
+        };
+        lock.since = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339());
+        lock.path = path.display().to_string();
+        locks.insert(identity.to_string(), lock);
+    }
+    locks
+}
+
+fn build_lock_rows(
+    names: &[String],
+    locks: &BTreeMap<String, IdentityLock>,
+    daemon_sessions: &HashMap<String, SessionQueryResult>,
+    pid_alive: impl Fn(u32) -> bool,
+) -> Vec<LockStatusRow> {
+    let identities: BTreeSet<&String> = names.iter().chain(locks.keys()).collect();
+    identities
+        .into_iter()
+        .map(|identity| {
+            let lock = locks.get(identity);
+            let session = daemon_sessions.get(identity);
+            LockStatusRow {
+                identity: identity.clone(),
+                lock_state: match lock {
+                    Some(l) if pid_alive(l.pid) => "held",
+                    Some(_) => "stale",
+                    None => "none",
+                },
+                lock_pid: lock.map(|l| l.pid),
+                lock_agent_id: lock.map(|l| l.agent_id.clone()),
+                lock_since: lock.and_then(|l| l.since.clone()),
+                lock_path: lock.map(|l| l.path.clone()),
+                daemon_pid: session.map(|s| s.process_id),
+                daemon_alive: session.map(|s| s.alive),
+                daemon_last_seen_at: session.and_then(|s| s.last_seen_at.clone()),
+            }
+        })
+        .collect()
+}
+
+fn format_lock_row(row: &LockStatusRow) -> String {
+    let lock = match (row.lock_state, row.lock_pid) {
+        ("held", Some(pid)) => format!(
+            "lock: held by pid {pid} ({}) since {}",
+            row.lock_agent_id.as_deref().unwrap_or("-"),
+            row.lock_since.as_deref().unwrap_or("unknown")
+        ),
+        ("stale", Some(pid)) => format!(
+            "lock: STALE (pid {pid} not running) - remove {}",
+            row.lock_path.as_deref().unwrap_or("-")
+        ),
+        _ => "lock: none".to_string(),
+    };
+    let daemon = match (row.daemon_pid, row.daemon_alive) {
+        (Some(pid), Some(true)) => format!("daemon: pid {pid} alive"),
+        (Some(pid), _) => format!("daemon: pid {pid} dead"),
+        (None, _) => "daemon: no session".to_string(),
+    };
+    format!("{lock}; {daemon}")
+}
+
 /// Count pending and completed tasks
 fn count_tasks(tasks_dir: &std::path::Path) -> Result<(usize, usize)> {
     use agent_team_mail_core::{TaskItem, TaskStatus};
@@ -413,6 +771,117 @@ mod tests {
         }
     }
 
+    fn status_row(name: &str) -> StatusMemberRow {
+        StatusMemberRow {
+            name: name.to_string(),
+            agent_type: "general-purpose".to_string(),
+            liveness: None,
+            in_config: true,
+        }
+    }
+
+    #[test]
+    fn build_agent_status_entries_uses_daemon_agents_and_inbox_counts() {
+        let members = vec![status_row("arch-ctm"), status_row("team-lead")];
+        let agents = vec![AgentSummary {
+            agent: "arch-ctm".to_string(),
+            state: "busy".to_string(),
+        }];
+        let mut daemon_states = HashMap::new();
+        daemon_states.insert(
+            "arch-ctm".to_string(),
+            CanonicalMemberState {
+                agent: "arch-ctm".to_string(),
+                state: "active".to_string(),
+                activity: "busy".to_string(),
+                session_id: None,
+                process_id: None,
+                last_alive_at: Some("2026-10-17T12:00:00Z".to_string()),
+                reason: String::new(),
+                source: String::new(),
+                in_config: true,
+            },
+        );
+        let mut inbox_counts = HashMap::new();
+        inbox_counts.insert(
+            "arch-ctm".to_string(),
+            InboxCounts {
+                total: 5,
+                unread: 2,
+                pending: 1,
+            },
+        );
+
+        let entries =
+            build_agent_status_entries(&members, Some(&agents), &daemon_states, &inbox_counts);
+        assert_eq!(
+            serde_json::to_value(&entries).unwrap(),
+            json!([
+                {
+                    "name": "arch-ctm",
+                    "state": "busy",
+                    "inbox_count": 5,
+                    "unread_count": 2,
+                    "last_active": "2026-10-17T12:00:00Z",
+                },
+                {
+                    "name": "team-lead",
+                    "state": "unknown",
+                    "inbox_count": 0,
+                    "unread_count": 0,
+                    "last_active": null,
+                },
+            ])
+        );
+
+        // Daemon unreachable: filesystem counts only, every state unknown.
+        let entries = build_agent_status_entries(&members, None, &daemon_states, &inbox_counts);
+        assert_eq!(entries[0].state, "unknown");
+        assert_eq!(entries[0].inbox_count, 5);
+        assert_eq!(entries[0].last_active, None);
+    }
+
+    #[test]
+    fn build_status_tree_groups_by_repo_then_branch() {
+        let members = vec![
+            status_row("arch-ctm"),
+            status_row("qa"),
+            status_row("team-lead"),
+            status_row("worker-1"),
+        ];
+        let summary = |agent: &str, state: &str| AgentSummary {
+            agent: agent.to_string(),
+            state: state.to_string(),
+        };
+        let agents = vec![
+            summary("arch-ctm", "active"),
+            summary("qa", "idle"),
+            summary("team-lead", "idle"),
+            summary("worker-1", "offline"),
+        ];
+        let locate = |name: &str| match name {
+            "arch-ctm" | "qa" => (Some("atm".to_string()), Some("develop".to_string())),
+            "team-lead" => (Some("atm".to_string()), Some("main".to_string())),
+            _ => (None, None),
+        };
+
+        let tree = build_status_tree(&members, Some(&agents), &HashMap::new(), locate);
+        let names = |repo: &str, branch: &str| -> Vec<&str> {
+            tree[repo][branch].iter().map(|a| a.name.as_str()).collect()
+        };
+        assert_eq!(tree.len(), 2);
+        assert_eq!(names("atm", "develop"), vec!["arch-ctm", "qa"]);
+        assert_eq!(names("atm", "main"), vec!["team-lead"]);
+        assert_eq!(names(UNKNOWN_LOCATION, UNKNOWN_LOCATION), vec!["worker-1"]);
+        assert_eq!(tree["atm"]["develop"][0].state, "active");
+
+        // Daemon unreachable: states are unknown but locations still resolve.
+        let tree = build_status_tree(&members, None, &HashMap::new(), locate);
+        assert_eq!(tree["atm"]["main"][0].name, "team-lead");
+        assert_eq!(tree["atm"]["main"][0].state, "unknown");
+        assert_eq!(tree[UNKNOWN_LOCATION][UNKNOWN_LOCATION].len(), 1);
+    }
+
     #[test]
     fn build_status_member_rows_includes_daemon_only_member() {
         let cfg = TeamConfig {
@@ -421,6 +890,8 @@ mod tests {
             created_at: 0,
             lead_agent_id: "team-lead@atm-dev".to_string(),
             lead_session_id: "sess".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![member("team-lead")],
             unknown_fields: HashMap::new(),
         };
@@ -533,4 +1004,49 @@ mod tests {
         );
         assert!(value["last_error"]["at"].is_string());
     }
+
+    #[test]
+    fn build_lock_rows_flags_stale_and_orphan_locks() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("arch-ctm.lock"),
+            r#"{"pid": 100, "agent_id": "codex:live"}"#,
+        )
+        .unwrap();
+        fs::write(
+            tmp.path().join("ghost.lock"),
+            r#"{"pid": 200, "agent_id": "codex:dead"}"#,
+        )
+        .unwrap();
+        fs::write(tmp.path().join("broken.lock"), "not json").unwrap();
+
+        let locks = read_identity_locks(tmp.path());
+        assert_eq!(locks.len(), 2);
+        assert!(locks["arch-ctm"].since.is_some());
+
+        let mut sessions = HashMap::new();
+        sessions.insert(
+            "team-lead".to_string(),
+            SessionQueryResult {
+                session_id: "sess-1".to_string(),
+                process_id: 300,
+                alive: true,
+                last_seen_at: None,
+                runtime: None,
+                runtime_session_id: None,
+                pane_id: None,
+                runtime_home: None,
+            },
+        );
+        let names = vec!["arch-ctm".to_string(), "team-lead".to_string()];
+        let rows = build_lock_rows(&names, &locks, &sessions, |pid| pid == 100);
+
+        let by_name: HashMap<_, _> = rows.iter().map(|r| (r.identity.as_str(), r)).collect();
+        assert_eq!(by_name["arch-ctm"].lock_state, "held");
+        assert_eq!(by_name["arch-ctm"].lock_pid, Some(100));
+        assert_eq!(by_name["ghost"].lock_state, "stale");
+        assert!(format_lock_row(by_name["ghost"]).contains("STALE"));
+        assert_eq!(by_name["team-lead"].lock_state, "none");
+        assert_eq!(by_name["team-lead"].daemon_pid, Some(300));
+    }
 }
@@ -1,14 +1,21 @@
 //! Teams command implementation
 
-use agent_team_mail_core::config::{ConfigOverrides, resolve_config, resolve_identity};
+use agent_team_mail_core::config::{
+    ConfigOverrides, OutputFormat, resolve_config, resolve_identity,
+};
 use agent_team_mail_core::daemon_client::{
-    AgentSummary, LaunchConfig, RegisterHintOutcome, SessionQueryResult, launch_agent,
-    query_list_agents, query_session_for_team, query_team_member_states, register_hint,
+    AgentSummary, LaunchConfig, RegisterHintOutcome, SessionQueryResult, TeamDaemonSummary,
+    launch_agent, query_list_agents, query_list_teams, query_session_for_team,
+    query_team_member_states, register_hint,
 };
 use agent_team_mail_core::event_log::{EventFields, emit_event_best_effort};
+use agent_team_mail_core::home::{atm_config_dir_for, sessions_dir_for};
 use agent_team_mail_core::io::inbox::inbox_update;
+use agent_team_mail_core::logging_event::configured_spool_dir;
 use agent_team_mail_core::model_registry::ModelId;
+use agent_team_mail_core::retention::determine_archive_dir;
 use agent_team_mail_core::schema::{BackendType, TeamConfig};
+use agent_team_mail_core::storage::team_storage_usage_with_dirs;
 use agent_team_mail_core::team_config_store::TeamConfigStore;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
@@ -18,7 +25,7 @@ use sc_composer::{
     UnknownVariablePolicy, compose,
 };
 use serde_json::json;
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
@@ -65,6 +72,10 @@ pub struct TeamsArgs {
     /// Output as JSON
     #[arg(long)]
     json: bool,
+
+    /// Include daemon-known liveness (live agents, unread totals) per team
+    #[arg(long)]
+    live: bool,
 }
 
 #[derive(clap::Subcommand, Debug)]
@@ -87,12 +98,16 @@ pub enum TeamsCommand {
     Backup(BackupArgs),
     /// Restore a team's members, inboxes, and tasks from a backup snapshot
     Restore(RestoreArgs),
+    /// Show disk usage of a team's inboxes, archives, backups, and spool
+    Usage(UsageArgs),
+    /// Rename a team, moving its directory, tasks, backups, and archives
+    Rename(RenameTeamArgs),
 }
 
 /// Spawn a team member (runtime-aware daemon launch)
 #[derive(Args, Debug)]
 #[command(
-    after_long_help = "Environment:\n  ATM_TEAM     Effective team when --team is omitted.\n  ATM_IDENTITY Effective member identity for spawned runtime sessions.\n\nLaunch command output:\n  This command always prints the exact copy/paste launch command\n  before launch is attempted (success or failure).\n\nExamples:\n  atm teams spawn arch-ctm --runtime codex --folder /path/to/repo\n  atm teams spawn qa-gemini --runtime gemini --folder /path/to/repo --model gemini-2.5-pro\n  atm teams spawn test-member-3 --runtime claude --folder /path/to/repo --team atm-dev --color cyan --model haiku\n\nMismatch Handling:\n  If ATM_TEAM conflicts with .atm.toml default_team, pass --override-team to proceed."
+    after_long_help = "Environment:\n  ATM_TEAM     Effective team when --team is omitted.\n  ATM_IDENTITY Effective member identity for spawned runtime sessions.\n\nLaunch command output:\n  This command always prints the exact copy/paste launch command\n  before launch is attempted (success or failure).\n\nExamples:\n  atm teams spawn arch-ctm --runtime codex --folder /path/to/repo\n  atm teams spawn qa-gemini --runtime gemini --folder /path/to/repo --model gemini-2.5-pro\n  atm teams spawn test-member-3 --runtime claude --folder /path/to/repo --team atm-dev --runtime-color cyan --model haiku\n\nMismatch Handling:\n  If ATM_TEAM conflicts with .atm.toml default_team, pass --override-team to proceed."
 )]
 pub struct SpawnArgs {
     /// Agent name
@@ -112,7 +127,7 @@ pub struct SpawnArgs {
 
     /// Optional runtime color hint (currently used by Claude launch args)
     #[arg(long)]
-    color: Option<String>,
+    runtime_color: Option<String>,
 
     /// Optional sandbox mode override (`true` or `false`)
     #[arg(long)]
@@ -370,6 +385,35 @@ pub struct RestoreArgs {
     json: bool,
 }
 
+/// Show a team's storage footprint
+#[derive(Args, Debug)]
+pub struct UsageArgs {
+    /// Team name
+    team: String,
+
+    /// Output as JSON (includes every counted file)
+    #[arg(long)]
+    json: bool,
+}
+
+/// Rename a team
+#[derive(Args, Debug)]
+pub struct RenameTeamArgs {
+    /// Current team name
+    old: String,
+
+    /// New team name (must not already exist)
+    new: String,
+
+    /// Rename even while the daemon reports live sessions in the team
+    #[arg(long)]
+    force: bool,
+
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+}
+
 /// Team summary information
 #[derive(Debug)]
 struct TeamSummary {
@@ -391,15 +435,23 @@ pub fn execute(args: TeamsArgs) -> Result<()> {
             TeamsCommand::Cleanup(cleanup_args) => cleanup(cleanup_args),
             TeamsCommand::Backup(backup_args) => backup(backup_args),
             TeamsCommand::Restore(restore_args) => restore(restore_args),
+            TeamsCommand::Usage(usage_args) => usage(usage_args),
+            TeamsCommand::Rename(rename_args) => rename_team(rename_args),
         };
     }
 
     let home_dir = get_home_dir()?;
+    let overrides = ConfigOverrides {
+        format: args.json.then_some(OutputFormat::Json),
+        ..Default::default()
+    };
+    let config = resolve_config(&overrides, &std::env::current_dir()?, &home_dir)?;
+    let json_output = config.display.format == OutputFormat::Json;
     let teams_dir = teams_root_dir_for(&home_dir);
 
     // Check if teams directory exists
     if !teams_dir.exists() {
-        if args.json {
+        if json_output {
             println!("{}", json!({"teams": []}));
         } else {
             let teams_path = teams_dir.display();
@@ -443,25 +495,58 @@ pub fn execute(args: TeamsArgs) -> Result<()> {
     // Sort teams by name
     teams.sort_by(|a, b| a.name.cmp(&b.name));
 
+    // Daemon-known liveness is best-effort: `None` means the daemon was
+    // unreachable (or too old for `list-teams`).
+    let live: Option<HashMap<String, TeamDaemonSummary>> = if args.live {
+        query_list_teams()
+            .unwrap_or(None)
+            .map(|summaries| summaries.into_iter().map(|s| (s.team.clone(), s)).collect())
+    } else {
+        None
+    };
+
     // Output results
-    if args.json {
-        let output = json!({
-            "teams": teams.iter().map(|t| json!({
-                "name": t.name,
-                "memberCount": t.member_count,
-                "createdAt": t.created_at,
-            })).collect::<Vec<_>>()
+    if json_output {
+        let mut output = json!({
+            "teams": teams.iter().map(|t| {
+                let mut entry = json!({
+                    "name": t.name,
+                    "memberCount": t.member_count,
+                    "createdAt": t.created_at,
+                });
+                if let Some(summary) = live.as_ref().and_then(|l| l.get(&t.name)) {
+                    entry["liveAgents"] = json!(summary.live_agents);
+                    entry["unreadTotal"] = json!(summary.unread_total);
+                }
+                entry
+            }).collect::<Vec<_>>()
         });
+        if args.live {
+            output["daemonAvailable"] = json!(live.is_some());
+        }
         println!("{}", serde_json::to_string_pretty(&output)?);
     } else if teams.is_empty() {
         println!("No teams found");
     } else {
+        if args.live && live.is_none() {
+            eprintln!("Warning: daemon not reachable; live agent counts unavailable");
+        }
         println!("Teams:");
         for team in &teams {
             let age = format_age(team.created_at);
             let name = &team.name;
             let count = team.member_count;
-            println!("  {name:20}  {count} members    Created {age}");
+            match live.as_ref().map(|l| l.get(name)) {
+                Some(summary) => {
+                    let (live_count, unread) = summary
+                        .map(|s| (s.live_agents.len(), s.unread_total))
+                        .unwrap_or((0, 0));
+                    println!(
+                        "  {name:20}  {count} members  {live_count} live  {unread} unread    Created {age}"
+                    );
+                }
+                None => println!("  {name:20}  {count} members    Created {age}"),
+            }
         }
     }
 
@@ -532,7 +617,7 @@ fn spawn_member(args: SpawnArgs) -> Result<()> {
     let spec = SpawnSpec {
         team: team_name.clone(),
         agent: args.agent.clone(),
-        color: args.color.clone().or(frontmatter.color),
+        color: args.runtime_color.clone().or(frontmatter.color),
         cwd: launch_dir.clone(),
         model: args.model.clone().or(frontmatter.model),
         sandbox: args.sandbox,
@@ -2684,7 +2769,7 @@ fn cleanup(args: CleanupArgs) -> Result<()> {
 
 fn remove_member_mailbox_artifacts(team_dir: &Path, member_name: &str) {
     let inboxes_dir = team_dir.join("inboxes");
-    for ext in ["json", "lock"] {
+    for ext in ["json", "lock", "provenance"] {
         let path = inboxes_dir.join(format!("{member_name}.{ext}"));
         if path.exists()
             && let Err(e) = fs::remove_file(&path)
@@ -2730,7 +2815,7 @@ fn collect_orphan_mailboxes(team_dir: &Path, roster: &HashSet<String>) -> Vec<St
         for entry in entries.flatten() {
             let path = entry.path();
             let ext = path.extension().and_then(|s| s.to_str());
-            if !matches!(ext, Some("json") | Some("lock")) {
+            if !matches!(ext, Some("json") | Some("lock") | Some("provenance")) {
                 continue;
             }
             let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
@@ -2769,7 +2854,7 @@ fn purge_orphan_mailboxes(team_dir: &Path, roster: &HashSet<String>) -> Vec<Stri
 
     let inboxes_dir = team_dir.join("inboxes");
     for orphan in &orphans {
-        for ext in ["json", "lock"] {
+        for ext in ["json", "lock", "provenance"] {
             let path = inboxes_dir.join(format!("{orphan}.{ext}"));
             if !path.exists() {
                 continue;
@@ -3011,6 +3096,320 @@ fn prune_old_backups(home_dir: &Path, team: &str, keep: usize) -> Result<()> {
     Ok(())
 }
 
+/// Number of largest files listed by the human-readable `atm teams usage` output.
+const USAGE_TOP_FILES: usize = 10;
+
+/// Implement `atm teams usage <team>`
+///
+/// Sums the bytes ATM holds for the team across inboxes, team files,
+/// archives, backups, and spooled messages.
+fn usage(args: UsageArgs) -> Result<()> {
+    let home_dir = get_home_dir()?;
+    let team_dir = teams_root_dir_for(&home_dir).join(&args.team);
+    if !team_dir.exists() {
+        anyhow::bail!(
+            "Team '{}' not found (directory {} doesn't exist)",
+            args.team,
+            team_dir.display()
+        );
+    }
+
+    let config = resolve_config(
+        &ConfigOverrides::default(),
+        &std::env::current_dir()?,
+        &home_dir,
+    )?;
+    let archive_dir = determine_archive_dir(&config.retention)?;
+    let spool_dir = configured_spool_dir(&home_dir);
+    let usage = team_storage_usage_with_dirs(&team_dir, &archive_dir, &spool_dir)?;
+    let by_category = usage.by_category();
+
+    if args.json {
+        let categories: BTreeMap<&str, u64> = by_category
+            .iter()
+            .map(|(category, bytes)| (category.label(), *bytes))
+            .collect();
+        let output = json!({
+            "team": args.team,
+            "totalBytes": usage.total_bytes,
+            "categories": categories,
+            "files": usage.files,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!(
+        "Team '{}' storage: {} ({} bytes)",
+        args.team,
+        format_bytes(usage.total_bytes),
+        usage.total_bytes
+    );
+    if usage.files.is_empty() {
+        return Ok(());
+    }
+    println!();
+    for (category, bytes) in &by_category {
+        println!("  {:<8} {:>10}", category.label(), format_bytes(*bytes));
+    }
+    println!();
+    println!("Largest files:");
+    for file in usage.files.iter().take(USAGE_TOP_FILES) {
+        println!(
+            "  {:>10}  {:<8} {}",
+            format_bytes(file.bytes),
+            file.category.label(),
+            file.path.display()
+        );
+    }
+    let remaining = usage.files.len().saturating_sub(USAGE_TOP_FILES);
+    if remaining > 0 {
+        println!("  ... {remaining} more files (use --json for the full list)");
+    }
+    Ok(())
+}
+
+/// Render a byte count with a binary unit suffix (`B`, `KiB`, `MiB`, `GiB`).
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Implement `atm teams rename <old> <new>`
+///
+/// Moves the team directory plus the per-team task, backup, archive,
+/// retention archive, agent-session, and share directories, rewrites `name`
+/// and every `@<old>` agent id in `config.json`, and retargets spooled
+/// messages addressed to `<old>`. Any failure undoes the changes already made,
+/// leaving `<old>` as it was. Refuses while the daemon reports live sessions in
+/// the team unless `--force` is given, since running agents would recreate
+/// `<old>`.
+fn rename_team(args: RenameTeamArgs) -> Result<()> {
+    validate_team_name(&args.old)?;
+    validate_team_name(&args.new)?;
+    if args.old == args.new {
+        anyhow::bail!("Team is already named '{}'", args.new);
+    }
+    let home_dir = get_home_dir()?;
+    let teams_root = teams_root_dir_for(&home_dir);
+    let old_dir = teams_root.join(&args.old);
+    let new_dir = teams_root.join(&args.new);
+    if !old_dir.join("config.json").exists() {
+        anyhow::bail!(
+            "Team '{}' not found (no config at {})",
+            args.old,
+            old_dir.join("config.json").display()
+        );
+    }
+
+    if !args.force {
+        let live = query_list_teams()
+            .unwrap_or(None)
+            .and_then(|teams| teams.into_iter().find(|t| t.team == args.old))
+            .map(|t| t.live_agents)
+            .unwrap_or_default();
+        if !live.is_empty() {
+            anyhow::bail!(
+                "Team '{}' has live sessions ({}). Stop them first or use --force to override.",
+                args.old,
+                live.join(", ")
+            );
+        }
+    }
+
+    let config = resolve_config(
+        &ConfigOverrides::default(),
+        &std::env::current_dir()?,
+        &home_dir,
+    )?;
+    let retention_archive_dir = determine_archive_dir(&config.retention)?;
+    let spool_dir = configured_spool_dir(&home_dir);
+    let sessions_root = sessions_dir_for(&home_dir);
+    let share_root = atm_config_dir_for(&home_dir).join("share");
+    let tasks_root = claude_root_dir_for(&home_dir).join("tasks");
+    let candidates = [
+        (old_dir.clone(), new_dir.clone()),
+        (tasks_root.join(&args.old), tasks_root.join(&args.new)),
+        (
+            teams_root.join(".backups").join(&args.old),
+            teams_root.join(".backups").join(&args.new),
+        ),
+        (
+            teams_root.join(".archives").join(&args.old),
+            teams_root.join(".archives").join(&args.new),
+        ),
+        (
+            retention_archive_dir.join(&args.old),
+            retention_archive_dir.join(&args.new),
+        ),
+        (sessions_root.join(&args.old), sessions_root.join(&args.new)),
+        (share_root.join(&args.old), share_root.join(&args.new)),
+    ];
+    if let Some((_, taken)) = candidates.iter().find(|(_, to)| to.exists()) {
+        anyhow::bail!(
+            "Cannot rename team '{}' to '{}': {} already exists",
+            args.old,
+            args.new,
+            taken.display()
+        );
+    }
+    let moves: Vec<(PathBuf, PathBuf)> = candidates
+        .into_iter()
+        .filter(|(from, _)| from.exists())
+        .collect();
+
+    let mut done: Vec<&(PathBuf, PathBuf)> = Vec::new();
+    let mut retargeted: Vec<(PathBuf, Vec<u8>)> = Vec::new();
+    let result = moves
+        .iter()
+        .try_for_each(|entry| {
+            let (from, to) = entry;
+            fs::rename(from, to).with_context(|| {
+                format!("Failed to move {} to {}", from.display(), to.display())
+            })?;
+            done.push(entry);
+            Ok::<_, anyhow::Error>(())
+        })
+        .and_then(|()| retarget_spool(&spool_dir, &args.old, &args.new, &mut retargeted))
+        .and_then(|()| {
+            TeamConfigStore::open(&new_dir)
+                .update(|config| Ok(Some(renamed_team_config(config, &args.old, &args.new))))
+                .map(|_| ())
+        });
+    if let Err(err) = result {
+        for (path, original) in retargeted.into_iter().rev() {
+            if let Err(undo) = fs::write(&path, original) {
+                warn!("failed to restore spool entry {}: {undo}", path.display());
+            }
+        }
+        for (from, to) in done.into_iter().rev() {
+            if let Err(undo) = fs::rename(to, from) {
+                warn!(
+                    "failed to move {} back to {}: {undo}",
+                    to.display(),
+                    from.display()
+                );
+            }
+        }
+        return Err(err.context(format!(
+            "Rename of team '{}' failed; nothing was changed",
+            args.old
+        )));
+    }
+
+    emit_event_best_effort(EventFields {
+        level: "info",
+        source: "atm",
+        action: "team_rename",
+        team: Some(args.new.clone()),
+        target: Some(args.old.clone()),
+        result: Some("ok".to_string()),
+        ..Default::default()
+    });
+
+    if args.json {
+        let moved: Vec<_> = moves
+            .iter()
+            .map(|(from, to)| json!({ "from": from, "to": to }))
+            .collect();
+        let output = json!({
+            "old": args.old,
+            "new": args.new,
+            "moved": moved,
+            "spoolRetargeted": retargeted.len(),
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!("Renamed team '{}' to '{}'", args.old, args.new);
+        for (from, to) in &moves {
+            println!("  moved {} -> {}", from.display(), to.display());
+        }
+        if !retargeted.is_empty() {
+            println!("  retargeted {} spooled message(s)", retargeted.len());
+        }
+        println!(
+            "Running agents still use '{}'; update ATM_TEAM / default_team and restart them.",
+            args.old
+        );
+    }
+    Ok(())
+}
+
+/// Point spooled messages addressed to `old` at `new`.
+///
+/// Each rewritten entry is pushed onto `retargeted` with its original bytes
+/// before it is overwritten, so the caller can restore it on failure.
+fn retarget_spool(
+    spool_dir: &Path,
+    old: &str,
+    new: &str,
+    retargeted: &mut Vec<(PathBuf, Vec<u8>)>,
+) -> Result<()> {
+    for subdir in ["pending", "failed"] {
+        let Ok(entries) = fs::read_dir(spool_dir.join(subdir)) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let Ok(original) = fs::read(&path) else {
+                continue;
+            };
+            let Ok(mut entry) = serde_json::from_slice::<serde_json::Value>(&original) else {
+                continue;
+            };
+            if entry.get("target_team").and_then(|t| t.as_str()) != Some(old) {
+                continue;
+            }
+            entry["target_team"] = json!(new);
+            let updated = serde_json::to_vec_pretty(&entry)?;
+            retargeted.push((path.clone(), original));
+            fs::write(&path, updated)
+                .with_context(|| format!("Failed to retarget spool entry {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Reject team names that cannot be a single directory component.
+fn validate_team_name(name: &str) -> Result<()> {
+    if name.trim().is_empty()
+        || name.starts_with('.')
+        || name.contains(['/', '\\', '@'])
+        || name.chars().any(char::is_whitespace)
+    {
+        anyhow::bail!("Invalid team name '{name}'");
+    }
+    Ok(())
+}
+
+/// Point a team config at its new name, including `<member>@<team>` ids.
+fn renamed_team_config(mut config: TeamConfig, old: &str, new: &str) -> TeamConfig {
+    let old_suffix = format!("@{old}");
+    let rename_id = |id: &mut String| {
+        if let Some(member) = id.strip_suffix(&old_suffix) {
+            *id = format!("{member}@{new}");
+        }
+    };
+    config.name = new.to_string();
+    rename_id(&mut config.lead_agent_id);
+    for member in &mut config.members {
+        rename_id(&mut member.agent_id);
+    }
+    config
+}
+
 /// Implement `atm teams restore <team>`
 ///
 /// Restores non-team-lead members and their inbox files from a backup snapshot.
@@ -3307,6 +3706,14 @@ mod tests {
         original
     }
 
+    #[test]
+    fn test_format_bytes_uses_binary_units() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+
     fn restore_autostart_env(original: Option<String>) {
         // SAFETY: test-only env mutation, callers use #[serial].
         unsafe {
@@ -3447,6 +3854,41 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_renamed_team_config_rewrites_name_and_agent_ids() {
+        let config: TeamConfig = serde_json::from_value(serde_json::json!({
+            "name": "alpha",
+            "createdAt": 1739284800000i64,
+            "leadAgentId": "team-lead@alpha",
+            "leadSessionId": "sess-1",
+            "members": [
+                {"agentId": "team-lead@alpha", "name": "team-lead", "agentType": "general-purpose",
+                 "model": "m", "joinedAt": 1, "cwd": "/tmp", "subscriptions": []},
+                {"agentId": "dev@alphabet", "name": "dev", "agentType": "general-purpose",
+                 "model": "m", "joinedAt": 1, "cwd": "/tmp", "subscriptions": []}
+            ]
+        }))
+        .unwrap();
+
+        let renamed = renamed_team_config(config, "alpha", "beta");
+        assert_eq!(renamed.name, "beta");
+        assert_eq!(renamed.lead_agent_id, "team-lead@beta");
+        assert_eq!(renamed.lead_session_id, "sess-1");
+        assert_eq!(renamed.members[0].agent_id, "team-lead@beta");
+        assert_eq!(renamed.members[1].agent_id, "dev@alphabet");
+    }
+
+    #[test]
+    fn test_validate_team_name() {
+        assert!(validate_team_name("new-team").is_ok());
+        for bad in ["", " ", ".hidden", "a/b", "a\\b", "a@b", "a b"] {
+            assert!(
+                validate_team_name(bad).is_err(),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+
     #[test]
     fn test_format_age() {
         // Test with a timestamp from 1 day ago
@@ -4464,6 +4906,8 @@ mod tests {
             created_at: 12345,
             lead_agent_id: "team-lead@test".to_string(),
             lead_session_id: "sess-abc".to_string(),
+            groups: Default::default(),
+            display_names: Default::default(),
             members: vec![],
             unknown_fields: HashMap::new(),
         };
@@ -1,5 +1,7 @@
 //! File watching for blocking reads
 
+use agent_team_mail_core::daemon_client::query_message_read;
+use agent_team_mail_core::io::inbox_iter;
 use anyhow::Result;
 use notify::{Config as NotifyConfig, Event, RecommendedWatcher, RecursiveMode, Watcher};
 use std::path::Path;
@@ -15,6 +17,15 @@ pub enum WaitResult {
     Timeout,
 }
 
+/// Result of waiting for a recipient to read a message
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReadWaitResult {
+    /// The message's read flag is set
+    Read,
+    /// Timeout expired
+    Timeout,
+}
+
 /// Wait for a new message to arrive in the inbox
 ///
 /// Uses OS-level file watching (inotify on Linux, kqueue on macOS, ReadDirectoryChangesW on Windows)
@@ -178,6 +189,85 @@ fn polling_wait(
     }
 }
 
+/// Wait until `agent@team` marks the message with `message_id` read
+///
+/// Each check asks the daemon (`message-read`), which sees the merged inbox
+/// including origin files; once the daemon is unreachable the check reads
+/// `inbox_path` directly for the rest of the wait. Checks are triggered by
+/// watching the inbox directory the same way as [`wait_for_message`] (native
+/// events plus a `POLL_INTERVAL` re-check), falling back to polling every
+/// 2 seconds if the watcher cannot be initialised. A message that is not (yet)
+/// present in the inbox, e.g. still spooled, counts as unread.
+pub fn wait_for_read(
+    team: &str,
+    agent: &str,
+    inbox_path: &Path,
+    message_id: &str,
+    timeout: Duration,
+) -> Result<ReadWaitResult> {
+    let start = Instant::now();
+    let Some(inbox_dir) = inbox_path.parent() else {
+        anyhow::bail!("Inbox path {inbox_path:?} has no parent directory");
+    };
+
+    let (tx, rx) = channel();
+    let watcher = RecommendedWatcher::new(
+        move |res: Result<Event, notify::Error>| {
+            if res.is_ok() {
+                let _ = tx.send(());
+            }
+        },
+        NotifyConfig::default().with_poll_interval(Duration::from_millis(500)),
+    )
+    .and_then(|mut watcher| {
+        watcher.watch(inbox_dir, RecursiveMode::NonRecursive)?;
+        Ok(watcher)
+    });
+    let (_watcher, interval) = match watcher {
+        Ok(watcher) => (Some(watcher), POLL_INTERVAL),
+        Err(e) => {
+            eprintln!("Warning: File watching failed ({e}), falling back to polling");
+            (None, Duration::from_secs(2))
+        }
+    };
+
+    let mut use_daemon = true;
+    loop {
+        // Check after watcher setup so a read that races the setup is not missed.
+        let daemon_read = if use_daemon {
+            query_message_read(team, agent, message_id).ok().flatten()
+        } else {
+            None
+        };
+        use_daemon = daemon_read.is_some();
+        if daemon_read.unwrap_or_else(|| is_message_read(inbox_path, message_id)) {
+            return Ok(ReadWaitResult::Read);
+        }
+
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            return Ok(ReadWaitResult::Timeout);
+        }
+        let wait_for = std::cmp::min(timeout - elapsed, interval);
+
+        match rx.recv_timeout(wait_for) {
+            Ok(()) | Err(RecvTimeoutError::Timeout) => {}
+            // Without a watcher the sender is dropped immediately; just sleep.
+            Err(RecvTimeoutError::Disconnected) => std::thread::sleep(wait_for),
+        }
+    }
+}
+
+/// Whether the inbox holds `message_id` with its read flag set
+fn is_message_read(inbox_path: &Path, message_id: &str) -> bool {
+    let Ok(messages) = inbox_iter(inbox_path) else {
+        return false;
+    };
+    messages
+        .skip_invalid_records()
+        .any(|m| m.read && m.message_id.as_deref() == Some(message_id))
+}
+
 /// Count total messages across all inbox files
 fn count_messages(
     inbox_dir: &Path,
@@ -314,4 +404,41 @@ mod tests {
         let result = polling_wait(&inbox_dir, "test-agent", 5, 0, None).unwrap();
         assert_eq!(result, WaitResult::MessageReceived);
     }
+
+    #[test]
+    fn test_is_message_read_matches_message_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_file = temp_dir.path().join("test-agent.json");
+        assert!(!is_message_read(&inbox_file, "m-1"));
+
+        let messages = serde_json::json!([
+            {"from": "user1", "text": "msg1", "timestamp": "2026-01-01T00:00:00Z", "read": false, "message_id": "m-1"},
+            {"from": "user2", "text": "msg2", "timestamp": "2026-01-01T00:01:00Z", "read": true, "message_id": "m-2"}
+        ]);
+        fs::write(&inbox_file, serde_json::to_string(&messages).unwrap()).unwrap();
+
+        assert!(!is_message_read(&inbox_file, "m-1"));
+        assert!(is_message_read(&inbox_file, "m-2"));
+        assert!(!is_message_read(&inbox_file, "m-3"));
+    }
+
+    #[test]
+    fn test_wait_for_read_times_out_when_unread() {
+        let temp_dir = TempDir::new().unwrap();
+        let inbox_file = temp_dir.path().join("test-agent.json");
+        let messages = serde_json::json!([
+            {"from": "user1", "text": "msg1", "timestamp": "2026-01-01T00:00:00Z", "read": false, "message_id": "m-1"}
+        ]);
+        fs::write(&inbox_file, serde_json::to_string(&messages).unwrap()).unwrap();
+
+        let result = wait_for_read(
+            "test-team",
+            "test-agent",
+            &inbox_file,
+            "m-1",
+            Duration::from_millis(200),
+        )
+        .unwrap();
+        assert_eq!(result, ReadWaitResult::Timeout);
+    }
 }
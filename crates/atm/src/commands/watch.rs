@@ -0,0 +1,249 @@
+//! `atm watch` — periodically refreshed team snapshot for plain terminals.
+//!
+//! Combines team members, daemon-reported agent states, inbox counts, and the
+//! most recent team events from the unified log into a single view. Unlike the
+//! TUI it never enters the alternate screen, so it works over SSH and in
+//! headless sessions.
+//!
+//! # Examples
+//!
+//! ```text
+//! # Redraw every 2 seconds (default)
+//! atm watch atm-dev
+//!
+//! # Append a new snapshot every 10 seconds instead of redrawing
+//! atm watch atm-dev --interval 10 --no-clear
+//! ```
+
+use agent_team_mail_core::config::{ConfigOverrides, resolve_config};
+use agent_team_mail_core::daemon_client::query_list_agents_for_team;
+use agent_team_mail_core::io::inbox_iter;
+use agent_team_mail_core::log_reader::{LogFilter, LogReader, format_event_human};
+use agent_team_mail_core::logging_event::{LogEventV1, configured_log_path};
+use agent_team_mail_core::schema::TeamConfig;
+use anyhow::{Context, Result};
+use clap::Args;
+use std::collections::HashMap;
+use std::io::{IsTerminal, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::util::settings::{get_home_dir, teams_root_dir_for};
+
+/// Number of recent team events shown per snapshot.
+const RECENT_EVENT_COUNT: usize = 10;
+
+/// Only events newer than this are considered for the recent-events section.
+const RECENT_EVENT_WINDOW: Duration = Duration::from_secs(3600);
+
+/// ANSI sequence: clear screen and move the cursor home.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Watch a live team snapshot (members, states, inbox counts, recent events)
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    /// Team name (optional, uses default team if not specified)
+    team: Option<String>,
+
+    /// Refresh interval in seconds
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u64).range(1..))]
+    interval: u64,
+
+    /// Append each snapshot instead of clearing the screen (implied when
+    /// stdout is not a terminal)
+    #[arg(long)]
+    no_clear: bool,
+}
+
+/// One member row in a watch snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WatchMemberRow {
+    name: String,
+    state: String,
+    unread: usize,
+    total: usize,
+}
+
+/// Execute `atm watch`. Runs until interrupted.
+pub fn execute(args: WatchArgs) -> Result<()> {
+    let home_dir = get_home_dir()?;
+    let current_dir = std::env::current_dir()?;
+    let overrides = ConfigOverrides {
+        team: args.team.clone(),
+        ..Default::default()
+    };
+    let config = resolve_config(&overrides, &current_dir, &home_dir)?;
+    let team_name = config.core.default_team.clone();
+
+    let team_dir = teams_root_dir_for(&home_dir).join(&team_name);
+    if !team_dir.exists() {
+        anyhow::bail!("Team '{team_name}' not found (directory {team_dir:?} doesn't exist)");
+    }
+    let log_path = configured_log_path(&home_dir);
+    let interval = Duration::from_secs(args.interval);
+
+    loop {
+        let snapshot = build_snapshot(&team_name, &team_dir, &log_path)?;
+        let mut stdout = std::io::stdout().lock();
+        if !args.no_clear && stdout.is_terminal() {
+            write!(stdout, "{CLEAR_SCREEN}")?;
+        }
+        writeln!(stdout, "{snapshot}")?;
+        stdout.flush()?;
+        drop(stdout);
+        std::thread::sleep(interval);
+    }
+}
+
+/// Gather current team data and render it as a snapshot.
+fn build_snapshot(team: &str, team_dir: &Path, log_path: &Path) -> Result<String> {
+    let config_path = team_dir.join("config.json");
+    let team_config: TeamConfig = serde_json::from_str(
+        &std::fs::read_to_string(&config_path)
+            .with_context(|| format!("Failed to read team config at {config_path:?}"))?,
+    )
+    .with_context(|| format!("Failed to parse team config at {config_path:?}"))?;
+
+    let states: HashMap<String, String> = query_list_agents_for_team(team)
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|a| (a.agent, a.state))
+        .collect();
+
+    let rows = build_member_rows(&team_config, &states, &team_dir.join("inboxes"));
+    let events = recent_team_events(log_path, team, RECENT_EVENT_COUNT);
+    let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    Ok(render_snapshot(team, &now, &rows, &events))
+}
+
+fn build_member_rows(
+    team_config: &TeamConfig,
+    states: &HashMap<String, String>,
+    inboxes_dir: &Path,
+) -> Vec<WatchMemberRow> {
+    team_config
+        .members
+        .iter()
+        .map(|member| {
+            let (mut unread, mut total) = (0, 0);
+            if let Ok(messages) = inbox_iter(&inboxes_dir.join(format!("{}.json", member.name))) {
+                for message in messages.skip_invalid_records() {
+                    unread += usize::from(!message.read);
+                    total += 1;
+                }
+            }
+            WatchMemberRow {
+                name: member.name.clone(),
+                state: states
+                    .get(&member.name)
+                    .cloned()
+                    .unwrap_or_else(|| "unknown".to_string()),
+                unread,
+                total,
+            }
+        })
+        .collect()
+}
+
+/// Return the last `count` events for `team` from the unified log.
+///
+/// A missing or unreadable log yields no events rather than an error so the
+/// watch loop keeps running.
+fn recent_team_events(log_path: &Path, team: &str, count: usize) -> Vec<LogEventV1> {
+    let filter = LogFilter {
+        since: Some(RECENT_EVENT_WINDOW),
+        ..Default::default()
+    };
+    let mut events: Vec<LogEventV1> = LogReader::new(log_path.to_path_buf(), filter)
+        .read_filtered()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|e| e.team.as_deref() == Some(team))
+        .collect();
+    let skip = events.len().saturating_sub(count);
+    events.drain(..skip);
+    events
+}
+
+fn render_snapshot(
+    team: &str,
+    now: &str,
+    rows: &[WatchMemberRow],
+    events: &[LogEventV1],
+) -> String {
+    let mut out = format!("Team: {team}    (updated {now})\n\n");
+    out.push_str(&format!("Members ({}):\n", rows.len()));
+    out.push_str(&format!(
+        "  {:<20} {:<10} {:>6} {:>6}\n",
+        "NAME", "STATE", "UNREAD", "TOTAL"
+    ));
+    for row in rows {
+        out.push_str(&format!(
+            "  {:<20} {:<10} {:>6} {:>6}\n",
+            row.name, row.state, row.unread, row.total
+        ));
+    }
+    out.push('\n');
+    out.push_str("Recent events:\n");
+    if events.is_empty() {
+        out.push_str("  (none)\n");
+    }
+    for event in events {
+        out.push_str(&format!("  {}\n", format_event_human(event)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agent_team_mail_core::logging_event::new_log_event;
+    use tempfile::TempDir;
+
+    fn team_event(team: &str, action: &str) -> LogEventV1 {
+        let mut event = new_log_event("atm", action, "atm::watch", "info");
+        event.team = Some(team.to_string());
+        event
+    }
+
+    #[test]
+    fn test_recent_team_events_filters_team_and_keeps_latest() {
+        let tmp = TempDir::new().unwrap();
+        let log_path = tmp.path().join("atm.log.jsonl");
+        let lines: Vec<String> = [
+            team_event("atm-dev", "first"),
+            team_event("other", "foreign"),
+            team_event("atm-dev", "second"),
+            team_event("atm-dev", "third"),
+        ]
+        .iter()
+        .map(|e| serde_json::to_string(e).unwrap())
+        .collect();
+        std::fs::write(&log_path, lines.join("\n") + "\n").unwrap();
+
+        let events = recent_team_events(&log_path, "atm-dev", 2);
+        let actions: Vec<_> = events.iter().map(|e| e.action.as_str()).collect();
+        assert_eq!(actions, vec!["second", "third"]);
+
+        assert!(recent_team_events(&tmp.path().join("missing.jsonl"), "atm-dev", 2).is_empty());
+    }
+
+    #[test]
+    fn test_render_snapshot_lists_members_and_placeholder_events() {
+        let rows = vec![WatchMemberRow {
+            name: "arch-ctm".to_string(),
+            state: "idle".to_string(),
+            unread: 2,
+            total: 5,
+        }];
+        let out = render_snapshot("atm-dev", "2026-02-11 10:00:00", &rows, &[]);
+        assert!(out.starts_with("Team: atm-dev"));
+        assert!(out.contains("Members (1):"));
+        let row = out.lines().find(|l| l.contains("arch-ctm")).unwrap();
+        assert!(row.contains("idle"));
+        assert!(row.trim_end().ends_with("2      5"));
+        assert!(out.contains("Recent events:\n  (none)"));
+    }
+}
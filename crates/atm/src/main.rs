@@ -211,6 +211,24 @@ fn install_cli_otel_event_hook() {
     }));
 }
 
+/// Install `[core] inbox_file_mode` from the config visible to this
+/// invocation so every inbox write below honors it.
+fn install_cli_inbox_file_mode() {
+    let (Ok(home_dir), Ok(current_dir)) = (
+        agent_team_mail_core::home::get_home_dir(),
+        std::env::current_dir(),
+    ) else {
+        return;
+    };
+    let overrides = agent_team_mail_core::config::ConfigOverrides::default();
+    if let Ok(config) =
+        agent_team_mail_core::config::resolve_config(&overrides, &current_dir, &home_dir)
+    {
+        agent_team_mail_core::config::install_inbox_file_mode(&config.core);
+        agent_team_mail_core::config::install_inbox_provenance(&config.core);
+    }
+}
+
 fn export_trace_records_from_entrypoint(records: &[TraceRecord], config: &OtelConfig) {
     let _ = sc_observability_otlp::export_traces(config, records);
 }
@@ -227,6 +245,22 @@ fn main() {
         unsafe { std::env::set_var("ATM_DAEMON_AUTOSTART", "1") };
     }
 
+    let cli = Cli::parse();
+    if cli.no_daemon() {
+        // SAFETY: process-local env mutation at startup before command execution.
+        unsafe { std::env::set_var("ATM_NO_DAEMON", "1") };
+    }
+
+    // Prompt integrations run on every shell prompt: no logging sinks, daemon
+    // socket, or telemetry export, just the command and its exit status.
+    if cli.is_hot_path() {
+        if let Err(e) = cli.execute() {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let _guards = logging::init_unified(
         "atm",
         logging::UnifiedLogMode::ProducerFanIn {
@@ -239,13 +273,13 @@ fn main() {
     )
     .unwrap_or_else(|_| logging::init_stderr_only());
     install_cli_otel_event_hook();
+    install_cli_inbox_file_mode();
     if let Ok(home_dir) = agent_team_mail_core::home::get_home_dir() {
         install_cli_teardown_hook(Arc::new(move || {
             let _ = flush_local_gh_observability_records(&home_dir);
         }));
     }
 
-    let cli = Cli::parse();
     let command_name = cli.command_name().to_string();
     let request_id = Uuid::new_v4().to_string();
     let trace_id = agent_team_mail_core::event_log::trace_id_for_request("atm", &request_id);
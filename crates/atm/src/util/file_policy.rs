@@ -1,9 +1,51 @@
 //! File access policy enforcement for file references
 
 use agent_team_mail_core::config::resolve_settings;
-use anyhow::Result;
+use agent_team_mail_core::text::truncate_bytes;
+use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
+/// Marker appended to a file body cut down to the message size cap.
+const TRUNCATED_SUFFIX: &str = "\n[atm] ... truncated: file exceeded the message size limit";
+
+/// Read `file_path` as a message body.
+///
+/// The file must be UTF-8.
+pub fn read_file_body(file_path: &Path) -> Result<String> {
+    std::fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read message file {}", file_path.display()))
+}
+
+/// Compose a message that carries the contents of `file_path` inline.
+///
+/// The result is `head` followed by `body`. The recipient never opens
+/// `file_path`, so [`check_file_reference`] does not apply and no share copy
+/// is made. `reserved` bytes are kept free for text the caller adds
+/// afterwards, so only `body` is cut: when everything would exceed
+/// `max_bytes` the body is truncated on a char boundary, a truncation marker
+/// is appended, and a warning is printed to stderr.
+pub fn embed_file_body(
+    file_path: &Path,
+    head: &str,
+    body: &str,
+    reserved: usize,
+    max_bytes: usize,
+) -> String {
+    let budget = max_bytes.saturating_sub(reserved + head.len());
+    let body = match truncate_bytes(body, budget, TRUNCATED_SUFFIX) {
+        Some(truncated) => {
+            eprintln!(
+                "Warning: {} is {} bytes; truncated to fit the {max_bytes}-byte message limit",
+                file_path.display(),
+                body.len(),
+            );
+            truncated
+        }
+        None => body.to_string(),
+    };
+    format!("{head}{body}")
+}
+
 /// Walk up from `start_dir` to find the nearest `.git` directory,
 /// returning the repo root (the parent of `.git`).
 pub fn find_git_root(start_dir: &Path) -> Option<PathBuf> {
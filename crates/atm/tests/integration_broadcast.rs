@@ -139,6 +139,49 @@ fn test_broadcast_basic_message() {
     assert!(!human_inbox.exists(), "Sender should not receive broadcast");
 }
 
+#[test]
+fn test_broadcast_to_group_only_reaches_members() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+    let config_path = team_dir.join("config.json");
+    let mut config: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+    config["groups"] = serde_json::json!({"reviewers": ["agent-1", "agent-3"]});
+    fs::write(&config_path, serde_json::to_string_pretty(&config).unwrap()).unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("broadcast")
+        .arg("--group")
+        .arg("reviewers")
+        .arg("Please review")
+        .assert()
+        .success();
+
+    let inboxes_dir = team_dir.join("inboxes");
+    assert!(inboxes_dir.join("agent-1.json").exists());
+    assert!(inboxes_dir.join("agent-3.json").exists());
+    assert!(!inboxes_dir.join("agent-2.json").exists());
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "test-team")
+        .arg("broadcast")
+        .arg("--group")
+        .arg("reveiwers")
+        .arg("Typo")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("did you mean: reviewers?"),
+        "stderr: {stderr}"
+    );
+}
+
 #[test]
 fn test_broadcast_with_team_flag() {
     let temp_dir = TempDir::new().unwrap();
@@ -188,6 +231,105 @@ fn test_broadcast_with_stdin() {
     assert_eq!(messages[0]["text"], "Broadcast from stdin");
 }
 
+#[test]
+fn test_broadcast_with_file_sends_contents() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(workdir.join(".git")).unwrap();
+    let body_file = workdir.join("design.md");
+    fs::write(&body_file, "# Design\n\nShip it.\n").unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("broadcast")
+        .arg("--file")
+        .arg(&body_file)
+        .assert()
+        .success();
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/agent-1.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    assert_eq!(messages[0]["text"], "# Design\n\nShip it.\n");
+}
+
+#[test]
+fn test_broadcast_with_oversize_file_truncates_and_warns() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+    let body_file = temp_dir.path().join("big.log");
+    fs::write(&body_file, "x".repeat(2 * 1024 * 1024)).unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("broadcast")
+        .arg("--file")
+        .arg(&body_file)
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("truncated"));
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/agent-1.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    let text = messages[0]["text"].as_str().unwrap();
+    assert!(text.len() <= 1_048_576);
+    assert!(text.contains("truncated: file exceeded the message size limit"));
+}
+
+#[test]
+fn test_broadcast_with_file_outside_repo_embeds_without_share_copy() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+    let body_file = temp_dir.path().join("notes.md");
+    fs::write(&body_file, "Ship it.").unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("broadcast")
+        .arg("--file")
+        .arg(&body_file)
+        .assert()
+        .success();
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/agent-1.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    assert_eq!(messages[0]["text"], "Ship it.");
+    assert!(!temp_dir.path().join(".config/atm/share").exists());
+}
+
+#[test]
+fn test_broadcast_active_only_without_daemon_reaches_all_members() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .env("ATM_NO_DAEMON", "1")
+        .arg("broadcast")
+        .arg("--active-only")
+        .arg("Status check")
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("daemon unreachable"));
+
+    for agent in ["agent-1", "agent-2", "agent-3"] {
+        assert!(team_dir.join(format!("inboxes/{agent}.json")).exists());
+    }
+}
+
 #[test]
 fn test_broadcast_with_summary() {
     let temp_dir = TempDir::new().unwrap();
@@ -378,3 +520,73 @@ fn test_broadcast_cross_team() {
 
     assert!(!team_a_inbox.exists());
 }
+
+#[test]
+fn test_broadcast_exclude_skips_named_members() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "test-team")
+        .args([
+            "broadcast",
+            "--exclude",
+            "agent-2",
+            "--json",
+            "Skip agent-2",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["summary"]["written"], 2);
+
+    let inboxes_dir = team_dir.join("inboxes");
+    assert!(inboxes_dir.join("agent-1.json").exists());
+    assert!(!inboxes_dir.join("agent-2.json").exists());
+    assert!(inboxes_dir.join("agent-3.json").exists());
+    assert!(!inboxes_dir.join("human.json").exists());
+}
+
+#[test]
+fn test_broadcast_include_self_delivers_to_sender() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .args(["broadcast", "--include-self", "Note to all"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Broadcast sent to 4 agents"));
+
+    assert!(team_dir.join("inboxes/human.json").exists());
+}
+
+#[test]
+fn test_broadcast_excluding_everyone_reports_no_recipients() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .args([
+            "broadcast",
+            "--exclude",
+            "agent-1",
+            "--exclude",
+            "agent-2",
+            "--exclude",
+            "agent-3",
+            "Nobody hears this",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("No recipients"));
+
+    assert!(!team_dir.join("inboxes/agent-1.json").exists());
+}
@@ -0,0 +1,84 @@
+//! Integration tests for the global `--color` flag.
+
+use assert_cmd::cargo;
+use std::fs;
+use tempfile::TempDir;
+
+fn write_warn_event(temp_dir: &TempDir) -> std::path::PathBuf {
+    let event =
+        agent_team_mail_core::logging_event::new_log_event("atm", "send", "atm::send", "warn");
+    let log_path = temp_dir.path().join("atm.log.jsonl");
+    fs::write(&log_path, serde_json::to_string(&event).unwrap() + "\n").unwrap();
+    log_path
+}
+
+fn run_logs(temp_dir: &TempDir, color_args: &[&str], no_color: Option<&str>) -> String {
+    let log_path = write_warn_event(temp_dir);
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    cmd.env("ATM_HOME", temp_dir.path())
+        .env("ATM_DAEMON_AUTOSTART", "0")
+        .env_remove("ATM_NO_COLOR")
+        .env_remove("NO_COLOR");
+    if let Some(value) = no_color {
+        cmd.env("NO_COLOR", value);
+    }
+    let output = cmd
+        .args(color_args)
+        .arg("logs")
+        .arg("--file")
+        .arg(&log_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_color_auto_is_plain_when_piped() {
+    let temp_dir = TempDir::new().unwrap();
+    let stdout = run_logs(&temp_dir, &[], None);
+    assert!(stdout.contains("WARN"));
+    assert!(
+        !stdout.contains('\x1b'),
+        "piped output must not contain ANSI codes"
+    );
+}
+
+#[test]
+fn test_color_always_forces_ansi_and_never_disables_it() {
+    let temp_dir = TempDir::new().unwrap();
+    let always = run_logs(&temp_dir, &["--color", "always"], None);
+    assert!(
+        always.contains("\x1b[33m"),
+        "--color always must colorize: {always:?}"
+    );
+
+    // `always` is an explicit request and wins over NO_COLOR.
+    let always_no_color = run_logs(&temp_dir, &["--color", "always"], Some("1"));
+    assert!(always_no_color.contains('\x1b'));
+
+    let never = run_logs(&temp_dir, &["--color=never"], None);
+    assert!(!never.contains('\x1b'));
+
+    // The original `--color-output` spelling is kept as an alias.
+    let alias = run_logs(&temp_dir, &["--color-output", "always"], None);
+    assert!(alias.contains('\x1b'));
+}
+
+#[test]
+fn test_teams_spawn_separates_runtime_color_from_global_color() {
+    let temp_dir = TempDir::new().unwrap();
+    let output = cargo::cargo_bin_cmd!("atm")
+        .env("ATM_HOME", temp_dir.path())
+        .env("ATM_DAEMON_AUTOSTART", "0")
+        .args(["teams", "spawn", "--color", "never", "--help"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("--runtime-color <RUNTIME_COLOR>"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("--color <COLOR>"), "{stdout}");
+}
@@ -495,7 +495,6 @@ fn test_spool_drain_delivery_cycle() {
     use agent_team_mail_core::io::inbox::inbox_append;
     use agent_team_mail_core::io::lock::acquire_lock;
     use agent_team_mail_core::schema::InboxMessage;
-    use std::collections::HashMap;
 
     let temp_dir = TempDir::new().unwrap();
     // Use ATM_HOME to redirect spool dir — works cross-platform (dirs::config_dir()
@@ -514,16 +513,7 @@ fn test_spool_drain_delivery_cycle() {
     let held_lock = acquire_lock(&lock_path, 0).unwrap();
 
     // Step 2: Try to append message - should be queued
-    let message = InboxMessage {
-        from: "tester".to_string(),
-        source_team: None,
-        text: "Spooled message".to_string(),
-        timestamp: chrono::Utc::now().to_rfc3339(),
-        read: false,
-        summary: None,
-        message_id: Some("spool-test-001".to_string()),
-        unknown_fields: HashMap::new(),
-    };
+    let message = InboxMessage::new("tester", "Spooled message").message_id("spool-test-001");
 
     let outcome = inbox_append(&inbox_path, &message, "test-team", "agent-a").unwrap();
     assert!(
@@ -130,6 +130,8 @@ while running:
             }
         elif command == "agent-state":
             response_payload = {"state": "idle", "last_transition": None}
+        elif command == "ping":
+            response_payload = {"pid": os.getpid()}
         else:
             response_payload = {}
         response = {
@@ -295,6 +297,63 @@ fn test_status_noops_when_daemon_already_healthy() {
     );
 }
 
+#[test]
+#[cfg(unix)]
+fn test_daemon_health_succeeds_silently_against_running_daemon() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+    let script = write_fake_daemon_script(home);
+    let daemon = Command::new(&script).env("ATM_HOME", home).spawn().unwrap();
+    let _daemon_guard = daemon_process_guard::DaemonProcessGuard::from_child(
+        daemon,
+        std::path::Path::new(&script),
+        home,
+    );
+    wait_for_daemon_socket(home);
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    let output = cmd
+        .env("ATM_HOME", home)
+        .env("ATM_DAEMON_BIN", &script)
+        .args(["daemon", "health", "--timeout", "2"])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "health probe should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(output.stdout.is_empty());
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_daemon_health_fails_without_autostarting_daemon() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+    let script = write_fake_daemon_script(home);
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    let output = cmd
+        .env("ATM_HOME", home)
+        .env("ATM_DAEMON_BIN", &script)
+        .args(["daemon", "health", "--timeout", "1"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(stderr.lines().count(), 1, "unexpected stderr: {stderr}");
+    assert!(
+        stderr.contains("unreachable"),
+        "unexpected stderr: {stderr}"
+    );
+    assert_eq!(spawn_count(home), 0, "health probe must not autostart");
+}
+
 #[test]
 #[cfg(unix)]
 fn test_concurrent_multi_team_status_uses_single_daemon_instance() {
@@ -142,6 +142,27 @@ fn test_teams_command_json_output() {
     cmd.arg("teams").arg("--json").assert().success();
 }
 
+#[test]
+fn test_teams_live_json_without_daemon_reports_unavailable() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .arg("teams")
+        .arg("--live")
+        .arg("--json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["daemonAvailable"], false);
+    assert_eq!(json["teams"][0]["name"], "test-team");
+    assert!(json["teams"][0].get("liveAgents").is_none());
+}
+
 #[test]
 fn test_members_command_default_team() {
     let temp_dir = TempDir::new().unwrap();
@@ -240,6 +261,38 @@ fn test_status_command_explicit_team() {
         .success();
 }
 
+#[test]
+fn test_status_command_tree_without_daemon_groups_under_unknown() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_test_team(&temp_dir, "tree-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .arg("status")
+        .arg("tree-team")
+        .arg("--tree")
+        .arg("--json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let value: Value = serde_json::from_slice(&output).expect("status tree json");
+    assert_eq!(value["team"], "tree-team");
+    assert_eq!(value["daemonAvailable"], false);
+    let repos = value["repos"].as_array().unwrap();
+    assert_eq!(repos.len(), 1);
+    assert_eq!(repos[0]["repo"], "(unknown)");
+    let agents = repos[0]["branches"][0]["agents"].as_array().unwrap();
+    assert!(
+        agents
+            .iter()
+            .any(|agent| agent["name"] == "agent-1" && agent["state"] == "unknown")
+    );
+}
+
 #[test]
 fn test_status_command_team_not_found() {
     let temp_dir = TempDir::new().unwrap();
@@ -308,6 +361,73 @@ fn test_status_command_json_output() {
         .expect("agent-1 row");
     assert_eq!(member["unreadCount"].as_u64(), Some(1));
     assert_eq!(member["pendingCount"].as_u64(), Some(2));
+    assert_eq!(member["inboxCount"].as_u64(), Some(3));
+    assert_eq!(member["state"], "unknown");
+    assert_eq!(value["daemonAvailable"], false);
+}
+
+#[test]
+fn test_status_command_format_overrides_configured_display_format() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_test_team(&temp_dir, "test-team");
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(&workdir).unwrap();
+    fs::write(
+        workdir.join(".atm.toml"),
+        "[display]\nformat = \"json\"\ncolor = false\ntimestamps = \"relative\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "test-team")
+        .args(["status", "--format", "text"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let text = String::from_utf8(output).unwrap();
+    assert!(text.starts_with("Team: test-team"), "{text}");
+
+    // The configured format prints the overview; `--format json` prints the
+    // per-agent dashboard shape with snake_case keys.
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let configured = cmd
+        .env("ATM_TEAM", "test-team")
+        .arg("status")
+        .output()
+        .unwrap();
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let explicit = cmd
+        .env("ATM_TEAM", "test-team")
+        .args(["status", "--format", "json"])
+        .output()
+        .unwrap();
+    let configured: Value = serde_json::from_slice(&configured.stdout).unwrap();
+    let explicit: Value = serde_json::from_slice(&explicit.stdout).unwrap();
+    assert!(configured["members"][0].get("inboxCount").is_some());
+    let keys = |v: &Value| -> Vec<String> {
+        let mut keys: Vec<String> = v.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        keys
+    };
+    assert_eq!(keys(&explicit), ["agents", "daemonAvailable", "team"]);
+    let agent = &explicit["agents"][0];
+    assert_eq!(
+        keys(agent),
+        [
+            "inbox_count",
+            "last_active",
+            "name",
+            "state",
+            "unread_count"
+        ]
+    );
+    assert_eq!(agent["state"], "unknown");
 }
 
 #[test]
@@ -328,6 +448,60 @@ fn test_config_command_json_output() {
     cmd.arg("config").arg("--json").assert().success();
 }
 
+#[test]
+fn test_config_check_reports_errors_and_exits_nonzero() {
+    let temp_dir = TempDir::new().unwrap();
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(&workdir).unwrap();
+    fs::write(
+        workdir.join(".atm.toml"),
+        "[retention]\nmax_count = 0\n\n[plugins.bridge]\nenabled = true\n\n\
+         [[plugins.bridge.remotes]]\nhostname = \"laptop\"\n\n[plugins.nope]\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.arg("config")
+        .arg("check")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("error: "))
+        .stdout(predicates::str::contains("[retention.max_count]"))
+        .stdout(predicates::str::contains(
+            "[plugins.bridge.remotes[0].address]",
+        ))
+        .stdout(predicates::str::contains("warning: "))
+        .stdout(predicates::str::contains("[plugins.nope]"))
+        .stdout(predicates::str::contains("2 error(s), 1 warning(s)"));
+}
+
+#[test]
+fn test_config_check_clean_config_succeeds() {
+    let temp_dir = TempDir::new().unwrap();
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(&workdir).unwrap();
+    fs::write(
+        workdir.join(".atm.toml"),
+        "[retention]\nmax_age = \"30d\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .arg("config")
+        .arg("check")
+        .arg("--json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(report["diagnostics"], serde_json::json!([]));
+}
+
 #[test]
 fn test_empty_team_members() {
     let temp_dir = TempDir::new().unwrap();
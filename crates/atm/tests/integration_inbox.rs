@@ -75,12 +75,12 @@ fn setup_test_team(temp_dir: &TempDir, team_name: &str) -> PathBuf {
 
 /// Create a test inbox with messages
 fn create_test_inbox(team_dir: &Path, agent_name: &str, messages: Vec<serde_json::Value>) {
+    // Write beside the inboxes directory and rename, as ATM writers do, so a
+    // concurrent `inbox --watch` never reads a half-written file.
     let inbox_path = team_dir.join("inboxes").join(format!("{agent_name}.json"));
-    fs::write(
-        &inbox_path,
-        serde_json::to_string_pretty(&messages).unwrap(),
-    )
-    .unwrap();
+    let tmp_path = team_dir.join(format!(".{agent_name}.json.tmp"));
+    fs::write(&tmp_path, serde_json::to_string_pretty(&messages).unwrap()).unwrap();
+    fs::rename(&tmp_path, &inbox_path).unwrap();
 }
 
 #[test]
@@ -207,3 +207,283 @@ fn test_inbox_with_team_flag() {
         .assert()
         .success();
 }
+
+#[test]
+fn test_inbox_json_schema_snapshot() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+    create_test_inbox(
+        &team_dir,
+        "test-agent",
+        vec![
+            serde_json::json!({
+                "from": "team-lead",
+                "text": "Unread",
+                "timestamp": "2026-02-11T10:00:00Z",
+                "read": false,
+                "message_id": "msg-1"
+            }),
+            serde_json::json!({
+                "from": "team-lead",
+                "text": "Read",
+                "timestamp": "2026-02-11T11:00:00Z",
+                "read": true,
+                "message_id": "msg-2"
+            }),
+        ],
+    );
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "test-team")
+        .arg("inbox")
+        .arg("--no-since-last-seen")
+        .arg("--json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "team": "test-team",
+            "status": "ok",
+            "since_last_seen": false,
+            "members": [
+                {"agent": "team-lead", "new": 0, "total": 0, "latest_timestamp": null},
+                {
+                    "agent": "test-agent",
+                    "new": 1,
+                    "total": 2,
+                    "latest_timestamp": "2026-02-11T11:00:00Z"
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_inbox_json_all_teams_is_array() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_test_team(&temp_dir, "team-a");
+    setup_test_team(&temp_dir, "team-b");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .arg("inbox")
+        .arg("--all-teams")
+        .arg("--json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let teams: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["team"].as_str().unwrap())
+        .collect();
+    assert_eq!(teams, vec!["team-a", "team-b"]);
+}
+
+#[test]
+fn test_inbox_count_prints_bare_integer() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+    let messages = vec![
+        serde_json::json!({
+            "from": "team-lead",
+            "text": "Unread",
+            "timestamp": "2026-02-11T10:00:00Z",
+            "read": false,
+            "message_id": "msg-001"
+        }),
+        serde_json::json!({
+            "from": "team-lead",
+            "text": "Read",
+            "timestamp": "2026-02-11T11:00:00Z",
+            "read": true,
+            "message_id": "msg-002"
+        }),
+    ];
+    create_test_inbox(&team_dir, "test-agent", messages);
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.args(["inbox", "count", "test-team", "test-agent"])
+        .assert()
+        .success()
+        .stdout("2\n");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.args(["inbox", "count", "test-team", "test-agent", "--unread"])
+        .assert()
+        .success()
+        .stdout("1\n");
+}
+
+#[test]
+fn test_inbox_count_missing_inbox_is_zero() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.args(["inbox", "count", "no-such-team", "nobody", "--unread"])
+        .assert()
+        .success()
+        .stdout("0\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_inbox_watch_prints_only_new_messages_and_exits_on_sigint() {
+    use std::io::BufRead;
+    use std::time::Duration;
+
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+    let old = serde_json::json!({
+        "from": "team-lead",
+        "text": "old news",
+        "timestamp": "2026-02-11T10:00:00Z",
+        "read": false,
+        "message_id": "msg-old"
+    });
+    create_test_inbox(&team_dir, "test-agent", vec![old.clone()]);
+
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(&workdir).unwrap();
+    let mut child = std::process::Command::new(cargo::cargo_bin("atm"))
+        .env("ATM_HOME", temp_dir.path())
+        .env("ATM_DAEMON_AUTOSTART", "0")
+        .env("ATM_TEAM", "test-team")
+        .env_remove("ATM_IDENTITY")
+        .env_remove("ATM_CONFIG")
+        .env_remove("CLAUDE_SESSION_ID")
+        .current_dir(&workdir)
+        .args(["inbox", "--watch"])
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::BufReader::new(stdout)
+            .lines()
+            .map_while(Result::ok)
+        {
+            let _ = tx.send(line);
+        }
+    });
+
+    // Let the watcher seed its state from the existing inbox.
+    std::thread::sleep(Duration::from_millis(1500));
+
+    // Marking a known message read is not news.
+    let mut old = old;
+    old["read"] = serde_json::json!(true);
+    create_test_inbox(&team_dir, "test-agent", vec![old.clone()]);
+    std::thread::sleep(Duration::from_millis(500));
+
+    let new = serde_json::json!({
+        "from": "ci-agent",
+        "text": "fresh news\nsecond line",
+        "timestamp": "2026-02-11T11:00:00Z",
+        "read": false,
+        "message_id": "msg-new"
+    });
+    create_test_inbox(&team_dir, "test-agent", vec![old, new]);
+
+    let line = rx
+        .recv_timeout(Duration::from_secs(10))
+        .expect("watch never reported the new message");
+    assert!(!line.contains("old news"), "reported old message: {line}");
+    assert!(line.contains("new message"), "{line}");
+    assert!(line.starts_with('['), "missing timestamp prefix: {line}");
+    assert!(line.contains("test-agent@test-team"), "{line}");
+    assert!(line.contains("from=ci-agent"), "{line}");
+    assert!(line.contains("summary=fresh news"), "{line}");
+    assert!(!line.contains("second line"), "{line}");
+    let extra = rx.recv_timeout(Duration::from_millis(500));
+    assert!(extra.is_err(), "unexpected watch output: {extra:?}");
+
+    // SAFETY: signalling our own child process.
+    unsafe { libc::kill(child.id() as libc::pid_t, libc::SIGINT) };
+    let status = child.wait().unwrap();
+    assert!(status.success(), "watch did not exit cleanly: {status:?}");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_inbox_watch_survives_unreadable_inbox() {
+    use std::io::BufRead;
+    use std::time::Duration;
+
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+    create_test_inbox(&team_dir, "test-agent", vec![]);
+
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(&workdir).unwrap();
+    let mut child = std::process::Command::new(cargo::cargo_bin("atm"))
+        .env("ATM_HOME", temp_dir.path())
+        .env("ATM_DAEMON_AUTOSTART", "0")
+        .env("ATM_TEAM", "test-team")
+        .env_remove("ATM_IDENTITY")
+        .env_remove("ATM_CONFIG")
+        .env_remove("CLAUDE_SESSION_ID")
+        .current_dir(&workdir)
+        .args(["inbox", "--watch"])
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::BufReader::new(stdout)
+            .lines()
+            .map_while(Result::ok)
+        {
+            let _ = tx.send(line);
+        }
+    });
+    std::thread::sleep(Duration::from_millis(1500));
+
+    // An inbox that fails to parse is logged, not fatal.
+    let inbox_path = team_dir.join("inboxes").join("test-agent.json");
+    let tmp_path = team_dir.join(".test-agent.json.tmp");
+    fs::write(&tmp_path, "[{not json").unwrap();
+    fs::rename(&tmp_path, &inbox_path).unwrap();
+    std::thread::sleep(Duration::from_millis(500));
+
+    let new = serde_json::json!({
+        "from": "ci-agent",
+        "text": "after the glitch",
+        "timestamp": "2026-02-11T11:00:00Z",
+        "read": false,
+        "message_id": "msg-after"
+    });
+    create_test_inbox(&team_dir, "test-agent", vec![new]);
+
+    let line = rx
+        .recv_timeout(Duration::from_secs(10))
+        .expect("watch stopped after an unreadable inbox");
+    assert!(line.contains("summary=after the glitch"), "{line}");
+
+    // SAFETY: signalling our own child process.
+    unsafe { libc::kill(child.id() as libc::pid_t, libc::SIGINT) };
+    let status = child.wait().unwrap();
+    assert!(status.success(), "watch did not exit cleanly: {status:?}");
+}
@@ -0,0 +1,219 @@
+use assert_cmd::cargo;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn set_home_env(cmd: &mut assert_cmd::Command, temp_dir: &TempDir) {
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(&workdir).unwrap();
+    cmd.env("ATM_HOME", temp_dir.path())
+        .env("ATM_DAEMON_AUTOSTART", "0")
+        .env_remove("ATM_CONFIG")
+        .env_remove("ATM_TEAM")
+        .env_remove("ATM_IDENTITY")
+        .current_dir(&workdir);
+}
+
+fn member(team: &str, name: &str) -> serde_json::Value {
+    serde_json::json!({
+        "agentId": format!("{name}@{team}"),
+        "name": name,
+        "agentType": "general-purpose",
+        "model": "unknown",
+        "joinedAt": 1739284800000u64,
+        "tmuxPaneId": "",
+        "cwd": ".",
+        "subscriptions": []
+    })
+}
+
+fn message(from: &str, text: &str) -> serde_json::Value {
+    serde_json::json!({
+        "from": from,
+        "text": text,
+        "timestamp": "2026-02-11T14:30:00Z",
+        "read": false
+    })
+}
+
+fn write_team(home: &TempDir, team: &str) -> std::path::PathBuf {
+    let team_dir = home.path().join(".claude/teams").join(team);
+    fs::create_dir_all(team_dir.join("inboxes")).unwrap();
+    let config = serde_json::json!({
+        "name": team,
+        "createdAt": 1739284800000u64,
+        "leadAgentId": format!("team-lead@{team}"),
+        "leadSessionId": "lead-sess",
+        "members": [member(team, "team-lead"), member(team, "arch-ctm")],
+        "groups": {"reviewers": ["arch-ctm"]}
+    });
+    fs::write(
+        team_dir.join("config.json"),
+        serde_json::to_string_pretty(&config).unwrap(),
+    )
+    .unwrap();
+    fs::write(
+        team_dir.join("inboxes/arch-ctm.json"),
+        serde_json::to_string(&vec![message("team-lead", "hello")]).unwrap(),
+    )
+    .unwrap();
+    fs::write(
+        team_dir.join("inboxes/team-lead.json"),
+        serde_json::to_string(&vec![message("arch-ctm", "done")]).unwrap(),
+    )
+    .unwrap();
+    team_dir
+}
+
+fn write_session_lock(home: &TempDir, team: &str, name: &str, pid: u32) -> std::path::PathBuf {
+    let dir = home.path().join(".config/atm/agent-sessions").join(team);
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(format!("{name}.lock"));
+    fs::write(
+        &path,
+        serde_json::json!({"pid": pid, "agent_id": "codex:test"}).to_string(),
+    )
+    .unwrap();
+    path
+}
+
+fn read_json(path: &Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn rename_cmd(home: &TempDir, extra: &[&str]) -> assert_cmd::Command {
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, home);
+    cmd.args(["members", "rename", "atm-dev", "arch-ctm", "arch-lead"])
+        .args(extra);
+    cmd
+}
+
+#[test]
+fn test_members_rename_migrates_config_inbox_and_lock() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = write_team(&temp_dir, "atm-dev");
+    // A PID that cannot be running, so the lock is stale and safe to migrate.
+    let lock = write_session_lock(&temp_dir, "atm-dev", "arch-ctm", 999_999_999);
+
+    rename_cmd(&temp_dir, &["--rewrite-from"])
+        .assert()
+        .success();
+
+    let config = read_json(&team_dir.join("config.json"));
+    let names: Vec<&str> = config["members"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| m["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["team-lead", "arch-lead"]);
+    assert_eq!(config["members"][1]["agentId"], "arch-lead@atm-dev");
+    assert_eq!(config["groups"]["reviewers"][0], "arch-lead");
+
+    assert!(!team_dir.join("inboxes/arch-ctm.json").exists());
+    let inbox = read_json(&team_dir.join("inboxes/arch-lead.json"));
+    assert_eq!(inbox[0]["text"], "hello");
+    let lead_inbox = read_json(&team_dir.join("inboxes/team-lead.json"));
+    assert_eq!(lead_inbox[0]["from"], "arch-lead");
+
+    assert!(!lock.exists());
+    assert!(lock.with_file_name("arch-lead.lock").exists());
+}
+
+#[test]
+fn test_members_rename_dry_run_reports_plan_without_mutation() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = write_team(&temp_dir, "atm-dev");
+    let config_before = fs::read_to_string(team_dir.join("config.json")).unwrap();
+
+    let output = rename_cmd(&temp_dir, &["--dry-run", "--rewrite-from", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let plan: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(plan["dryRun"], true);
+    assert_eq!(plan["moves"].as_array().unwrap().len(), 1);
+    assert_eq!(plan["fromRewrites"][0]["inbox"], "team-lead");
+    assert_eq!(plan["fromRewrites"][0]["messages"], 1);
+
+    assert_eq!(
+        fs::read_to_string(team_dir.join("config.json")).unwrap(),
+        config_before
+    );
+    assert!(team_dir.join("inboxes/arch-ctm.json").exists());
+}
+
+#[test]
+fn test_members_rename_refuses_live_session_without_force() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = write_team(&temp_dir, "atm-dev");
+    let lock = write_session_lock(&temp_dir, "atm-dev", "arch-ctm", std::process::id());
+
+    rename_cmd(&temp_dir, &[])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("live session"));
+    assert!(team_dir.join("inboxes/arch-ctm.json").exists());
+
+    rename_cmd(&temp_dir, &["--force"]).assert().success();
+    assert!(lock.with_file_name("arch-lead.lock").exists());
+}
+
+#[test]
+fn test_members_rename_rejects_existing_target() {
+    let temp_dir = TempDir::new().unwrap();
+    write_team(&temp_dir, "atm-dev");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.args(["members", "rename", "atm-dev", "arch-ctm", "team-lead"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("already exists"));
+}
+
+#[test]
+fn test_members_rename_carries_id_counter_and_origin_inboxes() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = write_team(&temp_dir, "atm-dev");
+    let send = |to: &str, text: &str| {
+        let mut cmd = cargo::cargo_bin_cmd!("atm");
+        set_home_env(&mut cmd, &temp_dir);
+        fs::write(
+            temp_dir.path().join("workdir/.atm.toml"),
+            "[messaging]\nid_scheme = \"counter\"\n",
+        )
+        .unwrap();
+        cmd.env("ATM_TEAM", "atm-dev")
+            .env("ATM_IDENTITY", "team-lead")
+            .args(["send", to, text])
+            .assert()
+            .success();
+    };
+    send("arch-ctm", "before rename");
+    fs::write(
+        team_dir.join("inboxes/arch-ctm.remote1.json"),
+        serde_json::to_string(&vec![message("team-lead", "bridged")]).unwrap(),
+    )
+    .unwrap();
+
+    rename_cmd(&temp_dir, &[]).assert().success();
+    send("arch-lead", "after rename");
+
+    assert!(!team_dir.join("inboxes/arch-ctm.seq").exists());
+    assert!(!team_dir.join("inboxes/arch-ctm.remote1.json").exists());
+    let origin = read_json(&team_dir.join("inboxes/arch-lead.remote1.json"));
+    assert_eq!(origin[0]["text"], "bridged");
+
+    let inbox = read_json(&team_dir.join("inboxes/arch-lead.json"));
+    let ids: Vec<&str> = inbox
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|m| m["message_id"].as_str())
+        .filter_map(|id| id.rsplit('/').next())
+        .collect();
+    assert_eq!(ids, vec!["00000000000000000001", "00000000000000000002"]);
+    assert_eq!(inbox[2]["text"], "after rename");
+}
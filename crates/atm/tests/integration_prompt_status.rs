@@ -0,0 +1,79 @@
+//! Integration tests for `atm prompt-status`
+
+use assert_cmd::cargo;
+use std::fs;
+use tempfile::TempDir;
+
+fn prompt_status_cmd(temp_dir: &TempDir) -> assert_cmd::Command {
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(&workdir).unwrap();
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    cmd.env("ATM_HOME", temp_dir.path())
+        .env("ATM_DAEMON_AUTOSTART", "0")
+        .env("ATM_TEAM", "atm-dev")
+        .env("ATM_IDENTITY", "arch-ctm")
+        .env_remove("ATM_CONFIG")
+        .env_remove("CLAUDE_SESSION_ID")
+        .current_dir(&workdir)
+        .arg("prompt-status");
+    cmd
+}
+
+fn prompt_status(temp_dir: &TempDir, args: &[&str]) -> std::process::Output {
+    prompt_status_cmd(temp_dir).args(args).output().unwrap()
+}
+
+fn write_inbox(temp_dir: &TempDir, json: &str) {
+    let inboxes = temp_dir.path().join(".claude/teams/atm-dev/inboxes");
+    fs::create_dir_all(&inboxes).unwrap();
+    fs::write(inboxes.join("arch-ctm.json"), json).unwrap();
+}
+
+#[test]
+fn test_prompt_status_prints_unread_count() {
+    let temp_dir = TempDir::new().unwrap();
+    write_inbox(
+        &temp_dir,
+        r#"[
+            {"from": "team-lead", "text": "a", "timestamp": "2026-02-11T10:00:00Z", "read": false},
+            {"from": "team-lead", "text": "b", "timestamp": "2026-02-11T10:01:00Z", "read": true},
+            {"from": "team-lead", "text": "c", "timestamp": "2026-02-11T10:02:00Z", "read": false}
+        ]"#,
+    );
+
+    let output = prompt_status(&temp_dir, &[]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "atm:2\n");
+    assert!(output.stderr.is_empty(), "prompt-status must be quiet");
+
+    let output = prompt_status(&temp_dir, &["--prefix", "mail"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "mail:2\n");
+}
+
+#[test]
+fn test_prompt_status_zero_unread_exits_success() {
+    let temp_dir = TempDir::new().unwrap();
+
+    // Missing inbox counts as zero unread.
+    let output = prompt_status(&temp_dir, &[]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "atm:0\n");
+
+    let output = prompt_status(&temp_dir, &["--hide-zero"]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn test_prompt_status_config_error_exits_nonzero() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("broken.toml");
+    fs::write(&config_path, "[core\nbroken").unwrap();
+
+    let output = prompt_status_cmd(&temp_dir)
+        .env("ATM_CONFIG", &config_path)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+}
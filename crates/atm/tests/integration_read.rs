@@ -395,6 +395,118 @@ fn test_read_json_reports_bucket_counts() {
     assert_eq!(json["count"], 2);
 }
 
+#[test]
+fn test_read_json_preserves_stored_fields_and_respects_unread_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let messages = vec![
+        serde_json::json!({
+            "from": "team-lead",
+            "text": "Unread with extras",
+            "timestamp": "2026-02-11T12:00:00Z",
+            "read": false,
+            "summary": "extras",
+            "message_id": "msg-u1",
+            "customField": {"nested": [1, 2]}
+        }),
+        serde_json::json!({
+            "from": "team-lead",
+            "text": "Already read",
+            "timestamp": "2026-02-11T10:00:00Z",
+            "read": true,
+            "message_id": "msg-h1"
+        }),
+    ];
+    create_test_inbox(&team_dir, "test-agent", messages);
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "test-team")
+        .arg("read")
+        .arg("--no-since-last-seen")
+        .arg("--unread-only")
+        .arg("--no-mark")
+        .arg("--json")
+        .arg("test-agent")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["count"], 1);
+    assert_eq!(
+        json["messages"],
+        serde_json::json!([{
+            "from": "team-lead",
+            "text": "Unread with extras",
+            "timestamp": "2026-02-11T12:00:00Z",
+            "read": false,
+            "summary": "extras",
+            "message_id": "msg-u1",
+            "customField": {"nested": [1, 2]}
+        }])
+    );
+}
+
+#[test]
+fn test_read_priority_first_orders_unread_by_priority() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let messages = vec![
+        serde_json::json!({
+            "from": "team-lead",
+            "text": "Urgent but older",
+            "timestamp": "2026-02-11T09:00:00Z",
+            "read": false,
+            "message_id": "msg-urgent",
+            "priority": 9
+        }),
+        serde_json::json!({
+            "from": "team-lead",
+            "text": "Chatter",
+            "timestamp": "2026-02-11T12:00:00Z",
+            "read": false,
+            "message_id": "msg-chatter"
+        }),
+    ];
+    create_test_inbox(&team_dir, "test-agent", messages);
+
+    let read_ids = |priority_first: bool| -> Vec<String> {
+        let mut cmd = cargo::cargo_bin_cmd!("atm");
+        set_home_env(&mut cmd, &temp_dir);
+        cmd.env("ATM_TEAM", "test-team")
+            .arg("read")
+            .arg("--no-since-last-seen")
+            .arg("--no-mark")
+            .arg("--json");
+        if priority_first {
+            cmd.arg("--priority-first");
+        }
+        let output = cmd
+            .arg("test-agent")
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        json["messages"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["message_id"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    assert_eq!(read_ids(false), vec!["msg-chatter", "msg-urgent"]);
+    assert_eq!(read_ids(true), vec!["msg-urgent", "msg-chatter"]);
+}
+
 #[test]
 fn test_read_no_mark() {
     let temp_dir = TempDir::new().unwrap();
@@ -799,6 +911,100 @@ fn test_read_json_output() {
         .success();
 }
 
+#[test]
+fn test_read_json_schema_snapshot() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let messages = vec![serde_json::json!({
+        "from": "team-lead",
+        "text": "Snapshot message",
+        "timestamp": "2026-02-11T10:00:00Z",
+        "read": false,
+        "summary": "snap",
+        "message_id": "msg-snap"
+    })];
+    create_test_inbox(&team_dir, "test-agent", messages);
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "test-team")
+        .arg("read")
+        .arg("--no-since-last-seen")
+        .arg("--no-mark")
+        .arg("--json")
+        .arg("test-agent")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "action": "read",
+            "agent": "test-agent",
+            "team": "test-team",
+            "messages": [{
+                "from": "team-lead",
+                "text": "Snapshot message",
+                "timestamp": "2026-02-11T10:00:00Z",
+                "read": false,
+                "summary": "snap",
+                "message_id": "msg-snap"
+            }],
+            "count": 1,
+            "bucket_counts": {"unread": 1, "pending_ack": 0, "history": 0},
+            "history_collapsed": true
+        })
+    );
+}
+
+#[test]
+fn test_read_json_from_configured_output_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+    create_test_inbox(
+        &team_dir,
+        "test-agent",
+        vec![serde_json::json!({
+            "from": "team-lead",
+            "text": "Configured json",
+            "timestamp": "2026-02-11T10:00:00Z",
+            "read": false,
+            "message_id": "msg-cfg"
+        })],
+    );
+    let config_dir = temp_dir.path().join(".config/atm");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("config.toml"),
+        "[display]\nformat = \"json\"\ncolor = false\ntimestamps = \"relative\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "test-team")
+        .arg("read")
+        .arg("--no-since-last-seen")
+        .arg("--no-mark")
+        .arg("test-agent")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["count"], 1);
+    assert_eq!(json["messages"][0]["message_id"], "msg-cfg");
+}
+
 #[test]
 fn test_read_since_last_seen_default() {
     let temp_dir = TempDir::new().unwrap();
@@ -1404,3 +1610,49 @@ fn test_inbox_clear_removes_idle_acked_and_old_messages() {
     assert_eq!(persisted.len(), 1);
     assert_eq!(persisted[0]["message_id"], "msg-keep");
 }
+
+#[test]
+fn test_read_shows_display_name_with_raw_identity_for_own_team_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+    let config_path = team_dir.join("config.json");
+    let mut config: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+    config["displayNames"] = serde_json::json!({"arch-ctm": "Architect"});
+    fs::write(&config_path, serde_json::to_string_pretty(&config).unwrap()).unwrap();
+
+    create_test_inbox(
+        &team_dir,
+        "test-agent",
+        vec![
+            serde_json::json!({
+                "from": "arch-ctm@test-team",
+                "text": "same team",
+                "timestamp": "2026-02-11T10:00:00Z",
+                "read": false,
+                "message_id": "msg-001"
+            }),
+            serde_json::json!({
+                "from": "arch-ctm@other-team",
+                "text": "other team",
+                "timestamp": "2026-02-11T11:00:00Z",
+                "read": false,
+                "message_id": "msg-002"
+            }),
+        ],
+    );
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("read")
+        .arg("--no-since-last-seen")
+        .arg("--no-mark")
+        .arg("test-agent")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "From: Architect (arch-ctm@test-team) |",
+        ))
+        .stdout(predicate::str::contains("From: arch-ctm@other-team |"));
+}
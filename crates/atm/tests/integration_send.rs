@@ -516,6 +516,135 @@ fn test_send_cross_team_addressing() {
     assert_eq!(messages[0]["text"], "Cross-team message");
 }
 
+#[test]
+fn test_send_multiple_recipients() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir1 = setup_test_team(&temp_dir, "team-a");
+    let _team_dir2 = setup_test_team(&temp_dir, "team-b");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "team-a")
+        .env("ATM_IDENTITY", "team-lead")
+        .arg("send")
+        .arg("test-agent")
+        .arg("test-agent@team-b")
+        .arg("--message")
+        .arg("Fan-out message")
+        .arg("--json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["summary"]["total"], 2);
+    assert_eq!(json["summary"]["failed"], 0);
+
+    for team in ["team-a", "team-b"] {
+        let inbox_path = temp_dir
+            .path()
+            .join(format!(".claude/teams/{team}/inboxes/test-agent.json"));
+        let messages: Vec<serde_json::Value> =
+            serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0]["text"], "Fan-out message");
+    }
+}
+
+#[test]
+fn test_send_multiple_recipients_unknown_agent_writes_nothing() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .env("ATM_IDENTITY", "team-lead")
+        .arg("send")
+        .arg("test-agent")
+        .arg("ghost")
+        .arg("Hello both")
+        .assert()
+        .failure();
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    assert!(!inbox_path.exists());
+}
+
+/// Add `groups` to an existing team config.
+fn set_team_groups(team_dir: &Path, groups: serde_json::Value) {
+    let config_path = team_dir.join("config.json");
+    let mut config: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+    config["groups"] = groups;
+    fs::write(&config_path, serde_json::to_string_pretty(&config).unwrap()).unwrap();
+}
+
+#[test]
+fn test_send_to_group_expands_members() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+    set_team_groups(
+        &team_dir,
+        serde_json::json!({"everyone": ["team-lead", "test-agent"]}),
+    );
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "test-team")
+        .env("ATM_IDENTITY", "team-lead")
+        .arg("send")
+        .arg("everyone")
+        .arg("Group message")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("group 'everyone' expanded to test-agent"),
+        "stderr: {stderr}"
+    );
+
+    let inboxes_dir = team_dir.join("inboxes");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(inboxes_dir.join("test-agent.json")).unwrap())
+            .unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["text"], "Group message");
+    assert!(
+        !inboxes_dir.join("team-lead.json").exists(),
+        "sender should be excluded from its own group"
+    );
+}
+
+#[test]
+fn test_send_unknown_group_suggests_close_names() {
+    let temp_dir = TempDir::new().unwrap();
+    let team_dir = setup_test_team(&temp_dir, "test-team");
+    set_team_groups(&team_dir, serde_json::json!({"reviewers": ["test-agent"]}));
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "test-team")
+        .env("ATM_IDENTITY", "team-lead")
+        .arg("send")
+        .arg("reviewer")
+        .arg("Hello")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("not found in team 'test-team' (did you mean: reviewers?)"),
+        "stderr: {stderr}"
+    );
+}
+
 #[test]
 fn test_send_alias_with_team_suffix_resolves_end_to_end() {
     let temp_dir = TempDir::new().unwrap();
@@ -746,6 +875,432 @@ fn test_send_with_stdin() {
     assert_eq!(messages[0]["text"], "Message from stdin");
 }
 
+#[test]
+fn test_send_with_stdin_preserves_trailing_newlines() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let body = "```\nlet x = 1;\n```\n\n";
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .args(["send", "test-agent", "--stdin"])
+        .write_stdin(body)
+        .assert()
+        .success();
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    assert_eq!(messages[0]["text"], body);
+}
+
+#[test]
+fn test_send_stdin_with_positional_message_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .args(["send", "test-agent", "inline body text", "--stdin"])
+        .write_stdin("piped body")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("--stdin"));
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    assert!(!inbox_path.exists());
+}
+
+#[test]
+fn test_send_stdin_rejects_extra_positional_that_is_not_a_recipient() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .args(["send", "test-agent", "ok", "--stdin"])
+        .write_stdin("piped body")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Cannot combine a positional message (\"ok\") with --stdin",
+        ));
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    assert!(!inbox_path.exists());
+
+    // A second roster member is still a valid extra recipient.
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .args(["send", "test-agent", "team-lead", "--stdin"])
+        .write_stdin("piped body")
+        .assert()
+        .success();
+    assert!(inbox_path.exists());
+}
+
+#[test]
+fn test_send_template_expands_placeholders() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("send")
+        .arg("test-agent")
+        .arg("--template")
+        .arg("[{team}] status from {from} {{ok}}")
+        .assert()
+        .success();
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    let inbox_content = fs::read_to_string(&inbox_path).unwrap();
+    let messages: Vec<serde_json::Value> = serde_json::from_str(&inbox_content).unwrap();
+
+    assert_eq!(
+        messages[0]["text"],
+        "[test-team] status from team-lead {ok}"
+    );
+}
+
+#[test]
+fn test_send_without_template_keeps_literal_braces() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("send")
+        .arg("test-agent")
+        .arg("literal {team} {unknown}")
+        .assert()
+        .success();
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    let inbox_content = fs::read_to_string(&inbox_path).unwrap();
+    let messages: Vec<serde_json::Value> = serde_json::from_str(&inbox_content).unwrap();
+
+    assert_eq!(messages[0]["text"], "literal {team} {unknown}");
+}
+
+#[test]
+fn test_send_template_unknown_placeholder_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("send")
+        .arg("test-agent")
+        .arg("--template")
+        .arg("hello {nope}")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("unknown template placeholder"));
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    assert!(!inbox_path.exists());
+}
+
+fn write_validate_sender_config(temp_dir: &TempDir) {
+    fs::write(
+        temp_dir.path().join("workdir/.atm.toml"),
+        "[core]\ndefault_team = \"test-team\"\nidentity = \"human\"\n\n[messaging]\nvalidate_sender = true\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_send_validate_sender_rejects_spoofed_from() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    write_validate_sender_config(&temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("send")
+        .arg("test-agent")
+        .arg("--from")
+        .arg("impostor")
+        .arg("trust me")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Sender identity 'impostor' is not a member of team 'test-team'",
+        ));
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    assert!(!inbox_path.exists());
+}
+
+#[test]
+fn test_send_validate_sender_allows_members_and_as_override() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut member = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut member, &temp_dir);
+    write_validate_sender_config(&temp_dir);
+    member
+        .env("ATM_TEAM", "test-team")
+        .arg("send")
+        .arg("team-lead")
+        .arg("--from")
+        .arg("test-agent")
+        .arg("from a member")
+        .assert()
+        .success();
+
+    let mut admin = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut admin, &temp_dir);
+    admin
+        .env("ATM_TEAM", "test-team")
+        .arg("send")
+        .arg("test-agent")
+        .arg("--as")
+        .arg("ops-bot")
+        .arg("from an operator")
+        .assert()
+        .success();
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    assert_eq!(messages[0]["from"], "ops-bot");
+}
+
+#[test]
+fn test_send_counter_id_scheme_is_monotonic_per_inbox() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    for text in ["first", "second"] {
+        let mut cmd = cargo::cargo_bin_cmd!("atm");
+        set_home_env(&mut cmd, &temp_dir);
+        fs::write(
+            temp_dir.path().join("workdir/.atm.toml"),
+            "[messaging]\nid_scheme = \"counter\"\n",
+        )
+        .unwrap();
+        cmd.env("ATM_TEAM", "test-team")
+            .arg("send")
+            .arg("test-agent")
+            .arg(text)
+            .assert()
+            .success();
+    }
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    let ids: Vec<&str> = messages
+        .iter()
+        .filter_map(|m| m["message_id"].as_str())
+        .collect();
+    assert_eq!(ids.len(), 2);
+    assert!(ids[0].ends_with("/test-team/test-agent/00000000000000000001"));
+    assert!(ids[1].ends_with("/test-team/test-agent/00000000000000000002"));
+}
+
+#[test]
+fn test_send_dry_run_does_not_advance_id_counter() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    for extra in [Some("--dry-run"), None] {
+        let mut cmd = cargo::cargo_bin_cmd!("atm");
+        set_home_env(&mut cmd, &temp_dir);
+        fs::write(
+            temp_dir.path().join("workdir/.atm.toml"),
+            "[messaging]\nid_scheme = \"counter\"\n",
+        )
+        .unwrap();
+        cmd.env("ATM_TEAM", "test-team")
+            .arg("send")
+            .arg("test-agent")
+            .arg("hello")
+            .args(extra)
+            .assert()
+            .success();
+    }
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    assert_eq!(messages.len(), 1);
+    assert!(
+        messages[0]["message_id"]
+            .as_str()
+            .unwrap()
+            .ends_with("/00000000000000000001")
+    );
+}
+
+#[test]
+fn test_send_priority_is_read_back_first() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    for (text, priority) in [("routine", None), ("urgent", Some("9"))] {
+        let mut cmd = cargo::cargo_bin_cmd!("atm");
+        set_home_env(&mut cmd, &temp_dir);
+        cmd.env("ATM_TEAM", "test-team")
+            .arg("send")
+            .arg("test-agent")
+            .arg(text);
+        if let Some(priority) = priority {
+            cmd.args(["--priority", priority]);
+        }
+        cmd.assert().success();
+    }
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .env("ATM_TEAM", "test-team")
+        .args([
+            "read",
+            "--no-since-last-seen",
+            "--no-mark",
+            "--json",
+            "--priority-first",
+            "test-agent",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["messages"][0]["text"], "urgent");
+    assert_eq!(json["messages"][0]["priority"], 9);
+    assert_eq!(json["messages"][1]["text"], "routine");
+}
+
+#[test]
+fn test_send_rejects_out_of_range_priority() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .args(["send", "test-agent", "hello", "--priority", "256"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_send_confirm_read_times_out_when_unread() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("send")
+        .arg("test-agent")
+        .arg("please read")
+        .arg("--confirm-read")
+        .arg("--timeout")
+        .arg("1s")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Timed out after 1s waiting for test-agent@test-team to read message",
+        ));
+
+    // The message is still delivered.
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    assert_eq!(messages.len(), 1);
+}
+
+#[test]
+fn test_send_confirm_read_succeeds_once_message_is_read() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(&workdir).unwrap();
+
+    let child = std::process::Command::new(cargo::cargo_bin("atm"))
+        .env("ATM_HOME", temp_dir.path())
+        .env("ATM_DAEMON_AUTOSTART", "0")
+        .env("ATM_TEAM", "test-team")
+        .env("ATM_IDENTITY", "team-lead")
+        .env_remove("ATM_CONFIG")
+        .env_remove("CLAUDE_SESSION_ID")
+        .current_dir(&workdir)
+        .args([
+            "send",
+            "test-agent",
+            "please read",
+            "--confirm-read",
+            "--timeout",
+            "30s",
+            "--json",
+        ])
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    while !inbox_path.exists() {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "message never delivered"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+
+    // Recipient reads the inbox, flipping the read flag.
+    let mut read = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut read, &temp_dir);
+    read.env("ATM_TEAM", "test-team")
+        .env("ATM_IDENTITY", "test-agent")
+        .arg("read")
+        .assert()
+        .success();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["read_confirmed"], true);
+}
+
 #[test]
 fn test_send_with_file_reference() {
     let temp_dir = TempDir::new().unwrap();
@@ -782,6 +1337,70 @@ fn test_send_with_file_reference() {
     assert!(text.contains("test-file.txt"));
 }
 
+#[test]
+fn test_send_with_file_embed_sends_contents() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(workdir.join(".git")).unwrap();
+    let test_file = workdir.join("notes.txt");
+    fs::write(&test_file, "File content").unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("send")
+        .arg("test-agent")
+        .arg("--file")
+        .arg(&test_file)
+        .arg("--embed")
+        .assert()
+        .success();
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    assert_eq!(messages[0]["text"], "File content");
+}
+
+#[test]
+fn test_send_embed_oversize_file_leaves_room_for_prefixes() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+    let big_file = temp_dir.path().join("big.log");
+    fs::write(&big_file, "x".repeat(2 * 1024 * 1024)).unwrap();
+
+    // Self-send adds a warning prefix. The path is outside any repo, but the
+    // contents are embedded, so no share copy is made.
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .arg("send")
+        .arg("team-lead")
+        .arg("see log")
+        .arg("--file")
+        .arg(&big_file)
+        .arg("--embed")
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("truncated"));
+
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/team-lead.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    let text = messages[0]["text"].as_str().unwrap();
+    assert!(text.len() <= 1_048_576);
+    assert!(text.starts_with("[WARNING: Sent to self"));
+    assert!(text.contains("see log\n\nxxx"));
+    assert!(text.contains("truncated: file exceeded the message size limit"));
+    assert!(!text.contains("[atm] File path rewritten"));
+    assert!(!temp_dir.path().join(".config/atm/share").exists());
+}
+
 #[test]
 fn test_send_agent_not_found() {
     let temp_dir = TempDir::new().unwrap();
@@ -1178,6 +1797,62 @@ fn test_send_warns_and_continues_when_register_hint_is_unsupported() {
     let _ = fake_claude.wait();
 }
 
+#[cfg(unix)]
+#[test]
+fn test_send_registers_sender_hint_once_and_not_on_dry_run() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+
+    let mut fake_claude = start_fake_claude_process(temp_dir.path());
+    let (mut daemon, request_log) = start_fake_request_logging_daemon(temp_dir.path());
+
+    let ppid = std::process::id();
+    let hook_path = temp_dir.path().join(format!("atm-hook-{ppid}.json"));
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64();
+    let hook = serde_json::json!({
+        "pid": fake_claude.id(),
+        "session_id": "test-session-send-hint-once",
+        "agent_name": "team-lead",
+        "created_at": now,
+    });
+    fs::write(&hook_path, serde_json::to_string(&hook).unwrap()).unwrap();
+
+    let register_hints = || {
+        fs::read_to_string(&request_log)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .filter(|request| request["command"] == "register-hint")
+            .count()
+    };
+    let send = |extra: &[&str]| {
+        let mut cmd = cargo::cargo_bin_cmd!("atm");
+        set_home_env(&mut cmd, &temp_dir);
+        cmd.env("TMPDIR", temp_dir.path())
+            .env("TMP", temp_dir.path())
+            .env("TEMP", temp_dir.path())
+            .env("ATM_TEAM", "test-team")
+            .arg("send")
+            .args(extra)
+            .args(["test-agent", "team-lead", "hint once"])
+            .assert()
+            .success();
+    };
+
+    send(&["--dry-run"]);
+    assert_eq!(register_hints(), 0, "a dry run must not register a hint");
+    send(&[]);
+    assert_eq!(register_hints(), 1, "one hint per send, not per recipient");
+
+    let _ = daemon.kill();
+    let _ = daemon.wait();
+    let _ = fake_claude.kill();
+    let _ = fake_claude.wait();
+}
+
 #[cfg(unix)]
 #[test]
 fn test_send_emits_post_send_idle_without_subscribe_side_effect() {
@@ -1223,3 +1898,38 @@ fn test_send_emits_post_send_idle_without_subscribe_side_effect() {
     let _ = daemon.kill();
     let _ = daemon.wait();
 }
+
+#[test]
+#[cfg(unix)]
+fn test_send_no_daemon_flag_skips_daemon_socket() {
+    let temp_dir = TempDir::new().unwrap();
+    let _team_dir = setup_test_team(&temp_dir, "test-team");
+    let (mut daemon, request_log) = start_fake_request_logging_daemon(temp_dir.path());
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.env("ATM_TEAM", "test-team")
+        .env("ATM_RUNTIME", "codex")
+        .env("ATM_SESSION_ID", "codex-session-123")
+        .arg("--no-daemon")
+        .arg("send")
+        .arg("test-agent")
+        .arg("filesystem only")
+        .assert()
+        .success();
+
+    let requests = fs::read_to_string(&request_log).unwrap_or_default();
+    assert!(
+        requests.trim().is_empty(),
+        "--no-daemon must not send daemon requests; got: {requests}"
+    );
+    let inbox_path = temp_dir
+        .path()
+        .join(".claude/teams/test-team/inboxes/test-agent.json");
+    let messages: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&inbox_path).unwrap()).unwrap();
+    assert_eq!(messages[0]["text"], "filesystem only");
+
+    let _ = daemon.kill();
+    let _ = daemon.wait();
+}
@@ -818,7 +818,7 @@ fn test_spawn_help_without_atm_toml_includes_generated_launch_reference() {
     assert!(stdout.contains("Launch command output:"));
     assert!(stdout.contains("exact copy/paste launch command"));
     assert!(stdout.contains("atm teams spawn test-member-3 --runtime claude"));
-    assert!(stdout.contains("--color cyan --model haiku"));
+    assert!(stdout.contains("--runtime-color cyan --model haiku"));
 }
 
 #[test]
@@ -0,0 +1,252 @@
+//! Integration tests for `atm teams rename`
+
+use assert_cmd::cargo;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+/// Set ATM_HOME so all commands use the temp directory.
+fn set_home_env(cmd: &mut assert_cmd::Command, temp_dir: &TempDir) {
+    let workdir = temp_dir.path().join("workdir");
+    std::fs::create_dir_all(&workdir).ok();
+    cmd.env("ATM_HOME", temp_dir.path())
+        .env("ATM_DAEMON_AUTOSTART", "0")
+        .env_remove("ATM_TEAM")
+        .env_remove("ATM_IDENTITY")
+        .env_remove("ATM_CONFIG")
+        .env_remove("CLAUDE_SESSION_ID")
+        .current_dir(&workdir);
+}
+
+/// Create a team with team-lead and dev members, each with an inbox.
+fn setup_team(temp_dir: &TempDir, team_name: &str) -> PathBuf {
+    let team_dir = temp_dir.path().join(".claude/teams").join(team_name);
+    let inboxes_dir = team_dir.join("inboxes");
+    fs::create_dir_all(&inboxes_dir).unwrap();
+
+    let config = serde_json::json!({
+        "name": team_name,
+        "description": "Test team",
+        "createdAt": 1739284800000i64,
+        "leadAgentId": format!("team-lead@{team_name}"),
+        "leadSessionId": "test-session-id",
+        "members": [
+            {
+                "agentId": format!("team-lead@{team_name}"),
+                "name": "team-lead",
+                "agentType": "general-purpose",
+                "model": "claude-opus-4-6",
+                "joinedAt": 1739284800000i64,
+                "cwd": temp_dir.path().to_str().unwrap(),
+                "subscriptions": []
+            },
+            {
+                "agentId": format!("dev@{team_name}"),
+                "name": "dev",
+                "agentType": "general-purpose",
+                "model": "claude-haiku-4-5",
+                "joinedAt": 1739284800000i64,
+                "cwd": temp_dir.path().to_str().unwrap(),
+                "subscriptions": []
+            }
+        ]
+    });
+    fs::write(
+        team_dir.join("config.json"),
+        serde_json::to_string_pretty(&config).unwrap(),
+    )
+    .unwrap();
+    for member in ["team-lead", "dev"] {
+        fs::write(
+            inboxes_dir.join(format!("{member}.json")),
+            r#"[{"from":"team-lead","text":"hi","timestamp":"2026-01-01T00:00:00Z","read":false}]"#,
+        )
+        .unwrap();
+    }
+
+    team_dir
+}
+
+fn read_config(team_dir: &std::path::Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(team_dir.join("config.json")).unwrap()).unwrap()
+}
+
+#[test]
+fn test_teams_rename_moves_team_and_keeps_members_and_inboxes() {
+    let temp_dir = TempDir::new().unwrap();
+    let old_dir = setup_team(&temp_dir, "alpha");
+    let tasks_dir = temp_dir.path().join(".claude/tasks/alpha");
+    fs::create_dir_all(&tasks_dir).unwrap();
+    fs::write(tasks_dir.join("1.json"), r#"{"id":"1"}"#).unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.args(["teams", "rename", "alpha", "beta"]);
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("Renamed team 'alpha' to 'beta'"));
+
+    let new_dir = temp_dir.path().join(".claude/teams/beta");
+    assert!(!old_dir.exists(), "old team dir should be gone");
+    let config = read_config(&new_dir);
+    assert_eq!(config["name"], "beta");
+    assert_eq!(config["leadAgentId"], "team-lead@beta");
+    assert_eq!(config["leadSessionId"], "test-session-id");
+    let members = config["members"].as_array().unwrap();
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0]["agentId"], "team-lead@beta");
+    assert_eq!(members[1]["agentId"], "dev@beta");
+    assert_eq!(members[1]["name"], "dev");
+
+    for member in ["team-lead", "dev"] {
+        let inbox = fs::read_to_string(new_dir.join(format!("inboxes/{member}.json"))).unwrap();
+        assert!(inbox.contains("\"text\":\"hi\""));
+    }
+    assert!(temp_dir.path().join(".claude/tasks/beta/1.json").exists());
+    assert!(!tasks_dir.exists());
+}
+
+#[test]
+fn test_teams_rename_refuses_existing_target_and_leaves_old_untouched() {
+    let temp_dir = TempDir::new().unwrap();
+    let old_dir = setup_team(&temp_dir, "alpha");
+    setup_team(&temp_dir, "beta");
+    let before = fs::read_to_string(old_dir.join("config.json")).unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.args(["teams", "rename", "alpha", "beta"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("already exists"));
+
+    assert_eq!(
+        fs::read_to_string(old_dir.join("config.json")).unwrap(),
+        before
+    );
+    assert!(old_dir.join("inboxes/dev.json").exists());
+    assert_eq!(
+        read_config(&temp_dir.path().join(".claude/teams/beta"))["name"],
+        "beta"
+    );
+}
+
+#[test]
+fn test_teams_rename_rejects_invalid_name() {
+    let temp_dir = TempDir::new().unwrap();
+    let old_dir = setup_team(&temp_dir, "alpha");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.args(["teams", "rename", "alpha", "../escape"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("Invalid team name"));
+    assert!(old_dir.join("config.json").exists());
+}
+
+#[test]
+fn test_teams_rename_rejects_invalid_old_name() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_team(&temp_dir, "alpha");
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.args(["teams", "rename", "../alpha", "beta"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("Invalid team name '../alpha'"));
+    assert!(!temp_dir.path().join(".claude/teams/beta").exists());
+}
+
+#[test]
+fn test_teams_rename_moves_runtime_dirs_and_retargets_spool() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_team(&temp_dir, "alpha");
+    let atm_dir = temp_dir.path().join(".config/atm");
+    for dir in ["archive/alpha/dev", "agent-sessions/alpha", "share/alpha"] {
+        fs::create_dir_all(atm_dir.join(dir)).unwrap();
+        fs::write(atm_dir.join(dir).join("f.json"), "{}").unwrap();
+    }
+    let spool = atm_dir.join("logs/atm/spool");
+    fs::create_dir_all(spool.join("pending")).unwrap();
+    fs::create_dir_all(spool.join("failed")).unwrap();
+    let entry = |team: &str| {
+        serde_json::json!({
+            "target_team": team,
+            "target_agent": "dev",
+            "message": {"from": "team-lead", "text": "hi", "timestamp": "2026-01-01T00:00:00Z"},
+            "retry_count": 0,
+            "max_retries": 10,
+            "created_at": "2026-01-01T00:00:00Z",
+            "last_attempt": "2026-01-01T00:00:00Z"
+        })
+        .to_string()
+    };
+    fs::write(spool.join("pending/1-dev@alpha.json"), entry("alpha")).unwrap();
+    fs::write(spool.join("failed/2-dev@alpha.json"), entry("alpha")).unwrap();
+    fs::write(spool.join("pending/3-dev@gamma.json"), entry("gamma")).unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.args(["teams", "rename", "alpha", "beta"]);
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("retargeted 2 spooled message(s)"));
+
+    for dir in ["archive/beta/dev", "agent-sessions/beta", "share/beta"] {
+        assert!(atm_dir.join(dir).join("f.json").exists(), "{dir}");
+    }
+    for dir in ["archive/alpha", "agent-sessions/alpha", "share/alpha"] {
+        assert!(!atm_dir.join(dir).exists(), "{dir}");
+    }
+    let target = |path: &str| {
+        let value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(spool.join(path)).unwrap()).unwrap();
+        value["target_team"].as_str().unwrap().to_string()
+    };
+    assert_eq!(target("pending/1-dev@alpha.json"), "beta");
+    assert_eq!(target("failed/2-dev@alpha.json"), "beta");
+    assert_eq!(target("pending/3-dev@gamma.json"), "gamma");
+}
+
+#[test]
+fn test_teams_rename_failure_rolls_back_moves_and_spool() {
+    let temp_dir = TempDir::new().unwrap();
+    let old_dir = setup_team(&temp_dir, "alpha");
+    // A config that cannot be parsed makes the rewrite fail after the moves.
+    fs::write(old_dir.join("config.json"), "not json").unwrap();
+    let tasks_dir = temp_dir.path().join(".claude/tasks/alpha");
+    fs::create_dir_all(&tasks_dir).unwrap();
+    let share_dir = temp_dir.path().join(".config/atm/share/alpha");
+    fs::create_dir_all(&share_dir).unwrap();
+    let spool_entry = temp_dir
+        .path()
+        .join(".config/atm/logs/atm/spool/pending/1-dev@alpha.json");
+    fs::create_dir_all(spool_entry.parent().unwrap()).unwrap();
+    let spooled = r#"{"target_team":"alpha","target_agent":"dev"}"#;
+    fs::write(&spool_entry, spooled).unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    cmd.args(["teams", "rename", "alpha", "beta"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("nothing was changed"));
+
+    assert_eq!(
+        fs::read_to_string(old_dir.join("config.json")).unwrap(),
+        "not json"
+    );
+    assert!(old_dir.join("inboxes/dev.json").exists());
+    assert!(tasks_dir.exists());
+    assert!(share_dir.exists());
+    assert_eq!(fs::read_to_string(&spool_entry).unwrap(), spooled);
+    for moved in [
+        ".claude/teams/beta",
+        ".claude/tasks/beta",
+        ".config/atm/share/beta",
+    ] {
+        assert!(!temp_dir.path().join(moved).exists(), "{moved}");
+    }
+}
@@ -0,0 +1,67 @@
+use assert_cmd::cargo;
+use std::fs;
+use tempfile::TempDir;
+
+fn set_home_env(cmd: &mut assert_cmd::Command, temp_dir: &TempDir) {
+    let workdir = temp_dir.path().join("workdir");
+    fs::create_dir_all(&workdir).unwrap();
+    cmd.env("ATM_HOME", temp_dir.path())
+        .env("ATM_DAEMON_AUTOSTART", "0")
+        .env_remove("ATM_CONFIG")
+        .env_remove("ATM_TEAM")
+        .env_remove("ATM_IDENTITY")
+        .current_dir(&workdir);
+}
+
+fn write_file(path: &std::path::Path, bytes: usize) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, vec![b'x'; bytes]).unwrap();
+}
+
+#[test]
+fn test_teams_usage_json_reports_categories_and_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let teams_root = temp_dir.path().join(".claude/teams");
+    write_file(&teams_root.join("alpha/config.json"), 40);
+    write_file(&teams_root.join("alpha/inboxes/worker.json"), 300);
+    write_file(
+        &teams_root.join(".archives/alpha/removed-old-1/inboxes/old.json"),
+        60,
+    );
+    write_file(&teams_root.join("beta/inboxes/worker.json"), 5000);
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd
+        .args(["teams", "usage", "alpha", "--json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["team"], "alpha");
+    assert_eq!(json["totalBytes"], 400);
+    assert_eq!(json["categories"]["inbox"], 300);
+    assert_eq!(json["categories"]["team"], 40);
+    assert_eq!(json["categories"]["archive"], 60);
+    let files = json["files"].as_array().unwrap();
+    assert_eq!(files.len(), 3);
+    assert_eq!(files[0]["category"], "inbox");
+    assert_eq!(files[0]["bytes"], 300);
+}
+
+#[test]
+fn test_teams_usage_unknown_team_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir_all(temp_dir.path().join(".claude/teams")).unwrap();
+
+    let mut cmd = cargo::cargo_bin_cmd!("atm");
+    set_home_env(&mut cmd, &temp_dir);
+    let output = cmd.args(["teams", "usage", "missing"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Team 'missing' not found"));
+}